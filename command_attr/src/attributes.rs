@@ -3,7 +3,7 @@ use syn::parse::{Error, Result};
 use syn::spanned::Spanned;
 use syn::{Attribute, Ident, Lit, LitStr, Meta, NestedMeta, Path};
 
-use crate::structures::{Checks, Colour, HelpBehaviour, OnlyIn, Permissions};
+use crate::structures::{Checks, Colour, CommandArgument, HelpBehaviour, OnlyIn, Permissions};
 use crate::util::{AsOption, LitExt};
 
 use std::fmt::{self, Write};
@@ -298,6 +298,40 @@ impl AttributeOption for Permissions {
     }
 }
 
+impl AttributeOption for CommandArgument {
+    fn parse(values: Values) -> Result<Self> {
+        validate(&values, &[ValueKind::List])?;
+
+        let span = values.span;
+        let mut literals = values.literals.into_iter();
+
+        let (name, ty) = match (literals.next(), literals.next()) {
+            (Some(name), Some(ty)) => (name.to_str(), ty),
+            _ => return Err(Error::new(span, "expected an argument name and type")),
+        };
+
+        let kind = CommandArgument::kind_from_type(&ty.to_str(), ty.span())?;
+
+        let mut description = AsOption(None);
+        let mut required = true;
+
+        for lit in literals {
+            match lit.to_str().as_str() {
+                "optional" => required = false,
+                _ if description.0.is_none() => description = AsOption(Some(lit.to_str())),
+                _ => return Err(Error::new(lit.span(), "unexpected value; expected `optional`")),
+            }
+        }
+
+        Ok(CommandArgument {
+            name,
+            kind,
+            description,
+            required,
+        })
+    }
+}
+
 impl<T: AttributeOption> AttributeOption for AsOption<T> {
     #[inline]
     fn parse(values: Values) -> Result<Self> {
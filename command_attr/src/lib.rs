@@ -14,7 +14,7 @@ use syn::{
     parse_macro_input, parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
-    Ident, Lit, Token,
+    Data, DeriveInput, Fields, Ident, Lit, Token,
 };
 
 pub(crate) mod attributes;
@@ -79,12 +79,30 @@ macro_rules! match_options {
 /// | `#[owners_only]` </br> `#[owners_only(b)]`                                   | If this command is exclusive to owners.                                                                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[owner_privilege]` </br> `#[owner_privilege(b)]`                           | If owners can bypass certain options.                                                                    | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[sub_commands(commands)]`                                                  | The sub or children commands of this command. They are executed in the form: `this-command sub-command`. | `commands` is a comma separated list of identifiers referencing functions marked by the `#[command]` macro.                                                                                                                      |
+/// | `#[arg(name, type, desc)]` </br> `#[arg(name, type, desc, optional)]`        | An argument of the command, exported as an option when registering the command as a slash command. May be called multiple times, in the order of the arguments. | `name` is the argument's name, `type` is the type it is parsed as (e.g. `u64`, `String` or `UserId`), `desc` is an optional string describing the argument. `optional` marks the argument as not required. |
 ///
 /// Documentation comments (`///`) applied onto the function are interpreted as sugar for the
 /// `#[description]` option. When more than one application of the option is performed,
 /// the text is delimited by newlines. This mimics the behaviour of regular doc-comments,
 /// which are sugar for the `#[doc = "..."]` attribute.
 ///
+/// ## Typed arguments
+///
+/// In place of `Args`, the function may declare parameters of any type implementing
+/// `ArgumentParse` after the context and message. The arguments are parsed into them in order
+/// before the body runs, and a parsing error is returned from the command.
+/// A parameter marked with `#[rest]` takes the remainder of the arguments.
+///
+/// ```rust,ignore
+/// #[command]
+/// async fn remind(ctx: &Context, msg: &Message, time: Duration, #[rest] text: String) -> CommandResult {
+///     ...
+/// }
+/// ```
+///
+/// Unless `#[arg]` options are given, the parameters also describe the arguments of the
+/// command when it is registered as a slash command.
+///
 /// # Notes
 /// The name of the command is parsed from the applied function,
 /// or may be specified inside the `#[command]` attribute, a lá `#[command("foobar")]`.
@@ -129,6 +147,11 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
                     .examples
                     .push(propagate_err!(attributes::parse(values)));
             }
+            "arg" => {
+                options
+                    .arguments
+                    .push(propagate_err!(attributes::parse(values)));
+            }
             "description" => {
                 let arg: String = propagate_err!(attributes::parse(values));
 
@@ -178,9 +201,22 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         owners_only,
         owner_privilege,
         sub_commands,
+        mut arguments,
     } = options;
 
+    let typed_args = split_typed_arguments(&mut fun);
+
+    // Without `#[arg]` attributes, the typed parameters describe the arguments.
+    if arguments.is_empty() && !typed_args.is_empty() {
+        arguments = typed_args
+            .iter()
+            .map(CommandArgument::from_typed)
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+    }
+
     propagate_err!(create_declaration_validations(&mut fun, DeclarFor::Command));
+    create_argument_parsing(&mut fun, &typed_args);
 
     let res = parse_quote!(serenity::framework::standard::CommandResult);
     create_return_type_validation(&mut fun, res);
@@ -225,6 +261,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             owners_only: #owners_only,
             owner_privilege: #owner_privilege,
             sub_commands: &[#(&#sub_commands),*],
+            arguments: &[#(#arguments),*],
         };
 
         #(#cooked2)*
@@ -822,3 +859,69 @@ pub fn hook(_attr: TokenStream, input: TokenStream) -> TokenStream {
     }
 
 }
+
+/// Derives `ArgumentParse` for a struct, parsing its fields in order.
+///
+/// A field marked with `#[rest]` takes the remainder of the arguments.
+///
+/// ```rust,ignore
+/// #[derive(ArgumentParse)]
+/// struct KickArgs {
+///     member: Member,
+///     #[rest]
+///     reason: String,
+/// }
+/// ```
+#[proc_macro_derive(ArgumentParse, attributes(rest))]
+pub fn argument_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let data = match input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Error::new(input.ident.span(), "`ArgumentParse` can only be derived for structs")
+                .to_compile_error()
+                .into();
+        },
+    };
+
+    let parse_path = quote!(serenity::framework::standard::ArgumentParse);
+
+    let values = data.fields.iter().map(|field| {
+        let kind = &field.ty;
+
+        if field.attrs.iter().any(|a| a.path.is_ident("rest")) {
+            quote!(serenity::framework::standard::parse_rest(args)?)
+        } else {
+            quote!(<#kind as #parse_path>::parse(ctx, msg, args).await?)
+        }
+    });
+
+    let value = match &data.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| &f.ident);
+
+            quote!(Self { #(#names: #values),* })
+        },
+        Fields::Unnamed(_) => quote!(Self(#(#values),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[serenity::async_trait]
+        impl #impl_generics #parse_path for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            async fn parse(
+                ctx: &serenity::client::Context,
+                msg: &serenity::model::channel::Message,
+                args: &mut serenity::framework::standard::Args,
+            ) -> ::std::result::Result<Self, serenity::framework::standard::ArgumentParseError> {
+                Ok(#value)
+            }
+        }
+    })
+    .into()
+}
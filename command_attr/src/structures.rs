@@ -8,8 +8,8 @@ use syn::{
     parse::{Error, Parse, ParseStream, Result},
     spanned::Spanned,
     punctuated::Punctuated,
-    Attribute, Block, FnArg, Ident, Pat, Path, PathSegment, ReturnType, Stmt, Expr, ExprClosure,
-    Token, Type, Visibility,
+    Attribute, Block, FnArg, GenericArgument, Ident, Pat, Path, PathArguments, PathSegment, ReturnType,
+    Stmt, Expr, ExprClosure, Token, Type, Visibility,
 };
 use std::str::FromStr;
 
@@ -54,6 +54,7 @@ fn parse_argument(arg: FnArg) -> Result<Argument> {
         FnArg::Typed(typed) => {
             let pat = typed.pat;
             let kind = typed.ty;
+            let rest = typed.attrs.iter().any(|a| a.path.is_ident("rest"));
 
             match *pat {
                 Pat::Ident(id) => {
@@ -64,6 +65,7 @@ fn parse_argument(arg: FnArg) -> Result<Argument> {
                         mutable,
                         name,
                         kind: *kind,
+                        rest,
                     })
                 }
                 Pat::Wild(wild) => {
@@ -75,6 +77,7 @@ fn parse_argument(arg: FnArg) -> Result<Argument> {
                         mutable: None,
                         name,
                         kind: *kind,
+                        rest,
                     })
                 }
                 _ => Err(Error::new(
@@ -223,8 +226,6 @@ impl ToTokens for CommandFun {
 
 #[derive(Debug)]
 pub struct FunctionHook {
-    /// `#[...]`-style attributes.
-    pub attributes: Vec<Attribute>,
     /// Populated by cooked attributes. These are attributes outside of the realm of this crate's procedural macros
     /// and will appear in generated output.
     pub cooked: Vec<Attribute>,
@@ -237,8 +238,6 @@ pub struct FunctionHook {
 
 #[derive(Debug)]
 pub struct ClosureHook {
-    /// `#[...]`-style attributes.
-    pub attributes: Vec<Attribute>,
     /// Populated by cooked attributes. These are attributes outside of the realm of this crate's procedural macros
     /// and will appear in generated output.
     pub cooked: Vec<Attribute>,
@@ -259,9 +258,9 @@ impl Parse for Hook {
         let cooked = remove_cooked(&mut attributes);
 
         if is_function(input) {
-            parse_function_hook(input, attributes, cooked).map(Self::Function)
+            parse_function_hook(input, cooked).map(Self::Function)
         } else {
-            parse_closure_hook(input, attributes, cooked).map(Self::Closure)
+            parse_closure_hook(input, cooked).map(Self::Closure)
         }
     }
 }
@@ -273,7 +272,6 @@ fn is_function(input: ParseStream<'_>) -> bool {
 
 fn parse_function_hook(
     input: ParseStream<'_>,
-    attributes: Vec<Attribute>,
     cooked: Vec<Attribute>
 ) -> Result<FunctionHook> {
     let visibility = input.parse::<Visibility>()?;
@@ -304,7 +302,6 @@ fn parse_function_hook(
         .collect::<Result<Vec<_>>>()?;
 
     Ok(FunctionHook {
-        attributes,
         cooked,
         visibility,
         name,
@@ -316,14 +313,12 @@ fn parse_function_hook(
 
 fn parse_closure_hook(
     input: ParseStream<'_>,
-    attributes: Vec<Attribute>,
     cooked: Vec<Attribute>
 ) -> Result<ClosureHook> {
     input.parse::<Token![async]>()?;
     let closure = input.parse::<ExprClosure>()?;
 
     Ok(ClosureHook {
-        attributes,
         cooked,
         args: closure.inputs,
         ret: closure.output,
@@ -447,6 +442,104 @@ impl ToTokens for Checks {
     }
 }
 
+#[derive(Debug)]
+pub struct CommandArgument {
+    pub name: String,
+    pub kind: Ident,
+    pub description: AsOption<String>,
+    pub required: bool,
+}
+
+impl CommandArgument {
+    /// Maps the type an argument is parsed as to the type of its application
+    /// command option.
+    pub fn kind_from_type(ty: &str, span: Span) -> Result<Ident> {
+        let kind = match ty {
+            "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => "Integer",
+            "f32" | "f64" => "Number",
+            "bool" => "Boolean",
+            "String" | "str" | "char" => "String",
+            "User" | "UserId" | "Member" => "User",
+            "Channel" | "ChannelId" | "GuildChannel" => "Channel",
+            "Role" | "RoleId" => "Role",
+            _ => return Err(Error::new(span, format_args!("unsupported argument type: {}", ty))),
+        };
+
+        Ok(Ident::new(kind, span))
+    }
+
+    /// Derives the argument from a typed parameter of a command, if its type
+    /// maps to the type of an application command option.
+    ///
+    /// `Option<T>` and `Vec<T>` parameters are not required.
+    pub fn from_typed(arg: &Argument) -> Option<Self> {
+        let (kind, required) = match generic_argument(&arg.kind, "Option")
+            .or_else(|| generic_argument(&arg.kind, "Vec"))
+        {
+            Some(inner) => (inner, false),
+            None => (&arg.kind, true),
+        };
+
+        let ident = &last_segment(kind)?.ident;
+        let kind = Self::kind_from_type(&ident.to_string(), ident.span()).ok()?;
+
+        let name = arg.name.to_string().trim_start_matches('_').to_string();
+
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(CommandArgument {
+            name,
+            kind,
+            description: AsOption(None),
+            required,
+        })
+    }
+}
+
+fn last_segment(kind: &Type) -> Option<&PathSegment> {
+    match kind {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn generic_argument<'a>(kind: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = last_segment(kind).filter(|s| s.ident == wrapper)?;
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(kind) => Some(kind),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+impl ToTokens for CommandArgument {
+    fn to_tokens(&self, stream: &mut TokenStream2) {
+        let Self {
+            name,
+            kind,
+            description,
+            required,
+        } = self;
+
+        let argument_path = quote!(serenity::framework::standard::CommandArgument);
+        let kind_path = quote!(serenity::model::interactions::ApplicationCommandOptionType);
+
+        stream.extend(quote! {
+            #argument_path {
+                name: #name,
+                kind: #kind_path::#kind,
+                description: #description,
+                required: #required,
+            }
+        });
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Options {
     pub checks: Checks,
@@ -465,6 +558,7 @@ pub struct Options {
     pub owners_only: bool,
     pub owner_privilege: bool,
     pub sub_commands: Vec<Ident>,
+    pub arguments: Vec<CommandArgument>,
 }
 
 impl Options {
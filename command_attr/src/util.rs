@@ -4,7 +4,7 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    braced, bracketed, parenthesized,
+    parenthesized,
     parse::{Error, Parse, ParseStream, Result as SynResult},
     parse_quote,
     punctuated::Punctuated,
@@ -77,30 +77,6 @@ macro_rules! propagate_err {
     }};
 }
 
-#[derive(Debug)]
-pub struct Bracketed<T>(pub Punctuated<T, Comma>);
-
-impl<T: Parse> Parse for Bracketed<T> {
-    fn parse(input: ParseStream<'_>) -> SynResult<Self> {
-        let content;
-        bracketed!(content in input);
-
-        Ok(Bracketed(content.parse_terminated(T::parse)?))
-    }
-}
-
-#[derive(Debug)]
-pub struct Braced<T>(pub Punctuated<T, Comma>);
-
-impl<T: Parse> Parse for Braced<T> {
-    fn parse(input: ParseStream<'_>) -> SynResult<Self> {
-        let content;
-        braced!(content in input);
-
-        Ok(Braced(content.parse_terminated(T::parse)?))
-    }
-}
-
 #[derive(Debug)]
 pub struct Parenthesised<T>(pub Punctuated<T, Comma>);
 
@@ -144,6 +120,8 @@ pub struct Argument {
     pub mutable: Option<Mut>,
     pub name: Ident,
     pub kind: Type,
+    /// Whether the argument is marked with `#[rest]`.
+    pub rest: bool,
 }
 
 impl ToTokens for Argument {
@@ -152,6 +130,7 @@ impl ToTokens for Argument {
             mutable,
             name,
             kind,
+            ..
         } = self;
 
         stream.extend(quote! {
@@ -206,6 +185,7 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
                 mutable: None,
                 name: Ident::new(name, Span::call_site()),
                 kind,
+                rest: false,
             }),
         }
 
@@ -233,6 +213,75 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
     Ok(())
 }
 
+/// Splits off the parameters of a command that are parsed from its arguments,
+/// which are declared after the context and message in place of `Args`.
+///
+/// The parameters are replaced by the `Args` they are parsed from.
+pub fn split_typed_arguments(fun: &mut CommandFun) -> Vec<Argument> {
+    let typed = match fun.args.get(2) {
+        Some(arg) => !is_args_type(&arg.kind),
+        None => false,
+    };
+
+    if !typed {
+        return Vec::new();
+    }
+
+    let typed = fun.args.split_off(2);
+
+    // The context and message are needed to parse the arguments.
+    for (arg, name) in fun.args.iter_mut().zip(&["__ctx", "__msg"]) {
+        if arg.name == "_" {
+            arg.name = Ident::new(name, Span::call_site());
+        }
+    }
+
+    fun.args.push(Argument {
+        mutable: Some(Mut::default()),
+        name: Ident::new("__args", Span::call_site()),
+        kind: parse_quote!(serenity::framework::standard::Args),
+        rest: false,
+    });
+
+    typed
+}
+
+fn is_args_type(kind: &Type) -> bool {
+    match kind {
+        Type::Path(path) => path.path.segments.last().map_or(false, |s| s.ident == "Args"),
+        _ => false,
+    }
+}
+
+/// Generates the statements parsing the typed parameters of a command, in
+/// order, at the start of its body.
+pub fn create_argument_parsing(fun: &mut CommandFun, typed: &[Argument]) {
+    let ctx = fun.args[0].name.clone();
+    let msg = fun.args[1].name.clone();
+
+    let stmts = typed.iter().map(|arg| {
+        let Argument {
+            mutable,
+            name,
+            kind,
+            rest,
+        } = arg;
+
+        if *rest {
+            parse_quote! {
+                let #mutable #name: #kind = serenity::framework::standard::parse_rest(&mut __args)?;
+            }
+        } else {
+            parse_quote! {
+                let #mutable #name: #kind =
+                    <#kind as serenity::framework::standard::ArgumentParse>::parse(#ctx, #msg, &mut __args).await?;
+            }
+        }
+    });
+
+    fun.body.splice(0..0, stmts.collect::<Vec<syn::Stmt>>());
+}
+
 #[inline]
 pub fn create_return_type_validation(r#fn: &mut CommandFun, expect: Type) {
     let stmt = generate_type_validation(r#fn.ret.clone(), expect);
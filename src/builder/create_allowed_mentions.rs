@@ -15,8 +15,9 @@ pub enum ParseValue {
     Roles,
 }
 
-/// A builder to manage the allowed mentions on a message, 
-/// used by the [`ChannelId::send_message`] method.
+/// A builder to manage the allowed mentions on a message, used by the
+/// [`ChannelId::send_message`] and [`Message::edit`] methods, webhook
+/// executions and interaction responses.
 ///
 /// # Examples
 ///
@@ -58,9 +59,15 @@ pub enum ParseValue {
 ///     am.users(vec![msg.author.id])
 /// });
 ///
+/// // Reply to a message without pinging its author.
+/// m.allowed_mentions(|am| {
+///     am.replied_user(false)
+/// });
+///
 /// ```
 ///
 /// [`ChannelId::send_message`]: ../model/id/struct.ChannelId.html#method.send_message
+/// [`Message::edit`]: ../model/channel/struct.Message.html#method.edit
 #[derive(Clone, Debug)]
 pub struct CreateAllowedMentions(pub HashMap<&'static str, Value>);
 
@@ -138,6 +145,15 @@ impl CreateAllowedMentions {
         }
         self
     }
+
+    /// Sets whether the author of the message being replied to is mentioned.
+    ///
+    /// Defaults to `false` when allowed mentions are set.
+    #[inline]
+    pub fn replied_user(&mut self, mention_user: bool) -> &mut Self {
+        self.0.insert("replied_user", Value::Bool(mention_user));
+        self
+    }
 }
 
 impl Default for CreateAllowedMentions {
@@ -0,0 +1,285 @@
+use crate::internal::prelude::*;
+use crate::model::{
+    channel::ChannelType,
+    interactions::ApplicationCommandOptionType,
+};
+use crate::utils;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder to create or edit an [`ApplicationCommand`] for use via a number
+/// of model methods.
+///
+/// These are:
+///
+/// - [`ApplicationCommand::create_global_application_command`]
+/// - [`ApplicationCommand::edit_global_application_command`]
+/// - [`GuildId::create_application_command`]
+/// - [`GuildId::edit_application_command`]
+///
+/// # Examples
+///
+/// Create a command taking a user to greet:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::GuildId, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let guild_id = GuildId(2);
+/// use serenity::model::interactions::ApplicationCommandOptionType;
+///
+/// let command = guild_id.create_application_command(&http, 1, |c| {
+///     c.name("greet").description("Greets a user").create_option(|o| {
+///         o.name("user")
+///             .description("The user to greet")
+///             .kind(ApplicationCommandOptionType::User)
+///             .required(true)
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`ApplicationCommand`]: ../model/interactions/struct.ApplicationCommand.html
+/// [`ApplicationCommand::create_global_application_command`]: ../model/interactions/struct.ApplicationCommand.html#method.create_global_application_command
+/// [`ApplicationCommand::edit_global_application_command`]: ../model/interactions/struct.ApplicationCommand.html#method.edit_global_application_command
+/// [`GuildId::create_application_command`]: ../model/id/struct.GuildId.html#method.create_application_command
+/// [`GuildId::edit_application_command`]: ../model/id/struct.GuildId.html#method.edit_application_command
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommand(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommand {
+    /// Sets the name of the command, 1-32 lowercase characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the command, 1-100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets whether the command is enabled by default when the application
+    /// is added to a guild.
+    ///
+    /// Defaults to `true`.
+    pub fn default_permission(&mut self, default_permission: bool) -> &mut Self {
+        self.0.insert("default_permission", Value::Bool(default_permission));
+        self
+    }
+
+    /// Creates an option for the command, at most 25 options can be added.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        self.add_option(option)
+    }
+
+    /// Adds an option to the command, at most 25 options can be added.
+    pub fn add_option(&mut self, option: CreateApplicationCommandOption) -> &mut Self {
+        let option = Value::Object(utils::hashmap_to_json_map(option.0));
+
+        self.0.entry("options")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(option);
+
+        self
+    }
+
+    /// Sets all options of the command, replacing previously added options.
+    pub fn set_options(&mut self, options: Vec<CreateApplicationCommandOption>) -> &mut Self {
+        let options = options.into_iter()
+            .map(|o| Value::Object(utils::hashmap_to_json_map(o.0)))
+            .collect();
+
+        self.0.insert("options", Value::Array(options));
+        self
+    }
+}
+
+/// A builder for an option of an [`ApplicationCommand`], see
+/// [`CreateApplicationCommand::create_option`].
+///
+/// [`ApplicationCommand`]: ../model/interactions/struct.ApplicationCommand.html
+/// [`CreateApplicationCommand::create_option`]: struct.CreateApplicationCommand.html#method.create_option
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandOption(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandOption {
+    /// Sets the type of the option.
+    ///
+    /// **Note**: This field is required.
+    pub fn kind(&mut self, kind: ApplicationCommandOptionType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the name of the option, 1-32 lowercase characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the option, 1-100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets whether the option must be given by the user.
+    ///
+    /// Required options must be added before optional ones.
+    ///
+    /// Defaults to `false`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Adds a choice to a [`String`] option, at most 25 choices can be added.
+    ///
+    /// [`String`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.String
+    pub fn add_string_choice<D: ToString, E: ToString>(&mut self, name: D, value: E) -> &mut Self {
+        self.add_choice(name, Value::String(value.to_string()))
+    }
+
+    /// Adds a choice to an [`Integer`] option, at most 25 choices can be added.
+    ///
+    /// [`Integer`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Integer
+    pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i64) -> &mut Self {
+        self.add_choice(name, Value::Number(Number::from(value)))
+    }
+
+    /// Adds a choice to a [`Number`] option, at most 25 choices can be added.
+    ///
+    /// Non-finite values are ignored.
+    ///
+    /// [`Number`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Number
+    pub fn add_number_choice<D: ToString>(&mut self, name: D, value: f64) -> &mut Self {
+        match Number::from_f64(value) {
+            Some(value) => self.add_choice(name, Value::Number(value)),
+            None => self,
+        }
+    }
+
+    fn add_choice<D: ToString>(&mut self, name: D, value: Value) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "value": value,
+        });
+
+        self.0.entry("choices")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(choice);
+
+        self
+    }
+
+    /// Creates an option of a [`SubCommand`] or a sub-command of a
+    /// [`SubCommandGroup`].
+    ///
+    /// [`SubCommand`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommand
+    /// [`SubCommandGroup`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommandGroup
+    pub fn create_sub_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        self.add_sub_option(option)
+    }
+
+    /// Adds an option to a [`SubCommand`] or a sub-command to a
+    /// [`SubCommandGroup`].
+    ///
+    /// [`SubCommand`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommand
+    /// [`SubCommandGroup`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.SubCommandGroup
+    pub fn add_sub_option(&mut self, option: CreateApplicationCommandOption) -> &mut Self {
+        let option = Value::Object(utils::hashmap_to_json_map(option.0));
+
+        self.0.entry("options")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(option);
+
+        self
+    }
+
+    /// Sets whether choices are suggested via [`Autocomplete`] interactions
+    /// while the user fills in the option.
+    ///
+    /// **Note**: Options with autocomplete can't have fixed [`choices`].
+    ///
+    /// [`Autocomplete`]: ../model/interactions/enum.InteractionType.html#variant.Autocomplete
+    /// [`choices`]: #method.add_string_choice
+    pub fn autocomplete(&mut self, autocomplete: bool) -> &mut Self {
+        self.0.insert("autocomplete", Value::Bool(autocomplete));
+        self
+    }
+
+    /// Restricts a [`Channel`] option to the given channel types.
+    ///
+    /// [`Channel`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Channel
+    pub fn channel_types(&mut self, channel_types: &[ChannelType]) -> &mut Self {
+        let channel_types = channel_types.iter()
+            .map(|kind| Value::Number(Number::from(kind.num())))
+            .collect();
+
+        self.0.insert("channel_types", Value::Array(channel_types));
+        self
+    }
+}
+
+/// A builder to overwrite all commands of an application or a guild at once.
+///
+/// Used by [`ApplicationCommand::set_global_application_commands`] and
+/// [`GuildId::set_application_commands`].
+///
+/// [`ApplicationCommand::set_global_application_commands`]: ../model/interactions/struct.ApplicationCommand.html#method.set_global_application_commands
+/// [`GuildId::set_application_commands`]: ../model/id/struct.GuildId.html#method.set_application_commands
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommands(pub Vec<Value>);
+
+impl CreateApplicationCommands {
+    /// Creates a command to register.
+    pub fn create_application_command<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+
+        self.add_application_command(command)
+    }
+
+    /// Adds a command to register.
+    pub fn add_application_command(&mut self, command: CreateApplicationCommand) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(command.0)));
+        self
+    }
+
+    /// Sets all commands to register, replacing previously added commands.
+    pub fn set_application_commands(&mut self, commands: Vec<CreateApplicationCommand>) -> &mut Self {
+        self.0 = commands.into_iter()
+            .map(|c| Value::Object(utils::hashmap_to_json_map(c.0)))
+            .collect();
+
+        self
+    }
+}
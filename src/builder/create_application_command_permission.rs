@@ -0,0 +1,186 @@
+use crate::internal::prelude::*;
+use crate::model::{
+    id::CommandId,
+    interactions::ApplicationCommandPermissionType,
+};
+use crate::utils;
+use std::collections::HashMap;
+
+/// A builder to overwrite the permissions of several [`ApplicationCommand`]s
+/// of a guild at once, used by [`GuildId::set_application_commands_permissions`].
+///
+/// Commands that are not given keep their permissions.
+///
+/// # Examples
+///
+/// Allow a role to use a command:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::GuildId, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let guild_id = GuildId(2);
+/// use serenity::model::interactions::ApplicationCommandPermissionType;
+///
+/// guild_id.set_application_commands_permissions(&http, 1, |p| {
+///     p.create_application_command(|c| {
+///         c.id(3).create_permissions(|p| {
+///             p.kind(ApplicationCommandPermissionType::Role).id(4).permission(true)
+///         })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`ApplicationCommand`]: ../model/interactions/struct.ApplicationCommand.html
+/// [`GuildId::set_application_commands_permissions`]: ../model/id/struct.GuildId.html#method.set_application_commands_permissions
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandsPermissions(pub Vec<Value>);
+
+impl CreateApplicationCommandsPermissions {
+    /// Creates the permissions of a command.
+    pub fn create_application_command<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandPermissions) -> &mut CreateApplicationCommandPermissions {
+        let mut command = CreateApplicationCommandPermissions::default();
+        f(&mut command);
+
+        self.add_application_command(command)
+    }
+
+    /// Adds the permissions of a command.
+    pub fn add_application_command(&mut self, command: CreateApplicationCommandPermissions) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(command.0)));
+        self
+    }
+
+    /// Sets the permissions of all commands, replacing previously added
+    /// commands.
+    pub fn set_application_commands(&mut self, commands: Vec<CreateApplicationCommandPermissions>) -> &mut Self {
+        self.0 = commands.into_iter()
+            .map(|c| Value::Object(utils::hashmap_to_json_map(c.0)))
+            .collect();
+
+        self
+    }
+}
+
+/// A builder for the permissions of a single command, as part of a
+/// [`CreateApplicationCommandsPermissions`].
+///
+/// The [`id`] is required.
+///
+/// [`CreateApplicationCommandsPermissions`]: struct.CreateApplicationCommandsPermissions.html
+/// [`id`]: #method.id
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandPermissions(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandPermissions {
+    /// Sets the command the permissions apply to.
+    pub fn id<C: Into<CommandId>>(&mut self, command_id: C) -> &mut Self {
+        self.0.insert("id", Value::String(command_id.into().0.to_string()));
+        self
+    }
+
+    /// Creates a permission of the command.
+    pub fn create_permissions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandPermissionData) -> &mut CreateApplicationCommandPermissionData {
+        let mut permission = CreateApplicationCommandPermissionData::default();
+        f(&mut permission);
+
+        self.add_permissions(permission)
+    }
+
+    /// Adds a permission of the command.
+    pub fn add_permissions(&mut self, permission: CreateApplicationCommandPermissionData) -> &mut Self {
+        add_permission(&mut self.0, permission);
+        self
+    }
+
+    /// Sets all permissions of the command, replacing previously added
+    /// permissions.
+    pub fn set_permissions(&mut self, permissions: Vec<CreateApplicationCommandPermissionData>) -> &mut Self {
+        set_permissions(&mut self.0, permissions);
+        self
+    }
+}
+
+/// A builder to overwrite the permissions of a single [`ApplicationCommand`]
+/// of a guild, used by [`GuildId::set_application_command_permissions`].
+///
+/// [`ApplicationCommand`]: ../model/interactions/struct.ApplicationCommand.html
+/// [`GuildId::set_application_command_permissions`]: ../model/id/struct.GuildId.html#method.set_application_command_permissions
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandPermissionsData(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandPermissionsData {
+    /// Creates a permission of the command.
+    pub fn create_permissions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandPermissionData) -> &mut CreateApplicationCommandPermissionData {
+        let mut permission = CreateApplicationCommandPermissionData::default();
+        f(&mut permission);
+
+        self.add_permissions(permission)
+    }
+
+    /// Adds a permission of the command.
+    pub fn add_permissions(&mut self, permission: CreateApplicationCommandPermissionData) -> &mut Self {
+        add_permission(&mut self.0, permission);
+        self
+    }
+
+    /// Sets all permissions of the command, replacing previously added
+    /// permissions.
+    pub fn set_permissions(&mut self, permissions: Vec<CreateApplicationCommandPermissionData>) -> &mut Self {
+        set_permissions(&mut self.0, permissions);
+        self
+    }
+}
+
+/// A builder for a permission of a command for a role, user or channel.
+///
+/// The [`kind`], [`id`] and [`permission`] are required.
+///
+/// [`id`]: #method.id
+/// [`kind`]: #method.kind
+/// [`permission`]: #method.permission
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandPermissionData(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandPermissionData {
+    /// Sets the type of the target of the permission.
+    pub fn kind(&mut self, kind: ApplicationCommandPermissionType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the Id of the role, user or channel the permission applies to.
+    pub fn id(&mut self, id: u64) -> &mut Self {
+        self.0.insert("id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets whether the command is allowed for the target.
+    pub fn permission(&mut self, permission: bool) -> &mut Self {
+        self.0.insert("permission", Value::Bool(permission));
+        self
+    }
+}
+
+fn add_permission(map: &mut HashMap<&'static str, Value>, permission: CreateApplicationCommandPermissionData) {
+    map.entry("permissions")
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .expect("Must be an array")
+        .push(Value::Object(utils::hashmap_to_json_map(permission.0)));
+}
+
+fn set_permissions(map: &mut HashMap<&'static str, Value>, permissions: Vec<CreateApplicationCommandPermissionData>) {
+    let permissions = permissions.into_iter()
+        .map(|p| Value::Object(utils::hashmap_to_json_map(p.0)))
+        .collect();
+
+    map.insert("permissions", Value::Array(permissions));
+}
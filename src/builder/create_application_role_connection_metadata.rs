@@ -0,0 +1,130 @@
+use crate::internal::prelude::*;
+use crate::model::application::ApplicationRoleConnectionMetadataType;
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to create a metadata record of an application's role
+/// connections.
+///
+/// Used by [`CreateApplicationRoleConnectionMetadataRecords`].
+///
+/// [`CreateApplicationRoleConnectionMetadataRecords`]: struct.CreateApplicationRoleConnectionMetadataRecords.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationRoleConnectionMetadata(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationRoleConnectionMetadata {
+    /// Sets how the value of a user's role connection is compared.
+    ///
+    /// **Note**: This is required.
+    pub fn kind(&mut self, kind: ApplicationRoleConnectionMetadataType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Sets the key of the value in a user's role connection metadata.
+    ///
+    /// **Note**: This is required. Must be between 1 and 50 characters long,
+    /// and only contain lowercase letters, digits and underscores.
+    pub fn key<D: ToString>(&mut self, key: D) -> &mut Self {
+        self.0.insert("key", Value::String(key.to_string()));
+
+        self
+    }
+
+    /// Sets the name of the record.
+    ///
+    /// **Note**: This is required. Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Adds a name of the record localized for the locale, e.g. `de`.
+    pub fn name_localized<L: ToString, D: ToString>(&mut self, locale: L, name: D) -> &mut Self {
+        self.localize("name_localizations", locale.to_string(), name.to_string());
+
+        self
+    }
+
+    /// Sets the description of the record.
+    ///
+    /// **Note**: This is required. Must be between 1 and 200 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Adds a description of the record localized for the locale, e.g. `de`.
+    pub fn description_localized<L: ToString, D: ToString>(&mut self, locale: L, description: D) -> &mut Self {
+        self.localize("description_localizations", locale.to_string(), description.to_string());
+
+        self
+    }
+
+    fn localize(&mut self, key: &'static str, locale: String, text: String) {
+        let localizations = self.0.entry(key).or_insert_with(|| Value::Object(Map::new()));
+
+        if let Value::Object(map) = localizations {
+            map.insert(locale, Value::String(text));
+        }
+    }
+}
+
+/// A builder to overwrite all metadata records of an application's role
+/// connections at once.
+///
+/// Used by [`ApplicationRoleConnectionMetadata::set_records`].
+///
+/// [`ApplicationRoleConnectionMetadata::set_records`]: ../model/application/struct.ApplicationRoleConnectionMetadata.html#method.set_records
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationRoleConnectionMetadataRecords(pub Vec<Value>);
+
+impl CreateApplicationRoleConnectionMetadataRecords {
+    /// Creates a record to set.
+    pub fn create_record<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationRoleConnectionMetadata) -> &mut CreateApplicationRoleConnectionMetadata {
+        let mut record = CreateApplicationRoleConnectionMetadata::default();
+        f(&mut record);
+
+        self.add_record(record)
+    }
+
+    /// Adds a record to set.
+    pub fn add_record(&mut self, record: CreateApplicationRoleConnectionMetadata) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(record.0)));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateApplicationRoleConnectionMetadataRecords;
+    use crate::model::application::ApplicationRoleConnectionMetadataType;
+    use serde_json::json;
+
+    #[test]
+    fn creates_records() {
+        let mut records = CreateApplicationRoleConnectionMetadataRecords::default();
+        records.create_record(|r| {
+            r.kind(ApplicationRoleConnectionMetadataType::BooleanEqual)
+                .key("verified")
+                .name("Verified")
+                .name_localized("de", "Verifiziert")
+                .name_localized("fr", "Vérifié")
+                .description("Has a verified account")
+        });
+
+        assert_eq!(records.0, vec![json!({
+            "type": 7,
+            "key": "verified",
+            "name": "Verified",
+            "name_localizations": {"de": "Verifiziert", "fr": "Vérifié"},
+            "description": "Has a verified account",
+        })]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::internal::prelude::*;
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// The maximum number of choices Discord accepts in an autocomplete response.
+const MAX_CHOICES: usize = 25;
+
+/// A builder for the suggested choices of an autocomplete response, for use
+/// via [`Interaction::create_autocomplete_response`].
+///
+/// At most 25 choices can be suggested, further choices are ignored.
+///
+/// [`Interaction::create_autocomplete_response`]: ../model/interactions/struct.Interaction.html#method.create_autocomplete_response
+#[derive(Clone, Debug)]
+pub struct CreateAutocompleteResponse(pub HashMap<&'static str, Value>);
+
+impl CreateAutocompleteResponse {
+    /// Adds a choice to a [`String`] option.
+    ///
+    /// [`String`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.String
+    pub fn add_string_choice<D: ToString, E: ToString>(&mut self, name: D, value: E) -> &mut Self {
+        self.add_choice(name, Value::String(value.to_string()))
+    }
+
+    /// Adds a choice to an [`Integer`] option.
+    ///
+    /// [`Integer`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Integer
+    pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i64) -> &mut Self {
+        self.add_choice(name, Value::Number(Number::from(value)))
+    }
+
+    /// Adds a choice to a [`Number`] option.
+    ///
+    /// Non-finite values are ignored.
+    ///
+    /// [`Number`]: ../model/interactions/enum.ApplicationCommandOptionType.html#variant.Number
+    pub fn add_number_choice<D: ToString>(&mut self, name: D, value: f64) -> &mut Self {
+        match Number::from_f64(value) {
+            Some(value) => self.add_choice(name, Value::Number(value)),
+            None => self,
+        }
+    }
+
+    fn add_choice<D: ToString>(&mut self, name: D, value: Value) -> &mut Self {
+        let choices = self.0.entry("choices")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array");
+
+        if choices.len() < MAX_CHOICES {
+            choices.push(json!({
+                "name": name.to_string(),
+                "value": value,
+            }));
+        }
+
+        self
+    }
+}
+
+impl Default for CreateAutocompleteResponse {
+    fn default() -> CreateAutocompleteResponse {
+        let mut map = HashMap::new();
+        // An empty list of choices shows "no options match" to the user.
+        map.insert("choices", Value::Array(Vec::new()));
+
+        CreateAutocompleteResponse(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateAutocompleteResponse;
+
+    #[test]
+    fn test_choices_capped() {
+        let mut response = CreateAutocompleteResponse::default();
+
+        for i in 0..30 {
+            response.add_int_choice(i, i);
+        }
+
+        assert_eq!(response.0["choices"].as_array().unwrap().len(), 25);
+    }
+}
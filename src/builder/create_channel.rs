@@ -68,6 +68,23 @@ impl CreateChannel {
         self
     }
 
+    /// [Voice-only] Set the Id of the [`VoiceRegion`] of the channel, or `None` to
+    /// choose the region automatically.
+    ///
+    /// [`VoiceRegion`]: ../model/voice/struct.VoiceRegion.html
+    pub fn voice_region(&mut self, region: Option<String>) -> &mut Self {
+        self.0.insert("rtc_region", region.map_or(Value::Null, Value::String));
+
+        self
+    }
+
+    /// [Voice-only] Set the video quality of the voice channel.
+    pub fn video_quality_mode(&mut self, quality: VideoQualityMode) -> &mut Self {
+        self.0.insert("video_quality_mode", Value::Number(Number::from(quality.num())));
+
+        self
+    }
+
     /// How many seconds must a user wait before sending another message.
     ///
     /// Bots, or users with the `MANAGE_MESSAGES` and/or`MANAGE_CHANNEL` permissions are exempt
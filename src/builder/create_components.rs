@@ -0,0 +1,544 @@
+use crate::internal::prelude::*;
+use crate::model::channel::{ChannelType, ReactionType};
+use crate::model::id::{ChannelId, RoleId, UserId};
+use crate::model::interactions::{ButtonStyle, ComponentType, InputTextStyle};
+use crate::utils;
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// A builder for the components of a message, i.e. its action rows.
+///
+/// A message can have at most 5 action rows.
+///
+/// # Examples
+///
+/// Send a message with a confirmation button and a link button:
+///
+/// ```rust,no_run
+/// # use serenity::{http::Http, model::id::ChannelId};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// use serenity::model::interactions::ButtonStyle;
+///
+/// ChannelId(7).send_message(&http, |m| {
+///     m.content("Are you sure?").components(|c| {
+///         c.create_action_row(|r| {
+///             r.create_button(|b| {
+///                 b.style(ButtonStyle::Danger).label("Delete").custom_id("delete")
+///             })
+///             .create_button(|b| {
+///                 b.style(ButtonStyle::Link).label("Help").url("https://example.com")
+///             })
+///         })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CreateComponents(pub Vec<Value>);
+
+impl CreateComponents {
+    /// Creates an action row.
+    pub fn create_action_row<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateActionRow) -> &mut CreateActionRow {
+        let mut row = CreateActionRow::default();
+        f(&mut row);
+
+        self.add_action_row(row)
+    }
+
+    /// Adds an action row.
+    pub fn add_action_row(&mut self, row: CreateActionRow) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(row.0)));
+        self
+    }
+
+    /// Sets all action rows, replacing previously added rows.
+    pub fn set_action_rows(&mut self, rows: Vec<CreateActionRow>) -> &mut Self {
+        self.0 = rows.into_iter()
+            .map(|r| Value::Object(utils::hashmap_to_json_map(r.0)))
+            .collect();
+
+        self
+    }
+}
+
+/// A builder for an action row, containing either up to 5 buttons, a single
+/// select menu, or a single input text in modals.
+#[derive(Clone, Debug)]
+pub struct CreateActionRow(pub HashMap<&'static str, Value>);
+
+impl CreateActionRow {
+    /// Creates a button.
+    pub fn create_button<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateButton) -> &mut CreateButton {
+        let mut button = CreateButton::default();
+        f(&mut button);
+
+        self.add_button(button)
+    }
+
+    /// Adds a button.
+    pub fn add_button(&mut self, button: CreateButton) -> &mut Self {
+        self.push(Value::Object(utils::hashmap_to_json_map(button.0)))
+    }
+
+    /// Creates a select menu.
+    pub fn create_select_menu<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenu) -> &mut CreateSelectMenu {
+        let mut menu = CreateSelectMenu::default();
+        f(&mut menu);
+
+        self.add_select_menu(menu)
+    }
+
+    /// Adds a select menu.
+    pub fn add_select_menu(&mut self, menu: CreateSelectMenu) -> &mut Self {
+        self.push(Value::Object(utils::hashmap_to_json_map(menu.0)))
+    }
+
+    /// Creates an input text.
+    ///
+    /// **Note**: Input texts are only available in modals.
+    pub fn create_input_text<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateInputText) -> &mut CreateInputText {
+        let mut input = CreateInputText::default();
+        f(&mut input);
+
+        self.add_input_text(input)
+    }
+
+    /// Adds an input text.
+    ///
+    /// **Note**: Input texts are only available in modals.
+    pub fn add_input_text(&mut self, input: CreateInputText) -> &mut Self {
+        self.push(Value::Object(utils::hashmap_to_json_map(input.0)))
+    }
+
+    fn push(&mut self, component: Value) -> &mut Self {
+        self.0.entry("components")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(component);
+
+        self
+    }
+}
+
+impl Default for CreateActionRow {
+    fn default() -> CreateActionRow {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::ActionRow.num())));
+
+        CreateActionRow(map)
+    }
+}
+
+/// A builder for a button.
+///
+/// A button either has a [`custom_id`], sent back in the interaction when
+/// clicked, or is a [`Link`] button with a [`url`].
+///
+/// [`Link`]: ../model/interactions/enum.ButtonStyle.html#variant.Link
+/// [`custom_id`]: #method.custom_id
+/// [`url`]: #method.url
+#[derive(Clone, Debug)]
+pub struct CreateButton(pub HashMap<&'static str, Value>);
+
+impl CreateButton {
+    /// Sets the style of the button.
+    ///
+    /// Defaults to [`Primary`].
+    ///
+    /// [`Primary`]: ../model/interactions/enum.ButtonStyle.html#variant.Primary
+    pub fn style(&mut self, style: ButtonStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+        self
+    }
+
+    /// Sets the text of the button, at most 80 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown on the button.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self._emoji(emoji.into())
+    }
+
+    fn _emoji(&mut self, emoji: ReactionType) -> &mut Self {
+        let emoji = serde_json::to_value(emoji).expect("ReactionType serializes to JSON");
+
+        self.0.insert("emoji", emoji);
+        self
+    }
+
+    /// Sets the developer defined Id of the button, at most 100 characters.
+    ///
+    /// **Note**: Link buttons can't have a custom Id.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the URL opened by a [`Link`] button.
+    ///
+    /// [`Link`]: ../model/interactions/enum.ButtonStyle.html#variant.Link
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("url", Value::String(url.to_string()));
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+}
+
+impl Default for CreateButton {
+    fn default() -> CreateButton {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::Button.num())));
+        map.insert("style", Value::Number(Number::from(ButtonStyle::Primary.num())));
+
+        CreateButton(map)
+    }
+}
+
+/// A builder for a select menu.
+///
+/// By default, users pick from the menu's [`options`]. Setting a different
+/// [`kind`] lets them pick users, roles or channels instead.
+///
+/// # Examples
+///
+/// Let users pick a text channel, preselecting the current one:
+///
+/// ```rust,no_run
+/// # use serenity::{http::Http, model::id::ChannelId};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let channel_id = ChannelId(7);
+/// use serenity::model::{channel::ChannelType, interactions::ComponentType};
+///
+/// channel_id.send_message(&http, |m| {
+///     m.content("Where should logs go?").components(|c| {
+///         c.create_action_row(|r| {
+///             r.create_select_menu(|s| {
+///                 s.custom_id("log_channel")
+///                     .kind(ComponentType::ChannelSelect)
+///                     .channel_types(&[ChannelType::Text])
+///                     .default_channels(vec![channel_id])
+///             })
+///         })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`kind`]: #method.kind
+/// [`options`]: #method.options
+#[derive(Clone, Debug)]
+pub struct CreateSelectMenu(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenu {
+    /// Sets the type of the menu, one of [`SelectMenu`], [`UserSelect`],
+    /// [`RoleSelect`], [`MentionableSelect`] or [`ChannelSelect`].
+    ///
+    /// Defaults to [`SelectMenu`].
+    ///
+    /// [`ChannelSelect`]: ../model/interactions/enum.ComponentType.html#variant.ChannelSelect
+    /// [`MentionableSelect`]: ../model/interactions/enum.ComponentType.html#variant.MentionableSelect
+    /// [`RoleSelect`]: ../model/interactions/enum.ComponentType.html#variant.RoleSelect
+    /// [`SelectMenu`]: ../model/interactions/enum.ComponentType.html#variant.SelectMenu
+    /// [`UserSelect`]: ../model/interactions/enum.ComponentType.html#variant.UserSelect
+    pub fn kind(&mut self, kind: ComponentType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the developer defined Id of the menu, at most 100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the text shown if nothing is selected, at most 100 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+
+    /// Sets the minimum number of options that must be picked, 0-25.
+    ///
+    /// Defaults to `1`.
+    pub fn min_values(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_values", Value::Number(Number::from(min)));
+        self
+    }
+
+    /// Sets the maximum number of options that can be picked, at most 25.
+    ///
+    /// Defaults to `1`.
+    pub fn max_values(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_values", Value::Number(Number::from(max)));
+        self
+    }
+
+    /// Sets whether the menu is disabled.
+    ///
+    /// Defaults to `false`.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+
+    /// Sets the options of the menu, at most 25.
+    ///
+    /// **Note**: This field is required.
+    pub fn options<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenuOptions) -> &mut CreateSelectMenuOptions {
+        let mut options = CreateSelectMenuOptions::default();
+        f(&mut options);
+
+        self.0.insert("options", Value::Array(options.0));
+        self
+    }
+
+    /// Restricts the channels that can be picked in a [`ChannelSelect`]
+    /// menu to the given types.
+    ///
+    /// [`ChannelSelect`]: ../model/interactions/enum.ComponentType.html#variant.ChannelSelect
+    pub fn channel_types(&mut self, channel_types: &[ChannelType]) -> &mut Self {
+        let channel_types = channel_types.iter()
+            .map(|kind| Value::Number(Number::from(kind.num())))
+            .collect();
+
+        self.0.insert("channel_types", Value::Array(channel_types));
+        self
+    }
+
+    /// Adds users that are picked by default in a [`UserSelect`] or
+    /// [`MentionableSelect`] menu.
+    ///
+    /// [`MentionableSelect`]: ../model/interactions/enum.ComponentType.html#variant.MentionableSelect
+    /// [`UserSelect`]: ../model/interactions/enum.ComponentType.html#variant.UserSelect
+    pub fn default_users<U: Into<UserId>, It: IntoIterator<Item=U>>(&mut self, users: It) -> &mut Self {
+        self.default_values("user", users.into_iter().map(|user| user.into().0))
+    }
+
+    /// Adds roles that are picked by default in a [`RoleSelect`] or
+    /// [`MentionableSelect`] menu.
+    ///
+    /// [`MentionableSelect`]: ../model/interactions/enum.ComponentType.html#variant.MentionableSelect
+    /// [`RoleSelect`]: ../model/interactions/enum.ComponentType.html#variant.RoleSelect
+    pub fn default_roles<R: Into<RoleId>, It: IntoIterator<Item=R>>(&mut self, roles: It) -> &mut Self {
+        self.default_values("role", roles.into_iter().map(|role| role.into().0))
+    }
+
+    /// Adds channels that are picked by default in a [`ChannelSelect`] menu.
+    ///
+    /// [`ChannelSelect`]: ../model/interactions/enum.ComponentType.html#variant.ChannelSelect
+    pub fn default_channels<C: Into<ChannelId>, It: IntoIterator<Item=C>>(&mut self, channels: It) -> &mut Self {
+        self.default_values("channel", channels.into_iter().map(|channel| channel.into().0))
+    }
+
+    fn default_values(&mut self, kind: &str, ids: impl Iterator<Item=u64>) -> &mut Self {
+        let values = self.0.entry("default_values")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array");
+
+        for id in ids {
+            values.push(json!({
+                "id": id.to_string(),
+                "type": kind,
+            }));
+        }
+
+        self
+    }
+}
+
+impl Default for CreateSelectMenu {
+    fn default() -> CreateSelectMenu {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::SelectMenu.num())));
+
+        CreateSelectMenu(map)
+    }
+}
+
+/// A builder for the options of a [`CreateSelectMenu`].
+///
+/// [`CreateSelectMenu`]: struct.CreateSelectMenu.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOptions(pub Vec<Value>);
+
+impl CreateSelectMenuOptions {
+    /// Creates an option.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenuOption) -> &mut CreateSelectMenuOption {
+        let mut option = CreateSelectMenuOption::default();
+        f(&mut option);
+
+        self.add_option(option)
+    }
+
+    /// Adds an option.
+    pub fn add_option(&mut self, option: CreateSelectMenuOption) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(option.0)));
+        self
+    }
+
+    /// Sets all options, replacing previously added options.
+    pub fn set_options(&mut self, options: Vec<CreateSelectMenuOption>) -> &mut Self {
+        self.0 = options.into_iter()
+            .map(|o| Value::Object(utils::hashmap_to_json_map(o.0)))
+            .collect();
+
+        self
+    }
+}
+
+/// A builder for an option of a [`CreateSelectMenu`].
+///
+/// [`CreateSelectMenu`]: struct.CreateSelectMenu.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOption(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenuOption {
+    /// Sets the text shown for the option, at most 100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the developer defined value of the option, at most 100
+    /// characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// Sets the additional description of the option, at most 100
+    /// characters.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown next to the option.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self._emoji(emoji.into())
+    }
+
+    fn _emoji(&mut self, emoji: ReactionType) -> &mut Self {
+        let emoji = serde_json::to_value(emoji).expect("ReactionType serializes to JSON");
+
+        self.0.insert("emoji", emoji);
+        self
+    }
+
+    /// Sets whether the option is selected by default.
+    pub fn default_selection(&mut self, default: bool) -> &mut Self {
+        self.0.insert("default", Value::Bool(default));
+        self
+    }
+}
+
+/// A builder for an input text of a modal.
+///
+/// The entered value is received in the [`ModalSubmitInteractionData`].
+///
+/// [`ModalSubmitInteractionData`]: ../model/interactions/struct.ModalSubmitInteractionData.html
+#[derive(Clone, Debug)]
+pub struct CreateInputText(pub HashMap<&'static str, Value>);
+
+impl CreateInputText {
+    /// Sets the developer defined Id of the input, at most 100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the style of the input.
+    ///
+    /// Defaults to [`Short`].
+    ///
+    /// [`Short`]: ../model/interactions/enum.InputTextStyle.html#variant.Short
+    pub fn style(&mut self, style: InputTextStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+        self
+    }
+
+    /// Sets the label shown above the input, at most 45 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// Sets the minimum length of the input, 0-4000.
+    pub fn min_length(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_length", Value::Number(Number::from(min)));
+        self
+    }
+
+    /// Sets the maximum length of the input, 1-4000.
+    pub fn max_length(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_length", Value::Number(Number::from(max)));
+        self
+    }
+
+    /// Sets whether the input must be filled in.
+    ///
+    /// Defaults to `true`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Sets the pre-filled value of the input, at most 4000 characters.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// Sets the text shown if the input is empty, at most 100 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+}
+
+impl Default for CreateInputText {
+    fn default() -> CreateInputText {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::InputText.num())));
+        map.insert("style", Value::Number(Number::from(InputTextStyle::Short.num())));
+
+        CreateInputText(map)
+    }
+}
@@ -17,6 +17,7 @@
 
 use crate::internal::prelude::*;
 use crate::model::channel::Embed;
+use crate::model::timestamp::Timestamp as ModelTimestamp;
 use crate::utils;
 
 use chrono::{DateTime, TimeZone};
@@ -516,6 +517,22 @@ impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
     }
 }
 
+impl From<ModelTimestamp> for Timestamp {
+    fn from(timestamp: ModelTimestamp) -> Self {
+        Self {
+            ts: timestamp.to_string(),
+        }
+    }
+}
+
+impl<'a> From<&'a ModelTimestamp> for Timestamp {
+    fn from(timestamp: &'a ModelTimestamp) -> Self {
+        Self {
+            ts: timestamp.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo},
@@ -0,0 +1,175 @@
+use crate::internal::prelude::*;
+use crate::model::{channel::MessageFlags, interactions::InteractionResponseType};
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to create the initial response to an [`Interaction`], for use
+/// via [`Interaction::create_interaction_response`].
+///
+/// Defaults to responding with a message, see [`kind`].
+///
+/// # Examples
+///
+/// Acknowledge an interaction, to fill in the response later via
+/// [`Interaction::edit_original_interaction_response`]:
+///
+/// ```rust,no_run
+/// # use serenity::{http::Http, model::interactions::Interaction};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run(interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// use serenity::model::interactions::InteractionResponseType;
+///
+/// interaction.create_interaction_response(&http, |r| {
+///     r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+/// }).await?;
+///
+/// interaction.edit_original_interaction_response(&http, |r| {
+///     r.content("That took a while.")
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Interaction`]: ../model/interactions/struct.Interaction.html
+/// [`Interaction::create_interaction_response`]: ../model/interactions/struct.Interaction.html#method.create_interaction_response
+/// [`Interaction::edit_original_interaction_response`]: ../model/interactions/struct.Interaction.html#method.edit_original_interaction_response
+/// [`kind`]: #method.kind
+#[derive(Clone, Debug)]
+pub struct CreateInteractionResponse(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponse {
+    /// Sets the type of the response.
+    pub fn kind(&mut self, kind: InteractionResponseType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the data of the response, i.e. the message to respond with.
+    pub fn interaction_response_data<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData {
+        let mut data = CreateInteractionResponseData::default();
+        f(&mut data);
+        let map = utils::hashmap_to_json_map(data.0);
+
+        self.0.insert("data", Value::Object(map));
+        self
+    }
+}
+
+impl Default for CreateInteractionResponse {
+    fn default() -> CreateInteractionResponse {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(InteractionResponseType::ChannelMessageWithSource.num())));
+
+        CreateInteractionResponse(map)
+    }
+}
+
+/// A builder for the message of a [`CreateInteractionResponse`].
+///
+/// [`CreateInteractionResponse`]: struct.CreateInteractionResponse.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponseData(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponseData {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Sets whether the message is text-to-speech.
+    pub fn tts(&mut self, tts: bool) -> &mut Self {
+        self.0.insert("tts", Value::Bool(tts));
+        self
+    }
+
+    /// Creates an embed for the message, at most 10 embeds can be added.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message, at most 10 embeds can be added.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        self.0.entry("embeds")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(embed);
+
+        self
+    }
+
+    /// Sets all embeds of the message, replacing previously added embeds.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        let embeds = embeds.into_iter()
+            .map(|e| Value::Object(utils::hashmap_to_json_map(e.0)))
+            .collect();
+
+        self.0.insert("embeds", Value::Array(embeds));
+        self
+    }
+
+    /// Sets the mentions allowed to notify users and roles.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+
+    /// Sets the components of the message, i.e. buttons and select menus.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets whether the message is only visible to the user who invoked the
+    /// interaction.
+    pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
+        // `1 << 6` is defined by the API to be the EPHEMERAL flag.
+        if ephemeral {
+            self.0.insert("flags", Value::Number(Number::from(1 << 6)));
+        } else {
+            self.0.remove("flags");
+        }
+
+        self
+    }
+
+    /// Sets the flags of the message.
+    ///
+    /// **Note**: Only [`EPHEMERAL`] and [`SUPPRESS_EMBEDS`] can be set.
+    ///
+    /// [`EPHEMERAL`]: ../model/channel/struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    /// [`SUPPRESS_EMBEDS`]: ../model/channel/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(Number::from(flags.bits)));
+
+        self
+    }
+}
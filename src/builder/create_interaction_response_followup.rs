@@ -0,0 +1,129 @@
+use crate::internal::prelude::*;
+use crate::model::channel::MessageFlags;
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to create or edit a followup message of an [`Interaction`], for
+/// use via [`Interaction::create_followup_message`] and
+/// [`Interaction::edit_followup_message`].
+///
+/// [`Interaction`]: ../model/interactions/struct.Interaction.html
+/// [`Interaction::create_followup_message`]: ../model/interactions/struct.Interaction.html#method.create_followup_message
+/// [`Interaction::edit_followup_message`]: ../model/interactions/struct.Interaction.html#method.edit_followup_message
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponseFollowup(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponseFollowup {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Overrides the username shown for the message.
+    pub fn username<D: ToString>(&mut self, username: D) -> &mut Self {
+        self.0.insert("username", Value::String(username.to_string()));
+        self
+    }
+
+    /// Overrides the avatar shown for the message.
+    pub fn avatar<D: ToString>(&mut self, avatar_url: D) -> &mut Self {
+        self.0.insert("avatar_url", Value::String(avatar_url.to_string()));
+        self
+    }
+
+    /// Sets whether the message is text-to-speech.
+    pub fn tts(&mut self, tts: bool) -> &mut Self {
+        self.0.insert("tts", Value::Bool(tts));
+        self
+    }
+
+    /// Creates an embed for the message, at most 10 embeds can be added.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message, at most 10 embeds can be added.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        self.0.entry("embeds")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(embed);
+
+        self
+    }
+
+    /// Sets all embeds of the message, replacing previously added embeds.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        let embeds = embeds.into_iter()
+            .map(|e| Value::Object(utils::hashmap_to_json_map(e.0)))
+            .collect();
+
+        self.0.insert("embeds", Value::Array(embeds));
+        self
+    }
+
+    /// Sets the mentions allowed to notify users and roles.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+
+    /// Sets the components of the message, i.e. buttons and select menus.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets whether the message is only visible to the user who invoked the
+    /// interaction.
+    ///
+    /// **Note**: This has no effect when editing a message.
+    pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
+        // `1 << 6` is defined by the API to be the EPHEMERAL flag.
+        if ephemeral {
+            self.0.insert("flags", Value::Number(Number::from(1 << 6)));
+        } else {
+            self.0.remove("flags");
+        }
+
+        self
+    }
+
+    /// Sets the flags of the message.
+    ///
+    /// **Note**: Only [`EPHEMERAL`] and [`SUPPRESS_EMBEDS`] can be set.
+    ///
+    /// [`EPHEMERAL`]: ../model/channel/struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    /// [`SUPPRESS_EMBEDS`]: ../model/channel/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(Number::from(flags.bits)));
+
+        self
+    }
+}
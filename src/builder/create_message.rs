@@ -1,8 +1,10 @@
 use crate::internal::prelude::*;
 use crate::http::AttachmentType;
-use crate::model::channel::ReactionType;
+use crate::model::{channel::{MessageFlags, MessageReference, ReactionType}, id::StickerId};
 use super::CreateEmbed;
 use super::CreateAllowedMentions;
+use super::CreateComponents;
+use super::CreatePoll;
 use crate::utils;
 
 use std::collections::HashMap;
@@ -87,6 +89,21 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Sets the components of the message, i.e. buttons and select menus.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
     /// Set whether the message is text-to-speech.
     ///
     /// Think carefully before setting this to `true`.
@@ -97,6 +114,16 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Sets the flags of the message.
+    ///
+    /// **Note**: Only [`SUPPRESS_EMBEDS`] can be set when sending a message.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: ../model/channel/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(Number::from(flags.bits)));
+        self
+    }
+
     /// Adds a list of reactions to create after the message's sent.
     #[inline]
     pub fn reactions<R: Into<ReactionType>, It: IntoIterator<Item=R>>(&mut self, reactions: It) -> &mut Self {
@@ -114,6 +141,23 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Appends a file to the message with a description, shown as its alt
+    /// text.
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+    where T: Into<AttachmentType<'a>>, D: ToString {
+        let mut attachment = Map::new();
+        attachment.insert("id".to_string(), Value::Number(Number::from(self.2.len() as u64)));
+        attachment.insert("description".to_string(), Value::String(description.to_string()));
+
+        self.0.entry("attachments")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(Value::Object(attachment));
+
+        self.add_file(file)
+    }
+
     /// Appends a list of files to the message.
     pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
         self.2.extend(files.into_iter().map(|f| f.into()));
@@ -125,6 +169,7 @@ impl<'a> CreateMessage<'a> {
     /// Calling this multiple times will overwrite the file list.
     /// To append files, call `add_file` or `add_files` instead.
     pub fn files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
+        self.0.remove("attachments");
         self.2 = files.into_iter().map(|f| f.into()).collect();
         self
     }
@@ -140,6 +185,58 @@ impl<'a> CreateMessage<'a> {
         self.0.insert("allowed_mentions", allowed_mentions);
         self
     }
+
+    /// Sets the message this message replies to.
+    ///
+    /// Use [`allowed_mentions`] to control whether the author of the
+    /// referenced message is notified.
+    ///
+    /// [`allowed_mentions`]: #method.allowed_mentions
+    pub fn reference_message<R: Into<MessageReference>>(&mut self, reference: R) -> &mut Self {
+        let reference = serde_json::to_value(reference.into()).expect("Message reference is serializable");
+
+        self.0.insert("message_reference", reference);
+        self
+    }
+
+    /// Attaches a poll to the message.
+    ///
+    /// Refer to [`CreatePoll`] for an example.
+    ///
+    /// **Note**: A message with a poll can not be edited.
+    ///
+    /// [`CreatePoll`]: struct.CreatePoll.html
+    pub fn poll<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreatePoll) -> &mut CreatePoll {
+        let mut poll = CreatePoll::default();
+        f(&mut poll);
+
+        self.0.insert("poll", Value::Object(utils::hashmap_to_json_map(poll.0)));
+        self
+    }
+
+    /// Adds a sticker to the message, at most 3 stickers can be sent.
+    pub fn add_sticker_id<S: Into<StickerId>>(&mut self, sticker_id: S) -> &mut Self {
+        self.0.entry("sticker_ids")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(Value::Number(Number::from(sticker_id.into().0)));
+        self
+    }
+
+    /// Sets the stickers of the message, at most 3 stickers can be sent.
+    ///
+    /// Calling this multiple times will overwrite the sticker list.
+    /// To append stickers, call `add_sticker_id` instead.
+    pub fn sticker_ids<S: Into<StickerId>, It: IntoIterator<Item=S>>(&mut self, sticker_ids: It) -> &mut Self {
+        let sticker_ids = sticker_ids.into_iter()
+            .map(|sticker_id| Value::Number(Number::from(sticker_id.into().0)))
+            .collect();
+
+        self.0.insert("sticker_ids", Value::Array(sticker_ids));
+        self
+    }
 }
 
 impl<'a> Default for CreateMessage<'a> {
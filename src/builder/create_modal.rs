@@ -0,0 +1,49 @@
+use crate::internal::prelude::*;
+use super::CreateComponents;
+
+use std::collections::HashMap;
+
+/// A builder for a modal, for use via [`Interaction::create_modal_response`].
+///
+/// A modal contains up to 5 action rows, each containing a single
+/// [`CreateInputText`].
+///
+/// [`CreateInputText`]: struct.CreateInputText.html
+/// [`Interaction::create_modal_response`]: ../model/interactions/struct.Interaction.html#method.create_modal_response
+#[derive(Clone, Debug, Default)]
+pub struct CreateModal(pub HashMap<&'static str, Value>);
+
+impl CreateModal {
+    /// Sets the developer defined Id of the modal, at most 100 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// Sets the title of the modal, at most 45 characters.
+    ///
+    /// **Note**: This field is required.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::String(title.to_string()));
+        self
+    }
+
+    /// Sets the action rows of the modal.
+    ///
+    /// **Note**: This field is required.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the action rows of the modal, replacing previously set rows.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+}
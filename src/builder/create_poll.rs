@@ -0,0 +1,138 @@
+use crate::internal::prelude::*;
+use crate::model::channel::{PollLayoutType, ReactionType};
+use crate::utils;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder for a poll attached to a message, used by
+/// [`CreateMessage::poll`].
+///
+/// The [`question`] and at least one answer are required.
+///
+/// # Examples
+///
+/// Ask what to eat, letting users vote for several answers:
+///
+/// ```rust,no_run
+/// # use serenity::{http::Http, model::id::ChannelId};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// ChannelId(7).send_message(&http, |m| {
+///     m.poll(|p| {
+///         p.question("What's for dinner?")
+///             .create_answer(|a| a.text("Pizza").emoji('🍕'))
+///             .create_answer(|a| a.text("Sushi").emoji('🍣'))
+///             .duration(24)
+///             .allow_multiselect(true)
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`CreateMessage::poll`]: struct.CreateMessage.html#method.poll
+/// [`question`]: #method.question
+#[derive(Clone, Debug)]
+pub struct CreatePoll(pub HashMap<&'static str, Value>);
+
+impl CreatePoll {
+    /// Sets the question of the poll, at most 300 characters.
+    pub fn question<D: ToString>(&mut self, question: D) -> &mut Self {
+        self.0.insert("question", json!({ "text": question.to_string() }));
+        self
+    }
+
+    /// Creates an answer of the poll.
+    pub fn create_answer<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreatePollAnswer) -> &mut CreatePollAnswer {
+        let mut answer = CreatePollAnswer::default();
+        f(&mut answer);
+
+        self.add_answer(answer)
+    }
+
+    /// Adds an answer of the poll, at most 10 answers can be added.
+    pub fn add_answer(&mut self, answer: CreatePollAnswer) -> &mut Self {
+        self.0.entry("answers")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(answer.into_value());
+
+        self
+    }
+
+    /// Sets all answers of the poll, replacing previously added answers.
+    pub fn set_answers(&mut self, answers: Vec<CreatePollAnswer>) -> &mut Self {
+        let answers = answers.into_iter().map(CreatePollAnswer::into_value).collect();
+
+        self.0.insert("answers", Value::Array(answers));
+        self
+    }
+
+    /// Sets the number of hours the poll runs for, at most 768 (32 days).
+    ///
+    /// Defaults to `24`.
+    pub fn duration(&mut self, hours: u64) -> &mut Self {
+        self.0.insert("duration", Value::Number(Number::from(hours)));
+        self
+    }
+
+    /// Sets whether users can vote for more than one answer.
+    ///
+    /// Defaults to `false`.
+    pub fn allow_multiselect(&mut self, allow_multiselect: bool) -> &mut Self {
+        self.0.insert("allow_multiselect", Value::Bool(allow_multiselect));
+        self
+    }
+
+    /// Sets the layout of the poll.
+    ///
+    /// Defaults to [`PollLayoutType::Default`].
+    ///
+    /// [`PollLayoutType::Default`]: ../model/channel/enum.PollLayoutType.html#variant.Default
+    pub fn layout_type(&mut self, layout_type: PollLayoutType) -> &mut Self {
+        self.0.insert("layout_type", Value::Number(Number::from(layout_type.num())));
+        self
+    }
+}
+
+impl Default for CreatePoll {
+    fn default() -> CreatePoll {
+        let mut map = HashMap::new();
+        map.insert("duration", Value::Number(Number::from(24)));
+
+        CreatePoll(map)
+    }
+}
+
+/// A builder for an answer of a [`CreatePoll`].
+///
+/// [`CreatePoll`]: struct.CreatePoll.html
+#[derive(Clone, Debug, Default)]
+pub struct CreatePollAnswer(pub HashMap<&'static str, Value>);
+
+impl CreatePollAnswer {
+    /// Sets the text of the answer, at most 55 characters.
+    pub fn text<D: ToString>(&mut self, text: D) -> &mut Self {
+        self.0.insert("text", Value::String(text.to_string()));
+        self
+    }
+
+    /// Sets the emoji shown next to the answer.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        let emoji = match emoji.into() {
+            ReactionType::Custom { id, .. } => json!({ "id": id.0.to_string() }),
+            ReactionType::Unicode(name) => json!({ "name": name }),
+        };
+
+        self.0.insert("emoji", emoji);
+        self
+    }
+
+    fn into_value(self) -> Value {
+        json!({ "poll_media": utils::hashmap_to_json_map(self.0) })
+    }
+}
@@ -0,0 +1,260 @@
+use crate::builder::Timestamp;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::utils;
+
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder to create a [`ScheduledEvent`] in a guild, see
+/// [`GuildId::create_scheduled_event`].
+///
+/// The [`name`], [`start_time`] and [`kind`] are required. Events in a stage
+/// or voice channel require a [`channel_id`], [`External`] events require a
+/// [`location`] and an [`end_time`].
+///
+/// [`External`]: ../model/guild/enum.ScheduledEventType.html#variant.External
+/// [`GuildId::create_scheduled_event`]: ../model/id/struct.GuildId.html#method.create_scheduled_event
+/// [`ScheduledEvent`]: ../model/guild/struct.ScheduledEvent.html
+/// [`channel_id`]: #method.channel_id
+/// [`end_time`]: #method.end_time
+/// [`kind`]: #method.kind
+/// [`location`]: #method.location
+/// [`name`]: #method.name
+/// [`start_time`]: #method.start_time
+#[derive(Clone, Debug)]
+pub struct CreateScheduledEvent(pub HashMap<&'static str, Value>);
+
+impl CreateScheduledEvent {
+    /// Sets the stage or voice channel the event takes place in.
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::String(channel_id.into().0.to_string()));
+
+        self
+    }
+
+    /// Sets the name of the event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the description of the event.
+    ///
+    /// **Note**: Must be between 1 and 1000 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the time the event is scheduled to start.
+    pub fn start_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_start_time", Value::String(timestamp.into().ts));
+
+        self
+    }
+
+    /// Sets the time the event is scheduled to end.
+    pub fn end_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_end_time", Value::String(timestamp.into().ts));
+
+        self
+    }
+
+    /// Sets the type of the event's location.
+    pub fn kind(&mut self, kind: ScheduledEventType) -> &mut Self {
+        self.0.insert("entity_type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Sets the location of an [`External`] event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    ///
+    /// [`External`]: ../model/guild/enum.ScheduledEventType.html#variant.External
+    pub fn location<D: ToString>(&mut self, location: D) -> &mut Self {
+        self.0.insert("entity_metadata", json!({
+            "location": location.to_string(),
+        }));
+
+        self
+    }
+
+    /// Sets the cover image of the event from the contents of an image file,
+    /// encoding it into a base64 data URI.
+    pub fn image(&mut self, data: &[u8]) -> &mut Self {
+        self.0.insert("image", Value::String(utils::encode_image(data)));
+
+        self
+    }
+
+    /// Makes the event a recurring event, recurring by the given rule.
+    ///
+    /// Refer to [`CreateRecurrenceRule`] for an example.
+    ///
+    /// [`CreateRecurrenceRule`]: struct.CreateRecurrenceRule.html
+    pub fn recurrence_rule<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateRecurrenceRule) -> &mut CreateRecurrenceRule {
+        let mut rule = CreateRecurrenceRule::default();
+        f(&mut rule);
+
+        self.0.insert("recurrence_rule", Value::Object(utils::hashmap_to_json_map(rule.0)));
+
+        self
+    }
+}
+
+impl Default for CreateScheduledEvent {
+    /// Creates a builder with the privacy level set to guild only, the only
+    /// privacy level Discord accepts.
+    fn default() -> CreateScheduledEvent {
+        let mut map = HashMap::new();
+        map.insert("privacy_level", Value::Number(Number::from(2)));
+
+        CreateScheduledEvent(map)
+    }
+}
+
+/// A builder for the [`RecurrenceRule`] of a recurring [`ScheduledEvent`],
+/// used by [`CreateScheduledEvent::recurrence_rule`] and
+/// [`EditScheduledEvent::recurrence_rule`].
+///
+/// The [`start`], [`frequency`] and [`interval`] are required.
+///
+/// # Examples
+///
+/// Schedule a meetup on the first Friday of every month:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::GuildId, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let guild_id = GuildId(1);
+/// use serenity::model::guild::{RecurrenceRuleFrequency, RecurrenceRuleWeekday, ScheduledEventType};
+///
+/// guild_id.create_scheduled_event(&http, |e| {
+///     e.name("Meetup")
+///         .kind(ScheduledEventType::External)
+///         .location("Town square")
+///         .start_time("2024-09-06T18:00:00Z")
+///         .end_time("2024-09-06T20:00:00Z")
+///         .recurrence_rule(|r| {
+///             r.start("2024-09-06T18:00:00Z")
+///                 .frequency(RecurrenceRuleFrequency::Monthly)
+///                 .interval(1)
+///                 .add_n_weekday(1, RecurrenceRuleWeekday::Friday)
+///         })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`CreateScheduledEvent::recurrence_rule`]: struct.CreateScheduledEvent.html#method.recurrence_rule
+/// [`EditScheduledEvent::recurrence_rule`]: struct.EditScheduledEvent.html#method.recurrence_rule
+/// [`RecurrenceRule`]: ../model/guild/struct.RecurrenceRule.html
+/// [`ScheduledEvent`]: ../model/guild/struct.ScheduledEvent.html
+/// [`frequency`]: #method.frequency
+/// [`interval`]: #method.interval
+/// [`start`]: #method.start
+#[derive(Clone, Debug, Default)]
+pub struct CreateRecurrenceRule(pub HashMap<&'static str, Value>);
+
+impl CreateRecurrenceRule {
+    /// Sets the start of the interval the event recurs within.
+    ///
+    /// **Note**: This should match the start time of the event.
+    pub fn start<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("start", Value::String(timestamp.into().ts));
+
+        self
+    }
+
+    /// Sets how often the event recurs.
+    pub fn frequency(&mut self, frequency: RecurrenceRuleFrequency) -> &mut Self {
+        self.0.insert("frequency", Value::Number(Number::from(frequency.num())));
+
+        self
+    }
+
+    /// Sets the spacing between recurrences, e.g. `2` with a [`Weekly`]
+    /// frequency recurs every other week.
+    ///
+    /// [`Weekly`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Weekly
+    pub fn interval(&mut self, interval: u64) -> &mut Self {
+        self.0.insert("interval", Value::Number(Number::from(interval)));
+
+        self
+    }
+
+    /// Sets the days of the week the event recurs on.
+    ///
+    /// **Note**: This is only valid with a [`Daily`] or [`Weekly`]
+    /// frequency.
+    ///
+    /// [`Daily`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Daily
+    /// [`Weekly`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Weekly
+    pub fn by_weekday(&mut self, days: &[RecurrenceRuleWeekday]) -> &mut Self {
+        let days = days.iter().map(|d| Value::Number(Number::from(d.num()))).collect();
+
+        self.0.insert("by_weekday", Value::Array(days));
+
+        self
+    }
+
+    /// Adds a day of a specific week of the month the event recurs on, e.g.
+    /// `2` and [`Tuesday`] for the second Tuesday.
+    ///
+    /// **Note**: This is only valid with a [`Monthly`] frequency.
+    ///
+    /// [`Monthly`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Monthly
+    /// [`Tuesday`]: ../model/guild/enum.RecurrenceRuleWeekday.html#variant.Tuesday
+    pub fn add_n_weekday(&mut self, n: u8, day: RecurrenceRuleWeekday) -> &mut Self {
+        self.0.entry("by_n_weekday")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(json!({
+                "n": n,
+                "day": day.num(),
+            }));
+
+        self
+    }
+
+    /// Sets the months the event recurs in.
+    ///
+    /// **Note**: This is only valid with a [`Yearly`] frequency, together
+    /// with [`by_month_day`].
+    ///
+    /// [`Yearly`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Yearly
+    /// [`by_month_day`]: #method.by_month_day
+    pub fn by_month(&mut self, months: &[RecurrenceRuleMonth]) -> &mut Self {
+        let months = months.iter().map(|m| Value::Number(Number::from(m.num()))).collect();
+
+        self.0.insert("by_month", Value::Array(months));
+
+        self
+    }
+
+    /// Sets the days of the month the event recurs on.
+    ///
+    /// **Note**: This is only valid with a [`Yearly`] frequency, together
+    /// with [`by_month`].
+    ///
+    /// [`Yearly`]: ../model/guild/enum.RecurrenceRuleFrequency.html#variant.Yearly
+    /// [`by_month`]: #method.by_month
+    pub fn by_month_day(&mut self, days: &[u8]) -> &mut Self {
+        let days = days.iter().map(|&d| Value::Number(Number::from(d))).collect();
+
+        self.0.insert("by_month_day", Value::Array(days));
+
+        self
+    }
+}
@@ -0,0 +1,47 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder to create a [`StageInstance`] in a stage channel, see
+/// [`ChannelId::create_stage_instance`].
+///
+/// Except [`topic`], all fields are optional.
+///
+/// [`ChannelId::create_stage_instance`]: ../model/id/struct.ChannelId.html#method.create_stage_instance
+/// [`StageInstance`]: ../model/channel/struct.StageInstance.html
+/// [`topic`]: #method.topic
+#[derive(Clone, Debug, Default)]
+pub struct CreateStageInstance(pub HashMap<&'static str, Value>);
+
+impl CreateStageInstance {
+    /// Sets the stage channel to create the stage instance in.
+    ///
+    /// **Note**: This is set by [`ChannelId::create_stage_instance`].
+    ///
+    /// [`ChannelId::create_stage_instance`]: ../model/id/struct.ChannelId.html#method.create_stage_instance
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::String(id.into().0.to_string()));
+
+        self
+    }
+
+    /// Sets the topic of the stage instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
+    pub fn topic<D: ToString>(&mut self, topic: D) -> &mut Self {
+        self.0.insert("topic", Value::String(topic.to_string()));
+
+        self
+    }
+
+    /// Sets the privacy level of the stage instance, defaulting to
+    /// [`GuildOnly`].
+    ///
+    /// [`GuildOnly`]: ../model/channel/enum.StagePrivacyLevel.html#variant.GuildOnly
+    pub fn privacy_level(&mut self, privacy_level: StagePrivacyLevel) -> &mut Self {
+        self.0.insert("privacy_level", Value::Number(Number::from(privacy_level.num())));
+
+        self
+    }
+}
@@ -0,0 +1,72 @@
+use crate::internal::prelude::*;
+use crate::http::AttachmentType;
+
+use std::collections::HashMap;
+
+/// A builder to upload a [`Sticker`] to a guild, used by
+/// [`GuildId::create_sticker`].
+///
+/// All fields are required.
+///
+/// # Examples
+///
+/// Upload a sticker from a file:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::GuildId, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let guild_id = GuildId(2);
+/// let sticker = guild_id.create_sticker(&http, |s| {
+///     s.name("cheese")
+///         .description("A piece of cheese")
+///         .tags("cheese")
+///         .file("./cheese.png")
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`GuildId::create_sticker`]: ../model/id/struct.GuildId.html#method.create_sticker
+/// [`Sticker`]: ../model/sticker/struct.Sticker.html
+#[derive(Clone, Debug, Default)]
+pub struct CreateSticker<'a>(pub HashMap<&'static str, Value>, pub Option<AttachmentType<'a>>);
+
+impl<'a> CreateSticker<'a> {
+    /// Sets the name of the sticker.
+    ///
+    /// **Note**: Must be between 2 and 30 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the description of the sticker.
+    ///
+    /// **Note**: Must be empty or between 2 and 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the name of the unicode emoji representing the sticker, used
+    /// for autocomplete suggestions.
+    pub fn tags<D: ToString>(&mut self, tags: D) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+
+        self
+    }
+
+    /// Sets the image of the sticker.
+    ///
+    /// **Note**: Must be a PNG, APNG or Lottie JSON file of at most 500 KB.
+    pub fn file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1 = Some(file.into());
+
+        self
+    }
+}
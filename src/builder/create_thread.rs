@@ -0,0 +1,69 @@
+use crate::internal::prelude::*;
+use crate::model::channel::ChannelType;
+
+use std::collections::HashMap;
+
+/// A builder for creating a new thread in a [`GuildChannel`], either attached
+/// to a message or not.
+///
+/// Except [`name`], all fields are optional.
+///
+/// Used by [`ChannelId::create_thread`] and
+/// [`ChannelId::create_thread_from_message`].
+///
+/// [`ChannelId::create_thread`]: ../model/id/struct.ChannelId.html#method.create_thread
+/// [`ChannelId::create_thread_from_message`]: ../model/id/struct.ChannelId.html#method.create_thread_from_message
+/// [`GuildChannel`]: ../model/channel/struct.GuildChannel.html
+/// [`name`]: #method.name
+#[derive(Clone, Debug, Default)]
+pub struct CreateThread(pub HashMap<&'static str, Value>);
+
+impl CreateThread {
+    /// Sets the name of the thread.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets after how many minutes without activity the thread is archived.
+    ///
+    /// **Note**: Must be one of 60, 1440, 4320 or 10080.
+    pub fn auto_archive_duration(&mut self, duration: u64) -> &mut Self {
+        self.0.insert("auto_archive_duration", Value::Number(Number::from(duration)));
+
+        self
+    }
+
+    /// Sets the type of a thread not attached to a message, defaulting to
+    /// [`PrivateThread`].
+    ///
+    /// **Note**: Ignored for threads attached to a message, their type
+    /// follows the channel's type.
+    ///
+    /// [`PrivateThread`]: ../model/channel/enum.ChannelType.html#variant.PrivateThread
+    pub fn kind(&mut self, kind: ChannelType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Sets whether non-moderators can add other non-moderators to a private
+    /// thread.
+    pub fn invitable(&mut self, invitable: bool) -> &mut Self {
+        self.0.insert("invitable", Value::Bool(invitable));
+
+        self
+    }
+
+    /// How many seconds must a user wait before sending another message.
+    ///
+    /// **Note**: Must be between 0 and 21600 seconds (360 minutes or 6 hours).
+    pub fn rate_limit_per_user(&mut self, limit: u64) -> &mut Self {
+        self.0.insert("rate_limit_per_user", Value::Number(Number::from(limit)));
+
+        self
+    }
+}
@@ -0,0 +1,184 @@
+use crate::internal::prelude::*;
+use crate::model::{
+    guild::{AutoModAction, AutoModEventType, AutoModKeywordPresetType, AutoModTriggerType},
+    id::{ChannelId, RoleId},
+};
+use std::collections::HashMap;
+
+/// A builder to create or edit an [`AutoModRule`] for use via a number of
+/// model methods.
+///
+/// These are:
+///
+/// - [`GuildId::create_automod_rule`]
+/// - [`GuildId::edit_automod_rule`]
+/// - [`AutoModRule::edit`]
+///
+/// On creation, the [`name`], [`event_type`], [`trigger_type`] and
+/// [`actions`] are required.
+///
+/// # Examples
+///
+/// Block messages containing a word and alert the moderators:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::{ChannelId, GuildId}, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let (channel_id, guild_id) = (ChannelId(1), GuildId(2));
+/// use serenity::model::guild::{AutoModAction, AutoModEventType, AutoModTriggerType};
+///
+/// let rule = guild_id.create_automod_rule(&http, |r| {
+///     r.name("No cheese")
+///         .event_type(AutoModEventType::MessageSend)
+///         .trigger_type(AutoModTriggerType::Keyword)
+///         .keyword_filter(vec!["cheese".to_string()])
+///         .actions(vec![
+///             AutoModAction::block_message(None),
+///             AutoModAction::send_alert_message(channel_id),
+///         ])
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`AutoModRule`]: ../model/guild/struct.AutoModRule.html
+/// [`AutoModRule::edit`]: ../model/guild/struct.AutoModRule.html#method.edit
+/// [`GuildId::create_automod_rule`]: ../model/id/struct.GuildId.html#method.create_automod_rule
+/// [`GuildId::edit_automod_rule`]: ../model/id/struct.GuildId.html#method.edit_automod_rule
+/// [`actions`]: #method.actions
+/// [`event_type`]: #method.event_type
+/// [`name`]: #method.name
+/// [`trigger_type`]: #method.trigger_type
+#[derive(Clone, Debug, Default)]
+pub struct EditAutoModRule(pub HashMap<&'static str, Value>);
+
+impl EditAutoModRule {
+    /// Sets the name of the rule.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the context in which the rule is checked.
+    pub fn event_type(&mut self, event_type: AutoModEventType) -> &mut Self {
+        self.0.insert("event_type", Value::Number(Number::from(event_type.num())));
+
+        self
+    }
+
+    /// Sets the kind of content triggering the rule.
+    ///
+    /// **Note**: This can only be set on creation.
+    pub fn trigger_type(&mut self, trigger_type: AutoModTriggerType) -> &mut Self {
+        self.0.insert("trigger_type", Value::Number(Number::from(trigger_type.num())));
+
+        self
+    }
+
+    /// Sets the words or phrases blocked by a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    pub fn keyword_filter(&mut self, keywords: Vec<String>) -> &mut Self {
+        let keywords = keywords.into_iter().map(Value::String).collect();
+
+        self.trigger_metadata("keyword_filter", Value::Array(keywords))
+    }
+
+    /// Sets the regular expressions blocked by a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    pub fn regex_patterns(&mut self, patterns: Vec<String>) -> &mut Self {
+        let patterns = patterns.into_iter().map(Value::String).collect();
+
+        self.trigger_metadata("regex_patterns", Value::Array(patterns))
+    }
+
+    /// Sets the Discord-defined word lists blocked by a [`KeywordPreset`]
+    /// rule.
+    ///
+    /// [`KeywordPreset`]: ../model/guild/enum.AutoModTriggerType.html#variant.KeywordPreset
+    pub fn presets(&mut self, presets: &[AutoModKeywordPresetType]) -> &mut Self {
+        let presets = presets.iter()
+            .map(|preset| Value::Number(Number::from(preset.num())))
+            .collect();
+
+        self.trigger_metadata("presets", Value::Array(presets))
+    }
+
+    /// Sets the words or phrases exempt from a [`Keyword`] or
+    /// [`KeywordPreset`] rule.
+    ///
+    /// [`Keyword`]: ../model/guild/enum.AutoModTriggerType.html#variant.Keyword
+    /// [`KeywordPreset`]: ../model/guild/enum.AutoModTriggerType.html#variant.KeywordPreset
+    pub fn allow_list(&mut self, allowed: Vec<String>) -> &mut Self {
+        let allowed = allowed.into_iter().map(Value::String).collect();
+
+        self.trigger_metadata("allow_list", Value::Array(allowed))
+    }
+
+    /// Sets the number of unique role and user mentions allowed per message
+    /// by a [`MentionSpam`] rule.
+    ///
+    /// **Note**: Must be at most 50.
+    ///
+    /// [`MentionSpam`]: ../model/guild/enum.AutoModTriggerType.html#variant.MentionSpam
+    pub fn mention_total_limit(&mut self, limit: u64) -> &mut Self {
+        self.trigger_metadata("mention_total_limit", Value::Number(Number::from(limit)))
+    }
+
+    fn trigger_metadata(&mut self, key: &str, value: Value) -> &mut Self {
+        self.0.entry("trigger_metadata")
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .expect("Must be an object")
+            .insert(key.to_string(), value);
+
+        self
+    }
+
+    /// Sets the actions executed when the rule is triggered.
+    pub fn actions(&mut self, actions: Vec<AutoModAction>) -> &mut Self {
+        let actions = actions.iter()
+            .map(|action| serde_json::to_value(action).expect("Action is serializable"))
+            .collect();
+
+        self.0.insert("actions", Value::Array(actions));
+
+        self
+    }
+
+    /// Sets whether the rule is enabled.
+    ///
+    /// Defaults to `false`.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Sets the roles whose members are not affected by the rule, at most 20.
+    pub fn exempt_roles<R: Into<RoleId>, It: IntoIterator<Item=R>>(&mut self, roles: It) -> &mut Self {
+        let roles = roles.into_iter()
+            .map(|role| Value::Number(Number::from(role.into().0)))
+            .collect();
+
+        self.0.insert("exempt_roles", Value::Array(roles));
+
+        self
+    }
+
+    /// Sets the channels in which the rule is not checked, at most 50.
+    pub fn exempt_channels<C: Into<ChannelId>, It: IntoIterator<Item=C>>(&mut self, channels: It) -> &mut Self {
+        let channels = channels.into_iter()
+            .map(|channel| Value::Number(Number::from(channel.into().0)))
+            .collect();
+
+        self.0.insert("exempt_channels", Value::Array(channels));
+
+        self
+    }
+}
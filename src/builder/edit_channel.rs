@@ -1,4 +1,5 @@
 use crate::internal::prelude::*;
+use crate::model::channel::VideoQualityMode;
 use crate::model::id::ChannelId;
 use std::collections::HashMap;
 
@@ -87,6 +88,32 @@ impl EditChannel {
         self
     }
 
+    /// The [`VoiceRegion`] of the channel, given by its Id, or `None` to
+    /// choose the region automatically.
+    ///
+    /// This is for [voice] and [stage] channels only.
+    ///
+    /// [`VoiceRegion`]: ../model/voice/struct.VoiceRegion.html
+    /// [stage]: ../model/channel/enum.ChannelType.html#variant.Stage
+    /// [voice]: ../model/channel/enum.ChannelType.html#variant.Voice
+    pub fn voice_region(&mut self, region: Option<String>) -> &mut Self {
+        self.0.insert("rtc_region", region.map_or(Value::Null, Value::String));
+
+        self
+    }
+
+    /// The video quality of the channel.
+    ///
+    /// This is for [voice] and [stage] channels only.
+    ///
+    /// [stage]: ../model/channel/enum.ChannelType.html#variant.Stage
+    /// [voice]: ../model/channel/enum.ChannelType.html#variant.Voice
+    pub fn video_quality_mode(&mut self, quality: VideoQualityMode) -> &mut Self {
+        self.0.insert("video_quality_mode", Value::Number(Number::from(quality.num())));
+
+        self
+    }
+
     /// The parent category of the channel.
     ///
     /// This is for [text] and [voice] channels only.
@@ -0,0 +1,101 @@
+use crate::internal::prelude::*;
+use crate::model::id::ChannelId;
+
+use std::collections::HashMap;
+
+/// A builder to move a channel within the channel list of a guild, used by
+/// [`GuildId::reorder_channels`] and [`GuildId::edit_channel_position`].
+///
+/// Only the given properties of the channel are changed.
+///
+/// [`GuildId::edit_channel_position`]: ../model/id/struct.GuildId.html#method.edit_channel_position
+/// [`GuildId::reorder_channels`]: ../model/id/struct.GuildId.html#method.reorder_channels
+#[derive(Clone, Debug, Default)]
+pub struct EditChannelPosition(pub HashMap<&'static str, Value>);
+
+impl EditChannelPosition {
+    /// The position of the channel in the channel list.
+    pub fn position(&mut self, position: u64) -> &mut Self {
+        self.0.insert("position", Value::Number(Number::from(position)));
+
+        self
+    }
+
+    /// The category to move the channel into, or `None` to move it out of its
+    /// category.
+    #[inline]
+    pub fn category<C: Into<Option<ChannelId>>>(&mut self, category: C) -> &mut Self {
+        self._category(category.into());
+
+        self
+    }
+
+    fn _category(&mut self, category: Option<ChannelId>) {
+        self.0.insert("parent_id", match category {
+            Some(c) => Value::Number(Number::from(c.0)),
+            None => Value::Null,
+        });
+    }
+
+    /// Whether to sync the permission overwrites of the channel with the
+    /// category it is moved into.
+    pub fn lock_permissions(&mut self, lock_permissions: bool) -> &mut Self {
+        self.0.insert("lock_permissions", Value::Bool(lock_permissions));
+
+        self
+    }
+
+    pub(crate) fn channel(&mut self, channel_id: ChannelId) -> &mut Self {
+        self.0.insert("id", Value::Number(Number::from(channel_id.0)));
+
+        self
+    }
+}
+
+/// Moves the channel to the position.
+impl From<(ChannelId, u64)> for EditChannelPosition {
+    fn from((channel_id, position): (ChannelId, u64)) -> Self {
+        let mut builder = EditChannelPosition::default();
+        builder.channel(channel_id).position(position);
+
+        builder
+    }
+}
+
+/// Moves the channel to the position in the category, or out of its category
+/// if it is `None`, and syncs its permissions with the category if the last
+/// element is `true`.
+impl From<(ChannelId, u64, Option<ChannelId>, bool)> for EditChannelPosition {
+    fn from((channel_id, position, category, lock_permissions): (ChannelId, u64, Option<ChannelId>, bool)) -> Self {
+        let mut builder = EditChannelPosition::from((channel_id, position));
+        builder.category(category).lock_permissions(lock_permissions);
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditChannelPosition;
+    use crate::model::id::ChannelId;
+    use serde_json::json;
+
+    #[test]
+    fn from_tuples() {
+        let builder = EditChannelPosition::from((ChannelId(1), 2));
+
+        assert_eq!(builder.0.len(), 2);
+        assert_eq!(builder.0["id"], json!(1));
+        assert_eq!(builder.0["position"], json!(2));
+
+        let builder = EditChannelPosition::from((ChannelId(1), 2, None, false));
+
+        assert_eq!(builder.0["parent_id"], json!(null));
+        assert_eq!(builder.0["lock_permissions"], json!(false));
+
+        let builder = EditChannelPosition::from((ChannelId(1), 2, Some(ChannelId(3)), true));
+
+        assert_eq!(builder.0["parent_id"], json!(3));
+        assert_eq!(builder.0["lock_permissions"], json!(true));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+use crate::utils;
 use std::collections::HashMap;
 
 /// A builder to optionally edit certain fields of a [`Guild`]. This is meant
@@ -42,6 +43,9 @@ impl EditGuild {
     /// Set the amount of time a user is to be moved to the AFK channel -
     /// configured via [`afk_channel`] - after being AFK.
     ///
+    /// The timeout is in seconds, and must be one of `60`, `300`, `900`,
+    /// `1800` or `3600`.
+    ///
     /// [`afk_channel`]: #method.afk_channel
     pub fn afk_timeout(&mut self, timeout: u64) -> &mut Self {
         self.0.insert(
@@ -51,6 +55,61 @@ impl EditGuild {
         self
     }
 
+    /// Set the banner of the guild as a base64 data URI. Pass `None` to
+    /// remove the banner.
+    ///
+    /// Requires that the guild have the `BANNER` feature enabled.
+    /// You can check this through a guild's [`features`] list.
+    ///
+    /// [`features`]: ../model/guild/struct.Guild.html#structfield.features
+    pub fn banner(&mut self, banner: Option<&str>) -> &mut Self {
+        let banner = banner.map_or(Value::Null, |x| Value::String(x.to_string()));
+        self.0.insert("banner", banner);
+        self
+    }
+
+    /// Set the banner of the guild from the contents of an image file,
+    /// encoding it into a base64 data URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::id::GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::default();
+    /// #     let mut guild = GuildId(0).to_partial_guild(&http).await?;
+    /// // assuming a `guild` has already been bound
+    ///
+    /// let banner = std::fs::read("./banner.png")?;
+    ///
+    /// guild.edit(&http, |g| g.banner_image(&banner)).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn banner_image(&mut self, data: &[u8]) -> &mut Self {
+        self.banner(Some(&utils::encode_image(data)))
+    }
+
+    /// Set the splash image of the guild shown in the server discovery as a
+    /// base64 data URI. Pass `None` to remove the image.
+    ///
+    /// Requires that the guild have the `DISCOVERABLE` feature enabled.
+    /// You can check this through a guild's [`features`] list.
+    ///
+    /// [`features`]: ../model/guild/struct.Guild.html#structfield.features
+    pub fn discovery_splash(&mut self, splash: Option<&str>) -> &mut Self {
+        let splash = splash.map_or(Value::Null, |x| Value::String(x.to_string()));
+        self.0.insert("discovery_splash", splash);
+        self
+    }
+
+    /// Set the splash image of the guild shown in the server discovery from
+    /// the contents of an image file, encoding it into a base64 data URI.
+    pub fn discovery_splash_image(&mut self, data: &[u8]) -> &mut Self {
+        self.discovery_splash(Some(&utils::encode_image(data)))
+    }
+
     /// Set the icon of the guild. Pass `None` to remove the icon.
     ///
     /// # Examples
@@ -111,6 +170,9 @@ impl EditGuild {
 
     /// Set the voice region of the server.
     ///
+    /// **Note**: Discord no longer uses the region of a guild, set the region
+    /// of each voice channel with [`EditChannel::voice_region`] instead.
+    ///
     /// # Examples
     ///
     /// Setting the region to [`Region::UsWest`]:
@@ -133,6 +195,7 @@ impl EditGuild {
     /// # }
     /// ```
     ///
+    /// [`EditChannel::voice_region`]: struct.EditChannel.html#method.voice_region
     /// [`Region::UsWest`]: ../model/guild/enum.Region.html#variant.UsWest
     pub fn region(&mut self, region: Region) -> &mut Self {
         self.0.insert("region", Value::String(region.name().to_string()));
@@ -151,6 +214,12 @@ impl EditGuild {
         self
     }
 
+    /// Set the splash image of the guild on the invitation page from the
+    /// contents of an image file, encoding it into a base64 data URI.
+    pub fn splash_image(&mut self, data: &[u8]) -> &mut Self {
+        self.splash(Some(&utils::encode_image(data)))
+    }
+
     /// Set the verification level of the guild. This can restrict what a
     /// user must have prior to being able to send messages in a guild.
     ///
@@ -0,0 +1,32 @@
+use crate::internal::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder to edit a [`GuildTemplate`], used by
+/// [`GuildId::edit_template`] and [`GuildTemplate::edit`].
+///
+/// [`GuildId::edit_template`]: ../model/id/struct.GuildId.html#method.edit_template
+/// [`GuildTemplate`]: ../model/guild/struct.GuildTemplate.html
+/// [`GuildTemplate::edit`]: ../model/guild/struct.GuildTemplate.html#method.edit
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildTemplate(pub HashMap<&'static str, Value>);
+
+impl EditGuildTemplate {
+    /// Sets the name of the template.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the description of the template.
+    ///
+    /// **Note**: Must be at most 120 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+}
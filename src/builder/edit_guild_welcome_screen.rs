@@ -0,0 +1,129 @@
+use crate::internal::prelude::*;
+use crate::model::{channel::ReactionType, id::ChannelId};
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to edit the welcome screen of a [`Guild`], used by
+/// [`GuildId::edit_welcome_screen`].
+///
+/// # Examples
+///
+/// Point new members to the rules channel:
+///
+/// ```rust,no_run
+/// # use serenity::{model::id::GuildId, http::Http};
+/// # use std::sync::Arc;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Arc::new(Http::default());
+/// # let guild_id = GuildId(1);
+/// guild_id.edit_welcome_screen(&http, |w| {
+///     w.enabled(true)
+///         .description("Welcome to the server!")
+///         .create_welcome_channel(|c| {
+///             c.channel_id(2).description("Read the rules").emoji('📜')
+///         })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [`GuildId::edit_welcome_screen`]: ../model/id/struct.GuildId.html#method.edit_welcome_screen
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWelcomeScreen(pub HashMap<&'static str, Value>);
+
+impl EditGuildWelcomeScreen {
+    /// Sets whether the welcome screen is enabled.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Sets the description shown on the welcome screen.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Creates a welcome channel, shown on the welcome screen.
+    ///
+    /// **Note**: A welcome screen can have at most 5 channels.
+    pub fn create_welcome_channel<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateGuildWelcomeChannel) -> &mut CreateGuildWelcomeChannel {
+        let mut channel = CreateGuildWelcomeChannel::default();
+        f(&mut channel);
+
+        self.add_welcome_channel(channel)
+    }
+
+    /// Adds a welcome channel, shown on the welcome screen.
+    ///
+    /// **Note**: A welcome screen can have at most 5 channels.
+    pub fn add_welcome_channel(&mut self, channel: CreateGuildWelcomeChannel) -> &mut Self {
+        let channel = Value::Object(utils::hashmap_to_json_map(channel.0));
+
+        self.0.entry("welcome_channels")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(channel);
+
+        self
+    }
+
+    /// Sets all welcome channels, replacing previously added channels.
+    pub fn set_welcome_channels(&mut self, channels: Vec<CreateGuildWelcomeChannel>) -> &mut Self {
+        let channels = channels.into_iter()
+            .map(|c| Value::Object(utils::hashmap_to_json_map(c.0)))
+            .collect();
+
+        self.0.insert("welcome_channels", Value::Array(channels));
+
+        self
+    }
+}
+
+/// A builder for a channel shown on a guild's welcome screen.
+///
+/// The [`channel_id`] and [`description`] are required.
+///
+/// [`channel_id`]: #method.channel_id
+/// [`description`]: #method.description
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuildWelcomeChannel(pub HashMap<&'static str, Value>);
+
+impl CreateGuildWelcomeChannel {
+    /// Sets the channel shown.
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::String(channel_id.into().0.to_string()));
+
+        self
+    }
+
+    /// Sets the description shown for the channel.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the emoji shown for the channel.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        match emoji.into() {
+            ReactionType::Custom { id, name, .. } => {
+                self.0.insert("emoji_id", Value::String(id.0.to_string()));
+                self.0.insert("emoji_name", name.map_or(Value::Null, Value::String));
+            },
+            ReactionType::Unicode(name) => {
+                self.0.remove("emoji_id");
+                self.0.insert("emoji_name", Value::String(name));
+            },
+        }
+
+        self
+    }
+}
@@ -0,0 +1,37 @@
+use crate::internal::prelude::*;
+use crate::model::id::ChannelId;
+
+use std::collections::HashMap;
+
+/// A builder to edit the widget settings of a [`Guild`], used by
+/// [`GuildId::edit_widget`].
+///
+/// [`Guild`]: ../model/guild/struct.Guild.html
+/// [`GuildId::edit_widget`]: ../model/id/struct.GuildId.html#method.edit_widget
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWidget(pub HashMap<&'static str, Value>);
+
+impl EditGuildWidget {
+    /// Sets whether the widget is enabled.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Sets the channel the widget invites to, or `None` to not invite to
+    /// any channel.
+    #[inline]
+    pub fn channel_id<C: Into<Option<ChannelId>>>(&mut self, channel_id: C) -> &mut Self {
+        self._channel_id(channel_id.into());
+
+        self
+    }
+
+    fn _channel_id(&mut self, channel_id: Option<ChannelId>) {
+        self.0.insert("channel_id", match channel_id {
+            Some(c) => Value::Number(Number::from(c.0)),
+            None => Value::Null,
+        });
+    }
+}
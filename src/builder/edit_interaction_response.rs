@@ -0,0 +1,89 @@
+use crate::internal::prelude::*;
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to edit the initial response to an [`Interaction`], for use via
+/// [`Interaction::edit_original_interaction_response`].
+///
+/// Fields that are not set are left unchanged.
+///
+/// [`Interaction`]: ../model/interactions/struct.Interaction.html
+/// [`Interaction::edit_original_interaction_response`]: ../model/interactions/struct.Interaction.html#method.edit_original_interaction_response
+#[derive(Clone, Debug, Default)]
+pub struct EditInteractionResponse(pub HashMap<&'static str, Value>);
+
+impl EditInteractionResponse {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Creates an embed for the message, at most 10 embeds can be added.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message, at most 10 embeds can be added.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        self.0.entry("embeds")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(embed);
+
+        self
+    }
+
+    /// Sets all embeds of the message, replacing the existing embeds.
+    ///
+    /// Passing an empty `Vec` removes all embeds.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        let embeds = embeds.into_iter()
+            .map(|e| Value::Object(utils::hashmap_to_json_map(e.0)))
+            .collect();
+
+        self.0.insert("embeds", Value::Array(embeds));
+        self
+    }
+
+    /// Sets the components of the message, i.e. buttons and select menus.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    ///
+    /// Passing an empty [`CreateComponents`] removes all components.
+    ///
+    /// [`CreateComponents`]: struct.CreateComponents.html
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets the mentions allowed to notify users and roles.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+}
@@ -1,3 +1,4 @@
+use crate::builder::Timestamp;
 use crate::internal::prelude::*;
 use crate::model::id::{ChannelId, RoleId};
 use std::collections::HashMap;
@@ -61,6 +62,29 @@ impl EditMember {
         self.0.insert("roles", Value::Array(roles));
     }
 
+    /// Times the member out until the given time, disabling their ability
+    /// to send messages, react or join voice channels.
+    ///
+    /// **Note**: The time can be at most 28 days in the future.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: ../model/permissions/struct.Permissions.html#associatedconstant.MODERATE_MEMBERS
+    pub fn disable_communication_until<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("communication_disabled_until", Value::String(timestamp.into().ts));
+        self
+    }
+
+    /// Removes the member's timeout, if any.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: ../model/permissions/struct.Permissions.html#associatedconstant.MODERATE_MEMBERS
+    pub fn enable_communication(&mut self) -> &mut Self {
+        self.0.insert("communication_disabled_until", Value::Null);
+        self
+    }
+
     /// The Id of the voice channel to move the member to.
     ///
     /// Requires the [Move Members] permission.
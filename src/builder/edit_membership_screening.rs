@@ -0,0 +1,38 @@
+use crate::internal::prelude::*;
+use crate::model::guild::MembershipScreeningField;
+
+use std::collections::HashMap;
+
+/// A builder to edit the [`MembershipScreening`] of a guild, used by
+/// [`GuildId::edit_membership_screening`].
+///
+/// [`GuildId::edit_membership_screening`]: ../model/id/struct.GuildId.html#method.edit_membership_screening
+/// [`MembershipScreening`]: ../model/guild/struct.MembershipScreening.html
+#[derive(Clone, Debug, Default)]
+pub struct EditMembershipScreening(pub HashMap<&'static str, Value>);
+
+impl EditMembershipScreening {
+    /// Sets whether new members must pass the screening.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+
+        self
+    }
+
+    /// Sets the description of the guild shown on the screening.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the fields new members must fill out, at most 5.
+    pub fn form_fields(&mut self, fields: Vec<MembershipScreeningField>) -> &mut Self {
+        // Discord expects the fields as a JSON string rather than an array.
+        let fields = serde_json::to_string(&fields).expect("Fields are serializable");
+
+        self.0.insert("form_fields", Value::String(fields));
+
+        self
+    }
+}
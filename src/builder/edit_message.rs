@@ -1,5 +1,7 @@
+use crate::http::AttachmentType;
 use crate::internal::prelude::*;
-use super::CreateEmbed;
+use crate::model::{channel::MessageFlags, id::AttachmentId};
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
 use crate::utils;
 
 use std::collections::HashMap;
@@ -29,11 +31,16 @@ use std::collections::HashMap;
 /// # }
 /// ```
 ///
+/// **Note**: Like [`CreateMessage`], the builder borrows the files to upload
+/// for the lifetime `'a`, kept in its second field. Code naming the type must
+/// name the lifetime too, e.g. `EditMessage<'_>`.
+///
+/// [`CreateMessage`]: struct.CreateMessage.html
 /// [`Message`]: ../model/channel/struct.Message.html
 #[derive(Clone, Debug, Default)]
-pub struct EditMessage(pub HashMap<&'static str, Value>);
+pub struct EditMessage<'a>(pub HashMap<&'static str, Value>, pub Vec<AttachmentType<'a>>);
 
-impl EditMessage {
+impl<'a> EditMessage<'a> {
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -55,6 +62,37 @@ impl EditMessage {
         self
     }
 
+    /// Sets the components of the message, i.e. buttons and select menus.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    ///
+    /// Passing an empty [`CreateComponents`] removes all components.
+    ///
+    /// [`CreateComponents`]: struct.CreateComponents.html
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Set the allowed mentions for the message.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+        let allowed_mentions = Value::Object(map);
+
+        self.0.insert("allowed_mentions", allowed_mentions);
+        self
+    }
+
     /// Delete all embeds in the message, this includes those generated by Discord themselves
     pub fn suppress_embeds(&mut self, suppress: bool) -> &mut Self {
 
@@ -69,4 +107,66 @@ impl EditMessage {
         self
     }
 
+    /// Sets the flags of the message, replacing the current flags.
+    ///
+    /// **Note**: Only [`SUPPRESS_EMBEDS`] can be changed when editing a
+    /// message.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: ../model/channel/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(Number::from(flags.bits)));
+        self
+    }
+
+    /// Keeps an existing attachment of the message.
+    ///
+    /// **Note**: Once an attachment is kept, all existing attachments not
+    /// kept are removed.
+    pub fn attachment<A: Into<AttachmentId>>(&mut self, attachment_id: A) -> &mut Self {
+        let mut attachment = Map::new();
+        attachment.insert("id".to_string(), Value::Number(Number::from(*attachment_id.into().as_u64())));
+
+        self.attachments().push(Value::Object(attachment));
+        self
+    }
+
+    /// Removes all existing attachments of the message, only keeping those
+    /// given to [`attachment`] afterwards.
+    ///
+    /// [`attachment`]: #method.attachment
+    pub fn remove_all_attachments(&mut self) -> &mut Self {
+        self.0.insert("attachments", Value::Array(Vec::new()));
+        self
+    }
+
+    /// Uploads a new file as an attachment of the message.
+    pub fn add_file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1.push(file.into());
+        self
+    }
+
+    /// Uploads a new file with a description, shown as its alt text, as an
+    /// attachment of the message.
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+    where T: Into<AttachmentType<'a>>, D: ToString {
+        let mut attachment = Map::new();
+        attachment.insert("id".to_string(), Value::Number(Number::from(self.1.len() as u64)));
+        attachment.insert("description".to_string(), Value::String(description.to_string()));
+
+        self.attachments().push(Value::Object(attachment));
+        self.add_file(file)
+    }
+
+    /// Uploads a list of new files as attachments of the message.
+    pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
+        self.1.extend(files.into_iter().map(|f| f.into()));
+        self
+    }
+
+    fn attachments(&mut self) -> &mut Vec<Value> {
+        self.0.entry("attachments")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+    }
 }
@@ -1,23 +1,26 @@
 use crate::internal::prelude::*;
+use crate::http::AttachmentType;
 use std::collections::HashMap;
 
 /// A builder to edit the current user's settings, to be used in conjunction
 /// with [`CurrentUser::edit`].
 ///
+/// The avatar and banner images are read and encoded into base64 data URIs
+/// when the profile is edited, detecting their format from their contents.
+///
 /// [`CurrentUser::edit`]: ../model/user/struct.CurrentUser.html#method.edit
 #[derive(Clone, Debug, Default)]
-pub struct EditProfile(pub HashMap<&'static str, Value>);
+pub struct EditProfile<'a>(pub HashMap<&'static str, Value>, pub HashMap<&'static str, AttachmentType<'a>>);
 
-impl EditProfile {
-    /// Sets the avatar of the current user. `None` can be passed to remove an
-    /// avatar.
+impl<'a> EditProfile<'a> {
+    /// Sets the avatar of the current user.
     ///
-    /// A base64-encoded string is accepted as the avatar content.
+    /// The image may be given as a path to a file, an image URL, the bytes
+    /// of the image or an opened file.
     ///
     /// # Examples
     ///
-    /// A utility method - [`utils::read_image`] - is provided to read an
-    /// image from a file and return its contents in base64-encoded form:
+    /// Set the avatar from a file:
     ///
     /// ```rust,no_run
     /// # #[cfg(all(feature = "client", feature = "cache", feature = "gateway"))]
@@ -30,30 +33,70 @@ impl EditProfile {
     /// # #[serenity::async_trait]
     /// # impl EventHandler for Handler {
     /// #     async fn message(&self, context: Context, _: Message) {
-    /// use serenity::utils;
-    ///
     /// // assuming a `context` has been bound
     ///
-    /// let base64 = utils::read_image("./my_image.jpg")
-    ///     .expect("Failed to read image");
-    ///
     /// let mut user = context.cache.current_user().await;
     /// let _ = user.edit(&context, |p| {
-    ///     p.avatar(Some(&base64))
+    ///     p.avatar("./my_image.jpg")
     /// }).await;
     /// #     }
     /// # }
     /// # }
     /// ```
     ///
-    /// [`utils::read_image`]: ../utils/fn.read_image.html
-    pub fn avatar(&mut self, avatar: Option<&str>) -> &mut Self {
-        let avatar = avatar.map_or(Value::Null, |x| Value::String(x.to_string()));
+    /// Set the avatar from the bytes of an image:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::user::CurrentUser;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::default();
+    /// #     let mut user = CurrentUser::default();
+    /// let bytes = std::fs::read("./my_image.gif")?;
+    ///
+    /// user.edit(&http, |p| p.avatar((bytes.as_slice(), "my_image.gif"))).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn avatar<T: Into<AttachmentType<'a>>>(&mut self, avatar: T) -> &mut Self {
+        self.set_image("avatar", avatar.into());
+        self
+    }
 
-        self.0.insert("avatar", avatar);
+    /// Removes the avatar of the current user, resetting it to the default
+    /// avatar.
+    pub fn delete_avatar(&mut self) -> &mut Self {
+        self.delete_image("avatar");
         self
     }
 
+    /// Sets the banner of the current user.
+    ///
+    /// The image may be given the same ways as for [`avatar`].
+    ///
+    /// [`avatar`]: #method.avatar
+    pub fn banner<T: Into<AttachmentType<'a>>>(&mut self, banner: T) -> &mut Self {
+        self.set_image("banner", banner.into());
+        self
+    }
+
+    /// Removes the banner of the current user.
+    pub fn delete_banner(&mut self) -> &mut Self {
+        self.delete_image("banner");
+        self
+    }
+
+    fn set_image(&mut self, key: &'static str, image: AttachmentType<'a>) {
+        self.0.remove(key);
+        self.1.insert(key, image);
+    }
+
+    fn delete_image(&mut self, key: &'static str) {
+        self.1.remove(key);
+        self.0.insert(key, Value::Null);
+    }
+
     /// Modifies the current user's email address.
     ///
     /// Note that when modifying the email address, the current password must
@@ -101,3 +144,24 @@ impl EditProfile {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::EditProfile;
+    use serde_json::Value;
+
+    #[test]
+    fn deleting_replaces_image() {
+        let mut profile = EditProfile::default();
+        profile.avatar("./avatar.png").banner("https://example.com/banner.gif");
+        assert_eq!(profile.1.len(), 2);
+
+        profile.delete_avatar();
+        assert!(!profile.1.contains_key("avatar"));
+        assert_eq!(profile.0.get("avatar"), Some(&Value::Null));
+
+        profile.avatar("./avatar.png");
+        assert!(profile.1.contains_key("avatar"));
+        assert!(!profile.0.contains_key("avatar"));
+    }
+}
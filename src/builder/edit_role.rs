@@ -107,6 +107,59 @@ impl EditRole {
         self
     }
 
+    /// The icon shown next to the role's members' names, removing any
+    /// [`unicode_emoji`], or `None` to remove it.
+    ///
+    /// The icon must be a base64 data URI, which [`utils::encode_image`] and
+    /// [`utils::read_image`] create.
+    ///
+    /// **Note**: Requires the guild to have the `ROLE_ICONS` feature.
+    ///
+    /// # Examples
+    ///
+    /// Set the icon of a role from an image file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{model::id::{GuildId, RoleId}, http::Http};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// # let (guild_id, role_id) = (GuildId(1), RoleId(2));
+    /// use serenity::utils;
+    ///
+    /// let icon = utils::read_image("./role_icon.png")?;
+    ///
+    /// guild_id.edit_role(&http, role_id, |r| r.icon(Some(&icon))).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`unicode_emoji`]: #method.unicode_emoji
+    /// [`utils::encode_image`]: ../utils/fn.encode_image.html
+    /// [`utils::read_image`]: ../utils/fn.read_image.html
+    pub fn icon(&mut self, icon: Option<&str>) -> &mut Self {
+        let icon = icon.map_or(Value::Null, |x| Value::String(x.to_string()));
+
+        self.0.insert("icon", icon);
+        self.0.insert("unicode_emoji", Value::Null);
+        self
+    }
+
+    /// The unicode emoji shown next to the role's members' names, removing
+    /// any [`icon`], or `None` to remove it.
+    ///
+    /// **Note**: Requires the guild to have the `ROLE_ICONS` feature.
+    ///
+    /// [`icon`]: #method.icon
+    pub fn unicode_emoji<S: ToString>(&mut self, emoji: Option<S>) -> &mut Self {
+        let emoji = emoji.map_or(Value::Null, |x| Value::String(x.to_string()));
+
+        self.0.insert("unicode_emoji", emoji);
+        self.0.insert("icon", Value::Null);
+        self
+    }
+
     /// The position to assign the role in the role list. This correlates to the
     /// role's position in the user list.
     pub fn position(&mut self, position: u8) -> &mut Self {
@@ -0,0 +1,138 @@
+use crate::builder::Timestamp;
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::utils;
+use super::CreateRecurrenceRule;
+
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder to edit a [`ScheduledEvent`], see
+/// [`GuildId::edit_scheduled_event`].
+///
+/// [`GuildId::edit_scheduled_event`]: ../model/id/struct.GuildId.html#method.edit_scheduled_event
+/// [`ScheduledEvent`]: ../model/guild/struct.ScheduledEvent.html
+#[derive(Clone, Debug, Default)]
+pub struct EditScheduledEvent(pub HashMap<&'static str, Value>);
+
+impl EditScheduledEvent {
+    /// Sets the stage or voice channel the event takes place in.
+    ///
+    /// Pass `None` when changing the event to an [`External`] event.
+    ///
+    /// [`External`]: ../model/guild/enum.ScheduledEventType.html#variant.External
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: Option<C>) -> &mut Self {
+        let channel_id = match channel_id {
+            Some(id) => Value::String(id.into().0.to_string()),
+            None => Value::Null,
+        };
+
+        self.0.insert("channel_id", channel_id);
+
+        self
+    }
+
+    /// Sets the name of the event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the description of the event.
+    ///
+    /// **Note**: Must be between 1 and 1000 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the time the event is scheduled to start.
+    pub fn start_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_start_time", Value::String(timestamp.into().ts));
+
+        self
+    }
+
+    /// Sets the time the event is scheduled to end.
+    pub fn end_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_end_time", Value::String(timestamp.into().ts));
+
+        self
+    }
+
+    /// Sets the type of the event's location.
+    pub fn kind(&mut self, kind: ScheduledEventType) -> &mut Self {
+        self.0.insert("entity_type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+
+    /// Sets the location of an [`External`] event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    ///
+    /// [`External`]: ../model/guild/enum.ScheduledEventType.html#variant.External
+    pub fn location<D: ToString>(&mut self, location: D) -> &mut Self {
+        self.0.insert("entity_metadata", json!({
+            "location": location.to_string(),
+        }));
+
+        self
+    }
+
+    /// Sets the status of the event, e.g. to start or cancel it.
+    ///
+    /// **Note**: A [`Scheduled`] event can only become [`Active`] or
+    /// [`Canceled`], and an [`Active`] event can only become [`Completed`].
+    ///
+    /// [`Active`]: ../model/guild/enum.ScheduledEventStatus.html#variant.Active
+    /// [`Canceled`]: ../model/guild/enum.ScheduledEventStatus.html#variant.Canceled
+    /// [`Completed`]: ../model/guild/enum.ScheduledEventStatus.html#variant.Completed
+    /// [`Scheduled`]: ../model/guild/enum.ScheduledEventStatus.html#variant.Scheduled
+    pub fn status(&mut self, status: ScheduledEventStatus) -> &mut Self {
+        self.0.insert("status", Value::Number(Number::from(status.num())));
+
+        self
+    }
+
+    /// Sets the cover image of the event from the contents of an image file,
+    /// encoding it into a base64 data URI.
+    pub fn image(&mut self, data: &[u8]) -> &mut Self {
+        self.0.insert("image", Value::String(utils::encode_image(data)));
+
+        self
+    }
+
+    /// Removes the cover image of the event.
+    pub fn remove_image(&mut self) -> &mut Self {
+        self.0.insert("image", Value::Null);
+
+        self
+    }
+
+    /// Sets the rule the event recurs by, replacing the previous rule.
+    ///
+    /// Refer to [`CreateRecurrenceRule`] for an example.
+    ///
+    /// [`CreateRecurrenceRule`]: struct.CreateRecurrenceRule.html
+    pub fn recurrence_rule<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateRecurrenceRule) -> &mut CreateRecurrenceRule {
+        let mut rule = CreateRecurrenceRule::default();
+        f(&mut rule);
+
+        self.0.insert("recurrence_rule", Value::Object(utils::hashmap_to_json_map(rule.0)));
+
+        self
+    }
+
+    /// Makes the event a one-time event again, removing its recurrence rule.
+    pub fn remove_recurrence_rule(&mut self) -> &mut Self {
+        self.0.insert("recurrence_rule", Value::Null);
+
+        self
+    }
+}
@@ -0,0 +1,30 @@
+use crate::internal::prelude::*;
+use crate::model::channel::StagePrivacyLevel;
+
+use std::collections::HashMap;
+
+/// A builder to edit a [`StageInstance`], see
+/// [`ChannelId::edit_stage_instance`].
+///
+/// [`ChannelId::edit_stage_instance`]: ../model/id/struct.ChannelId.html#method.edit_stage_instance
+/// [`StageInstance`]: ../model/channel/struct.StageInstance.html
+#[derive(Clone, Debug, Default)]
+pub struct EditStageInstance(pub HashMap<&'static str, Value>);
+
+impl EditStageInstance {
+    /// Sets the topic of the stage instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
+    pub fn topic<D: ToString>(&mut self, topic: D) -> &mut Self {
+        self.0.insert("topic", Value::String(topic.to_string()));
+
+        self
+    }
+
+    /// Sets the privacy level of the stage instance.
+    pub fn privacy_level(&mut self, privacy_level: StagePrivacyLevel) -> &mut Self {
+        self.0.insert("privacy_level", Value::Number(Number::from(privacy_level.num())));
+
+        self
+    }
+}
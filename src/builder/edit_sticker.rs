@@ -0,0 +1,40 @@
+use crate::internal::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder to edit a guild's [`Sticker`], used by
+/// [`GuildId::edit_sticker`] and [`Sticker::edit`].
+///
+/// [`GuildId::edit_sticker`]: ../model/id/struct.GuildId.html#method.edit_sticker
+/// [`Sticker`]: ../model/sticker/struct.Sticker.html
+/// [`Sticker::edit`]: ../model/sticker/struct.Sticker.html#method.edit
+#[derive(Clone, Debug, Default)]
+pub struct EditSticker(pub HashMap<&'static str, Value>);
+
+impl EditSticker {
+    /// Sets the name of the sticker.
+    ///
+    /// **Note**: Must be between 2 and 30 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+
+        self
+    }
+
+    /// Sets the description of the sticker.
+    ///
+    /// **Note**: Must be empty or between 2 and 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+
+        self
+    }
+
+    /// Sets the name of the unicode emoji representing the sticker, used
+    /// for autocomplete suggestions.
+    pub fn tags<D: ToString>(&mut self, tags: D) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+
+        self
+    }
+}
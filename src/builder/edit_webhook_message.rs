@@ -0,0 +1,65 @@
+use super::{CreateAllowedMentions, CreateComponents};
+use crate::utils;
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A builder to specify the fields to edit in a message previously sent by a
+/// [`Webhook`], used by [`Webhook::edit_message`].
+///
+/// [`Webhook`]: ../model/webhook/struct.Webhook.html
+/// [`Webhook::edit_message`]: ../model/webhook/struct.Webhook.html#method.edit_message
+#[derive(Clone, Debug, Default)]
+pub struct EditWebhookMessage(pub HashMap<&'static str, Value>);
+
+impl EditWebhookMessage {
+    /// Set the allowed mentions for the message.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+        let allowed_mentions = Value::Object(map);
+
+        self.0.insert("allowed_mentions", allowed_mentions);
+        self
+    }
+
+    /// Sets the components of the message, i.e. buttons and select menus.
+    ///
+    /// **Note**: Only webhooks owned by an application can send components.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Set the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<S: ToString>(&mut self, content: S) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Set the embeds of the message, replacing the existing embeds.
+    ///
+    /// This should be used in combination with [`Embed::fake`], creating one
+    /// or more fake embeds to send to the API.
+    ///
+    /// [`Embed::fake`]: ../model/channel/struct.Embed.html#method.fake
+    pub fn embeds(&mut self, embeds: Vec<Value>) -> &mut Self {
+        self.0.insert("embeds", Value::Array(embeds));
+        self
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    ///
+    /// **Note**: Only webhooks owned by an application can send components.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+}
@@ -1,4 +1,9 @@
-use serde_json::Value;
+use super::{CreateAllowedMentions, CreateComponents};
+use crate::http::AttachmentType;
+use crate::model::{channel::MessageFlags, id::ChannelId};
+use crate::utils;
+
+use serde_json::{Map, Number, Value};
 use std::collections::HashMap;
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
@@ -50,9 +55,50 @@ use std::collections::HashMap;
 /// [`Webhook::execute`]: ../model/webhook/struct.Webhook.html#method.execute
 /// [`execute_webhook`]: ../http/client/struct.Http.html#method.execute_webhook
 #[derive(Clone, Debug)]
-pub struct ExecuteWebhook(pub HashMap<&'static str, Value>);
+pub struct ExecuteWebhook<'a>(pub HashMap<&'static str, Value>, pub Vec<AttachmentType<'a>>);
+
+impl<'a> ExecuteWebhook<'a> {
+    /// Appends a file to the message.
+    pub fn add_file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1.push(file.into());
+        self
+    }
+
+    /// Appends a file to the message with a description, shown as its alt
+    /// text.
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+    where T: Into<AttachmentType<'a>>, D: ToString {
+        let mut attachment = Map::new();
+        attachment.insert("id".to_string(), Value::Number(Number::from(self.1.len() as u64)));
+        attachment.insert("description".to_string(), Value::String(description.to_string()));
+
+        self.0.entry("attachments")
+            .or_insert_with(|| Value::Array(Vec::new()))
+            .as_array_mut()
+            .expect("Must be an array")
+            .push(Value::Object(attachment));
+
+        self.add_file(file)
+    }
+
+    /// Appends a list of files to the message.
+    pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
+        self.1.extend(files.into_iter().map(|f| f.into()));
+        self
+    }
+
+    /// Set the allowed mentions for the message.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+        let allowed_mentions = Value::Object(map);
+
+        self.0.insert("allowed_mentions", allowed_mentions);
+        self
+    }
 
-impl ExecuteWebhook {
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -80,6 +126,25 @@ impl ExecuteWebhook {
         self
     }
 
+    /// Sets the components of the message, i.e. buttons and select menus.
+    ///
+    /// **Note**: Only webhooks owned by an application can send components.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the message, replacing existing components.
+    ///
+    /// **Note**: Only webhooks owned by an application can send components.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// Note that when setting at least one embed via [`embeds`], this may be
@@ -132,6 +197,27 @@ impl ExecuteWebhook {
         self
     }
 
+    /// Sets the flags of the message.
+    ///
+    /// **Note**: Only [`SUPPRESS_EMBEDS`] can be set.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: ../model/channel/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
+        self.0.insert("flags", Value::Number(Number::from(flags.bits)));
+        self
+    }
+
+    /// Sends the message to a thread of the webhook's channel instead.
+    ///
+    /// **Note**: The thread is sent as a query parameter rather than in the
+    /// message's payload, see [`Webhook::execute`].
+    ///
+    /// [`Webhook::execute`]: ../model/webhook/struct.Webhook.html#method.execute
+    pub fn in_thread<C: Into<ChannelId>>(&mut self, thread_id: C) -> &mut Self {
+        self.0.insert("thread_id", Value::Number(Number::from(thread_id.into().0)));
+        self
+    }
+
     /// Whether the message is a text-to-speech message.
     ///
     /// # Examples
@@ -191,7 +277,7 @@ impl ExecuteWebhook {
     }
 }
 
-impl Default for ExecuteWebhook {
+impl<'a> Default for ExecuteWebhook<'a> {
     /// Returns a default set of values for a [`Webhook`] execution.
     ///
     /// The only default value is [`tts`] being set to `false`.
@@ -208,10 +294,10 @@ impl Default for ExecuteWebhook {
     ///
     /// [`Webhook`]: ../model/webhook/struct.Webhook.html
     /// [`tts`]: #method.tts
-    fn default() -> ExecuteWebhook {
+    fn default() -> ExecuteWebhook<'a> {
         let mut map = HashMap::new();
         map.insert("tts", Value::Bool(false));
 
-        ExecuteWebhook(map)
+        ExecuteWebhook(map, Vec::new())
     }
 }
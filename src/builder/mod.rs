@@ -5,33 +5,101 @@
 //! optional, and/or sane default values for required parameters can be applied
 //! by a builder.
 
+mod create_application_command;
+mod create_application_command_permission;
+mod create_application_role_connection_metadata;
+mod create_autocomplete_response;
 mod create_embed;
 mod create_channel;
+mod create_components;
+mod create_interaction_response;
+mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
+mod create_modal;
+mod create_poll;
+mod create_scheduled_event;
+mod create_stage_instance;
+mod create_sticker;
+mod create_thread;
 mod create_allowed_mentions;
+mod edit_automod_rule;
 mod edit_channel;
+mod edit_channel_position;
 mod edit_guild;
+mod edit_guild_template;
+mod edit_guild_welcome_screen;
+mod edit_guild_widget;
+mod edit_interaction_response;
 mod edit_member;
+mod edit_membership_screening;
 mod edit_message;
 mod edit_profile;
 mod edit_role;
+mod edit_scheduled_event;
+mod edit_stage_instance;
+mod edit_sticker;
+mod edit_webhook_message;
 mod execute_webhook;
 mod get_messages;
 
 pub use self::{
+    create_application_command::{
+        CreateApplicationCommand,
+        CreateApplicationCommandOption,
+        CreateApplicationCommands,
+    },
+    create_application_command_permission::{
+        CreateApplicationCommandPermissionData,
+        CreateApplicationCommandPermissions,
+        CreateApplicationCommandPermissionsData,
+        CreateApplicationCommandsPermissions,
+    },
+    create_application_role_connection_metadata::{
+        CreateApplicationRoleConnectionMetadata,
+        CreateApplicationRoleConnectionMetadataRecords,
+    },
+    create_autocomplete_response::CreateAutocompleteResponse,
     create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, Timestamp},
     create_channel::CreateChannel,
+    create_components::{
+        CreateActionRow,
+        CreateButton,
+        CreateComponents,
+        CreateInputText,
+        CreateSelectMenu,
+        CreateSelectMenuOption,
+        CreateSelectMenuOptions,
+    },
+    create_interaction_response::{CreateInteractionResponse, CreateInteractionResponseData},
+    create_interaction_response_followup::CreateInteractionResponseFollowup,
     create_invite::CreateInvite,
     create_message::CreateMessage,
+    create_modal::CreateModal,
+    create_poll::{CreatePoll, CreatePollAnswer},
+    create_scheduled_event::{CreateRecurrenceRule, CreateScheduledEvent},
+    create_stage_instance::CreateStageInstance,
+    create_sticker::CreateSticker,
+    create_thread::CreateThread,
     create_allowed_mentions::CreateAllowedMentions,
     create_allowed_mentions::ParseValue,
+    edit_automod_rule::EditAutoModRule,
     edit_channel::EditChannel,
+    edit_channel_position::EditChannelPosition,
     edit_guild::EditGuild,
+    edit_guild_template::EditGuildTemplate,
+    edit_guild_welcome_screen::{CreateGuildWelcomeChannel, EditGuildWelcomeScreen},
+    edit_guild_widget::EditGuildWidget,
+    edit_interaction_response::EditInteractionResponse,
     edit_member::EditMember,
+    edit_membership_screening::EditMembershipScreening,
     edit_message::EditMessage,
     edit_profile::EditProfile,
     edit_role::EditRole,
+    edit_scheduled_event::EditScheduledEvent,
+    edit_stage_instance::EditStageInstance,
+    edit_sticker::EditSticker,
+    edit_webhook_message::EditWebhookMessage,
     execute_webhook::ExecuteWebhook,
     get_messages::GetMessages
 };
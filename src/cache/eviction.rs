@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::time::Instant;
+use super::EvictionPolicy;
+
+/// Tracks when the entries of a bounded collection of the cache were last
+/// used, to determine which entries to evict.
+#[derive(Debug)]
+pub(crate) struct Recency<K> {
+    next: u64,
+    used: HashMap<K, (u64, Instant)>,
+    order: BTreeMap<u64, K>,
+}
+
+impl<K> Default for Recency<K> {
+    fn default() -> Self {
+        Recency {
+            next: 0,
+            used: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> Recency<K> {
+    /// Marks an entry as used now, tracking it if it was not.
+    pub(crate) fn touch(&mut self, key: K) {
+        let tick = self.next;
+        self.next += 1;
+
+        if let Some((previous, _)) = self.used.insert(key, (tick, Instant::now())) {
+            self.order.remove(&previous);
+        }
+
+        self.order.insert(tick, key);
+    }
+
+    /// Marks an entry as used now, if it is tracked.
+    pub(crate) fn refresh(&mut self, key: K) {
+        if self.used.contains_key(&key) {
+            self.touch(key);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &K) {
+        if let Some((tick, _)) = self.used.remove(key) {
+            self.order.remove(&tick);
+        }
+    }
+
+    /// Stops tracking the entries to evict under the policy, least recently
+    /// used first, returning them.
+    pub(crate) fn evict(&mut self, max: Option<usize>, policy: EvictionPolicy) -> Vec<K> {
+        let mut evicted = Vec::new();
+
+        while let Some((&tick, &key)) = self.order.iter().next() {
+            let exceeds = max.map_or(false, |max| self.used.len() > max);
+            let expired = match policy {
+                EvictionPolicy::Lru => false,
+                EvictionPolicy::Ttl(ttl) => self.used[&key].1.elapsed() >= ttl,
+            };
+
+            if !exceeds && !expired {
+                break;
+            }
+
+            self.order.remove(&tick);
+            self.used.remove(&key);
+            evicted.push(key);
+        }
+
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Recency;
+    use crate::cache::EvictionPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut recency = Recency::default();
+
+        for key in 1..=3 {
+            recency.touch(key);
+        }
+
+        recency.refresh(1);
+        recency.refresh(4);
+        recency.touch(5);
+
+        assert_eq!(recency.evict(Some(2), EvictionPolicy::Lru), vec![2, 3]);
+        assert!(recency.evict(Some(2), EvictionPolicy::Lru).is_empty());
+
+        recency.remove(&1);
+        assert_eq!(recency.evict(Some(0), EvictionPolicy::Lru), vec![5]);
+    }
+
+    #[test]
+    fn evicts_expired() {
+        let mut recency = Recency::default();
+        recency.touch(1);
+
+        assert!(recency.evict(None, EvictionPolicy::Ttl(Duration::from_secs(60))).is_empty());
+        assert_eq!(recency.evict(None, EvictionPolicy::Ttl(Duration::from_secs(0))), vec![1]);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::model::prelude::*;
+use std::collections::HashMap;
+
+/// An invite of a guild tracked by the cache, to find out which invite new
+/// members joined with.
+///
+/// Refer to [`Cache::update_guild_invites`] for more information.
+///
+/// [`Cache::update_guild_invites`]: struct.Cache.html#method.update_guild_invites
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CachedInvite {
+    /// The unique code of the invite.
+    pub code: String,
+    /// The Id of the channel the invite leads to.
+    pub channel_id: ChannelId,
+    /// The Id of the user who created the invite, if known.
+    pub inviter_id: Option<UserId>,
+    /// The number of times the invite was used.
+    pub uses: u64,
+    /// The maximum number of times the invite may be used, `0` if unlimited.
+    pub max_uses: u64,
+    /// The maximum age of the invite in seconds, `0` if it never expires.
+    pub max_age: u64,
+    /// Whether the invite only grants temporary membership.
+    pub temporary: bool,
+}
+
+impl From<&RichInvite> for CachedInvite {
+    fn from(invite: &RichInvite) -> Self {
+        CachedInvite {
+            code: invite.code.clone(),
+            channel_id: invite.channel.id,
+            inviter_id: Some(invite.inviter.id),
+            uses: invite.uses,
+            max_uses: invite.max_uses,
+            max_age: invite.max_age,
+            temporary: invite.temporary,
+        }
+    }
+}
+
+impl From<&InviteCreateEvent> for CachedInvite {
+    fn from(event: &InviteCreateEvent) -> Self {
+        CachedInvite {
+            code: event.code.clone(),
+            channel_id: event.channel_id,
+            inviter_id: event.inviter.as_ref().map(|user| user.id),
+            uses: 0,
+            max_uses: event.max_uses,
+            max_age: event.max_age,
+            temporary: event.temporary,
+        }
+    }
+}
+
+/// The invites whose uses increased between the tracked and the current
+/// invites of a guild, with their current number of uses.
+///
+/// Invites which reached their maximum number of uses are deleted by Discord,
+/// so tracked invites missing from the current invites are counted as used
+/// if they had a single use left.
+pub(crate) fn used_invites(
+    tracked: &HashMap<String, CachedInvite>,
+    current: &HashMap<String, CachedInvite>,
+) -> Vec<CachedInvite> {
+    let mut used = Vec::new();
+
+    for (code, old) in tracked {
+        match current.get(code) {
+            Some(new) if new.uses > old.uses => used.push(new.clone()),
+            Some(_) => {},
+            None if old.max_uses != 0 && old.uses + 1 == old.max_uses => {
+                let mut invite = old.clone();
+                invite.uses += 1;
+
+                used.push(invite);
+            },
+            None => {},
+        }
+    }
+
+    used
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn invite(code: &str, uses: u64, max_uses: u64) -> (String, CachedInvite) {
+        (code.to_string(), CachedInvite {
+            code: code.to_string(),
+            channel_id: ChannelId(1),
+            inviter_id: None,
+            uses,
+            max_uses,
+            max_age: 0,
+            temporary: false,
+        })
+    }
+
+    #[test]
+    fn finds_used_invites() {
+        let tracked = vec![invite("a", 1, 0), invite("b", 3, 0), invite("c", 0, 1), invite("d", 0, 5)]
+            .into_iter()
+            .collect();
+        let current = vec![invite("a", 1, 0), invite("b", 4, 0)].into_iter().collect();
+
+        let mut used = used_invites(&tracked, &current);
+        used.sort_by(|a, b| a.code.cmp(&b.code));
+
+        assert_eq!(used.len(), 2);
+        assert_eq!((used[0].code.as_str(), used[0].uses), ("b", 4));
+        assert_eq!((used[1].code.as_str(), used[1].uses), ("c", 1));
+    }
+}
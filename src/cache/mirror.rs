@@ -0,0 +1,800 @@
+use async_trait::async_trait;
+use crate::model::prelude::*;
+use futures::channel::mpsc::{self, Sender};
+use futures::{SinkExt, StreamExt};
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::warn;
+use super::{Cache, CacheUpdate};
+
+/// An error returned by a [`CacheMirror`].
+///
+/// [`CacheMirror`]: trait.CacheMirror.html
+pub type CacheMirrorError = Box<dyn StdError + Send + Sync>;
+
+/// A write-only copy of the cache, such as an external store shared between
+/// processes, fed with the entities the cache receives from the gateway.
+///
+/// Set with [`Cache::set_mirror`]. Once set, every gateway event updating
+/// the cache also updates the mirror, with the entities as cached after the
+/// update. The cache keeps serving its entities from memory and never reads
+/// from the mirror, which is meant to be read by other clients or processes.
+///
+/// The mirror is fed by a background task, applying the changes in the
+/// order of the updates, so a slow mirror does not delay the dispatch of
+/// events at first. Once 1000 changes are queued, the updates of the cache
+/// wait for the mirror to catch up. Errors of the mirror are logged and do
+/// not affect the cache.
+///
+/// Guilds are inserted whole, including their members and channels, only
+/// when they are received and when their settings change. Their channels,
+/// members, roles, emojis and stickers are inserted on their own as they
+/// change, so that they may also be retrieved without the guild.
+///
+/// [`InMemoryMirror`] keeps the entities in memory, which may be shared
+/// between clients in the same process.
+///
+/// [`Cache::set_mirror`]: struct.Cache.html#method.set_mirror
+/// [`InMemoryMirror`]: struct.InMemoryMirror.html
+#[async_trait]
+pub trait CacheMirror: Send + Sync {
+    /// Retrieves a guild.
+    async fn guild(&self, guild_id: GuildId) -> Result<Option<Guild>, CacheMirrorError>;
+
+    /// Inserts a guild, replacing it if it exists.
+    async fn insert_guild(&self, guild: &Guild) -> Result<(), CacheMirrorError>;
+
+    /// Removes a guild, along with its members and channels.
+    async fn remove_guild(&self, guild_id: GuildId) -> Result<(), CacheMirrorError>;
+
+    /// Retrieves a channel of a guild.
+    async fn channel(&self, channel_id: ChannelId) -> Result<Option<GuildChannel>, CacheMirrorError>;
+
+    /// Inserts a channel of a guild, replacing it if it exists.
+    async fn insert_channel(&self, channel: &GuildChannel) -> Result<(), CacheMirrorError>;
+
+    /// Removes a channel of a guild.
+    async fn remove_channel(&self, channel_id: ChannelId) -> Result<(), CacheMirrorError>;
+
+    /// Inserts a role into its guild, replacing it if it exists.
+    async fn insert_role(&self, role: &Role) -> Result<(), CacheMirrorError>;
+
+    /// Removes a role from its guild.
+    async fn remove_role(&self, guild_id: GuildId, role_id: RoleId) -> Result<(), CacheMirrorError>;
+
+    /// Replaces the emojis of a guild.
+    async fn set_emojis(&self, guild_id: GuildId, emojis: &HashMap<EmojiId, Emoji>) -> Result<(), CacheMirrorError>;
+
+    /// Replaces the stickers of a guild.
+    async fn set_stickers(&self, guild_id: GuildId, stickers: &HashMap<StickerId, Sticker>) -> Result<(), CacheMirrorError>;
+
+    /// Retrieves a user.
+    async fn user(&self, user_id: UserId) -> Result<Option<User>, CacheMirrorError>;
+
+    /// Inserts a user, replacing it if it exists.
+    async fn insert_user(&self, user: &User) -> Result<(), CacheMirrorError>;
+
+    /// Removes a user.
+    async fn remove_user(&self, user_id: UserId) -> Result<(), CacheMirrorError>;
+
+    /// Retrieves a member of a guild.
+    async fn member(&self, guild_id: GuildId, user_id: UserId) -> Result<Option<Member>, CacheMirrorError>;
+
+    /// Inserts a member of a guild, replacing it if it exists.
+    async fn insert_member(&self, member: &Member) -> Result<(), CacheMirrorError>;
+
+    /// Removes a member of a guild.
+    async fn remove_member(&self, guild_id: GuildId, user_id: UserId) -> Result<(), CacheMirrorError>;
+
+    /// Retrieves a message.
+    async fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Result<Option<Message>, CacheMirrorError>;
+
+    /// Inserts a message, replacing it if it exists.
+    ///
+    /// **Note**: Messages are only inserted if the cache stores messages, and
+    /// are removed when the cache evicts them.
+    async fn insert_message(&self, message: &Message) -> Result<(), CacheMirrorError>;
+
+    /// Removes a message.
+    async fn remove_message(&self, channel_id: ChannelId, message_id: MessageId) -> Result<(), CacheMirrorError>;
+
+    /// Retrieves the presence of a user.
+    async fn presence(&self, user_id: UserId) -> Result<Option<Presence>, CacheMirrorError>;
+
+    /// Inserts the presence of a user, replacing it if it exists.
+    async fn insert_presence(&self, presence: &Presence) -> Result<(), CacheMirrorError>;
+
+    /// Removes the presence of a user.
+    async fn remove_presence(&self, user_id: UserId) -> Result<(), CacheMirrorError>;
+}
+
+/// A [`CacheMirror`] keeping the entities in memory.
+///
+/// # Examples
+///
+/// Share the entities received by two clients:
+///
+/// ```rust,no_run
+/// use serenity::cache::InMemoryMirror;
+/// use serenity::Client;
+/// use std::sync::Arc;
+///
+/// # async fn run(first: Client, second: Client) {
+/// let mirror = Arc::new(InMemoryMirror::new());
+///
+/// first.cache_and_http.cache.set_mirror(mirror.clone()).await;
+/// second.cache_and_http.cache.set_mirror(mirror.clone()).await;
+/// # }
+/// ```
+///
+/// [`CacheMirror`]: trait.CacheMirror.html
+#[derive(Debug, Default)]
+pub struct InMemoryMirror {
+    guilds: RwLock<HashMap<GuildId, Guild>>,
+    channels: RwLock<HashMap<ChannelId, GuildChannel>>,
+    users: RwLock<HashMap<UserId, User>>,
+    members: RwLock<HashMap<(GuildId, UserId), Member>>,
+    messages: RwLock<HashMap<(ChannelId, MessageId), Message>>,
+    presences: RwLock<HashMap<UserId, Presence>>,
+}
+
+impl InMemoryMirror {
+    /// Creates a mirror without any entities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CacheMirror for InMemoryMirror {
+    async fn guild(&self, guild_id: GuildId) -> Result<Option<Guild>, CacheMirrorError> {
+        Ok(self.guilds.read().await.get(&guild_id).cloned())
+    }
+
+    async fn insert_guild(&self, guild: &Guild) -> Result<(), CacheMirrorError> {
+        self.guilds.write().await.insert(guild.id, guild.clone());
+
+        Ok(())
+    }
+
+    async fn remove_guild(&self, guild_id: GuildId) -> Result<(), CacheMirrorError> {
+        self.guilds.write().await.remove(&guild_id);
+        self.channels.write().await.retain(|_, c| c.guild_id != guild_id);
+        self.members.write().await.retain(|(g, _), _| *g != guild_id);
+
+        Ok(())
+    }
+
+    async fn channel(&self, channel_id: ChannelId) -> Result<Option<GuildChannel>, CacheMirrorError> {
+        Ok(self.channels.read().await.get(&channel_id).cloned())
+    }
+
+    async fn insert_channel(&self, channel: &GuildChannel) -> Result<(), CacheMirrorError> {
+        self.channels.write().await.insert(channel.id, channel.clone());
+
+        Ok(())
+    }
+
+    async fn remove_channel(&self, channel_id: ChannelId) -> Result<(), CacheMirrorError> {
+        self.channels.write().await.remove(&channel_id);
+
+        Ok(())
+    }
+
+    async fn insert_role(&self, role: &Role) -> Result<(), CacheMirrorError> {
+        if let Some(guild) = self.guilds.write().await.get_mut(&role.guild_id) {
+            guild.roles.insert(role.id, role.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn remove_role(&self, guild_id: GuildId, role_id: RoleId) -> Result<(), CacheMirrorError> {
+        if let Some(guild) = self.guilds.write().await.get_mut(&guild_id) {
+            guild.roles.remove(&role_id);
+        }
+
+        Ok(())
+    }
+
+    async fn set_emojis(&self, guild_id: GuildId, emojis: &HashMap<EmojiId, Emoji>) -> Result<(), CacheMirrorError> {
+        if let Some(guild) = self.guilds.write().await.get_mut(&guild_id) {
+            guild.emojis = emojis.clone();
+        }
+
+        Ok(())
+    }
+
+    async fn set_stickers(&self, guild_id: GuildId, stickers: &HashMap<StickerId, Sticker>) -> Result<(), CacheMirrorError> {
+        if let Some(guild) = self.guilds.write().await.get_mut(&guild_id) {
+            guild.stickers = stickers.clone();
+        }
+
+        Ok(())
+    }
+
+    async fn user(&self, user_id: UserId) -> Result<Option<User>, CacheMirrorError> {
+        Ok(self.users.read().await.get(&user_id).cloned())
+    }
+
+    async fn insert_user(&self, user: &User) -> Result<(), CacheMirrorError> {
+        self.users.write().await.insert(user.id, user.clone());
+
+        Ok(())
+    }
+
+    async fn remove_user(&self, user_id: UserId) -> Result<(), CacheMirrorError> {
+        self.users.write().await.remove(&user_id);
+
+        Ok(())
+    }
+
+    async fn member(&self, guild_id: GuildId, user_id: UserId) -> Result<Option<Member>, CacheMirrorError> {
+        Ok(self.members.read().await.get(&(guild_id, user_id)).cloned())
+    }
+
+    async fn insert_member(&self, member: &Member) -> Result<(), CacheMirrorError> {
+        self.members.write().await.insert((member.guild_id, member.user.id), member.clone());
+
+        Ok(())
+    }
+
+    async fn remove_member(&self, guild_id: GuildId, user_id: UserId) -> Result<(), CacheMirrorError> {
+        self.members.write().await.remove(&(guild_id, user_id));
+
+        Ok(())
+    }
+
+    async fn message(&self, channel_id: ChannelId, message_id: MessageId) -> Result<Option<Message>, CacheMirrorError> {
+        Ok(self.messages.read().await.get(&(channel_id, message_id)).cloned())
+    }
+
+    async fn insert_message(&self, message: &Message) -> Result<(), CacheMirrorError> {
+        self.messages.write().await.insert((message.channel_id, message.id), message.clone());
+
+        Ok(())
+    }
+
+    async fn remove_message(&self, channel_id: ChannelId, message_id: MessageId) -> Result<(), CacheMirrorError> {
+        self.messages.write().await.remove(&(channel_id, message_id));
+
+        Ok(())
+    }
+
+    async fn presence(&self, user_id: UserId) -> Result<Option<Presence>, CacheMirrorError> {
+        Ok(self.presences.read().await.get(&user_id).cloned())
+    }
+
+    async fn insert_presence(&self, presence: &Presence) -> Result<(), CacheMirrorError> {
+        self.presences.write().await.insert(presence.user_id, presence.clone());
+
+        Ok(())
+    }
+
+    async fn remove_presence(&self, user_id: UserId) -> Result<(), CacheMirrorError> {
+        self.presences.write().await.remove(&user_id);
+
+        Ok(())
+    }
+}
+
+/// A change to apply to a mirror, taken from an update once it has been
+/// applied to the cache.
+#[derive(Debug)]
+pub(crate) enum MirrorOp {
+    InsertGuild(Guild),
+    RemoveGuild(GuildId),
+    InsertChannel(GuildChannel),
+    RemoveChannel(ChannelId),
+    InsertUser(User),
+    InsertMember(Member),
+    RemoveMember(GuildId, UserId),
+    InsertRole(Role),
+    RemoveRole(GuildId, RoleId),
+    SetEmojis(GuildId, HashMap<EmojiId, Emoji>),
+    SetStickers(GuildId, HashMap<StickerId, Sticker>),
+    InsertMessage(Message),
+    RemoveMessage(ChannelId, MessageId),
+    InsertPresence(Presence),
+    RemovePresence(UserId),
+}
+
+impl MirrorOp {
+    async fn apply(self, mirror: &dyn CacheMirror) -> Result<(), CacheMirrorError> {
+        match self {
+            MirrorOp::InsertGuild(guild) => mirror.insert_guild(&guild).await,
+            MirrorOp::RemoveGuild(guild_id) => mirror.remove_guild(guild_id).await,
+            MirrorOp::InsertChannel(channel) => mirror.insert_channel(&channel).await,
+            MirrorOp::RemoveChannel(channel_id) => mirror.remove_channel(channel_id).await,
+            MirrorOp::InsertUser(user) => mirror.insert_user(&user).await,
+            MirrorOp::InsertMember(member) => mirror.insert_member(&member).await,
+            MirrorOp::RemoveMember(guild_id, user_id) => mirror.remove_member(guild_id, user_id).await,
+            MirrorOp::InsertRole(role) => mirror.insert_role(&role).await,
+            MirrorOp::RemoveRole(guild_id, role_id) => mirror.remove_role(guild_id, role_id).await,
+            MirrorOp::SetEmojis(guild_id, emojis) => mirror.set_emojis(guild_id, &emojis).await,
+            MirrorOp::SetStickers(guild_id, stickers) => mirror.set_stickers(guild_id, &stickers).await,
+            MirrorOp::InsertMessage(message) => mirror.insert_message(&message).await,
+            MirrorOp::RemoveMessage(channel_id, message_id) => mirror.remove_message(channel_id, message_id).await,
+            MirrorOp::InsertPresence(presence) => mirror.insert_presence(&presence).await,
+            MirrorOp::RemovePresence(user_id) => mirror.remove_presence(user_id).await,
+        }
+    }
+}
+
+/// The number of changes queued for a mirror before the updates of the cache
+/// wait for it.
+const QUEUE_SIZE: usize = 1000;
+
+/// The mirror set on a cache, fed by a task applying the changes in the
+/// order they are sent.
+#[derive(Clone)]
+pub(crate) struct MirrorHandle {
+    tx: Sender<MirrorOp>,
+}
+
+impl MirrorHandle {
+    /// Spawns the task feeding the `mirror`.
+    ///
+    /// The task ends once all handles are dropped and the remaining changes
+    /// have been applied.
+    pub(crate) fn spawn(mirror: Arc<dyn CacheMirror>) -> Self {
+        let (tx, mut rx) = mpsc::channel::<MirrorOp>(QUEUE_SIZE);
+
+        tokio::spawn(async move {
+            while let Some(op) = rx.next().await {
+                if let Err(why) = op.apply(&*mirror).await {
+                    warn!("Failed to feed the cache mirror: {}", why);
+                }
+            }
+        });
+
+        Self {
+            tx,
+        }
+    }
+
+    /// Queues the `ops` to be applied to the mirror, waiting while the queue
+    /// is full.
+    pub(crate) async fn send(&self, ops: Vec<MirrorOp>) {
+        let mut tx = self.tx.clone();
+
+        for op in ops {
+            if tx.send(op).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl fmt::Debug for MirrorHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MirrorHandle")
+    }
+}
+
+/// Takes the entities changed by an update from the cache, once the update
+/// was applied to it.
+#[async_trait]
+pub(crate) trait Feed: CacheUpdate {
+    async fn feed(&self, cache: &Cache, output: Option<&Self::Output>) -> Vec<MirrorOp>;
+}
+
+async fn feed_member(cache: &Cache, guild_id: GuildId, user_id: UserId, ops: &mut Vec<MirrorOp>) {
+    if let Some(member) = cache.member(guild_id, user_id).await {
+        ops.push(MirrorOp::InsertUser(member.user.clone()));
+        ops.push(MirrorOp::InsertMember(member));
+    }
+}
+
+macro_rules! feed_nothing {
+    ($($event:ty),*) => {
+        $(
+            #[async_trait]
+            impl Feed for $event {
+                async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+                    Vec::new()
+                }
+            }
+        )*
+    };
+}
+
+// The current user and voice states are not fed, the latter as they change
+// too frequently to insert their guild again. Invites are only tracked in
+// memory.
+feed_nothing!(
+    ChannelPinsUpdateEvent,
+    InviteCreateEvent,
+    InviteDeleteEvent,
+    UserUpdateEvent,
+    VoiceStateUpdateEvent
+);
+
+#[async_trait]
+impl Feed for ChannelCreateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        match &self.channel {
+            Channel::Guild(channel) => vec![MirrorOp::InsertChannel(channel.clone())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+#[async_trait]
+impl Feed for ChannelDeleteEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        match &self.channel {
+            Channel::Guild(channel) => vec![MirrorOp::RemoveChannel(channel.id)],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Feed for ChannelUpdateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        match &self.channel {
+            Channel::Guild(channel) => vec![MirrorOp::InsertChannel(channel.clone())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Feed for GuildCreateEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        let guild = match cache.guild(self.guild.id).await {
+            Some(guild) => guild,
+            None => return Vec::new(),
+        };
+
+        let mut ops = Vec::with_capacity(1 + guild.channels.len() + guild.members.len() * 2);
+
+        for channel in guild.channels.values() {
+            ops.push(MirrorOp::InsertChannel(channel.clone()));
+        }
+
+        for member in guild.members.values() {
+            ops.push(MirrorOp::InsertUser(member.user.clone()));
+            ops.push(MirrorOp::InsertMember(member.clone()));
+        }
+
+        ops.insert(0, MirrorOp::InsertGuild(guild));
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for GuildDeleteEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::RemoveGuild(self.guild.id)]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildUnavailableEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::RemoveGuild(self.guild_id)]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildUpdateEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        match cache.guild(self.guild.id).await {
+            Some(guild) => vec![MirrorOp::InsertGuild(guild)],
+            None => Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Feed for GuildEmojisUpdateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::SetEmojis(self.guild_id, self.emojis.clone())]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildStickersUpdateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::SetStickers(self.guild_id, self.stickers.clone())]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildRoleCreateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::InsertRole(self.role.clone())]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildRoleDeleteEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::RemoveRole(self.guild_id, self.role_id)]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildRoleUpdateEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::InsertRole(self.role.clone())]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildMemberAddEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        let mut ops = Vec::new();
+        feed_member(cache, self.guild_id, self.member.user.id, &mut ops).await;
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for GuildMemberRemoveEvent {
+    async fn feed(&self, _: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        vec![MirrorOp::RemoveMember(self.guild_id, self.user.id)]
+    }
+}
+
+#[async_trait]
+impl Feed for GuildMemberUpdateEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        let mut ops = Vec::new();
+        feed_member(cache, self.guild_id, self.user.id, &mut ops).await;
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for GuildMembersChunkEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        let mut ops = Vec::with_capacity(self.members.len() * 2);
+
+        for user_id in self.members.keys() {
+            feed_member(cache, self.guild_id, *user_id, &mut ops).await;
+        }
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for MessageCreateEvent {
+    async fn feed(&self, cache: &Cache, evicted: Option<&Self::Output>) -> Vec<MirrorOp> {
+        let mut ops = Vec::new();
+
+        if let Some(evicted) = evicted {
+            ops.push(MirrorOp::RemoveMessage(evicted.channel_id, evicted.id));
+        }
+
+        if let Some(message) = cache.message(self.message.channel_id, self.message.id).await {
+            ops.push(MirrorOp::InsertMessage(message));
+        }
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for MessageUpdateEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        match cache.message(self.channel_id, self.id).await {
+            Some(message) => vec![MirrorOp::InsertMessage(message)],
+            None => Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Feed for PresenceUpdateEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        if !cache.settings().await.cache_presences {
+            return Vec::new();
+        }
+
+        let user_id = self.presence.user_id;
+        let mut ops = Vec::new();
+
+        if self.presence.user.is_some() {
+            if let Some(user) = cache.user(user_id).await {
+                ops.push(MirrorOp::InsertUser(user));
+            }
+        }
+
+        if self.presence.status == OnlineStatus::Offline {
+            ops.push(MirrorOp::RemovePresence(user_id));
+        } else {
+            ops.push(MirrorOp::InsertPresence(self.presence.clone()));
+        }
+
+        ops
+    }
+}
+
+#[async_trait]
+impl Feed for PresencesReplaceEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        if !cache.settings().await.cache_presences {
+            return Vec::new();
+        }
+
+        self.presences.iter().cloned().map(MirrorOp::InsertPresence).collect()
+    }
+}
+
+#[async_trait]
+impl Feed for ReadyEvent {
+    async fn feed(&self, cache: &Cache, _: Option<&Self::Output>) -> Vec<MirrorOp> {
+        if !cache.settings().await.cache_presences {
+            return Vec::new();
+        }
+
+        self.ready.presences.values().cloned().map(MirrorOp::InsertPresence).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MirrorOp, CacheMirror, Feed, InMemoryMirror};
+    use crate::cache::Cache;
+    use crate::model::prelude::*;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn guild() -> Guild {
+        Guild {
+            id: GuildId(1),
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: vec![],
+            icon: None,
+            joined_at: DateTime::parse_from_rfc3339("2016-11-08T19:09:14.274+00:00").unwrap().with_timezone(&Utc),
+            large: false,
+            member_count: 0,
+            members: HashMap::new(),
+            mfa_level: MfaLevel::None,
+            name: String::new(),
+            owner_id: UserId(3),
+            presences: HashMap::new(),
+            region: String::new(),
+            roles: HashMap::new(),
+            splash: None,
+            discovery_splash: None,
+            system_channel_id: None,
+            verification_level: VerificationLevel::Low,
+            voice_states: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            channels: HashMap::new(),
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: None,
+            preferred_locale: "en-US".to_string(),
+            stickers: HashMap::new(),
+            _nonexhaustive: (),
+        }
+    }
+
+    fn role_create() -> GuildRoleCreateEvent {
+        serde_json::from_value(json!({
+            "guild_id": "1",
+            "role": {
+                "id": "4",
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "mentionable": false,
+                "name": "role",
+                "permissions": "0",
+                "position": 1,
+            },
+        })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn feeds_only_changed_role() {
+        let cache = Cache::default();
+        let mut guild_create = GuildCreateEvent {
+            guild: guild(),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut guild_create).await;
+
+        let mut role_create = role_create();
+        cache.update(&mut role_create).await;
+
+        let ops = role_create.feed(&cache, None).await;
+        assert_eq!(ops.len(), 1);
+
+        match ops[0] {
+            MirrorOp::InsertRole(ref role) => assert_eq!(role.id, RoleId(4)),
+            ref op => panic!("expected the role to be inserted, got {:?}", op),
+        }
+    }
+
+    #[tokio::test]
+    async fn feeds_mirror_in_background() {
+        let cache = Cache::default();
+        let mirror = Arc::new(InMemoryMirror::new());
+        cache.set_mirror(mirror.clone()).await;
+
+        let mut guild_create = GuildCreateEvent {
+            guild: guild(),
+            _nonexhaustive: (),
+        };
+        let output = cache.update(&mut guild_create).await;
+        cache.feed_mirror(&guild_create, output.as_ref()).await;
+
+        let mut role_create = role_create();
+        let output = cache.update(&mut role_create).await;
+        cache.feed_mirror(&role_create, output.as_ref()).await;
+
+        let fed = async {
+            loop {
+                if let Some(guild) = mirror.guild(GuildId(1)).await.unwrap() {
+                    if guild.roles.contains_key(&RoleId(4)) {
+                        break;
+                    }
+                }
+
+                tokio::time::delay_for(Duration::from_millis(1)).await;
+            }
+        };
+
+        tokio::time::timeout(Duration::from_secs(5), fed).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn in_memory_mirror_removes_guild_entities() {
+        let mirror = InMemoryMirror::new();
+
+        let user = User {
+            id: UserId(2),
+            avatar: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
+            bot: false,
+            discriminator: 1,
+            name: "user".to_string(),
+            _nonexhaustive: (),
+        };
+        let member = Member {
+            deaf: false,
+            guild_id: GuildId(1),
+            joined_at: None,
+            mute: false,
+            nick: None,
+            roles: vec![],
+            user: user.clone(),
+            communication_disabled_until: None,
+            pending: false,
+            _nonexhaustive: (),
+        };
+
+        mirror.insert_user(&user).await.unwrap();
+        mirror.insert_member(&member).await.unwrap();
+        assert!(mirror.member(GuildId(1), UserId(2)).await.unwrap().is_some());
+
+        mirror.remove_guild(GuildId(1)).await.unwrap();
+        assert!(mirror.member(GuildId(1), UserId(2)).await.unwrap().is_none());
+        assert_eq!(mirror.user(UserId(2)).await.unwrap().map(|u| u.name), Some("user".to_string()));
+    }
+}
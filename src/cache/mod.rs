@@ -46,14 +46,31 @@ use std::collections::{
     VecDeque,
 };
 use std::default::Default;
+use std::sync::Arc;
 use async_trait::async_trait;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
+mod mirror;
 mod cache_update;
+mod eviction;
+mod invites;
 mod settings;
+#[cfg(feature = "cache_snapshot")]
+mod snapshot;
+mod stats;
 
+pub use self::mirror::{CacheMirror, CacheMirrorError, InMemoryMirror};
 pub use self::cache_update::CacheUpdate;
-pub use self::settings::Settings;
+pub use self::invites::CachedInvite;
+pub use self::settings::{EvictionPolicy, Settings};
+pub use self::stats::{CacheStats, CollectionStats};
+
+use self::mirror::MirrorHandle;
+pub(crate) use self::mirror::Feed;
+use self::eviction::Recency;
+#[cfg(feature = "cache_snapshot")]
+use self::snapshot::Snapshot;
+use self::stats::Lookups;
 
 type MessageCache = HashMap<ChannelId, HashMap<MessageId, Message>>;
 
@@ -130,6 +147,9 @@ pub struct Cache {
     /// A map of direct message channels that the current user has open with
     /// other users.
     pub(crate) private_channels: RwLock<HashMap<ChannelId, PrivateChannel>>,
+    /// A map of the Ids of users to the Ids of the direct message channels
+    /// that the current user has open with them.
+    pub(crate) private_channel_ids: RwLock<HashMap<UserId, ChannelId>>,
     /// The total number of shards being used by the bot.
     pub(crate) shard_count: RwLock<u64>,
     /// A list of guilds which are "unavailable". Refer to the documentation for
@@ -181,6 +201,28 @@ pub struct Cache {
     /// inserted into the cache. When a maximum number of messages are in a
     /// channel's cache, we can pop the front and remove that ID from the cache.
     pub(crate) message_queue: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
+    /// When the cached users were last used, if they are bounded.
+    user_recency: RwLock<Recency<UserId>>,
+    /// When the cached members of each guild were last used, if they are
+    /// bounded.
+    member_recency: RwLock<HashMap<GuildId, Recency<UserId>>>,
+    /// When the cached presences of each guild were last used, if they are
+    /// bounded.
+    presence_recency: RwLock<HashMap<GuildId, Recency<UserId>>>,
+    /// The guilds restored from a snapshot that have not been received from
+    /// the gateway since, which is always empty without the `cache_snapshot`
+    /// feature.
+    pub(crate) restored_guilds: RwLock<HashSet<GuildId>>,
+    /// The invites of the guilds whose invites are tracked, by their codes.
+    ///
+    /// Refer to [`update_guild_invites`] for more information.
+    ///
+    /// [`update_guild_invites`]: #method.update_guild_invites
+    pub(crate) invites: RwLock<HashMap<GuildId, HashMap<String, CachedInvite>>>,
+    /// The mirror fed with the updates of the cache, if any.
+    mirror: RwLock<Option<MirrorHandle>>,
+    /// Counters of the lookups of each collection.
+    lookups: Lookups,
     /// The settings for the cache.
     settings: RwLock<Settings>,
 }
@@ -367,14 +409,14 @@ impl Cache {
     async fn _channel(&self, id: ChannelId) -> Option<Channel> {
         if let Some(channel) = self.channels.read().await.get(&id) {
             let channel = channel.clone();
-            return Some(Channel::Guild(channel));
+            return self.lookups.channels.record(Some(Channel::Guild(channel)));
         }
 
         if let Some(private_channel) = self.private_channels.read().await.get(&id).cloned() {
-            return Some(Channel::Private(private_channel));
+            return self.lookups.channels.record(Some(Channel::Private(private_channel)));
         }
 
-        None
+        self.lookups.channels.record(None)
     }
 
     /// Clones an entire guild from the cache based on the given `id`.
@@ -407,7 +449,7 @@ impl Cache {
     }
 
     async fn _guild(&self, id: GuildId) -> Option<Guild> {
-        self.guilds.read().await.get(&id).cloned()
+        self.lookups.guilds.record(self.guilds.read().await.get(&id).cloned())
     }
 
     /// This method allows to select a field of the guild instead of
@@ -435,7 +477,7 @@ impl Cache {
     async fn _guild_field<Ret, Fun>(&self, id: GuildId, field_accessor: Fun) -> Option<Ret>
     where Fun: FnOnce(&Guild) -> Ret {
         let guilds = self.guilds.read().await;
-        let guild = guilds.get(&id)?;
+        let guild = self.lookups.guilds.record(guilds.get(&id))?;
 
         Some(field_accessor(guild))
     }
@@ -499,7 +541,7 @@ impl Cache {
     }
 
     async fn _guild_channel(&self, id: ChannelId) -> Option<GuildChannel> {
-        self.channels.read().await.get(&id).cloned()
+        self.lookups.channels.record(self.channels.read().await.get(&id).cloned())
     }
 
     /// This method allows to only clone a field of the guild channel instead of
@@ -531,7 +573,7 @@ impl Cache {
         field_selector: Fun) -> Option<Ret>
     where Fun: FnOnce(&GuildChannel) -> Ret {
         let guild_channels = &self.channels.read().await;
-        let channel = guild_channels.get(&id)?;
+        let channel = self.lookups.channels.record(guild_channels.get(&id))?;
 
         Some(field_selector(channel))
     }
@@ -598,7 +640,7 @@ impl Cache {
     }
 
     async fn _member(&self, guild_id: GuildId, user_id: UserId) -> Option<Member> {
-        match self.guilds.read().await.get(&guild_id) {
+        let member = match self.guilds.read().await.get(&guild_id) {
             Some(guild) => {
                 guild
                     .members
@@ -606,7 +648,11 @@ impl Cache {
                     .cloned()
             }
             None => None,
-        }
+        }?;
+
+        self.refresh_member(guild_id, user_id).await;
+
+        Some(member)
     }
 
     /// This method allows to only clone a field of a member instead of
@@ -639,11 +685,17 @@ impl Cache {
         user_id: UserId,
         field_selector: Fun) -> Option<Ret>
     where Fun: FnOnce(&Member) -> Ret {
-        let guilds = &self.guilds.read().await;
-        let guild = guilds.get(&guild_id)?;
-        let member = guild.members.get(&user_id)?;
+        let field = {
+            let guilds = &self.guilds.read().await;
+            let guild = guilds.get(&guild_id)?;
+            let member = guild.members.get(&user_id)?;
 
-        Some(field_selector(member))
+            field_selector(member)
+        };
+
+        self.refresh_member(guild_id, user_id).await;
+
+        Some(field)
     }
 
     #[inline]
@@ -655,6 +707,61 @@ impl Cache {
         self.guilds.read().await.get(&guild_id).map(|g| g.roles.clone())
     }
 
+    /// Retrieves the tracked invites of a guild, by their codes.
+    ///
+    /// Returns `None` if the guild's invites are not tracked. Refer to
+    /// [`update_guild_invites`] for more information.
+    ///
+    /// [`update_guild_invites`]: #method.update_guild_invites
+    #[inline]
+    pub async fn guild_invites(&self, guild_id: impl Into<GuildId>) -> Option<HashMap<String, CachedInvite>> {
+        self._guild_invites(guild_id.into()).await
+    }
+
+    async fn _guild_invites(&self, guild_id: GuildId) -> Option<HashMap<String, CachedInvite>> {
+        self.invites.read().await.get(&guild_id).cloned()
+    }
+
+    /// Replaces the tracked invites of a guild with its current invites,
+    /// returning the invites whose number of uses increased since.
+    ///
+    /// The invites of a guild are only tracked once this was called with them,
+    /// as they are not sent by the gateway. Afterwards, created and deleted
+    /// invites are tracked with the [`InviteCreate`] and [`InviteDelete`]
+    /// events, until the guild is removed from the cache.
+    ///
+    /// This can be used to find out which invite a new member joined with, by
+    /// fetching the invites of the guild when a member joins. Refer to
+    /// [`GuildId::used_invites`] for doing so.
+    ///
+    /// **Note**: Invites reaching their maximum number of uses are deleted.
+    /// Such an invite is only found if it is still tracked, as the event of
+    /// its deletion may be received before the one of the member joining.
+    ///
+    /// [`GuildId::used_invites`]: ../model/id/struct.GuildId.html#method.used_invites
+    /// [`InviteCreate`]: ../model/event/enum.Event.html#variant.InviteCreate
+    /// [`InviteDelete`]: ../model/event/enum.Event.html#variant.InviteDelete
+    #[inline]
+    pub async fn update_guild_invites(&self, guild_id: impl Into<GuildId>, invites: &[RichInvite]) -> Vec<CachedInvite> {
+        self._update_guild_invites(guild_id.into(), invites).await
+    }
+
+    async fn _update_guild_invites(&self, guild_id: GuildId, invites: &[RichInvite]) -> Vec<CachedInvite> {
+        let current = invites
+            .iter()
+            .map(|invite| (invite.code.clone(), CachedInvite::from(invite)))
+            .collect::<HashMap<_, _>>();
+
+        let used = match self.invites.read().await.get(&guild_id) {
+            Some(tracked) => invites::used_invites(tracked, &current),
+            None => Vec::new(),
+        };
+
+        self.invites.write().await.insert(guild_id, current);
+
+        used
+    }
+
     /// This method clones and returns all unavailable guilds.
     #[inline]
     pub async fn unavailable_guilds(&self) -> HashSet<GuildId> {
@@ -721,9 +828,11 @@ impl Cache {
     }
 
     async fn _message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
-        self.messages.read().await.get(&channel_id).and_then(|messages| {
+        let message = self.messages.read().await.get(&channel_id).and_then(|messages| {
             messages.get(&message_id).cloned()
-        })
+        });
+
+        self.lookups.messages.record(message)
     }
 
     /// Retrieves a [`PrivateChannel`] from the cache's [`private_channels`]
@@ -763,6 +872,36 @@ impl Cache {
         self.private_channels.read().await.get(&channel_id).cloned()
     }
 
+    /// Retrieves the [`PrivateChannel`] that the current user has open with
+    /// the given user, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve the direct message channel with a user:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// #   let cache = Cache::default();
+    /// if let Some(channel) = cache.private_channel_by_recipient(7).await {
+    ///     println!("The channel with the user is {}", channel.id);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`PrivateChannel`]: ../model/channel/struct.PrivateChannel.html
+    #[inline]
+    pub async fn private_channel_by_recipient(&self, user_id: impl Into<UserId>) -> Option<PrivateChannel> {
+        self._private_channel_by_recipient(user_id.into()).await
+    }
+
+    async fn _private_channel_by_recipient(&self, user_id: UserId) -> Option<PrivateChannel> {
+        let channel_id = *self.private_channel_ids.read().await.get(&user_id)?;
+
+        self._private_channel(channel_id).await
+    }
+
     /// Retrieves a [`Guild`]'s role by their Ids.
     ///
     /// **Note**: This will clone the entire role. Instead, retrieve the guild
@@ -854,7 +993,11 @@ impl Cache {
     }
 
     async fn _user(&self, user_id: UserId) -> Option<User> {
-        self.users.read().await.get(&user_id).cloned()
+        let user = self.lookups.users.record(self.users.read().await.get(&user_id).cloned())?;
+
+        self.user_recency.write().await.refresh(user_id);
+
+        Some(user)
     }
 
     /// Clones all users and returns them.
@@ -943,6 +1086,287 @@ impl Cache {
         e.update(self).await
     }
 
+    /// Retrieves statistics of the collections of the cache: the number of
+    /// entries, their approximate memory usage, and how many lookups found an
+    /// entry.
+    ///
+    /// **Note**: Approximating the memory usage visits every entry of the
+    /// cache, so this should not be called frequently.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::default();
+    /// let stats = cache.stats().await;
+    ///
+    /// println!(
+    ///     "{} messages using ~{} KiB, {:?} of lookups found",
+    ///     stats.messages.count,
+    ///     stats.messages.approximate_bytes / 1024,
+    ///     stats.messages.hit_ratio(),
+    /// );
+    /// # }
+    /// ```
+    pub async fn stats(&self) -> CacheStats {
+        let guilds = {
+            let guilds = self.guilds.read().await;
+            let bytes = guilds.values().map(stats::guild_size).sum();
+
+            self.lookups.guilds.stats(guilds.len(), bytes)
+        };
+
+        let channels = {
+            let channels = self.channels.read().await;
+            let bytes = channels.values().map(stats::channel_size).sum();
+
+            self.lookups.channels.stats(channels.len(), bytes)
+        };
+
+        let users = {
+            let users = self.users.read().await;
+            let bytes = users.values().map(stats::user_size).sum();
+
+            self.lookups.users.stats(users.len(), bytes)
+        };
+
+        let messages = {
+            let messages = self.messages.read().await;
+            let count = messages.values().map(HashMap::len).sum();
+            let bytes = messages.values().flat_map(HashMap::values).map(stats::message_size).sum();
+
+            self.lookups.messages.stats(count, bytes)
+        };
+
+        let presences = {
+            let presences = self.presences.read().await;
+            let bytes = presences.values().map(stats::presence_size).sum();
+
+            CollectionStats {
+                count: presences.len(),
+                approximate_bytes: bytes,
+                ..Default::default()
+            }
+        };
+
+        CacheStats {
+            guilds,
+            channels,
+            users,
+            messages,
+            presences,
+        }
+    }
+
+    /// Serializes the guilds, channels, users and presences of the cache to a
+    /// compressed snapshot, to be restored with [`restore`] when the bot starts
+    /// again.
+    ///
+    /// Messages are not kept in the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// Write a snapshot to a file once the client stopped:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run(cache: &Cache) -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = cache.dump().await?;
+    /// std::fs::write("cache.snapshot", snapshot)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if an entity failed to serialize.
+    ///
+    /// [`restore`]: #method.restore
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    #[cfg(feature = "cache_snapshot")]
+    pub async fn dump(&self) -> crate::Result<Vec<u8>> {
+        let mut snapshot = Snapshot::new(self.current_user().await);
+
+        snapshot.guilds = self.guilds.read().await.values().cloned().collect();
+        snapshot.unavailable_guilds = self.unavailable_guilds.read().await.iter().copied().collect();
+        snapshot.categories = self.categories.read().await.values().cloned().collect();
+        snapshot.private_channels = self.private_channels.read().await.values().cloned().collect();
+        snapshot.users = self.users.read().await.values().cloned().collect();
+        snapshot.presences = self.presences.read().await.values().cloned().collect();
+
+        snapshot.encode()
+    }
+
+    /// Restores a snapshot created with [`dump`], so that guilds and channels
+    /// are available before the gateway sends them again.
+    ///
+    /// Entities that are already cached are left as is. Restored guilds are
+    /// replaced once they are received from the gateway, while those the
+    /// current user is no longer in are removed once the shard they belong to
+    /// is ready.
+    ///
+    /// # Examples
+    ///
+    /// Restore a snapshot before starting the client:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// #
+    /// # #[cfg(feature = "client")]
+    /// # async fn run(mut client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Ok(snapshot) = std::fs::read("cache.snapshot") {
+    ///     client.cache_and_http.cache.restore(&snapshot).await?;
+    /// }
+    ///
+    /// client.start().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the snapshot failed to decompress, an
+    /// [`Error::Json`] if it failed to deserialize, or an [`Error::Other`] if
+    /// it was created by an incompatible version of the library.
+    ///
+    /// [`dump`]: #method.dump
+    /// [`Error::Io`]: ../enum.Error.html#variant.Io
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::Other`]: ../enum.Error.html#variant.Other
+    #[cfg(feature = "cache_snapshot")]
+    pub async fn restore(&self, snapshot: &[u8]) -> crate::Result<()> {
+        let snapshot = Snapshot::decode(snapshot)?;
+        let settings = self.settings().await;
+
+        {
+            let mut user = self.user.write().await;
+
+            if user.id.0 == 0 {
+                *user = snapshot.user;
+            }
+        }
+
+        for user in &snapshot.users {
+            if !self.users.read().await.contains_key(&user.id) {
+                self.update_user_entry(user).await;
+            }
+        }
+
+        for mut guild in snapshot.guilds {
+            if self.guilds.read().await.contains_key(&guild.id) {
+                continue;
+            }
+
+            if !settings.cache_presences {
+                guild.presences.clear();
+            }
+
+            let member_ids = guild.members.keys().copied().collect::<Vec<_>>();
+            self.track_members(&mut guild, &member_ids).await;
+
+            let presence_ids = guild.presences.keys().copied().collect::<Vec<_>>();
+            self.track_presences(&mut guild, &presence_ids).await;
+
+            self.restored_guilds.write().await.insert(guild.id);
+
+            for (channel_id, channel) in &guild.channels {
+                self.channels.write().await.entry(*channel_id).or_insert_with(|| channel.clone());
+            }
+
+            self.guilds.write().await.insert(guild.id, guild);
+        }
+
+        {
+            let guilds = self.guilds.read().await;
+            let mut unavailable_guilds = self.unavailable_guilds.write().await;
+
+            for guild_id in snapshot.unavailable_guilds {
+                if !guilds.contains_key(&guild_id) {
+                    unavailable_guilds.insert(guild_id);
+                }
+            }
+        }
+
+        if settings.cache_categories {
+            let mut categories = self.categories.write().await;
+
+            for category in snapshot.categories {
+                categories.entry(category.id).or_insert(category);
+            }
+        }
+
+        {
+            let mut private_channels = self.private_channels.write().await;
+            let mut private_channel_ids = self.private_channel_ids.write().await;
+
+            for channel in snapshot.private_channels {
+                private_channel_ids.entry(channel.recipient.id).or_insert(channel.id);
+                private_channels.entry(channel.id).or_insert(channel);
+            }
+        }
+
+        if settings.cache_presences {
+            let mut presences = self.presences.write().await;
+
+            for presence in snapshot.presences {
+                presences.entry(presence.user_id).or_insert(presence);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets a mirror to feed with the entities received from the gateway,
+    /// replacing the previous mirror.
+    ///
+    /// The mirror is fed in addition to the cache, which keeps its entities
+    /// in memory and never reads from the mirror; bound the cache with
+    /// [`Settings`] to reduce its memory usage. Refer to [`CacheMirror`] for
+    /// more information.
+    ///
+    /// This spawns a task feeding the mirror, which ends once the mirror is
+    /// replaced and all changes queued for it have been applied.
+    ///
+    /// [`CacheMirror`]: trait.CacheMirror.html
+    /// [`Settings`]: struct.Settings.html
+    pub async fn set_mirror<B: CacheMirror + 'static>(&self, mirror: Arc<B>) {
+        *self.mirror.write().await = Some(MirrorHandle::spawn(mirror));
+    }
+
+    /// Queues the entities changed by an update that was applied to the cache
+    /// to be fed to the mirror, if any.
+    pub(crate) async fn feed_mirror<E: Feed>(&self, e: &E, output: Option<&E::Output>) {
+        let mirror = match self.mirror.read().await.clone() {
+            Some(mirror) => mirror,
+            None => return,
+        };
+
+        mirror.send(e.feed(self, output).await).await;
+    }
+
+    /// Inserts a direct message channel, indexing it by its recipient.
+    ///
+    /// Returns the channel previously cached under the same Id, if any.
+    pub(crate) async fn insert_private_channel(&self, channel: PrivateChannel) -> Option<PrivateChannel> {
+        self.private_channel_ids.write().await.insert(channel.recipient.id, channel.id);
+        self.private_channels.write().await.insert(channel.id, channel)
+    }
+
+    /// Removes a direct message channel and its index entry.
+    pub(crate) async fn remove_private_channel(&self, channel_id: ChannelId) -> Option<PrivateChannel> {
+        let channel = self.private_channels.write().await.remove(&channel_id)?;
+        let mut private_channel_ids = self.private_channel_ids.write().await;
+
+        if private_channel_ids.get(&channel.recipient.id) == Some(&channel_id) {
+            private_channel_ids.remove(&channel.recipient.id);
+        }
+
+        Some(channel)
+    }
+
     pub(crate) async fn update_user_entry(&self, user: &User) {
         match self.users.write().await.entry(user.id) {
             Entry::Vacant(e) => {
@@ -952,6 +1376,105 @@ impl Cache {
                 e.get_mut().clone_from(user);
             },
         }
+
+        let settings = self.settings.read().await;
+
+        if !settings.evicts(settings.max_users) {
+            return;
+        }
+
+        let mut recency = self.user_recency.write().await;
+        recency.touch(user.id);
+
+        let evicted = recency.evict(settings.max_users, settings.eviction);
+
+        if !evicted.is_empty() {
+            let mut users = self.users.write().await;
+
+            for user_id in evicted {
+                users.remove(&user_id);
+            }
+        }
+    }
+
+    /// Whether to cache a member of a guild, which is not the case if only
+    /// members in voice channels are cached and the member is not in one.
+    pub(crate) async fn caches_member(&self, guild_id: GuildId, user_id: UserId) -> bool {
+        if !self.settings.read().await.voice_members_only {
+            return true;
+        }
+
+        self.guilds
+            .read()
+            .await
+            .get(&guild_id)
+            .map_or(false, |g| g.voice_states.contains_key(&user_id))
+    }
+
+    /// Marks members of a guild as used, evicting members if they exceed the
+    /// bounds of the cache.
+    pub(crate) async fn track_members(&self, guild: &mut Guild, user_ids: &[UserId]) {
+        let settings = self.settings.read().await;
+
+        if !settings.evicts(settings.max_members) {
+            return;
+        }
+
+        let mut recencies = self.member_recency.write().await;
+        let recency = recencies.entry(guild.id).or_default();
+
+        for user_id in user_ids {
+            recency.touch(*user_id);
+        }
+
+        for user_id in recency.evict(settings.max_members, settings.eviction) {
+            guild.members.remove(&user_id);
+        }
+    }
+
+    /// Marks presences in a guild as used, evicting presences if they exceed
+    /// the bounds of the cache.
+    pub(crate) async fn track_presences(&self, guild: &mut Guild, user_ids: &[UserId]) {
+        let settings = self.settings.read().await;
+
+        if !settings.evicts(settings.max_presences) {
+            return;
+        }
+
+        let mut recencies = self.presence_recency.write().await;
+        let recency = recencies.entry(guild.id).or_default();
+
+        for user_id in user_ids {
+            recency.touch(*user_id);
+        }
+
+        for user_id in recency.evict(settings.max_presences, settings.eviction) {
+            guild.presences.remove(&user_id);
+        }
+    }
+
+    async fn refresh_member(&self, guild_id: GuildId, user_id: UserId) {
+        if let Some(recency) = self.member_recency.write().await.get_mut(&guild_id) {
+            recency.refresh(user_id);
+        }
+    }
+
+    pub(crate) async fn forget_member(&self, guild_id: GuildId, user_id: UserId) {
+        if let Some(recency) = self.member_recency.write().await.get_mut(&guild_id) {
+            recency.remove(&user_id);
+        }
+    }
+
+    pub(crate) async fn forget_presence(&self, guild_id: GuildId, user_id: UserId) {
+        if let Some(recency) = self.presence_recency.write().await.get_mut(&guild_id) {
+            recency.remove(&user_id);
+        }
+    }
+
+    pub(crate) async fn forget_guild(&self, guild_id: GuildId) {
+        self.member_recency.write().await.remove(&guild_id);
+        self.presence_recency.write().await.remove(&guild_id);
+        self.invites.write().await.remove(&guild_id);
     }
 }
 
@@ -964,12 +1487,20 @@ impl Default for Cache {
             messages: RwLock::new(HashMap::default()),
             presences: RwLock::new(HashMap::default()),
             private_channels: RwLock::new(HashMap::with_capacity(128)),
+            private_channel_ids: RwLock::new(HashMap::with_capacity(128)),
             settings: RwLock::new(Settings::default()),
             shard_count: RwLock::new(1),
             unavailable_guilds: RwLock::new(HashSet::default()),
             user: RwLock::new(CurrentUser::default()),
             users: RwLock::new(HashMap::default()),
             message_queue: RwLock::new(HashMap::default()),
+            user_recency: RwLock::new(Recency::default()),
+            member_recency: RwLock::new(HashMap::default()),
+            presence_recency: RwLock::new(HashMap::default()),
+            restored_guilds: RwLock::new(HashSet::default()),
+            invites: RwLock::new(HashMap::default()),
+            mirror: RwLock::new(None),
+            lookups: Lookups::default(),
         }
     }
 }
@@ -1003,6 +1534,9 @@ mod test {
                 author: User {
                     id: UserId(2),
                     avatar: None,
+                    banner: None,
+                    accent_colour: None,
+                    avatar_decoration: None,
                     bot: false,
                     discriminator: 1,
                     name: "user 1".to_owned(),
@@ -1022,13 +1556,15 @@ mod test {
                 nonce: Value::Number(Number::from(1)),
                 pinned: false,
                 reactions: vec![],
-                timestamp: datetime.clone(),
+                timestamp: datetime.into(),
                 tts: false,
                 webhook_id: None,
                 activity: None,
                 application: None,
                 message_reference: None,
                 flags: None,
+                sticker_items: Vec::new(),
+                poll: None,
                 _nonexhaustive: (),
             },
             _nonexhaustive: (),
@@ -1075,6 +1611,13 @@ mod test {
             user_limit: None,
             nsfw: false,
             slow_mode_rate: Some(0),
+            thread_metadata: None,
+            member: None,
+            owner_id: None,
+            message_count: None,
+            member_count: None,
+            rtc_region: None,
+            video_quality_mode: None,
             _nonexhaustive: (),
         };
 
@@ -1115,6 +1658,7 @@ mod test {
                     region: String::new(),
                     roles: HashMap::new(),
                     splash: None,
+                    discovery_splash: None,
                     system_channel_id: None,
                     verification_level: VerificationLevel::Low,
                     voice_states: HashMap::new(),
@@ -1125,6 +1669,7 @@ mod test {
                     banner: None,
                     vanity_url_code: Some("bruhmoment".to_string()),
                     preferred_locale: "en-US".to_string(),
+                    stickers: HashMap::new(),
                     _nonexhaustive: (),
                 },
                 _nonexhaustive: (),
@@ -1148,4 +1693,39 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.read().await.contains_key(&ChannelId(2)));
     }
+
+    #[tokio::test]
+    async fn test_private_channel_by_recipient() {
+        let cache = Cache::default();
+
+        let mut recipient = User::default();
+        recipient.id = UserId(2);
+
+        let channel = PrivateChannel {
+            id: ChannelId(1),
+            last_message_id: None,
+            last_pin_timestamp: None,
+            kind: ChannelType::Private,
+            recipient,
+            _nonexhaustive: (),
+        };
+
+        let mut create = ChannelCreateEvent {
+            channel: Channel::Private(channel.clone()),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut create).await;
+
+        let cached = cache.private_channel_by_recipient(UserId(2)).await.unwrap();
+        assert_eq!(cached.id, ChannelId(1));
+        assert!(cache.private_channel_by_recipient(UserId(3)).await.is_none());
+
+        let mut delete = ChannelDeleteEvent {
+            channel: Channel::Private(channel),
+            _nonexhaustive: (),
+        };
+        cache.update(&mut delete).await;
+
+        assert!(cache.private_channel_by_recipient(UserId(2)).await.is_none());
+    }
 }
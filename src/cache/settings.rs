@@ -1,3 +1,31 @@
+use std::time::Duration;
+
+/// How entries are evicted from the collections of the cache that are
+/// bounded.
+///
+/// Refer to [`Settings`] for the bounds of each collection.
+///
+/// [`Settings`]: struct.Settings.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EvictionPolicy {
+    /// Evicts the least recently used entries once a collection exceeds its
+    /// maximum size.
+    Lru,
+    /// Evicts the entries that have not been used for the given duration, as
+    /// well as the least recently used entries once a collection exceeds its
+    /// maximum size.
+    ///
+    /// Messages expire once they are older than the duration.
+    Ttl(Duration),
+}
+
+impl Default for EvictionPolicy {
+    fn default() -> Self {
+        EvictionPolicy::Lru
+    }
+}
+
 /// Settings for the cache.
 ///
 /// # Examples
@@ -10,6 +38,31 @@
 /// let mut settings = CacheSettings::new();
 /// settings.max_messages(10);
 /// ```
+///
+/// Skip caching presences and the content of messages:
+///
+/// ```rust
+/// use serenity::cache::Settings as CacheSettings;
+///
+/// let mut settings = CacheSettings::new();
+/// settings
+///     .max_messages(100)
+///     .cache_presences(false)
+///     .cache_message_content(false);
+/// ```
+///
+/// Bound the number of users and members, evicting those unused for an hour:
+///
+/// ```rust
+/// use serenity::cache::{EvictionPolicy, Settings as CacheSettings};
+/// use std::time::Duration;
+///
+/// let mut settings = CacheSettings::new();
+/// settings
+///     .max_users(50_000)
+///     .max_members(1_000)
+///     .eviction(EvictionPolicy::Ttl(Duration::from_secs(60 * 60)));
+/// ```
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Settings {
@@ -17,12 +70,65 @@ pub struct Settings {
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// The maximum number of members to store per guild.
+    ///
+    /// Defaults to `None`, storing every member received.
+    pub max_members: Option<usize>,
+    /// The maximum number of users to store.
+    ///
+    /// Defaults to `None`, storing every user received.
+    pub max_users: Option<usize>,
+    /// The maximum number of presences to store per guild.
+    ///
+    /// Defaults to `None`, storing every presence received.
+    pub max_presences: Option<usize>,
+    /// How entries are evicted from the bounded collections.
+    ///
+    /// Defaults to [`EvictionPolicy::Lru`].
+    ///
+    /// **Note**: Changing the policy from [`EvictionPolicy::Lru`], or bounding
+    /// a collection, only applies to entries cached afterwards.
+    ///
+    /// [`EvictionPolicy::Lru`]: enum.EvictionPolicy.html#variant.Lru
+    pub eviction: EvictionPolicy,
+    /// Whether to cache presences.
+    ///
+    /// Presence updates are not processed at all when disabled, so users are
+    /// not updated from them either.
+    ///
+    /// Defaults to `true`.
+    pub cache_presences: bool,
+    /// Whether to keep the content of cached messages.
+    ///
+    /// Defaults to `true`.
+    pub cache_message_content: bool,
+    /// Whether to cache channel categories.
+    ///
+    /// Defaults to `true`.
+    pub cache_categories: bool,
+    /// Whether to only cache members, and their users, while they are
+    /// connected to a voice channel of the guild.
+    ///
+    /// **Note**: Voice state updates do not include the member, so members
+    /// joining a voice channel are cached once their member is received
+    /// again, e.g. from a member update.
+    ///
+    /// Defaults to `false`.
+    pub voice_members_only: bool,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Settings {
             max_messages: usize::default(),
+            max_members: None,
+            max_users: None,
+            max_presences: None,
+            eviction: EvictionPolicy::default(),
+            cache_presences: true,
+            cache_message_content: true,
+            cache_categories: true,
+            voice_members_only: false,
         }
     }
 }
@@ -55,4 +161,98 @@ impl Settings {
 
         self
     }
+
+    /// Sets the maximum number of members to cache in a guild.
+    ///
+    /// Refer to [`max_members`] for more information.
+    ///
+    /// [`max_members`]: #structfield.max_members
+    pub fn max_members(&mut self, max: usize) -> &mut Self {
+        self.max_members = Some(max);
+
+        self
+    }
+
+    /// Sets the maximum number of users to cache.
+    ///
+    /// Refer to [`max_users`] for more information.
+    ///
+    /// [`max_users`]: #structfield.max_users
+    pub fn max_users(&mut self, max: usize) -> &mut Self {
+        self.max_users = Some(max);
+
+        self
+    }
+
+    /// Sets the maximum number of presences to cache in a guild.
+    ///
+    /// Refer to [`max_presences`] for more information.
+    ///
+    /// [`max_presences`]: #structfield.max_presences
+    pub fn max_presences(&mut self, max: usize) -> &mut Self {
+        self.max_presences = Some(max);
+
+        self
+    }
+
+    /// Sets how entries are evicted from the bounded collections.
+    ///
+    /// Refer to [`eviction`] for more information.
+    ///
+    /// [`eviction`]: #structfield.eviction
+    pub fn eviction(&mut self, policy: EvictionPolicy) -> &mut Self {
+        self.eviction = policy;
+
+        self
+    }
+
+    /// Sets whether to cache presences.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, cache: bool) -> &mut Self {
+        self.cache_presences = cache;
+
+        self
+    }
+
+    /// Sets whether to keep the content of cached messages.
+    ///
+    /// Refer to [`cache_message_content`] for more information.
+    ///
+    /// [`cache_message_content`]: #structfield.cache_message_content
+    pub fn cache_message_content(&mut self, cache: bool) -> &mut Self {
+        self.cache_message_content = cache;
+
+        self
+    }
+
+    /// Sets whether to cache channel categories.
+    ///
+    /// Refer to [`cache_categories`] for more information.
+    ///
+    /// [`cache_categories`]: #structfield.cache_categories
+    pub fn cache_categories(&mut self, cache: bool) -> &mut Self {
+        self.cache_categories = cache;
+
+        self
+    }
+
+    /// Sets whether to only cache members connected to a voice channel.
+    ///
+    /// Refer to [`voice_members_only`] for more information.
+    ///
+    /// [`voice_members_only`]: #structfield.voice_members_only
+    pub fn voice_members_only(&mut self, voice_only: bool) -> &mut Self {
+        self.voice_members_only = voice_only;
+
+        self
+    }
+
+    /// Whether entries of a collection with the given maximum size are
+    /// evicted, and their use must therefore be tracked.
+    pub(crate) fn evicts(&self, max: Option<usize>) -> bool {
+        max.is_some() || self.eviction != EvictionPolicy::Lru
+    }
 }
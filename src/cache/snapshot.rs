@@ -0,0 +1,118 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// The version of the snapshot format, bumped whenever snapshots of previous
+/// versions can no longer be restored.
+const VERSION: u8 = 1;
+
+/// The entities of the cache kept in a snapshot.
+///
+/// The channels of guilds are restored from the guilds themselves, while
+/// messages are left out as they are likely outdated by the time the snapshot
+/// is restored.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Snapshot {
+    pub(crate) version: u8,
+    pub(crate) user: CurrentUser,
+    pub(crate) guilds: Vec<Guild>,
+    pub(crate) unavailable_guilds: Vec<GuildId>,
+    pub(crate) categories: Vec<ChannelCategory>,
+    pub(crate) private_channels: Vec<PrivateChannel>,
+    pub(crate) users: Vec<User>,
+    pub(crate) presences: Vec<Presence>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(user: CurrentUser) -> Self {
+        Snapshot {
+            version: VERSION,
+            user,
+            guilds: Vec::new(),
+            unavailable_guilds: Vec::new(),
+            categories: Vec::new(),
+            private_channels: Vec::new(),
+            users: Vec::new(),
+            presences: Vec::new(),
+        }
+    }
+
+    /// Serializes the snapshot to JSON, compressed with zlib.
+    ///
+    /// JSON is used rather than a binary format as the models only deserialize
+    /// from self-describing formats.
+    pub(crate) fn encode(&self) -> Result<Vec<u8>> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, self)?;
+
+        Ok(encoder.finish()?)
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut json = Vec::new();
+        ZlibDecoder::new(bytes).read_to_end(&mut json)?;
+
+        let snapshot: Snapshot = serde_json::from_slice(&json)?;
+
+        if snapshot.version != VERSION {
+            return Err(Error::Other("Unsupported cache snapshot version"));
+        }
+
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::Cache;
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn restores_dumped_guilds() {
+        let guild: Guild = serde_json::from_value(json!({
+            "afk_timeout": 0,
+            "channels": [{
+                "id": "2",
+                "type": 0,
+                "name": "general",
+                "permission_overwrites": [],
+                "position": 0,
+            }],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "id": "1",
+            "joined_at": "2020-01-01T00:00:00+00:00",
+            "large": false,
+            "member_count": 0,
+            "members": [],
+            "mfa_level": 0,
+            "name": "guild",
+            "owner_id": "3",
+            "premium_tier": 0,
+            "presences": [],
+            "preferred_locale": "en-US",
+            "region": "europe",
+            "roles": [],
+            "stickers": [],
+            "verification_level": 0,
+            "voice_states": [],
+        })).unwrap();
+
+        let cache = Cache::new();
+        cache.guilds.write().await.insert(guild.id, guild);
+        let snapshot = cache.dump().await.unwrap();
+
+        let restored = Cache::new();
+        restored.restore(&snapshot).await.unwrap();
+
+        assert_eq!(restored.guild_field(1, |g| g.name.clone()).await.as_deref(), Some("guild"));
+        assert_eq!(restored.guild_channel_field(2, |c| c.name.clone()).await.as_deref(), Some("general"));
+        assert!(restored.restored_guilds.read().await.contains(&GuildId(1)));
+        assert!(restored.restore(b"not a snapshot").await.is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::model::prelude::*;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Statistics of a collection of the cache.
+///
+/// Refer to [`CacheStats`] for more information.
+///
+/// [`CacheStats`]: struct.CacheStats.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CollectionStats {
+    /// The number of entries in the collection.
+    pub count: usize,
+    /// The approximate number of bytes used by the entries.
+    pub approximate_bytes: usize,
+    /// The number of lookups that found an entry.
+    pub hits: u64,
+    /// The number of lookups that did not find an entry.
+    pub misses: u64,
+}
+
+impl CollectionStats {
+    /// The ratio of lookups that found an entry, `None` if there were no
+    /// lookups.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 {
+            return None;
+        }
+
+        Some(self.hits as f64 / lookups as f64)
+    }
+}
+
+/// Statistics of the collections of the cache, retrieved with
+/// [`Cache::stats`].
+///
+/// The approximate memory usage accounts for the entries and the text and
+/// collections they contain, but not for the overhead of the maps storing
+/// them. The lookups include those the library makes itself, e.g. when
+/// updating the cache.
+///
+/// [`Cache::stats`]: struct.Cache.html#method.stats
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// Statistics of the guilds, including their members, channels, roles,
+    /// emojis and presences.
+    pub guilds: CollectionStats,
+    /// Statistics of the channels of guilds.
+    pub channels: CollectionStats,
+    /// Statistics of the users.
+    pub users: CollectionStats,
+    /// Statistics of the messages, over all channels.
+    pub messages: CollectionStats,
+    /// Statistics of the presences of users outside of guilds.
+    pub presences: CollectionStats,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct LookupCounter {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl LookupCounter {
+    /// Counts a lookup, returning its result.
+    pub(crate) fn record<T>(&self, found: Option<T>) -> Option<T> {
+        let counter = if found.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        found
+    }
+
+    pub(crate) fn stats(&self, count: usize, approximate_bytes: usize) -> CollectionStats {
+        CollectionStats {
+            count,
+            approximate_bytes,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Counters of the lookups of each collection of the cache.
+#[derive(Debug, Default)]
+pub(crate) struct Lookups {
+    pub(crate) guilds: LookupCounter,
+    pub(crate) channels: LookupCounter,
+    pub(crate) users: LookupCounter,
+    pub(crate) messages: LookupCounter,
+}
+
+fn text_size(text: &Option<String>) -> usize {
+    text.as_ref().map_or(0, String::len)
+}
+
+pub(crate) fn user_size(user: &User) -> usize {
+    size_of::<User>() + user.name.len() + text_size(&user.avatar)
+}
+
+pub(crate) fn member_size(member: &Member) -> usize {
+    size_of::<Member>() - size_of::<User>()
+        + user_size(&member.user)
+        + text_size(&member.nick)
+        + member.roles.len() * size_of::<RoleId>()
+}
+
+pub(crate) fn channel_size(channel: &GuildChannel) -> usize {
+    size_of::<GuildChannel>()
+        + channel.name.len()
+        + text_size(&channel.topic)
+        + channel.permission_overwrites.len() * size_of::<PermissionOverwrite>()
+}
+
+pub(crate) fn presence_size(presence: &Presence) -> usize {
+    size_of::<Presence>() + presence.activities.len() * size_of::<Activity>()
+}
+
+pub(crate) fn message_size(message: &Message) -> usize {
+    size_of::<Message>() - size_of::<User>()
+        + user_size(&message.author)
+        + message.content.len()
+        + message.attachments.len() * size_of::<Attachment>()
+        + message.embeds.len() * size_of::<Embed>()
+}
+
+pub(crate) fn guild_size(guild: &Guild) -> usize {
+    size_of::<Guild>()
+        + guild.name.len()
+        + guild.members.values().map(member_size).sum::<usize>()
+        + guild.channels.values().map(channel_size).sum::<usize>()
+        + guild.presences.values().map(presence_size).sum::<usize>()
+        + guild.roles.values().map(|r| size_of::<Role>() + r.name.len()).sum::<usize>()
+        + guild.emojis.len() * size_of::<Emoji>()
+        + guild.voice_states.len() * size_of::<VoiceState>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LookupCounter;
+
+    #[test]
+    fn counts_lookups() {
+        let counter = LookupCounter::default();
+
+        assert_eq!(counter.record(Some(1)), Some(1));
+        assert_eq!(counter.record(None::<u8>), None);
+        counter.record(Some(2));
+
+        let stats = counter.stats(2, 16);
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+        assert_eq!(stats.hit_ratio(), Some(2.0 / 3.0));
+        assert_eq!(LookupCounter::default().stats(0, 0).hit_ratio(), None);
+    }
+}
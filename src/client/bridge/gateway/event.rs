@@ -3,13 +3,35 @@
 
 use super::ShardId;
 use crate::gateway::ConnectionStage;
+use std::time::Duration;
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug)]
 pub(crate) enum ClientEvent {
+    ShardReconnect(ShardReconnectEvent),
     ShardStageUpdate(ShardStageUpdateEvent),
 }
 
+/// An event denoting that a shard is about to attempt to reconnect, following
+/// the [`ReconnectPolicy`].
+///
+/// Frequent attempts indicate that the connection to the gateway is flapping.
+///
+/// [`ReconnectPolicy`]: ../../../../gateway/struct.ReconnectPolicy.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardReconnectEvent {
+    /// The ID of the shard reconnecting.
+    pub shard_id: ShardId,
+    /// The number of the attempt since the shard was last connected, counted
+    /// from 1.
+    pub attempt: u32,
+    /// The delay before the attempt is made.
+    pub delay: Duration,
+    /// Whether the shard resumes its session, rather than identifying anew.
+    pub resume: bool,
+}
+
 /// An event denoting that a shard's connection stage was changed.
 ///
 /// # Examples
@@ -0,0 +1,285 @@
+use crate::client::dispatch::DispatchEvent;
+use crate::client::InFlight;
+use crate::model::event::Event;
+use futures::{
+    channel::mpsc::{self, Sender},
+    future::BoxFuture,
+    SinkExt,
+    StreamExt,
+};
+use std::{
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// How events are dispatched to the [`EventHandler`].
+///
+/// Regardless of the strategy, commands of the framework are always
+/// dispatched in their own task.
+///
+/// [`EventHandler`]: ../../trait.EventHandler.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventDispatchStrategy {
+    /// Spawns a task for every event.
+    ///
+    /// Handlers run concurrently without limit, and events of a channel may be
+    /// handled out of order. This is the default.
+    Spawn,
+    /// Dispatches events to a fixed number of worker tasks, each handling its
+    /// events one after the other.
+    ///
+    /// Events of the same channel, such as messages and reactions, are always
+    /// handled by the same worker, in the order they were received. Other
+    /// events are distributed among the workers in turn.
+    ///
+    /// Once the queue of a worker is full, the shard receiving the event waits
+    /// for the worker to catch up.
+    ///
+    /// **Note**: While waiting, the shard receives no events, so collectors
+    /// receive none either. A handler waiting for a collector keeps its
+    /// worker busy: once the worker's queue is full, the shard waits for the
+    /// handler and the handler waits for the shard, and neither ever
+    /// finishes. Handlers must spawn a task to wait for collectors instead.
+    WorkerPool {
+        /// The number of workers, at least one.
+        workers: usize,
+        /// The number of events each worker queues.
+        queue_size: usize,
+    },
+    /// Handles events within the shard, one after the other.
+    ///
+    /// **Note**: The shard does not receive events while a handler runs, so
+    /// handlers must not wait for the events of collectors, or they never
+    /// finish.
+    Inline,
+}
+
+impl Default for EventDispatchStrategy {
+    fn default() -> Self {
+        EventDispatchStrategy::Spawn
+    }
+}
+
+type Job = BoxFuture<'static, ()>;
+
+/// Dispatches events according to an [`EventDispatchStrategy`], shared by all
+/// shards.
+///
+/// [`EventDispatchStrategy`]: enum.EventDispatchStrategy.html
+#[derive(Clone, Debug)]
+pub struct EventDispatcher {
+    pool: Option<Arc<WorkerPool>>,
+    strategy: EventDispatchStrategy,
+}
+
+impl EventDispatcher {
+    /// Creates a dispatcher, spawning the workers of a
+    /// [`EventDispatchStrategy::WorkerPool`].
+    ///
+    /// **Note**: This must be called within the Tokio runtime.
+    ///
+    /// [`EventDispatchStrategy::WorkerPool`]: enum.EventDispatchStrategy.html#variant.WorkerPool
+    pub fn new(strategy: EventDispatchStrategy) -> Self {
+        let pool = match strategy {
+            EventDispatchStrategy::WorkerPool { workers, queue_size } => {
+                Some(Arc::new(WorkerPool::new(workers, queue_size)))
+            },
+            _ => None,
+        };
+
+        EventDispatcher {
+            pool,
+            strategy,
+        }
+    }
+
+    /// The strategy events are dispatched with.
+    pub fn strategy(&self) -> EventDispatchStrategy {
+        self.strategy
+    }
+
+    /// Picks how the handlers of an event are run, tracking them as in
+    /// flight until they finished.
+    pub(crate) fn spawner(&self, event: &DispatchEvent, in_flight: &InFlight) -> Spawner {
+        let kind = match (&self.strategy, &self.pool) {
+            (EventDispatchStrategy::Inline, _) => SpawnKind::Inline,
+            (_, Some(pool)) => SpawnKind::Worker(pool.worker(channel_id(event))),
+            _ => SpawnKind::Spawn,
+        };
+
+        Spawner {
+            kind,
+            in_flight: in_flight.clone(),
+        }
+    }
+}
+
+impl Default for EventDispatcher {
+    fn default() -> Self {
+        EventDispatcher::new(EventDispatchStrategy::default())
+    }
+}
+
+struct WorkerPool {
+    workers: Vec<Sender<Job>>,
+    next: AtomicUsize,
+}
+
+impl WorkerPool {
+    fn new(workers: usize, queue_size: usize) -> Self {
+        let workers = (0..workers.max(1)).map(|_| {
+            let (tx, mut rx) = mpsc::channel::<Job>(queue_size);
+
+            tokio::spawn(async move {
+                while let Some(job) = rx.next().await {
+                    job.await;
+                }
+            });
+
+            tx
+        }).collect();
+
+        WorkerPool {
+            workers,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// The worker handling the events of a channel, or the next worker in
+    /// turn.
+    fn worker(&self, channel_id: Option<u64>) -> Sender<Job> {
+        let index = match channel_id {
+            Some(channel_id) => channel_id as usize,
+            None => self.next.fetch_add(1, Ordering::Relaxed),
+        };
+
+        self.workers[index % self.workers.len()].clone()
+    }
+}
+
+impl fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("workers", &self.workers.len())
+            .finish()
+    }
+}
+
+/// The channel whose events must be handled in order.
+fn channel_id(event: &DispatchEvent) -> Option<u64> {
+    let channel_id = match event {
+        DispatchEvent::Model(Event::MessageCreate(event)) => event.message.channel_id,
+        DispatchEvent::Model(Event::MessageUpdate(event)) => event.channel_id,
+        DispatchEvent::Model(Event::MessageDelete(event)) => event.channel_id,
+        DispatchEvent::Model(Event::MessageDeleteBulk(event)) => event.channel_id,
+        DispatchEvent::Model(Event::ReactionAdd(event)) => event.reaction.channel_id,
+        DispatchEvent::Model(Event::ReactionRemove(event)) => event.reaction.channel_id,
+        DispatchEvent::Model(Event::ReactionRemoveAll(event)) => event.channel_id,
+        DispatchEvent::Model(Event::TypingStart(event)) => event.channel_id,
+        DispatchEvent::Model(Event::ChannelPinsUpdate(event)) => event.channel_id,
+        _ => return None,
+    };
+
+    Some(channel_id.0)
+}
+
+/// Runs the handlers of a single event.
+pub(crate) struct Spawner {
+    kind: SpawnKind,
+    in_flight: InFlight,
+}
+
+enum SpawnKind {
+    Spawn,
+    Inline,
+    Worker(Sender<Job>),
+}
+
+impl Spawner {
+    pub(crate) async fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        // Queued handlers are in flight as well, as they still run.
+        let task = self.in_flight.start();
+        let future = async move {
+            future.await;
+            drop(task);
+        };
+
+        match &self.kind {
+            SpawnKind::Spawn => {
+                tokio::spawn(future);
+            },
+            SpawnKind::Inline => future.await,
+            SpawnKind::Worker(worker) => {
+                // The worker only stops once the dispatcher is dropped.
+                let _ = worker.clone().send(Box::pin(future)).await;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+
+    fn spawner(pool: &WorkerPool) -> Spawner {
+        Spawner {
+            kind: SpawnKind::Worker(pool.worker(Some(7))),
+            in_flight: InFlight::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn worker_pool_keeps_channel_order() {
+        let pool = WorkerPool::new(2, 4);
+        let (tx, rx) = oneshot::channel();
+        let order = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+
+        for i in 0..3 {
+            let order = Arc::clone(&order);
+            spawner(&pool).spawn(async move {
+                tokio::time::delay_for(std::time::Duration::from_millis(1)).await;
+                order.lock().await.push(i);
+            }).await;
+        }
+
+        spawner(&pool).spawn(async move {
+            let _ = tx.send(());
+        }).await;
+
+        rx.await.unwrap();
+        assert_eq!(*order.lock().await, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn handlers_are_in_flight_until_finished() {
+        let in_flight = InFlight::default();
+        let (tx, rx) = oneshot::channel::<()>();
+        let spawner = Spawner {
+            kind: SpawnKind::Spawn,
+            in_flight: in_flight.clone(),
+        };
+
+        spawner.spawn(async move {
+            let _ = rx.await;
+        }).await;
+
+        assert_eq!(in_flight.tasks(), 1);
+
+        tx.send(()).unwrap();
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while in_flight.tasks() > 0 {
+                tokio::time::delay_for(std::time::Duration::from_millis(1)).await;
+            }
+        }).await.unwrap();
+    }
+}
@@ -54,9 +54,12 @@ __impl_bitflags! {
         ///
         /// - GUILD_EMOJIS_UPDATE
         GUILD_EMOJIS = 1 << 3;
-        /// Enables following gateway event:
+        /// Enables following gateway events:
         ///
         /// - GUILD_INTEGRATIONS_UPDATE
+        /// - INTEGRATION_CREATE
+        /// - INTEGRATION_UPDATE
+        /// - INTEGRATION_DELETE
         GUILD_INTEGRATIONS = 1 << 4;
         /// Enables following gateway event:
         ///
@@ -118,6 +121,34 @@ __impl_bitflags! {
         ///
         /// - TYPING_START
         DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Enables following gateway events:
+        ///
+        /// - GUILD_SCHEDULED_EVENT_CREATE
+        /// - GUILD_SCHEDULED_EVENT_UPDATE
+        /// - GUILD_SCHEDULED_EVENT_DELETE
+        /// - GUILD_SCHEDULED_EVENT_USER_ADD
+        /// - GUILD_SCHEDULED_EVENT_USER_REMOVE
+        GUILD_SCHEDULED_EVENTS = 1 << 16;
+        /// Enables following gateway events:
+        ///
+        /// - AUTO_MODERATION_RULE_CREATE
+        /// - AUTO_MODERATION_RULE_UPDATE
+        /// - AUTO_MODERATION_RULE_DELETE
+        AUTO_MODERATION_CONFIGURATION = 1 << 20;
+        /// Enable following gateway event:
+        ///
+        /// - AUTO_MODERATION_ACTION_EXECUTION
+        AUTO_MODERATION_EXECUTION = 1 << 21;
+        /// Enables following gateway events:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        GUILD_MESSAGE_POLLS = 1 << 24;
+        /// Enables following gateway events:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        DIRECT_MESSAGE_POLLS = 1 << 25;
     }
 }
 
@@ -276,4 +307,44 @@ impl GatewayIntents {
     pub fn direct_message_typing(self) -> bool {
         self.contains(Self::DIRECT_MESSAGE_TYPING)
     }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [GUILD_SCHEDULED_EVENTS] intent.
+    ///
+    /// [GUILD_SCHEDULED_EVENTS]: #associatedconstant.GUILD_SCHEDULED_EVENTS
+    pub fn guild_scheduled_events(self) -> bool {
+        self.contains(Self::GUILD_SCHEDULED_EVENTS)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [AUTO_MODERATION_CONFIGURATION] intent.
+    ///
+    /// [AUTO_MODERATION_CONFIGURATION]: #associatedconstant.AUTO_MODERATION_CONFIGURATION
+    pub fn auto_moderation_configuration(self) -> bool {
+        self.contains(Self::AUTO_MODERATION_CONFIGURATION)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [AUTO_MODERATION_EXECUTION] intent.
+    ///
+    /// [AUTO_MODERATION_EXECUTION]: #associatedconstant.AUTO_MODERATION_EXECUTION
+    pub fn auto_moderation_execution(self) -> bool {
+        self.contains(Self::AUTO_MODERATION_EXECUTION)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [GUILD_MESSAGE_POLLS] intent.
+    ///
+    /// [GUILD_MESSAGE_POLLS]: #associatedconstant.GUILD_MESSAGE_POLLS
+    pub fn guild_message_polls(self) -> bool {
+        self.contains(Self::GUILD_MESSAGE_POLLS)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [DIRECT_MESSAGE_POLLS] intent.
+    ///
+    /// [DIRECT_MESSAGE_POLLS]: #associatedconstant.DIRECT_MESSAGE_POLLS
+    pub fn direct_message_polls(self) -> bool {
+        self.contains(Self::DIRECT_MESSAGE_POLLS)
+    }
 }
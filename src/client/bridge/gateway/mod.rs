@@ -50,6 +50,7 @@
 
 pub mod event;
 
+mod event_dispatcher;
 mod shard_manager;
 mod shard_manager_monitor;
 mod shard_messenger;
@@ -58,7 +59,9 @@ mod shard_runner;
 mod shard_runner_message;
 mod intents;
 
-pub use self::shard_manager::{ShardManager, ShardManagerOptions};
+pub use self::event_dispatcher::{EventDispatchStrategy, EventDispatcher};
+pub(crate) use self::event_dispatcher::Spawner;
+pub use self::shard_manager::{ReshardEvent, ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::{ShardManagerMonitor, ShardManagerError};
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
@@ -72,7 +75,7 @@ use std::{
         Formatter,
         Result as FmtResult
     },
-    time::Duration as StdDuration
+    time::{Duration as StdDuration, Instant},
 };
 use crate::gateway::ConnectionStage;
 
@@ -107,6 +110,8 @@ pub enum ShardManagerMessage {
     ShardUpdate {
         id: ShardId,
         latency: Option<StdDuration>,
+        last_heartbeat_ack: Option<Instant>,
+        reconnects: u64,
         stage: ConnectionStage,
     },
     /// Indicator that a [`ShardManagerMonitor`] should fully shutdown a shard
@@ -145,6 +150,11 @@ pub enum ShardManagerMessage {
     ///
     /// [`DisallowedGatewayIntents`]: ../../../gateway/enum.GatewayError.html#variant.DisallowedGatewayIntents
     ShardDisallowedGatewayIntents,
+    /// Indicator that a shard gave up reconnecting, as configured by the
+    /// [`ReconnectPolicy`].
+    ///
+    /// [`ReconnectPolicy`]: ../../../gateway/struct.ReconnectPolicy.html
+    ShardReconnectFailure(ShardId),
 }
 
 /// A message to be sent to the [`ShardQueuer`].
@@ -158,10 +168,17 @@ pub enum ShardQueuerMessage {
     /// Message to start a shard, where the 0-index element is the ID of the
     /// Shard to start and the 1-index element is the total shards in use.
     Start(ShardId, ShardId),
+    /// Message to start the given shards on standby onto a new total of
+    /// shards, the 1-index element, replacing all running shards once every
+    /// one of them is connected.
+    Reshard(Vec<ShardId>, ShardId),
     /// Message to shutdown the shard queuer.
     Shutdown,
     /// Message to dequeue/shutdown a shard.
     ShutdownShard(ShardId, u16),
+    /// Message to set the number of shards that may identify at the same
+    /// time.
+    SetMaxConcurrency(u64),
 }
 
 /// A light tuplestruct wrapper around a u64 to verify type correctness when
@@ -187,6 +204,11 @@ pub struct ShardRunnerInfo {
     /// The latency between when a heartbeat was sent and when the
     /// acknowledgement was received.
     pub latency: Option<StdDuration>,
+    /// When the last heartbeat acknowledgement was received.
+    pub last_heartbeat_ack: Option<Instant>,
+    /// The number of times the shard resumed or reconnected since the runner
+    /// was started.
+    pub reconnects: u64,
     /// The channel used to communicate with the shard runner, telling it
     /// what to do with regards to its status.
     pub runner_tx: ShardMessenger,
@@ -194,6 +216,23 @@ pub struct ShardRunnerInfo {
     pub stage: ConnectionStage,
 }
 
+/// The health of a shard, retrieved with [`ShardManager::runners`].
+///
+/// [`ShardManager::runners`]: struct.ShardManager.html#method.runners
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardHealth {
+    /// The latency between when the last heartbeat was sent and when its
+    /// acknowledgement was received.
+    pub latency: Option<StdDuration>,
+    /// When the last heartbeat acknowledgement was received.
+    pub last_heartbeat_ack: Option<Instant>,
+    /// The current connection stage of the shard.
+    pub stage: ConnectionStage,
+    /// The number of times the shard resumed, reconnected or was restarted.
+    pub reconnects: u64,
+}
+
 impl AsRef<ShardMessenger> for ShardRunnerInfo {
     fn as_ref(&self) -> &ShardMessenger {
         &self.runner_tx
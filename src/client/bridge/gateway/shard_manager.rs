@@ -1,31 +1,64 @@
 use crate::internal::prelude::*;
 use crate::CacheAndHttp;
-use tokio::time::timeout;
+use tokio::time::{delay_for, timeout, Duration};
 use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
 use std::{
     collections::{HashMap, VecDeque},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 use futures::channel::mpsc::{self, UnboundedSender as Sender, UnboundedReceiver as Receiver};
 use futures::StreamExt;
-use crate::client::{EventHandler, RawEventHandler};
+use crate::client::{EventHandler, InFlight, RawEventHandler, RawPayloadHandler};
+use crate::gateway::{ReconnectPolicy, SessionStore};
 use super::{
+    EventDispatchStrategy,
+    EventDispatcher,
     GatewayIntents,
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
     ShardQueuer,
+    ShardHealth,
     ShardQueuerMessage,
     ShardRunnerInfo,
 };
+use crate::model::{gateway::Activity, user::OnlineStatus};
 use tracing::{info, warn, instrument};
 
 use typemap_rev::TypeMap;
+#[cfg(feature = "cache")]
+use crate::cache::Cache;
+use crate::http::Http;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 
+/// The maximum number of guilds Discord allows a single shard to handle.
+#[cfg(feature = "cache")]
+const MAX_GUILDS_PER_SHARD: u64 = 2500;
+
+/// Information about the shards having been restarted onto a new total by
+/// [`ShardManager::check_shard_count`].
+///
+/// [`ShardManager::check_shard_count`]: struct.ShardManager.html#method.check_shard_count
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ReshardEvent {
+    /// The total number of shards used before.
+    pub old_total: u64,
+    /// The total number of shards restarted onto.
+    pub new_total: u64,
+    /// The number of shards recommended by Discord.
+    pub recommended: u64,
+    /// The number of shards that may identify at the same time.
+    pub max_concurrency: u64,
+}
+
 /// A manager for handling the status of shards by starting them, restarting
 /// them, and stopping them when required.
 ///
@@ -49,8 +82,9 @@ use crate::client::bridge::voice::VoiceGatewayManager;
 /// # async fn run() -> Result<(), Box<dyn Error>> {
 /// #
 /// use tokio::sync::{Mutex, RwLock};
-/// use serenity::client::bridge::gateway::{ShardManager, ShardManagerOptions, GatewayIntents};
+/// use serenity::client::bridge::gateway::{EventDispatchStrategy, ShardManager, ShardManagerOptions, GatewayIntents};
 /// use serenity::client::{EventHandler, RawEventHandler};
+/// use serenity::gateway::ReconnectPolicy;
 /// use serenity::http::Http;
 /// use serenity::CacheAndHttp;
 /// use serenity::prelude::*;
@@ -74,6 +108,7 @@ use crate::client::bridge::voice::VoiceGatewayManager;
 ///     data: &data,
 ///     event_handler: &Some(event_handler),
 ///     raw_event_handler: &None,
+///     raw_payload_handler: &None,
 ///     framework: &framework,
 ///     // the shard index to start initiating from
 ///     shard_index: 0,
@@ -86,6 +121,10 @@ use crate::client::bridge::voice::VoiceGatewayManager;
 ///     ws_url: &gateway_url,
 ///     # cache_and_http: &cache_and_http,
 ///     intents: GatewayIntents::non_privileged(),
+///     transport_compression: false,
+///     session_store: &None,
+///     reconnect_policy: ReconnectPolicy::default(),
+///     event_dispatch_strategy: EventDispatchStrategy::default(),
 /// });
 /// #     Ok(())
 /// # }
@@ -105,10 +144,20 @@ pub struct ShardManager {
     shard_index: u64,
     /// The number of shards to initialize.
     shard_init: u64,
-    /// The total shards in use, 1-indexed.
-    shard_total: u64,
+    /// The total shards in use, 1-indexed, updated by the queuer once the
+    /// shards of a new total replaced the running ones.
+    shard_total: Arc<AtomicU64>,
     shard_queuer: Sender<ShardQueuerMessage>,
     shard_shutdown: Receiver<ShardId>,
+    /// The number of times each shard was restarted.
+    restarts: HashMap<ShardId, u64>,
+    #[cfg(feature = "cache")]
+    cache: Arc<Cache>,
+    http: Arc<Http>,
+    in_flight: InFlight,
+    /// Whether sessions are stored, so that shards shut down gracefully may
+    /// resume them.
+    resumable: bool,
 }
 
 impl ShardManager {
@@ -120,14 +169,18 @@ impl ShardManager {
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
         let (shutdown_send, shutdown_recv) = mpsc::unbounded();
+        let shard_total = Arc::new(AtomicU64::new(opt.shard_total));
+        let retiring = Arc::new(Mutex::new(HashMap::new()));
 
         let mut shard_queuer = ShardQueuer {
             data: Arc::clone(opt.data),
             event_handler: opt.event_handler.as_ref().map(|h| Arc::clone(h)),
             raw_event_handler: opt.raw_event_handler.as_ref().map(|rh| Arc::clone(rh)),
+            raw_payload_handler: opt.raw_payload_handler.clone(),
             #[cfg(feature = "framework")]
             framework: Arc::clone(&opt.framework),
-            last_start: None,
+            last_starts: HashMap::new(),
+            max_concurrency: 1,
             manager_tx: thread_tx.clone(),
             queue: VecDeque::new(),
             runners: Arc::clone(&runners),
@@ -137,6 +190,13 @@ impl ShardManager {
             ws_url: Arc::clone(opt.ws_url),
             cache_and_http: Arc::clone(&opt.cache_and_http),
             intents: opt.intents,
+            transport_compression: opt.transport_compression,
+            session_store: opt.session_store.clone(),
+            reconnect_policy: Arc::new(opt.reconnect_policy),
+            event_dispatcher: EventDispatcher::new(opt.event_dispatch_strategy),
+            standby: None,
+            shard_total: Arc::clone(&shard_total),
+            retiring: Arc::clone(&retiring),
         };
 
         tokio::spawn(async move {
@@ -148,15 +208,22 @@ impl ShardManager {
             shard_index: opt.shard_index,
             shard_init: opt.shard_init,
             shard_queuer: shard_queue_tx,
-            shard_total: opt.shard_total,
+            shard_total,
             shard_shutdown: shutdown_recv,
+            restarts: HashMap::new(),
             runners,
+            #[cfg(feature = "cache")]
+            cache: Arc::clone(&opt.cache_and_http.cache),
+            http: Arc::clone(&opt.cache_and_http.http),
+            in_flight: opt.cache_and_http.in_flight.clone(),
+            resumable: opt.session_store.is_some(),
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
             rx: thread_rx,
             manager,
             shutdown: shutdown_send,
+            retiring,
         })
     }
 
@@ -180,7 +247,7 @@ impl ShardManager {
         let shard_to = self.shard_index + self.shard_init;
 
         for shard_id in self.shard_index..shard_to {
-            let shard_total = self.shard_total;
+            let shard_total = self.shard_total();
 
             self.boot([ShardId(shard_id), ShardId(shard_total)]);
         }
@@ -188,6 +255,21 @@ impl ShardManager {
         Ok(())
     }
 
+    /// Sets the number of shards that may identify at the same time, as
+    /// given by the `max_concurrency` of the [`SessionStartLimit`].
+    ///
+    /// Shards are started in parallel up to this number, rather than one
+    /// every 5 seconds. This is done by [`Client::start_autosharded`], while
+    /// it defaults to `1` otherwise.
+    ///
+    /// [`Client::start_autosharded`]: ../../struct.Client.html#method.start_autosharded
+    /// [`SessionStartLimit`]: ../../../model/gateway/struct.SessionStartLimit.html
+    #[instrument(skip(self))]
+    pub fn set_max_concurrency(&self, max_concurrency: u64) {
+        let msg = ShardQueuerMessage::SetMaxConcurrency(max_concurrency);
+        let _ = self.shard_queuer.unbounded_send(msg);
+    }
+
     /// Sets the new sharding information for the manager.
     ///
     /// This will shutdown all existing shards.
@@ -199,7 +281,147 @@ impl ShardManager {
 
         self.shard_index = index;
         self.shard_init = init;
-        self.shard_total = total;
+        self.shard_total.store(total, Ordering::Release);
+    }
+
+    /// The total number of shards in use.
+    ///
+    /// While resharding, this is the old total until the shards of the new
+    /// total replaced the running ones.
+    pub fn shard_total(&self) -> u64 {
+        self.shard_total.load(Ordering::Acquire)
+    }
+
+    /// Restarts the shards onto a new total number of shards.
+    ///
+    /// The shards of the new total are started alongside the running shards,
+    /// without dispatching events. Once every one of them is connected, they
+    /// replace the running shards, which are shut down. This way, shards of
+    /// different totals never dispatch events at the same time.
+    ///
+    /// If the manager is responsible for all shards, it will be responsible for
+    /// all shards of the new total. Otherwise, it keeps its range of shards.
+    #[instrument(skip(self))]
+    pub fn reshard(&mut self, total: u64) {
+        info!("Resharding from {} to {} shards", self.shard_total(), total);
+
+        if self.shard_index == 0 && self.shard_init == self.shard_total() {
+            self.shard_init = total;
+        }
+
+        let shard_to = self.shard_index + self.shard_init;
+        let shard_ids = (self.shard_index..shard_to.min(total)).map(ShardId).collect();
+
+        let msg = ShardQueuerMessage::Reshard(shard_ids, ShardId(total));
+        let _ = self.shard_queuer.unbounded_send(msg);
+    }
+
+    /// Checks whether more shards are needed, resharding onto the new total
+    /// via [`reshard`] if so.
+    ///
+    /// More shards are needed if Discord recommends more shards than are in
+    /// use, or, if the cache is enabled, the shards would handle more guilds
+    /// than allowed.
+    ///
+    /// Only a manager responsible for all shards reshards automatically, as
+    /// other processes would otherwise keep running shards of the old total.
+    /// Neither does it while the shards of a previous reshard are starting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the recommended number of shards could not
+    /// be retrieved.
+    ///
+    /// [`reshard`]: #method.reshard
+    /// [`Error::Http`]: ../../../enum.Error.html#variant.Http
+    #[instrument(skip(self))]
+    pub async fn check_shard_count(&mut self) -> Result<Option<ReshardEvent>> {
+        // While resharding, the manager is already responsible for the shards
+        // of the new total.
+        if self.shard_init == 0 || self.shard_index != 0 || self.shard_init != self.shard_total() {
+            return Ok(None);
+        }
+
+        let gateway = self.http.get_bot_gateway().await?;
+        let required = gateway.shards;
+
+        #[cfg(feature = "cache")]
+        let required = {
+            let guilds = self.cache.guilds().await.len() as u64;
+
+            required.max((guilds + MAX_GUILDS_PER_SHARD - 1) / MAX_GUILDS_PER_SHARD)
+        };
+
+        let old_total = self.shard_total();
+
+        if required <= old_total {
+            return Ok(None);
+        }
+
+        self.reshard(required);
+
+        Ok(Some(ReshardEvent {
+            old_total,
+            new_total: required,
+            recommended: gateway.shards,
+            max_concurrency: gateway.session_start_limit.max_concurrency,
+        }))
+    }
+
+    /// Spawns a task checking whether more shards are needed at every interval
+    /// via [`check_shard_count`], calling the `hook` after resharding.
+    ///
+    /// The task stops once the manager is dropped.
+    ///
+    /// # Examples
+    ///
+    /// Check the shard count every hour:
+    ///
+    /// ```rust,no_run
+    /// use serenity::client::bridge::gateway::ShardManager;
+    /// use serenity::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # async fn run(mut client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// ShardManager::spawn_reshard_watcher(
+    ///     &client.shard_manager,
+    ///     Duration::from_secs(60 * 60),
+    ///     |event| println!("Resharded from {} to {} shards", event.old_total, event.new_total),
+    /// );
+    ///
+    /// client.start_autosharded().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`check_shard_count`]: #method.check_shard_count
+    pub fn spawn_reshard_watcher<F>(
+        manager: &Arc<Mutex<ShardManager>>,
+        interval: Duration,
+        hook: F,
+    ) -> JoinHandle<()>
+    where F: Fn(ReshardEvent) + Send + Sync + 'static
+    {
+        let manager = Arc::downgrade(manager);
+
+        tokio::spawn(async move {
+            loop {
+                delay_for(interval).await;
+
+                let manager = match manager.upgrade() {
+                    Some(manager) => manager,
+                    None => break,
+                };
+
+                let result = manager.lock().await.check_shard_count().await;
+
+                match result {
+                    Ok(Some(event)) => hook(event),
+                    Ok(None) => {},
+                    Err(why) => warn!("Failed to check the shard count: {:?}", why),
+                }
+            }
+        })
     }
 
     /// Restarts a shard runner.
@@ -242,12 +464,98 @@ impl ShardManager {
     pub async fn restart(&mut self, shard_id: ShardId) {
         info!("Restarting shard {}", shard_id);
         self.shutdown(shard_id, 4000).await;
+        *self.restarts.entry(shard_id).or_default() += 1;
 
-        let shard_total = self.shard_total;
+        let shard_total = self.shard_total();
 
         self.boot([shard_id, ShardId(shard_total)]);
     }
 
+    /// Returns the health of the shards that have been instantiated, such as
+    /// their latency and how often they reconnected.
+    ///
+    /// # Examples
+    ///
+    /// Print the latency of every shard, e.g. in a status command:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// #
+    /// # async fn run(client: Client) {
+    /// for (shard_id, health) in client.shard_manager.lock().await.runners().await {
+    ///     println!("Shard {}: {:?} ({:?})", shard_id, health.latency, health.stage);
+    /// }
+    /// # }
+    /// ```
+    pub async fn runners(&self) -> HashMap<ShardId, ShardHealth> {
+        self.runners
+            .lock()
+            .await
+            .iter()
+            .map(|(shard_id, runner)| {
+                let restarts = self.restarts.get(shard_id).copied().unwrap_or_default();
+
+                (*shard_id, ShardHealth {
+                    latency: runner.latency,
+                    last_heartbeat_ack: runner.last_heartbeat_ack,
+                    stage: runner.stage,
+                    reconnects: runner.reconnects + restarts,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the average heartbeat latency of the shards, `None` if no shard
+    /// has received a heartbeat acknowledgement yet.
+    pub async fn latency(&self) -> Option<Duration> {
+        let runners = self.runners.lock().await;
+        let latencies = runners.values().filter_map(|r| r.latency).collect::<Vec<_>>();
+
+        if latencies.is_empty() {
+            return None;
+        }
+
+        Some(latencies.iter().sum::<Duration>() / latencies.len() as u32)
+    }
+
+    /// Sets the presence of a single shard, with any number of activities.
+    ///
+    /// Returns whether the shard is running. Refer to
+    /// [`ShardMessenger::set_activities`] for more information.
+    ///
+    /// [`ShardMessenger::set_activities`]: struct.ShardMessenger.html#method.set_activities
+    pub async fn set_presence(&self, shard_id: ShardId, activities: Vec<Activity>, status: OnlineStatus) -> bool {
+        match self.runners.lock().await.get(&shard_id) {
+            Some(runner) => {
+                runner.runner_tx.set_activities(activities, status);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Sets the presence of every running shard, with any number of
+    /// activities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::Client;
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// # async fn run(client: Client) {
+    /// let activities = vec![Activity::custom("Under maintenance")];
+    /// client.shard_manager.lock().await.set_presence_all(activities, OnlineStatus::Idle).await;
+    /// # }
+    /// ```
+    pub async fn set_presence_all(&self, activities: Vec<Activity>, status: OnlineStatus) {
+        for runner in self.runners.lock().await.values() {
+            runner.runner_tx.set_activities(activities.clone(), status);
+        }
+    }
+
     /// Returns the [`ShardId`]s of the shards that have been instantiated and
     /// currently have a valid [`ShardRunner`].
     ///
@@ -303,6 +611,10 @@ impl ShardManager {
     /// [`shutdown`]: #method.shutdown
     #[instrument(skip(self))]
     pub async fn shutdown_all(&mut self) {
+        self.shutdown_all_with(1000).await;
+    }
+
+    async fn shutdown_all_with(&mut self, code: u16) {
         let keys = {
             let runners = self.runners.lock().await;
 
@@ -316,13 +628,95 @@ impl ShardManager {
         info!("Shutting down all shards");
 
         for shard_id in keys {
-            self.shutdown(shard_id, 1000).await;
+            self.shutdown(shard_id, code).await;
         }
 
         let _ = self.shard_queuer.unbounded_send(ShardQueuerMessage::Shutdown);
         let _ = self.monitor_tx.unbounded_send(ShardManagerMessage::ShutdownInitiated);
     }
 
+    /// Shuts down all shards gracefully, waiting up to `timeout` for the
+    /// framework commands and event handlers in flight to finish first.
+    ///
+    /// New events are no longer dispatched to the event handlers and the
+    /// framework, but still update the cache. Running collectors are
+    /// cancelled, so that commands waiting for them finish. Once everything
+    /// finished or the timeout elapsed, all shards are shut down like with
+    /// [`shutdown_all`]. If a [`SessionStore`] is configured, they close with
+    /// a code keeping their sessions resumable instead.
+    ///
+    /// This takes the manager behind its mutex, which is only locked briefly,
+    /// so that commands may still lock it while finishing.
+    ///
+    /// Returns whether everything finished within the timeout.
+    ///
+    /// # Examples
+    ///
+    /// Shut down gracefully after one hour of operation:
+    ///
+    /// ```rust,no_run
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use serenity::client::bridge::gateway::ShardManager;
+    /// use serenity::client::{Client, EventHandler};
+    /// use std::time::Duration;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler { }
+    ///
+    /// let token = std::env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::builder(&token).event_handler(Handler).await?;
+    /// let shard_manager = client.shard_manager.clone();
+    ///
+    /// tokio::spawn(async move {
+    ///     tokio::time::delay_for(Duration::from_secs(60 * 60)).await;
+    ///
+    ///     if !ShardManager::shutdown_gracefully(&shard_manager, Duration::from_secs(30)).await {
+    ///         println!("Commands were still running at shutdown");
+    ///     }
+    /// });
+    ///
+    /// client.start().await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SessionStore`]: ../../../gateway/trait.SessionStore.html
+    /// [`shutdown_all`]: #method.shutdown_all
+    pub async fn shutdown_gracefully(manager: &Mutex<ShardManager>, timeout: Duration) -> bool {
+        let (in_flight, runners) = {
+            let manager = manager.lock().await;
+
+            (manager.in_flight.clone(), Arc::clone(&manager.runners))
+        };
+
+        info!("Draining shards");
+        in_flight.drain();
+
+        let drained = tokio::time::timeout(timeout, async {
+            loop {
+                // Tasks in flight may start new collectors while finishing.
+                cancel_collectors(&runners).await;
+
+                if in_flight.tasks() == 0 {
+                    break;
+                }
+
+                delay_for(Duration::from_millis(100)).await;
+            }
+        }).await.is_ok();
+
+        if !drained {
+            warn!("Timed out draining shards, {} tasks in flight", in_flight.tasks());
+        }
+
+        let mut manager = manager.lock().await;
+        let code = if manager.resumable { 4000 } else { 1000 };
+        manager.shutdown_all_with(code).await;
+
+        drained
+    }
+
     #[instrument(skip(self))]
     fn boot(&mut self, shard_info: [ShardId; 2]) {
         info!("Telling shard queuer to start shard {}", shard_info[0]);
@@ -332,6 +726,24 @@ impl ShardManager {
     }
 }
 
+/// Cancels the collectors of every shard runner.
+#[cfg(feature = "collector")]
+async fn cancel_collectors(runners: &Mutex<HashMap<ShardId, ShardRunnerInfo>>) {
+    let messengers = runners.lock().await
+        .values()
+        .map(|runner| runner.runner_tx.clone())
+        .collect::<Vec<_>>();
+
+    for messenger in messengers {
+        for collector in messenger.collectors().await {
+            messenger.cancel_collector(collector.id);
+        }
+    }
+}
+
+#[cfg(not(feature = "collector"))]
+async fn cancel_collectors(_: &Mutex<HashMap<ShardId, ShardRunnerInfo>>) {}
+
 impl Drop for ShardManager {
     /// A custom drop implementation to clean up after the manager.
     ///
@@ -350,6 +762,7 @@ pub struct ShardManagerOptions<'a> {
     pub data: &'a Arc<RwLock<TypeMap>>,
     pub event_handler: &'a Option<Arc<dyn EventHandler>>,
     pub raw_event_handler: &'a Option<Arc<dyn RawEventHandler>>,
+    pub raw_payload_handler: &'a Option<Arc<dyn RawPayloadHandler>>,
     #[cfg(feature = "framework")]
     pub framework: &'a Arc<Box<dyn Framework + Send + Sync>>,
     pub shard_index: u64,
@@ -360,4 +773,84 @@ pub struct ShardManagerOptions<'a> {
     pub ws_url: &'a Arc<Mutex<String>>,
     pub cache_and_http: &'a Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
+    pub transport_compression: bool,
+    pub session_store: &'a Option<Arc<dyn SessionStore>>,
+    pub reconnect_policy: ReconnectPolicy,
+    pub event_dispatch_strategy: EventDispatchStrategy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    fn manager(index: u64, init: u64, total: u64) -> (ShardManager, Receiver<ShardQueuerMessage>) {
+        let (monitor_tx, _) = mpsc::unbounded();
+        let (shard_queuer, queuer_rx) = mpsc::unbounded();
+        let (_, shard_shutdown) = mpsc::unbounded();
+        let cache_and_http = CacheAndHttp::default();
+
+        let manager = ShardManager {
+            monitor_tx,
+            runners: Arc::new(Mutex::new(HashMap::new())),
+            shard_index: index,
+            shard_init: init,
+            shard_total: Arc::new(AtomicU64::new(total)),
+            shard_queuer,
+            shard_shutdown,
+            restarts: HashMap::new(),
+            #[cfg(feature = "cache")]
+            cache: Arc::clone(&cache_and_http.cache),
+            http: Arc::clone(&cache_and_http.http),
+            in_flight: InFlight::default(),
+            resumable: false,
+        };
+
+        (manager, queuer_rx)
+    }
+
+    fn reshard_message(rx: &mut Receiver<ShardQueuerMessage>) -> (Vec<u64>, u64) {
+        match rx.next().now_or_never() {
+            Some(Some(ShardQueuerMessage::Reshard(ids, total))) => (ids.into_iter().map(|id| id.0).collect(), total.0),
+            other => panic!("expected a reshard, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reshards_all_shards_on_standby() {
+        let (mut manager, mut rx) = manager(0, 2, 2);
+        manager.reshard(4);
+
+        assert_eq!(reshard_message(&mut rx), (vec![0, 1, 2, 3], 4));
+        assert_eq!(manager.shard_init, 4);
+        // The old total stays in use until the new shards took over.
+        assert_eq!(manager.shard_total(), 2);
+    }
+
+    #[test]
+    fn reshards_own_range_only() {
+        let (mut manager, mut rx) = manager(2, 2, 4);
+        manager.reshard(8);
+
+        assert_eq!(reshard_message(&mut rx), (vec![2, 3], 8));
+        assert_eq!(manager.shard_init, 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_check_shard_count_of_other_processes() {
+        let (mut manager, mut rx) = manager(2, 2, 4);
+
+        assert!(manager.check_shard_count().await.unwrap().is_none());
+        assert!(rx.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn does_not_check_shard_count_while_resharding() {
+        let (mut manager, mut rx) = manager(0, 2, 2);
+        manager.reshard(4);
+        reshard_message(&mut rx);
+
+        assert!(manager.check_shard_count().await.unwrap().is_none());
+        assert!(rx.next().now_or_never().is_none());
+    }
 }
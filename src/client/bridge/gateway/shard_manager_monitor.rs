@@ -1,5 +1,5 @@
 use tokio::sync::Mutex;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use super::{ShardManager, ShardManagerMessage};
 use crate::client::bridge::gateway::ShardId;
 use tracing::{debug, warn, instrument};
@@ -24,6 +24,9 @@ pub struct ShardManagerMonitor {
     pub rx: Receiver<ShardManagerMessage>,
     /// The mpsc Sender channel to inform the manager that a shard has just properly shut down
     pub shutdown: Sender<ShardId>,
+    /// The number of shards of each ID replaced while resharding, whose
+    /// shutdown the manager does not wait for.
+    pub(crate) retiring: Arc<Mutex<HashMap<ShardId, usize>>>,
 }
 #[derive(Debug)]
 pub enum ShardManagerError {
@@ -40,6 +43,8 @@ pub enum ShardManagerError {
     ///
     /// [`DisallowedGatewayIntents`]: ../../../gateway/enum.GatewayError.html#variant.DisallowedGatewayIntents
     DisallowedGatewayIntents,
+    /// Returned when a shard gave up reconnecting.
+    ReconnectFailure(ShardId),
 }
 
 type Result<T> = std::result::Result<T, ShardManagerError>;
@@ -67,12 +72,14 @@ impl ShardManagerMonitor {
                     self.manager.lock().await.restart(shard_id).await;
                     let _  = self.shutdown.unbounded_send(shard_id);
                 },
-                ShardManagerMessage::ShardUpdate { id, latency, stage } => {
+                ShardManagerMessage::ShardUpdate { id, latency, last_heartbeat_ack, reconnects, stage } => {
                     let manager = self.manager.lock().await;
                     let mut runners = manager.runners.lock().await;
 
                     if let Some(runner) = runners.get_mut(&id) {
                         runner.latency = latency;
+                        runner.last_heartbeat_ack = last_heartbeat_ack;
+                        runner.reconnects = reconnects;
                         runner.stage = stage;
                     }
                 }
@@ -87,6 +94,12 @@ impl ShardManagerMonitor {
                 },
                 ShardManagerMessage::ShutdownInitiated => break,
                 ShardManagerMessage::ShutdownFinished(shard_id) => {
+                    if self.retire(shard_id).await {
+                        debug!("[ShardMonitor] Replaced shard {} shut down", shard_id);
+
+                        continue;
+                    }
+
                     if let Err(why) = self.shutdown.unbounded_send(shard_id) {
                         warn!(
                             "[ShardMonitor] Could not forward Shutdown signal to ShardManager for shard {}: {:#?}",
@@ -108,9 +121,33 @@ impl ShardManagerMonitor {
                     self.manager.lock().await.shutdown_all().await;
                     return Err(ShardManagerError::DisallowedGatewayIntents);
                 },
+                ShardManagerMessage::ShardReconnectFailure(shard_id) => {
+                    warn!("[ShardMonitor] Shard {} gave up reconnecting", shard_id);
+                    self.manager.lock().await.shutdown_all().await;
+                    return Err(ShardManagerError::ReconnectFailure(shard_id));
+                },
             }
         }
 
         Ok(())
     }
+
+    /// Counts the shutdown of a shard replaced while resharding, returning
+    /// whether the shard was one.
+    async fn retire(&self, shard_id: ShardId) -> bool {
+        let mut retiring = self.retiring.lock().await;
+
+        match retiring.get_mut(&shard_id) {
+            Some(count) => {
+                *count -= 1;
+
+                if *count == 0 {
+                    retiring.remove(&shard_id);
+                }
+
+                true
+            },
+            None => false,
+        }
+    }
 }
@@ -4,7 +4,22 @@ use super::{ShardClientMessage, ShardRunnerMessage, ChunkGuildFilter};
 use futures::channel::mpsc::{UnboundedSender as Sender, TrySendError};
 use async_tungstenite::tungstenite::Message;
 #[cfg(feature = "collector")]
-use crate::collector::{ReactionFilter, MessageFilter};
+use crate::collector::{CollectorId, CollectorInfo, EventFilter, ReactionFilter, MessageFilter};
+#[cfg(feature = "collector")]
+use crate::collector::GuildMembersChunkCollectorBuilder;
+#[cfg(feature = "collector")]
+use futures::{channel::mpsc, StreamExt};
+#[cfg(feature = "collector")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "collector")]
+use std::time::Duration;
+
+/// The number of member requests made by [`ShardMessenger::request_members`],
+/// used to give each request a unique nonce.
+///
+/// [`ShardMessenger::request_members`]: struct.ShardMessenger.html#method.request_members
+#[cfg(feature = "collector")]
+static MEMBER_REQUESTS: AtomicU64 = AtomicU64::new(0);
 
 /// A lightweight wrapper around an mpsc sender.
 ///
@@ -46,6 +61,12 @@ impl ShardMessenger {
     /// If the `cache` feature is enabled, the cache will automatically be
     /// updated with member chunks.
     ///
+    /// If `presences` is `true`, the chunks also contain the presences of the
+    /// members, which requires the `GUILD_PRESENCES` intent.
+    ///
+    /// To await the complete list of members instead, use
+    /// [`request_members`].
+    ///
     /// # Examples
     ///
     /// Chunk a single guild by Id, limiting to 2000 [`Member`]s, and not
@@ -65,7 +86,7 @@ impl ShardMessenger {
     /// #
     /// use serenity::model::id::GuildId;
     ///
-    /// shard.chunk_guild(GuildId(81384788765712384), Some(2000), ChunkGuildFilter::None, None);
+    /// shard.chunk_guild(GuildId(81384788765712384), Some(2000), ChunkGuildFilter::None, false, None);
     /// #     Ok(())
     /// # }
     /// ```
@@ -87,7 +108,7 @@ impl ShardMessenger {
     /// #
     /// use serenity::model::id::GuildId;
     ///
-    /// shard.chunk_guild(GuildId(81384788765712384), Some(20), ChunkGuildFilter::Query("do".to_owned()), Some("request"));
+    /// shard.chunk_guild(GuildId(81384788765712384), Some(20), ChunkGuildFilter::Query("do".to_owned()), false, Some("request"));
     /// #     Ok(())
     /// # }
     /// ```
@@ -95,21 +116,92 @@ impl ShardMessenger {
     /// [`Event::GuildMembersChunk`]: ../../../model/event/enum.Event.html#variant.GuildMembersChunk
     /// [`Guild`]: ../../../model/guild/struct.Guild.html
     /// [`Member`]: ../../../model/guild/struct.Member.html
+    /// [`request_members`]: #method.request_members
     pub fn chunk_guild(
         &self,
         guild_id: GuildId,
         limit: Option<u16>,
         filter: ChunkGuildFilter,
+        presences: bool,
         nonce: Option<String>,
     ) {
         let _ = self.send_to_shard(ShardRunnerMessage::ChunkGuild {
             guild_id,
             limit,
             filter,
+            presences,
             nonce,
         });
     }
 
+    /// Requests the members of a [`Guild`] like [`chunk_guild`], collecting
+    /// the [`Event::GuildMembersChunk`] events answering the request into a
+    /// complete member list.
+    ///
+    /// Returns `None` if not all chunks were received within the `timeout`,
+    /// e.g. because the shard is not running or lacks the required intents.
+    ///
+    /// # Examples
+    ///
+    /// Fetch the members whose name starts with `"do"`:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::bridge::gateway::ChunkGuildFilter;
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::id::GuildId;
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn run(ctx: Context) {
+    /// let filter = ChunkGuildFilter::Query("do".to_owned());
+    /// let members = ctx.shard
+    ///     .request_members(GuildId(81384788765712384), None, filter, Duration::from_secs(10))
+    ///     .await;
+    ///
+    /// if let Some(members) = members {
+    ///     println!("Found {} members", members.len());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`chunk_guild`]: #method.chunk_guild
+    /// [`Event::GuildMembersChunk`]: ../../../model/event/enum.Event.html#variant.GuildMembersChunk
+    /// [`Guild`]: ../../../model/guild/struct.Guild.html
+    #[cfg(feature = "collector")]
+    pub async fn request_members(
+        &self,
+        guild_id: GuildId,
+        limit: Option<u16>,
+        filter: ChunkGuildFilter,
+        timeout: Duration,
+    ) -> Option<Vec<Member>> {
+        let nonce = format!("members-{}", MEMBER_REQUESTS.fetch_add(1, Ordering::Relaxed));
+        let expected = nonce.clone();
+
+        // The filter is registered before the request is sent, as both are
+        // sent to the shard runner in order.
+        let mut chunks = GuildMembersChunkCollectorBuilder::new(self)
+            .guild_id(guild_id)
+            .filter(move |chunk| chunk.nonce.as_deref() == Some(expected.as_str()))
+            .timeout(timeout)
+            .await;
+
+        self.chunk_guild(guild_id, limit, filter, false, Some(nonce));
+
+        let mut members = Vec::new();
+        let mut received = 0;
+
+        while let Some(chunk) = chunks.next().await {
+            members.extend(chunk.members.values().cloned());
+            received += 1;
+
+            if received >= chunk.chunk_count {
+                return Some(members);
+            }
+        }
+
+        None
+    }
+
     /// Sets the user's current activity, if any.
     ///
     /// Other presence settings are maintained.
@@ -175,6 +267,45 @@ impl ShardMessenger {
         let _ = self.send_to_shard(ShardRunnerMessage::SetPresence(status, activity));
     }
 
+    /// Sets the user's full presence information like [`set_presence`], but
+    /// with any number of activities.
+    ///
+    /// Passing no activities clears the current ones.
+    ///
+    /// # Examples
+    ///
+    /// Set a custom status alongside a game being played:
+    ///
+    /// ```rust,no_run
+    /// # use tokio::sync::Mutex;
+    /// # use serenity::client::bridge::gateway::ShardMessenger;
+    /// #
+    /// # fn run(shard: ShardMessenger) {
+    /// use serenity::model::gateway::{Activity, ActivityEmoji};
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// let emoji = ActivityEmoji {
+    ///     name: "🎮".to_string(),
+    ///     id: None,
+    ///     animated: None,
+    /// };
+    ///
+    /// shard.set_activities(vec![
+    ///     Activity::custom("Taking requests").with_emoji(emoji),
+    ///     Activity::playing("Heroes of the Storm"),
+    /// ], OnlineStatus::Online);
+    /// # }
+    /// ```
+    ///
+    /// [`set_presence`]: #method.set_presence
+    pub fn set_activities(&self, activities: Vec<Activity>, mut status: OnlineStatus) {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        let _ = self.send_to_shard(ShardRunnerMessage::SetActivities(status, activities));
+    }
+
     /// Sets the user's current online status.
     ///
     /// Note that [`Offline`] is not a valid online status, so it is
@@ -216,6 +347,63 @@ impl ShardMessenger {
         let _ = self.send_to_shard(ShardRunnerMessage::SetStatus(online_status));
     }
 
+    /// Joins or moves to a voice channel of a guild.
+    ///
+    /// This only updates the voice state of the current user on Discord's
+    /// side. The resulting [`VoiceServerUpdateEvent`] and
+    /// [`VoiceStateUpdateEvent`] are forwarded to the [`VoiceGatewayManager`]
+    /// of the client, if any, which establishes the voice connection.
+    ///
+    /// # Examples
+    ///
+    /// Join a voice channel, deafened:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::bridge::gateway::ShardMessenger;
+    /// #
+    /// # fn run(shard: ShardMessenger) {
+    /// use serenity::model::id::{ChannelId, GuildId};
+    ///
+    /// shard.join_voice_channel(GuildId(81384788765712384), ChannelId(381880193700069377), false, true);
+    /// # }
+    /// ```
+    ///
+    /// [`VoiceGatewayManager`]: ../voice/trait.VoiceGatewayManager.html
+    /// [`VoiceServerUpdateEvent`]: ../../../model/event/struct.VoiceServerUpdateEvent.html
+    /// [`VoiceStateUpdateEvent`]: ../../../model/event/struct.VoiceStateUpdateEvent.html
+    pub fn join_voice_channel(&self, guild_id: GuildId, channel_id: ChannelId, self_mute: bool, self_deaf: bool) {
+        self.update_voice_state(guild_id, Some(channel_id), self_mute, self_deaf);
+    }
+
+    /// Leaves the current voice channel of a guild.
+    pub fn leave_voice_channel(&self, guild_id: GuildId) {
+        self.update_voice_state(guild_id, None, false, false);
+    }
+
+    /// Updates the voice state of the current user in a guild, which joins,
+    /// moves between or leaves voice channels and sets whether the user is
+    /// muted or deafened.
+    ///
+    /// Passing `None` as the `channel_id` leaves the current voice channel.
+    ///
+    /// Refer to [`join_voice_channel`] for more information.
+    ///
+    /// [`join_voice_channel`]: #method.join_voice_channel
+    pub fn update_voice_state(
+        &self,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) {
+        let _ = self.send_to_shard(ShardRunnerMessage::UpdateVoiceState {
+            guild_id,
+            channel_id,
+            self_mute,
+            self_deaf,
+        });
+    }
+
     /// Shuts down the websocket by attempting to cleanly close the
     /// connection.
     pub fn shutdown_clean(&self) {
@@ -254,6 +442,44 @@ impl ShardMessenger {
     pub fn set_reaction_filter(&self, collector: ReactionFilter) {
         let _ = self.send_to_shard(ShardRunnerMessage::SetReactionFilter(collector));
     }
+
+    /// Sets a new filter for an event collector.
+    #[cfg(feature = "collector")]
+    pub fn set_event_filter(&self, collector: EventFilter) {
+        let _ = self.send_to_shard(ShardRunnerMessage::SetEventFilter(collector));
+    }
+
+    /// Returns a snapshot of the collectors currently receiving from this
+    /// shard.
+    ///
+    /// Collectors that have been stopped or dropped are not listed.
+    ///
+    /// If the shard is not running, an empty list is returned.
+    #[cfg(feature = "collector")]
+    pub async fn collectors(&self) -> Vec<CollectorInfo> {
+        let (tx, mut rx) = mpsc::unbounded();
+
+        if self.send_to_shard(ShardRunnerMessage::ListCollectors(tx)).is_err() {
+            return Vec::new();
+        }
+
+        rx.next().await.unwrap_or_default()
+    }
+
+    /// Cancels a collector receiving from this shard, ending its stream.
+    ///
+    /// The `id` is returned by the collector's [`id`] method, or listed by
+    /// [`collectors`].
+    /// The collector's [`end_reason`] will be [`CollectorEnd::Cancelled`].
+    ///
+    /// [`id`]: ../../../collector/struct.EventCollector.html#method.id
+    /// [`collectors`]: #method.collectors
+    /// [`end_reason`]: ../../../collector/struct.EventCollector.html#method.end_reason
+    /// [`CollectorEnd::Cancelled`]: ../../../collector/enum.CollectorEnd.html#variant.Cancelled
+    #[cfg(feature = "collector")]
+    pub fn cancel_collector(&self, id: CollectorId) {
+        let _ = self.send_to_shard(ShardRunnerMessage::CancelCollector(id));
+    }
 }
 
 impl AsRef<ShardMessenger> for ShardMessenger {
@@ -1,18 +1,23 @@
-use crate::gateway::{InterMessage, Shard};
+use crate::gateway::{InterMessage, ReconnectPolicy, SessionStore, Shard};
 use crate::internal::prelude::*;
 use crate::CacheAndHttp;
 use tokio::sync::{Mutex, RwLock};
 use std::{
-    collections::{HashMap, VecDeque},
-    sync::Arc,
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 use futures::{
     StreamExt,
+    future::join_all,
     channel::mpsc::{UnboundedSender as Sender, UnboundedReceiver as Receiver},
 };
-use tokio::time::{delay_for, timeout, Duration, Instant};
-use crate::client::{EventHandler, RawEventHandler};
+use tokio::time::{timeout, Duration, Instant};
+use crate::client::{EventHandler, RawEventHandler, RawPayloadHandler};
 use super::{
+    EventDispatcher,
     GatewayIntents,
     ShardId,
     ShardClientMessage,
@@ -23,6 +28,7 @@ use super::{
     ShardRunnerInfo,
     ShardRunnerOptions,
 };
+use super::shard_runner::Standby;
 use crate::gateway::ConnectionStage;
 use tracing::{debug, info, warn, instrument};
 
@@ -56,13 +62,21 @@ pub struct ShardQueuer {
     ///
     /// [`Client`]: ../../struct.Client.html
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    /// A reference to a `RawPayloadHandler`, such as the one given to the
+    /// [`Client`].
+    ///
+    /// [`Client`]: ../../struct.Client.html
+    pub raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<Box<dyn Framework + Send + Sync>>,
-    /// The instant that a shard was last started.
+    /// The instants that a shard of each identify bucket was last started.
     ///
     /// This is used to determine how long to wait between shard IDENTIFYs.
-    pub last_start: Option<Instant>,
+    pub last_starts: HashMap<u64, Instant>,
+    /// The number of shards that may identify at the same time, each in its
+    /// own bucket.
+    pub max_concurrency: u64,
     /// A copy of the sender channel to communicate with the
     /// [`ShardManagerMonitor`].
     ///
@@ -83,6 +97,60 @@ pub struct ShardQueuer {
     pub ws_url: Arc<Mutex<String>>,
     pub cache_and_http: Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
+    /// Whether shards use `zlib-stream` transport compression.
+    pub transport_compression: bool,
+    /// The store to persist the sessions of shards in.
+    pub session_store: Option<Arc<dyn SessionStore>>,
+    /// How shards reconnect after losing their connection.
+    pub reconnect_policy: Arc<ReconnectPolicy>,
+    /// How events are dispatched to the event handler.
+    pub event_dispatcher: EventDispatcher,
+    /// The shards started on standby while resharding.
+    pub(crate) standby: Option<StandbyShards>,
+    /// The total number of shards in use, shared with the manager.
+    pub(crate) shard_total: Arc<AtomicU64>,
+    /// The number of replaced shards of each ID whose shutdown is yet to be
+    /// confirmed, shared with the [`ShardManagerMonitor`].
+    ///
+    /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
+    pub(crate) retiring: Arc<Mutex<HashMap<ShardId, usize>>>,
+}
+
+/// The shards started on standby onto a new total number of shards.
+pub(crate) struct StandbyShards {
+    total: u64,
+    runners: HashMap<ShardId, (ShardRunnerInfo, Arc<Standby>)>,
+}
+
+impl StandbyShards {
+    fn new(total: u64) -> Self {
+        Self {
+            total,
+            runners: HashMap::new(),
+        }
+    }
+
+    /// Removes the shards whose runner stopped, e.g. failing to connect,
+    /// returning their IDs to start them again.
+    fn take_stopped(&mut self) -> Vec<u64> {
+        let stopped = self.runners.iter()
+            .filter(|(_, (info, _))| info.runner_tx.tx.is_closed())
+            .map(|(id, _)| id.0)
+            .collect::<Vec<_>>();
+
+        for id in &stopped {
+            self.runners.remove(&ShardId(*id));
+        }
+
+        stopped
+    }
+
+    /// Whether every shard has been started and is connected, none of them
+    /// being queued anymore.
+    fn is_ready(&self, queue: &VecDeque<(u64, u64)>) -> bool {
+        queue.iter().all(|&(_, total)| total != self.total)
+            && self.runners.values().all(|(_, standby)| standby.is_ready())
+    }
 }
 
 impl ShardQueuer {
@@ -91,12 +159,18 @@ impl ShardQueuer {
     /// This will loop over the internal [`rx`] for [`ShardQueuerMessage`]s,
     /// blocking for messages on what to do.
     ///
-    /// If a [`ShardQueuerMessage::Start`] is received, this will:
+    /// If a [`ShardQueuerMessage::Start`] is received, the shard is queued.
+    /// Once no more messages are pending, this will:
+    ///
+    /// 1. Take the first queued shard of each identify bucket, a shard's
+    /// bucket being its ID modulo the [`max_concurrency`]
+    /// 2. Skip the buckets in which a shard was started less than 5 seconds
+    /// ago, as only one shard per bucket may identify at a time
+    /// 3. Start the taken shards in parallel
     ///
-    /// 1. Check how much time has passed since the last shard was started
-    /// 2. If the amount of time is less than the ratelimit, it will sleep until
-    /// that time has passed
-    /// 3. Start the shard by ID
+    /// If a [`ShardQueuerMessage::Reshard`] is received, the shards are queued
+    /// to start on standby. Once all of them are connected, they replace the
+    /// running shards, which are shut down.
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and
     /// the loop will be over.
@@ -105,18 +179,19 @@ impl ShardQueuer {
     /// nature of the loop.
     ///
     /// [`ShardQueuerMessage`]: enum.ShardQueuerMessage.html
+    /// [`ShardQueuerMessage::Reshard`]: enum.ShardQueuerMessage.html#variant.Reshard
     /// [`ShardQueuerMessage::Shutdown`]: enum.ShardQueuerMessage.html#variant.Shutdown
     /// [`ShardQueuerMessage::Start`]: enum.ShardQueuerMessage.html#variant.Start
+    /// [`max_concurrency`]: #structfield.max_concurrency
     /// [`rx`]: #structfield.rx
     #[instrument(skip(self))]
     pub async fn run(&mut self) {
-        // The duration to timeout from reads over the Rx channel. This can be
-        // done in a loop, and if the read times out then a shard can be
-        // started if one is presently waiting in the queue.
-        const TIMEOUT: Duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
-
         loop {
-            match timeout(TIMEOUT, self.rx.next()).await {
+            self.check_standby().await;
+
+            // Reads over the Rx channel time out once a queued shard may be
+            // started, which happens when no other message is pending.
+            match timeout(self.next_start_in(), self.rx.next()).await {
                 Ok(Some(ShardQueuerMessage::Shutdown)) => {
                     debug!("[Shard Queuer] Received to shutdown.");
                     self.shutdown_runners().await;
@@ -129,92 +204,226 @@ impl ShardQueuer {
                 },
                 Ok(Some(ShardQueuerMessage::Start(id, total))) => {
                     debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
-                    self.checked_start(id.0, total.0).await;
+                    self.queue.push_back((id.0, total.0));
+                },
+                Ok(Some(ShardQueuerMessage::Reshard(ids, total))) => {
+                    debug!("[Shard Queuer] Received to reshard onto {} shards.", total.0);
+                    self.shutdown_standby();
+                    self.standby = Some(StandbyShards::new(total.0));
+                    self.queue.extend(ids.into_iter().map(|id| (id.0, total.0)));
+                },
+                Ok(Some(ShardQueuerMessage::SetMaxConcurrency(max_concurrency))) => {
+                    debug!("[Shard Queuer] Received to start up to {} shards at a time.", max_concurrency);
+                    self.max_concurrency = max_concurrency.max(1);
                 },
                 Ok(None) => break,
                 Err(_) => {
-                    if let Some((id, total)) = self.queue.pop_front() {
-                        self.checked_start(id, total).await;
-                    }
+                    let batch = self.take_batch();
+                    self.start_batch(batch).await;
                 },
             }
         }
     }
 
-    #[instrument(skip(self))]
-    async fn check_last_start(&mut self) {
-        let instant = match self.last_start {
-            Some(instant) => instant,
+    /// The identify bucket of a shard.
+    fn bucket(&self, shard_id: u64) -> u64 {
+        shard_id % self.max_concurrency.max(1)
+    }
+
+    /// The time until a shard of the given bucket may be started.
+    fn bucket_ready_in(&self, bucket: u64) -> Duration {
+        // We must wait 5 seconds between IDENTIFYs of a bucket to avoid
+        // session invalidations.
+        let duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
+
+        match self.last_starts.get(&bucket) {
+            Some(instant) => duration.checked_sub(instant.elapsed()).unwrap_or_default(),
+            None => Duration::default(),
+        }
+    }
+
+    /// The time until a queued shard may be started.
+    fn next_start_in(&self) -> Duration {
+        self.queue.iter()
+            .map(|&(id, _)| self.bucket_ready_in(self.bucket(id)))
+            .min()
+            .unwrap_or_else(|| Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS))
+    }
+
+    /// Takes the first queued shard of every bucket that may start a shard.
+    fn take_batch(&mut self) -> Vec<(u64, u64)> {
+        let mut buckets = HashSet::new();
+        let mut batch = Vec::new();
+        let mut i = 0;
+
+        while i < self.queue.len() {
+            let bucket = self.bucket(self.queue[i].0);
+
+            if self.bucket_ready_in(bucket) == Duration::default() && buckets.insert(bucket) {
+                batch.extend(self.queue.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+
+        batch
+    }
+
+    /// Starts stopped standby shards again, and replaces the running shards
+    /// once every standby shard is connected.
+    async fn check_standby(&mut self) {
+        let standby = match &mut self.standby {
+            Some(standby) => standby,
             None => return,
         };
 
-        // We must wait 5 seconds between IDENTIFYs to avoid session
-        // invalidations.
-        let duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
-        let elapsed = instant.elapsed();
+        for id in standby.take_stopped() {
+            info!("[Shard Queuer] Re-queueing start of standby shard {}", id);
 
-        if elapsed >= duration {
+            self.queue.push_back((id, standby.total));
+        }
+
+        if !standby.is_ready(&self.queue) {
             return;
         }
 
-        let to_sleep = duration - elapsed;
+        if let Some(standby) = self.standby.take() {
+            self.swap(standby).await;
+        }
+    }
+
+    /// Shuts down the running shards, letting the standby shards take over.
+    #[instrument(skip(self, standby))]
+    async fn swap(&mut self, standby: StandbyShards) {
+        info!("[Shard Queuer] Swapping in {} standby shards", standby.runners.len());
+
+        let mut runners = self.runners.lock().await;
+        let mut retiring = self.retiring.lock().await;
+
+        for (shard_id, runner) in runners.drain() {
+            if send_shutdown(shard_id, &runner, 1000) {
+                *retiring.entry(shard_id).or_default() += 1;
+            }
+        }
+
+        for (shard_id, (mut runner, standby)) in standby.runners {
+            runner.stage = ConnectionStage::Connected;
+            standby.release();
 
-        delay_for(to_sleep).await;
+            runners.insert(shard_id, runner);
+        }
+
+        // Restarts of replaced shards may still have been queued.
+        self.queue.clear();
+        self.shard_total.store(standby.total, Ordering::Release);
+
+        #[cfg(feature = "cache")]
+        {
+            *self.cache_and_http.cache.shard_count.write().await = standby.total;
+        }
     }
 
-    #[instrument(skip(self))]
-    async fn checked_start(&mut self, id: u64, total: u64) {
-        debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
-        self.check_last_start().await;
+    /// Shuts down the shards started on standby, if any.
+    fn shutdown_standby(&mut self) {
+        if let Some(standby) = self.standby.take() {
+            for (shard_id, (runner, _)) in &standby.runners {
+                send_shutdown(*shard_id, runner, 1000);
+            }
 
-        if let Err(why) = self.start(id, total).await {
-            warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
-            info!("[Shard Queuer] Re-queueing start of shard {}", id);
+            let total = standby.total;
+            self.queue.retain(|&(_, t)| t != total);
+        }
+    }
 
-            self.queue.push_back((id, total));
+    /// Starts the shards in parallel, re-queueing those failing to start.
+    #[instrument(skip(self))]
+    async fn start_batch(&mut self, batch: Vec<(u64, u64)>) {
+        if batch.is_empty() {
+            return;
         }
 
-        self.last_start = Some(Instant::now());
+        debug!("[Shard Queuer] Starting shards {:?}", batch);
+
+        let results = join_all(batch.iter().map(|&(id, total)| self.start(id, total))).await;
+        let now = Instant::now();
+
+        for (&(id, total), result) in batch.iter().zip(results) {
+            let bucket = self.bucket(id);
+            self.last_starts.insert(bucket, now);
+
+            match result {
+                Ok((runner_info, Some(state))) => {
+                    if let Some(standby) = &mut self.standby {
+                        standby.runners.insert(ShardId(id), (runner_info, state));
+                    }
+                },
+                Ok((runner_info, None)) => {
+                    self.runners.lock().await.insert(ShardId(id), runner_info);
+                },
+                Err(why) => {
+                    warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
+                    info!("[Shard Queuer] Re-queueing start of shard {}", id);
+
+                    self.queue.push_back((id, total));
+                },
+            }
+        }
     }
 
+    /// Starts a shard, on standby if it is of the total being resharded onto.
     #[instrument(skip(self))]
-    async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
+    async fn start(&self, shard_id: u64, shard_total: u64) -> Result<(ShardRunnerInfo, Option<Arc<Standby>>)> {
         let shard_info = [shard_id, shard_total];
 
-        let shard = Shard::new(
+        let shard = Shard::new_with_compression(
             Arc::clone(&self.ws_url),
             &self.cache_and_http.http.token,
             shard_info,
             self.intents,
+            self.transport_compression,
         ).await?;
 
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
             event_handler: self.event_handler.as_ref().map(|eh| Arc::clone(eh)),
             raw_event_handler: self.raw_event_handler.as_ref().map(|rh| Arc::clone(rh)),
+            raw_payload_handler: self.raw_payload_handler.clone(),
             #[cfg(feature = "framework")]
             framework: Arc::clone(&self.framework),
             manager_tx: self.manager_tx.clone(),
             #[cfg(feature = "voice")]
             voice_manager: self.voice_manager.clone(),
+            session_store: self.session_store.clone(),
+            reconnect_policy: Arc::clone(&self.reconnect_policy),
+            event_dispatcher: self.event_dispatcher.clone(),
             shard,
             cache_and_http: Arc::clone(&self.cache_and_http),
         });
 
         let runner_info = ShardRunnerInfo {
             latency: None,
+            last_heartbeat_ack: None,
+            reconnects: 0,
             runner_tx: ShardMessenger::new(runner.runner_tx()),
             stage: ConnectionStage::Disconnected,
         };
 
+        let standby = match &self.standby {
+            Some(standby) if standby.total == shard_total => {
+                let state = Arc::new(Standby::default());
+                runner.set_standby(Arc::clone(&state));
+
+                Some(state)
+            },
+            _ => None,
+        };
+
         tokio::spawn(async move {
             let _ = runner.run().await;
             debug!("[ShardRunner {:?}] Stopping", runner.shard.shard_info());
         });
 
-        self.runners.lock().await.insert(ShardId(shard_id), runner_info);
-
-        Ok(())
+        Ok((runner_info, standby))
     }
 
     #[instrument(skip(self))]
@@ -234,6 +443,8 @@ impl ShardQueuer {
         for shard_id in keys {
             self.shutdown(shard_id, 1000).await;
         }
+
+        self.shutdown_standby();
     }
 
     /// Attempts to shut down the shard runner by Id.
@@ -247,17 +458,85 @@ impl ShardQueuer {
         info!("Shutting down shard {}", shard_id);
 
         if let Some(runner) = self.runners.lock().await.get(&shard_id) {
-            let shutdown = ShardManagerMessage::Shutdown(shard_id, code);
-            let client_msg = ShardClientMessage::Manager(shutdown);
-            let msg = InterMessage::Client(Box::new(client_msg));
-
-            if let Err(why) = runner.runner_tx.tx.unbounded_send(msg) {
-                warn!(
-                    "Failed to cleanly shutdown shard {} when sending message to shard runner: {:?}",
-                    shard_id,
-                    why,
-                );
-            }
+            send_shutdown(shard_id, runner, code);
         }
     }
 }
+
+/// Tells a shard runner to shut down, returning whether it is still running.
+fn send_shutdown(shard_id: ShardId, runner: &ShardRunnerInfo, code: u16) -> bool {
+    let shutdown = ShardManagerMessage::Shutdown(shard_id, code);
+    let client_msg = ShardClientMessage::Manager(shutdown);
+    let msg = InterMessage::Client(Box::new(client_msg));
+
+    match runner.runner_tx.tx.unbounded_send(msg) {
+        Ok(()) => true,
+        Err(why) => {
+            warn!(
+                "Failed to cleanly shutdown shard {} when sending message to shard runner: {:?}",
+                shard_id,
+                why,
+            );
+
+            false
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::mpsc;
+
+    fn standby_runner(shards: &mut StandbyShards, id: u64) -> (mpsc::UnboundedReceiver<InterMessage>, Arc<Standby>) {
+        let (tx, rx) = mpsc::unbounded();
+        let standby = Arc::new(Standby::default());
+        let info = ShardRunnerInfo {
+            latency: None,
+            last_heartbeat_ack: None,
+            reconnects: 0,
+            runner_tx: ShardMessenger::new(tx),
+            stage: ConnectionStage::Connecting,
+        };
+
+        shards.runners.insert(ShardId(id), (info, Arc::clone(&standby)));
+
+        (rx, standby)
+    }
+
+    #[test]
+    fn standby_is_ready_once_all_shards_started() {
+        let mut shards = StandbyShards::new(2);
+        let mut queue = VecDeque::new();
+        queue.push_back((1, 2));
+        let (_rx, first) = standby_runner(&mut shards, 0);
+        first.set_ready(true);
+
+        assert!(!shards.is_ready(&queue));
+
+        // Shards of the old total being restarted are of no concern.
+        queue.pop_front();
+        queue.push_back((0, 1));
+        let (_rx_1, second) = standby_runner(&mut shards, 1);
+
+        assert!(!shards.is_ready(&queue));
+
+        second.set_ready(true);
+
+        assert!(shards.is_ready(&queue));
+    }
+
+    #[test]
+    fn takes_stopped_standby_shards() {
+        let mut shards = StandbyShards::new(2);
+        let (rx, _) = standby_runner(&mut shards, 0);
+        let (_rx, _) = standby_runner(&mut shards, 1);
+
+        assert!(shards.take_stopped().is_empty());
+
+        drop(rx);
+
+        assert_eq!(shards.take_stopped(), vec![0]);
+        assert_eq!(shards.runners.len(), 1);
+    }
+}
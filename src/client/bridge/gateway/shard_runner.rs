@@ -1,20 +1,35 @@
-use crate::gateway::{InterMessage, ReconnectType, Shard, ShardAction, GatewayError};
+use crate::gateway::{
+    ConnectionStage,
+    InterMessage,
+    ReconnectPolicy,
+    ReconnectType,
+    SessionStore,
+    Shard,
+    ShardAction,
+    GatewayError,
+};
 use crate::internal::prelude::*;
-use crate::internal::ws_impl::{ReceiverExt, SenderExt};
+use crate::internal::ws_impl::SenderExt;
 use crate::model::event::{Event, GatewayEvent};
 use crate::CacheAndHttp;
 use tokio::sync::RwLock;
+use tokio::time::delay_for;
 use serde::Deserialize;
+use serde_json::Value;
 use std::{
     borrow::Cow,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::{SinkExt, StreamExt};
-use crate::client::dispatch::{DispatchEvent, dispatch};
-use crate::client::{EventHandler, RawEventHandler};
-use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use crate::client::dispatch::{DispatchEvent, context, dispatch};
+use crate::client::{Context, EventHandler, PayloadAction, RawEventHandler, RawPayloadHandler};
+use crate::constants::OpCode;
+use super::event::{ClientEvent, ShardReconnectEvent, ShardStageUpdateEvent};
+use super::{EventDispatcher, ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
 use async_tungstenite::tungstenite::{
     self,
     error::Error as TungsteniteError,
@@ -27,7 +42,7 @@ use crate::framework::Framework;
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionAction, ReactionFilter};
+use crate::collector::Filters;
 
 use tracing::{trace, error, debug, warn, instrument};
 
@@ -38,6 +53,7 @@ pub struct ShardRunner {
     data: Arc<RwLock<TypeMap>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
     #[cfg(feature = "framework")]
     framework: Arc<Box<dyn Framework + Send + Sync>>,
     manager_tx: Sender<ShardManagerMessage>,
@@ -48,11 +64,41 @@ pub struct ShardRunner {
     pub(crate) shard: Shard,
     #[cfg(feature = "voice")]
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
+    session_store: Option<Arc<dyn SessionStore>>,
+    reconnect_policy: Arc<ReconnectPolicy>,
+    /// The number of attempts to reconnect since the shard was last connected.
+    reconnect_attempts: u32,
+    event_dispatcher: EventDispatcher,
     cache_and_http: Arc<CacheAndHttp>,
     #[cfg(feature = "collector")]
-    message_filters: Vec<MessageFilter>,
-    #[cfg(feature = "collector")]
-    reaction_filters: Vec<ReactionFilter>,
+    filters: Filters,
+    standby: Option<Arc<Standby>>,
+}
+
+/// The state of a shard started on standby while resharding.
+///
+/// Until released, the runner neither dispatches events nor reports to the
+/// manager, so that it may connect alongside the shard it replaces.
+#[derive(Debug, Default)]
+pub(crate) struct Standby {
+    released: AtomicBool,
+    ready: AtomicBool,
+}
+
+impl Standby {
+    /// Whether the shard is connected and may replace the running shard.
+    pub(crate) fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn set_ready(&self, ready: bool) {
+        self.ready.store(ready, Ordering::Release);
+    }
+
+    /// Lets the runner take over from the shard it replaces.
+    pub(crate) fn release(&self) {
+        self.released.store(true, Ordering::Release);
+    }
 }
 
 impl ShardRunner {
@@ -66,17 +112,34 @@ impl ShardRunner {
             data: opt.data,
             event_handler: opt.event_handler,
             raw_event_handler: opt.raw_event_handler,
+            raw_payload_handler: opt.raw_payload_handler,
             #[cfg(feature = "framework")]
             framework: opt.framework,
             manager_tx: opt.manager_tx,
             shard: opt.shard,
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager,
+            session_store: opt.session_store,
+            reconnect_policy: opt.reconnect_policy,
+            reconnect_attempts: 0,
+            event_dispatcher: opt.event_dispatcher,
             cache_and_http: opt.cache_and_http,
             #[cfg(feature = "collector")]
-            message_filters: Vec::new(),
-            #[cfg(feature = "collector")]
-            reaction_filters: Vec::new(),
+            filters: Filters::default(),
+            standby: None,
+        }
+    }
+
+    /// Starts the runner on standby, until the given state is released.
+    pub(crate) fn set_standby(&mut self, standby: Arc<Standby>) {
+        self.standby = Some(standby);
+    }
+
+    /// Whether the runner is on standby and has not been released yet.
+    fn on_standby(&self) -> bool {
+        match &self.standby {
+            Some(standby) => !standby.released.load(Ordering::Acquire),
+            None => false,
         }
     }
 
@@ -110,6 +173,16 @@ impl ShardRunner {
     pub async fn run(&mut self) -> Result<()> {
         warn!("[ShardRunner {:?}] Running", self.shard.shard_info());
 
+        if let Err(why) = self.resume_stored_session().await {
+            warn!(
+                "[ShardRunner {:?}] Error resuming stored session: {:?}",
+                self.shard.shard_info(),
+                why,
+            );
+
+            return self.request_restart().await;
+        }
+
         loop {
             trace!("[ShardRunner {:?}] loop iteration started.", self.shard.shard_info());
             if !self.recv().await? {
@@ -127,12 +200,23 @@ impl ShardRunner {
             }
 
             let pre = self.shard.stage();
+            let last_ack = self.shard.last_heartbeat_ack().copied();
             let (event, action, successful) = self.recv_event().await?;
             let post = self.shard.stage();
 
+            if post == pre && self.shard.last_heartbeat_ack().copied() != last_ack {
+                self.update_manager();
+                self.save_session().await;
+            }
+
             if post != pre {
                 self.update_manager();
 
+                if post == ConnectionStage::Connected {
+                    self.reconnect_attempts = 0;
+                    self.save_session().await;
+                }
+
                 let e = ClientEvent::ShardStageUpdate(ShardStageUpdateEvent {
                     new: post,
                     old: pre,
@@ -147,15 +231,17 @@ impl ShardRunner {
                     return self.request_restart().await;
                 },
                 Some(other) => {
-                    let _ = self.action(&other).await;
+                    if let Err(why @ Error::Gateway(GatewayError::ReconnectFailure)) = self.action(&other).await {
+                        return Err(why);
+                    }
                 },
                 None => {},
             }
 
-            if let Some(event) = event {
+            if let Some(event) = event.filter(|_| !self.on_standby()) {
                 #[cfg(feature = "collector")]
                 {
-                    self.handle_filters(&event);
+                    self.filters.handle_event(&event, &self.cache_and_http).await;
                 }
 
                 self.dispatch(DispatchEvent::Model(event)).await;
@@ -168,59 +254,6 @@ impl ShardRunner {
         }
     }
 
-    /// Lets filters check the `event` to send them to collectors if the `event`
-    /// is accepted by them.
-    #[cfg(feature = "collector")]
-    fn handle_filters(&mut self, event: &Event) {
-        /// Unlike `Vec`'s `retain`, allows mutable references in `f`.
-        fn retain<T, F>(vec: &mut Vec<T>, mut f: F)
-        where
-            F: FnMut(&mut T) -> bool,
-        {
-            let len = vec.len();
-            let mut del = 0;
-            {
-                let v = &mut **vec;
-
-                for i in 0..len {
-
-                    if !f(&mut v[i]) {
-                        del += 1;
-                    } else if del > 0 {
-                        v.swap(i - del, i);
-                    }
-                }
-            }
-
-            if del > 0 {
-                vec.truncate(len - del);
-            }
-        }
-
-        // Avoid the clone if there is no message filter.
-        if !self.message_filters.is_empty() {
-
-            if let Event::MessageCreate(ref msg_event) = &event {
-                let msg = Arc::new(msg_event.message.clone());
-
-                retain(&mut self.message_filters, |f| f.send_message(&msg));
-            }
-        }
-
-        // Avoid the clone if there is no reacton filter.
-        if !self.reaction_filters.is_empty() {
-            let reaction = Arc::new(match &event {
-                Event::ReactionAdd(ref reaction_event) =>
-                    ReactionAction::Added(Arc::new(reaction_event.reaction.clone())),
-                Event::ReactionRemove(ref reaction_event) =>
-                    ReactionAction::Removed(Arc::new(reaction_event.reaction.clone())),
-                _ => return,
-            });
-
-            retain(&mut self.reaction_filters, |f| f.send_reaction(&reaction));
-        }
-    }
-
     /// Clones the internal copy of the Sender to the shard runner.
     pub(super) fn runner_tx(&self) -> Sender<InterMessage> {
         self.runner_tx.clone()
@@ -239,7 +272,7 @@ impl ShardRunner {
     async fn action(&mut self, action: &ShardAction) -> Result<()> {
         match *action {
             ShardAction::Reconnect(ReconnectType::Reidentify) => self.request_restart().await,
-            ShardAction::Reconnect(ReconnectType::Resume) => self.shard.resume().await,
+            ShardAction::Reconnect(ReconnectType::Resume) => self.resume().await,
             ShardAction::Heartbeat => self.shard.heartbeat().await,
             ShardAction::Identify => self.shard.identify().await,
         }
@@ -262,6 +295,14 @@ impl ShardRunner {
             return true;
         }
 
+        // Closing with 1000 invalidates the session, any other code keeps it
+        // resumable.
+        if close_code == 1000 {
+            self.remove_session().await;
+        } else {
+            self.save_session().await;
+        }
+
         // Send a Close Frame to Discord, which allows a bot to "log off"
         let _ = self.shard.client.close(Some(CloseFrame {
             code: close_code.into(),
@@ -284,7 +325,12 @@ impl ShardRunner {
             }
         }
 
-        // Inform the manager that shutdown for this shard has finished.
+        // Inform the manager that shutdown for this shard has finished, unless
+        // the shard never took over from the one it replaces.
+        if self.on_standby() {
+            return false;
+        }
+
         if let Err(why) = self.manager_tx.unbounded_send(ShardManagerMessage::ShutdownFinished(id)) {
             warn!(
                 "[ShardRunner {:?}] Could not send ShutdownFinished: {:#?}",
@@ -298,6 +344,10 @@ impl ShardRunner {
     #[inline]
     #[instrument(skip(self, event))]
     async fn dispatch(&self, event: DispatchEvent) {
+        if self.on_standby() {
+            return;
+        }
+
         dispatch(
             event,
             #[cfg(feature = "framework")]
@@ -308,6 +358,7 @@ impl ShardRunner {
             &self.runner_tx,
             self.shard.shard_info()[0],
             Arc::clone(&self.cache_and_http),
+            &self.event_dispatcher,
         ).await;
     }
 
@@ -351,7 +402,8 @@ impl ShardRunner {
                 },
                 ShardClientMessage::Manager(ShardManagerMessage::ShardDisallowedGatewayIntents)
                     | ShardClientMessage::Manager(ShardManagerMessage::ShardInvalidAuthentication)
-                    | ShardClientMessage::Manager(ShardManagerMessage::ShardInvalidGatewayIntents) => {
+                    | ShardClientMessage::Manager(ShardManagerMessage::ShardInvalidGatewayIntents)
+                    | ShardClientMessage::Manager(ShardManagerMessage::ShardReconnectFailure(_)) => {
                         // These variants should never be received.
                         warn!(
                             "[ShardRunner {:?}] Received a ShardError?",
@@ -360,11 +412,12 @@ impl ShardRunner {
 
                         true
                     },
-                ShardClientMessage::Runner(ShardRunnerMessage::ChunkGuild { guild_id, limit, filter, nonce }) => {
+                ShardClientMessage::Runner(ShardRunnerMessage::ChunkGuild { guild_id, limit, filter, presences, nonce }) => {
                     self.shard.chunk_guild(
                         guild_id,
                         limit,
                         filter,
+                        presences,
                         nonce.as_deref(),
                     ).await.is_ok()
                 },
@@ -402,20 +455,52 @@ impl ShardRunner {
 
                     self.shard.update_presence().await.is_ok()
                 },
+                ShardClientMessage::Runner(ShardRunnerMessage::SetActivities(status, activities)) => {
+                    self.shard.set_activities(activities);
+                    self.shard.set_status(status);
+
+                    self.shard.update_presence().await.is_ok()
+                },
                 ShardClientMessage::Runner(ShardRunnerMessage::SetStatus(status)) => {
                     self.shard.set_status(status);
 
                     self.shard.update_presence().await.is_ok()
                 },
+                ShardClientMessage::Runner(ShardRunnerMessage::UpdateVoiceState { guild_id, channel_id, self_mute, self_deaf }) => {
+                    self.shard.update_voice_state(
+                        guild_id,
+                        channel_id,
+                        self_mute,
+                        self_deaf,
+                    ).await.is_ok()
+                },
                 #[cfg(feature = "collector")]
                 ShardClientMessage::Runner(ShardRunnerMessage::SetMessageFilter(collector)) => {
-                    self.message_filters.push(collector);
+                    self.filters.message_filters.push(collector);
 
                     true
                 },
                 #[cfg(feature = "collector")]
                 ShardClientMessage::Runner(ShardRunnerMessage::SetReactionFilter(collector)) => {
-                    self.reaction_filters.push(collector);
+                    self.filters.reaction_filters.push(collector);
+
+                    true
+                },
+                #[cfg(feature = "collector")]
+                ShardClientMessage::Runner(ShardRunnerMessage::SetEventFilter(collector)) => {
+                    self.filters.event_filters.push(collector);
+
+                    true
+                },
+                #[cfg(feature = "collector")]
+                ShardClientMessage::Runner(ShardRunnerMessage::ListCollectors(tx)) => {
+                    let _ = tx.unbounded_send(self.filters.infos());
+
+                    true
+                },
+                #[cfg(feature = "collector")]
+                ShardClientMessage::Runner(ShardRunnerMessage::CancelCollector(id)) => {
+                    self.filters.cancel(id);
 
                     true
                 },
@@ -430,6 +515,10 @@ impl ShardRunner {
     #[cfg(feature = "voice")]
     #[instrument(skip(self))]
     async fn handle_voice_event(&self, event: &Event) {
+        if self.on_standby() {
+            return;
+        }
+
         if let Some(voice_manager) = &self.voice_manager {
             match *event {
                 Event::Ready(_) => {
@@ -494,8 +583,12 @@ impl ShardRunner {
     /// present event was successful.
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
-        let gw_event = match self.shard.client.recv_json().await {
+        let gw_event = match self.shard.recv_json().await {
             Ok(Some(value)) => {
+                if self.handle_raw_payload(&value).await {
+                    return Ok((None, None, true));
+                }
+
                 GatewayEvent::deserialize(value).map(Some).map_err(From::from)
             },
             Ok(None) => Ok(None),
@@ -505,9 +598,13 @@ impl ShardRunner {
                 match self.shard.reconnection_type() {
                     ReconnectType::Reidentify => return Ok((None, None, false)),
                     ReconnectType::Resume => {
-                        if let Err(why) = self.shard.resume().await {
+                        if let Err(why) = self.resume().await {
                             warn!("Failed to resume: {:?}", why);
 
+                            if let Error::Gateway(GatewayError::ReconnectFailure) = why {
+                                return Err(why);
+                            }
+
                             return Ok((None, None, false));
                         }
                     },
@@ -524,6 +621,20 @@ impl ShardRunner {
             Err(why) => Err(why),
         };
 
+        if let Err(Error::Gateway(GatewayError::Closed(Some(ref frame)))) = event {
+            let code = frame.code.into();
+
+            if self.reconnect_policy.is_fatal(code) {
+                warn!(
+                    "[ShardRunner {:?}] Closed with fatal code {}",
+                    self.shard.shard_info(),
+                    code,
+                );
+
+                return Err(self.give_up());
+            }
+        }
+
         let action = match self.shard.handle_event(&event) {
             Ok(Some(action)) => Some(action),
             Ok(None) => None,
@@ -579,15 +690,141 @@ impl ShardRunner {
         Ok((event, action, true))
     }
 
+    /// Passes a payload to the raw payload handler, if any.
+    ///
+    /// Returns whether the handler handled the payload, which must then not
+    /// be processed any further.
+    async fn handle_raw_payload(&mut self, payload: &Value) -> bool {
+        let handler = match &self.raw_payload_handler {
+            Some(handler) => Arc::clone(handler),
+            None => return false,
+        };
+
+        let op = match payload.get("op").and_then(Value::as_u64) {
+            Some(op) => op,
+            None => return false,
+        };
+
+        if handler.payload(self.context(), op, payload).await != PayloadAction::Handled
+            || op != OpCode::Event.num() {
+            return false;
+        }
+
+        match payload.get("t").and_then(Value::as_str) {
+            Some("READY") | Some("RESUMED") | None => return false,
+            Some(_) => {},
+        }
+
+        match payload.get("s").and_then(Value::as_u64) {
+            Some(seq) => {
+                self.shard.handle_raw_dispatch(seq);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn context(&self) -> Context {
+        #[cfg(not(feature = "cache"))]
+        let context = context(&self.data, &self.runner_tx, self.shard.shard_info()[0], &self.cache_and_http.http);
+        #[cfg(feature = "cache")]
+        let context = context(&self.data, &self.runner_tx, self.shard.shard_info()[0], &self.cache_and_http.http, &self.cache_and_http.cache);
+
+        context
+    }
+
+    /// Resumes the session of the shard, retrying as configured by the
+    /// reconnect policy.
+    async fn resume(&mut self) -> Result<()> {
+        loop {
+            self.wait_to_reconnect(true).await?;
+
+            match self.shard.resume().await {
+                Ok(()) => return Ok(()),
+                Err(why @ Error::Gateway(GatewayError::NoSessionId)) => return Err(why),
+                Err(why) => warn!(
+                    "[ShardRunner {:?}] Failed to resume: {:?}",
+                    self.shard.shard_info(),
+                    why,
+                ),
+            }
+        }
+    }
+
+    /// Counts an attempt to reconnect and waits for its backoff.
+    ///
+    /// Returns an error if the reconnect policy gives up on the attempt.
+    async fn wait_to_reconnect(&mut self, resume: bool) -> Result<()> {
+        self.reconnect_attempts += 1;
+        let attempt = self.reconnect_attempts;
+
+        if self.reconnect_policy.gives_up_at(attempt) {
+            return Err(self.give_up());
+        }
+
+        let delay = self.reconnect_policy.backoff(attempt);
+
+        debug!(
+            "[ShardRunner {:?}] Reconnect attempt {} in {:?}",
+            self.shard.shard_info(),
+            attempt,
+            delay,
+        );
+
+        let e = ClientEvent::ShardReconnect(ShardReconnectEvent {
+            shard_id: ShardId(self.shard.shard_info()[0]),
+            attempt,
+            delay,
+            resume,
+        });
+
+        self.dispatch(DispatchEvent::Client(e)).await;
+        delay_for(delay).await;
+
+        Ok(())
+    }
+
+    /// Tells the manager that the shard gave up reconnecting.
+    fn give_up(&self) -> Error {
+        warn!("[ShardRunner {:?}] Giving up reconnecting", self.shard.shard_info());
+
+        // The queuer starts a stopped standby shard again, while the running
+        // shards keep going.
+        if self.on_standby() {
+            return Error::Gateway(GatewayError::ReconnectFailure);
+        }
+
+        let msg = ShardManagerMessage::ShardReconnectFailure(ShardId(self.shard.shard_info()[0]));
+
+        if let Err(why) = self.manager_tx.unbounded_send(msg) {
+            warn!("Error sending reconnect failure: {:?}", why);
+        }
+
+        Error::Gateway(GatewayError::ReconnectFailure)
+    }
+
     #[instrument(skip(self))]
     async fn request_restart(&mut self) -> Result<()> {
         self.update_manager();
+        self.wait_to_reconnect(false).await?;
+
+        // The restarted shard identifies anew, rather than resuming a session
+        // that may be invalid.
+        self.remove_session().await;
 
         debug!(
             "[ShardRunner {:?}] Requesting restart",
             self.shard.shard_info(),
         );
         let shard_id = ShardId(self.shard.shard_info()[0]);
+
+        // A standby shard is started again by the queuer once it stopped,
+        // rather than restarting the running shard of the same ID.
+        if self.on_standby() {
+            return Ok(());
+        }
+
         let msg = ShardManagerMessage::Restart(shard_id);
 
         if let Err(error) = self.manager_tx.unbounded_send(msg) {
@@ -602,11 +839,82 @@ impl ShardRunner {
         Ok(())
     }
 
+    /// Resumes the session saved in the session store, if any, instead of
+    /// identifying.
+    ///
+    /// A session identified with another total number of shards, e.g. before
+    /// resharding, is removed instead, as it covers other guilds. A standby
+    /// shard keeps it, as it belongs to the shard still running.
+    async fn resume_stored_session(&mut self) -> Result<()> {
+        let store = match &self.session_store {
+            Some(store) => store,
+            None => return Ok(()),
+        };
+
+        let [shard_id, shard_total] = self.shard.shard_info();
+
+        match store.load(shard_id).await {
+            Some(session) if session.shard_total == shard_total => {
+                self.shard.resume_session(session).await?;
+            },
+            // On standby, the session is the one of the running shard.
+            Some(_) if self.on_standby() => {},
+            Some(_) => {
+                debug!("[ShardRunner {:?}] Not resuming a session of another shard total", self.shard.shard_info());
+
+                store.remove(shard_id).await;
+            },
+            None => {},
+        }
+
+        Ok(())
+    }
+
+    /// Saves the current session of the shard in the session store.
+    ///
+    /// A standby shard keeps the session of the running shard it replaces.
+    async fn save_session(&self) {
+        if self.on_standby() {
+            return;
+        }
+
+        if let (Some(store), Some(session)) = (&self.session_store, self.shard.session()) {
+            store.save(self.shard.shard_info()[0], session).await;
+        }
+    }
+
+    /// Removes the session of the shard from the session store.
+    ///
+    /// A session of another total number of shards is kept, as it belongs to
+    /// the shard of the same ID having replaced this one while resharding.
+    async fn remove_session(&self) {
+        let store = match &self.session_store {
+            Some(store) => store,
+            None => return,
+        };
+
+        let [shard_id, shard_total] = self.shard.shard_info();
+
+        if let Some(session) = store.load(shard_id).await {
+            if session.shard_total == shard_total {
+                store.remove(shard_id).await;
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     fn update_manager(&self) {
+        if let Some(standby) = self.standby.as_ref().filter(|_| self.on_standby()) {
+            standby.set_ready(self.shard.stage() == ConnectionStage::Connected);
+
+            return;
+        }
+
         let _ = self.manager_tx.unbounded_send(ShardManagerMessage::ShardUpdate {
             id: ShardId(self.shard.shard_info()[0]),
             latency: self.shard.latency(),
+            last_heartbeat_ack: self.shard.last_heartbeat_ack().copied(),
+            reconnects: self.shard.reconnects(),
             stage: self.shard.stage(),
         });
     }
@@ -619,11 +927,15 @@ pub struct ShardRunnerOptions {
     pub data: Arc<RwLock<TypeMap>>,
     pub event_handler: Option<Arc<dyn EventHandler>>,
     pub raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    pub raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
     #[cfg(feature = "framework")]
     pub framework: Arc<Box<dyn Framework + Send + Sync>>,
     pub manager_tx: Sender<ShardManagerMessage>,
     pub shard: Shard,
     #[cfg(feature = "voice")]
     pub voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync>>,
+    pub session_store: Option<Arc<dyn SessionStore>>,
+    pub reconnect_policy: Arc<ReconnectPolicy>,
+    pub event_dispatcher: EventDispatcher,
     pub cache_and_http: Arc<CacheAndHttp>,
 }
@@ -1,11 +1,13 @@
 use crate::model::{
     gateway::Activity,
-    id::{GuildId, UserId},
+    id::{ChannelId, GuildId, UserId},
     user::OnlineStatus,
 };
 
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionFilter};
+use crate::collector::{CollectorId, CollectorInfo, EventFilter, MessageFilter, ReactionFilter};
+#[cfg(feature = "collector")]
+use futures::channel::mpsc::UnboundedSender as Sender;
 use async_tungstenite::tungstenite::Message;
 
 #[derive(Clone, Debug)]
@@ -36,6 +38,9 @@ pub enum ShardRunnerMessage {
         limit: Option<u16>,
         /// A filter to apply to the returned members.
         filter: ChunkGuildFilter,
+        /// Whether to receive the presences of the members. Requires the
+        /// `GUILD_PRESENCES` intent.
+        presences: bool,
         /// Optional nonce to identify [`GuildMembersChunkEvent`] responses.
         ///
         /// [`GuildMembersChunkEvent`]: ../../../model/event/struct.GuildMembersChunkEvent.html
@@ -57,12 +62,38 @@ pub enum ShardRunnerMessage {
     /// Indicates that the client is to update the shard's presence in its
     /// entirity.
     SetPresence(OnlineStatus, Option<Activity>),
+    /// Indicates that the client is to update the shard's presence in its
+    /// entirity, with any number of activities.
+    SetActivities(OnlineStatus, Vec<Activity>),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
+    /// Indicates that the client is to join, move between or leave the voice
+    /// channels of a guild.
+    UpdateVoiceState {
+        /// The ID of the guild of the voice channel.
+        guild_id: GuildId,
+        /// The ID of the voice channel to join, `None` to leave the current
+        /// one.
+        channel_id: Option<ChannelId>,
+        /// Whether the current user is muted.
+        self_mute: bool,
+        /// Whether the current user is deafened.
+        self_deaf: bool,
+    },
     /// Sends a new filter for messages to the shard.
     #[cfg(feature = "collector")]
     SetMessageFilter(MessageFilter),
     /// Sends a new filter for reactions to the shard.
     #[cfg(feature = "collector")]
     SetReactionFilter(ReactionFilter),
+    /// Sends a new filter for events to the shard.
+    #[cfg(feature = "collector")]
+    SetEventFilter(EventFilter),
+    /// Requests a snapshot of the shard's active collectors, sent back over
+    /// the given channel.
+    #[cfg(feature = "collector")]
+    ListCollectors(Sender<Vec<CollectorInfo>>),
+    /// Cancels the collector with the given ID.
+    #[cfg(feature = "collector")]
+    CancelCollector(CollectorId),
 }
@@ -8,17 +8,43 @@ use crate::{
 	},
 };
 
-/// FIXME: must be documented.
-/// All these take read refs to allow struct to use its own concurrency access mechanisms.
+/// The gateway side of a voice connection, implemented by voice crates to
+/// receive the voice events of every shard.
+///
+/// The manager is set with [`ClientBuilder::voice_manager`]. Voice channels
+/// are joined by updating the voice state of the current user through the
+/// shard of the guild, e.g. with [`ShardMessenger::join_voice_channel`] on a
+/// messenger wrapping the sender given to [`register_shard`]. Discord then
+/// answers with the voice server and voice state updates forwarded to
+/// [`server_update`] and [`state_update`], which hold all the information
+/// needed to connect to the voice server.
+///
+/// All methods take `&self`, allowing implementations to use their own
+/// concurrency mechanisms.
+///
+/// [`ClientBuilder::voice_manager`]: ../../struct.ClientBuilder.html#method.voice_manager
+/// [`ShardMessenger::join_voice_channel`]: ../gateway/struct.ShardMessenger.html#method.join_voice_channel
+/// [`register_shard`]: #tymethod.register_shard
+/// [`server_update`]: #tymethod.server_update
+/// [`state_update`]: #tymethod.state_update
 #[async_trait]
 pub trait VoiceGatewayManager: Send + Sync {
+    /// Called once the current user is known, before any shard is registered.
     async fn initialise(&self, shard_count: u64, user_id: UserId);
 
+    /// Called when a shard is ready, with the sender to communicate with it.
     async fn register_shard(&self, shard_id: u64, sender: Sender<InterMessage>);
 
+    /// Called when a shard shuts down.
     async fn deregister_shard(&self, shard_id: u64);
 
+    /// Called with the endpoint and token of the voice server of a guild.
+    ///
+    /// An endpoint of `None` means the voice server went away, and a new one
+    /// will be sent once it has been allocated.
     async fn server_update(&self, guild_id: GuildId, endpoint: &Option<String>, token: &str);
 
+    /// Called when the voice state of a user in a guild changes, including
+    /// the current user's.
     async fn state_update(&self, guild_id: GuildId, voice_state: &VoiceState);
 }
@@ -12,7 +12,7 @@ use typemap_rev::TypeMap;
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionFilter};
+use crate::collector::{EventFilter, MessageFilter, ReactionFilter};
 
 /// The context is a general utility struct provided on event dispatches, which
 /// helps with dealing with the current "context" of the event dispatch.
@@ -388,6 +388,20 @@ impl Context {
         self.shard.set_presence(activity, status);
     }
 
+    /// Sets the current user's presence like [`set_presence`], but with any
+    /// number of activities, e.g. a custom status alongside a game.
+    ///
+    /// This only updates the presence of the current shard. To update every
+    /// shard, use [`ShardManager::set_presence_all`].
+    ///
+    /// [`set_presence`]: #method.set_presence
+    /// [`ShardManager::set_presence_all`]: bridge/gateway/struct.ShardManager.html#method.set_presence_all
+    #[cfg(feature = "gateway")]
+    #[inline]
+    pub async fn set_activities(&self, activities: Vec<Activity>, status: OnlineStatus) {
+        self.shard.set_activities(activities, status);
+    }
+
     /// Sets a new `filter` for the shard to check if a message event shall be
     /// sent back to `filter`'s paired receiver.
     #[inline]
@@ -403,6 +417,14 @@ impl Context {
     pub async fn set_reaction_filter(&self, filter: ReactionFilter) {
         self.shard.set_reaction_filter(filter);
     }
+
+    /// Sets a new `filter` for the shard to check if an event shall be
+    /// sent back to `filter`'s paired receiver.
+    #[inline]
+    #[cfg(feature = "collector")]
+    pub async fn set_event_filter(&self, filter: EventFilter) {
+        self.shard.set_event_filter(filter);
+    }
 }
 
 impl AsRef<Http> for Context {
@@ -13,7 +13,7 @@ use futures::{
 use tracing::instrument;
 #[cfg(feature = "gateway")]
 use super::{
-    bridge::gateway::event::ClientEvent,
+    bridge::gateway::{event::ClientEvent, EventDispatcher, Spawner},
     event_handler::{EventHandler, RawEventHandler},
 };
 use super::Context;
@@ -26,14 +26,17 @@ use crate::framework::Framework;
 #[cfg(feature = "cache")]
 use crate::model::id::GuildId;
 #[cfg(feature = "cache")]
-use crate::cache::{Cache, CacheUpdate};
+use crate::cache::{Cache, Feed};
 #[cfg(feature = "cache")]
 use std::fmt;
 
 #[inline]
 #[cfg(feature = "cache")]
-async fn update<E: CacheUpdate + fmt::Debug>(cache_and_http: &Arc<CacheAndHttp>, event: &mut E) -> Option<E::Output> {
-    cache_and_http.cache.update(event).await
+async fn update<E: Feed + fmt::Debug>(cache_and_http: &Arc<CacheAndHttp>, event: &mut E) -> Option<E::Output> {
+    let output = cache_and_http.cache.update(event).await;
+    cache_and_http.cache.feed_mirror(event, output.as_ref()).await;
+
+    output
 }
 
 #[inline]
@@ -43,7 +46,7 @@ async fn update<E>(_cache_and_http: &Arc<CacheAndHttp>, _event: &mut E) -> Optio
 }
 
 #[cfg(feature = "cache")]
-fn context(
+pub(crate) fn context(
     data: &Arc<RwLock<TypeMap>>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
@@ -54,7 +57,7 @@ fn context(
 }
 
 #[cfg(not(feature = "cache"))]
-fn context(
+pub(crate) fn context(
     data: &Arc<RwLock<TypeMap>>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
@@ -114,9 +117,18 @@ impl DispatchEvent {
             Self::Model(Event::GuildRoleUpdate(ref mut event)) => {
                 update(cache_and_http, event).await;
             },
+            Self::Model(Event::GuildStickersUpdate(ref mut event)) => {
+                update(cache_and_http, event).await;
+            },
             Self::Model(Event::GuildUnavailable(ref mut event)) => {
                 update(cache_and_http, event).await;
             },
+            Self::Model(Event::InviteCreate(ref mut event)) => {
+                update(cache_and_http, event).await;
+            },
+            Self::Model(Event::InviteDelete(ref mut event)) => {
+                update(cache_and_http, event).await;
+            },
             // Already handled by the framework check macro
             Self::Model(Event::MessageCreate(_)) => {},
             Self::Model(Event::MessageUpdate(ref mut event)) => {
@@ -154,8 +166,39 @@ pub(crate) fn dispatch<'rec>(
     runner_tx: &'rec Sender<InterMessage>,
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
+    event_dispatcher: &'rec EventDispatcher,
 ) -> BoxFuture<'rec, ()> {
     async move {
+        // While shutting down gracefully, only the cache is kept up to date.
+        if cache_and_http.in_flight.is_draining() {
+            event.update(&cache_and_http).await;
+
+            return;
+        }
+
+        let spawner = event_dispatcher.spawner(&event, &cache_and_http.in_flight);
+
+        #[cfg(feature = "framework")]
+        {
+            if let DispatchEvent::Model(Event::MessageUpdate(ref event)) = event {
+                if framework.handles_updates() {
+                    #[cfg(not(feature = "cache"))]
+                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    #[cfg(feature = "cache")]
+                    let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+
+                    let framework = Arc::clone(&framework);
+                    let event = event.clone();
+                    let task = cache_and_http.in_flight.start();
+
+                    tokio::spawn(async move {
+                        framework.dispatch_update(context, event).await;
+                        drop(task);
+                    });
+                }
+            }
+        }
+
         match (event_handler, raw_event_handler) {
             (None, None) => {
                 event.update(&cache_and_http).await;
@@ -169,9 +212,11 @@ pub(crate) fn dispatch<'rec>(
                         let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
 
                         let framework = Arc::clone(&framework);
+                        let task = cache_and_http.in_flight.start();
 
                         tokio::spawn(async move {
                             framework.dispatch(context, event.message).await;
+                            drop(task);
                         });
                     }
                 }
@@ -190,14 +235,17 @@ pub(crate) fn dispatch<'rec>(
                             context.clone(),
                             event.message.clone(),
                             h,
+                            &spawner,
                         ).await;
 
                         #[cfg(feature = "framework")]
                         {
                             let framework = Arc::clone(&framework);
+                            let task = cache_and_http.in_flight.start();
 
                             tokio::spawn(async move {
                                 framework.dispatch(context, event.message).await;
+                                drop(task);
                             });
                         }
                     },
@@ -209,6 +257,7 @@ pub(crate) fn dispatch<'rec>(
                             runner_tx,
                             shard_id,
                             cache_and_http,
+                            &spawner,
                         ).await;
                     }
                 }
@@ -236,9 +285,11 @@ pub(crate) fn dispatch<'rec>(
                             event_handler.raw_event(context.clone(), event).await;
 
                             let framework = Arc::clone(&framework);
+                            let task = cache_and_http.in_flight.start();
 
                             tokio::spawn(async move {
                                 framework.dispatch(context, message).await;
+                                drop(task);
                             });
                         } else {
                             // Avoid cloning, if there is no framework-dispatch.
@@ -265,14 +316,18 @@ pub(crate) fn dispatch<'rec>(
                             context.clone(),
                             event.message.clone(),
                             handler,
+                            &spawner,
                         ).await;
 
                         #[cfg(feature = "framework")]
                         {
                             let framework = Arc::clone(&framework);
                             let message =  event.message;
+                            let task = cache_and_http.in_flight.start();
+
                             tokio::spawn(async move {
                                 framework.dispatch(context, message).await;
+                                drop(task);
                             });
                         }
                     },
@@ -284,6 +339,7 @@ pub(crate) fn dispatch<'rec>(
                             runner_tx,
                             shard_id,
                             cache_and_http,
+                            &spawner,
                         ).await,
                 }
             },
@@ -295,6 +351,7 @@ async fn dispatch_message(
     context: Context,
     mut message: Message,
     event_handler: &Arc<dyn EventHandler>,
+    spawner: &Spawner,
 ) {
     #[cfg(feature = "model")]
     {
@@ -303,13 +360,13 @@ async fn dispatch_message(
 
     let event_handler = Arc::clone(event_handler);
 
-    tokio::spawn(async move {
+    spawner.spawn(async move {
         event_handler.message(context, message).await;
-    });
+    }).await;
 }
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip(event, data, event_handler, cache_and_http))]
+#[instrument(skip(event, data, event_handler, cache_and_http, spawner))]
 async fn handle_event(
     event: DispatchEvent,
     data: &Arc<RwLock<TypeMap>>,
@@ -317,6 +374,7 @@ async fn handle_event(
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
+    spawner: &Spawner,
 ) {
     #[cfg(not(feature = "cache"))]
     let context = context(data, runner_tx, shard_id, &cache_and_http.http);
@@ -324,29 +382,64 @@ async fn handle_event(
     let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
 
     match event {
+        DispatchEvent::Client(ClientEvent::ShardReconnect(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.shard_reconnect(context, event).await;
+            }).await;
+        }
         DispatchEvent::Client(ClientEvent::ShardStageUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.shard_stage_update(context, event).await;
-            });
+            }).await;
         }
+        DispatchEvent::Model(Event::AutoModerationActionExecution(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.auto_moderation_action_execution(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::AutoModerationRuleCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.auto_moderation_rule_create(context, event.rule).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::AutoModerationRuleUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.auto_moderation_rule_update(context, event.rule).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::AutoModerationRuleDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.auto_moderation_rule_delete(context, event.rule).await;
+            }).await;
+        },
         DispatchEvent::Model(Event::ChannelCreate(mut event)) => {
             update(&cache_and_http, &mut event).await;
             match event.channel {
                 Channel::Guild(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         event_handler.channel_create(context, &channel).await;
-                    });
+                    }).await;
                 },
                 Channel::Category(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         event_handler.category_create(context, &channel).await;
-                    });
+                    }).await;
                 },
                 // Private channel create events are no longer sent to bots in the v8 gateway.
                 _ => {}
@@ -360,30 +453,30 @@ async fn handle_event(
                 Channel::Guild(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         event_handler.channel_delete(context, &channel).await;
-                    });
+                    }).await;
                 },
                 Channel::Category(channel) => {
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         event_handler.category_delete(context, &channel).await;
-                    });
+                    }).await;
                 },
             }
         },
         DispatchEvent::Model(Event::ChannelPinsUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.channel_pins_update(context, event).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::ChannelUpdate(mut event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     let old_channel = cache_and_http.cache.as_ref().channel(event.channel.id()).await;
                     update(&cache_and_http, &mut event).await;
@@ -394,22 +487,22 @@ async fn handle_event(
 
                     event_handler.channel_update(context, event.channel).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildBanAdd(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_ban_addition(context, event.guild_id, event.user).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildBanRemove(event)) => {
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_ban_removal(context, event.guild_id, event.user).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildCreate(mut event)) => {
             #[cfg(feature = "cache")]
@@ -433,69 +526,69 @@ async fn handle_event(
                         .collect::<Vec<GuildId>>();
                     let event_handler = Arc::clone(event_handler);
 
-                    tokio::spawn(async move {
+                    spawner.spawn(async move {
                         event_handler.cache_ready(context, guild_amount).await;
-                    });
+                    }).await;
                 }
             }
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.guild_create(context, event.guild, _is_new).await;
                 } else {
                     event_handler.guild_create(context, event.guild).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildDelete(mut event)) => {
             let _full = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.guild_delete(context, event.guild, _full).await;
                 } else {
                     event_handler.guild_delete(context, event.guild).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildEmojisUpdate(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_emojis_update(context, event.guild_id, event.emojis).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildIntegrationsUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_integrations_update(context, event.guild_id).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildMemberAdd(mut event)) => {
             update(&cache_and_http, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_member_addition(context, event.guild_id, event.member).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildMemberRemove(mut event)) => {
             let _member = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.guild_member_removal(context, event.guild_id, event.user, _member).await;
                 } else {
                     event_handler.guild_member_removal(context, event.guild_id, event.user).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildMemberUpdate(mut event)) => {
             let _before = update(&cache_and_http, &mut event).await;
@@ -507,7 +600,7 @@ async fn handle_event(
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     if let Some(after) = _after {
                         event_handler.guild_member_update(context, _before, after).await;
@@ -515,60 +608,103 @@ async fn handle_event(
                 } else {
                     event_handler.guild_member_update(context, event).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildMembersChunk(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_members_chunk(context, event).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildRoleCreate(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_role_create(context, event.guild_id, event.role).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildRoleDelete(mut event)) => {
             let _role = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.guild_role_delete(context, event.guild_id, event.role_id, _role).await;
                 } else {
                     event_handler.guild_role_delete(context, event.guild_id, event.role_id).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildRoleUpdate(mut event)) => {
             let _before = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.guild_role_update(context, event.guild_id, _before, event.role).await;
                 } else {
                     event_handler.guild_role_update(context, event.guild_id, event.role).await;
                 }}
-            });
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildScheduledEventCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_scheduled_event_create(context, event.event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildScheduledEventDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_scheduled_event_delete(context, event.event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildScheduledEventUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_scheduled_event_update(context, event.event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildScheduledEventUserAdd(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_scheduled_event_user_add(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildScheduledEventUserRemove(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_scheduled_event_user_remove(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::GuildStickersUpdate(mut event)) => {
+            update(&cache_and_http, &mut event).await;
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.guild_stickers_update(context, event.guild_id, event.stickers).await;
+            }).await;
         },
         DispatchEvent::Model(Event::GuildUnavailable(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.guild_unavailable(context, event.guild_id).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::GuildUpdate(mut event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     let before = cache_and_http.cache
                         .guild(&event.guild.id)
@@ -582,155 +718,255 @@ async fn handle_event(
 
                     event_handler.guild_update(context, event.guild).await;
                 }}
-            });
+            }).await;
+        },
+        DispatchEvent::Model(Event::IntegrationCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.integration_create(context, event.integration).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::IntegrationUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.integration_update(context, event.integration).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::IntegrationDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.integration_delete(context, event.id, event.guild_id, event.application_id).await;
+            }).await;
         },
-        DispatchEvent::Model(Event::InviteCreate(event)) => {
+        DispatchEvent::Model(Event::InteractionCreate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
+                event_handler.interaction_create(context, event.interaction).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::InviteCreate(mut event)) => {
+            update(&cache_and_http, &mut event).await;
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
                 event_handler.invite_create(context, event).await;
-            });
+            }).await;
         },
-        DispatchEvent::Model(Event::InviteDelete(event)) => {
+        DispatchEvent::Model(Event::InviteDelete(mut event)) => {
+            update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.invite_delete(context, event).await;
-            });
+            }).await;
         },
         // Already handled by the framework check macro
         DispatchEvent::Model(Event::MessageCreate(_)) => {},
         DispatchEvent::Model(Event::MessageDeleteBulk(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.message_delete_bulk(context, event.channel_id, event.ids).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::MessageDelete(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.message_delete(context, event.channel_id, event.message_id).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::MessageUpdate(mut event)) => {
             let _before = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     let _after = cache_and_http.cache.message(event.channel_id, event.id).await;
                     event_handler.message_update(context, _before, _after, event).await;
                 } else {
                     event_handler.message_update(context, event).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::PresencesReplace(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.presence_replace(context, event.presences).await;
-            });
+            }).await;
+        },
+        DispatchEvent::Model(Event::MessagePollVoteAdd(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.message_poll_vote_add(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::MessagePollVoteRemove(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.message_poll_vote_remove(context, event).await;
+            }).await;
         },
         DispatchEvent::Model(Event::PresenceUpdate(mut event)) => {
             update(&cache_and_http, &mut event).await;
 
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.presence_update(context, event).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::ReactionAdd(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.reaction_add(context, event.reaction).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::ReactionRemove(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.reaction_remove(context, event.reaction).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::ReactionRemoveAll(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.reaction_remove_all(context, event.channel_id, event.message_id).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::Ready(mut event)) => {
             update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(&event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.ready(context, event.ready).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::Resumed(event)) => {
             let event_handler = Arc::clone(&event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.resume(context, event).await;
-            });
+            }).await;
+        },
+        DispatchEvent::Model(Event::StageInstanceCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.stage_instance_create(context, event.stage_instance).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::StageInstanceDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.stage_instance_delete(context, event.stage_instance).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::StageInstanceUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.stage_instance_update(context, event.stage_instance).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::ThreadCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.thread_create(context, event.thread).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::ThreadDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.thread_delete(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::ThreadListSync(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.thread_list_sync(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::ThreadMembersUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.thread_members_update(context, event).await;
+            }).await;
+        },
+        DispatchEvent::Model(Event::ThreadUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            spawner.spawn(async move {
+                event_handler.thread_update(context, event.thread).await;
+            }).await;
         },
         DispatchEvent::Model(Event::TypingStart(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.typing_start(context, event).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::Unknown(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.unknown(context, event.kind, event.value).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::UserUpdate(mut event)) => {
             let _before = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.user_update(context, _before.expect("missing old user"), event.current_user).await;
                 } else {
                     event_handler.user_update(context, event.current_user).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::VoiceServerUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.voice_server_update(context, event).await;
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::VoiceStateUpdate(mut event)) => {
             let _before = update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 feature_cache! {{
                     event_handler.voice_state_update(context, event.guild_id, _before, event.voice_state).await;
                 } else {
                     event_handler.voice_state_update(context, event.guild_id, event.voice_state).await;
                 }}
-            });
+            }).await;
         },
         DispatchEvent::Model(Event::WebhookUpdate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
-            tokio::spawn(async move {
+            spawner.spawn(async move {
                 event_handler.webhook_update(context, event.guild_id, event.channel_id).await;
-            });
+            }).await;
         },
     }
 }
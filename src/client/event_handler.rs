@@ -19,6 +19,28 @@ pub trait EventHandler: Send + Sync {
     #[cfg(feature = "cache")]
     async fn cache_ready(&self, _ctx: Context, _guilds: Vec<GuildId>) {}
 
+    /// Dispatched when an auto moderation rule is triggered and one of its
+    /// actions has been executed.
+    ///
+    /// Provides the executed action and information about the triggering
+    /// content.
+    async fn auto_moderation_action_execution(&self, _ctx: Context, _execution: AutoModerationActionExecutionEvent) {}
+
+    /// Dispatched when an auto moderation rule is created.
+    ///
+    /// Provides the created rule.
+    async fn auto_moderation_rule_create(&self, _ctx: Context, _rule: AutoModRule) {}
+
+    /// Dispatched when an auto moderation rule is updated.
+    ///
+    /// Provides the updated rule.
+    async fn auto_moderation_rule_update(&self, _ctx: Context, _rule: AutoModRule) {}
+
+    /// Dispatched when an auto moderation rule is deleted.
+    ///
+    /// Provides the deleted rule.
+    async fn auto_moderation_rule_delete(&self, _ctx: Context, _rule: AutoModRule) {}
+
     /// Dispatched when a channel is created.
     ///
     /// Provides said channel's data.
@@ -152,9 +174,14 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when a member is updated (e.g their nickname is updated).
     ///
     /// Provides the member's old data (if available) and the new data.
+    ///
+    /// A member passed the guild's membership screening if the old data is
+    /// [`pending`] while the new data is not.
     /// 
     /// Note: This event will not trigger unless the "guild members" privileged intent
     /// is enabled on the bot application page.
+    ///
+    /// [`pending`]: ../model/guild/struct.Member.html#structfield.pending
     #[cfg(feature = "cache")]
     async fn guild_member_update(&self, _ctx: Context, _old_if_available: Option<Member>, _new: Member) {}
 
@@ -201,6 +228,32 @@ pub trait EventHandler: Send + Sync {
     #[cfg(not(feature = "cache"))]
     async fn guild_role_update(&self, _ctx: Context, _guild_id: GuildId, _new_data: Role) {}
 
+    /// Dispatched when a scheduled event is created.
+    ///
+    /// Provides the created scheduled event.
+    async fn guild_scheduled_event_create(&self, _ctx: Context, _event: ScheduledEvent) {}
+
+    /// Dispatched when a scheduled event is deleted.
+    ///
+    /// Provides the deleted scheduled event.
+    async fn guild_scheduled_event_delete(&self, _ctx: Context, _event: ScheduledEvent) {}
+
+    /// Dispatched when a scheduled event is updated.
+    ///
+    /// Provides the updated scheduled event.
+    async fn guild_scheduled_event_update(&self, _ctx: Context, _event: ScheduledEvent) {}
+
+    /// Dispatched when a user subscribed to a scheduled event.
+    async fn guild_scheduled_event_user_add(&self, _ctx: Context, _subscribed: GuildScheduledEventUserAddEvent) {}
+
+    /// Dispatched when a user unsubscribed from a scheduled event.
+    async fn guild_scheduled_event_user_remove(&self, _ctx: Context, _unsubscribed: GuildScheduledEventUserRemoveEvent) {}
+
+    /// Dispatched when the stickers are updated.
+    ///
+    /// Provides the guild's id and the new state of the stickers in the guild.
+    async fn guild_stickers_update(&self, _ctx: Context, _guild_id: GuildId, _current_state: HashMap<StickerId, Sticker>) {}
+
     /// Dispatched when a guild became unavailable.
     ///
     /// Provides the guild's id.
@@ -218,6 +271,34 @@ pub trait EventHandler: Send + Sync {
     #[cfg(not(feature = "cache"))]
     async fn guild_update(&self, _ctx: Context, _new_but_incomplete_data: PartialGuild) {}
 
+    /// Dispatched when an integration is created.
+    ///
+    /// Provides the created integration.
+    async fn integration_create(&self, _ctx: Context, _integration: Integration) {}
+
+    /// Dispatched when an integration is updated.
+    ///
+    /// Provides the updated integration.
+    async fn integration_update(&self, _ctx: Context, _integration: Integration) {}
+
+    /// Dispatched when an integration is deleted.
+    ///
+    /// Provides the integration's id, the id of the guild it belonged to, and
+    /// the id of its bot application, if it had one.
+    async fn integration_delete(
+        &self,
+        _ctx: Context,
+        _integration_id: IntegrationId,
+        _guild_id: GuildId,
+        _application_id: Option<ApplicationId>,
+    ) {}
+
+    /// Dispatched when an interaction is created, e.g. a slash command was
+    /// used or a button was clicked.
+    ///
+    /// Provides the created interaction.
+    async fn interaction_create(&self, _ctx: Context, _interaction: Interaction) {}
+
     /// Dispatched when a invite is created.
     ///
     /// Provides data about the invite.
@@ -277,6 +358,16 @@ pub trait EventHandler: Send + Sync {
     /// This event is legacy, and likely no longer sent by discord.
     async fn presence_replace(&self, _ctx: Context, _: Vec<Presence>) {}
 
+    /// Dispatched when a user adds a vote for an answer of a poll.
+    ///
+    /// Provides the user's, message's and answer's Ids.
+    async fn message_poll_vote_add(&self, _ctx: Context, _vote: MessagePollVoteAddEvent) {}
+
+    /// Dispatched when a user removes a vote from an answer of a poll.
+    ///
+    /// Provides the user's, message's and answer's Ids.
+    async fn message_poll_vote_remove(&self, _ctx: Context, _vote: MessagePollVoteRemoveEvent) {}
+
     /// Dispatched when a user's presence is updated (e.g off -> on).
     ///
     /// Provides the presence's new data.
@@ -293,11 +384,54 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched upon reconnection.
     async fn resume(&self, _ctx: Context, _: ResumedEvent) {}
 
+    /// Dispatched when a shard is about to attempt to reconnect.
+    ///
+    /// Provides the context of the shard and the event information about the
+    /// attempt.
+    async fn shard_reconnect(&self, _ctx: Context, _: ShardReconnectEvent) {}
+
     /// Dispatched when a shard's connection stage is updated
     ///
     /// Provides the context of the shard and the event information about the update.
     async fn shard_stage_update(&self, _ctx: Context, _: ShardStageUpdateEvent) {}
 
+    /// Dispatched when a stage instance is created.
+    ///
+    /// Provides the created stage instance.
+    async fn stage_instance_create(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a stage instance is deleted.
+    ///
+    /// Provides the deleted stage instance.
+    async fn stage_instance_delete(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a stage instance is updated.
+    ///
+    /// Provides the updated stage instance.
+    async fn stage_instance_update(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a thread is created or the current user is added to a
+    /// private thread.
+    ///
+    /// Provides the thread's data.
+    async fn thread_create(&self, _ctx: Context, _thread: GuildChannel) {}
+
+    /// Dispatched when a thread is deleted.
+    async fn thread_delete(&self, _ctx: Context, _thread: ThreadDeleteEvent) {}
+
+    /// Dispatched when the current user gains access to a channel.
+    ///
+    /// Provides the channel's active threads.
+    async fn thread_list_sync(&self, _ctx: Context, _thread_list_sync: ThreadListSyncEvent) {}
+
+    /// Dispatched when users are added to or removed from a thread.
+    async fn thread_members_update(&self, _ctx: Context, _thread_members_update: ThreadMembersUpdateEvent) {}
+
+    /// Dispatched when a thread is updated.
+    ///
+    /// Provides the updated thread's data.
+    async fn thread_update(&self, _ctx: Context, _thread: GuildChannel) {}
+
     /// Dispatched when a user starts typing.
     async fn typing_start(&self, _ctx: Context, _: TypingStartEvent) {}
 
@@ -349,3 +483,75 @@ pub trait RawEventHandler: Send + Sync {
     /// Dispatched when any event occurs
     async fn raw_event(&self, _ctx: Context, _ev: Event) {}
 }
+
+/// Whether a payload received by a [`RawPayloadHandler`] is processed any
+/// further.
+///
+/// [`RawPayloadHandler`]: trait.RawPayloadHandler.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PayloadAction {
+    /// The payload is deserialized and dispatched as usual.
+    Continue,
+    /// The payload was handled, and is neither deserialized nor dispatched to
+    /// the cache, the other handlers, the framework, or collectors.
+    ///
+    /// **Note**: Only dispatch payloads can be handled, other than those of
+    /// the `READY` and `RESUMED` events, as the shard needs the others to stay
+    /// connected.
+    Handled,
+}
+
+/// A trait for handling the raw payloads received from the gateway, before
+/// they are deserialized.
+///
+/// This allows to consume events not yet covered by the models, or to
+/// instrument the events received.
+///
+/// The handler is awaited before the shard processes the payload, so it should
+/// finish quickly.
+///
+/// # Examples
+///
+/// Count the events of types unknown to the models, and handle a new event:
+///
+/// ```rust,no_run
+/// use serenity::async_trait;
+/// use serenity::client::{Context, PayloadAction, RawPayloadHandler};
+/// use serenity::model::event::EventType;
+/// use serde_json::Value;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// struct Handler {
+///     unknown: AtomicU64,
+/// }
+///
+/// #[async_trait]
+/// impl RawPayloadHandler for Handler {
+///     async fn payload(&self, _: Context, op: u64, payload: &Value) -> PayloadAction {
+///         let kind = match payload.get("t").and_then(Value::as_str) {
+///             Some(kind) if op == 0 => kind,
+///             _ => return PayloadAction::Continue,
+///         };
+///
+///         if kind == "GUILD_JOIN_REQUEST_CREATE" {
+///             println!("Join request: {}", payload["d"]);
+///
+///             return PayloadAction::Handled;
+///         }
+///
+///         if let Ok(EventType::Other(_)) = serde_json::from_value(payload["t"].clone()) {
+///             self.unknown.fetch_add(1, Ordering::Relaxed);
+///         }
+///
+///         PayloadAction::Continue
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait RawPayloadHandler: Send + Sync {
+    /// Dispatched with every payload received, along with its opcode.
+    async fn payload(&self, _ctx: Context, _op: u64, _payload: &Value) -> PayloadAction {
+        PayloadAction::Continue
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Tracks the framework commands and event handlers being dispatched, so that
+/// a graceful shutdown can stop dispatching new events and wait for them to
+/// finish.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InFlight {
+    tasks: Arc<AtomicUsize>,
+    draining: Arc<AtomicBool>,
+}
+
+impl InFlight {
+    /// Registers a task, which is in flight until the returned guard is
+    /// dropped.
+    pub(crate) fn start(&self) -> InFlightTask {
+        self.tasks.fetch_add(1, Ordering::SeqCst);
+
+        InFlightTask(Arc::clone(&self.tasks))
+    }
+
+    /// The number of tasks in flight.
+    pub(crate) fn tasks(&self) -> usize {
+        self.tasks.load(Ordering::SeqCst)
+    }
+
+    /// Stops the dispatch of new events to the handlers and the framework.
+    pub(crate) fn drain(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+}
+
+/// A task in flight, finished once dropped.
+pub(crate) struct InFlightTask(Arc<AtomicUsize>);
+
+impl Drop for InFlightTask {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InFlight;
+
+    #[test]
+    fn counts_tasks_until_dropped() {
+        let in_flight = InFlight::default();
+        let first = in_flight.start();
+        let second = in_flight.clone().start();
+        assert_eq!(in_flight.tasks(), 2);
+
+        drop(first);
+        assert_eq!(in_flight.tasks(), 1);
+        drop(second);
+        assert_eq!(in_flight.tasks(), 0);
+
+        assert!(!in_flight.is_draining());
+        in_flight.clone().drain();
+        assert!(in_flight.is_draining());
+    }
+}
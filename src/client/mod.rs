@@ -28,6 +28,8 @@ mod error;
 mod event_handler;
 #[cfg(feature = "gateway")]
 mod extras;
+#[cfg(feature = "gateway")]
+mod in_flight;
 
 pub use self::{
     context::Context,
@@ -36,10 +38,13 @@ pub use self::{
 
 #[cfg(feature = "gateway")]
 pub use self::{
-    event_handler::{EventHandler, RawEventHandler},
+    event_handler::{EventHandler, PayloadAction, RawEventHandler, RawPayloadHandler},
     extras::Extras,
 };
 
+#[cfg(feature = "gateway")]
+pub(crate) use self::in_flight::InFlight;
+
 pub use crate::CacheAndHttp;
 
 #[cfg(feature = "cache")]
@@ -48,9 +53,9 @@ pub use crate::cache::Cache;
 use crate::internal::prelude::*;
 use tokio::sync::{Mutex, RwLock};
 #[cfg(feature = "gateway")]
-use super::gateway::GatewayError;
+use super::gateway::{GatewayError, ReconnectPolicy, SessionStore};
 #[cfg(feature = "gateway")]
-use self::bridge::gateway::{GatewayIntents, ShardManager, ShardManagerMonitor, ShardManagerOptions, ShardManagerError};
+use self::bridge::gateway::{EventDispatchStrategy, GatewayIntents, ShardManager, ShardManagerMonitor, ShardManagerOptions, ShardManagerError};
 use std::{
     boxed::Box,
     sync::Arc,
@@ -58,7 +63,7 @@ use std::{
     pin::Pin,
     task::{Context as FutContext, Poll},
 };
-#[cfg(all(feature = "cache", feature = "gateway"))]
+#[cfg(feature = "gateway")]
 use std::time::Duration;
 use tracing::{error, debug, info, instrument};
 
@@ -80,6 +85,10 @@ pub struct ClientBuilder<'a> {
     http: Option<Http>,
     fut: Option<BoxFuture<'a, Result<Client>>>,
     intents: GatewayIntents,
+    transport_compression: bool,
+    session_store: Option<Arc<dyn SessionStore>>,
+    reconnect_policy: ReconnectPolicy,
+    event_dispatch_strategy: EventDispatchStrategy,
     #[cfg(feature = "cache")]
     timeout: Option<Duration>,
     #[cfg(feature = "framework")]
@@ -88,6 +97,7 @@ pub struct ClientBuilder<'a> {
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    raw_payload_handler: Option<Arc<dyn RawPayloadHandler>>,
 }
 
 #[cfg(feature = "gateway")]
@@ -103,11 +113,29 @@ impl<'a> ClientBuilder<'a> {
     /// [`framework`]: #method.framework
     /// [`framework_arc`]: #method.framework_arc
     pub fn new(token: impl AsRef<str>) -> Self {
+        Self::new_with_http(Http::new_with_token(token.as_ref()))
+    }
+
+    /// Construct a new builder with an [`Http`] client, such as one sending
+    /// requests through a proxy built with an [`HttpBuilder`].
+    ///
+    /// **Panic**:
+    /// As with [`new`], a framework must be specified if the
+    /// `framework`-feature is enabled.
+    ///
+    /// [`Http`]: ../http/client/struct.Http.html
+    /// [`HttpBuilder`]: ../http/client/struct.HttpBuilder.html
+    /// [`new`]: #method.new
+    pub fn new_with_http(http: Http) -> Self {
         Self {
             data: Some(TypeMap::new()),
-            http: None,
+            http: Some(http),
             fut: None,
             intents: GatewayIntents::non_privileged(),
+            transport_compression: false,
+            session_store: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            event_dispatch_strategy: EventDispatchStrategy::default(),
             #[cfg(feature = "cache")]
             timeout: None,
             #[cfg(feature = "framework")]
@@ -116,21 +144,20 @@ impl<'a> ClientBuilder<'a> {
             voice_manager: None,
             event_handler: None,
             raw_event_handler: None,
-        }.token(token)
+            raw_payload_handler: None,
+        }
     }
 
     /// Sets a token for the bot. If the token is not prefixed "Bot ",
     /// this method will automatically do so.
+    ///
+    /// **Note**: This replaces the [`Http`] client, including one given to
+    /// [`new_with_http`].
+    ///
+    /// [`Http`]: ../http/client/struct.Http.html
+    /// [`new_with_http`]: #method.new_with_http
     pub fn token(mut self, token: impl AsRef<str>) -> Self {
-        let token = token.as_ref().trim();
-
-        let token = if token.starts_with("Bot ") {
-            token.to_string()
-        } else {
-            format!("Bot {}", token)
-        };
-
-        self.http = Some(Http::new_with_token(&token));
+        self.http = Some(Http::new_with_token(token.as_ref()));
 
         self
     }
@@ -256,6 +283,55 @@ impl<'a> ClientBuilder<'a> {
         self
     }
 
+    /// Sets whether shards use `zlib-stream` transport compression, which
+    /// compresses everything received from the gateway.
+    ///
+    /// This considerably reduces the bandwidth used by large bots, at the cost
+    /// of keeping a zlib context in memory for every shard.
+    ///
+    /// Defaults to `false`.
+    pub fn transport_compression(mut self, compress: bool) -> Self {
+        self.transport_compression = compress;
+
+        self
+    }
+
+    /// Sets the store to persist the sessions of shards in, so that shards
+    /// resume them after the process restarted instead of identifying anew.
+    ///
+    /// Refer to [`SessionStore`] for more information.
+    ///
+    /// [`SessionStore`]: ../gateway/trait.SessionStore.html
+    pub fn session_store<S: SessionStore + 'static>(mut self, session_store: S) -> Self {
+        self.session_store = Some(Arc::new(session_store));
+
+        self
+    }
+
+    /// Sets how shards reconnect after losing their connection to the
+    /// gateway.
+    ///
+    /// Refer to [`ReconnectPolicy`] for more information.
+    ///
+    /// [`ReconnectPolicy`]: ../gateway/struct.ReconnectPolicy.html
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+
+        self
+    }
+
+    /// Sets how events are dispatched to the event handler, such as in a
+    /// bounded pool of workers rather than a task per event.
+    ///
+    /// Refer to [`EventDispatchStrategy`] for more information.
+    ///
+    /// [`EventDispatchStrategy`]: bridge/gateway/enum.EventDispatchStrategy.html
+    pub fn event_dispatch_strategy(mut self, strategy: EventDispatchStrategy) -> Self {
+        self.event_dispatch_strategy = strategy;
+
+        self
+    }
+
     /// Sets an event handler with multiple methods for each possible event.
     pub fn event_handler<H: EventHandler + 'static>(mut self, event_handler: H) -> Self {
         self.event_handler = Some(Arc::new(event_handler));
@@ -270,6 +346,18 @@ impl<'a> ClientBuilder<'a> {
 
         self
     }
+
+    /// Sets a handler receiving the raw payloads from the gateway before they
+    /// are deserialized.
+    ///
+    /// Refer to [`RawPayloadHandler`] for more information.
+    ///
+    /// [`RawPayloadHandler`]: trait.RawPayloadHandler.html
+    pub fn raw_payload_handler<H: RawPayloadHandler + 'static>(mut self, raw_payload_handler: H) -> Self {
+        self.raw_payload_handler = Some(Arc::new(raw_payload_handler));
+
+        self
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -286,7 +374,12 @@ impl<'a> Future for ClientBuilder<'a> {
                 If you don't want to use the command framework, disable default features and specify all features you want to use.");
             let event_handler = self.event_handler.take();
             let raw_event_handler = self.raw_event_handler.take();
+            let raw_payload_handler = self.raw_payload_handler.take();
             let intents = self.intents;
+            let transport_compression = self.transport_compression;
+            let session_store = self.session_store.take();
+            let reconnect_policy = self.reconnect_policy.clone();
+            let event_dispatch_strategy = self.event_dispatch_strategy;
             let http = Arc::new(self.http.take().unwrap());
             #[cfg(feature = "voice")]
             let voice_manager = self.voice_manager.take();
@@ -297,6 +390,7 @@ impl<'a> Future for ClientBuilder<'a> {
                 #[cfg(feature = "cache")]
                 update_cache_timeout: self.timeout.take(),
                 http: Arc::clone(&http),
+                in_flight: InFlight::default(),
             });
 
             self.fut = Some(Box::pin(async move {
@@ -307,6 +401,7 @@ impl<'a> Future for ClientBuilder<'a> {
                         data: &data,
                         event_handler: &event_handler,
                         raw_event_handler: &raw_event_handler,
+                        raw_payload_handler: &raw_payload_handler,
                         #[cfg(feature = "framework")]
                         framework: &framework,
                         shard_index: 0,
@@ -317,6 +412,10 @@ impl<'a> Future for ClientBuilder<'a> {
                         ws_url: &url,
                         cache_and_http: &cache_and_http,
                         intents,
+                        transport_compression,
+                        session_store: &session_store,
+                        reconnect_policy,
+                        event_dispatch_strategy,
                     }).await
                 };
 
@@ -675,12 +774,14 @@ impl Client {
     /// [gateway docs]: ../gateway/index.html#sharding
     #[instrument(skip(self))]
     pub async fn start_autosharded(&mut self) -> Result<()> {
-        let (x, y) = {
+        let (x, y, max_concurrency) = {
             let res = self.cache_and_http.http.get_bot_gateway().await?;
 
-            (res.shards as u64 - 1, res.shards as u64)
+            (res.shards as u64 - 1, res.shards as u64, res.session_start_limit.max_concurrency)
         };
 
+        self.shard_manager.lock().await.set_max_concurrency(max_concurrency);
+
         self.start_connection([0, x, y]).await
     }
 
@@ -859,6 +960,21 @@ impl Client {
         self.start_connection([range[0], range[1], total_shards]).await
     }
 
+    /// Shuts down all shards gracefully, waiting up to `timeout` for the
+    /// framework commands and event handlers in flight to finish first.
+    ///
+    /// Returns whether everything finished within the timeout.
+    ///
+    /// As the client is borrowed while running, shutting down from another
+    /// task is done with a clone of the [`shard_manager`] instead. Refer to
+    /// [`ShardManager::shutdown_gracefully`] for more information.
+    ///
+    /// [`ShardManager::shutdown_gracefully`]: bridge/gateway/struct.ShardManager.html#method.shutdown_gracefully
+    /// [`shard_manager`]: #structfield.shard_manager
+    pub async fn shutdown_gracefully(&self, timeout: Duration) -> bool {
+        ShardManager::shutdown_gracefully(&self.shard_manager, timeout).await
+    }
+
     /// Shard data layout is:
     /// 0: first shard number to initialize
     /// 1: shard number to initialize up to and including
@@ -908,6 +1024,7 @@ impl Client {
         if let Err(why) = self.shard_manager_worker.run().await {
             let err =  match why {
                 ShardManagerError::DisallowedGatewayIntents => GatewayError::DisallowedGatewayIntents,
+                ShardManagerError::ReconnectFailure(_) => GatewayError::ReconnectFailure,
                 ShardManagerError::InvalidGatewayIntents => GatewayError::InvalidGatewayIntents,
                 ShardManagerError::InvalidToken => GatewayError::InvalidAuthentication,
             };
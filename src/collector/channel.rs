@@ -0,0 +1,476 @@
+//! A channel connecting a filter on the shard's end with its collector.
+//!
+//! Unlike the channels found in `tokio`, the channel may be bounded with a
+//! policy deciding what happens to items once the buffer is full.
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, Weak,
+    },
+    task::{Context as FutContext, Poll, Waker},
+};
+use tokio::sync::Notify;
+use futures::{
+    channel::mpsc::{self, UnboundedSender},
+    future::BoxFuture,
+    stream::{Stream, StreamExt},
+};
+use crate::CacheAndHttp;
+
+/// Decides what happens to an item collected by a filter when the collector's
+/// buffer is full.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BufferPolicy {
+    /// Drops the oldest buffered item to make room for the new item.
+    DropOldest,
+    /// Drops the new item, keeping the buffered items.
+    DropNewest,
+    /// Blocks the shard's dispatcher until the collector consumed an item.
+    ///
+    /// **Note**: While blocked, the shard will neither dispatch events nor
+    /// heartbeat, use this only with collectors that are consumed quickly.
+    Block,
+}
+
+impl Default for BufferPolicy {
+    fn default() -> Self {
+        BufferPolicy::DropOldest
+    }
+}
+
+/// The reason a collector stopped receiving items.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CollectorEnd {
+    /// The collector collected as many items as its collect limit allows.
+    CollectLimit,
+    /// The collector filtered as many items as its filter limit allows.
+    FilterLimit,
+    /// The collector's timeout elapsed.
+    Timeout,
+    /// The collector's idle timeout elapsed without an item being collected.
+    IdleTimeout,
+    /// The collector has been cancelled via
+    /// [`ShardMessenger::cancel_collector`].
+    ///
+    /// [`ShardMessenger::cancel_collector`]: ../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+    Cancelled,
+    /// The shard dropped the collector's filter without reaching a limit,
+    /// e.g. because the shard shut down.
+    Disconnected,
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    filtered: u32,
+    collected: u32,
+    end: Option<CollectorEnd>,
+    receiver_closed: bool,
+    senders: usize,
+    receiver_waker: Option<Waker>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    capacity: Option<usize>,
+    policy: BufferPolicy,
+    space: Notify,
+}
+
+/// Creates a channel with an unbounded buffer if `buffer` is `None`.
+pub(crate) fn channel<T>(buffer: Option<usize>, policy: BufferPolicy) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            filtered: 0,
+            collected: 0,
+            end: None,
+            receiver_closed: false,
+            senders: 1,
+            receiver_waker: None,
+        }),
+        capacity: buffer,
+        policy,
+        space: Notify::new(),
+    });
+
+    (Sender { shared: Arc::clone(&shared) }, Receiver { shared })
+}
+
+pub(crate) struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends an `item` to the receiver, returning it back if the receiver
+    /// has been closed.
+    ///
+    /// This only waits if the buffer is full and the policy is
+    /// [`BufferPolicy::Block`].
+    ///
+    /// [`BufferPolicy::Block`]: enum.BufferPolicy.html#variant.Block
+    pub(crate) async fn send(&self, item: T) -> Result<(), T> {
+        loop {
+            // The lock must be released before awaiting space.
+            {
+                let mut state = self.shared.state.lock().unwrap();
+
+                if state.receiver_closed {
+                    return Err(item);
+                }
+
+                let is_full = self.shared.capacity.map_or(false, |cap| state.queue.len() >= cap);
+
+                if !is_full || self.shared.policy != BufferPolicy::Block {
+                    if is_full {
+                        if self.shared.policy == BufferPolicy::DropNewest {
+                            return Ok(());
+                        }
+
+                        state.queue.pop_front();
+                    }
+
+                    state.queue.push_back(item);
+
+                    if let Some(waker) = state.receiver_waker.take() {
+                        waker.wake();
+                    }
+
+                    return Ok(());
+                }
+            }
+
+            self.shared.space.notified().await;
+        }
+    }
+
+    /// Publishes the filter's counters to the receiver, along with the reason
+    /// the filter stops, if it does.
+    ///
+    /// A reason published before is kept.
+    pub(crate) fn set_progress(&self, filtered: u32, collected: u32, end: Option<CollectorEnd>) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.filtered = filtered;
+        state.collected = collected;
+        state.end = state.end.or(end);
+    }
+
+    /// Whether the receiver has been closed or dropped.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.shared.state.lock().unwrap().receiver_closed
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            if let Some(waker) = state.receiver_waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sender")
+            .field("capacity", &self.shared.capacity)
+            .field("policy", &self.shared.policy)
+            .finish()
+    }
+}
+
+/// Maps an item received by a filter to the item its collector yields,
+/// dropping the item if `None` is returned.
+pub(crate) type ItemMap<I, T> = Arc<dyn Fn(I) -> Option<T> + 'static + Send + Sync>;
+
+/// The sending half of a filter, hiding the type of item the collector
+/// receives.
+pub(crate) trait ItemSender<I>: std::fmt::Debug + Send + Sync {
+    /// Maps the `item` and sends it to the collector.
+    ///
+    /// Returns whether the item has been collected, or an error if the
+    /// collector stopped receiving.
+    fn send<'a>(&'a self, item: &'a I, cache_and_http: &'a Arc<CacheAndHttp>) -> BoxFuture<'a, Result<bool, ()>>;
+
+    fn set_progress(&self, filtered: u32, collected: u32, end: Option<CollectorEnd>);
+
+    fn is_closed(&self) -> bool;
+
+    /// Returns how many items have been collected, if the sender counts them
+    /// itself instead of the filter.
+    fn collected(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// A [`Sender`] mapping items before sending them.
+///
+/// [`Sender`]: struct.Sender.html
+pub(crate) struct MappedSender<I, T> {
+    sender: Sender<T>,
+    map: ItemMap<I, T>,
+}
+
+impl<I, T> MappedSender<I, T> {
+    pub(crate) fn new(sender: Sender<T>, map: ItemMap<I, T>) -> Self {
+        Self {
+            sender,
+            map,
+        }
+    }
+}
+
+impl<I: Clone + Send + Sync, T: Send + 'static> ItemSender<I> for MappedSender<I, T> {
+    fn send<'a>(&'a self, item: &'a I, _: &'a Arc<CacheAndHttp>) -> BoxFuture<'a, Result<bool, ()>> {
+        Box::pin(async move {
+            match (self.map)(item.clone()) {
+                Some(item) => self.sender.send(item).await.map(|_| true).map_err(|_| ()),
+                None => Ok(false),
+            }
+        })
+    }
+
+    fn set_progress(&self, filtered: u32, collected: u32, end: Option<CollectorEnd>) {
+        self.sender.set_progress(filtered, collected, end);
+    }
+
+    fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<I, T> std::fmt::Debug for MappedSender<I, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedSender")
+            .field("sender", &self.sender)
+            .field("map", &"Arc<dyn Fn(I) -> Option<T> + 'static + Send + Sync>")
+            .finish()
+    }
+}
+
+/// A filter given the cache and HTTP client, returning a future resolving
+/// to whether the item passes.
+pub(crate) type ContextFilter<I> = Arc<dyn Fn(&I, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync>;
+
+/// Counters shared between a [`ContextSender`] and its task.
+///
+/// [`ContextSender`]: struct.ContextSender.html
+#[derive(Debug, Default)]
+struct ContextProgress {
+    filtered: AtomicU32,
+    collected: AtomicU32,
+}
+
+/// An [`ItemSender`] running a [`ContextFilter`] on a spawned task, so the
+/// shard does not wait for the filter.
+///
+/// The task checks the items in the order they are sent and forwards those
+/// passing the filter. It owns the inner sender, so the collector ends as
+/// soon as the task reaches the collect limit.
+///
+/// [`ContextFilter`]: type.ContextFilter.html
+/// [`ItemSender`]: trait.ItemSender.html
+pub(crate) struct ContextSender<I> {
+    tx: UnboundedSender<(I, Arc<CacheAndHttp>)>,
+    inner: Weak<dyn ItemSender<I>>,
+    progress: Arc<ContextProgress>,
+}
+
+impl<I: Clone + Send + Sync + 'static> ContextSender<I> {
+    /// Spawns the task filtering items with `filter` before sending them
+    /// with `inner`.
+    pub(crate) fn spawn(inner: Arc<dyn ItemSender<I>>, filter: ContextFilter<I>, collect_limit: Option<u32>) -> Self {
+        let (tx, mut rx) = mpsc::unbounded::<(I, Arc<CacheAndHttp>)>();
+        let progress = Arc::new(ContextProgress::default());
+        let sender = Self {
+            tx,
+            inner: Arc::downgrade(&inner),
+            progress: Arc::clone(&progress),
+        };
+
+        tokio::spawn(async move {
+            while let Some((item, cache_and_http)) = rx.next().await {
+                if inner.is_closed() {
+                    break;
+                }
+
+                if !filter(&item, Arc::clone(&cache_and_http)).await {
+                    continue;
+                }
+
+                match inner.send(&item, &cache_and_http).await {
+                    Ok(true) => (),
+                    Ok(false) => continue,
+                    Err(()) => break,
+                }
+
+                let collected = progress.collected.fetch_add(1, Ordering::SeqCst) + 1;
+                let end = if collect_limit.map_or(false, |limit| collected >= limit) {
+                    Some(CollectorEnd::CollectLimit)
+                } else {
+                    None
+                };
+
+                inner.set_progress(progress.filtered.load(Ordering::SeqCst), collected, end);
+
+                if end.is_some() {
+                    break;
+                }
+            }
+        });
+
+        sender
+    }
+}
+
+impl<I: Clone + Send + Sync + 'static> ItemSender<I> for ContextSender<I> {
+    fn send<'a>(&'a self, item: &'a I, cache_and_http: &'a Arc<CacheAndHttp>) -> BoxFuture<'a, Result<bool, ()>> {
+        let sent = self.tx.unbounded_send((item.clone(), Arc::clone(cache_and_http)));
+
+        // The item only counts as collected once it passed the filter.
+        Box::pin(futures::future::ready(sent.map(|_| false).map_err(|_| ())))
+    }
+
+    fn set_progress(&self, filtered: u32, _: u32, end: Option<CollectorEnd>) {
+        self.progress.filtered.store(filtered, Ordering::SeqCst);
+
+        if let Some(inner) = self.inner.upgrade() {
+            inner.set_progress(filtered, self.progress.collected.load(Ordering::SeqCst), end);
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.tx.is_closed() || self.inner.upgrade().map_or(true, |inner| inner.is_closed())
+    }
+
+    fn collected(&self) -> Option<u32> {
+        Some(self.progress.collected.load(Ordering::SeqCst))
+    }
+}
+
+impl<I> std::fmt::Debug for ContextSender<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextSender")
+            .field("progress", &self.progress)
+            .finish()
+    }
+}
+
+pub(crate) struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Closes the receiving half, letting further sends fail.
+    ///
+    /// Already buffered items can still be received.
+    pub(crate) fn close(&mut self) {
+        self.shared.state.lock().unwrap().receiver_closed = true;
+        // Unblock a sender waiting for space, it will notice the closure.
+        self.shared.space.notify();
+    }
+
+    /// Returns how many items have been filtered and collected.
+    pub(crate) fn progress(&self) -> (u32, u32) {
+        let state = self.shared.state.lock().unwrap();
+
+        (state.filtered, state.collected)
+    }
+
+    /// Returns why no more items will be received, once all items have
+    /// been received.
+    pub(crate) fn end(&self) -> Option<CollectorEnd> {
+        let state = self.shared.state.lock().unwrap();
+
+        if state.senders == 0 && state.queue.is_empty() {
+            Some(state.end.unwrap_or(CollectorEnd::Disconnected))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if let Some(item) = state.queue.pop_front() {
+            drop(state);
+            self.shared.space.notify();
+
+            return Poll::Ready(Some(item));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        state.receiver_waker = Some(ctx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{channel, BufferPolicy};
+    use futures::stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_drop_oldest() {
+        let (sender, receiver) = channel(Some(2), BufferPolicy::DropOldest);
+
+        for i in 0..3 {
+            assert!(sender.send(i).await.is_ok());
+        }
+
+        drop(sender);
+
+        assert_eq!(receiver.collect::<Vec<_>>().await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest() {
+        let (sender, receiver) = channel(Some(2), BufferPolicy::DropNewest);
+
+        for i in 0..3 {
+            assert!(sender.send(i).await.is_ok());
+        }
+
+        drop(sender);
+
+        assert_eq!(receiver.collect::<Vec<_>>().await, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_closed_receiver() {
+        let (sender, mut receiver) = channel(None, BufferPolicy::Block);
+        receiver.close();
+
+        assert_eq!(sender.send(1).await, Err(1));
+    }
+}
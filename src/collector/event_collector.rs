@@ -0,0 +1,1021 @@
+use std::{
+    boxed::Box,
+    future::Future,
+    sync::Arc,
+    time::Duration,
+    pin::Pin,
+    task::{Context as FutContext, Poll},
+};
+use tokio::time::{Delay, Instant, delay_for};
+use futures::{
+    future::{self, BoxFuture},
+    stream::{Stream, StreamExt},
+};
+use crate::{
+    CacheAndHttp,
+    client::bridge::gateway::ShardMessenger,
+    collector::channel::{channel, BufferPolicy, CollectorEnd, ContextFilter, ContextSender, ItemMap, ItemSender, MappedSender, Receiver},
+    collector::registry::{CollectorId, CollectorInfo, CollectorKind},
+    model::channel::Channel,
+    model::event::*,
+    model::id::{ChannelId, GuildId, UserId},
+};
+
+macro_rules! impl_event_collector {
+    ($($name:ident;)*) => {
+        $(
+            impl<'a> $name<'a> {
+                /// Limits how many events will attempt to be filtered.
+                ///
+                /// The filter checks whether the event has been sent
+                /// in the right guild, channel, and by the right user.
+                pub fn filter_limit(mut self, limit: u32) -> Self {
+                    self.filter.as_mut().unwrap().filter_limit = Some(limit);
+
+                    self
+                }
+
+                /// Sets the required author ID of an event.
+                /// If an event does not carry this user ID, it won't be received.
+                ///
+                /// The author of an event is the user who caused it, e.g. the
+                /// author of a message or the user adding a reaction.
+                pub fn author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id = vec![author_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed author ID, letting an event pass if it meets any
+                /// of the added IDs.
+                /// If an event meets none of the IDs, it won't be received.
+                pub fn add_author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id.push(author_id.into());
+
+                    self
+                }
+
+                /// Sets the required channel ID of an event.
+                /// If an event does not carry this channel ID, it won't be received.
+                pub fn channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id = vec![channel_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed channel ID, letting an event pass if it meets any
+                /// of the added IDs.
+                /// If an event meets none of the IDs, it won't be received.
+                pub fn add_channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id.push(channel_id.into());
+
+                    self
+                }
+
+                /// Sets the required guild ID of an event.
+                /// If an event does not carry this guild ID, it won't be received.
+                pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id = vec![guild_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed guild ID, letting an event pass if it meets any
+                /// of the added IDs.
+                /// If an event meets none of the IDs, it won't be received.
+                pub fn add_guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id.push(guild_id.into());
+
+                    self
+                }
+
+                /// Limits the amount of events buffered for the collector
+                /// to `size`.
+                ///
+                /// By default, the buffer is unbounded, which may let memory
+                /// grow if the collector consumes events slower than they arrive.
+                /// What happens once the buffer is full is decided by the
+                /// [`buffer_policy`].
+                ///
+                /// [`buffer_policy`]: #method.buffer_policy
+                pub fn buffer(mut self, size: usize) -> Self {
+                    self.filter.as_mut().unwrap().buffer = Some(size);
+
+                    self
+                }
+
+                /// Sets what happens to collected events when the [`buffer`]
+                /// is full.
+                ///
+                /// Defaults to [`BufferPolicy::DropOldest`].
+                ///
+                /// [`buffer`]: #method.buffer
+                /// [`BufferPolicy::DropOldest`]: ../enum.BufferPolicy.html#variant.DropOldest
+                pub fn buffer_policy(mut self, policy: BufferPolicy) -> Self {
+                    self.filter.as_mut().unwrap().buffer_policy = policy;
+
+                    self
+                }
+
+                /// Sets a `duration` for how long the collector shall receive
+                /// events.
+                pub fn timeout(mut self, duration: Duration) -> Self {
+                    self.timeout = Some(delay_for(duration));
+
+                    self
+                }
+
+                /// Sets a `duration` after which the collector stops if no
+                /// event has been collected in the meantime.
+                ///
+                /// Unlike the [`timeout`], this duration restarts whenever
+                /// an event is collected.
+                ///
+                /// [`timeout`]: #method.timeout
+                pub fn idle_timeout(mut self, duration: Duration) -> Self {
+                    self.idle_timeout = Some(duration);
+
+                    self
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_untyped_event_collector {
+    ($($name:ident;)*) => {
+        $(
+            impl<'a> $name<'a> {
+                /// Sets a filter function where events passed to the `function` must
+                /// return `true`, otherwise the event won't be collected and failed the filter
+                /// process.
+                /// This is the last instance to pass for an event to count as *collected*.
+                pub fn filter<F: Fn(&Arc<Event>) -> bool + 'static + Send + Sync>(mut self, function: F) -> Self {
+                    self.filter.as_mut().unwrap().filter = Some(Arc::new(function));
+
+                    self
+                }
+
+                /// Sets a filter function like [`filter`], but the `function` is
+                /// additionally given the cache and HTTP client, allowing it to
+                /// check e.g. roles or permissions.
+                ///
+                /// As the cache and HTTP client are accessed asynchronously, the
+                /// `function` returns a future. It is awaited on a separate task,
+                /// one event after another, so a slow filter delays this
+                /// collector but never the shard.
+                ///
+                /// If both filters are set, an event must pass both.
+                ///
+                /// [`filter`]: #method.filter
+                pub fn filter_with_context<F>(mut self, function: F) -> Self
+                where F: Fn(&Arc<Event>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync {
+                    self.filter.as_mut().unwrap().filter_with_context = Some(Arc::new(function));
+
+                    self
+                }
+
+                /// Adds an event type the collector shall receive.
+                /// If at least one event type is added, events of other types
+                /// won't be received.
+                ///
+                /// This can be called multiple times to receive several kinds
+                /// of events, e.g. both [`EventType::MessageUpdate`] and
+                /// [`EventType::MessageDelete`].
+                ///
+                /// [`EventType::MessageUpdate`]: ../../model/event/enum.EventType.html#variant.MessageUpdate
+                /// [`EventType::MessageDelete`]: ../../model/event/enum.EventType.html#variant.MessageDelete
+                pub fn add_event_type(mut self, event_type: EventType) -> Self {
+                    self.filter.as_mut().unwrap().event_types.push(event_type);
+
+                    self
+                }
+            }
+        )*
+    }
+}
+
+type EventMap<T> = ItemMap<Arc<Event>, T>;
+
+/// Filters events on the shard's end and sends them to the collector.
+#[derive(Clone, Debug)]
+pub struct EventFilter {
+    id: CollectorId,
+    created: Instant,
+    filtered: u32,
+    collected: u32,
+    options: FilterOptions,
+    sender: Arc<dyn ItemSender<Arc<Event>>>,
+}
+
+impl EventFilter {
+    /// Creates a new filter
+    fn new(options: FilterOptions) -> (Self, Receiver<Arc<Event>>) {
+        Self::new_mapped(options, Arc::new(Some))
+    }
+
+    /// Creates a new filter, mapping events with `map` before sending them.
+    fn new_mapped<T: Send + 'static>(options: FilterOptions, map: EventMap<T>) -> (Self, Receiver<T>) {
+        let (sender, receiver) = channel(options.buffer, options.buffer_policy);
+        let mut sender: Arc<dyn ItemSender<Arc<Event>>> = Arc::new(MappedSender::new(sender, map));
+
+        if let Some(ref filter) = options.filter_with_context {
+            sender = Arc::new(ContextSender::spawn(sender, Arc::clone(filter), options.collect_limit));
+        }
+
+        let filter = Self {
+            id: CollectorId::next(),
+            created: Instant::now(),
+            filtered: 0,
+            collected: 0,
+            sender,
+            options,
+        };
+
+        (filter, receiver)
+    }
+
+    /// Sends an `event` to the consuming collector if the `event` conforms
+    /// to the constraints and the limits are not reached yet.
+    pub(crate) async fn send_event(&mut self, event: &Arc<Event>, cache_and_http: &Arc<CacheAndHttp>) -> bool {
+        if self.is_passing_constraints(&event) && self.options.filter.as_ref().map_or(true, |f| f(&event)) {
+            match self.sender.send(event, cache_and_http).await {
+                Ok(true) => self.collected += 1,
+                Ok(false) => (),
+                Err(()) => return false,
+            }
+        }
+
+        self.filtered += 1;
+
+        let end = self.reached_limit();
+        self.sender.set_progress(self.filtered, self.collected, end);
+
+        end.is_none()
+    }
+
+    /// Checks if the `event` passes set constraints.
+    /// Constraints are optional, as it is possible to limit events to
+    /// be caused by a specific user or to happen in a specifc guild.
+    ///
+    /// An event that does not carry an ID that is constrained will not pass.
+    fn is_passing_constraints(&self, event: &Arc<Event>) -> bool {
+        (self.options.event_types.is_empty() || self.options.event_types.contains(&event.event_type()))
+        && (self.options.guild_id.is_empty() || event_guild_id(event).map_or(false, |g| self.options.guild_id.contains(&g.0)))
+        && (self.options.channel_id.is_empty() || event_channel_id(event).map_or(false, |c| self.options.channel_id.contains(&c.0)))
+        && (self.options.author_id.is_empty() || event_user_id(event).map_or(false, |u| self.options.author_id.contains(&u.0)))
+    }
+
+    /// Describes the filter and its progress.
+    pub(crate) fn info(&self) -> CollectorInfo {
+        CollectorInfo {
+            id: self.id,
+            kind: CollectorKind::Event,
+            filtered: self.filtered,
+            collected: self.sender.collected().unwrap_or(self.collected),
+            age: self.created.elapsed(),
+            summary: format!("{:?}", self.options),
+        }
+    }
+
+    /// Returns the ID of the collector this filter belongs to.
+    pub(crate) fn id(&self) -> CollectorId {
+        self.id
+    }
+
+    /// Whether the collector stopped receiving events.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Lets the collector know it has been cancelled.
+    ///
+    /// The filter must be dropped afterwards to end the collector.
+    pub(crate) fn cancel(&self) {
+        self.sender.set_progress(self.filtered, self.collected, Some(CollectorEnd::Cancelled));
+    }
+
+    /// Checks if the filter reached the set receive or collect limit,
+    /// returning which one has been reached.
+    /// An event is considered *received* even when it does not meet the
+    /// constraints.
+    fn reached_limit(&self) -> Option<CollectorEnd> {
+        if self.options.collect_limit.map_or(false, |limit| self.collected >= limit) {
+            Some(CollectorEnd::CollectLimit)
+        } else if self.options.filter_limit.map_or(false, |limit| self.filtered >= limit) {
+            Some(CollectorEnd::FilterLimit)
+        } else {
+            None
+        }
+    }
+}
+
+fn channel_guild_id(channel: &Channel) -> Option<GuildId> {
+    match channel {
+        Channel::Guild(channel) => Some(channel.guild_id),
+        Channel::Category(category) => Some(category.guild_id),
+        Channel::Private(_) => None,
+    }
+}
+
+/// Returns the ID of the guild the `event` happened in, if any.
+fn event_guild_id(event: &Event) -> Option<GuildId> {
+    match event {
+        Event::AutoModerationActionExecution(e) => Some(e.guild_id),
+        Event::AutoModerationRuleCreate(e) => Some(e.rule.guild_id),
+        Event::AutoModerationRuleDelete(e) => Some(e.rule.guild_id),
+        Event::AutoModerationRuleUpdate(e) => Some(e.rule.guild_id),
+        Event::ChannelCreate(e) => channel_guild_id(&e.channel),
+        Event::ChannelDelete(e) => channel_guild_id(&e.channel),
+        Event::ChannelUpdate(e) => channel_guild_id(&e.channel),
+        Event::ChannelPinsUpdate(e) => e.guild_id,
+        Event::GuildBanAdd(e) => Some(e.guild_id),
+        Event::GuildBanRemove(e) => Some(e.guild_id),
+        Event::GuildCreate(e) => Some(e.guild.id),
+        Event::GuildDelete(e) => Some(e.guild.id),
+        Event::GuildEmojisUpdate(e) => Some(e.guild_id),
+        Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildMemberAdd(e) => Some(e.guild_id),
+        Event::GuildMemberRemove(e) => Some(e.guild_id),
+        Event::GuildMemberUpdate(e) => Some(e.guild_id),
+        Event::GuildMembersChunk(e) => Some(e.guild_id),
+        Event::GuildRoleCreate(e) => Some(e.guild_id),
+        Event::GuildRoleDelete(e) => Some(e.guild_id),
+        Event::GuildRoleUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.event.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.event.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.event.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
+        Event::GuildStickersUpdate(e) => Some(e.guild_id),
+        Event::GuildUnavailable(e) => Some(e.guild_id),
+        Event::GuildUpdate(e) => Some(e.guild.id),
+        Event::IntegrationCreate(e) => e.integration.guild_id,
+        Event::IntegrationDelete(e) => Some(e.guild_id),
+        Event::IntegrationUpdate(e) => e.integration.guild_id,
+        Event::InteractionCreate(e) => e.interaction.guild_id,
+        Event::InviteCreate(e) => e.guild_id,
+        Event::InviteDelete(e) => e.guild_id,
+        Event::MessageCreate(e) => e.message.guild_id,
+        Event::MessageDelete(e) => e.guild_id,
+        Event::MessageDeleteBulk(e) => e.guild_id,
+        Event::MessagePollVoteAdd(e) => e.guild_id,
+        Event::MessagePollVoteRemove(e) => e.guild_id,
+        Event::MessageUpdate(e) => e.guild_id,
+        Event::PresenceUpdate(e) => e.guild_id,
+        Event::ReactionAdd(e) => e.reaction.guild_id,
+        Event::ReactionRemove(e) => e.reaction.guild_id,
+        Event::ReactionRemoveAll(e) => e.guild_id,
+        Event::StageInstanceCreate(e) => Some(e.stage_instance.guild_id),
+        Event::StageInstanceDelete(e) => Some(e.stage_instance.guild_id),
+        Event::StageInstanceUpdate(e) => Some(e.stage_instance.guild_id),
+        Event::ThreadCreate(e) => Some(e.thread.guild_id),
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadMembersUpdate(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => Some(e.thread.guild_id),
+        Event::TypingStart(e) => e.guild_id,
+        Event::VoiceServerUpdate(e) => e.guild_id,
+        Event::VoiceStateUpdate(e) => e.guild_id,
+        Event::WebhookUpdate(e) => Some(e.guild_id),
+        _ => None,
+    }
+}
+
+/// Returns the ID of the channel the `event` happened in, if any.
+fn event_channel_id(event: &Event) -> Option<ChannelId> {
+    match event {
+        Event::AutoModerationActionExecution(e) => e.channel_id,
+        Event::ChannelCreate(e) => Some(e.channel.id()),
+        Event::ChannelDelete(e) => Some(e.channel.id()),
+        Event::ChannelUpdate(e) => Some(e.channel.id()),
+        Event::ChannelPinsUpdate(e) => Some(e.channel_id),
+        Event::GuildScheduledEventCreate(e) => e.event.channel_id,
+        Event::GuildScheduledEventDelete(e) => e.event.channel_id,
+        Event::GuildScheduledEventUpdate(e) => e.event.channel_id,
+        Event::InteractionCreate(e) => e.interaction.channel_id,
+        Event::InviteCreate(e) => Some(e.channel_id),
+        Event::InviteDelete(e) => Some(e.channel_id),
+        Event::MessageCreate(e) => Some(e.message.channel_id),
+        Event::MessageDelete(e) => Some(e.channel_id),
+        Event::MessageDeleteBulk(e) => Some(e.channel_id),
+        Event::MessagePollVoteAdd(e) => Some(e.channel_id),
+        Event::MessagePollVoteRemove(e) => Some(e.channel_id),
+        Event::MessageUpdate(e) => Some(e.channel_id),
+        Event::ReactionAdd(e) => Some(e.reaction.channel_id),
+        Event::ReactionRemove(e) => Some(e.reaction.channel_id),
+        Event::ReactionRemoveAll(e) => Some(e.channel_id),
+        Event::StageInstanceCreate(e) => Some(e.stage_instance.channel_id),
+        Event::StageInstanceDelete(e) => Some(e.stage_instance.channel_id),
+        Event::StageInstanceUpdate(e) => Some(e.stage_instance.channel_id),
+        Event::ThreadCreate(e) => Some(e.thread.id),
+        Event::ThreadDelete(e) => Some(e.id),
+        Event::ThreadMembersUpdate(e) => Some(e.id),
+        Event::ThreadUpdate(e) => Some(e.thread.id),
+        Event::TypingStart(e) => Some(e.channel_id),
+        Event::VoiceServerUpdate(e) => e.channel_id,
+        Event::VoiceStateUpdate(e) => e.voice_state.channel_id,
+        Event::WebhookUpdate(e) => Some(e.channel_id),
+        _ => None,
+    }
+}
+
+/// Returns the ID of the user who caused the `event`, if any.
+fn event_user_id(event: &Event) -> Option<UserId> {
+    match event {
+        Event::AutoModerationActionExecution(e) => Some(e.user_id),
+        Event::GuildBanAdd(e) => Some(e.user.id),
+        Event::GuildBanRemove(e) => Some(e.user.id),
+        Event::GuildMemberAdd(e) => Some(e.member.user.id),
+        Event::GuildMemberRemove(e) => Some(e.user.id),
+        Event::GuildMemberUpdate(e) => Some(e.user.id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.user_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.user_id),
+        Event::InteractionCreate(e) => e.interaction.user.as_ref().map(|u| u.id),
+        Event::InviteCreate(e) => e.inviter.as_ref().map(|u| u.id),
+        Event::MessageCreate(e) => Some(e.message.author.id),
+        Event::MessagePollVoteAdd(e) => Some(e.user_id),
+        Event::MessagePollVoteRemove(e) => Some(e.user_id),
+        Event::MessageUpdate(e) => e.author.as_ref().map(|u| u.id),
+        Event::PresenceUpdate(e) => Some(e.presence.user_id),
+        Event::ReactionAdd(e) => e.reaction.user_id,
+        Event::ReactionRemove(e) => e.reaction.user_id,
+        Event::TypingStart(e) => Some(e.user_id),
+        Event::UserUpdate(e) => Some(e.current_user.id),
+        Event::VoiceStateUpdate(e) => Some(e.voice_state.user_id),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Default)]
+struct FilterOptions {
+    filter_limit: Option<u32>,
+    collect_limit: Option<u32>,
+    buffer: Option<usize>,
+    buffer_policy: BufferPolicy,
+    filter: Option<Arc<dyn Fn(&Arc<Event>) -> bool + 'static + Send + Sync>>,
+    filter_with_context: Option<ContextFilter<Arc<Event>>>,
+    event_types: Vec<EventType>,
+    channel_id: Vec<u64>,
+    guild_id: Vec<u64>,
+    author_id: Vec<u64>,
+}
+
+impl std::fmt::Debug for FilterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventFilter")
+            .field("collect_limit", &self.collect_limit)
+            .field("buffer", &self.buffer)
+            .field("buffer_policy", &self.buffer_policy)
+            .field("filter", &"Option<Arc<dyn Fn(&Arc<Event>) -> bool + 'static + Send + Sync>>")
+            .field("filter_with_context", &"Option<Arc<dyn Fn(&Arc<Event>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync>>")
+            .field("event_types", &self.event_types)
+            .field("channel_id", &self.channel_id)
+            .field("guild_id", &self.guild_id)
+            .field("author_id", &self.author_id)
+            .finish()
+    }
+}
+
+// Implement the common setters for all event collector types.
+impl_event_collector! {
+    CollectEvent;
+    EventCollectorBuilder;
+}
+
+// Implement the setters only available to collectors receiving any kind of
+// event.
+impl_untyped_event_collector! {
+    CollectEvent;
+    EventCollectorBuilder;
+}
+
+/// Future building a stream of events.
+pub struct EventCollectorBuilder<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
+    fut: Option<BoxFuture<'a, EventCollector>>,
+}
+
+impl<'a> EventCollectorBuilder<'a> {
+    /// A future that builds an [`EventCollector`] based on the settings.
+    ///
+    /// [`EventCollector`]: ../struct.EventCollector.html
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions::default()),
+            shard: Some(shard_messenger.as_ref().clone()),
+            timeout: None,
+            idle_timeout: None,
+            fut: None,
+        }
+    }
+
+    /// Limits how many events can be collected.
+    ///
+    /// An event is considered *collected*, if the event
+    /// passes all the requirements.
+    pub fn collect_limit(mut self, limit: u32) -> Self {
+        self.filter.as_mut().unwrap().collect_limit = Some(limit);
+
+        self
+    }
+
+    /// Builds the collector and awaits its first event, returning `None`
+    /// if the collector ended before receiving one, e.g. due to a timeout.
+    pub async fn collect_single(self) -> Option<Arc<Event>> {
+        self.collect_limit(1).await.next().await
+    }
+
+    /// Maps events to items on the shard's end, so the collector yields
+    /// the items instead of whole events.
+    ///
+    /// The `map` runs after all other filters, an event is only *collected*
+    /// if `map` returns `Some`.
+    ///
+    /// As this changes the collector's type, it must be called after all
+    /// other setters.
+    pub fn map<T, F>(self, map: F) -> MappedEventCollectorBuilder<'a, T>
+    where
+        T: Send + 'static,
+        F: Fn(Arc<Event>) -> Option<T> + 'static + Send + Sync,
+    {
+        MappedEventCollectorBuilder {
+            filter: self.filter,
+            shard: self.shard,
+            timeout: self.timeout,
+            idle_timeout: self.idle_timeout,
+            map: Some(Arc::new(map)),
+            fut: None,
+        }
+    }
+}
+
+impl<'a> Future for EventCollectorBuilder<'a> {
+    type Output = EventCollector;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let (filter, receiver) = EventFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
+            let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_event_filter(filter);
+
+                EventCollector {
+                    id,
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future building a stream of items mapped from events, see
+/// [`EventCollectorBuilder::map`].
+///
+/// [`EventCollectorBuilder::map`]: struct.EventCollectorBuilder.html#method.map
+pub struct MappedEventCollectorBuilder<'a, T> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
+    map: Option<EventMap<T>>,
+    fut: Option<BoxFuture<'a, EventCollector<T>>>,
+}
+
+impl<'a, T: Send + 'static> Future for MappedEventCollectorBuilder<'a, T> {
+    type Output = EventCollector<T>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let map = self.map.take().unwrap();
+            let (filter, receiver) = EventFilter::new_mapped(self.filter.take().unwrap(), map);
+            let id = filter.id();
+            let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_event_filter(filter);
+
+                EventCollector {
+                    id,
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future awaiting a single event.
+pub struct CollectEvent<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
+    fut: Option<BoxFuture<'a, Option<Arc<Event>>>>,
+}
+
+impl<'a> CollectEvent<'a> {
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions::default()),
+            shard: Some(shard_messenger.as_ref().clone()),
+            timeout: None,
+            idle_timeout: None,
+            fut: None,
+        }
+    }
+}
+
+impl<'a> Future for CollectEvent<'a> {
+    type Output = Option<Arc<Event>>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let (filter, receiver) = EventFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
+            let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_event_filter(filter);
+
+                EventCollector {
+                    id,
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
+                }.next().await
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// An event collector receives events matching the given filter for a
+/// set duration.
+///
+/// If the collector has been built with [`EventCollectorBuilder::map`], it
+/// yields the mapped items instead.
+///
+/// [`EventCollectorBuilder::map`]: struct.EventCollectorBuilder.html#method.map
+pub struct EventCollector<T = Arc<Event>> {
+    id: CollectorId,
+    receiver: Pin<Box<Receiver<T>>>,
+    timeout: Option<Pin<Box<Delay>>>,
+    idle_timeout: Option<(Duration, Pin<Box<Delay>>)>,
+    end: Option<CollectorEnd>,
+}
+
+impl<T> EventCollector<T> {
+    /// Returns the ID of the collector, used to cancel it via
+    /// [`ShardMessenger::cancel_collector`].
+    ///
+    /// [`ShardMessenger::cancel_collector`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+    pub fn id(&self) -> CollectorId {
+        self.id
+    }
+
+    /// Stops collecting, this will implicitly be done once the
+    /// collector drops.
+    /// In case the drop does not appear until later, it is preferred to
+    /// stop the collector early.
+    pub fn stop(mut self) {
+        self.receiver.close();
+    }
+
+    /// Returns how many events have been checked by the filter so far.
+    pub fn filtered(&self) -> u32 {
+        self.receiver.progress().0
+    }
+
+    /// Returns how many events passed the filter so far.
+    pub fn collected(&self) -> u32 {
+        self.receiver.progress().1
+    }
+
+    /// Returns why the collector stopped receiving events.
+    ///
+    /// This is `None` as long as the collector may still yield events.
+    pub fn end_reason(&self) -> Option<CollectorEnd> {
+        self.end.or_else(|| self.receiver.end())
+    }
+
+    /// Stops receiving, remembering the reason.
+    fn finish(&mut self, end: CollectorEnd) {
+        self.end = Some(end);
+        self.receiver.close();
+    }
+}
+
+impl<T> Stream for EventCollector<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(ref mut timeout) = self.timeout {
+
+            match timeout.as_mut().poll(ctx) {
+                Poll::Ready(_) => {
+                    self.finish(CollectorEnd::Timeout);
+
+                    return Poll::Ready(None);
+                },
+                Poll::Pending => (),
+            }
+        }
+
+        let item = self.receiver.as_mut().poll_next(ctx);
+
+        if let Some((duration, idle_timeout)) = &mut self.idle_timeout {
+
+            match item {
+                Poll::Ready(Some(_)) => idle_timeout.reset(Instant::now() + *duration),
+                Poll::Pending => if idle_timeout.as_mut().poll(ctx).is_ready() {
+                    self.finish(CollectorEnd::IdleTimeout);
+
+                    return Poll::Ready(None);
+                },
+                Poll::Ready(None) => (),
+            }
+        }
+
+        item
+    }
+}
+
+impl<T> Drop for EventCollector<T> {
+    fn drop(&mut self) {
+        self.receiver.close();
+    }
+}
+
+/// Generates a collector builder and a collector stream for a single
+/// [`Event`] variant, yielding the variant's payload instead of the
+/// whole [`Event`].
+///
+/// [`Event`]: ../../model/event/enum.Event.html
+macro_rules! typed_event_collector {
+    ($($builder:ident, $collector:ident => $variant:ident($payload:ty);)*) => {
+        $(
+            /// Future building a stream of a single kind of event.
+            pub struct $builder<'a> {
+                filter: Option<FilterOptions>,
+                shard: Option<ShardMessenger>,
+                timeout: Option<Delay>,
+                idle_timeout: Option<Duration>,
+                fut: Option<BoxFuture<'a, $collector>>,
+            }
+
+            impl<'a> $builder<'a> {
+                /// A future that builds a typed event collector based on the settings.
+                pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+                    Self {
+                        filter: Some(FilterOptions {
+                            event_types: vec![EventType::$variant],
+                            ..FilterOptions::default()
+                        }),
+                        shard: Some(shard_messenger.as_ref().clone()),
+                        timeout: None,
+                        idle_timeout: None,
+                        fut: None,
+                    }
+                }
+
+                /// Limits how many events can be collected.
+                ///
+                /// An event is considered *collected*, if the event
+                /// passes all the requirements.
+                pub fn collect_limit(mut self, limit: u32) -> Self {
+                    self.filter.as_mut().unwrap().collect_limit = Some(limit);
+
+                    self
+                }
+
+                /// Builds the collector and awaits its first event, returning `None`
+                /// if the collector ended before receiving one, e.g. due to a timeout.
+                pub async fn collect_single(self) -> Option<Arc<$payload>> {
+                    self.collect_limit(1).await.next().await
+                }
+
+                /// Sets a filter function where events passed to the `function` must
+                /// return `true`, otherwise the event won't be collected and failed the filter
+                /// process.
+                /// This is the last instance to pass for an event to count as *collected*.
+                pub fn filter<F: Fn(&$payload) -> bool + 'static + Send + Sync>(mut self, function: F) -> Self {
+                    self.filter.as_mut().unwrap().filter = Some(Arc::new(move |event: &Arc<Event>| {
+                        match **event {
+                            Event::$variant(ref inner) => function(inner),
+                            _ => false,
+                        }
+                    }));
+
+                    self
+                }
+
+                /// Sets a filter function like [`filter`], but the `function` is
+                /// additionally given the cache and HTTP client, allowing it to
+                /// check e.g. roles or permissions.
+                ///
+                /// As the cache and HTTP client are accessed asynchronously, the
+                /// `function` returns a future. It is awaited on a separate task,
+                /// one event after another, so a slow filter delays this
+                /// collector but never the shard.
+                ///
+                /// If both filters are set, an event must pass both.
+                ///
+                /// [`filter`]: #method.filter
+                pub fn filter_with_context<F>(mut self, function: F) -> Self
+                where F: Fn(&$payload, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync {
+                    self.filter.as_mut().unwrap().filter_with_context = Some(Arc::new(move |event: &Arc<Event>, cache_and_http: Arc<CacheAndHttp>| {
+                        match **event {
+                            Event::$variant(ref inner) => function(inner, cache_and_http),
+                            _ => Box::pin(future::ready(false)),
+                        }
+                    }));
+
+                    self
+                }
+            }
+
+            impl_event_collector! {
+                $builder;
+            }
+
+            impl<'a> Future for $builder<'a> {
+                type Output = $collector;
+
+                fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+                    if self.fut.is_none() {
+                        let shard_messenger = self.shard.take().unwrap();
+                        let (filter, receiver) = EventFilter::new(self.filter.take().unwrap());
+                        let id = filter.id();
+                        let timeout = self.timeout.take();
+                        let idle_timeout = self.idle_timeout.take();
+
+                        self.fut = Some(Box::pin(async move {
+                            shard_messenger.set_event_filter(filter);
+
+                            $collector {
+                                inner: EventCollector {
+                                    id,
+                                    receiver: Box::pin(receiver),
+                                    timeout: timeout.map(Box::pin),
+                                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                                    end: None,
+                                },
+                            }
+                        }))
+                    }
+
+                    self.fut.as_mut().unwrap().as_mut().poll(ctx)
+                }
+            }
+
+            /// A typed event collector receives the payloads of events matching
+            /// the given filter for a set duration.
+            pub struct $collector {
+                inner: EventCollector,
+            }
+
+            impl $collector {
+                /// Returns the ID of the collector, used to cancel it via
+                /// [`ShardMessenger::cancel_collector`].
+                ///
+                /// [`ShardMessenger::cancel_collector`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+                pub fn id(&self) -> CollectorId {
+                    self.inner.id()
+                }
+
+                /// Stops collecting, this will implicitly be done once the
+                /// collector drops.
+                /// In case the drop does not appear until later, it is preferred to
+                /// stop the collector early.
+                pub fn stop(self) {
+                    self.inner.stop();
+                }
+
+                /// Returns how many events have been checked by the filter so far.
+                pub fn filtered(&self) -> u32 {
+                    self.inner.filtered()
+                }
+
+                /// Returns how many events passed the filter so far.
+                pub fn collected(&self) -> u32 {
+                    self.inner.collected()
+                }
+
+                /// Returns why the collector stopped receiving events.
+                ///
+                /// This is `None` as long as the collector may still yield events.
+                pub fn end_reason(&self) -> Option<CollectorEnd> {
+                    self.inner.end_reason()
+                }
+            }
+
+            impl Stream for $collector {
+                type Item = Arc<$payload>;
+                fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
+                    loop {
+                        let event = match Pin::new(&mut self.inner).poll_next(ctx) {
+                            Poll::Ready(Some(event)) => event,
+                            Poll::Ready(None) => return Poll::Ready(None),
+                            Poll::Pending => return Poll::Pending,
+                        };
+
+                        // Avoid the clone if no other collector holds the event.
+                        let inner = match Arc::try_unwrap(event) {
+                            Ok(Event::$variant(inner)) => inner,
+                            Err(event) => match *event {
+                                Event::$variant(ref inner) => inner.clone(),
+                                _ => continue,
+                            },
+                            Ok(_) => continue,
+                        };
+
+                        return Poll::Ready(Some(Arc::new(inner)));
+                    }
+                }
+            }
+        )*
+    }
+}
+
+typed_event_collector! {
+    AutoModerationActionExecutionCollectorBuilder, AutoModerationActionExecutionCollector => AutoModerationActionExecution(AutoModerationActionExecutionEvent);
+    AutoModerationRuleCreateCollectorBuilder, AutoModerationRuleCreateCollector => AutoModerationRuleCreate(AutoModerationRuleCreateEvent);
+    AutoModerationRuleDeleteCollectorBuilder, AutoModerationRuleDeleteCollector => AutoModerationRuleDelete(AutoModerationRuleDeleteEvent);
+    AutoModerationRuleUpdateCollectorBuilder, AutoModerationRuleUpdateCollector => AutoModerationRuleUpdate(AutoModerationRuleUpdateEvent);
+    ChannelCreateCollectorBuilder, ChannelCreateCollector => ChannelCreate(ChannelCreateEvent);
+    ChannelDeleteCollectorBuilder, ChannelDeleteCollector => ChannelDelete(ChannelDeleteEvent);
+    ChannelPinsUpdateCollectorBuilder, ChannelPinsUpdateCollector => ChannelPinsUpdate(ChannelPinsUpdateEvent);
+    ChannelUpdateCollectorBuilder, ChannelUpdateCollector => ChannelUpdate(ChannelUpdateEvent);
+    GuildBanAddCollectorBuilder, GuildBanAddCollector => GuildBanAdd(GuildBanAddEvent);
+    GuildBanRemoveCollectorBuilder, GuildBanRemoveCollector => GuildBanRemove(GuildBanRemoveEvent);
+    GuildCreateCollectorBuilder, GuildCreateCollector => GuildCreate(GuildCreateEvent);
+    GuildDeleteCollectorBuilder, GuildDeleteCollector => GuildDelete(GuildDeleteEvent);
+    GuildEmojisUpdateCollectorBuilder, GuildEmojisUpdateCollector => GuildEmojisUpdate(GuildEmojisUpdateEvent);
+    GuildIntegrationsUpdateCollectorBuilder, GuildIntegrationsUpdateCollector => GuildIntegrationsUpdate(GuildIntegrationsUpdateEvent);
+    GuildMemberAddCollectorBuilder, GuildMemberAddCollector => GuildMemberAdd(GuildMemberAddEvent);
+    GuildMemberRemoveCollectorBuilder, GuildMemberRemoveCollector => GuildMemberRemove(GuildMemberRemoveEvent);
+    GuildMemberUpdateCollectorBuilder, GuildMemberUpdateCollector => GuildMemberUpdate(GuildMemberUpdateEvent);
+    GuildMembersChunkCollectorBuilder, GuildMembersChunkCollector => GuildMembersChunk(GuildMembersChunkEvent);
+    GuildRoleCreateCollectorBuilder, GuildRoleCreateCollector => GuildRoleCreate(GuildRoleCreateEvent);
+    GuildRoleDeleteCollectorBuilder, GuildRoleDeleteCollector => GuildRoleDelete(GuildRoleDeleteEvent);
+    GuildRoleUpdateCollectorBuilder, GuildRoleUpdateCollector => GuildRoleUpdate(GuildRoleUpdateEvent);
+    GuildScheduledEventCreateCollectorBuilder, GuildScheduledEventCreateCollector => GuildScheduledEventCreate(GuildScheduledEventCreateEvent);
+    GuildScheduledEventDeleteCollectorBuilder, GuildScheduledEventDeleteCollector => GuildScheduledEventDelete(GuildScheduledEventDeleteEvent);
+    GuildScheduledEventUpdateCollectorBuilder, GuildScheduledEventUpdateCollector => GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent);
+    GuildScheduledEventUserAddCollectorBuilder, GuildScheduledEventUserAddCollector => GuildScheduledEventUserAdd(GuildScheduledEventUserAddEvent);
+    GuildScheduledEventUserRemoveCollectorBuilder, GuildScheduledEventUserRemoveCollector => GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveEvent);
+    GuildStickersUpdateCollectorBuilder, GuildStickersUpdateCollector => GuildStickersUpdate(GuildStickersUpdateEvent);
+    GuildUnavailableCollectorBuilder, GuildUnavailableCollector => GuildUnavailable(GuildUnavailableEvent);
+    GuildUpdateCollectorBuilder, GuildUpdateCollector => GuildUpdate(GuildUpdateEvent);
+    IntegrationCreateCollectorBuilder, IntegrationCreateCollector => IntegrationCreate(IntegrationCreateEvent);
+    IntegrationDeleteCollectorBuilder, IntegrationDeleteCollector => IntegrationDelete(IntegrationDeleteEvent);
+    IntegrationUpdateCollectorBuilder, IntegrationUpdateCollector => IntegrationUpdate(IntegrationUpdateEvent);
+    InteractionCreateCollectorBuilder, InteractionCreateCollector => InteractionCreate(InteractionCreateEvent);
+    InviteCreateCollectorBuilder, InviteCreateCollector => InviteCreate(InviteCreateEvent);
+    InviteDeleteCollectorBuilder, InviteDeleteCollector => InviteDelete(InviteDeleteEvent);
+    MessageCreateCollectorBuilder, MessageCreateCollector => MessageCreate(MessageCreateEvent);
+    MessageDeleteCollectorBuilder, MessageDeleteCollector => MessageDelete(MessageDeleteEvent);
+    MessageDeleteBulkCollectorBuilder, MessageDeleteBulkCollector => MessageDeleteBulk(MessageDeleteBulkEvent);
+    MessagePollVoteAddCollectorBuilder, MessagePollVoteAddCollector => MessagePollVoteAdd(MessagePollVoteAddEvent);
+    MessagePollVoteRemoveCollectorBuilder, MessagePollVoteRemoveCollector => MessagePollVoteRemove(MessagePollVoteRemoveEvent);
+    MessageUpdateCollectorBuilder, MessageUpdateCollector => MessageUpdate(MessageUpdateEvent);
+    PresenceUpdateCollectorBuilder, PresenceUpdateCollector => PresenceUpdate(PresenceUpdateEvent);
+    ReactionAddCollectorBuilder, ReactionAddCollector => ReactionAdd(ReactionAddEvent);
+    ReactionRemoveCollectorBuilder, ReactionRemoveCollector => ReactionRemove(ReactionRemoveEvent);
+    ReactionRemoveAllCollectorBuilder, ReactionRemoveAllCollector => ReactionRemoveAll(ReactionRemoveAllEvent);
+    StageInstanceCreateCollectorBuilder, StageInstanceCreateCollector => StageInstanceCreate(StageInstanceCreateEvent);
+    StageInstanceDeleteCollectorBuilder, StageInstanceDeleteCollector => StageInstanceDelete(StageInstanceDeleteEvent);
+    StageInstanceUpdateCollectorBuilder, StageInstanceUpdateCollector => StageInstanceUpdate(StageInstanceUpdateEvent);
+    ThreadCreateCollectorBuilder, ThreadCreateCollector => ThreadCreate(ThreadCreateEvent);
+    ThreadDeleteCollectorBuilder, ThreadDeleteCollector => ThreadDelete(ThreadDeleteEvent);
+    ThreadListSyncCollectorBuilder, ThreadListSyncCollector => ThreadListSync(ThreadListSyncEvent);
+    ThreadMembersUpdateCollectorBuilder, ThreadMembersUpdateCollector => ThreadMembersUpdate(ThreadMembersUpdateEvent);
+    ThreadUpdateCollectorBuilder, ThreadUpdateCollector => ThreadUpdate(ThreadUpdateEvent);
+    TypingStartCollectorBuilder, TypingStartCollector => TypingStart(TypingStartEvent);
+    UserUpdateCollectorBuilder, UserUpdateCollector => UserUpdate(UserUpdateEvent);
+    VoiceServerUpdateCollectorBuilder, VoiceServerUpdateCollector => VoiceServerUpdate(VoiceServerUpdateEvent);
+    VoiceStateUpdateCollectorBuilder, VoiceStateUpdateCollector => VoiceStateUpdate(VoiceStateUpdateEvent);
+    WebhookUpdateCollectorBuilder, WebhookUpdateCollector => WebhookUpdate(WebhookUpdateEvent);
+}
@@ -6,20 +6,16 @@ use std::{
     pin::Pin,
     task::{Context as FutContext, Poll},
 };
-use tokio::{
-    sync::mpsc::{
-        unbounded_channel,
-        UnboundedReceiver as Receiver,
-        UnboundedSender as Sender,
-    },
-    time::{Delay, delay_for},
-};
+use tokio::time::{Delay, Instant, delay_for};
 use futures::{
     future::BoxFuture,
     stream::{Stream, StreamExt},
 };
 use crate::{
+    CacheAndHttp,
     client::bridge::gateway::ShardMessenger,
+    collector::channel::{channel, BufferPolicy, CollectorEnd, ContextFilter, ContextSender, ItemMap, ItemSender, MappedSender, Receiver},
+    collector::registry::{CollectorId, CollectorInfo, CollectorKind},
     model::channel::Message,
 };
 
@@ -49,10 +45,38 @@ macro_rules! impl_message_collector {
                     self
                 }
 
+                /// Sets a filter function like [`filter`], but the `function` is
+                /// additionally given the cache and HTTP client, allowing it to
+                /// check e.g. roles or permissions.
+                ///
+                /// As the cache and HTTP client are accessed asynchronously, the
+                /// `function` returns a future. It is awaited on a separate task,
+                /// one message after another, so a slow filter delays this
+                /// collector but never the shard.
+                ///
+                /// If both filters are set, a message must pass both.
+                ///
+                /// [`filter`]: #method.filter
+                pub fn filter_with_context<F>(mut self, function: F) -> Self
+                where F: Fn(&Arc<Message>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync {
+                    self.filter.as_mut().unwrap().filter_with_context = Some(Arc::new(function));
+
+                    self
+                }
+
                 /// Sets the required author ID of a message.
                 /// If a message does not meet this ID, it won't be received.
                 pub fn author_id(mut self, author_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().author_id = Some(author_id.into());
+                    self.filter.as_mut().unwrap().author_id = vec![author_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed author ID, letting a message pass if it meets any
+                /// of the added IDs.
+                /// If a message meets none of the IDs, it won't be received.
+                pub fn add_author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id.push(author_id.into());
 
                     self
                 }
@@ -60,7 +84,24 @@ macro_rules! impl_message_collector {
                 /// Sets the required channel ID of a message.
                 /// If a message does not meet this ID, it won't be received.
                 pub fn channel_id(mut self, channel_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().channel_id = Some(channel_id.into());
+                    self.filter.as_mut().unwrap().channel_id = vec![channel_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed channel ID, letting a message pass if it meets any
+                /// of the added IDs.
+                /// If a message meets none of the IDs, it won't be received.
+                pub fn add_channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id.push(channel_id.into());
+
+                    self
+                }
+
+                /// Sets the message ID a message must reply to.
+                /// If a message does not reference this message, it won't be received.
+                pub fn reference_id(mut self, message_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().reference_id = Some(message_id.into());
 
                     self
                 }
@@ -68,7 +109,44 @@ macro_rules! impl_message_collector {
                 /// Sets the required guild ID of a message.
                 /// If a message does not meet this ID, it won't be received.
                 pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().guild_id = Some(guild_id.into());
+                    self.filter.as_mut().unwrap().guild_id = vec![guild_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed guild ID, letting a message pass if it meets any
+                /// of the added IDs.
+                /// If a message meets none of the IDs, it won't be received.
+                pub fn add_guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id.push(guild_id.into());
+
+                    self
+                }
+
+                /// Limits the amount of messages buffered for the collector
+                /// to `size`.
+                ///
+                /// By default, the buffer is unbounded, which may let memory
+                /// grow if the collector consumes messages slower than they arrive.
+                /// What happens once the buffer is full is decided by the
+                /// [`buffer_policy`].
+                ///
+                /// [`buffer_policy`]: #method.buffer_policy
+                pub fn buffer(mut self, size: usize) -> Self {
+                    self.filter.as_mut().unwrap().buffer = Some(size);
+
+                    self
+                }
+
+                /// Sets what happens to collected messages when the [`buffer`]
+                /// is full.
+                ///
+                /// Defaults to [`BufferPolicy::DropOldest`].
+                ///
+                /// [`buffer`]: #method.buffer
+                /// [`BufferPolicy::DropOldest`]: ../enum.BufferPolicy.html#variant.DropOldest
+                pub fn buffer_policy(mut self, policy: BufferPolicy) -> Self {
+                    self.filter.as_mut().unwrap().buffer_policy = policy;
 
                     self
                 }
@@ -80,6 +158,19 @@ macro_rules! impl_message_collector {
 
                     self
                 }
+
+                /// Sets a `duration` after which the collector stops if no
+                /// message has been collected in the meantime.
+                ///
+                /// Unlike the [`timeout`], this duration restarts whenever
+                /// a message is collected.
+                ///
+                /// [`timeout`]: #method.timeout
+                pub fn idle_timeout(mut self, duration: Duration) -> Self {
+                    self.idle_timeout = Some(duration);
+
+                    self
+                }
             }
         )*
     }
@@ -88,18 +179,32 @@ macro_rules! impl_message_collector {
 /// Filters events on the shard's end and sends them to the collector.
 #[derive(Clone, Debug)]
 pub struct MessageFilter {
+    id: CollectorId,
+    created: Instant,
     filtered: u32,
     collected: u32,
     options: FilterOptions,
-    sender: Sender<Arc<Message>>,
+    sender: Arc<dyn ItemSender<Arc<Message>>>,
 }
 
 impl MessageFilter {
     /// Creates a new filter
     fn new(options: FilterOptions) -> (Self, Receiver<Arc<Message>>) {
-        let (sender, receiver) = unbounded_channel();
+        Self::new_mapped(options, Arc::new(Some))
+    }
+
+    /// Creates a new filter, mapping messages with `map` before sending them.
+    fn new_mapped<T: Send + 'static>(options: FilterOptions, map: ItemMap<Arc<Message>, T>) -> (Self, Receiver<T>) {
+        let (sender, receiver) = channel(options.buffer, options.buffer_policy);
+        let mut sender: Arc<dyn ItemSender<Arc<Message>>> = Arc::new(MappedSender::new(sender, map));
+
+        if let Some(ref filter) = options.filter_with_context {
+            sender = Arc::new(ContextSender::spawn(sender, Arc::clone(filter), options.collect_limit));
+        }
 
         let filter = Self {
+            id: CollectorId::next(),
+            created: Instant::now(),
             filtered: 0,
             collected: 0,
             sender,
@@ -111,38 +216,76 @@ impl MessageFilter {
 
     /// Sends a `message` to the consuming collector if the `message` conforms
     /// to the constraints and the limits are not reached yet.
-    pub(crate) fn send_message(&mut self, message: &Arc<Message>) -> bool {
-        if self.is_passing_constraints(&message) {
-
-            if self.options.filter.as_ref().map_or(true, |f| f(&message)) {
-                self.collected += 1;
-
-                if let Err(_) = self.sender.send(Arc::clone(message)) {
-                    return false;
-                }
+    pub(crate) async fn send_message(&mut self, message: &Arc<Message>, cache_and_http: &Arc<CacheAndHttp>) -> bool {
+        if self.is_passing_constraints(&message) && self.options.filter.as_ref().map_or(true, |f| f(&message)) {
+            match self.sender.send(message, cache_and_http).await {
+                Ok(true) => self.collected += 1,
+                Ok(false) => (),
+                Err(()) => return false,
             }
         }
 
         self.filtered += 1;
 
-        self.is_within_limits()
+        let end = self.reached_limit();
+        self.sender.set_progress(self.filtered, self.collected, end);
+
+        end.is_none()
     }
 
     /// Checks if the `message` passes set constraints.
     /// Constraints are optional, as it is possible to limit messages to
     /// be sent by a specific author or in a specifc guild.
     fn is_passing_constraints(&self, message: &Arc<Message>) -> bool {
-        self.options.guild_id.map_or(true, |g| { Some(g) == message.guild_id.map(|g| g.0) })
-        && self.options.channel_id.map_or(true, |g| { g == message.channel_id.0 })
-        && self.options.author_id.map_or(true, |g| { g == message.author.id.0 })
+        (self.options.guild_id.is_empty() || message.guild_id.map_or(false, |g| self.options.guild_id.contains(&g.0)))
+        && (self.options.channel_id.is_empty() || self.options.channel_id.contains(&message.channel_id.0))
+        && (self.options.author_id.is_empty() || self.options.author_id.contains(&message.author.id.0))
+        && self.options.reference_id.map_or(true, |id| {
+            message.message_reference.as_ref().and_then(|r| r.message_id).map_or(false, |r| r.0 == id)
+        })
     }
 
-    /// Checks if the filter is within set receive and collect limits.
+    /// Describes the filter and its progress.
+    pub(crate) fn info(&self) -> CollectorInfo {
+        CollectorInfo {
+            id: self.id,
+            kind: CollectorKind::Message,
+            filtered: self.filtered,
+            collected: self.sender.collected().unwrap_or(self.collected),
+            age: self.created.elapsed(),
+            summary: format!("{:?}", self.options),
+        }
+    }
+
+    /// Returns the ID of the collector this filter belongs to.
+    pub(crate) fn id(&self) -> CollectorId {
+        self.id
+    }
+
+    /// Whether the collector stopped receiving messages.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Lets the collector know it has been cancelled.
+    ///
+    /// The filter must be dropped afterwards to end the collector.
+    pub(crate) fn cancel(&self) {
+        self.sender.set_progress(self.filtered, self.collected, Some(CollectorEnd::Cancelled));
+    }
+
+    /// Checks if the filter reached the set receive or collect limit,
+    /// returning which one has been reached.
     /// A message is considered *received* even when it does not meet the
     /// constraints.
-    fn is_within_limits(&self) -> bool {
-        self.options.filter_limit.as_ref().map_or(true, |limit| { self.filtered < *limit })
-        && self.options.collect_limit.as_ref().map_or(true, |limit| { self.collected < *limit })
+    fn reached_limit(&self) -> Option<CollectorEnd> {
+        if self.options.collect_limit.map_or(false, |limit| self.collected >= limit) {
+            Some(CollectorEnd::CollectLimit)
+        } else if self.options.filter_limit.map_or(false, |limit| self.filtered >= limit) {
+            Some(CollectorEnd::FilterLimit)
+        } else {
+            None
+        }
     }
 }
 
@@ -151,10 +294,14 @@ impl MessageFilter {
 struct FilterOptions {
     filter_limit: Option<u32>,
     collect_limit: Option<u32>,
+    buffer: Option<usize>,
+    buffer_policy: BufferPolicy,
     filter: Option<Arc<dyn Fn(&Arc<Message>) -> bool + 'static + Send + Sync>>,
-    channel_id: Option<u64>,
-    guild_id: Option<u64>,
-    author_id: Option<u64>,
+    filter_with_context: Option<ContextFilter<Arc<Message>>>,
+    channel_id: Vec<u64>,
+    guild_id: Vec<u64>,
+    author_id: Vec<u64>,
+    reference_id: Option<u64>,
 }
 
 // Implement the common setters for all message collector types.
@@ -168,6 +315,7 @@ pub struct MessageCollectorBuilder<'a> {
     filter: Option<FilterOptions>,
     shard: Option<ShardMessenger>,
     timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
     fut: Option<BoxFuture<'a, MessageCollector>>,
 }
 
@@ -180,6 +328,7 @@ impl<'a> MessageCollectorBuilder<'a> {
             filter: Some(FilterOptions::default()),
             shard: Some(shard_messenger.as_ref().clone()),
             timeout: None,
+            idle_timeout: None,
             fut: None,
         }
     }
@@ -193,6 +342,35 @@ impl<'a> MessageCollectorBuilder<'a> {
 
         self
     }
+
+    /// Builds the collector and awaits its first message, returning `None`
+    /// if the collector ended before receiving one, e.g. due to a timeout.
+    pub async fn collect_single(self) -> Option<Arc<Message>> {
+        self.collect_limit(1).await.next().await
+    }
+
+    /// Maps messages to items on the shard's end, so the collector yields
+    /// the items instead of whole messages.
+    ///
+    /// The `map` runs after all other filters, a message is only *collected*
+    /// if `map` returns `Some`.
+    ///
+    /// As this changes the collector's type, it must be called after all
+    /// other setters.
+    pub fn map<T, F>(self, map: F) -> MappedMessageCollectorBuilder<'a, T>
+    where
+        T: Send + 'static,
+        F: Fn(Arc<Message>) -> Option<T> + 'static + Send + Sync,
+    {
+        MappedMessageCollectorBuilder {
+            filter: self.filter,
+            shard: self.shard,
+            timeout: self.timeout,
+            idle_timeout: self.idle_timeout,
+            map: Some(Arc::new(map)),
+            fut: None,
+        }
+    }
 }
 
 impl<'a> Future for MessageCollectorBuilder<'a> {
@@ -202,14 +380,61 @@ impl<'a> Future for MessageCollectorBuilder<'a> {
         if self.fut.is_none() {
             let shard_messenger = self.shard.take().unwrap();
             let (filter, receiver) = MessageFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
             let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
 
             self.fut = Some(Box::pin(async move {
                 shard_messenger.set_message_filter(filter);
 
                 MessageCollector {
+                    id,
                     receiver: Box::pin(receiver),
                     timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future building a stream of items mapped from messages, see
+/// [`MessageCollectorBuilder::map`].
+///
+/// [`MessageCollectorBuilder::map`]: struct.MessageCollectorBuilder.html#method.map
+pub struct MappedMessageCollectorBuilder<'a, T> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
+    map: Option<ItemMap<Arc<Message>, T>>,
+    fut: Option<BoxFuture<'a, MessageCollector<T>>>,
+}
+
+impl<'a, T: Send + 'static> Future for MappedMessageCollectorBuilder<'a, T> {
+    type Output = MessageCollector<T>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let map = self.map.take().unwrap();
+            let (filter, receiver) = MessageFilter::new_mapped(self.filter.take().unwrap(), map);
+            let id = filter.id();
+            let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_message_filter(filter);
+
+                MessageCollector {
+                    id,
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
                 }
             }))
         }
@@ -222,6 +447,7 @@ pub struct CollectReply<'a> {
     filter: Option<FilterOptions>,
     shard: Option<ShardMessenger>,
     timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
     fut: Option<BoxFuture<'a, Option<Arc<Message>>>>,
 }
 
@@ -231,6 +457,7 @@ impl<'a> CollectReply<'a> {
             filter: Some(FilterOptions::default()),
             shard: Some(shard_messenger.as_ref().clone()),
             timeout: None,
+            idle_timeout: None,
             fut: None,
         }
     }
@@ -243,14 +470,19 @@ impl<'a> Future for CollectReply<'a> {
         if self.fut.is_none() {
             let shard_messenger = self.shard.take().unwrap();
             let (filter, receiver) = MessageFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
             let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
 
             self.fut = Some(Box::pin(async move {
                 shard_messenger.set_message_filter(filter);
 
                 MessageCollector {
+                    id,
                     receiver: Box::pin(receiver),
                     timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
                 }.next().await
             }))
         }
@@ -263,22 +495,42 @@ impl std::fmt::Debug for FilterOptions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MessageFilter")
             .field("collect_limit", &self.collect_limit)
+            .field("buffer", &self.buffer)
+            .field("buffer_policy", &self.buffer_policy)
             .field("filter", &"Option<Arc<dyn Fn(&Arc<Message>) -> bool + 'static + Send + Sync>>")
+            .field("filter_with_context", &"Option<Arc<dyn Fn(&Arc<Message>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync>>")
             .field("channel_id", &self.channel_id)
             .field("guild_id", &self.guild_id)
             .field("author_id", &self.author_id)
+            .field("reference_id", &self.reference_id)
             .finish()
     }
 }
 
 /// A message collector receives messages matching a the given filter for a
 /// set duration.
-pub struct MessageCollector {
-    receiver: Pin<Box<Receiver<Arc<Message>>>>,
+///
+/// If the collector has been built with [`MessageCollectorBuilder::map`], it
+/// yields the mapped items instead.
+///
+/// [`MessageCollectorBuilder::map`]: struct.MessageCollectorBuilder.html#method.map
+pub struct MessageCollector<T = Arc<Message>> {
+    id: CollectorId,
+    receiver: Pin<Box<Receiver<T>>>,
     timeout: Option<Pin<Box<Delay>>>,
+    idle_timeout: Option<(Duration, Pin<Box<Delay>>)>,
+    end: Option<CollectorEnd>,
 }
 
-impl MessageCollector {
+impl<T> MessageCollector<T> {
+    /// Returns the ID of the collector, used to cancel it via
+    /// [`ShardMessenger::cancel_collector`].
+    ///
+    /// [`ShardMessenger::cancel_collector`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+    pub fn id(&self) -> CollectorId {
+        self.id
+    }
+
     /// Stops collecting, this will implicitly be done once the
     /// collector drops.
     /// In case the drop does not appear until later, it is preferred to
@@ -286,27 +538,198 @@ impl MessageCollector {
     pub fn stop(mut self) {
         self.receiver.close();
     }
+
+    /// Returns how many messages have been checked by the filter so far.
+    pub fn filtered(&self) -> u32 {
+        self.receiver.progress().0
+    }
+
+    /// Returns how many messages passed the filter so far.
+    pub fn collected(&self) -> u32 {
+        self.receiver.progress().1
+    }
+
+    /// Returns why the collector stopped receiving messages.
+    ///
+    /// This is `None` as long as the collector may still yield messages.
+    pub fn end_reason(&self) -> Option<CollectorEnd> {
+        self.end.or_else(|| self.receiver.end())
+    }
+
+    /// Stops receiving, remembering the reason.
+    fn finish(&mut self, end: CollectorEnd) {
+        self.end = Some(end);
+        self.receiver.close();
+    }
 }
 
-impl Stream for MessageCollector {
-    type Item = Arc<Message>;
+impl<T> Stream for MessageCollector<T> {
+    type Item = T;
     fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
         if let Some(ref mut timeout) = self.timeout {
 
             match timeout.as_mut().poll(ctx) {
                 Poll::Ready(_) => {
+                    self.finish(CollectorEnd::Timeout);
+
                     return Poll::Ready(None);
                 },
                 Poll::Pending => (),
             }
         }
 
-        self.receiver.as_mut().poll_next(ctx)
+        let item = self.receiver.as_mut().poll_next(ctx);
+
+        if let Some((duration, idle_timeout)) = &mut self.idle_timeout {
+
+            match item {
+                Poll::Ready(Some(_)) => idle_timeout.reset(Instant::now() + *duration),
+                Poll::Pending => if idle_timeout.as_mut().poll(ctx).is_ready() {
+                    self.finish(CollectorEnd::IdleTimeout);
+
+                    return Poll::Ready(None);
+                },
+                Poll::Ready(None) => (),
+            }
+        }
+
+        item
     }
 }
 
-impl Drop for MessageCollector {
+impl<T> Drop for MessageCollector<T> {
     fn drop(&mut self) {
         self.receiver.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CacheAndHttp,
+        client::bridge::gateway::ShardMessenger,
+        collector::{CollectorEnd, Filters, MessageCollectorBuilder},
+        model::event::{Event, MessageCreateEvent},
+    };
+    #[cfg(feature = "cache")]
+    use crate::model::user::User;
+    use futures::{channel::mpsc, future, FutureExt, StreamExt};
+    use serde_json::json;
+    use std::{sync::Arc, time::Duration};
+
+    fn message_create(channel_id: u64, content: &str) -> Event {
+        let message = serde_json::from_value(json!({
+            "attachments": [],
+            "author": {
+                "avatar": null,
+                "discriminator": "5479",
+                "id": "114941315417899012",
+                "username": "zeyla",
+            },
+            "channel_id": channel_id.to_string(),
+            "content": content,
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "302917639565475840",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2017-04-15T21:26:33.210000+00:00",
+            "tts": false,
+            "type": 0,
+        })).unwrap();
+
+        Event::MessageCreate(MessageCreateEvent {
+            message,
+            _nonexhaustive: (),
+        })
+    }
+
+    #[tokio::test]
+    async fn maps_messages() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let mut collector = MessageCollectorBuilder::new(&messenger)
+            .channel_id(7u64)
+            .collect_limit(1)
+            .map(|message| message.content.parse::<u32>().ok())
+            .await;
+        filters.register(&mut rx).await;
+
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+
+        for &(channel_id, content) in &[(8, "1"), (7, "two"), (7, "3")] {
+            filters.handle_event(&message_create(channel_id, content), &cache_and_http).await;
+        }
+
+        assert_eq!(collector.next().await, Some(3));
+        assert_eq!(collector.next().await, None);
+        assert_eq!(collector.filtered(), 3);
+        assert_eq!(collector.collected(), 1);
+        assert_eq!(collector.end_reason(), Some(CollectorEnd::CollectLimit));
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn filters_with_context() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let collector = MessageCollectorBuilder::new(&messenger)
+            .filter_with_context(|message, cache_and_http| {
+                let channel_id = message.channel_id;
+
+                async move {
+                    cache_and_http.cache.user(channel_id.0).await.is_some()
+                }.boxed()
+            })
+            .collect_limit(2)
+            .await;
+        filters.register(&mut rx).await;
+
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+        let user = serde_json::from_value::<User>(json!({
+            "avatar": null,
+            "discriminator": "5479",
+            "id": "7",
+            "username": "zeyla",
+        })).unwrap();
+        cache_and_http.cache.users.write().await.insert(user.id, user);
+
+        for &(channel_id, content) in &[(7, "first"), (8, "uncached"), (7, "second")] {
+            filters.handle_event(&message_create(channel_id, content), &cache_and_http).await;
+        }
+
+        // The collector ends once the filter's task collected enough
+        // messages, without waiting for the shard to drop the filter.
+        let contents = collector.map(|message| message.content.clone()).collect::<Vec<_>>().await;
+        assert_eq!(contents, vec!["first", "second"]);
+    }
+
+    #[tokio::test]
+    async fn does_not_wait_for_context_filters() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let mut pending = MessageCollectorBuilder::new(&messenger)
+            .filter_with_context(|_, _| future::pending().boxed())
+            .await;
+        filters.register(&mut rx).await;
+        let mut collector = MessageCollectorBuilder::new(&messenger).await;
+        filters.register(&mut rx).await;
+
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+        let event = message_create(7, "content");
+        let handled = filters.handle_event(&event, &cache_and_http);
+        tokio::time::timeout(Duration::from_secs(5), handled).await.unwrap();
+
+        assert_eq!(collector.next().await.map(|message| message.content.clone()), Some("content".to_string()));
+        assert!(pending.next().now_or_never().is_none());
+        assert_eq!(filters.infos()[0].filtered, 1);
+    }
+}
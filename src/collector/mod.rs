@@ -1,8 +1,17 @@
 //! Collectors will receive events from the contextual shard, check if the
 //! filter lets them pass, and collects if the receive, collect, or time limits
 //! are not reached yet.
+mod channel;
+pub mod event_collector;
 pub mod message_collector;
+pub mod modal_interaction_collector;
 pub mod reaction_collector;
+mod registry;
 
+pub use channel::{BufferPolicy, CollectorEnd};
+pub use event_collector::*;
 pub use message_collector::*;
+pub use modal_interaction_collector::*;
 pub use reaction_collector::*;
+pub use registry::{CollectorId, CollectorInfo, CollectorKind};
+pub(crate) use registry::Filters;
@@ -0,0 +1,433 @@
+use std::{
+    boxed::Box,
+    future::Future,
+    sync::Arc,
+    time::Duration,
+    pin::Pin,
+    task::{Context as FutContext, Poll},
+};
+use futures::{
+    future::BoxFuture,
+    stream::{Stream, StreamExt},
+};
+use crate::{
+    client::bridge::gateway::ShardMessenger,
+    collector::channel::{BufferPolicy, CollectorEnd},
+    collector::event_collector::{EventCollector, EventCollectorBuilder, MappedEventCollectorBuilder},
+    collector::registry::CollectorId,
+    model::event::{Event, EventType},
+    model::interactions::{Interaction, InteractionData},
+};
+
+macro_rules! impl_modal_interaction_collector {
+    ($($name:ident;)*) => {
+        $(
+            impl<'a> $name<'a> {
+                /// Sets the developer defined ID of the modal.
+                /// If a submission is not for a modal with this ID, it won't be received.
+                pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+                    self.filter.as_mut().unwrap().custom_id = Some(custom_id.into());
+
+                    self
+                }
+
+                /// Sets the required user ID of a submission.
+                /// If a modal is not submitted by a user with this ID, it won't be received.
+                pub fn author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id = Some(author_id.into());
+
+                    self
+                }
+
+                /// Sets the channel in which the modal must be submitted.
+                /// If a modal is not submitted in a channel with this ID, it won't be received.
+                pub fn channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id = Some(channel_id.into());
+
+                    self
+                }
+
+                /// Sets the guild in which the modal must be submitted.
+                /// If a modal is not submitted in a guild with this ID, it won't be received.
+                pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id = Some(guild_id.into());
+
+                    self
+                }
+
+                /// Sets a filter function where submissions passed to the `function` must
+                /// return `true`, otherwise the submission won't be collected.
+                /// This is the last instance to pass for a submission to count as *collected*.
+                pub fn filter<F: Fn(&Interaction) -> bool + 'static + Send + Sync>(mut self, function: F) -> Self {
+                    self.filter.as_mut().unwrap().filter = Some(Arc::new(function));
+
+                    self
+                }
+
+                /// Limits the amount of submissions buffered for the collector
+                /// to `size`.
+                ///
+                /// By default, the buffer is unbounded, which may let memory
+                /// grow if the collector consumes submissions slower than they arrive.
+                /// What happens once the buffer is full is decided by the
+                /// [`buffer_policy`].
+                ///
+                /// [`buffer_policy`]: #method.buffer_policy
+                pub fn buffer(mut self, size: usize) -> Self {
+                    self.filter.as_mut().unwrap().buffer = Some(size);
+
+                    self
+                }
+
+                /// Sets what happens to collected submissions when the [`buffer`]
+                /// is full.
+                ///
+                /// Defaults to [`BufferPolicy::DropOldest`].
+                ///
+                /// [`buffer`]: #method.buffer
+                /// [`BufferPolicy::DropOldest`]: ../enum.BufferPolicy.html#variant.DropOldest
+                pub fn buffer_policy(mut self, policy: BufferPolicy) -> Self {
+                    self.filter.as_mut().unwrap().buffer_policy = policy;
+
+                    self
+                }
+
+                /// Sets a `duration` for how long the collector shall receive
+                /// submissions.
+                pub fn timeout(mut self, duration: Duration) -> Self {
+                    self.filter.as_mut().unwrap().timeout = Some(duration);
+
+                    self
+                }
+
+                /// Sets a `duration` after which the collector stops if no
+                /// submission has been collected in the meantime.
+                ///
+                /// Unlike the [`timeout`], this duration restarts whenever
+                /// a submission is collected.
+                ///
+                /// [`timeout`]: #method.timeout
+                pub fn idle_timeout(mut self, duration: Duration) -> Self {
+                    self.filter.as_mut().unwrap().idle_timeout = Some(duration);
+
+                    self
+                }
+            }
+        )*
+    }
+}
+
+#[derive(Clone, Default)]
+struct FilterOptions {
+    collect_limit: Option<u32>,
+    timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    buffer: Option<usize>,
+    buffer_policy: BufferPolicy,
+    filter: Option<Arc<dyn Fn(&Interaction) -> bool + 'static + Send + Sync>>,
+    custom_id: Option<String>,
+    channel_id: Option<u64>,
+    guild_id: Option<u64>,
+    author_id: Option<u64>,
+}
+
+impl FilterOptions {
+    /// Builds an event collector receiving the modal submissions that pass
+    /// the options.
+    fn into_builder<'a>(self, shard_messenger: ShardMessenger) -> MappedEventCollectorBuilder<'a, Arc<Interaction>> {
+        let mut builder = EventCollectorBuilder::new(shard_messenger)
+            .add_event_type(EventType::InteractionCreate);
+
+        if let Some(author_id) = self.author_id {
+            builder = builder.author_id(author_id);
+        }
+
+        if let Some(channel_id) = self.channel_id {
+            builder = builder.channel_id(channel_id);
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            builder = builder.guild_id(guild_id);
+        }
+
+        if let Some(limit) = self.collect_limit {
+            builder = builder.collect_limit(limit);
+        }
+
+        if let Some(duration) = self.timeout {
+            builder = builder.timeout(duration);
+        }
+
+        if let Some(duration) = self.idle_timeout {
+            builder = builder.idle_timeout(duration);
+        }
+
+        if let Some(size) = self.buffer {
+            builder = builder.buffer(size);
+        }
+
+        let custom_id = self.custom_id;
+        let filter = self.filter;
+
+        builder
+            .buffer_policy(self.buffer_policy)
+            .filter(move |event| match **event {
+                Event::InteractionCreate(ref event) => is_matching_submission(&event.interaction, custom_id.as_deref())
+                    && filter.as_ref().map_or(true, |f| f(&event.interaction)),
+                _ => false,
+            })
+            .map(|event| match *event {
+                Event::InteractionCreate(ref event) => Some(Arc::new(event.interaction.clone())),
+                _ => None,
+            })
+    }
+}
+
+/// Checks whether the `interaction` is a modal submission, optionally of the
+/// modal with the given `custom_id`.
+fn is_matching_submission(interaction: &Interaction, custom_id: Option<&str>) -> bool {
+    match interaction.data {
+        Some(InteractionData::ModalSubmit(ref data)) => custom_id.map_or(true, |id| id == data.custom_id),
+        _ => false,
+    }
+}
+
+// Implement the common setters for all modal interaction collector types.
+impl_modal_interaction_collector! {
+    CollectModalInteraction;
+    ModalInteractionCollectorBuilder;
+}
+
+/// Future building a stream of modal submissions.
+pub struct ModalInteractionCollectorBuilder<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    fut: Option<BoxFuture<'a, ModalInteractionCollector>>,
+}
+
+impl<'a> ModalInteractionCollectorBuilder<'a> {
+    /// A future that builds a [`ModalInteractionCollector`] based on the settings.
+    ///
+    /// [`ModalInteractionCollector`]: ../struct.ModalInteractionCollector.html
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions::default()),
+            shard: Some(shard_messenger.as_ref().clone()),
+            fut: None,
+        }
+    }
+
+    /// Limits how many submissions can be collected.
+    ///
+    /// A submission is considered *collected*, if the submission
+    /// passes all the requirements.
+    pub fn collect_limit(mut self, limit: u32) -> Self {
+        self.filter.as_mut().unwrap().collect_limit = Some(limit);
+
+        self
+    }
+
+    /// Builds the collector and awaits its first submission, returning `None`
+    /// if the collector ended before receiving one, e.g. due to a timeout.
+    pub async fn collect_single(self) -> Option<Arc<Interaction>> {
+        self.collect_limit(1).await.next().await
+    }
+}
+
+impl<'a> Future for ModalInteractionCollectorBuilder<'a> {
+    type Output = ModalInteractionCollector;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let builder = self.filter.take().unwrap().into_builder(shard_messenger);
+
+            self.fut = Some(Box::pin(async move {
+                ModalInteractionCollector {
+                    inner: builder.await,
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future awaiting a single modal submission.
+pub struct CollectModalInteraction<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    fut: Option<BoxFuture<'a, Option<Arc<Interaction>>>>,
+}
+
+impl<'a> CollectModalInteraction<'a> {
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions {
+                collect_limit: Some(1),
+                ..FilterOptions::default()
+            }),
+            shard: Some(shard_messenger.as_ref().clone()),
+            fut: None,
+        }
+    }
+}
+
+impl<'a> Future for CollectModalInteraction<'a> {
+    type Output = Option<Arc<Interaction>>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let builder = self.filter.take().unwrap().into_builder(shard_messenger);
+
+            self.fut = Some(Box::pin(async move {
+                builder.await.next().await
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// A modal interaction collector receives the submissions of modals matching
+/// the given filter for a set duration.
+///
+/// The collector is backed by an event collector, so it is listed as
+/// [`CollectorKind::Event`] by [`ShardMessenger::collectors`].
+///
+/// [`CollectorKind::Event`]: ../enum.CollectorKind.html#variant.Event
+/// [`ShardMessenger::collectors`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.collectors
+pub struct ModalInteractionCollector {
+    inner: EventCollector<Arc<Interaction>>,
+}
+
+impl ModalInteractionCollector {
+    /// Returns the ID of the collector, used to cancel it via
+    /// [`ShardMessenger::cancel_collector`].
+    ///
+    /// [`ShardMessenger::cancel_collector`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+    pub fn id(&self) -> CollectorId {
+        self.inner.id()
+    }
+
+    /// Stops collecting, this will implicitly be done once the
+    /// collector drops.
+    /// In case the drop does not appear until later, it is preferred to
+    /// stop the collector early.
+    pub fn stop(self) {
+        self.inner.stop();
+    }
+
+    /// Returns how many interactions have been checked by the filter so far.
+    pub fn filtered(&self) -> u32 {
+        self.inner.filtered()
+    }
+
+    /// Returns how many submissions passed the filter so far.
+    pub fn collected(&self) -> u32 {
+        self.inner.collected()
+    }
+
+    /// Returns why the collector stopped receiving submissions.
+    ///
+    /// This is `None` as long as the collector may still yield submissions.
+    pub fn end_reason(&self) -> Option<CollectorEnd> {
+        self.inner.end_reason()
+    }
+}
+
+impl Stream for ModalInteractionCollector {
+    type Item = Arc<Interaction>;
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CacheAndHttp,
+        client::bridge::gateway::ShardMessenger,
+        collector::{CollectorEnd, Filters, ModalInteractionCollectorBuilder},
+        model::event::{Event, InteractionCreateEvent},
+        model::interactions::InteractionData,
+    };
+    use futures::{channel::mpsc, StreamExt};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn modal_submit(custom_id: &str, user_id: u64) -> Event {
+        let interaction = serde_json::from_value(json!({
+            "id": "846462639134605313",
+            "application_id": "775799577604522054",
+            "type": 5,
+            "data": {
+                "custom_id": custom_id,
+                "components": [{
+                    "type": 1,
+                    "components": [{"type": 4, "custom_id": "text", "value": "Looks good"}],
+                }],
+            },
+            "channel_id": "645027906669510667",
+            "user": {
+                "avatar": null,
+                "discriminator": "1337",
+                "id": user_id.to_string(),
+                "username": "Mason",
+            },
+            "token": "A_UNIQUE_TOKEN",
+            "version": 1,
+        })).unwrap();
+
+        Event::InteractionCreate(InteractionCreateEvent {
+            interaction,
+            _nonexhaustive: (),
+        })
+    }
+
+    #[tokio::test]
+    async fn collects_matching_submissions() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let mut collector = ModalInteractionCollectorBuilder::new(&messenger)
+            .custom_id("feedback")
+            .author_id(1u64)
+            .collect_limit(1)
+            .await;
+        filters.register(&mut rx).await;
+
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+
+        for event in &[modal_submit("survey", 1), modal_submit("feedback", 2), modal_submit("feedback", 1)] {
+            filters.handle_event(event, &cache_and_http).await;
+        }
+
+        let submission = collector.next().await.unwrap();
+
+        match submission.data {
+            Some(InteractionData::ModalSubmit(ref data)) => assert_eq!(data.value("text"), Some("Looks good")),
+            _ => panic!("expected a modal submission"),
+        }
+
+        assert_eq!(submission.user.as_ref().map(|u| u.id.0), Some(1));
+        assert!(collector.next().await.is_none());
+        assert_eq!(collector.filtered(), 3);
+        assert_eq!(collector.end_reason(), Some(CollectorEnd::CollectLimit));
+    }
+
+    #[tokio::test]
+    async fn times_out() {
+        let (tx, _rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+
+        let mut collector = ModalInteractionCollectorBuilder::new(&messenger)
+            .timeout(std::time::Duration::from_millis(1))
+            .await;
+
+        assert!(collector.next().await.is_none());
+        assert_eq!(collector.end_reason(), Some(CollectorEnd::Timeout));
+    }
+}
@@ -6,21 +6,17 @@ use std::{
     pin::Pin,
     task::{Context as FutContext, Poll},
 };
-use tokio::{
-    sync::mpsc::{
-        unbounded_channel,
-        UnboundedReceiver as Receiver,
-        UnboundedSender as Sender,
-    },
-    time::{Delay, delay_for},
-};
+use tokio::time::{Delay, Instant, delay_for};
 use futures::{
     future::BoxFuture,
     stream::{Stream, StreamExt},
 };
 use crate::{
+    CacheAndHttp,
     client::bridge::gateway::ShardMessenger,
-    model::channel::Reaction,
+    collector::channel::{channel, BufferPolicy, CollectorEnd, ContextFilter, ContextSender, ItemMap, ItemSender, MappedSender, Receiver},
+    collector::registry::{CollectorId, CollectorInfo, CollectorKind},
+    model::channel::{Reaction, ReactionType},
     model::id::UserId,
 };
 
@@ -59,10 +55,38 @@ macro_rules! impl_reaction_collector {
                     self
                 }
 
+                /// Sets a filter function like [`filter`], but the `function` is
+                /// additionally given the cache and HTTP client, allowing it to
+                /// check e.g. roles or permissions.
+                ///
+                /// As the cache and HTTP client are accessed asynchronously, the
+                /// `function` returns a future. It is awaited on a separate task,
+                /// one reaction after another, so a slow filter delays this
+                /// collector but never the shard.
+                ///
+                /// If both filters are set, a reaction must pass both.
+                ///
+                /// [`filter`]: #method.filter
+                pub fn filter_with_context<F>(mut self, function: F) -> Self
+                where F: Fn(&Arc<Reaction>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync {
+                    self.filter.as_mut().unwrap().filter_with_context = Some(Arc::new(function));
+
+                    self
+                }
+
                 /// Sets the required author ID of a reaction.
                 /// If a reaction is not issued by a user with this ID, it won't be received.
                 pub fn author_id(mut self, author_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().author_id = Some(author_id.into());
+                    self.filter.as_mut().unwrap().author_id = vec![author_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed author ID, letting a reaction pass if it meets any
+                /// of the added IDs.
+                /// If a reaction meets none of the IDs, it won't be received.
+                pub fn add_author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id.push(author_id.into());
 
                     self
                 }
@@ -78,7 +102,16 @@ macro_rules! impl_reaction_collector {
                 /// Sets the guild in which the reaction must occur.
                 /// If a reaction is not on a message with this ID, it won't be received.
                 pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().guild_id = Some(guild_id.into());
+                    self.filter.as_mut().unwrap().guild_id = vec![guild_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed guild ID, letting a reaction pass if it meets any
+                /// of the added IDs.
+                /// If a reaction meets none of the IDs, it won't be received.
+                pub fn add_guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id.push(guild_id.into());
 
                     self
                 }
@@ -86,7 +119,38 @@ macro_rules! impl_reaction_collector {
                 /// Sets the channel on which the reaction must occur.
                 /// If a reaction is not on a message with this ID, it won't be received.
                 pub fn channel_id(mut self, channel_id: impl Into<u64>) -> Self {
-                    self.filter.as_mut().unwrap().channel_id = Some(channel_id.into());
+                    self.filter.as_mut().unwrap().channel_id = vec![channel_id.into()];
+
+                    self
+                }
+
+                /// Adds an allowed channel ID, letting a reaction pass if it meets any
+                /// of the added IDs.
+                /// If a reaction meets none of the IDs, it won't be received.
+                pub fn add_channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id.push(channel_id.into());
+
+                    self
+                }
+
+                /// Adds an emoji to the set of accepted emojis.
+                /// If at least one emoji is accepted, reactions using any other
+                /// emoji won't be received.
+                ///
+                /// Custom emojis are compared by their ID only.
+                pub fn accept_emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+                    self.filter.as_mut().unwrap().accepted_emojis.push(emoji.into());
+
+                    self
+                }
+
+                /// Adds an emoji to the set of denied emojis.
+                /// Reactions using a denied emoji won't be received, even if
+                /// the emoji has been accepted as well.
+                ///
+                /// Custom emojis are compared by their ID only.
+                pub fn deny_emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+                    self.filter.as_mut().unwrap().denied_emojis.push(emoji.into());
 
                     self
                 }
@@ -109,6 +173,34 @@ macro_rules! impl_reaction_collector {
                     self
                 }
 
+                /// Limits the amount of reactions buffered for the collector
+                /// to `size`.
+                ///
+                /// By default, the buffer is unbounded, which may let memory
+                /// grow if the collector consumes reactions slower than they arrive.
+                /// What happens once the buffer is full is decided by the
+                /// [`buffer_policy`].
+                ///
+                /// [`buffer_policy`]: #method.buffer_policy
+                pub fn buffer(mut self, size: usize) -> Self {
+                    self.filter.as_mut().unwrap().buffer = Some(size);
+
+                    self
+                }
+
+                /// Sets what happens to collected reactions when the [`buffer`]
+                /// is full.
+                ///
+                /// Defaults to [`BufferPolicy::DropOldest`].
+                ///
+                /// [`buffer`]: #method.buffer
+                /// [`BufferPolicy::DropOldest`]: ../enum.BufferPolicy.html#variant.DropOldest
+                pub fn buffer_policy(mut self, policy: BufferPolicy) -> Self {
+                    self.filter.as_mut().unwrap().buffer_policy = policy;
+
+                    self
+                }
+
                 /// Sets a `duration` for how long the collector shall receive
                 /// reactions.
                 pub fn timeout(mut self, duration: Duration) -> Self {
@@ -116,6 +208,19 @@ macro_rules! impl_reaction_collector {
 
                     self
                 }
+
+                /// Sets a `duration` after which the collector stops if no
+                /// reaction has been collected in the meantime.
+                ///
+                /// Unlike the [`timeout`], this duration restarts whenever
+                /// a reaction is collected.
+                ///
+                /// [`timeout`]: #method.timeout
+                pub fn idle_timeout(mut self, duration: Duration) -> Self {
+                    self.idle_timeout = Some(duration);
+
+                    self
+                }
             }
         )*
     }
@@ -136,6 +241,11 @@ impl ReactionAction {
         }
     }
 
+    /// Returns the emoji that has been added or removed.
+    pub fn emoji(&self) -> &ReactionType {
+        &self.as_inner_ref().emoji
+    }
+
     pub fn is_added(&self) -> bool {
         if let Self::Added(_) = &self {
             true
@@ -156,18 +266,36 @@ impl ReactionAction {
 /// Filters events on the shard's end and sends them to the collector.
 #[derive(Clone, Debug)]
 pub struct ReactionFilter {
+    id: CollectorId,
+    created: Instant,
     filtered: u32,
     collected: u32,
     options: FilterOptions,
-    sender: Sender<Arc<ReactionAction>>,
+    sender: Arc<dyn ItemSender<Arc<ReactionAction>>>,
 }
 
 impl ReactionFilter {
     /// Creates a new filter
     fn new(options: FilterOptions) -> (Self, Receiver<Arc<ReactionAction>>) {
-        let (sender, receiver) = unbounded_channel();
+        Self::new_mapped(options, Arc::new(Some))
+    }
+
+    /// Creates a new filter, mapping reactions with `map` before sending them.
+    fn new_mapped<T: Send + 'static>(options: FilterOptions, map: ItemMap<Arc<ReactionAction>, T>) -> (Self, Receiver<T>) {
+        let (sender, receiver) = channel(options.buffer, options.buffer_policy);
+        let mut sender: Arc<dyn ItemSender<Arc<ReactionAction>>> = Arc::new(MappedSender::new(sender, map));
+
+        if let Some(ref filter) = options.filter_with_context {
+            let filter = Arc::clone(filter);
+
+            sender = Arc::new(ContextSender::spawn(sender, Arc::new(move |reaction: &Arc<ReactionAction>, cache_and_http| {
+                filter(reaction.as_inner_ref(), cache_and_http)
+            }), options.collect_limit));
+        }
 
         let filter = Self {
+            id: CollectorId::next(),
+            created: Instant::now(),
             filtered: 0,
             collected: 0,
             sender,
@@ -179,18 +307,22 @@ impl ReactionFilter {
 
     /// Sends a `reaction` to the consuming collector if the `reaction` conforms
     /// to the constraints and the limits are not reached yet.
-    pub(crate) fn send_reaction(&mut self, reaction: &Arc<ReactionAction>) -> bool {
+    pub(crate) async fn send_reaction(&mut self, reaction: &Arc<ReactionAction>, cache_and_http: &Arc<CacheAndHttp>) -> bool {
         if self.is_passing_constraints(&reaction) {
-            self.collected += 1;
 
-            if self.sender.send(Arc::clone(reaction)).is_err() {
-                return false;
+            match self.sender.send(reaction, cache_and_http).await {
+                Ok(true) => self.collected += 1,
+                Ok(false) => (),
+                Err(()) => return false,
             }
         }
 
         self.filtered += 1;
 
-        self.is_within_limits()
+        let end = self.reached_limit();
+        self.sender.set_progress(self.filtered, self.collected, end);
+
+        end.is_none()
     }
 
     /// Checks if the `reaction` passes set constraints.
@@ -210,20 +342,67 @@ impl ReactionFilter {
             },
         };
 
-        self.options.guild_id.map_or(true, |id| { Some(id) == reaction.guild_id.map(|g| g.0) })
+        (self.options.guild_id.is_empty() || reaction.guild_id.map_or(false, |g| self.options.guild_id.contains(&g.0)))
         && self.options.message_id.map_or(true, |id| { id == reaction.message_id.0 })
-        && self.options.channel_id.map_or(true, |id| { id == reaction.channel_id.0 })
-        && self.options.author_id.map_or(true, |id| { id == reaction.user_id.unwrap_or(UserId(0)).0 })
+        && (self.options.channel_id.is_empty() || self.options.channel_id.contains(&reaction.channel_id.0))
+        && (self.options.author_id.is_empty() || self.options.author_id.contains(&reaction.user_id.unwrap_or(UserId(0)).0))
+        && (self.options.accepted_emojis.is_empty() || self.options.accepted_emojis.iter().any(|e| is_same_emoji(e, &reaction.emoji)))
+        && !self.options.denied_emojis.iter().any(|e| is_same_emoji(e, &reaction.emoji))
         && self.options.filter.as_ref().map_or(true, |f| f(&reaction))
     }
 
 
-    /// Checks if the filter is within set receive and collect limits.
+    /// Describes the filter and its progress.
+    pub(crate) fn info(&self) -> CollectorInfo {
+        CollectorInfo {
+            id: self.id,
+            kind: CollectorKind::Reaction,
+            filtered: self.filtered,
+            collected: self.sender.collected().unwrap_or(self.collected),
+            age: self.created.elapsed(),
+            summary: format!("{:?}", self.options),
+        }
+    }
+
+    /// Returns the ID of the collector this filter belongs to.
+    pub(crate) fn id(&self) -> CollectorId {
+        self.id
+    }
+
+    /// Whether the collector stopped receiving reactions.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Lets the collector know it has been cancelled.
+    ///
+    /// The filter must be dropped afterwards to end the collector.
+    pub(crate) fn cancel(&self) {
+        self.sender.set_progress(self.filtered, self.collected, Some(CollectorEnd::Cancelled));
+    }
+
+    /// Checks if the filter reached the set receive or collect limit,
+    /// returning which one has been reached.
     /// A reaction is considered *received* even when it does not meet the
     /// constraints.
-    fn is_within_limits(&self) -> bool {
-        self.options.filter_limit.map_or(true, |limit| { self.filtered < limit })
-        && self.options.collect_limit.map_or(true, |limit| { self.collected < limit })
+    fn reached_limit(&self) -> Option<CollectorEnd> {
+        if self.options.collect_limit.map_or(false, |limit| self.collected >= limit) {
+            Some(CollectorEnd::CollectLimit)
+        } else if self.options.filter_limit.map_or(false, |limit| self.filtered >= limit) {
+            Some(CollectorEnd::FilterLimit)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether two emojis are the same, ignoring the name and animation
+/// state of custom emojis.
+fn is_same_emoji(a: &ReactionType, b: &ReactionType) -> bool {
+    match (a, b) {
+        (ReactionType::Custom { id: a, .. }, ReactionType::Custom { id: b, .. }) => a == b,
+        (ReactionType::Unicode(a), ReactionType::Unicode(b)) => a == b,
+        _ => false,
     }
 }
 
@@ -231,11 +410,16 @@ impl ReactionFilter {
 struct FilterOptions {
     filter_limit: Option<u32>,
     collect_limit: Option<u32>,
+    buffer: Option<usize>,
+    buffer_policy: BufferPolicy,
     filter: Option<Arc<dyn Fn(&Arc<Reaction>) -> bool + 'static + Send + Sync>>,
-    channel_id: Option<u64>,
-    guild_id: Option<u64>,
-    author_id: Option<u64>,
+    filter_with_context: Option<ContextFilter<Arc<Reaction>>>,
+    channel_id: Vec<u64>,
+    guild_id: Vec<u64>,
+    author_id: Vec<u64>,
     message_id: Option<u64>,
+    accepted_emojis: Vec<ReactionType>,
+    denied_emojis: Vec<ReactionType>,
     accept_added: bool,
     accept_removed: bool,
 }
@@ -245,11 +429,16 @@ impl Default for FilterOptions {
         Self {
             filter_limit: None,
             collect_limit: None,
+            buffer: None,
+            buffer_policy: BufferPolicy::default(),
             filter: None,
-            channel_id: None,
-            guild_id: None,
-            author_id: None,
+            filter_with_context: None,
+            channel_id: Vec::new(),
+            guild_id: Vec::new(),
+            author_id: Vec::new(),
             message_id: None,
+            accepted_emojis: Vec::new(),
+            denied_emojis: Vec::new(),
             accept_added: true,
             accept_removed: false,
         }
@@ -268,6 +457,7 @@ pub struct ReactionCollectorBuilder<'a> {
     filter: Option<FilterOptions>,
     shard: Option<ShardMessenger>,
     timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
     fut: Option<BoxFuture<'a, ReactionCollector>>,
 }
 
@@ -277,6 +467,36 @@ impl<'a> ReactionCollectorBuilder<'a> {
             filter: Some(FilterOptions::default()),
             shard: Some(shard_messenger.as_ref().clone()),
             timeout: None,
+            idle_timeout: None,
+            fut: None,
+        }
+    }
+
+    /// Builds the collector and awaits its first reaction, returning `None`
+    /// if the collector ended before receiving one, e.g. due to a timeout.
+    pub async fn collect_single(self) -> Option<Arc<ReactionAction>> {
+        self.collect_limit(1).await.next().await
+    }
+
+    /// Maps reactions to items on the shard's end, so the collector yields
+    /// the items instead of reaction actions.
+    ///
+    /// The `map` runs after all other filters, a reaction is only *collected*
+    /// if `map` returns `Some`.
+    ///
+    /// As this changes the collector's type, it must be called after all
+    /// other setters.
+    pub fn map<T, F>(self, map: F) -> MappedReactionCollectorBuilder<'a, T>
+    where
+        T: Send + 'static,
+        F: Fn(Arc<ReactionAction>) -> Option<T> + 'static + Send + Sync,
+    {
+        MappedReactionCollectorBuilder {
+            filter: self.filter,
+            shard: self.shard,
+            timeout: self.timeout,
+            idle_timeout: self.idle_timeout,
+            map: Some(Arc::new(map)),
             fut: None,
         }
     }
@@ -289,14 +509,61 @@ impl<'a> Future for ReactionCollectorBuilder<'a> {
         if self.fut.is_none() {
             let shard_messenger = self.shard.take().unwrap();
             let (filter, receiver) = ReactionFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
             let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
 
             self.fut = Some(Box::pin(async move {
                 shard_messenger.set_reaction_filter(filter);
 
                 ReactionCollector {
+                    id,
                     receiver: Box::pin(receiver),
                     timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future building a stream of items mapped from reactions, see
+/// [`ReactionCollectorBuilder::map`].
+///
+/// [`ReactionCollectorBuilder::map`]: struct.ReactionCollectorBuilder.html#method.map
+pub struct MappedReactionCollectorBuilder<'a, T> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
+    map: Option<ItemMap<Arc<ReactionAction>, T>>,
+    fut: Option<BoxFuture<'a, ReactionCollector<T>>>,
+}
+
+impl<'a, T: Send + 'static> Future for MappedReactionCollectorBuilder<'a, T> {
+    type Output = ReactionCollector<T>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let map = self.map.take().unwrap();
+            let (filter, receiver) = ReactionFilter::new_mapped(self.filter.take().unwrap(), map);
+            let id = filter.id();
+            let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_reaction_filter(filter);
+
+                ReactionCollector {
+                    id,
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
                 }
             }))
         }
@@ -309,6 +576,7 @@ pub struct CollectReaction<'a> {
     filter: Option<FilterOptions>,
     shard: Option<ShardMessenger>,
     timeout: Option<Delay>,
+    idle_timeout: Option<Duration>,
     fut: Option<BoxFuture<'a, Option<Arc<ReactionAction>>>>,
 }
 
@@ -318,6 +586,7 @@ impl<'a> CollectReaction<'a> {
             filter: Some(FilterOptions::default()),
             shard: Some(shard_messenger.as_ref().clone()),
             timeout: None,
+            idle_timeout: None,
             fut: None,
         }
     }
@@ -330,14 +599,19 @@ impl<'a> Future for CollectReaction<'a> {
         if self.fut.is_none() {
             let shard_messenger = self.shard.take().unwrap();
             let (filter, receiver) = ReactionFilter::new(self.filter.take().unwrap());
+            let id = filter.id();
             let timeout = self.timeout.take();
+            let idle_timeout = self.idle_timeout.take();
 
             self.fut = Some(Box::pin(async move {
                 shard_messenger.set_reaction_filter(filter);
 
                 ReactionCollector {
+                    id,
                     receiver: Box::pin(receiver),
                     timeout: timeout.map(Box::pin),
+                    idle_timeout: idle_timeout.map(|d| (d, Box::pin(delay_for(d)))),
+                    end: None,
                 }.next().await
             }))
         }
@@ -350,22 +624,43 @@ impl std::fmt::Debug for FilterOptions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ReactionFilter")
             .field("collect_limit", &self.collect_limit)
+            .field("buffer", &self.buffer)
+            .field("buffer_policy", &self.buffer_policy)
             .field("filter", &"Option<Arc<dyn Fn(&Arc<Reaction>) -> bool + 'static + Send + Sync>>")
+            .field("filter_with_context", &"Option<Arc<dyn Fn(&Arc<Reaction>, Arc<CacheAndHttp>) -> BoxFuture<'static, bool> + 'static + Send + Sync>>")
             .field("channel_id", &self.channel_id)
             .field("guild_id", &self.guild_id)
             .field("author_id", &self.author_id)
+            .field("accepted_emojis", &self.accepted_emojis)
+            .field("denied_emojis", &self.denied_emojis)
             .finish()
     }
 }
 
 /// A reaction collector receives reactions matching a the given filter for a
 /// set duration.
-pub struct ReactionCollector {
-    receiver: Pin<Box<Receiver<Arc<ReactionAction>>>>,
+///
+/// If the collector has been built with [`ReactionCollectorBuilder::map`], it
+/// yields the mapped items instead.
+///
+/// [`ReactionCollectorBuilder::map`]: struct.ReactionCollectorBuilder.html#method.map
+pub struct ReactionCollector<T = Arc<ReactionAction>> {
+    id: CollectorId,
+    receiver: Pin<Box<Receiver<T>>>,
     timeout: Option<Pin<Box<Delay>>>,
+    idle_timeout: Option<(Duration, Pin<Box<Delay>>)>,
+    end: Option<CollectorEnd>,
 }
 
-impl ReactionCollector {
+impl<T> ReactionCollector<T> {
+    /// Returns the ID of the collector, used to cancel it via
+    /// [`ShardMessenger::cancel_collector`].
+    ///
+    /// [`ShardMessenger::cancel_collector`]: ../../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+    pub fn id(&self) -> CollectorId {
+        self.id
+    }
+
     /// Stops collecting, this will implicitly be done once the
     /// collector drops.
     /// In case the drop does not appear until later, it is preferred to
@@ -373,27 +668,126 @@ impl ReactionCollector {
     pub fn stop(mut self) {
         self.receiver.close();
     }
+
+    /// Returns how many reactions have been checked by the filter so far.
+    pub fn filtered(&self) -> u32 {
+        self.receiver.progress().0
+    }
+
+    /// Returns how many reactions passed the filter so far.
+    pub fn collected(&self) -> u32 {
+        self.receiver.progress().1
+    }
+
+    /// Returns why the collector stopped receiving reactions.
+    ///
+    /// This is `None` as long as the collector may still yield reactions.
+    pub fn end_reason(&self) -> Option<CollectorEnd> {
+        self.end.or_else(|| self.receiver.end())
+    }
+
+    /// Stops receiving, remembering the reason.
+    fn finish(&mut self, end: CollectorEnd) {
+        self.end = Some(end);
+        self.receiver.close();
+    }
 }
 
-impl Stream for ReactionCollector {
-    type Item = Arc<ReactionAction>;
+impl<T> Stream for ReactionCollector<T> {
+    type Item = T;
     fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
         if let Some(ref mut timeout) = self.timeout {
 
             match timeout.as_mut().poll(ctx) {
                 Poll::Ready(_) => {
+                    self.finish(CollectorEnd::Timeout);
+
                     return Poll::Ready(None);
                 },
                 Poll::Pending => (),
             }
         }
 
-        self.receiver.as_mut().poll_next(ctx)
+        let item = self.receiver.as_mut().poll_next(ctx);
+
+        if let Some((duration, idle_timeout)) = &mut self.idle_timeout {
+
+            match item {
+                Poll::Ready(Some(_)) => idle_timeout.reset(Instant::now() + *duration),
+                Poll::Pending => if idle_timeout.as_mut().poll(ctx).is_ready() {
+                    self.finish(CollectorEnd::IdleTimeout);
+
+                    return Poll::Ready(None);
+                },
+                Poll::Ready(None) => (),
+            }
+        }
+
+        item
     }
 }
 
-impl Drop for ReactionCollector {
+impl<T> Drop for ReactionCollector<T> {
     fn drop(&mut self) {
         self.receiver.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CacheAndHttp,
+        client::bridge::gateway::ShardMessenger,
+        collector::{Filters, ReactionCollectorBuilder},
+        model::channel::ReactionType,
+        model::event::{Event, ReactionAddEvent, ReactionRemoveEvent},
+    };
+    use futures::{channel::mpsc, StreamExt};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn reaction(emoji: serde_json::Value) -> serde_json::Value {
+        json!({
+            "channel_id": "7",
+            "emoji": emoji,
+            "message_id": "8",
+            "user_id": "9",
+        })
+    }
+
+    #[tokio::test]
+    async fn maps_reactions() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let mut collector = ReactionCollectorBuilder::new(&messenger)
+            .removed(true)
+            .map(|action| match *action.emoji() {
+                ReactionType::Unicode(ref emoji) => Some((action.is_added(), emoji.clone())),
+                _ => None,
+            })
+            .await;
+        filters.register(&mut rx).await;
+
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+        let events = vec![
+            Event::ReactionAdd(serde_json::from_value::<ReactionAddEvent>(reaction(json!({"id": null, "name": "👍"}))).unwrap()),
+            Event::ReactionAdd(serde_json::from_value::<ReactionAddEvent>(reaction(json!({"id": "10", "name": "ferris"}))).unwrap()),
+            Event::ReactionRemove(serde_json::from_value::<ReactionRemoveEvent>(reaction(json!({"id": null, "name": "👎"}))).unwrap()),
+        ];
+
+        for event in &events {
+            filters.handle_event(event, &cache_and_http).await;
+        }
+
+        // Dropping the filters ends the collector.
+        drop(filters);
+
+        assert_eq!(collector.next().await, Some((true, "👍".to_string())));
+        assert_eq!(collector.next().await, Some((false, "👎".to_string())));
+        assert_eq!(collector.next().await, None);
+        assert_eq!(collector.filtered(), 3);
+        assert_eq!(collector.collected(), 2);
+    }
+}
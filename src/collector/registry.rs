@@ -0,0 +1,229 @@
+//! Introspection of the collectors registered on a shard.
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use crate::{
+    CacheAndHttp,
+    collector::{EventFilter, MessageFilter, ReactionAction, ReactionFilter},
+    model::event::Event,
+};
+#[cfg(test)]
+use crate::{
+    client::bridge::gateway::{ShardClientMessage, ShardRunnerMessage},
+    gateway::InterMessage,
+};
+#[cfg(test)]
+use futures::{channel::mpsc::UnboundedReceiver, StreamExt};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A unique identifier of a collector, used to cancel it via
+/// [`ShardMessenger::cancel_collector`].
+///
+/// [`ShardMessenger::cancel_collector`]: ../client/bridge/gateway/struct.ShardMessenger.html#method.cancel_collector
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CollectorId(pub u64);
+
+impl CollectorId {
+    /// Creates a new, never used ID.
+    pub(crate) fn next() -> Self {
+        CollectorId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// The kind of item a collector receives.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CollectorKind {
+    /// The collector receives events, see [`EventCollector`].
+    ///
+    /// [`EventCollector`]: struct.EventCollector.html
+    Event,
+    /// The collector receives messages, see [`MessageCollector`].
+    ///
+    /// [`MessageCollector`]: struct.MessageCollector.html
+    Message,
+    /// The collector receives reactions, see [`ReactionCollector`].
+    ///
+    /// [`ReactionCollector`]: struct.ReactionCollector.html
+    Reaction,
+}
+
+/// A snapshot of an active collector, as returned by
+/// [`ShardMessenger::collectors`].
+///
+/// [`ShardMessenger::collectors`]: ../client/bridge/gateway/struct.ShardMessenger.html#method.collectors
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CollectorInfo {
+    /// The ID of the collector.
+    pub id: CollectorId,
+    /// What the collector receives.
+    pub kind: CollectorKind,
+    /// How many items have been checked by the filter.
+    pub filtered: u32,
+    /// How many items passed the filter.
+    pub collected: u32,
+    /// How long ago the collector has been registered.
+    pub age: Duration,
+    /// A human readable summary of the filter's constraints.
+    pub summary: String,
+}
+
+/// The filters registered on a shard, receiving the shard's events.
+#[derive(Default)]
+pub(crate) struct Filters {
+    pub(crate) event_filters: Vec<EventFilter>,
+    pub(crate) message_filters: Vec<MessageFilter>,
+    pub(crate) reaction_filters: Vec<ReactionFilter>,
+}
+
+impl Filters {
+    /// Lets filters check the `event` to send them to collectors if the `event`
+    /// is accepted by them.
+    ///
+    /// Filters that are done collecting are removed.
+    pub(crate) async fn handle_event(&mut self, event: &Event, cache_and_http: &Arc<CacheAndHttp>) {
+        // Avoid the clone if there is no event filter.
+        if !self.event_filters.is_empty() {
+            let event = Arc::new(event.clone());
+            let mut i = 0;
+
+            while i < self.event_filters.len() {
+                if self.event_filters[i].send_event(&event, cache_and_http).await {
+                    i += 1;
+                } else {
+                    self.event_filters.remove(i);
+                }
+            }
+        }
+
+        // Avoid the clone if there is no message filter.
+        if !self.message_filters.is_empty() {
+
+            if let Event::MessageCreate(ref msg_event) = &event {
+                let msg = Arc::new(msg_event.message.clone());
+                let mut i = 0;
+
+                while i < self.message_filters.len() {
+                    if self.message_filters[i].send_message(&msg, cache_and_http).await {
+                        i += 1;
+                    } else {
+                        self.message_filters.remove(i);
+                    }
+                }
+            }
+        }
+
+        // Avoid the clone if there is no reacton filter.
+        if !self.reaction_filters.is_empty() {
+            let reaction = Arc::new(match &event {
+                Event::ReactionAdd(ref reaction_event) =>
+                    ReactionAction::Added(Arc::new(reaction_event.reaction.clone())),
+                Event::ReactionRemove(ref reaction_event) =>
+                    ReactionAction::Removed(Arc::new(reaction_event.reaction.clone())),
+                _ => return,
+            });
+            let mut i = 0;
+
+            while i < self.reaction_filters.len() {
+                if self.reaction_filters[i].send_reaction(&reaction, cache_and_http).await {
+                    i += 1;
+                } else {
+                    self.reaction_filters.remove(i);
+                }
+            }
+        }
+    }
+
+    /// Returns a snapshot of all filters whose collectors are still receiving.
+    ///
+    /// Filters of stopped or dropped collectors are removed.
+    pub(crate) fn infos(&mut self) -> Vec<CollectorInfo> {
+        self.event_filters.retain(|f| !f.is_closed());
+        self.message_filters.retain(|f| !f.is_closed());
+        self.reaction_filters.retain(|f| !f.is_closed());
+
+        self.event_filters.iter().map(EventFilter::info)
+            .chain(self.message_filters.iter().map(MessageFilter::info))
+            .chain(self.reaction_filters.iter().map(ReactionFilter::info))
+            .collect()
+    }
+
+    /// Removes the filter of the collector with the given `id`, ending the
+    /// collector's stream.
+    pub(crate) fn cancel(&mut self, id: CollectorId) {
+        if let Some(i) = self.event_filters.iter().position(|f| f.id() == id) {
+            self.event_filters.remove(i).cancel();
+        } else if let Some(i) = self.message_filters.iter().position(|f| f.id() == id) {
+            self.message_filters.remove(i).cancel();
+        } else if let Some(i) = self.reaction_filters.iter().position(|f| f.id() == id) {
+            self.reaction_filters.remove(i).cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+impl Filters {
+    /// Registers the next filter sent to the shard, like the shard runner
+    /// does.
+    pub(crate) async fn register(&mut self, rx: &mut UnboundedReceiver<InterMessage>) {
+        let msg = match rx.next().await {
+            Some(InterMessage::Client(msg)) => *msg,
+            _ => panic!("expected a message for the shard runner"),
+        };
+
+        match msg {
+            ShardClientMessage::Runner(ShardRunnerMessage::SetEventFilter(filter)) => self.event_filters.push(filter),
+            ShardClientMessage::Runner(ShardRunnerMessage::SetMessageFilter(filter)) => self.message_filters.push(filter),
+            ShardClientMessage::Runner(ShardRunnerMessage::SetReactionFilter(filter)) => self.reaction_filters.push(filter),
+            _ => panic!("expected a filter"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filters;
+    use crate::{
+        client::bridge::gateway::ShardMessenger,
+        collector::{CollectorEnd, CollectorKind, EventCollectorBuilder, MessageCollectorBuilder, ReactionCollectorBuilder},
+        model::event::EventType,
+    };
+    use futures::channel::mpsc;
+
+    #[tokio::test]
+    async fn lists_and_cancels_collectors() {
+        let (tx, mut rx) = mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut filters = Filters::default();
+
+        let events = EventCollectorBuilder::new(&messenger).add_event_type(EventType::TypingStart).await;
+        filters.register(&mut rx).await;
+        let messages = MessageCollectorBuilder::new(&messenger).channel_id(7u64).await;
+        filters.register(&mut rx).await;
+        let reactions = ReactionCollectorBuilder::new(&messenger).await;
+        filters.register(&mut rx).await;
+
+        let listed = filters.infos().iter().map(|info| (info.id, info.kind)).collect::<Vec<_>>();
+        assert_eq!(listed, vec![
+            (events.id(), CollectorKind::Event),
+            (messages.id(), CollectorKind::Message),
+            (reactions.id(), CollectorKind::Reaction),
+        ]);
+
+        filters.cancel(messages.id());
+        assert_eq!(messages.end_reason(), Some(CollectorEnd::Cancelled));
+        assert_eq!(events.end_reason(), None);
+
+        // Stopped collectors are no longer listed either.
+        reactions.stop();
+
+        let listed = filters.infos().iter().map(|info| info.id).collect::<Vec<_>>();
+        assert_eq!(listed, vec![events.id()]);
+    }
+}
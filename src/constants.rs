@@ -2,6 +2,8 @@
 
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: u16 = 6000;
+/// The maximum size of an emoji's image in bytes.
+pub const EMOJI_MAX_SIZE: u64 = 256 * 1024;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 6;
@@ -172,6 +174,10 @@ pub mod close_codes {
     ///
     /// Cannot reconnect.
     pub const SHARDING_REQUIRED: u16 = 4011;
+    /// An invalid version of the gateway was requested.
+    ///
+    /// Cannot reconnect.
+    pub const INVALID_API_VERSION: u16 = 4012;
     /// Undocumented gateway intents have been provided.
     pub const INVALID_GATEWAY_INTENTS: u16 = 4013;
     /// Disallowed gateway intents have been provided.
@@ -25,6 +25,8 @@ use crate::gateway::GatewayError;
 use crate::http::HttpError;
 #[cfg(all(feature = "gateway", feature = "rustls_backend", not(feature = "native_tls_backend")))]
 use crate::internal::ws_impl::RustlsError;
+#[cfg(feature = "voice-driver")]
+use crate::voice::VoiceError;
 
 /// The common result type between most library functions.
 ///
@@ -98,6 +100,11 @@ pub enum Error {
     /// An error from the `tungstenite` crate.
     #[cfg(feature = "gateway")]
     Tungstenite(TungsteniteError),
+    /// An error from the [`voice`] module.
+    ///
+    /// [`voice`]: voice/index.html
+    #[cfg(feature = "voice-driver")]
+    Voice(VoiceError),
 }
 
 impl From<FormatError> for Error {
@@ -135,6 +142,11 @@ impl From<TungsteniteError> for Error {
     fn from(e: TungsteniteError) -> Error { Error::Tungstenite(e) }
 }
 
+#[cfg(feature = "voice-driver")]
+impl From<VoiceError> for Error {
+    fn from(e: VoiceError) -> Error { Error::Voice(e) }
+}
+
 #[cfg(feature = "http")]
 impl From<HttpError> for Error {
     fn from(e: HttpError) -> Error { Error::Http(Box::new(e)) }
@@ -171,6 +183,8 @@ impl Display for Error {
             Error::Rustls(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "gateway")]
             Error::Tungstenite(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "voice-driver")]
+            Error::Voice(inner) => fmt::Display::fmt(&inner, f),
         }
     }
 }
@@ -194,6 +208,8 @@ impl StdError for Error {
             Error::Rustls(inner) => Some(inner),
             #[cfg(feature = "gateway")]
             Error::Tungstenite(inner) => Some(inner),
+            #[cfg(feature = "voice-driver")]
+            Error::Voice(inner) => Some(inner),
             _ => None,
         }
     }
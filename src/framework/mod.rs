@@ -84,7 +84,7 @@ pub mod standard;
 pub use self::standard::StandardFramework;
 
 use crate::client::Context;
-use crate::model::channel::Message;
+use crate::model::{channel::Message, event::MessageUpdateEvent};
 use async_trait::async_trait;
 
 /// A trait for defining your own framework for serenity to use.
@@ -96,6 +96,23 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait Framework: Send + Sync {
     async fn dispatch(&self, _: Context, _: Message);
+
+    /// Called when a message is edited, if [`handles_updates`] returns `true`.
+    ///
+    /// Does nothing by default.
+    ///
+    /// [`handles_updates`]: #method.handles_updates
+    async fn dispatch_update(&self, _: Context, _: MessageUpdateEvent) {}
+
+    /// Whether [`dispatch_update`] should be called for edited messages.
+    ///
+    /// Returns `false` by default, so that no task is spawned for message
+    /// updates the framework ignores.
+    ///
+    /// [`dispatch_update`]: #method.dispatch_update
+    fn handles_updates(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -106,6 +123,16 @@ where F: Framework + ?Sized
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[inline]
+    async fn dispatch_update(&self, ctx: Context, event: MessageUpdateEvent) {
+        (**self).dispatch_update(ctx, event).await;
+    }
+
+    #[inline]
+    fn handles_updates(&self) -> bool {
+        (**self).handles_updates()
+    }
 }
 
 #[async_trait]
@@ -116,4 +143,14 @@ where F: Framework + ?Sized
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[inline]
+    async fn dispatch_update(&self, ctx: Context, event: MessageUpdateEvent) {
+        (**self).dispatch_update(ctx, event).await;
+    }
+
+    #[inline]
+    fn handles_updates(&self) -> bool {
+        (**self).handles_updates()
+    }
 }
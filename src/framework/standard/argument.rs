@@ -0,0 +1,366 @@
+use async_trait::async_trait;
+use crate::client::Context;
+use crate::model::{
+    channel::Message,
+    guild::Member,
+    id::{ChannelId, RoleId, UserId},
+    user::User,
+};
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use super::Args;
+
+/// An error returned when parsing the arguments of a command with typed
+/// parameters fails.
+///
+/// The error is returned from the command, so it can be handled in the
+/// [`after`] hook.
+///
+/// [`after`]: struct.StandardFramework.html#method.after
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArgumentParseError {
+    /// There were no arguments left to parse.
+    Missing {
+        /// What was expected, e.g. `"integer"`.
+        expected: &'static str,
+    },
+    /// An argument could not be parsed.
+    Invalid {
+        /// The argument as given.
+        argument: String,
+        /// What was expected, e.g. `"integer"`.
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ArgumentParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentParseError::Missing { expected } => write!(f, "Missing argument, expected a {}", expected),
+            ArgumentParseError::Invalid { argument, expected } => {
+                write!(f, "`{}` is not a valid {}", argument, expected)
+            },
+        }
+    }
+}
+
+impl StdError for ArgumentParseError {}
+
+/// A type that can be parsed from the arguments of a command.
+///
+/// Command functions may declare parameters of any type implementing this
+/// trait in place of [`Args`], which are then parsed in order before the
+/// body of the command runs:
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{CommandResult, macros::command};
+/// use serenity::model::{channel::Message, guild::Member};
+/// use serenity::prelude::*;
+/// use std::time::Duration;
+///
+/// #[command]
+/// async fn mute(ctx: &Context, msg: &Message, member: Member, time: Option<Duration>, #[rest] reason: String) -> CommandResult {
+///     let time = time.unwrap_or_else(|| Duration::from_secs(600));
+///
+///     msg.reply(ctx, format!("Muting {} for {:?}: {}", member.user.name, time, reason)).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// A parameter marked with `#[rest]` takes the remainder of the arguments.
+/// `Option<T>` parameters are `None` when the argument is missing or cannot
+/// be parsed, `Vec<T>` parameters take as many arguments as can be parsed.
+///
+/// The trait can be derived for structs, parsing their fields in order:
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{CommandResult, macros::{command, ArgumentParse}};
+/// use serenity::model::{channel::Message, id::UserId};
+/// use serenity::prelude::*;
+///
+/// #[derive(ArgumentParse)]
+/// struct BanArgs {
+///     user: UserId,
+///     days: Option<u8>,
+///     #[rest]
+///     reason: String,
+/// }
+///
+/// #[command]
+/// async fn ban(ctx: &Context, msg: &Message, args: BanArgs) -> CommandResult {
+///     if let Some(guild_id) = msg.guild_id {
+///         guild_id.ban_with_reason(ctx, args.user, args.days.unwrap_or(0), &args.reason).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Args`]: struct.Args.html
+#[async_trait]
+pub trait ArgumentParse: Sized + Send {
+    /// Parses the value from the next arguments, advancing past them.
+    async fn parse(ctx: &Context, msg: &Message, args: &mut Args) -> Result<Self, ArgumentParseError>;
+}
+
+/// Parses the remainder of the arguments as a single value, used for
+/// parameters marked with `#[rest]`.
+pub fn parse_rest<T: FromStr>(args: &mut Args) -> Result<T, ArgumentParseError> {
+    let argument = match args.remains() {
+        Some(rest) => rest.trim().to_string(),
+        None => return Err(ArgumentParseError::Missing { expected: "text" }),
+    };
+
+    let value = argument
+        .parse::<T>()
+        .map_err(|_| ArgumentParseError::Invalid { argument, expected: "text" })?;
+
+    while !args.is_empty() {
+        args.advance();
+    }
+
+    Ok(value)
+}
+
+fn single<T: FromStr>(args: &mut Args, expected: &'static str) -> Result<T, ArgumentParseError> {
+    let argument = match args.quoted().current() {
+        Some(argument) => argument.to_string(),
+        None => return Err(ArgumentParseError::Missing { expected }),
+    };
+
+    let value = argument
+        .parse::<T>()
+        .map_err(|_| ArgumentParseError::Invalid { argument, expected })?;
+
+    args.advance();
+
+    Ok(value)
+}
+
+macro_rules! from_str_argument {
+    ($($kind:ty => $expected:expr;)*) => {
+        $(
+            #[async_trait]
+            impl ArgumentParse for $kind {
+                async fn parse(_: &Context, _: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+                    single(args, $expected)
+                }
+            }
+        )*
+    };
+}
+
+from_str_argument! {
+    u8 => "integer";
+    u16 => "integer";
+    u32 => "integer";
+    u64 => "integer";
+    usize => "integer";
+    i8 => "integer";
+    i16 => "integer";
+    i32 => "integer";
+    i64 => "integer";
+    isize => "integer";
+    f32 => "number";
+    f64 => "number";
+    bool => "boolean";
+    char => "character";
+    String => "text";
+    UserId => "user";
+    ChannelId => "channel";
+    RoleId => "role";
+}
+
+#[async_trait]
+impl ArgumentParse for User {
+    async fn parse(ctx: &Context, msg: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+        let argument = args.quoted().current().map(String::from);
+        let user_id = <UserId as ArgumentParse>::parse(ctx, msg, args).await?;
+
+        match user_id.to_user(ctx).await {
+            Ok(user) => Ok(user),
+            Err(_) => {
+                args.rewind();
+
+                Err(ArgumentParseError::Invalid {
+                    argument: argument.unwrap_or_default(),
+                    expected: "user",
+                })
+            },
+        }
+    }
+}
+
+/// Parses a member of the guild the command was invoked in, given as a
+/// mention, an Id, or with the `cache` feature, a name.
+#[async_trait]
+impl ArgumentParse for Member {
+    async fn parse(ctx: &Context, msg: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+        let argument = match args.quoted().current() {
+            Some(argument) => argument.to_string(),
+            None => return Err(ArgumentParseError::Missing { expected: "member" }),
+        };
+
+        let invalid = || ArgumentParseError::Invalid {
+            argument: argument.clone(),
+            expected: "member",
+        };
+
+        let guild_id = msg.guild_id.ok_or_else(invalid)?;
+
+        #[allow(unused_mut)]
+        let mut user_id = argument.parse::<UserId>().ok();
+
+        #[cfg(feature = "cache")]
+        {
+            if user_id.is_none() {
+                user_id = ctx
+                    .cache
+                    .guild_field(guild_id, |g| g.member_named(&argument).map(|m| m.user.id))
+                    .await
+                    .flatten();
+            }
+        }
+
+        let member = guild_id
+            .member(ctx, user_id.ok_or_else(invalid)?)
+            .await
+            .map_err(|_| invalid())?;
+
+        args.advance();
+
+        Ok(member)
+    }
+}
+
+/// Parses a duration given in seconds, or as a combination of amounts of
+/// weeks, days, hours, minutes and seconds, e.g. `1h30m`.
+#[async_trait]
+impl ArgumentParse for Duration {
+    async fn parse(_: &Context, _: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+        let argument = match args.quoted().current() {
+            Some(argument) => argument.to_string(),
+            None => return Err(ArgumentParseError::Missing { expected: "duration" }),
+        };
+
+        let duration = parse_duration(&argument).ok_or(ArgumentParseError::Invalid {
+            argument,
+            expected: "duration",
+        })?;
+
+        args.advance();
+
+        Ok(duration)
+    }
+}
+
+fn parse_duration(s: &str) -> Option<Duration> {
+    if let Ok(secs) = s.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let mut secs = 0u64;
+    let mut amount = String::new();
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            amount.push(c);
+            continue;
+        }
+
+        let unit = match c.to_ascii_lowercase() {
+            'w' => 7 * 24 * 60 * 60,
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+
+        let amount = std::mem::take(&mut amount).parse::<u64>().ok()?;
+        secs = secs.checked_add(amount.checked_mul(unit)?)?;
+    }
+
+    // Trailing digits without a unit, or no amount at all.
+    if !amount.is_empty() || s.is_empty() {
+        return None;
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+#[async_trait]
+impl<T: ArgumentParse> ArgumentParse for Option<T> {
+    async fn parse(ctx: &Context, msg: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+        let before = args.clone();
+
+        match T::parse(ctx, msg, args).await {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *args = before;
+
+                Ok(None)
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl<T: ArgumentParse> ArgumentParse for Vec<T> {
+    async fn parse(ctx: &Context, msg: &Message, args: &mut Args) -> Result<Self, ArgumentParseError> {
+        let mut values = Vec::new();
+
+        while !args.is_empty() {
+            let before = args.clone();
+
+            match T::parse(ctx, msg, args).await {
+                Ok(value) => values.push(value),
+                Err(_) => {
+                    *args = before;
+
+                    break;
+                },
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_duration, parse_rest, single, ArgumentParseError};
+    use crate::framework::standard::{Args, Delimiter};
+    use std::time::Duration;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1h30m"), Some(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("2d5s"), Some(Duration::from_secs(172_805)));
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn single_and_rest() {
+        let mut args = Args::new("5 \"a b\" banned for spam", &[Delimiter::Single(' ')]);
+
+        assert_eq!(single::<u32>(&mut args, "integer"), Ok(5));
+        assert_eq!(single::<u32>(&mut args, "integer"), Err(ArgumentParseError::Invalid {
+            argument: "a b".to_string(),
+            expected: "integer",
+        }));
+        assert_eq!(single::<String>(&mut args, "text"), Ok("a b".to_string()));
+        assert_eq!(parse_rest::<String>(&mut args), Ok("banned for spam".to_string()));
+        assert!(args.is_empty());
+        assert_eq!(single::<String>(&mut args, "text"), Err(ArgumentParseError::Missing {
+            expected: "text",
+        }));
+    }
+}
@@ -0,0 +1,442 @@
+//! Built-in checks, failing with the structured [`Reason`]s.
+//!
+//! The checks are used like checks created with the `#[check]` macro,
+//! after importing them:
+//!
+//! ```rust,no_run
+//! use serenity::framework::standard::{
+//!     CommandResult,
+//!     checks::GUILD_OWNER_CHECK,
+//!     macros::command,
+//! };
+//! use serenity::model::channel::Message;
+//! use serenity::prelude::*;
+//!
+//! #[command]
+//! #[checks(guild_owner)]
+//! async fn lockdown(ctx: &Context, msg: &Message) -> CommandResult {
+//!     msg.reply(ctx, "Locking down the guild").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Checks depending on a role or permissions are built from the functions
+//! of this module:
+//!
+//! ```rust,no_run
+//! use serenity::framework::standard::{
+//!     Args,
+//!     CheckResult,
+//!     CommandOptions,
+//!     checks,
+//!     macros::check,
+//! };
+//! use serenity::model::{channel::Message, id::RoleId};
+//! use serenity::prelude::*;
+//!
+//! #[check]
+//! #[name = "Moderator"]
+//! async fn moderator(ctx: &Context, msg: &Message, _: &mut Args, _: &CommandOptions) -> CheckResult {
+//!     checks::has_role(ctx, msg, RoleId(7)).await
+//! }
+//! ```
+//!
+//! [`Reason`]: ../enum.Reason.html
+
+use crate::client::Context;
+use crate::model::{
+    channel::Message,
+    id::RoleId,
+    permissions::Permissions,
+};
+use futures::future::{BoxFuture, FutureExt};
+use super::{Args, Check, CheckResult, CommandOptions, Reason};
+
+/// Passes if the user has the role in the guild the command was used in.
+///
+/// Fails with [`Reason::MissingRole`], or [`Reason::GuildOnly`] outside of
+/// guilds.
+///
+/// [`Reason::GuildOnly`]: ../enum.Reason.html#variant.GuildOnly
+/// [`Reason::MissingRole`]: ../enum.Reason.html#variant.MissingRole
+pub async fn has_role(ctx: &Context, msg: &Message, role_id: impl Into<RoleId>) -> CheckResult {
+    let role_id = role_id.into();
+
+    if msg.guild_id.is_none() {
+        return Reason::GuildOnly.into();
+    }
+
+    match msg.member(ctx).await {
+        Ok(member) if member.roles.contains(&role_id) => CheckResult::Success,
+        Ok(_) => Reason::MissingRole(role_id).into(),
+        Err(why) => CheckResult::new_log(format!("Failed to get the member: {:?}", why)),
+    }
+}
+
+/// Passes if the user has all of the permissions in the channel the command
+/// was used in.
+///
+/// Fails with [`Reason::MissingPermissions`] holding the permissions the
+/// user lacks, or [`Reason::GuildOnly`] outside of guilds.
+///
+/// **Note**: The guild must be in the cache.
+///
+/// [`Reason::GuildOnly`]: ../enum.Reason.html#variant.GuildOnly
+/// [`Reason::MissingPermissions`]: ../enum.Reason.html#variant.MissingPermissions
+#[cfg(feature = "cache")]
+pub async fn has_permissions(ctx: &Context, msg: &Message, permissions: Permissions) -> CheckResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Reason::GuildOnly.into(),
+    };
+
+    let user_permissions = ctx
+        .cache
+        .guild_field(guild_id, |g| g.user_permissions_in_ids(msg.channel_id, msg.author.id))
+        .await;
+
+    match user_permissions {
+        Some(p) if p.administrator() || p.contains(permissions) => CheckResult::Success,
+        Some(p) => Reason::MissingPermissions(permissions - p).into(),
+        None => CheckResult::new_log("The guild is not in the cache"),
+    }
+}
+
+/// Passes if the user is the owner of the guild the command was used in.
+///
+/// Fails with [`Reason::NotGuildOwner`], or [`Reason::GuildOnly`] outside of
+/// guilds.
+///
+/// [`Reason::GuildOnly`]: ../enum.Reason.html#variant.GuildOnly
+/// [`Reason::NotGuildOwner`]: ../enum.Reason.html#variant.NotGuildOwner
+pub async fn is_guild_owner(ctx: &Context, msg: &Message) -> CheckResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Reason::GuildOnly.into(),
+    };
+
+    #[cfg(feature = "cache")]
+    {
+        if let Some(owner_id) = ctx.cache.guild_field(guild_id, |g| g.owner_id).await {
+            return is_owner(owner_id == msg.author.id);
+        }
+    }
+
+    match guild_id.to_partial_guild(&ctx.http).await {
+        Ok(guild) => is_owner(guild.owner_id == msg.author.id),
+        Err(why) => CheckResult::new_log(format!("Failed to get the guild: {:?}", why)),
+    }
+}
+
+fn is_owner(owner: bool) -> CheckResult {
+    if owner {
+        CheckResult::Success
+    } else {
+        Reason::NotGuildOwner.into()
+    }
+}
+
+/// Passes if the user owns the bot's application, or is a member of the
+/// team owning it.
+///
+/// Fails with [`Reason::NotBotOwner`].
+///
+/// **Note**: This requests the application's information every time. The
+/// [`owners_only`] option of commands relies on the owners set in the
+/// [`Configuration`] instead.
+///
+/// [`Configuration`]: ../struct.Configuration.html
+/// [`Reason::NotBotOwner`]: ../enum.Reason.html#variant.NotBotOwner
+/// [`owners_only`]: ../struct.CommandOptions.html#structfield.owners_only
+pub async fn is_bot_owner(ctx: &Context, msg: &Message) -> CheckResult {
+    let info = match ctx.http.get_current_application_info().await {
+        Ok(info) => info,
+        Err(why) => return CheckResult::new_log(format!("Failed to get the application: {:?}", why)),
+    };
+
+    let owner = match info.team {
+        Some(team) => team.members.iter().any(|m| m.user.id == msg.author.id),
+        None => info.owner.id == msg.author.id,
+    };
+
+    if owner {
+        CheckResult::Success
+    } else {
+        Reason::NotBotOwner.into()
+    }
+}
+
+/// Passes if the command was used in an NSFW channel.
+///
+/// Fails with [`Reason::NsfwRequired`].
+///
+/// [`Reason::NsfwRequired`]: ../enum.Reason.html#variant.NsfwRequired
+pub async fn is_nsfw(ctx: &Context, msg: &Message) -> CheckResult {
+    match msg.channel_id.to_channel(ctx).await {
+        Ok(channel) if channel.is_nsfw() => CheckResult::Success,
+        Ok(_) => Reason::NsfwRequired.into(),
+        Err(why) => CheckResult::new_log(format!("Failed to get the channel: {:?}", why)),
+    }
+}
+
+fn guild_owner<'fut>(
+    ctx: &'fut Context,
+    msg: &'fut Message,
+    _: &'fut mut Args,
+    _: &'fut CommandOptions,
+) -> BoxFuture<'fut, CheckResult> {
+    is_guild_owner(ctx, msg).boxed()
+}
+
+fn bot_owner<'fut>(
+    ctx: &'fut Context,
+    msg: &'fut Message,
+    _: &'fut mut Args,
+    _: &'fut CommandOptions,
+) -> BoxFuture<'fut, CheckResult> {
+    is_bot_owner(ctx, msg).boxed()
+}
+
+fn nsfw<'fut>(
+    ctx: &'fut Context,
+    msg: &'fut Message,
+    _: &'fut mut Args,
+    _: &'fut CommandOptions,
+) -> BoxFuture<'fut, CheckResult> {
+    is_nsfw(ctx, msg).boxed()
+}
+
+/// A check passing for the owner of the guild, used with
+/// `#[checks(guild_owner)]`.
+///
+/// See [`is_guild_owner`].
+///
+/// [`is_guild_owner`]: fn.is_guild_owner.html
+pub static GUILD_OWNER_CHECK: Check = Check {
+    name: "Guild Owner",
+    function: guild_owner,
+    check_in_help: true,
+    display_in_help: true,
+};
+
+/// A check passing for the owners of the bot, used with
+/// `#[checks(bot_owner)]`.
+///
+/// See [`is_bot_owner`].
+///
+/// [`is_bot_owner`]: fn.is_bot_owner.html
+pub static BOT_OWNER_CHECK: Check = Check {
+    name: "Bot Owner",
+    function: bot_owner,
+    check_in_help: false,
+    display_in_help: true,
+};
+
+/// A check passing in NSFW channels, used with `#[checks(nsfw)]`.
+///
+/// See [`is_nsfw`].
+///
+/// [`is_nsfw`]: fn.is_nsfw.html
+pub static NSFW_CHECK: Check = Check {
+    name: "NSFW",
+    function: nsfw,
+    check_in_help: true,
+    display_in_help: true,
+};
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use super::*;
+    use crate::cache::Cache;
+    use crate::http::{test_server, HttpBuilder};
+    use crate::model::event::GuildCreateEvent;
+    use futures::channel::mpsc;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+    use typemap_rev::TypeMap;
+
+    const OWNER: u64 = 2;
+    const MEMBER: u64 = 6;
+    const MODERATOR: RoleId = RoleId(3);
+    const CHANNEL: u64 = 4;
+    const NSFW_CHANNEL: u64 = 5;
+
+    fn channel(id: u64, nsfw: bool) -> Value {
+        json!({
+            "id": id.to_string(),
+            "name": "general",
+            "nsfw": nsfw,
+            "permission_overwrites": [],
+            "position": 0,
+            "type": 0,
+        })
+    }
+
+    fn member(id: u64, roles: Vec<RoleId>) -> Value {
+        json!({
+            "deaf": false,
+            "joined_at": "2021-01-01T00:00:00+00:00",
+            "mute": false,
+            "roles": roles.iter().map(|r| r.0.to_string()).collect::<Vec<_>>(),
+            "user": {"discriminator": "0001", "id": id.to_string(), "username": "user"},
+        })
+    }
+
+    fn role(id: RoleId, permissions: Permissions) -> Value {
+        json!({
+            "color": 0,
+            "hoist": false,
+            "id": id.0.to_string(),
+            "managed": false,
+            "mentionable": false,
+            "name": "role",
+            "permissions": permissions.bits().to_string(),
+            "position": 0,
+        })
+    }
+
+    async fn context(http: HttpBuilder) -> Context {
+        let mut event: GuildCreateEvent = serde_json::from_value(json!({
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "channels": [channel(CHANNEL, false), channel(NSFW_CHANNEL, true)],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "id": "1",
+            "joined_at": "2021-01-01T00:00:00+00:00",
+            "large": false,
+            "member_count": 2,
+            "members": [member(OWNER, vec![MODERATOR]), member(MEMBER, vec![])],
+            "mfa_level": 0,
+            "name": "guild",
+            "owner_id": OWNER.to_string(),
+            "preferred_locale": "en-US",
+            "presences": [],
+            "region": "europe",
+            "roles": [
+                role(RoleId(1), Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES),
+                role(MODERATOR, Permissions::KICK_MEMBERS),
+            ],
+            "splash": null,
+            "verification_level": 0,
+            "voice_states": [],
+        })).unwrap();
+
+        let cache = Arc::new(Cache::new());
+        cache.update(&mut event).await;
+
+        let (runner_tx, _) = mpsc::unbounded();
+        let data = Arc::new(RwLock::new(TypeMap::new()));
+
+        Context::new(data, runner_tx, 0, Arc::new(http.build().unwrap()), cache)
+    }
+
+    fn message(author: u64, channel_id: u64, guild: bool) -> Message {
+        let mut message = json!({
+            "attachments": [],
+            "author": {"discriminator": "0001", "id": author.to_string(), "username": "user"},
+            "channel_id": channel_id.to_string(),
+            "content": "~command",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "10",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "tts": false,
+            "type": 0,
+        });
+
+        if guild {
+            message["guild_id"] = json!("1");
+        }
+
+        serde_json::from_value(message).unwrap()
+    }
+
+    fn reason(result: CheckResult) -> Reason {
+        match result {
+            CheckResult::Failure(reason) => reason,
+            CheckResult::Success => panic!("Expected a failure"),
+        }
+    }
+
+    #[tokio::test]
+    async fn checks_roles() {
+        let ctx = context(HttpBuilder::new("token")).await;
+
+        assert!(has_role(&ctx, &message(OWNER, CHANNEL, true), MODERATOR).await.is_success());
+        assert!(match reason(has_role(&ctx, &message(MEMBER, CHANNEL, true), MODERATOR).await) {
+            Reason::MissingRole(role_id) => role_id == MODERATOR,
+            _ => false,
+        });
+        assert!(match reason(has_role(&ctx, &message(MEMBER, CHANNEL, false), MODERATOR).await) {
+            Reason::GuildOnly => true,
+            _ => false,
+        });
+    }
+
+    #[tokio::test]
+    async fn checks_permissions() {
+        let ctx = context(HttpBuilder::new("token")).await;
+        let kick = Permissions::KICK_MEMBERS | Permissions::SEND_MESSAGES;
+
+        assert!(has_permissions(&ctx, &message(OWNER, CHANNEL, true), kick).await.is_success());
+        assert!(match reason(has_permissions(&ctx, &message(MEMBER, CHANNEL, true), kick).await) {
+            Reason::MissingPermissions(missing) => missing == Permissions::KICK_MEMBERS,
+            _ => false,
+        });
+    }
+
+    #[tokio::test]
+    async fn checks_guild_owner() {
+        let ctx = context(HttpBuilder::new("token")).await;
+
+        assert!(is_guild_owner(&ctx, &message(OWNER, CHANNEL, true)).await.is_success());
+        assert!(match reason(is_guild_owner(&ctx, &message(MEMBER, CHANNEL, true)).await) {
+            Reason::NotGuildOwner => true,
+            _ => false,
+        });
+    }
+
+    #[tokio::test]
+    async fn checks_nsfw_channels() {
+        let ctx = context(HttpBuilder::new("token")).await;
+
+        assert!(is_nsfw(&ctx, &message(MEMBER, NSFW_CHANNEL, true)).await.is_success());
+        assert!(match reason(is_nsfw(&ctx, &message(MEMBER, CHANNEL, true)).await) {
+            Reason::NsfwRequired => true,
+            _ => false,
+        });
+    }
+
+    #[tokio::test]
+    async fn checks_bot_owner() {
+        let info = json!({
+            "bot_public": false,
+            "bot_require_code_grant": false,
+            "description": "",
+            "icon": null,
+            "id": "8",
+            "name": "bot",
+            "owner": {"discriminator": "0001", "id": OWNER.to_string(), "username": "owner"},
+            "team": null,
+        }).to_string();
+        let (proxy, requests) = test_server::serve(vec![info; 2]);
+        let ctx = context(HttpBuilder::new("token").proxy(proxy).unwrap()).await;
+
+        assert!(is_bot_owner(&ctx, &message(OWNER, CHANNEL, true)).await.is_success());
+        assert!(match reason(is_bot_owner(&ctx, &message(MEMBER, CHANNEL, true)).await) {
+            Reason::NotBotOwner => true,
+            _ => false,
+        });
+        assert!(requests.recv().unwrap().starts_with("GET /api/v8/oauth2/applications/@me "));
+    }
+}
@@ -1,7 +1,8 @@
-use super::Delimiter;
+use super::{CommandLocale, Delimiter, PrefixStore};
 use crate::client::Context;
 use crate::model::{channel::Message, id::{UserId, GuildId, ChannelId}};
 use std::collections::HashSet;
+use std::sync::Arc;
 use futures::future::BoxFuture;
 
 type DynamicPrefixHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>;
@@ -112,6 +113,8 @@ pub struct Configuration {
     #[doc(hidden)]
     pub dynamic_prefixes: Vec<DynamicPrefixHook>,
     #[doc(hidden)]
+    pub prefix_store: Option<Arc<dyn PrefixStore>>,
+    #[doc(hidden)]
     pub ignore_bots: bool,
     #[doc(hidden)]
     pub ignore_webhooks: bool,
@@ -127,6 +130,8 @@ pub struct Configuration {
     pub delimiters: Vec<Delimiter>,
     #[doc(hidden)]
     pub case_insensitive: bool,
+    #[doc(hidden)]
+    pub locale: CommandLocale,
 }
 
 impl Configuration {
@@ -317,6 +322,66 @@ impl Configuration {
         self
     }
 
+    /// Sets the store of the prefixes of guilds.
+    ///
+    /// A message sent in a guild with a prefix in the store must use that
+    /// prefix, in place of the prefixes set with [`prefix`] and [`prefixes`].
+    /// Mentions and [dynamic prefixes] still apply.
+    ///
+    /// **Note**: Defaults to no store.
+    ///
+    /// # Examples
+    ///
+    /// Let guilds set their own prefix with a command, keeping a handle to
+    /// the store in the [`Context::data`]:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::{
+    ///     Args,
+    ///     CommandResult,
+    ///     MemoryPrefixStore,
+    ///     PrefixStore,
+    ///     StandardFramework,
+    ///     macros::command,
+    /// };
+    /// use std::sync::Arc;
+    ///
+    /// struct Prefixes;
+    ///
+    /// impl TypeMapKey for Prefixes {
+    ///     type Value = Arc<MemoryPrefixStore>;
+    /// }
+    ///
+    /// #[command]
+    /// #[only_in(guilds)]
+    /// #[required_permissions(MANAGE_GUILD)]
+    /// async fn prefix(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    ///     let store = ctx.data.read().await.get::<Prefixes>().cloned().unwrap();
+    ///     store.set(msg.guild_id.unwrap(), args.rest()).await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// let store = Arc::new(MemoryPrefixStore::new());
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .configure(|c| c.prefix("~").prefix_store(store.clone()));
+    ///
+    /// // Insert `store` into the client's data with the `Prefixes` key.
+    /// ```
+    ///
+    /// [`Context::data`]: ../../client/struct.Context.html#structfield.data
+    /// [`prefix`]: #method.prefix
+    /// [`prefixes`]: #method.prefixes
+    /// [dynamic prefixes]: #method.dynamic_prefix
+    pub fn prefix_store<S: PrefixStore + 'static>(&mut self, store: Arc<S>) -> &mut Self {
+        self.prefix_store = Some(store);
+
+        self
+    }
+
     /// Whether the bot should respond to other bots.
     ///
     /// For example, if this is set to false, then the bot will respond to any
@@ -528,6 +593,11 @@ impl Configuration {
     /// insensitive.
     ///
     /// **Note**: Defaults to `false`.
+    ///
+    /// **Note**: Group prefixes and [localized names] are matched case
+    /// insensitively as well. Groups must be added after this is set.
+    ///
+    /// [localized names]: #method.locale
     pub fn case_insensitivity(&mut self, cs: bool) -> &mut Self {
         self.case_insensitive = cs;
 
@@ -537,6 +607,32 @@ impl Configuration {
 
         self
     }
+
+    /// Adds localized names of commands, accepted in addition to their
+    /// names and aliases.
+    ///
+    /// This method can be called many times to add more locales.
+    ///
+    /// **Note**: Groups must be added after the locales are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::{CommandLocale, StandardFramework};
+    ///
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let german = CommandLocale::from_json(&std::fs::read_to_string("locales/de.json")?)?;
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .configure(|c| c.case_insensitivity(true).locale(german));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn locale(&mut self, locale: CommandLocale) -> &mut Self {
+        self.locale.merge(locale);
+
+        self
+    }
 }
 
 impl Default for Configuration {
@@ -552,6 +648,8 @@ impl Default for Configuration {
     /// - **delimiters** to `vec![' ']`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefixes** to an empty vector
+    /// - **prefix_store** to `None`
+    /// - **locale** to no localized names
     /// - **ignore_bots** to `true`
     /// - **ignore_webhooks** to `true`
     /// - **no_dm_prefix** to `false`
@@ -570,6 +668,8 @@ impl Default for Configuration {
             delimiters: vec![Delimiter::Single(' ')],
             disabled_commands: HashSet::default(),
             dynamic_prefixes: Vec::new(),
+            prefix_store: None,
+            locale: CommandLocale::default(),
             ignore_bots: true,
             ignore_webhooks: true,
             no_dm_prefix: false,
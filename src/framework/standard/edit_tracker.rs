@@ -0,0 +1,223 @@
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::model::{
+    channel::Message,
+    event::MessageUpdateEvent,
+    id::MessageId,
+};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use typemap_rev::TypeMapKey;
+
+struct TrackedInvocation {
+    message: Message,
+    invoked_at: Instant,
+    response: Option<MessageId>,
+}
+
+/// Tracks recently invoked command messages, so that a command is executed
+/// again when its message is edited, set with
+/// [`StandardFramework::edit_tracker`].
+///
+/// Commands respond with [`say`] to have their previous response edited when
+/// they are executed again, instead of sending a new response.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{
+///     Args,
+///     CommandResult,
+///     EditTracker,
+///     StandardFramework,
+///     macros::command,
+/// };
+/// use serenity::model::channel::Message;
+/// use serenity::prelude::*;
+/// use std::{sync::Arc, time::Duration};
+///
+/// #[command]
+/// async fn echo(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+///     let tracker = ctx.data.read().await.get::<EditTracker>().cloned().unwrap();
+///     tracker.say(ctx, msg, args.rest()).await?;
+///
+///     Ok(())
+/// }
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let tracker = Arc::new(EditTracker::new(Duration::from_secs(120)));
+///
+/// let framework = StandardFramework::new()
+///     .edit_tracker(tracker.clone());
+///
+/// let mut client = Client::builder("token")
+///     .framework(framework)
+///     .await?;
+///
+/// client.data.write().await.insert::<EditTracker>(tracker);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`StandardFramework::edit_tracker`]: struct.StandardFramework.html#method.edit_tracker
+/// [`say`]: #method.say
+pub struct EditTracker {
+    window: Duration,
+    invocations: Mutex<HashMap<MessageId, TrackedInvocation>>,
+}
+
+impl EditTracker {
+    /// Creates a tracker executing commands again when their message is
+    /// edited within the window after the command was first invoked.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            invocations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn retain_recent(&self, invocations: &mut HashMap<MessageId, TrackedInvocation>) {
+        let window = self.window;
+
+        invocations.retain(|_, i| i.invoked_at.elapsed() < window);
+    }
+
+    /// Tracks a command message, keeping the response of a previous
+    /// invocation of the same message.
+    pub(crate) async fn track(&self, msg: &Message) {
+        let mut invocations = self.invocations.lock().await;
+        self.retain_recent(&mut invocations);
+
+        invocations.entry(msg.id).or_insert_with(|| TrackedInvocation {
+            message: msg.clone(),
+            invoked_at: Instant::now(),
+            response: None,
+        });
+    }
+
+    /// Applies an edit to a tracked message, returning the edited message if
+    /// its content changed within the window.
+    pub(crate) async fn edited(&self, event: &MessageUpdateEvent) -> Option<Message> {
+        let mut invocations = self.invocations.lock().await;
+        self.retain_recent(&mut invocations);
+
+        let invocation = invocations.get_mut(&event.id)?;
+        let content = event.content.as_ref()?;
+
+        if *content == invocation.message.content {
+            return None;
+        }
+
+        invocation.message.content = content.clone();
+        invocation.message.edited_timestamp = event.edited_timestamp;
+
+        Some(invocation.message.clone())
+    }
+
+    /// Responds to a command message, editing the previous response if the
+    /// command was executed again because the message was edited.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the response could not be sent or edited.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    pub async fn say(&self, http: impl AsRef<Http>, msg: &Message, content: impl Display) -> Result<Message> {
+        let response = self.invocations.lock().await.get(&msg.id).and_then(|i| i.response);
+
+        if let Some(response) = response {
+            return msg.channel_id.edit_message(&http, response, |m| m.content(content)).await;
+        }
+
+        let response = msg.channel_id.say(&http, content).await?;
+
+        if let Some(invocation) = self.invocations.lock().await.get_mut(&msg.id) {
+            invocation.response = Some(response.id);
+        }
+
+        Ok(response)
+    }
+}
+
+impl TypeMapKey for EditTracker {
+    type Value = Arc<EditTracker>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{test_server, HttpBuilder};
+    use serde_json::json;
+
+    fn message(content: &str) -> Message {
+        serde_json::from_value(json!({
+            "attachments": [],
+            "author": {"discriminator": "0001", "id": "2", "username": "user"},
+            "channel_id": "3",
+            "content": content,
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "1",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "tts": false,
+            "type": 0,
+        })).unwrap()
+    }
+
+    fn update(content: &str) -> MessageUpdateEvent {
+        serde_json::from_value(json!({
+            "channel_id": "3",
+            "content": content,
+            "edited_timestamp": "2021-01-01T00:01:00+00:00",
+            "id": "1",
+        })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn returns_edited_messages_with_changed_content() {
+        let tracker = EditTracker::new(Duration::from_secs(60));
+        tracker.track(&message("~ping")).await;
+
+        assert!(tracker.edited(&update("~ping")).await.is_none());
+
+        let edited = tracker.edited(&update("~pong")).await.unwrap();
+        assert_eq!(edited.content, "~pong");
+        assert!(edited.edited_timestamp.is_some());
+
+        assert!(tracker.edited(&update("~pong")).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn ignores_untracked_and_expired_messages() {
+        let tracker = EditTracker::new(Duration::from_secs(60));
+        assert!(tracker.edited(&update("~pong")).await.is_none());
+
+        let tracker = EditTracker::new(Duration::from_secs(0));
+        tracker.track(&message("~ping")).await;
+
+        assert!(tracker.edited(&update("~pong")).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn say_edits_previous_response() {
+        let response = serde_json::to_string(&message("pong")).unwrap();
+        let (proxy, requests) = test_server::serve(vec![response; 2]);
+        let http = HttpBuilder::new("token").proxy(proxy).unwrap().build().unwrap();
+        let tracker = EditTracker::new(Duration::from_secs(60));
+        let msg = message("~ping");
+
+        tracker.track(&msg).await;
+        tracker.say(&http, &msg, "pong").await.unwrap();
+        tracker.say(&http, &msg, "pong again").await.unwrap();
+
+        assert!(requests.recv().unwrap().starts_with("POST /api/v8/channels/3/messages "));
+        assert!(requests.recv().unwrap().starts_with("PATCH /api/v8/channels/3/messages/1 "));
+    }
+}
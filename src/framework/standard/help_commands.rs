@@ -48,8 +48,10 @@
 //! ```
 //!
 //! The same can be accomplished with no embeds by substituting `with_embeds`
-//! with the [`plain`] function.
+//! with the [`plain`] function, or with a page per group by substituting it
+//! with the [`paginated`] function.
 //!
+//! [`paginated`]: fn.paginated.html
 //! [`plain`]: fn.plain.html
 //! [`with_embeds`]: fn.with_embeds.html
 
@@ -82,6 +84,12 @@ use std::{
 use tracing::warn;
 #[cfg(all(feature = "cache", feature = "http"))]
 use futures::future::{BoxFuture, FutureExt};
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+use crate::{builder::CreateEmbed, model::channel::ReactionType};
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+use futures::stream::StreamExt;
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+use std::time::Duration;
 
 /// Macro to format a command according to a `HelpBehaviour` or
 /// continue to the next command-name upon hiding.
@@ -343,6 +351,25 @@ fn find_any_command_matches(
         }).cloned()
 }
 
+/// Finds a sub command by the path of names leading to it, starting with the
+/// name of `command`, e.g. `config set prefix`.
+#[cfg(all(feature = "cache", feature = "http"))]
+fn find_sub_command(command: &'static InternalCommand, path: &str) -> Option<&'static InternalCommand> {
+    let rest = command
+        .options
+        .names
+        .iter()
+        .find(|name| starts_with_whole_word(path, name))
+        .map(|name| &path[name.len() + 1..])?;
+
+    command.options.sub_commands.iter().find_map(|sub_command| {
+        if sub_command.options.names.contains(&rest) {
+            Some(*sub_command)
+        } else {
+            find_sub_command(sub_command, rest)
+        }
+    })
+}
 
 #[cfg(all(feature = "cache", feature = "http"))]
 async fn check_common_behaviour(
@@ -466,16 +493,21 @@ async fn _nested_group_command_search<'rec, 'a: 'rec>(
                 )
             };
 
-            if search_command_name_matched.is_some() {
+            let search_command = match search_command_name_matched {
+                Some(_) => Some(command),
+                None => find_sub_command(command, &name),
+            };
+
+            if let Some(search_command) = search_command {
                 if HelpBehaviour::Nothing == check_command_behaviour(
                     ctx,
                     msg,
-                    &command.options,
+                    &search_command.options,
                     group.options.checks,
                     &owners,
                     &help_options,
                 ).await {
-                    found = Some(command);
+                    found = Some(search_command);
                 } else {
                     break;
                 }
@@ -1273,19 +1305,29 @@ pub async fn with_embeds(
     let formatted_help =
         create_customised_help_data(ctx, msg, &args, &groups, &owners, help_options).await;
 
+    send_help_embed(ctx, msg, &formatted_help, help_options).await
+}
+
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn send_help_embed(
+    ctx: &Context,
+    msg: &Message,
+    formatted_help: &CustomisedHelpData<'_>,
+    help_options: &HelpOptions,
+) -> Option<Message> {
     let response_result = match formatted_help {
         CustomisedHelpData::SuggestedCommands {
-            ref help_description,
-            ref suggestions,
+            help_description,
+            suggestions,
         } => send_suggestion_embed(
             &ctx.http,
             msg.channel_id,
-            &help_description,
-            &suggestions,
+            help_description,
+            suggestions,
             help_options.embed_error_colour,
         ).await,
         CustomisedHelpData::NoCommandFound {
-            ref help_error_message,
+            help_error_message,
         } => send_error_embed(
             &ctx.http,
             msg.channel_id,
@@ -1293,21 +1335,21 @@ pub async fn with_embeds(
             help_options.embed_error_colour,
         ).await,
         CustomisedHelpData::GroupedCommands {
-            ref help_description,
-            ref groups,
+            help_description,
+            groups,
         } => send_grouped_commands_embed(
             &ctx.http,
-            &help_options,
+            help_options,
             msg.channel_id,
-            &help_description,
-            &groups,
+            help_description,
+            groups,
             help_options.embed_success_colour,
         ).await,
-        CustomisedHelpData::SingleCommand { ref command } => send_single_command_embed(
+        CustomisedHelpData::SingleCommand { command } => send_single_command_embed(
             &ctx.http,
-            &help_options,
+            help_options,
             msg.channel_id,
-            &command,
+            command,
             help_options.embed_success_colour,
         ).await,
     };
@@ -1315,12 +1357,150 @@ pub async fn with_embeds(
     match response_result {
         Ok(response) => Some(response),
         Err(why) => {
-            warn_about_failed_send!(&formatted_help, why);
+            warn_about_failed_send!(formatted_help, why);
             None
         },
     }
 }
 
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+const PREVIOUS_PAGE: &str = "◀️";
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+const NEXT_PAGE: &str = "▶️";
+
+/// Posts an embed showing one command group and its commands at a time, like
+/// [`with_embeds`] otherwise.
+///
+/// The invoking user navigates between the groups by reacting with ◀️ and
+/// ▶️, until no reaction was added or removed for two minutes. Commands are
+/// filtered by the user's checks like in the other help commands.
+///
+/// # Examples
+///
+/// Use the command with `exec_help`:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// use std::{collections::HashSet, hash::BuildHasher};
+/// use serenity::{framework::standard::{Args, CommandGroup, CommandResult,
+///     StandardFramework, macros::help, HelpOptions,
+///     help_commands::*}, model::prelude::*,
+/// };
+///
+/// #[help]
+/// async fn my_help(
+///     context: &Context,
+///     msg: &Message,
+///     args: Args,
+///     help_options: &'static HelpOptions,
+///     groups: &[&'static CommandGroup],
+///     owners: HashSet<UserId>
+/// ) -> CommandResult {
+///     let _ = paginated(context, msg, args, &help_options, groups, owners).await;
+///     Ok(())
+/// }
+///
+/// let framwork = StandardFramework::new()
+///     .help(&MY_HELP);
+/// ```
+///
+/// [`with_embeds`]: fn.with_embeds.html
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+#[allow(clippy::implicit_hasher)]
+pub async fn paginated(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    help_options: &HelpOptions,
+    groups: &[&'static CommandGroup],
+    owners: HashSet<UserId>,
+) -> Option<Message> {
+    let formatted_help =
+        create_customised_help_data(ctx, msg, &args, groups, &owners, help_options).await;
+
+    let (help_description, groups) = match &formatted_help {
+        CustomisedHelpData::GroupedCommands {
+            help_description,
+            groups,
+        } if groups.len() > 1 => (help_description, groups),
+        _ => return send_help_embed(ctx, msg, &formatted_help, help_options).await,
+    };
+
+    let pages = groups
+        .iter()
+        .map(|group| {
+            let mut text = String::default();
+            flatten_group_to_string(&mut text, group, 0, help_options);
+
+            (group.name, text)
+        })
+        .collect::<Vec<_>>();
+
+    let mut page = 0;
+
+    let mut response = match msg.channel_id.send_message(&ctx.http, |m| {
+        m.embed(|e| help_page_embed(e, help_options, help_description, &pages, page))
+    }).await {
+        Ok(response) => response,
+        Err(why) => {
+            warn_about_failed_send!(&formatted_help, why);
+            return None;
+        },
+    };
+
+    for emoji in &[PREVIOUS_PAGE, NEXT_PAGE] {
+        if let Err(why) = response.react(ctx, ReactionType::Unicode(emoji.to_string())).await {
+            warn!("Failed to add help navigation reactions: {:?}", why);
+            return Some(response);
+        }
+    }
+
+    // Removing a reaction counts as well, so the user does not have to add
+    // it again.
+    let mut reactions = response
+        .await_reactions(&ctx)
+        .author_id(msg.author.id)
+        .removed(true)
+        .idle_timeout(Duration::from_secs(120))
+        .await;
+
+    while let Some(action) = reactions.next().await {
+        page = match action.emoji() {
+            ReactionType::Unicode(emoji) if emoji == PREVIOUS_PAGE => page.checked_sub(1).unwrap_or(pages.len() - 1),
+            ReactionType::Unicode(emoji) if emoji == NEXT_PAGE => (page + 1) % pages.len(),
+            _ => continue,
+        };
+
+        let edited = response.edit(ctx, |m| {
+            m.embed(|e| help_page_embed(e, help_options, help_description, &pages, page))
+        }).await;
+
+        if let Err(why) = edited {
+            warn!("Failed to turn the help page: {:?}", why);
+            break;
+        }
+    }
+
+    Some(response)
+}
+
+#[cfg(all(feature = "cache", feature = "http", feature = "collector"))]
+fn help_page_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    help_options: &HelpOptions,
+    help_description: &str,
+    pages: &[(&str, String)],
+    page: usize,
+) -> &'a mut CreateEmbed {
+    let (name, text) = &pages[page];
+
+    embed
+        .colour(help_options.embed_success_colour)
+        .description(help_description)
+        .field(name, text, false)
+        .footer(|f| f.text(format!("{}/{}", page + 1, pages.len())))
+}
+
 /// Turns grouped commands into a `String` taking plain help format into account.
 #[cfg(all(feature = "cache", feature = "http"))]
 fn grouped_commands_to_plain_string(
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+/// Localized names of commands, accepted in addition to the names and
+/// aliases the commands were declared with.
+///
+/// The localized names are keyed by the first name of the command, and also
+/// apply to sub commands. Added with [`Configuration::locale`].
+///
+/// # Examples
+///
+/// Accept German names for some commands:
+///
+/// ```rust
+/// use serenity::framework::standard::CommandLocale;
+///
+/// let mut locale = CommandLocale::new();
+/// locale.name("ban", "bannen").name("help", "hilfe");
+///
+/// // Or equivalently, from a locale file:
+/// let locale = CommandLocale::from_json(r#"{"ban": ["bannen"], "help": ["hilfe"]}"#)?;
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [`Configuration::locale`]: struct.Configuration.html#method.locale
+#[derive(Clone, Debug, Default)]
+pub struct CommandLocale {
+    pub(crate) names: HashMap<String, Vec<String>>,
+}
+
+impl CommandLocale {
+    /// Creates a locale without any names.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a locale from a JSON object, mapping the first names of
+    /// commands to lists of their localized names.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not an object of lists of strings.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        Ok(Self {
+            names: serde_json::from_str(json)?,
+        })
+    }
+
+    /// Adds a localized name of a command, given its first name.
+    pub fn name(&mut self, command: impl Into<String>, localized: impl Into<String>) -> &mut Self {
+        self.names.entry(command.into()).or_default().push(localized.into());
+
+        self
+    }
+
+    /// The localized names of a command, given its first name.
+    pub fn names_of(&self, command: &str) -> &[String] {
+        self.names.get(command).map_or(&[], |n| &n[..])
+    }
+
+    pub(crate) fn merge(&mut self, other: CommandLocale) {
+        for (command, names) in other.names {
+            self.names.entry(command).or_default().extend(names);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandLocale;
+
+    #[test]
+    fn from_json_and_merge() {
+        let mut locale = CommandLocale::from_json(r#"{"ban": ["bannen"]}"#).unwrap();
+
+        let mut french = CommandLocale::new();
+        french.name("ban", "bannir").name("kick", "expulser");
+        locale.merge(french);
+
+        assert_eq!(locale.names_of("ban"), &["bannen".to_string(), "bannir".to_string()]);
+        assert_eq!(locale.names_of("kick"), &["expulser".to_string()]);
+        assert!(locale.names_of("help").is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// A command having been executed by the framework, passed to the
+/// [`FrameworkMetrics`].
+///
+/// [`FrameworkMetrics`]: trait.FrameworkMetrics.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CommandRecord {
+    /// The first name of the command.
+    pub command: &'static str,
+    /// How long the command took to execute.
+    pub latency: Duration,
+    /// Whether the command returned `Ok`.
+    pub success: bool,
+    /// The Id of the shard that received the invoking message.
+    pub shard_id: u64,
+}
+
+/// A recorder of command usage, set with [`StandardFramework::metrics`].
+///
+/// [`StandardFramework::metrics`]: struct.StandardFramework.html#method.metrics
+#[async_trait]
+pub trait FrameworkMetrics: Send + Sync {
+    /// Called after every command the framework executed.
+    async fn record(&self, record: &CommandRecord);
+}
+
+/// The usage of a command, as recorded by [`MemoryMetrics`].
+///
+/// [`MemoryMetrics`]: struct.MemoryMetrics.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CommandStats {
+    /// How many times the command was executed.
+    pub calls: u64,
+    /// How many times the command returned an error.
+    pub failures: u64,
+    /// The time spent executing the command, over all calls.
+    pub total_latency: Duration,
+}
+
+impl CommandStats {
+    /// The time the command took to execute on average, zero if it has not
+    /// been executed.
+    pub fn average_latency(&self) -> Duration {
+        if self.calls == 0 {
+            return Duration::default();
+        }
+
+        Duration::from_nanos((self.total_latency.as_nanos() / u128::from(self.calls)) as u64)
+    }
+}
+
+/// [`FrameworkMetrics`] counting the calls of each command in memory, which
+/// may be queried at runtime, e.g. from a command.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{MemoryMetrics, StandardFramework};
+/// use std::sync::Arc;
+///
+/// # async fn run() {
+/// let metrics = Arc::new(MemoryMetrics::new());
+///
+/// let framework = StandardFramework::new()
+///     .metrics(metrics.clone());
+///
+/// // Later on:
+/// for (command, stats) in metrics.all().await {
+///     println!("{}: {} calls, {} failed", command, stats.calls, stats.failures);
+/// }
+/// # }
+/// ```
+///
+/// [`FrameworkMetrics`]: trait.FrameworkMetrics.html
+#[derive(Debug, Default)]
+pub struct MemoryMetrics {
+    stats: RwLock<HashMap<&'static str, CommandStats>>,
+    shards: RwLock<HashMap<u64, u64>>,
+}
+
+impl MemoryMetrics {
+    /// Creates metrics without any recorded calls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The usage of a command, given its first name, `None` if it has not
+    /// been executed.
+    pub async fn stats(&self, command: &str) -> Option<CommandStats> {
+        self.stats.read().await.get(command).copied()
+    }
+
+    /// The usage of every command that has been executed, keyed by their
+    /// first names.
+    pub async fn all(&self) -> HashMap<&'static str, CommandStats> {
+        self.stats.read().await.clone()
+    }
+
+    /// How many commands were executed from messages received by each shard.
+    pub async fn calls_per_shard(&self) -> HashMap<u64, u64> {
+        self.shards.read().await.clone()
+    }
+
+    /// Clears all recorded calls.
+    pub async fn reset(&self) {
+        self.stats.write().await.clear();
+        self.shards.write().await.clear();
+    }
+}
+
+#[async_trait]
+impl FrameworkMetrics for MemoryMetrics {
+    async fn record(&self, record: &CommandRecord) {
+        {
+            let mut stats = self.stats.write().await;
+            let stats = stats.entry(record.command).or_default();
+
+            stats.calls += 1;
+            stats.total_latency += record.latency;
+
+            if !record.success {
+                stats.failures += 1;
+            }
+        }
+
+        *self.shards.write().await.entry(record.shard_id).or_default() += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandRecord, FrameworkMetrics, MemoryMetrics};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn memory_metrics_count_calls() {
+        let metrics = MemoryMetrics::new();
+
+        for (latency, success, shard_id) in &[(10, true, 0), (30, false, 1), (20, true, 1)] {
+            metrics.record(&CommandRecord {
+                command: "ping",
+                latency: Duration::from_millis(*latency),
+                success: *success,
+                shard_id: *shard_id,
+            }).await;
+        }
+
+        let stats = metrics.stats("ping").await.unwrap();
+        assert_eq!(stats.calls, 3);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.average_latency(), Duration::from_millis(20));
+        assert_eq!(metrics.calls_per_shard().await.get(&1), Some(&2));
+        assert_eq!(metrics.stats("help").await, None);
+
+        metrics.reset().await;
+        assert!(metrics.all().await.is_empty());
+    }
+}
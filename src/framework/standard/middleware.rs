@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use crate::client::Context;
+use crate::model::channel::Message;
+use typemap_rev::TypeMap;
+use super::{Args, Command, CommandError, CommandGroup, DispatchError};
+
+/// A command about to be, or having been, executed by the framework, passed
+/// through the [`Middleware`]s.
+///
+/// [`Middleware`]: trait.Middleware.html
+#[non_exhaustive]
+pub struct Invocation {
+    /// The group the command belongs to.
+    pub group: &'static CommandGroup,
+    /// The command to execute.
+    ///
+    /// Replacing the command executes another command instead, without
+    /// running its checks again.
+    pub command: &'static Command,
+    /// The arguments the command is executed with.
+    pub args: Args,
+    /// Data shared between the middlewares for the invocation, such as when
+    /// the command started executing.
+    pub data: TypeMap,
+}
+
+impl Invocation {
+    pub(crate) fn new(group: &'static CommandGroup, command: &'static Command, args: Args) -> Self {
+        Self {
+            group,
+            command,
+            args,
+            data: TypeMap::new(),
+        }
+    }
+}
+
+/// A layer of the framework's dispatch, added with
+/// [`StandardFramework::middleware`].
+///
+/// Middlewares are called in the order they were added, except for
+/// [`after_dispatch`] which is called in reverse order, so the first
+/// middleware wraps all others. Each method does nothing by default.
+///
+/// # Examples
+///
+/// Log how long commands take:
+///
+/// ```rust,no_run
+/// use serenity::async_trait;
+/// use serenity::framework::standard::{CommandError, Invocation, Middleware, StandardFramework};
+/// use serenity::model::channel::Message;
+/// use serenity::prelude::*;
+/// use std::time::Instant;
+///
+/// struct Started;
+///
+/// impl TypeMapKey for Started {
+///     type Value = Instant;
+/// }
+///
+/// struct Timing;
+///
+/// #[async_trait]
+/// impl Middleware for Timing {
+///     async fn before_dispatch(&self, _: &Context, _: &Message, invocation: &mut Invocation) -> bool {
+///         invocation.data.insert::<Started>(Instant::now());
+///
+///         true
+///     }
+///
+///     async fn after_dispatch(
+///         &self,
+///         _: &Context,
+///         _: &Message,
+///         invocation: &Invocation,
+///         _: &Result<(), CommandError>,
+///     ) {
+///         if let Some(started) = invocation.data.get::<Started>() {
+///             println!("{} took {:?}", invocation.command.options.names[0], started.elapsed());
+///         }
+///     }
+/// }
+///
+/// let framework = StandardFramework::new()
+///     .middleware(Timing);
+/// ```
+///
+/// [`StandardFramework::middleware`]: struct.StandardFramework.html#method.middleware
+/// [`after_dispatch`]: #method.after_dispatch
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called for every message the framework does not ignore, before it is
+    /// parsed.
+    ///
+    /// Returning `false` stops handling the message, including any
+    /// following middlewares.
+    async fn before_parse(&self, _ctx: &Context, _msg: &Message) -> bool {
+        true
+    }
+
+    /// Called once a command was found and its checks passed, before the
+    /// [`before`] hook.
+    ///
+    /// The invocation may be changed, returning `false` stops the command
+    /// from executing, including any following middlewares.
+    ///
+    /// **Note**: The help command does not pass through this method.
+    ///
+    /// [`before`]: struct.StandardFramework.html#method.before
+    async fn before_dispatch(&self, _ctx: &Context, _msg: &Message, _invocation: &mut Invocation) -> bool {
+        true
+    }
+
+    /// Called after a command was executed, before the [`after`] hook.
+    ///
+    /// **Note**: The help command does not pass through this method.
+    ///
+    /// [`after`]: struct.StandardFramework.html#method.after
+    async fn after_dispatch(
+        &self,
+        _ctx: &Context,
+        _msg: &Message,
+        _invocation: &Invocation,
+        _result: &Result<(), CommandError>,
+    ) {
+    }
+
+    /// Called when a command could not be dispatched, before the
+    /// [`on_dispatch_error`] hook.
+    ///
+    /// [`on_dispatch_error`]: struct.StandardFramework.html#method.on_dispatch_error
+    async fn on_error(&self, _ctx: &Context, _msg: &Message, _error: &DispatchError) {}
+}
@@ -1,35 +1,52 @@
+pub mod checks;
 pub mod help_commands;
 pub mod macros {
-    pub use command_attr::{command, group, help, check, hook};
+    pub use command_attr::{command, group, help, check, hook, ArgumentParse};
 }
 
 mod args;
+mod argument;
 mod configuration;
+mod edit_tracker;
+mod locale;
+mod metrics;
+mod middleware;
 mod parse;
+mod prefix_store;
 mod structures;
 
 pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
+pub use argument::{parse_rest, ArgumentParse, ArgumentParseError};
 pub use configuration::{Configuration, WithWhiteSpace};
+pub use edit_tracker::EditTracker;
+pub use locale::CommandLocale;
+pub use metrics::{CommandRecord, CommandStats, FrameworkMetrics, MemoryMetrics};
+pub use middleware::{Invocation, Middleware};
+pub use prefix_store::{JsonPrefixStore, MemoryPrefixStore, PrefixStore, PrefixStoreError};
 pub use structures::*;
 
-use structures::buckets::{Bucket, Ratelimit};
-pub use structures::buckets::BucketBuilder;
+use structures::buckets::Bucket;
+pub use structures::buckets::{BucketBuilder, Buckets, LimitedFor};
 
 use parse::{ParseError, Invoke};
 use parse::map::{CommandMap, GroupMap, Map};
 
 use super::Framework;
+use crate::builder::CreateApplicationCommands;
 use crate::client::Context;
+use crate::http::Http;
 use crate::model::{
     channel::Message,
+    event::MessageUpdateEvent,
+    id::GuildId,
+    interactions::ApplicationCommand,
     permissions::Permissions,
 };
+use serde_json::Value;
 
-use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use tokio::sync::Mutex;
 use futures::future::BoxFuture;
 use uwl::Stream;
 use async_trait::async_trait;
@@ -43,6 +60,8 @@ use crate::cache::Cache;
 use crate::model::guild::Member;
 #[cfg(all(feature = "cache", feature = "http", feature = "model"))]
 use crate::model::{guild::Role, id::RoleId};
+#[cfg(all(feature = "cache", feature = "http"))]
+use std::collections::HashMap;
 
 /// An enum representing all possible fail conditions under which a command won't
 /// be executed.
@@ -86,6 +105,7 @@ type AfterHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str, Result<()
 type UnrecognisedHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>;
 type NormalMessageHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
+type SubCommandFallbackHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut Command, &'fut str) -> BoxFuture<'fut, ()>;
 
 /// A utility for easily managing dispatches to commands.
 ///
@@ -95,13 +115,17 @@ type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'f
 #[derive(Default)]
 pub struct StandardFramework {
     groups: Vec<(&'static CommandGroup, Map)>,
-    buckets: Mutex<HashMap<String, Bucket>>,
+    buckets: Buckets,
     before: Option<BeforeHook>,
     after: Option<AfterHook>,
     dispatch: Option<DispatchHook>,
     unrecognised_command: Option<UnrecognisedHook>,
     normal_message: Option<NormalMessageHook>,
     prefix_only: Option<PrefixOnlyHook>,
+    sub_command_fallback: Option<SubCommandFallbackHook>,
+    middlewares: Vec<Box<dyn Middleware>>,
+    edit_tracker: Option<Arc<EditTracker>>,
+    metrics: Option<Arc<dyn FrameworkMetrics>>,
     config: Configuration,
     help: Option<&'static HelpCommand>,
     /// Whether the framework has been "initialized".
@@ -171,6 +195,10 @@ impl StandardFramework {
     /// Defines a bucket with `delay` between each command, and the `limit` of uses
     /// per `time_span`.
     ///
+    /// By default the cooldown applies to each user separately, refer to
+    /// [`BucketBuilder::limit_for`] to share it within a channel, guild or
+    /// between everyone.
+    ///
     /// # Examples
     ///
     /// Create and use a bucket that limits a command to 3 uses per 10 seconds with
@@ -193,6 +221,30 @@ impl StandardFramework {
     ///     .await;
     /// # }
     /// ```
+    ///
+    /// Let each guild use a command 5 times per minute, telling users when
+    /// they have to wait:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::standard::{LimitedFor, StandardFramework};
+    /// use serenity::model::channel::Message;
+    /// use serenity::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[hook]
+    /// async fn cooldown(ctx: &Context, msg: &Message, left: Duration) {
+    ///     let _ = msg.reply(ctx, format!("Try again in {} seconds.", left.as_secs())).await;
+    /// }
+    ///
+    /// # async fn run() {
+    /// let framework = StandardFramework::new()
+    ///     .bucket("guild", |b| b.limit_for(LimitedFor::Guild).time_span(60).limit(5).on_cooldown(cooldown))
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// [`BucketBuilder::limit_for`]: struct.BucketBuilder.html#method.limit_for
     #[inline]
     pub async fn bucket<F>(self, name: &str, f: F) -> Self
     where
@@ -202,40 +254,62 @@ impl StandardFramework {
 
         f(&mut builder);
 
-        let BucketBuilder {
-            delay,
-            time_span,
-            limit,
-            check,
-        } = builder;
-
-        self.buckets.lock().await.insert(
-            name.to_string(),
-            Bucket {
-                ratelimit: Ratelimit {
-                    delay,
-                    limit: Some((time_span, limit)),
-                },
-                users: HashMap::new(),
-                check,
-            },
-        );
+        self.buckets.0.lock().await.insert(name.to_string(), Bucket::from(builder));
 
         self
     }
 
+    /// Returns a handle to the framework's buckets, to reset cooldowns while
+    /// the framework is in use.
+    ///
+    /// The handle can be kept around, e.g. in the [`Client`]'s data, after
+    /// passing the framework to the client.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::standard::StandardFramework;
+    ///
+    /// # async fn run() {
+    /// let framework = StandardFramework::new()
+    ///     .bucket("basic", |b| b.delay(5))
+    ///     .await;
+    ///
+    /// let buckets = framework.buckets();
+    ///
+    /// // Later, e.g. from an owner command:
+    /// buckets.reset("basic").await;
+    /// # }
+    /// ```
+    ///
+    /// [`Client`]: ../../client/struct.Client.html
+    pub fn buckets(&self) -> Buckets {
+        self.buckets.clone()
+    }
+
     /// Whether the message should be ignored because it is from a bot or webhook.
     fn should_ignore(&self, msg: &Message) -> bool {
         (self.config.ignore_bots && msg.author.bot) ||
             (self.config.ignore_webhooks && msg.webhook_id.is_some())
     }
 
+    async fn dispatch_error(&self, ctx: &mut Context, msg: &Message, error: DispatchError) {
+        for middleware in &self.middlewares {
+            middleware.on_error(ctx, msg, &error).await;
+        }
+
+        if let Some(dispatch) = &self.dispatch {
+            dispatch(ctx, msg, error).await;
+        }
+    }
+
     async fn should_fail<'a>(
         &'a self,
         ctx: &'a Context,
         msg: &'a Message,
         args: &'a mut Args,
         command: &'static CommandOptions,
+        parents: &'a [&'static Command],
         group: &'static GroupOptions,
     ) -> Option<DispatchError> {
         if let Some(min) = command.min_args {
@@ -288,27 +362,44 @@ impl StandardFramework {
             return Some(DispatchError::BlockedChannel);
         }
 
-        {
-            let mut buckets = self.buckets.lock().await;
+        let rate_limit = {
+            let mut buckets = self.buckets.0.lock().await;
 
-            if let Some(ref mut bucket) = command.bucket.as_ref().and_then(|b| buckets.get_mut(*b)) {
-                let rate_limit = bucket.take(msg.author.id.0);
+            match command.bucket.as_ref().and_then(|b| buckets.get_mut(*b)) {
+                Some(bucket) => {
+                    let target = bucket.limited_for.target(msg);
 
-                let apply = match bucket.check.as_ref() {
-                    Some(check) => (check)(ctx, msg.guild_id, msg.channel_id, msg.author.id).await,
-                    None => true,
-                };
+                    bucket.take(target).map(|left| (left, bucket.check, bucket.on_cooldown))
+                },
+                None => None,
+            }
+        };
 
-                if let Some(rate_limit)= rate_limit {
-                    if apply {
-                        return Some(DispatchError::Ratelimited(rate_limit))
-                    }
+        // The hooks are called after releasing the lock, so they may make use
+        // of the buckets themselves.
+        if let Some((left, check, on_cooldown)) = rate_limit {
+            let apply = match check {
+                Some(check) => (check)(ctx, msg.guild_id, msg.channel_id, msg.author.id).await,
+                None => true,
+            };
+
+            if apply {
+                if let Some(on_cooldown) = on_cooldown {
+                    (on_cooldown)(ctx, msg, left).await;
                 }
+
+                return Some(DispatchError::Ratelimited(left));
             }
         }
 
-        for check in group.checks.iter().chain(command.checks.iter()) {
-            let res = (check.function)(ctx, msg, args, command).await;
+        // The checks of the commands a sub command belongs to apply to it as well.
+        let checks: Vec<(&'static Check, &'static CommandOptions)> = group.checks.iter().map(|c| (*c, command))
+            .chain(parents.iter().flat_map(|p| p.options.checks.iter().map(move |c| (*c, p.options))))
+            .chain(command.checks.iter().map(|c| (*c, command)))
+            .collect();
+
+        for (check, options) in checks {
+            let res = (check.function)(ctx, msg, args, options).await;
 
             if let CheckResult::Failure(r) = res {
                 return Some(DispatchError::CheckFailed(check.name, r));
@@ -402,6 +493,81 @@ impl StandardFramework {
         self.groups.retain(|&(g, _)| g != group)
     }
 
+    /// Creates the definitions of the commands of all groups as application
+    /// commands, letting them double as slash commands.
+    ///
+    /// Refer to [`CommandGroup::create_application_commands`] for how the
+    /// commands are converted.
+    ///
+    /// **Note**: Interactions invoking the commands are not dispatched to the
+    /// commands and must be handled separately.
+    ///
+    /// [`CommandGroup::create_application_commands`]: struct.CommandGroup.html#method.create_application_commands
+    pub fn application_commands(&self) -> CreateApplicationCommands {
+        let mut commands = CreateApplicationCommands::default();
+
+        for (group, _) in &self.groups {
+            group.create_application_commands(&mut commands);
+        }
+
+        commands
+    }
+
+    /// Registers the commands of all groups as the application commands of
+    /// the application, or of a guild if a `guild_id` is given, overwriting
+    /// the existing commands.
+    ///
+    /// This is best done once at startup, before passing the framework to
+    /// the [`Client`].
+    ///
+    /// # Examples
+    ///
+    /// Register the commands in a testing guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::framework::standard::{CommandResult, macros::{command, group}};
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// /// Rolls a die.
+    /// #[command]
+    /// #[arg(sides, u64, "The number of sides of the die", optional)]
+    /// async fn roll(ctx: &Context, msg: &Message) -> CommandResult {
+    ///     Ok(())
+    /// }
+    ///
+    /// #[group]
+    /// #[commands(roll)]
+    /// struct General;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let framework = StandardFramework::new().group(&GENERAL_GROUP);
+    /// let application_id = http.get_current_application_info().await?.id;
+    ///
+    /// framework.register_application_commands(&http, application_id.0, Some(GuildId(1))).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Client`]: ../../client/struct.Client.html
+    pub async fn register_application_commands(
+        &self,
+        http: impl AsRef<Http>,
+        application_id: u64,
+        guild_id: Option<GuildId>,
+    ) -> crate::Result<Vec<ApplicationCommand>> {
+        let commands = Value::Array(self.application_commands().0);
+
+        match guild_id {
+            Some(guild_id) => http.as_ref().create_guild_application_commands(application_id, guild_id.0, &commands).await,
+            None => http.as_ref().create_global_application_commands(application_id, &commands).await,
+        }
+    }
+
     /// Specify the function that's called in case a command wasn't executed for one reason or
     /// another.
     ///
@@ -444,6 +610,48 @@ impl StandardFramework {
         self
     }
 
+    /// Adds a middleware, a layer of the dispatch which may stop or change
+    /// invocations, or observe them, e.g. for metrics.
+    ///
+    /// Middlewares run in the order they were added, and run before the
+    /// [`before`], [`after`] and [`on_dispatch_error`] hooks. Refer to
+    /// [`Middleware`] for an example.
+    ///
+    /// [`Middleware`]: trait.Middleware.html
+    /// [`after`]: #method.after
+    /// [`before`]: #method.before
+    /// [`on_dispatch_error`]: #method.on_dispatch_error
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middlewares.push(Box::new(middleware));
+
+        self
+    }
+
+    /// Records the usage of every executed command, e.g. with
+    /// [`MemoryMetrics`] to count the calls of each command.
+    ///
+    /// The latency measured covers the command itself, excluding checks and
+    /// hooks.
+    ///
+    /// [`MemoryMetrics`]: struct.MemoryMetrics.html
+    pub fn metrics<M: FrameworkMetrics + 'static>(mut self, metrics: Arc<M>) -> Self {
+        self.metrics = Some(metrics);
+
+        self
+    }
+
+    /// Executes commands again when their message is edited shortly after,
+    /// tracking the invocations with the tracker.
+    ///
+    /// Refer to [`EditTracker`] for an example.
+    ///
+    /// [`EditTracker`]: struct.EditTracker.html
+    pub fn edit_tracker(mut self, tracker: Arc<EditTracker>) -> Self {
+        self.edit_tracker = Some(tracker);
+
+        self
+    }
+
     /// Specify the function to be called on messages comprised of only the prefix.
     pub fn prefix_only(mut self, f: PrefixOnlyHook) -> Self {
         self.prefix_only = Some(f);
@@ -589,6 +797,46 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called instead of a command with sub
+    /// commands, when it is invoked without naming one of its sub commands.
+    ///
+    /// The function receives the command and the name given in place of a
+    /// sub command, which is empty if none was given. By default, the
+    /// command itself is executed.
+    ///
+    /// # Examples
+    ///
+    /// Using `sub_command_fallback`:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::{Command, macros::hook};
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn sub_command_fallback_hook(ctx: &Context, msg: &Message, command: &Command, name: &str) {
+    ///     let sub_commands = command.options.sub_commands
+    ///         .iter()
+    ///         .map(|c| c.options.names[0])
+    ///         .collect::<Vec<_>>()
+    ///         .join("`, `");
+    ///
+    ///     let _ = msg.reply(ctx, format!(
+    ///         "`{}` is not a sub command of `{}`, try one of `{}`",
+    ///         name, command.options.names[0], sub_commands,
+    ///     )).await;
+    /// }
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .sub_command_fallback(sub_command_fallback_hook);
+    /// ```
+    pub fn sub_command_fallback(mut self, f: SubCommandFallbackHook) -> Self {
+        self.sub_command_fallback = Some(f);
+
+        self
+    }
+
     /// Sets what code should be executed when a user sends `(prefix)help`.
     ///
     /// If a command named `help` in a group was set, then this takes precedence first.
@@ -607,6 +855,12 @@ impl Framework for StandardFramework {
             return;
         }
 
+        for middleware in &self.middlewares {
+            if !middleware.before_parse(&ctx, &msg).await {
+                return;
+            }
+        }
+
         let mut stream = Stream::new(&msg.content);
 
         stream.take_while_char(|c| c.is_whitespace());
@@ -654,9 +908,7 @@ impl Framework for StandardFramework {
                 return;
             }
             Err(ParseError::Dispatch(error)) => {
-                if let Some(dispatch) = &self.dispatch {
-                    dispatch(&mut ctx, &msg, error).await;
-                }
+                self.dispatch_error(&mut ctx, &msg, error).await;
 
                 return;
             }
@@ -684,7 +936,7 @@ impl Framework for StandardFramework {
                     after(&mut ctx, &msg, name, res).await;
                 }
             }
-            Invoke::Command { command, group } => {
+            Invoke::Command { command, group, parents, partial } => {
                 let mut args = {
                     use std::borrow::Cow;
 
@@ -711,15 +963,36 @@ impl Framework for StandardFramework {
                 };
 
                 if let Some(error) =
-                    self.should_fail(&ctx, &msg, &mut args, &command.options, &group.options).await
+                    self.should_fail(&ctx, &msg, &mut args, &command.options, &parents, &group.options).await
                 {
-                    if let Some(dispatch) = &self.dispatch {
-                        dispatch(&mut ctx, &msg, error).await;
-                    }
+                    self.dispatch_error(&mut ctx, &msg, error).await;
 
                     return;
                 }
 
+                if partial {
+                    if let Some(sub_command_fallback) = &self.sub_command_fallback {
+                        let name = stream.peek_until_char(|c| c.is_whitespace());
+
+                        sub_command_fallback(&mut ctx, &msg, command, name).await;
+
+                        return;
+                    }
+                }
+
+                if let Some(tracker) = &self.edit_tracker {
+                    tracker.track(&msg).await;
+                }
+
+                let mut invocation = Invocation::new(group, command, args);
+
+                for middleware in &self.middlewares {
+                    if !middleware.before_dispatch(&ctx, &msg, &mut invocation).await {
+                        return;
+                    }
+                }
+
+                let command = invocation.command;
                 let name = command.options.names[0];
 
                 if let Some(before) = &self.before {
@@ -728,7 +1001,21 @@ impl Framework for StandardFramework {
                     }
                 }
 
-                let res = (command.fun)(&mut ctx, &msg, args).await;
+                let started = Instant::now();
+                let res = (command.fun)(&mut ctx, &msg, invocation.args.clone()).await;
+
+                if let Some(metrics) = &self.metrics {
+                    metrics.record(&CommandRecord {
+                        command: name,
+                        latency: started.elapsed(),
+                        success: res.is_ok(),
+                        shard_id: ctx.shard_id,
+                    }).await;
+                }
+
+                for middleware in self.middlewares.iter().rev() {
+                    middleware.after_dispatch(&ctx, &msg, &invocation, &res).await;
+                }
 
                 if let Some(after) = &self.after {
                     after(&mut ctx, &msg, name, res).await;
@@ -736,6 +1023,21 @@ impl Framework for StandardFramework {
             }
         }
     }
+
+    async fn dispatch_update(&self, ctx: Context, event: MessageUpdateEvent) {
+        let tracker = match &self.edit_tracker {
+            Some(tracker) => tracker,
+            None => return,
+        };
+
+        if let Some(msg) = tracker.edited(&event).await {
+            self.dispatch(ctx, msg).await;
+        }
+    }
+
+    fn handles_updates(&self) -> bool {
+        self.edit_tracker.is_some()
+    }
 }
 
 pub trait CommonOptions {
@@ -817,7 +1119,7 @@ pub(crate) async fn has_correct_permissions(
     if options.required_permissions().is_empty() {
         true
     } else if let Some(guild) = message.guild(&cache).await {
-        let perms = guild.user_permissions_in(message.channel_id, message.author.id);
+        let perms = guild.user_permissions_in_ids(message.channel_id, message.author.id);
 
         perms.contains(*options.required_permissions())
     } else {
@@ -18,6 +18,14 @@ pub trait ParseMap {
     fn is_empty(&self) -> bool;
 }
 
+fn case(conf: &Configuration, name: &str) -> String {
+    if conf.case_insensitive {
+        name.to_lowercase()
+    } else {
+        name.to_string()
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CommandMap {
     cmds: HashMap<String, (&'static Command, Arc<CommandMap>)>,
@@ -32,18 +40,15 @@ impl CommandMap {
         for cmd in cmds {
             let sub_map = Arc::new(Self::new(&cmd.options.sub_commands, conf));
 
-            for name in cmd.options.names {
+            let localized = conf.locale.names_of(cmd.options.names[0]);
+            let names = cmd.options.names.iter().copied().chain(localized.iter().map(String::as_str));
+
+            for name in names {
                 let len = name.chars().count();
                 map.min_length = std::cmp::min(len, map.min_length);
                 map.max_length = std::cmp::max(len, map.max_length);
 
-                let name = if conf.case_insensitive {
-                    name.to_lowercase()
-                } else {
-                    name.to_string()
-                };
-
-                map.cmds.insert(name, (*cmd, sub_map.clone()));
+                map.cmds.insert(case(conf, name), (*cmd, sub_map.clone()));
             }
         }
 
@@ -77,7 +82,7 @@ impl ParseMap for CommandMap {
 
 #[derive(Debug, Default)]
 pub struct GroupMap {
-    groups: HashMap<&'static str, (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>)>,
+    groups: HashMap<String, (&'static CommandGroup, Arc<GroupMap>, Arc<CommandMap>)>,
     min_length: usize,
     max_length: usize,
 }
@@ -95,7 +100,7 @@ impl GroupMap {
                 map.min_length = std::cmp::min(len, map.min_length);
                 map.max_length = std::cmp::max(len, map.max_length);
 
-                map.groups.insert(*prefix, (*group, subgroups_map.clone(), commands_map.clone()));
+                map.groups.insert(case(conf, prefix), (*group, subgroups_map.clone(), commands_map.clone()));
             }
         }
 
@@ -118,7 +123,7 @@ impl ParseMap for GroupMap {
 
     #[inline]
     fn get(&self, name: &str) -> Option<Self::Storage> {
-        self.groups.get(&name).cloned()
+        self.groups.get(name).cloned()
     }
 
     #[inline]
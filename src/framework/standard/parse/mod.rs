@@ -1,6 +1,7 @@
 use super::*;
 use crate::client::Context;
 use crate::model::prelude::*;
+use crate::model::guild::calculate_permissions;
 use crate::http::Http;
 
 use uwl::Stream;
@@ -11,111 +12,35 @@ use map::{CommandMap, GroupMap, ParseMap};
 
 use std::borrow::Cow;
 use futures::future::{BoxFuture, FutureExt};
-use tracing::{error, warn};
-
-// FIXME: Add the `http` parameter to `Guild::user_permissions_in`.
-//
-// Trying to shove the parameter to the original method results in several errors
-// and interface changes to methods using `Guild::user_permissions_in` that are not
-// worthwhile to resolve. As a compromise, the method has been copied with the parameter
-// added in to the place where the *problem* occurs.
-//
-// When a bot's command is invoked in a large guild (e.g., 250k+ members), the method
-// fails to retrieve the member data of the author that invoked the command, and instead
-// defaults to `@everyone`'s permissions. This is because Discord does not send data of
-// all members past 250, resulting in the problem to meet permissions of a command even if
-// the author does possess them. To avoid defaulting to permissions of everyone, we fetch
-// the member from HTTP if it is missing in the guild's members list.
+use tracing::warn;
+
+// When a bot's command is invoked in a large guild (e.g., 250k+ members), the member data
+// of the author may be missing from the cache, as Discord does not send data of all members
+// past 250. Calculating the permissions without it would default to `@everyone`'s
+// permissions, failing to meet the permissions of a command even if the author does
+// possess them. To avoid this, we fetch the member from HTTP if it is missing in the guild's
+// members list.
 async fn permissions_in(
     http: impl AsRef<Http>,
     guild: &Guild,
     channel_id: ChannelId,
     user_id: UserId,
 ) -> Permissions {
-    if user_id == guild.owner_id {
-        return Permissions::all();
-    }
-
-    let everyone = match guild.roles.get(&RoleId(guild.id.0)) {
-        Some(everyone) => everyone,
-        None => {
-            error!("@everyone role ({}) missing in '{}'", guild.id, guild.name);
-
-            return Permissions::empty();
-        },
-    };
-
-    let mut permissions = everyone.permissions;
-
     let member = match guild.members.get(&user_id) {
         Some(member) => Cow::Borrowed(member),
         None => match http.as_ref().get_member(guild.id.0, user_id.0).await {
             Ok(member) => Cow::Owned(member),
-            Err(_) => return everyone.permissions,
+            Err(_) => return guild.user_permissions_in_ids(channel_id, user_id),
         },
     };
 
-    for &role in &member.roles {
-        if let Some(role) = guild.roles.get(&role) {
-            permissions |= role.permissions;
-        } else {
-            warn!("{} on {} has non-existent role {:?}", member.user.id, guild.id, role);
-        }
-    }
-
-    if permissions.contains(Permissions::ADMINISTRATOR) {
-        return Permissions::all();
-    }
-
-    if let Some(channel) = guild.channels.get(&channel_id) {
-        if channel.kind == ChannelType::Text {
-            permissions &= !(Permissions::CONNECT
-                             | Permissions::SPEAK
-                             | Permissions::MUTE_MEMBERS
-                             | Permissions::DEAFEN_MEMBERS
-                             | Permissions::MOVE_MEMBERS
-                             | Permissions::USE_VAD
-                             | Permissions::STREAM);
-        }
-
-        let mut data = Vec::with_capacity(member.roles.len());
+    let channel = guild.channels.get(&channel_id);
 
-        for overwrite in &channel.permission_overwrites {
-            if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                if role.0 != guild.id.0 && !member.roles.contains(&role) {
-                    continue;
-                }
-
-                if let Some(role) = guild.roles.get(&role) {
-                    data.push((role.position, overwrite.deny, overwrite.allow));
-                }
-            }
-        }
-
-        data.sort_by(|a, b| a.0.cmp(&b.0));
-
-        for overwrite in data {
-            permissions = (permissions & !overwrite.1) | overwrite.2;
-        }
-
-        for overwrite in &channel.permission_overwrites {
-            if PermissionOverwriteType::Member(user_id) != overwrite.kind {
-                continue;
-            }
-
-            permissions = (permissions & !overwrite.deny) | overwrite.allow;
-        }
-    } else {
+    if channel.is_none() {
         warn!("Guild {} does not contain channel {}", guild.id, channel_id);
     }
 
-    if channel_id.0 == guild.id.0 {
-        permissions |= Permissions::READ_MESSAGES;
-    }
-
-    guild.remove_unusable_permissions(&mut permissions);
-
-    permissions
+    calculate_permissions(guild.id, guild.owner_id, &guild.roles, channel, user_id, Some(&member))
 }
 
 #[inline]
@@ -189,6 +114,15 @@ async fn find_prefix<'a>(
         }
     }
 
+    // A guild's own prefix replaces the default prefixes.
+    if let (Some(store), Some(guild_id)) = (&config.prefix_store, msg.guild_id) {
+        match store.get(guild_id).await {
+            Ok(Some(p)) => return try_match(&to_lowercase(config, &p)),
+            Ok(None) => {},
+            Err(why) => warn!("Failed to get the prefix of guild {}: {:?}", guild_id, why),
+        }
+    }
+
     config.prefixes.iter().find_map(|p| try_match(&p))
 }
 
@@ -309,13 +243,18 @@ fn try_parse<M: ParseMap>(
     }
 }
 
+/// Walks the tree of commands and their sub commands, returning the deepest
+/// command matched and whether more sub commands were expected after it.
+///
+/// The commands leading to it are pushed onto `parents`.
 fn parse_cmd<'a>(
     stream: &'a mut Stream<'_>,
     ctx: &'a Context,
     msg: &'a Message,
     config: &'a Configuration,
     map: &'a CommandMap,
-) -> BoxFuture<'a, Result<&'static Command, ParseError>> {
+    parents: &'a mut Vec<&'static Command>,
+) -> BoxFuture<'a, Result<(&'static Command, bool), ParseError>> {
     async move {
         let (n, r) = try_parse(stream, map, config.by_space, |s| {
             to_lowercase(config, s).into_owned()
@@ -335,11 +274,18 @@ fn parse_cmd<'a>(
             check_discrepancy(ctx, msg, config, &cmd.options).await?;
 
             if map.is_empty() {
-                return Ok(cmd);
+                return Ok((cmd, false));
             }
 
-            return match parse_cmd(stream, ctx, msg, config, &map).await {
-                Err(ParseError::UnrecognisedCommand(Some(_))) => Ok(cmd),
+            parents.push(cmd);
+
+            return match parse_cmd(stream, ctx, msg, config, &map, parents).await {
+                // Only part of the path matched.
+                Err(ParseError::UnrecognisedCommand(Some(_))) => {
+                    parents.pop();
+
+                    Ok((cmd, true))
+                },
                 res => res,
             };
         }
@@ -356,7 +302,9 @@ fn parse_group<'a>(
     map: &'a GroupMap,
 ) -> BoxFuture<'a, Result<(&'static CommandGroup, Arc<CommandMap>), ParseError>> {
     async move {
-        let (n, o) = try_parse(stream, map, config.by_space, ToString::to_string);
+        let (n, o) = try_parse(stream, map, config.by_space, |s| {
+            to_lowercase(config, s).into_owned()
+        });
 
         if let Some((group, map, commands)) = o {
             stream.increment(n.len());
@@ -390,10 +338,22 @@ async fn handle_command<'a>(
     map: &'a CommandMap,
     group: &'static CommandGroup,
 ) -> Result<Invoke, ParseError> {
-    match parse_cmd(stream, ctx, msg, config, map).await {
-        Ok(command) => Ok(Invoke::Command { group, command }),
+    let mut parents = Vec::new();
+
+    match parse_cmd(stream, ctx, msg, config, map, &mut parents).await {
+        Ok((command, partial)) => Ok(Invoke::Command {
+            group,
+            command,
+            parents,
+            partial,
+        }),
         Err(err) => match group.options.default_command {
-            Some(command) => Ok(Invoke::Command { group, command }),
+            Some(command) => Ok(Invoke::Command {
+                group,
+                command,
+                parents: Vec::new(),
+                partial: false,
+            }),
             None => Err(err),
         },
     }
@@ -507,6 +467,10 @@ pub enum Invoke {
     Command {
         group: &'static CommandGroup,
         command: &'static Command,
+        /// The commands the command is a sub command of, outermost first.
+        parents: Vec<&'static Command>,
+        /// Whether the command has sub commands, but none of them matched.
+        partial: bool,
     },
     Help(&'static str),
 }
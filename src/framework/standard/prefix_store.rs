@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use crate::model::id::GuildId;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+/// An error returned by a [`PrefixStore`].
+///
+/// [`PrefixStore`]: trait.PrefixStore.html
+pub type PrefixStoreError = Box<dyn StdError + Send + Sync>;
+
+/// A storage of the prefixes of guilds, used to resolve the prefix of
+/// messages sent in a guild.
+///
+/// Set with [`Configuration::prefix_store`].
+///
+/// **Note**: The prefix is retrieved for every message sent in a guild, so
+/// implementations backed by a database should keep the prefixes in memory.
+///
+/// [`Configuration::prefix_store`]: struct.Configuration.html#method.prefix_store
+#[async_trait]
+pub trait PrefixStore: Send + Sync {
+    /// Retrieves the prefix of a guild, `None` if it has no prefix of its own.
+    async fn get(&self, guild_id: GuildId) -> Result<Option<String>, PrefixStoreError>;
+
+    /// Sets the prefix of a guild.
+    async fn set(&self, guild_id: GuildId, prefix: &str) -> Result<(), PrefixStoreError>;
+
+    /// Removes the prefix of a guild, so it uses the default prefixes again.
+    async fn remove(&self, guild_id: GuildId) -> Result<(), PrefixStoreError>;
+}
+
+/// A [`PrefixStore`] keeping the prefixes in memory, which are lost when the
+/// bot restarts.
+///
+/// [`PrefixStore`]: trait.PrefixStore.html
+#[derive(Debug, Default)]
+pub struct MemoryPrefixStore {
+    prefixes: RwLock<HashMap<GuildId, String>>,
+}
+
+impl MemoryPrefixStore {
+    /// Creates a store without any prefixes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PrefixStore for MemoryPrefixStore {
+    async fn get(&self, guild_id: GuildId) -> Result<Option<String>, PrefixStoreError> {
+        Ok(self.prefixes.read().await.get(&guild_id).cloned())
+    }
+
+    async fn set(&self, guild_id: GuildId, prefix: &str) -> Result<(), PrefixStoreError> {
+        self.prefixes.write().await.insert(guild_id, prefix.to_string());
+
+        Ok(())
+    }
+
+    async fn remove(&self, guild_id: GuildId) -> Result<(), PrefixStoreError> {
+        self.prefixes.write().await.remove(&guild_id);
+
+        Ok(())
+    }
+}
+
+/// A [`PrefixStore`] persisting the prefixes to a JSON file, while keeping
+/// them in memory.
+///
+/// The file is rewritten whenever a prefix changes, which suits small bots;
+/// larger bots may implement [`PrefixStore`] with a database instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::framework::standard::{JsonPrefixStore, StandardFramework};
+/// use std::sync::Arc;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let store = Arc::new(JsonPrefixStore::open("prefixes.json").await?);
+///
+/// let framework = StandardFramework::new()
+///     .configure(|c| c.prefix("~").prefix_store(store.clone()));
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`PrefixStore`]: trait.PrefixStore.html
+#[derive(Debug)]
+pub struct JsonPrefixStore {
+    path: PathBuf,
+    prefixes: RwLock<HashMap<u64, String>>,
+}
+
+impl JsonPrefixStore {
+    /// Opens the store persisted at the given path, which is created once a
+    /// prefix is set if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid store.
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self, PrefixStoreError> {
+        let path = path.into();
+
+        let prefixes = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(why) if why.kind() == ErrorKind::NotFound => HashMap::new(),
+            Err(why) => return Err(why.into()),
+        };
+
+        Ok(Self {
+            path,
+            prefixes: RwLock::new(prefixes),
+        })
+    }
+
+    async fn save(&self, prefixes: &HashMap<u64, String>) -> Result<(), PrefixStoreError> {
+        tokio::fs::write(&self.path, serde_json::to_vec(prefixes)?).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PrefixStore for JsonPrefixStore {
+    async fn get(&self, guild_id: GuildId) -> Result<Option<String>, PrefixStoreError> {
+        Ok(self.prefixes.read().await.get(&guild_id.0).cloned())
+    }
+
+    async fn set(&self, guild_id: GuildId, prefix: &str) -> Result<(), PrefixStoreError> {
+        let mut prefixes = self.prefixes.write().await;
+        prefixes.insert(guild_id.0, prefix.to_string());
+
+        self.save(&prefixes).await
+    }
+
+    async fn remove(&self, guild_id: GuildId) -> Result<(), PrefixStoreError> {
+        let mut prefixes = self.prefixes.write().await;
+
+        if prefixes.remove(&guild_id.0).is_some() {
+            self.save(&prefixes).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonPrefixStore, PrefixStore};
+    use crate::model::id::GuildId;
+
+    #[tokio::test]
+    async fn json_store_persists() {
+        let path = std::env::temp_dir().join(format!("serenity-prefixes-{}.json", std::process::id()));
+
+        let store = JsonPrefixStore::open(&path).await.unwrap();
+        assert_eq!(store.get(GuildId(1)).await.unwrap(), None);
+
+        store.set(GuildId(1), "!").await.unwrap();
+        store.set(GuildId(2), "?").await.unwrap();
+        store.remove(GuildId(2)).await.unwrap();
+
+        let store = JsonPrefixStore::open(&path).await.unwrap();
+        assert_eq!(store.get(GuildId(1)).await.unwrap(), Some("!".to_string()));
+        assert_eq!(store.get(GuildId(2)).await.unwrap(), None);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::builder::{
+    CreateApplicationCommand,
+    CreateApplicationCommandOption,
+    CreateApplicationCommands,
+};
+use crate::model::interactions::ApplicationCommandOptionType;
+use super::{Command, CommandArgument, CommandGroup, CommandOptions};
+
+impl Command {
+    /// Creates the definition of the command as an application command, to
+    /// be registered as a slash command.
+    ///
+    /// The name of the command is its first name, the description is the
+    /// first line of its description. Arguments declared with the `#[arg]`
+    /// attribute become the options of the command, while sub commands
+    /// become sub command options.
+    pub fn create_application_command(&self) -> CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        command
+            .name(application_command_name(self.options.names[0]))
+            .description(application_command_description(self.options));
+
+        if self.options.sub_commands.is_empty() {
+            command.set_options(self.options.arguments.iter().map(argument_option).collect());
+        } else {
+            command.set_options(self.options.sub_commands.iter().map(|c| sub_command_option(c, true)).collect());
+        }
+
+        command
+    }
+}
+
+impl CommandGroup {
+    /// Adds the definitions of the commands of the group and its sub groups
+    /// as application commands, to be registered as slash commands.
+    ///
+    /// The commands of a group with a prefix are added as sub commands of a
+    /// single command named after the prefix, mirroring how they are invoked
+    /// as text commands.
+    pub fn create_application_commands<'a>(
+        &self,
+        commands: &'a mut CreateApplicationCommands,
+    ) -> &'a mut CreateApplicationCommands {
+        let options = self.options;
+
+        match options.prefixes.first() {
+            Some(prefix) if !options.commands.is_empty() => {
+                let mut command = CreateApplicationCommand::default();
+                command
+                    .name(application_command_name(prefix))
+                    .description(truncate(options.description.unwrap_or(self.name)))
+                    .set_options(options.commands.iter().map(|c| sub_command_option(c, true)).collect());
+
+                commands.add_application_command(command);
+            },
+            _ => {
+                for command in options.commands {
+                    commands.add_application_command(command.create_application_command());
+                }
+            },
+        }
+
+        for group in options.sub_groups {
+            group.create_application_commands(commands);
+        }
+
+        commands
+    }
+}
+
+fn sub_command_option(command: &Command, nested: bool) -> CreateApplicationCommandOption {
+    let mut option = CreateApplicationCommandOption::default();
+    option
+        .name(application_command_name(command.options.names[0]))
+        .description(application_command_description(command.options));
+
+    // Discord only allows sub command groups at the first level of nesting.
+    if nested && !command.options.sub_commands.is_empty() {
+        option.kind(ApplicationCommandOptionType::SubCommandGroup);
+
+        for sub_command in command.options.sub_commands {
+            option.add_sub_option(sub_command_option(sub_command, false));
+        }
+    } else {
+        option.kind(ApplicationCommandOptionType::SubCommand);
+
+        for argument in command.options.arguments {
+            option.add_sub_option(argument_option(argument));
+        }
+    }
+
+    option
+}
+
+fn argument_option(argument: &CommandArgument) -> CreateApplicationCommandOption {
+    let mut option = CreateApplicationCommandOption::default();
+    option
+        .kind(argument.kind)
+        .name(application_command_name(argument.name))
+        .description(truncate(argument.description.unwrap_or(argument.name)))
+        .required(argument.required);
+
+    option
+}
+
+fn application_command_name(name: &str) -> String {
+    name.to_lowercase().chars().take(32).collect()
+}
+
+fn application_command_description(options: &CommandOptions) -> String {
+    let description = options.desc
+        .and_then(|d| d.lines().find(|l| !l.trim().is_empty()))
+        .unwrap_or(options.names[0]);
+
+    truncate(description)
+}
+
+fn truncate(description: &str) -> String {
+    description.chars().take(100).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Command, CommandArgument, CommandOptions, CommandResult, OnlyIn};
+    use crate::client::Context;
+    use crate::framework::standard::Args;
+    use crate::model::{
+        channel::Message,
+        interactions::ApplicationCommandOptionType,
+        permissions::Permissions,
+    };
+    use futures::future::{BoxFuture, FutureExt};
+    use serde_json::json;
+
+    fn noop<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        async { Ok(()) }.boxed()
+    }
+
+    static ROLL_OPTIONS: CommandOptions = CommandOptions {
+        checks: &[],
+        bucket: None,
+        names: &["Roll", "r"],
+        desc: Some("Rolls a die.\nDefaults to six sides."),
+        delimiters: &[],
+        usage: None,
+        examples: &[],
+        min_args: None,
+        max_args: None,
+        allowed_roles: &[],
+        required_permissions: Permissions::empty(),
+        help_available: true,
+        only_in: OnlyIn::None,
+        owners_only: false,
+        owner_privilege: true,
+        sub_commands: &[],
+        arguments: &[CommandArgument {
+            name: "sides",
+            kind: ApplicationCommandOptionType::Integer,
+            description: None,
+            required: false,
+        }],
+    };
+
+    static ROLL: Command = Command {
+        fun: noop,
+        options: &ROLL_OPTIONS,
+    };
+
+    #[test]
+    fn create_application_command() {
+        let command = ROLL.create_application_command();
+
+        assert_eq!(command.0["name"], json!("roll"));
+        assert_eq!(command.0["description"], json!("Rolls a die."));
+        assert_eq!(command.0["options"], json!([{
+            "type": 4,
+            "name": "sides",
+            "description": "sides",
+            "required": false,
+        }]));
+    }
+}
@@ -1,19 +1,58 @@
 use crate::client::Context;
+use crate::model::channel::Message;
 use crate::model::id::{ChannelId, GuildId, UserId};
 use futures::future::BoxFuture;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
 
 type Check =
     for<'fut> fn(&'fut Context, Option<GuildId>, ChannelId, UserId) -> BoxFuture<'fut, bool>;
 
+pub(crate) type CooldownHook =
+    for<'fut> fn(&'fut Context, &'fut Message, Duration) -> BoxFuture<'fut, ()>;
+
+/// Who a bucket's cooldown applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LimitedFor {
+    /// Everyone shares the same cooldown.
+    Global,
+    /// Each user has their own cooldown.
+    User,
+    /// Each channel has its own cooldown.
+    Channel,
+    /// Each guild has its own cooldown, direct message channels have their
+    /// own cooldown each.
+    Guild,
+}
+
+impl LimitedFor {
+    /// The Id of the target of the cooldown an invocation counts towards.
+    pub(crate) fn target(self, msg: &Message) -> u64 {
+        match self {
+            LimitedFor::Global => 0,
+            LimitedFor::User => msg.author.id.0,
+            LimitedFor::Channel => msg.channel_id.0,
+            LimitedFor::Guild => msg.guild_id.map_or(msg.channel_id.0, |id| id.0),
+        }
+    }
+}
+
+impl Default for LimitedFor {
+    fn default() -> Self { Self::User }
+}
+
 pub(crate) struct Ratelimit {
     pub delay: Duration,
-    pub limit: Option<(Duration, u32)>,
+    pub time_span: Duration,
+    pub limit: Option<u32>,
+    pub free_uses: u32,
 }
 
 #[derive(Default)]
-pub(crate) struct MemberRatelimit {
+pub(crate) struct TargetRatelimit {
     pub last_time: Option<Instant>,
     pub set_time: Option<Instant>,
     pub tickets: u32,
@@ -21,52 +60,89 @@ pub(crate) struct MemberRatelimit {
 
 pub(crate) struct Bucket {
     pub ratelimit: Ratelimit,
-    pub users: HashMap<u64, MemberRatelimit>,
+    pub limited_for: LimitedFor,
+    pub targets: HashMap<u64, TargetRatelimit>,
     pub check: Option<Check>,
+    pub on_cooldown: Option<CooldownHook>,
 }
 
 impl Bucket {
-    pub fn take(&mut self, user_id: u64) -> Option<Duration> {
+    pub fn take(&mut self, target: u64) -> Option<Duration> {
         let now = Instant::now();
         let Self {
-            users, ratelimit, ..
+            targets, ratelimit, ..
         } = self;
-        let user = users.entry(user_id).or_default();
+        let target = targets.entry(target).or_default();
+        let has_time_span = ratelimit.time_span > Duration::default();
 
-        if let Some((timespan, limit)) = ratelimit.limit {
-            if (user.tickets + 1) > limit {
-                if let Some(res) = user
+        // Start a new time span once the previous one has passed. Without a
+        // time span, the tickets are never reset, so that free uses still
+        // apply to buckets only having a delay.
+        if target.set_time.map_or(true, |x| has_time_span && x + ratelimit.time_span <= now) {
+            target.tickets = 0;
+            target.set_time = Some(now);
+        }
+
+        if let Some(limit) = ratelimit.limit.filter(|_| has_time_span) {
+            if target.tickets >= limit {
+                return target
                     .set_time
-                    .and_then(|x| (x + timespan).checked_duration_since(now))
-                {
-                    return Some(res);
-                } else {
-                    user.tickets = 0;
-                    user.set_time = Some(now);
-                }
+                    .and_then(|x| (x + ratelimit.time_span).checked_duration_since(now));
             }
         }
 
-        if let Some(res) = user
-            .last_time
-            .and_then(|x| (x + ratelimit.delay).checked_duration_since(now))
-        {
-            return Some(res);
-        } else {
-            user.tickets += 1;
-            user.last_time = Some(now);
+        if target.tickets >= ratelimit.free_uses {
+            if let Some(res) = target
+                .last_time
+                .and_then(|x| (x + ratelimit.delay).checked_duration_since(now))
+            {
+                return Some(res);
+            }
         }
 
+        target.tickets += 1;
+        target.last_time = Some(now);
+
         None
     }
 }
 
+impl From<BucketBuilder> for Bucket {
+    fn from(builder: BucketBuilder) -> Self {
+        let BucketBuilder {
+            delay,
+            time_span,
+            limit,
+            free_uses,
+            limited_for,
+            check,
+            on_cooldown,
+        } = builder;
+
+        Bucket {
+            ratelimit: Ratelimit {
+                delay,
+                time_span,
+                limit: if limit == 0 { None } else { Some(limit) },
+                free_uses,
+            },
+            limited_for,
+            targets: HashMap::new(),
+            check,
+            on_cooldown,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct BucketBuilder {
     pub(crate) delay: Duration,
     pub(crate) time_span: Duration,
     pub(crate) limit: u32,
+    pub(crate) free_uses: u32,
+    pub(crate) limited_for: LimitedFor,
     pub(crate) check: Option<Check>,
+    pub(crate) on_cooldown: Option<CooldownHook>,
 }
 
 impl BucketBuilder {
@@ -92,7 +168,8 @@ impl BucketBuilder {
 
     /// Number of invocations allowed per [`time_span`].
     ///
-    /// Expressed in seconds.
+    /// Defaults to `0`, allowing any number of invocations. Has no effect
+    /// without a [`time_span`].
     ///
     /// [`time_span`]: #method.time_span
     #[inline]
@@ -102,6 +179,30 @@ impl BucketBuilder {
         self
     }
 
+    /// Number of invocations per [`time_span`] that are not subject to the
+    /// [`delay`], allowing a burst of invocations before the delay applies.
+    ///
+    /// Without a [`time_span`], the free uses are only granted once.
+    ///
+    /// [`delay`]: #method.delay
+    /// [`time_span`]: #method.time_span
+    #[inline]
+    pub fn free_uses(&mut self, n: u32) -> &mut Self {
+        self.free_uses = n;
+
+        self
+    }
+
+    /// Who the cooldown applies to, defaults to [`LimitedFor::User`].
+    ///
+    /// [`LimitedFor::User`]: enum.LimitedFor.html#variant.User
+    #[inline]
+    pub fn limit_for(&mut self, limited_for: LimitedFor) -> &mut Self {
+        self.limited_for = limited_for;
+
+        self
+    }
+
     /// Middleware confirming (or denying) that the bucket is eligible to apply.
     /// For instance, to limit the bucket to just one user.
     #[inline]
@@ -110,4 +211,119 @@ impl BucketBuilder {
 
         self
     }
+
+    /// Function called when an invocation is denied because of the cooldown,
+    /// receiving the time left until the command may be used again.
+    ///
+    /// The invocation still results in a [`DispatchError::Ratelimited`].
+    ///
+    /// [`DispatchError::Ratelimited`]: ../enum.DispatchError.html#variant.Ratelimited
+    #[inline]
+    pub fn on_cooldown(&mut self, f: CooldownHook) -> &mut Self {
+        self.on_cooldown = Some(f);
+
+        self
+    }
+}
+
+/// A handle to the buckets of a [`StandardFramework`], to reset cooldowns
+/// while the framework is in use.
+///
+/// Retrieved with [`StandardFramework::buckets`].
+///
+/// [`StandardFramework`]: ../struct.StandardFramework.html
+/// [`StandardFramework::buckets`]: ../struct.StandardFramework.html#method.buckets
+#[derive(Clone, Default)]
+pub struct Buckets(pub(crate) Arc<Mutex<HashMap<String, Bucket>>>);
+
+impl Buckets {
+    /// Resets the cooldowns of a bucket for everyone.
+    ///
+    /// Returns `false` if there is no bucket with the name.
+    pub async fn reset(&self, name: &str) -> bool {
+        match self.0.lock().await.get_mut(name) {
+            Some(bucket) => {
+                bucket.targets.clear();
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Resets the cooldown of a bucket for a single target, the Id of a user,
+    /// channel or guild depending on who the bucket's cooldown applies to.
+    ///
+    /// The target is ignored for [`LimitedFor::Global`] buckets.
+    ///
+    /// Returns `false` if there is no bucket with the name.
+    ///
+    /// [`LimitedFor::Global`]: enum.LimitedFor.html#variant.Global
+    pub async fn reset_target(&self, name: &str, target: impl Into<u64>) -> bool {
+        match self.0.lock().await.get_mut(name) {
+            Some(bucket) => {
+                let target = match bucket.limited_for {
+                    LimitedFor::Global => 0,
+                    _ => target.into(),
+                };
+
+                bucket.targets.remove(&target);
+
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bucket, BucketBuilder};
+
+    fn bucket(f: impl FnOnce(&mut BucketBuilder) -> &mut BucketBuilder) -> Bucket {
+        let mut builder = BucketBuilder::default();
+        f(&mut builder);
+
+        Bucket::from(builder)
+    }
+
+    #[test]
+    fn delay() {
+        let mut bucket = bucket(|b| b.delay(10));
+
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_some());
+        assert!(bucket.take(2).is_none());
+    }
+
+    #[test]
+    fn free_uses() {
+        let mut bucket = bucket(|b| b.delay(10).time_span(60).free_uses(2));
+
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_some());
+    }
+
+    #[test]
+    fn free_uses_without_time_span() {
+        let mut bucket = bucket(|b| b.delay(10).free_uses(2));
+
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_some());
+        assert!(bucket.take(2).is_none());
+    }
+
+    #[test]
+    fn limit() {
+        let mut bucket = bucket(|b| b.time_span(60).limit(2));
+
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_none());
+        assert!(bucket.take(1).is_some());
+
+        bucket.targets.clear();
+        assert!(bucket.take(1).is_none());
+    }
 }
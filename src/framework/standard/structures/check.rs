@@ -3,6 +3,7 @@ use std::fmt;
 use crate::model::channel::Message;
 use crate::client::Context;
 use crate::framework::standard::{Args, CommandOptions};
+use crate::model::{id::RoleId, permissions::Permissions};
 use futures::future::BoxFuture;
 
 /// This type describes why a check has failed and occurs on
@@ -14,8 +15,15 @@ use futures::future::BoxFuture;
 /// has failed and for the developer to log given failure (e.g. bugs or statstics)
 /// occurring in [`Check`]s.
 ///
+/// The reason is passed on to the dispatch error hook with
+/// [`DispatchError::CheckFailed`]. The [built-in checks] fail with the
+/// structured reasons, such as [`Reason::MissingPermissions`].
+///
 /// [`Check`]: struct.Check.html
 /// [`CheckResult::Failure`]: enum.CheckResult.html#variant.Failure
+/// [`DispatchError::CheckFailed`]: enum.DispatchError.html#variant.CheckFailed
+/// [`Reason::MissingPermissions`]: #variant.MissingPermissions
+/// [built-in checks]: checks/index.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Reason {
@@ -27,6 +35,38 @@ pub enum Reason {
     Log(String),
     /// Information for the user but also for logging purposes.
     UserAndLog { user: String, log: String },
+    /// The user lacks the permissions.
+    MissingPermissions(Permissions),
+    /// The user lacks the role.
+    MissingRole(RoleId),
+    /// The command may only be used in NSFW channels.
+    NsfwRequired,
+    /// The user is not the owner of the guild.
+    NotGuildOwner,
+    /// The user is not an owner of the bot.
+    NotBotOwner,
+    /// The command may only be used in guilds.
+    GuildOnly,
+}
+
+/// Renders the reason for the user, leaving out information meant for
+/// logging only.
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Unknown | Reason::Log(_) => f.write_str("Unknown reason"),
+            Reason::User(reason) => f.write_str(reason),
+            Reason::UserAndLog { user, .. } => f.write_str(user),
+            Reason::MissingPermissions(permissions) => {
+                write!(f, "Missing permissions: {}", permissions.get_permission_names().join(", "))
+            },
+            Reason::MissingRole(role_id) => write!(f, "Missing role: <@&{}>", role_id.0),
+            Reason::NsfwRequired => f.write_str("Only allowed in NSFW channels"),
+            Reason::NotGuildOwner => f.write_str("Only allowed for the owner of the guild"),
+            Reason::NotBotOwner => f.write_str("Only allowed for the owners of the bot"),
+            Reason::GuildOnly => f.write_str("Only allowed in guilds"),
+        }
+    }
 }
 
 /// Returned from [`Check`]s.
@@ -152,3 +192,58 @@ impl PartialEq for Check {
         self.name == other.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(result: CheckResult) -> Reason {
+        match result {
+            CheckResult::Failure(reason) => reason,
+            CheckResult::Success => panic!("Expected a failure"),
+        }
+    }
+
+    #[test]
+    fn displays_user_facing_reasons_only() {
+        assert_eq!(Reason::User("Try again".to_string()).to_string(), "Try again");
+        assert_eq!(Reason::Log("Request failed".to_string()).to_string(), "Unknown reason");
+        assert_eq!(Reason::Unknown.to_string(), "Unknown reason");
+
+        let both = Reason::UserAndLog {
+            user: "Try again".to_string(),
+            log: "Request failed".to_string(),
+        };
+        assert_eq!(both.to_string(), "Try again");
+    }
+
+    #[test]
+    fn displays_structured_reasons() {
+        let permissions = Reason::MissingPermissions(Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS);
+        assert_eq!(permissions.to_string(), "Missing permissions: Kick Members, Ban Members");
+
+        assert_eq!(Reason::MissingRole(RoleId(7)).to_string(), "Missing role: <@&7>");
+        assert_eq!(Reason::GuildOnly.to_string(), "Only allowed in guilds");
+    }
+
+    #[test]
+    fn maps_results_to_reasons() {
+        assert!(CheckResult::from(true).is_success());
+        assert!(match reason(false.into()) {
+            Reason::Unknown => true,
+            _ => false,
+        });
+        assert!(match reason(CheckResult::new_log("Request failed")) {
+            Reason::Log(log) => log == "Request failed",
+            _ => false,
+        });
+        assert!(match reason(CheckResult::new_user_and_log("Try again", "Request failed")) {
+            Reason::UserAndLog { user, log } => user == "Try again" && log == "Request failed",
+            _ => false,
+        });
+        assert!(match reason(Reason::NotBotOwner.into()) {
+            Reason::NotBotOwner => true,
+            _ => false,
+        });
+    }
+}
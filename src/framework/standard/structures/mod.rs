@@ -6,6 +6,7 @@ use std::{
 use crate::client::Context;
 use crate::model::{
     channel::Message,
+    interactions::ApplicationCommandOptionType,
     permissions::Permissions,
     id::UserId,
 };
@@ -13,6 +14,7 @@ use crate::utils::Colour;
 use super::Args;
 use futures::future::BoxFuture;
 
+mod application_command;
 mod check;
 pub mod buckets;
 
@@ -66,6 +68,25 @@ pub struct CommandOptions {
     pub owner_privilege: bool,
     /// Other commands belonging to this command.
     pub sub_commands: &'static [&'static Command],
+    /// The arguments of the command, exported as options when registering
+    /// the command as an application command.
+    pub arguments: &'static [CommandArgument],
+}
+
+/// An argument of a [`Command`], declared with the `#[arg]` attribute.
+///
+/// [`Command`]: struct.Command.html
+#[derive(Debug, PartialEq)]
+pub struct CommandArgument {
+    /// The name of the argument.
+    pub name: &'static str,
+    /// The type of the argument's application command option, derived from
+    /// the type the argument is parsed as.
+    pub kind: ApplicationCommandOptionType,
+    /// The description of the argument.
+    pub description: Option<&'static str>,
+    /// Whether the argument must be passed.
+    pub required: bool,
 }
 
 pub type CommandError = Box<dyn StdError + Send + Sync>;
@@ -47,11 +47,15 @@
 //! [docs]: https://discordapp.com/developers/docs/topics/gateway#sharding
 
 mod error;
+mod reconnect_policy;
+mod session;
 mod shard;
 mod ws_client_ext;
 
 pub use self::{
     error::Error as GatewayError,
+    reconnect_policy::ReconnectPolicy,
+    session::{Session, SessionStore},
     shard::Shard,
     ws_client_ext::WebSocketGatewayClientExt
 };
@@ -66,7 +70,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 #[cfg(feature = "client")]
 use crate::client::bridge::gateway::ShardClientMessage;
 
-pub type CurrentPresence = (Option<Activity>, OnlineStatus);
+pub type CurrentPresence = (Vec<Activity>, OnlineStatus);
 
 use async_tungstenite::{WebSocketStream, tokio::ConnectStream};
 
@@ -0,0 +1,207 @@
+use crate::constants::close_codes;
+use crate::internal::random;
+use std::time::Duration;
+
+/// How shards reconnect after losing their connection to the gateway.
+///
+/// Consecutive attempts to reconnect a shard are delayed by an exponential
+/// backoff, the first attempt being made right away. The attempts are counted
+/// until the shard is connected again.
+///
+/// Once a shard exceeds the maximum number of retries, or is closed with one
+/// of the fatal close codes, the client shuts down with a
+/// [`GatewayError::ReconnectFailure`].
+///
+/// # Examples
+///
+/// Give up after 10 retries, waiting at most 30 seconds between them:
+///
+/// ```rust
+/// use serenity::gateway::ReconnectPolicy;
+/// use std::time::Duration;
+///
+/// let mut policy = ReconnectPolicy::new();
+/// policy
+///     .max_retries(10)
+///     .max_backoff(Duration::from_secs(30));
+/// ```
+///
+/// [`GatewayError::ReconnectFailure`]: enum.GatewayError.html#variant.ReconnectFailure
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ReconnectPolicy {
+    /// The number of times a shard retries to reconnect before giving up.
+    ///
+    /// Defaults to `None`, retrying indefinitely.
+    pub max_retries: Option<u32>,
+    /// The delay before the first retry.
+    ///
+    /// Defaults to 1 second.
+    pub initial_backoff: Duration,
+    /// The maximum delay between retries.
+    ///
+    /// Defaults to 60 seconds.
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied by with every retry.
+    ///
+    /// Defaults to `2.0`.
+    pub multiplier: f64,
+    /// Whether delays are randomly shortened by up to half, so that shards
+    /// disconnected at once do not all reconnect at the same time.
+    ///
+    /// Defaults to `true`.
+    pub jitter: bool,
+    /// The close codes after which shards do not reconnect.
+    ///
+    /// **Note**: Shards closed due to invalid authentication or intents never
+    /// reconnect, and are reported with their own errors.
+    ///
+    /// Defaults to the codes of invalid shard data, required sharding and an
+    /// invalid API version.
+    pub fatal_close_codes: Vec<u16>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            max_retries: None,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: true,
+            fatal_close_codes: vec![
+                close_codes::INVALID_SHARD,
+                close_codes::SHARDING_REQUIRED,
+                close_codes::INVALID_API_VERSION,
+            ],
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Creates a new policy with the default settings.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of times a shard retries to reconnect before giving up.
+    ///
+    /// Refer to [`max_retries`] for more information.
+    ///
+    /// [`max_retries`]: #structfield.max_retries
+    pub fn max_retries(&mut self, retries: u32) -> &mut Self {
+        self.max_retries = Some(retries);
+
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// Refer to [`initial_backoff`] for more information.
+    ///
+    /// [`initial_backoff`]: #structfield.initial_backoff
+    pub fn initial_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.initial_backoff = backoff;
+
+        self
+    }
+
+    /// Sets the maximum delay between retries.
+    ///
+    /// Refer to [`max_backoff`] for more information.
+    ///
+    /// [`max_backoff`]: #structfield.max_backoff
+    pub fn max_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.max_backoff = backoff;
+
+        self
+    }
+
+    /// Sets the factor the delay is multiplied by with every retry.
+    ///
+    /// Refer to [`multiplier`] for more information.
+    ///
+    /// [`multiplier`]: #structfield.multiplier
+    pub fn multiplier(&mut self, multiplier: f64) -> &mut Self {
+        self.multiplier = multiplier;
+
+        self
+    }
+
+    /// Sets whether delays are randomly shortened.
+    ///
+    /// Refer to [`jitter`] for more information.
+    ///
+    /// [`jitter`]: #structfield.jitter
+    pub fn jitter(&mut self, jitter: bool) -> &mut Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Sets the close codes after which shards do not reconnect.
+    ///
+    /// Refer to [`fatal_close_codes`] for more information.
+    ///
+    /// [`fatal_close_codes`]: #structfield.fatal_close_codes
+    pub fn fatal_close_codes(&mut self, codes: Vec<u16>) -> &mut Self {
+        self.fatal_close_codes = codes;
+
+        self
+    }
+
+    /// Whether a shard gives up instead of making the given attempt, counted
+    /// from 1.
+    pub(crate) fn gives_up_at(&self, attempt: u32) -> bool {
+        // The first attempt is not a retry.
+        self.max_retries.map_or(false, |max| attempt > max + 1)
+    }
+
+    pub(crate) fn is_fatal(&self, close_code: u16) -> bool {
+        self.fatal_close_codes.contains(&close_code)
+    }
+
+    /// The delay before the given attempt, counted from 1.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        if attempt <= 1 {
+            return Duration::default();
+        }
+
+        let factor = self.multiplier.powi(attempt as i32 - 2);
+        let backoff = self.initial_backoff.as_secs_f64() * factor;
+        let mut backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        if self.jitter {
+            backoff *= 1.0 - random() / 2.0;
+        }
+
+        Duration::from_secs_f64(backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReconnectPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn backs_off_exponentially() {
+        let mut policy = ReconnectPolicy::new();
+        policy.jitter(false).max_retries(3);
+
+        assert_eq!(policy.backoff(1), Duration::default());
+        assert_eq!(policy.backoff(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff(4), Duration::from_secs(4));
+        assert_eq!(policy.backoff(20), Duration::from_secs(60));
+
+        policy.jitter(true);
+        let backoff = policy.backoff(4);
+        assert!(backoff >= Duration::from_secs(2) && backoff <= Duration::from_secs(4));
+
+        assert!(!policy.gives_up_at(4));
+        assert!(policy.gives_up_at(5));
+        assert!(policy.is_fatal(4011));
+        assert!(!policy.is_fatal(4000));
+    }
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// The session of a shard, which can be resumed with a RESUME instead of
+/// identifying anew.
+///
+/// Resuming a session replays the events missed since the sequence number,
+/// instead of receiving every guild again.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Session {
+    /// The Id of the session, received in the Ready event.
+    pub session_id: String,
+    /// The sequence number of the last event received.
+    pub seq: u64,
+    /// The total number of shards the session has been identified with.
+    ///
+    /// A session can only be resumed by a shard of the same total, it is not
+    /// resumed after resharding. Sessions saved without a total are never
+    /// resumed.
+    #[serde(default)]
+    pub shard_total: u64,
+}
+
+impl Session {
+    pub fn new(session_id: impl Into<String>, seq: u64, shard_total: u64) -> Self {
+        Session {
+            session_id: session_id.into(),
+            seq,
+            shard_total,
+        }
+    }
+}
+
+/// A store persisting the [`Session`]s of shards, so that a restarted process
+/// can resume them instead of identifying anew.
+///
+/// Sessions are saved once a shard connected, with every heartbeat
+/// acknowledgement, and when the shard is shut down. A shard only attempts to
+/// resume a loaded session when it first starts, and removes it when the
+/// session turns out to be invalid or was identified with another total
+/// number of shards.
+///
+/// **Note**: Discord invalidates the session of a shard shut down with the
+/// close code `1000`, as done by [`ShardManager::shutdown_all`]. Use another
+/// code, like `4000`, with [`ShardManager::shutdown`] for the session to stay
+/// resumable.
+///
+/// # Examples
+///
+/// Keep sessions in a file:
+///
+/// ```rust,no_run
+/// use serenity::async_trait;
+/// use serenity::gateway::{Session, SessionStore};
+/// use std::collections::HashMap;
+///
+/// struct FileStore;
+///
+/// impl FileStore {
+///     async fn read(&self) -> HashMap<u64, Session> {
+///         tokio::fs::read("sessions.json").await.ok()
+///             .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+///             .unwrap_or_default()
+///     }
+///
+///     async fn write(&self, sessions: &HashMap<u64, Session>) {
+///         if let Ok(bytes) = serde_json::to_vec(sessions) {
+///             let _ = tokio::fs::write("sessions.json", bytes).await;
+///         }
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl SessionStore for FileStore {
+///     async fn load(&self, shard_id: u64) -> Option<Session> {
+///         self.read().await.remove(&shard_id)
+///     }
+///
+///     async fn save(&self, shard_id: u64, session: Session) {
+///         let mut sessions = self.read().await;
+///         sessions.insert(shard_id, session);
+///         self.write(&sessions).await;
+///     }
+///
+///     async fn remove(&self, shard_id: u64) {
+///         let mut sessions = self.read().await;
+///         sessions.remove(&shard_id);
+///         self.write(&sessions).await;
+///     }
+/// }
+/// ```
+///
+/// [`Session`]: struct.Session.html
+/// [`ShardManager::shutdown`]: ../client/bridge/gateway/struct.ShardManager.html#method.shutdown
+/// [`ShardManager::shutdown_all`]: ../client/bridge/gateway/struct.ShardManager.html#method.shutdown_all
+#[async_trait]
+pub trait SessionStore: Send + Sync {
+    /// Loads the session of a shard, if one was saved.
+    async fn load(&self, shard_id: u64) -> Option<Session>;
+
+    /// Saves the session of a shard, replacing any previous one.
+    async fn save(&self, shard_id: u64, session: Session);
+
+    /// Removes the session of a shard, as it can no longer be resumed.
+    async fn remove(&self, shard_id: u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use serde_json::json;
+
+    #[test]
+    fn sessions_without_total_have_none() {
+        let session = serde_json::from_value::<Session>(json!({
+            "session_id": "abc",
+            "seq": 3,
+        })).unwrap();
+
+        assert_eq!(session, Session::new("abc", 3, 0));
+    }
+}
@@ -3,7 +3,7 @@ use crate::internal::prelude::*;
 use crate::model::{
     event::{Event, GatewayEvent},
     gateway::Activity,
-    id::GuildId,
+    id::{ChannelId, GuildId},
     user::OnlineStatus
 };
 use tokio::sync::Mutex;
@@ -18,6 +18,7 @@ use super::{
     ShardAction,
     GatewayError,
     ReconnectType,
+    Session,
     WsStream,
     WebSocketGatewayClientExt,
 };
@@ -33,6 +34,7 @@ use crate::internal::ws_impl::create_rustls_client;
 
 #[cfg(feature = "native_tls_backend")]
 use crate::internal::ws_impl::create_native_tls_client;
+use crate::internal::ws_impl::{recv_inflated_json, ReceiverExt, ZlibStreamInflater};
 
 /// A Shard is a higher-level handler for a websocket connection to Discord's
 /// gateway. The shard allows for sending and receiving messages over the
@@ -99,6 +101,11 @@ pub struct Shard {
     pub token: String,
     ws_url: Arc<Mutex<String>>,
     pub intents: GatewayIntents,
+    /// The inflater of the current connection, if `zlib-stream` transport
+    /// compression is used.
+    inflater: Option<ZlibStreamInflater>,
+    /// The number of times the shard resumed or reconnected.
+    reconnects: u64,
 }
 
 impl Shard {
@@ -136,11 +143,34 @@ impl Shard {
         token: &str,
         shard_info: [u64; 2],
         intents: GatewayIntents,
+    ) -> Result<Shard> {
+        Self::new_with_compression(ws_url, token, shard_info, intents, false).await
+    }
+
+    /// Instantiates a new instance of a Shard like [`new`], optionally using
+    /// `zlib-stream` transport compression.
+    ///
+    /// Transport compression compresses all payloads received over the
+    /// connection with a shared zlib context, which considerably reduces the
+    /// bandwidth used at the cost of the memory of the context.
+    ///
+    /// [`new`]: #method.new
+    pub async fn new_with_compression(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: [u64; 2],
+        intents: GatewayIntents,
+        transport_compression: bool,
     ) -> Result<Shard> {
         let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, transport_compression).await?;
+        let inflater = if transport_compression {
+            Some(ZlibStreamInflater::new())
+        } else {
+            None
+        };
 
-        let current_presence = (None, OnlineStatus::Online);
+        let current_presence = (Vec::new(), OnlineStatus::Online);
         let heartbeat_instants = (None, None);
         let heartbeat_interval = None;
         let last_heartbeat_acknowledged = true;
@@ -163,9 +193,20 @@ impl Shard {
             shard_info,
             ws_url,
             intents,
+            inflater,
+            reconnects: 0,
         })
     }
 
+    /// Receives the next payload from the gateway, if one was received in
+    /// time, decompressing it if transport compression is used.
+    pub async fn recv_json(&mut self) -> Result<Option<Value>> {
+        match self.inflater {
+            Some(ref mut inflater) => recv_inflated_json(&mut self.client, inflater).await,
+            None => self.client.recv_json().await,
+        }
+    }
+
     /// Retrieves the current presence of the shard.
     #[inline]
     pub fn current_presence(&self) -> &CurrentPresence {
@@ -205,6 +246,13 @@ impl Shard {
         self.heartbeat_instants.1.as_ref()
     }
 
+    /// Retrieves the number of times the shard resumed or reconnected to the
+    /// gateway.
+    #[inline]
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects
+    }
+
     /// Sends a heartbeat to the gateway with the current sequence.
     ///
     /// This sets the last heartbeat time to now, and
@@ -264,10 +312,25 @@ impl Shard {
         self.session_id.as_ref()
     }
 
+    /// Returns the session of the shard, which can be resumed by another
+    /// shard via [`resume_session`].
+    ///
+    /// [`resume_session`]: #method.resume_session
+    pub fn session(&self) -> Option<Session> {
+        self.session_id.as_ref().map(|session_id| Session::new(session_id.clone(), self.seq, self.shard_info[1]))
+    }
+
     #[inline]
     #[instrument(skip(self))]
     pub fn set_activity(&mut self, activity: Option<Activity>) {
-        self.current_presence.0 = activity;
+        self.current_presence.0 = activity.into_iter().collect();
+    }
+
+    /// Sets the activities of the shard, replacing any previous ones.
+    #[inline]
+    #[instrument(skip(self))]
+    pub fn set_activities(&mut self, activities: Vec<Activity>) {
+        self.current_presence.0 = activities;
     }
 
     #[inline]
@@ -478,6 +541,12 @@ impl Shard {
         }))
     }
 
+    /// Keeps track of the sequence number of a dispatch that is not
+    /// deserialized, as it was handled from its raw payload.
+    pub(crate) fn handle_raw_dispatch(&mut self, seq: u64) {
+        self.seq = seq;
+    }
+
     /// Handles an event from the gateway over the receiver, requiring the
     /// receiver to be passed if a reconnect needs to occur.
     ///
@@ -521,14 +590,14 @@ impl Shard {
                        self.shard_info,
                        interval);
 
-                if self.stage == ConnectionStage::Resuming {
-                    return Ok(None);
-                }
-
                 if interval > 0 {
                     self.heartbeat_interval = Some(interval);
                 }
 
+                if self.stage == ConnectionStage::Resuming {
+                    return Ok(None);
+                }
+
                 Ok(Some(if self.stage == ConnectionStage::Handshake {
                     ShardAction::Identify
                 } else {
@@ -679,6 +748,9 @@ impl Shard {
     /// If the `cache` feature is enabled, the cache will automatically be
     /// updated with member chunks.
     ///
+    /// If `presences` is `true`, the chunks also contain the presences of the
+    /// members, which requires the `GUILD_PRESENCES` intent.
+    ///
     /// # Examples
     ///
     /// Chunk a single guild by Id, limiting to 2000 [`Member`]s, and not
@@ -697,7 +769,7 @@ impl Shard {
     /// #
     /// use serenity::model::id::GuildId;
     ///
-    /// shard.chunk_guild(GuildId(81384788765712384), Some(2000), ChunkGuildFilter::None, None).await?;
+    /// shard.chunk_guild(GuildId(81384788765712384), Some(2000), ChunkGuildFilter::None, false, None).await?;
     /// #     Ok(())
     /// # }
     /// ```
@@ -720,7 +792,7 @@ impl Shard {
     /// #
     /// use serenity::model::id::GuildId;
     ///
-    /// shard.chunk_guild(GuildId(81384788765712384), Some(20), ChunkGuildFilter::Query("do".to_owned()), Some("request")).await?;
+    /// shard.chunk_guild(GuildId(81384788765712384), Some(20), ChunkGuildFilter::Query("do".to_owned()), false, Some("request")).await?;
     /// #     Ok(())
     /// # }
     /// ```
@@ -734,6 +806,7 @@ impl Shard {
         guild_id: GuildId,
         limit: Option<u16>,
         filter: ChunkGuildFilter,
+        presences: bool,
         nonce: Option<&str>,
     ) -> Result<()> {
         debug!("[Shard {:?}] Requesting member chunks", self.shard_info);
@@ -743,10 +816,39 @@ impl Shard {
             &self.shard_info,
             limit,
             filter,
+            presences,
             nonce,
         ).await
     }
 
+    /// Joins, moves between or leaves the voice channels of a guild, by
+    /// sending a voice state update.
+    ///
+    /// Passing `None` as the `channel_id` leaves the current voice channel.
+    ///
+    /// Discord answers with a [`VoiceStateUpdateEvent`] and, when joining, a
+    /// [`VoiceServerUpdateEvent`] containing the information needed to connect
+    /// to the voice server.
+    ///
+    /// [`VoiceServerUpdateEvent`]: ../model/event/struct.VoiceServerUpdateEvent.html
+    /// [`VoiceStateUpdateEvent`]: ../model/event/struct.VoiceStateUpdateEvent.html
+    #[instrument(skip(self))]
+    pub async fn update_voice_state(
+        &mut self,
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()> {
+        self.client.send_voice_state_update(
+            &self.shard_info,
+            guild_id,
+            channel_id,
+            self_mute,
+            self_deaf,
+        ).await
+    }
+
     /// Sets the shard as going into identifying stage, which sets:
     ///
     /// - the time that the last heartbeat sent as being now
@@ -780,9 +882,14 @@ impl Shard {
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
         let url = &self.ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, self.inflater.is_some()).await?;
         self.stage = ConnectionStage::Handshake;
 
+        // Every connection starts a new zlib context.
+        if self.inflater.is_some() {
+            self.inflater = Some(ZlibStreamInflater::new());
+        }
+
         Ok(client)
     }
 
@@ -800,6 +907,7 @@ impl Shard {
     pub async fn resume(&mut self) -> Result<()> {
         debug!("[Shard {:?}] Attempting to resume", self.shard_info);
 
+        self.reconnects += 1;
         self.client = self.initialize().await?;
         self.stage = ConnectionStage::Resuming;
 
@@ -816,11 +924,40 @@ impl Shard {
         }
     }
 
+    /// Resumes the session of a previous connection over the current one,
+    /// such as a session saved by a [`SessionStore`] before the process
+    /// restarted, instead of identifying.
+    ///
+    /// This must be done before the shard identified. If the session can no
+    /// longer be resumed, Discord invalidates it and the shard has to
+    /// re-identify.
+    ///
+    /// [`SessionStore`]: trait.SessionStore.html
+    #[instrument(skip(self))]
+    pub async fn resume_session(&mut self, session: Session) -> Result<()> {
+        debug!("[Shard {:?}] Resuming a previous session", self.shard_info);
+
+        self.client.send_resume(
+            &self.shard_info,
+            &session.session_id,
+            session.seq,
+            &self.token,
+        ).await?;
+
+        self.heartbeat_instants.0 = Some(Instant::now());
+        self.stage = ConnectionStage::Resuming;
+        self.session_id = Some(session.session_id);
+        self.seq = session.seq;
+
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     pub async fn reconnect(&mut self) -> Result<()> {
         info!("[Shard {:?}] Attempting to reconnect", self.shard_info());
 
         self.reset().await;
+        self.reconnects += 1;
         self.client = self.initialize().await?;
 
         Ok(())
@@ -836,21 +973,23 @@ impl Shard {
 }
 
 #[cfg(all(feature = "rustls_backend", not(feature = "native_tls_backend")))]
-async fn connect(base_url: &str) -> Result<WsStream> {
-    let url = build_gateway_url(base_url)?;
+async fn connect(base_url: &str, compress: bool) -> Result<WsStream> {
+    let url = build_gateway_url(base_url, compress)?;
 
     Ok(create_rustls_client(url).await?)
 }
 
 #[cfg(feature = "native_tls_backend")]
-async fn connect(base_url: &str) -> Result<WsStream> {
-    let url = build_gateway_url(base_url)?;
+async fn connect(base_url: &str, compress: bool) -> Result<WsStream> {
+    let url = build_gateway_url(base_url, compress)?;
 
     Ok(create_native_tls_client(url).await?)
 }
 
-fn build_gateway_url(base: &str) -> Result<Url> {
-    Url::parse(&format!("{}?v={}", base, constants::GATEWAY_VERSION))
+fn build_gateway_url(base: &str, compress: bool) -> Result<Url> {
+    let compression = if compress { "&compress=zlib-stream" } else { "" };
+
+    Url::parse(&format!("{}?v={}{}", base, constants::GATEWAY_VERSION, compression))
         .map_err(|why| {
             warn!("Error building gateway URL with base `{}`: {:?}", base, why);
 
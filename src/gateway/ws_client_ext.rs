@@ -3,7 +3,7 @@ use crate::constants::{self, OpCode};
 use crate::gateway::{CurrentPresence, WsStream};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
-use crate::model::id::GuildId;
+use crate::model::{gateway::Activity, id::{ChannelId, GuildId}};
 use async_trait::async_trait;
 use serde_json::json;
 use std::env::consts;
@@ -19,6 +19,7 @@ pub trait WebSocketGatewayClientExt {
         shard_info: &[u64; 2],
         limit: Option<u16>,
         filter: ChunkGuildFilter,
+        presences: bool,
         nonce: Option<&str>,
     ) -> Result<()>;
 
@@ -41,6 +42,15 @@ pub trait WebSocketGatewayClientExt {
         seq: u64,
         token: &str,
     ) -> Result<()>;
+
+    async fn send_voice_state_update(
+        &mut self,
+        shard_info: &[u64; 2],
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()>;
 }
 
 #[async_trait]
@@ -52,6 +62,7 @@ impl WebSocketGatewayClientExt for WsStream {
         shard_info: &[u64; 2],
         limit: Option<u16>,
         filter: ChunkGuildFilter,
+        presences: bool,
         nonce: Option<&str>,
     ) -> Result<()> {
         debug!("[Shard {:?}] Requesting member chunks", shard_info);
@@ -61,6 +72,7 @@ impl WebSocketGatewayClientExt for WsStream {
             "d": {
                 "guild_id": [guild_id.as_ref().0],
                 "limit": limit.unwrap_or(0),
+                "presences": presences,
                 "nonce": nonce.unwrap_or(""),
             },
         });
@@ -117,22 +129,22 @@ impl WebSocketGatewayClientExt for WsStream {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref activity, ref status) = current_presence;
+        let &(ref activities, ref status) = current_presence;
         let now = SystemTime::now();
 
         debug!("[Shard {:?}] Sending presence update", shard_info);
 
+        let activities = activities.iter().map(activity_json).collect::<Vec<_>>();
+
         self.send_json(&json!({
             "op": OpCode::StatusUpdate.num(),
             "d": {
                 "afk": false,
                 "since": now,
                 "status": status.name(),
-                "game": activity.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
+                // Older gateway versions only read the single `game`.
+                "game": activities.first(),
+                "activities": activities,
             },
         })).await
     }
@@ -156,4 +168,54 @@ impl WebSocketGatewayClientExt for WsStream {
             },
         })).await.map_err(From::from)
     }
+
+    #[instrument(skip(self))]
+    async fn send_voice_state_update(
+        &mut self,
+        shard_info: &[u64; 2],
+        guild_id: GuildId,
+        channel_id: Option<ChannelId>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()> {
+        debug!("[Shard {:?}] Sending voice state update", shard_info);
+
+        self.send_json(&json!({
+            "op": OpCode::VoiceStateUpdate.num(),
+            "d": {
+                "guild_id": guild_id.0,
+                "channel_id": channel_id.map(|id| id.0),
+                "self_mute": self_mute,
+                "self_deaf": self_deaf,
+            },
+        })).await
+    }
+}
+
+/// Builds the payload of an activity sent in a presence update, leaving out the
+/// fields only the gateway itself sends.
+fn activity_json(activity: &Activity) -> Value {
+    let mut payload = json!({
+        "name": activity.name,
+        "type": activity.kind,
+        "url": activity.url,
+    });
+
+    if let Some(state) = &activity.state {
+        payload["state"] = json!(state);
+    }
+
+    if let Some(emoji) = &activity.emoji {
+        payload["emoji"] = json!(emoji);
+    }
+
+    if let Some(timestamps) = &activity.timestamps {
+        payload["timestamps"] = json!(timestamps);
+    }
+
+    if !activity.buttons.is_empty() {
+        payload["buttons"] = json!(activity.buttons);
+    }
+
+    payload
 }
@@ -1,28 +1,32 @@
 use crate::constants;
 use reqwest::{
-    multipart::Part,
+    multipart::{Form, Part},
+    Method,
     Client,
     ClientBuilder,
     Response as ReqwestResponse,
 };
 use reqwest::{
-    header::{AUTHORIZATION, USER_AGENT, CONTENT_TYPE, HeaderValue, HeaderMap as Headers},
+    header::{AUTHORIZATION, USER_AGENT, CONTENT_TYPE, HeaderName, HeaderValue, HeaderMap as Headers},
     StatusCode,
     Url,
 };
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use super::{
-    ratelimiting::{Ratelimiter, RatelimitedRequest},
-    request::Request,
-    routing::RouteInfo,
+    ratelimiting::{RatelimitCallback, Ratelimiter, RatelimiterStats, RatelimitedRequest},
+    request::{proxied, Request},
+    routing::{Route, RouteInfo},
     typing::Typing,
     AttachmentType,
     GuildPagination,
     HttpError,
+    RetryPolicy,
+    UserPagination,
 };
 use bytes::buf::Buf;
 use serde::de::DeserializeOwned;
+use futures::stream::Stream;
 use serde_json::json;
 use tracing::{debug, trace, instrument};
 use std::{
@@ -35,10 +39,224 @@ use tokio::{
     fs::File,
 };
 
+/// A builder for [`Http`], to use a preconfigured `reqwest` client or to send
+/// requests through a proxy.
+///
+/// # Examples
+///
+/// Send requests through a proxy sharing ratelimits between processes, with a
+/// client timing out after 30 seconds:
+///
+/// ```rust,no_run
+/// use serenity::http::HttpBuilder;
+/// use std::time::Duration;
+///
+/// # fn run() -> serenity::Result<()> {
+/// let http = HttpBuilder::new("token")
+///     .client_builder(|builder| builder.timeout(Duration::from_secs(30)))
+///     .proxy("http://localhost:3000")?
+///     .build()?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Http`]: struct.Http.html
+pub struct HttpBuilder {
+    client: Option<Arc<Client>>,
+    client_builder: Option<ClientBuilder>,
+    token: String,
+    proxy: Option<Url>,
+    retry_policy: RetryPolicy,
+    ratelimit_callback: Option<Arc<dyn RatelimitCallback>>,
+    webhook: Option<(u64, String)>,
+}
+
+impl fmt::Debug for HttpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpBuilder")
+            .field("client", &self.client)
+            .field("proxy", &self.proxy)
+            .field("retry_policy", &self.retry_policy)
+            .field("webhook", &self.webhook.as_ref().map(|(id, _)| id))
+            .finish()
+    }
+}
+
+impl HttpBuilder {
+    /// Creates a builder for a bot's token. If the token is not prefixed
+    /// "Bot ", it will automatically be.
+    pub fn new(token: impl AsRef<str>) -> Self {
+        let token = token.as_ref().trim();
+
+        let token = if token.starts_with("Bot ") {
+            token.to_string()
+        } else {
+            format!("Bot {}", token)
+        };
+
+        Self {
+            client: None,
+            client_builder: None,
+            token,
+            proxy: None,
+            retry_policy: RetryPolicy::default(),
+            ratelimit_callback: None,
+            webhook: None,
+        }
+    }
+
+    /// Creates a builder for a client without a bot token, which can only use
+    /// the routes of the webhook of the given URL, such as to execute it or to
+    /// edit and delete its messages.
+    ///
+    /// Other requests fail with [`HttpError::TokenRequired`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// # async fn run() -> serenity::Result<()> {
+    /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    /// let http = HttpBuilder::webhook(url)?.build()?;
+    ///
+    /// let (webhook_id, token) = http.webhook().unwrap();
+    /// let webhook = http.get_webhook_with_token(webhook_id.0, token).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Url`] if the URL is not the URL of a webhook.
+    ///
+    /// [`Error::Url`]: ../../enum.Error.html#variant.Url
+    /// [`HttpError::TokenRequired`]: enum.HttpError.html#variant.TokenRequired
+    pub fn webhook(url: impl AsRef<str>) -> Result<Self> {
+        let url = url.as_ref();
+        let webhook = parse_webhook_url(url).ok_or_else(|| Error::Url(url.to_string()))?;
+
+        Ok(Self {
+            client: None,
+            client_builder: None,
+            token: String::new(),
+            proxy: None,
+            retry_policy: RetryPolicy::default(),
+            ratelimit_callback: None,
+            webhook: Some(webhook),
+        })
+    }
+
+    /// Sets the `reqwest` client requests are performed with.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(Arc::new(client));
+        self.client_builder = None;
+
+        self
+    }
+
+    /// Builds the `reqwest` client with the TLS backend of the library,
+    /// configured by the given function.
+    ///
+    /// An HTTP proxy, like a corporate one, can be set with
+    /// [`reqwest::ClientBuilder::proxy`].
+    ///
+    /// The client is built by [`build`], which fails if it cannot be built.
+    ///
+    /// [`build`]: #method.build
+    /// [`reqwest::ClientBuilder::proxy`]: https://docs.rs/reqwest/*/reqwest/struct.ClientBuilder.html#method.proxy
+    pub fn client_builder<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(ClientBuilder) -> ClientBuilder,
+    {
+        self.client_builder = Some(f(configure_client_backend(Client::builder())));
+        self.client = None;
+
+        self
+    }
+
+    /// Sets the base URL of a proxy the requests to Discord's API are sent to
+    /// instead, keeping their path.
+    ///
+    /// For example, with a proxy of `http://localhost:3000`, messages are
+    /// sent to `http://localhost:3000/api/v8/channels/{id}/messages`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the URL is invalid.
+    ///
+    /// [`Error::Url`]: ../enum.Error.html#variant.Url
+    pub fn proxy(mut self, proxy: impl AsRef<str>) -> Result<Self> {
+        let proxy = proxy.as_ref();
+        self.proxy = Some(Url::parse(proxy).map_err(|_| Error::Url(proxy.to_string()))?);
+
+        Ok(self)
+    }
+
+    /// Sets how requests failing transiently are retried.
+    ///
+    /// Refer to [`RetryPolicy`] for more information.
+    ///
+    /// [`RetryPolicy`]: ../retry/struct.RetryPolicy.html
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+
+        self
+    }
+
+    /// Sets the callback notified when requests hit a ratelimit.
+    ///
+    /// Refer to [`RatelimitCallback`] for more information.
+    ///
+    /// [`RatelimitCallback`]: ../ratelimiting/trait.RatelimitCallback.html
+    pub fn ratelimit_callback<C>(mut self, callback: C) -> Self
+    where
+        C: RatelimitCallback + 'static,
+    {
+        self.ratelimit_callback = Some(Arc::new(callback));
+
+        self
+    }
+
+    /// Builds the [`Http`] client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::Request`] if the `reqwest` client cannot be
+    /// built, e.g. because of an invalid configuration given to
+    /// [`client_builder`].
+    ///
+    /// [`Http`]: struct.Http.html
+    /// [`HttpError::Request`]: enum.HttpError.html#variant.Request
+    /// [`client_builder`]: #method.client_builder
+    pub fn build(self) -> Result<Http> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let builder = self.client_builder
+                    .unwrap_or_else(|| configure_client_backend(Client::builder()));
+
+                Arc::new(builder.build().map_err(HttpError::Request)?)
+            },
+        };
+
+        let mut http = Http::new(client, &self.token);
+        http.ratelimiter.proxy = self.proxy.clone();
+        http.ratelimiter.retry_policy = self.retry_policy;
+        http.ratelimiter.callback = self.ratelimit_callback;
+        http.proxy = self.proxy;
+        http.webhook = self.webhook;
+
+        Ok(http)
+    }
+}
+
 pub struct Http {
     pub(crate) client: Arc<Client>,
     pub ratelimiter: Ratelimiter,
     pub token: String,
+    proxy: Option<Url>,
+    webhook: Option<(u64, String)>,
 }
 
 impl fmt::Debug for Http {
@@ -46,6 +264,8 @@ impl fmt::Debug for Http {
         f.debug_struct("Http")
             .field("client", &self.client)
             .field("ratelimiter", &self.ratelimiter)
+            .field("proxy", &self.proxy)
+            .field("webhook", &self.webhook.as_ref().map(|(id, _)| id))
             .finish()
     }
 }
@@ -58,20 +278,60 @@ impl Http {
             client,
             ratelimiter: Ratelimiter::new(client2, token.to_string()),
             token: token.to_string(),
+            proxy: None,
+            webhook: None,
         }
     }
 
     pub fn new_with_token(token: &str) -> Self {
-        let builder = configure_client_backend(Client::builder());
-        let built = builder.build().expect("Cannot build reqwest::Client");
+        HttpBuilder::new(token).build().expect("Cannot build reqwest::Client")
+    }
 
-        let token = if token.trim().starts_with("Bot ") {
-            token.to_string()
-        } else {
-            format!("Bot {}", token)
-        };
+    /// Creates a client without a bot token from the URL of a webhook.
+    ///
+    /// Refer to [`HttpBuilder::webhook`] for more information.
+    ///
+    /// [`HttpBuilder::webhook`]: struct.HttpBuilder.html#method.webhook
+    pub fn new_with_webhook_url(url: &str) -> Result<Self> {
+        HttpBuilder::webhook(url)?.build()
+    }
+
+    /// The Id and the token of the webhook the client was created from, if it
+    /// was created with [`HttpBuilder::webhook`].
+    ///
+    /// [`HttpBuilder::webhook`]: struct.HttpBuilder.html#method.webhook
+    pub fn webhook(&self) -> Option<(WebhookId, &str)> {
+        self.webhook.as_ref().map(|(id, token)| (WebhookId(*id), token.as_str()))
+    }
+
+    /// Fails with [`HttpError::TokenRequired`] if the client has no bot token,
+    /// having been created from a webhook URL.
+    ///
+    /// [`HttpError::TokenRequired`]: enum.HttpError.html#variant.TokenRequired
+    fn require_token(&self) -> Result<()> {
+        if self.webhook.is_some() {
+            return Err(HttpError::TokenRequired.into());
+        }
+
+        Ok(())
+    }
+
+    /// The proxy requests are sent to instead of Discord, if one was set with
+    /// [`HttpBuilder::proxy`].
+    ///
+    /// [`HttpBuilder::proxy`]: struct.HttpBuilder.html#method.proxy
+    pub fn proxy(&self) -> Option<&Url> {
+        self.proxy.as_ref()
+    }
 
-        Self::new(Arc::new(built), &token)
+    /// Takes a snapshot of the ratelimits of all routes requests were made to,
+    /// and of how often requests were ratelimited, e.g. for a dashboard.
+    ///
+    /// Refer to [`RatelimiterStats`] for more information.
+    ///
+    /// [`RatelimiterStats`]: ../ratelimiting/struct.RatelimiterStats.html
+    pub async fn ratelimiter_stats(&self) -> RatelimiterStats {
+        self.ratelimiter.stats().await
     }
 
     /// Adds a single [`Role`] to a [`Member`] in a [`Guild`].
@@ -91,27 +351,57 @@ impl Http {
         }).await
     }
 
+    /// Adds a [`User`] to a thread.
+    ///
+    /// **Note**: The current user must be a member of the thread, which must
+    /// not be archived.
+    ///
+    /// [`User`]: ../../model/user/struct.User.html
+    pub async fn add_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::AddThreadMember { channel_id, user_id },
+        }).await
+    }
+
     /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
-    /// X number of days.
+    /// X number of seconds.
     ///
-    /// Passing a `delete_message_days` of `0` is equivalent to not removing any
-    /// messages. Up to `7` days' worth of messages may be deleted.
+    /// Passing a `delete_message_seconds` of `0` is equivalent to not removing
+    /// any messages. Up to `604800` seconds, or 7 days, worth of messages may
+    /// be deleted.
     ///
     /// **Note**: Requires that you have the [Ban Members] permission.
     ///
     /// [`Guild`]: ../../model/guild/struct.Guild.html
     /// [`User`]: ../../model/user/struct.User.html
     /// [Ban Members]: ../../model/permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
-    pub async fn ban_user(&self, guild_id: u64, user_id: u64, delete_message_days: u8, reason: &str) -> Result<()> {
+    pub async fn ban_user(&self, guild_id: u64, user_id: u64, delete_message_seconds: u32, reason: &str) -> Result<()> {
+        let map = json!({
+            "delete_message_seconds": delete_message_seconds,
+        });
+
         self.wind(204, Request {
-            body: None,
-            headers: None,
-            route: RouteInfo::GuildBanUser {
-                delete_message_days: Some(delete_message_days),
-                reason: Some(reason),
-                guild_id,
-                user_id,
-            },
+            body: Some(map.to_string().as_bytes()),
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::GuildBanUser { guild_id, user_id },
+        }).await
+    }
+
+    /// Bans up to 200 users from a [`Guild`] at once, deleting their messages
+    /// sent in the given number of seconds.
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    /// [Ban Members]: ../../model/permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn bulk_ban_users(&self, guild_id: u64, map: &Value, reason: &str) -> Result<BulkBanResponse> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::GuildBulkBan { guild_id },
         }).await
     }
 
@@ -132,6 +422,25 @@ impl Http {
         }).await
     }
 
+    /// Creates an [`AutoModRule`] in the guild.
+    ///
+    /// Refer to Discord's [docs] for information on what fields this requires.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../../model/guild/struct.AutoModRule.html
+    /// [docs]: https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn create_automod_rule(&self, guild_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateAutoModRule { guild_id },
+        }).await
+    }
+
     /// Creates a [`GuildChannel`] in the [`Guild`] given its Id.
     ///
     /// Refer to the Discord's [docs] for information on what fields this requires.
@@ -170,6 +479,54 @@ impl Http {
         }).await
     }
 
+    /// Sends a followup message to an [`Interaction`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-followup-message
+    pub async fn create_followup_message(&self, application_id: u64, interaction_token: &str, map: &Value) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateFollowupMessage { application_id, interaction_token },
+        }).await
+    }
+
+    /// Creates a global [`ApplicationCommand`], overwriting an existing
+    /// command with the same name.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-global-application-command
+    pub async fn create_global_application_command(&self, application_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGlobalApplicationCommand { application_id },
+        }).await
+    }
+
+    /// Overwrites all global [`ApplicationCommand`]s with the given commands.
+    ///
+    /// Commands not included in `map` are deleted.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn create_global_application_commands(&self, application_id: u64, map: &Value) -> Result<Vec<ApplicationCommand>> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGlobalApplicationCommands { application_id },
+        }).await
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
@@ -215,6 +572,59 @@ impl Http {
         }).await
     }
 
+    /// Creates an [`ApplicationCommand`] only available in the given guild,
+    /// overwriting an existing command with the same name.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-guild-application-command
+    pub async fn create_guild_application_command(&self, application_id: u64, guild_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommand { application_id, guild_id },
+        }).await
+    }
+
+    /// Overwrites all [`ApplicationCommand`]s of the given guild with the
+    /// given commands.
+    ///
+    /// Commands not included in `map` are deleted.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn create_guild_application_commands(&self, application_id: u64, guild_id: u64, map: &Value) -> Result<Vec<ApplicationCommand>> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommands { application_id, guild_id },
+        }).await
+    }
+
+    /// Creates a guild from a [`GuildTemplate`], with the bot as its owner.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: This endpoint is usually only available for user accounts.
+    /// Refer to [`create_guild`] for more information.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [`create_guild`]: #method.create_guild
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template
+    pub async fn create_guild_from_template(&self, code: &str, map: &Value) -> Result<PartialGuild> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate { code },
+        }).await
+    }
+
     /// Creates an [`Integration`] for a [`Guild`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -233,6 +643,41 @@ impl Http {
         }).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-template
+    pub async fn create_guild_template(&self, guild_id: u64, map: &Value) -> Result<GuildTemplate> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate { guild_id },
+        }).await
+    }
+
+    /// Creates the initial response to an [`Interaction`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-interaction-response
+    pub async fn create_interaction_response(&self, interaction_id: u64, interaction_token: &str, map: &Value) -> Result<()> {
+        let body = serde_json::to_vec(map)?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateInteractionResponse { interaction_id, interaction_token },
+        }).await
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -255,6 +700,28 @@ impl Http {
         }).await
     }
 
+    /// Creates an OAuth2 [`AccessToken`] from the given form, such as to
+    /// exchange an authorization code or to refresh a token.
+    ///
+    /// Refer to the [`oauth2`] module for helpers building the form.
+    ///
+    /// [`AccessToken`]: ../../model/application/struct.AccessToken.html
+    /// [`oauth2`]: ../oauth2/index.html
+    pub async fn create_oauth2_token(&self, form: &[(&str, &str)]) -> Result<AccessToken> {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(form)
+            .finish();
+
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/x-www-form-urlencoded"));
+
+        self.fire(Request {
+            body: Some(body.as_bytes()),
+            headers: Some(headers),
+            route: RouteInfo::CreateOAuth2Token,
+        }).await
+    }
+
     /// Creates a permission override for a member or a role in a channel.
     pub async fn create_permission(&self, channel_id: u64, target_id: u64, map: &Value) -> Result<()> {
         let body = serde_json::to_vec(map)?;
@@ -311,6 +778,107 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Creates a [`ScheduledEvent`] in the guild.
+    ///
+    /// Refer to Discord's [docs] for information on what fields this requires.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    /// [docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event
+    pub async fn create_scheduled_event(&self, guild_id: u64, map: &Value) -> Result<ScheduledEvent> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateScheduledEvent { guild_id },
+        }).await
+    }
+
+    /// Creates a [`StageInstance`] in a stage channel.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn create_stage_instance(&self, map: &Value) -> Result<StageInstance> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateStageInstance,
+        }).await
+    }
+
+    /// Uploads a [`Sticker`] to the guild.
+    ///
+    /// The map must contain the `name`, `description` and `tags` of the
+    /// sticker, the file must be a PNG, APNG or Lottie JSON file of at most
+    /// 500 KB.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn create_sticker<'a>(
+        &self,
+        guild_id: u64,
+        map: JsonMap,
+        file: impl Into<AttachmentType<'a>>,
+    ) -> Result<Sticker> {
+        self.require_token()?;
+
+        let uri = api!("/guilds/{}/stickers", guild_id);
+
+        let mut multipart = reqwest::multipart::Form::new()
+            .part("file", self.attachment_part(file.into()).await?);
+
+        for (k, v) in map {
+            if let Value::String(inner) = v {
+                multipart = multipart.text(k, inner);
+            }
+        }
+
+        self.send_multipart(Method::POST, uri, multipart)
+            .await?
+            .json::<Sticker>()
+            .await
+            .map_err(From::from)
+    }
+
+    /// Creates a thread in the [`GuildChannel`] given its Id, which is not
+    /// attached to a message.
+    ///
+    /// Refer to the Discord's [docs] for information on what fields this requires.
+    ///
+    /// [`GuildChannel`]: ../../model/channel/struct.GuildChannel.html
+    /// [docs]: https://discord.com/developers/docs/resources/channel#start-thread-without-message
+    pub async fn create_thread(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateThread { channel_id },
+        }).await
+    }
+
+    /// Creates a thread attached to the given message.
+    ///
+    /// Refer to the Discord's [docs] for information on what fields this requires.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/channel#start-thread-with-message
+    pub async fn create_thread_from_message(&self, channel_id: u64, message_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateThreadFromMessage { channel_id, message_id },
+        }).await
+    }
+
     /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
     /// the given data.
     ///
@@ -352,24 +920,86 @@ impl Http {
         }).await
     }
 
+    /// Publishes a message of an announcement channel to the channels
+    /// following it.
+    ///
+    /// **Note**: Requires the [Send Messages] permission for messages of the
+    /// current user, or the [Manage Messages] permission otherwise.
+    ///
+    /// [Manage Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Send Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    pub async fn crosspost_message(&self, channel_id: u64, message_id: u64) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::CrosspostMessage { channel_id, message_id },
+        }).await
+    }
+
+    /// Deletes an [`AutoModRule`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../../model/guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn delete_automod_rule(&self, guild_id: u64, rule_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
     /// Deletes a private channel or a channel in a guild.
     pub async fn delete_channel(&self, channel_id: u64) -> Result<Channel> {
+        self.delete_channel_with_reason(channel_id, "").await
+    }
+
+    /// Deletes a channel in a guild, with a reason shown in the audit log.
+    pub async fn delete_channel_with_reason(&self, channel_id: u64, reason: &str) -> Result<Channel> {
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteChannel { channel_id },
         }).await
     }
 
     /// Deletes an emoji from a server.
     pub async fn delete_emoji(&self, guild_id: u64, emoji_id: u64) -> Result<()> {
+        self.delete_emoji_with_reason(guild_id, emoji_id, "").await
+    }
+
+    /// Deletes an emoji from a server, with a reason shown in the audit log.
+    pub async fn delete_emoji_with_reason(&self, guild_id: u64, emoji_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteEmoji { guild_id, emoji_id },
         }).await
     }
 
+    /// Deletes a followup message of an [`Interaction`].
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    pub async fn delete_followup_message(&self, application_id: u64, interaction_token: &str, message_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteFollowupMessage { application_id, interaction_token, message_id },
+        }).await
+    }
+
+    /// Deletes a global [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn delete_global_application_command(&self, application_id: u64, command_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id },
+        }).await
+    }
+
     /// Deletes a guild, only if connected account owns it.
     pub async fn delete_guild(&self, guild_id: u64) -> Result<PartialGuild> {
         self.fire(Request {
@@ -379,20 +1009,56 @@ impl Http {
         }).await
     }
 
+    /// Deletes an [`ApplicationCommand`] of a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn delete_guild_application_command(&self, application_id: u64, guild_id: u64, command_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildApplicationCommand { application_id, guild_id, command_id },
+        }).await
+    }
+
     /// Removes an integration from a guild.
     pub async fn delete_guild_integration(&self, guild_id: u64, integration_id: u64) -> Result<()> {
+        self.delete_guild_integration_with_reason(guild_id, integration_id, "").await
+    }
+
+    /// Removes an integration from a guild, with a reason shown in the audit
+    /// log.
+    pub async fn delete_guild_integration_with_reason(&self, guild_id: u64, integration_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteGuildIntegration { guild_id, integration_id },
         }).await
     }
 
+    /// Deletes a [`GuildTemplate`] of a guild, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate { code, guild_id },
+        }).await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
+        self.delete_invite_with_reason(code, "").await
+    }
+
+    /// Deletes an invite by code, with a reason shown in the audit log.
+    pub async fn delete_invite_with_reason(&self, code: &str, reason: &str) -> Result<Invite> {
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteInvite { code },
         }).await
     }
@@ -400,18 +1066,28 @@ impl Http {
     /// Deletes a message if created by us or we have
     /// specific permissions.
     pub async fn delete_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+        self.delete_message_with_reason(channel_id, message_id, "").await
+    }
+
+    /// Deletes a message, with a reason shown in the audit log.
+    pub async fn delete_message_with_reason(&self, channel_id: u64, message_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteMessage { channel_id, message_id },
         }).await
     }
 
     /// Deletes a bunch of messages, only works for bots.
     pub async fn delete_messages(&self, channel_id: u64, map: &Value) -> Result<()> {
+        self.delete_messages_with_reason(channel_id, map, "").await
+    }
+
+    /// Deletes a bunch of messages, with a reason shown in the audit log.
+    pub async fn delete_messages_with_reason(&self, channel_id: u64, map: &Value, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteMessages { channel_id },
         }).await
     }
@@ -463,6 +1139,17 @@ impl Http {
         }).await
     }
 
+    /// Deletes the initial response to an [`Interaction`].
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    pub async fn delete_original_interaction_response(&self, application_id: u64, interaction_token: &str) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteOriginalInteractionResponse { application_id, interaction_token },
+        }).await
+    }
+
     /// Deletes a permission override from a role or a member in a channel.
     pub async fn delete_permission(&self, channel_id: u64, target_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -488,21 +1175,66 @@ impl Http {
         self.wind(204, Request {
             body: None,
             headers: None,
-            route: RouteInfo::DeleteReaction {
-                reaction: &reaction_type.as_data(),
-                user: &user,
-                channel_id,
-                message_id,
-            },
+            route: RouteInfo::DeleteReaction {
+                reaction: &reaction_type.as_data(),
+                user: &user,
+                channel_id,
+                message_id,
+            },
+        }).await
+    }
+
+    /// Deletes a role from a server. Can't remove the default everyone role.
+    pub async fn delete_role(&self, guild_id: u64, role_id: u64) -> Result<()> {
+        self.delete_role_with_reason(guild_id, role_id, "").await
+    }
+
+    /// Deletes a role from a server, with a reason shown in the audit log.
+    pub async fn delete_role_with_reason(&self, guild_id: u64, role_id: u64, reason: &str) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::DeleteRole { guild_id, role_id },
+        }).await
+    }
+
+    /// Deletes a [`ScheduledEvent`] from the guild.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    pub async fn delete_scheduled_event(&self, guild_id: u64, event_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteScheduledEvent { guild_id, event_id },
         }).await
     }
 
-    /// Deletes a role from a server. Can't remove the default everyone role.
-    pub async fn delete_role(&self, guild_id: u64, role_id: u64) -> Result<()> {
+    /// Deletes the [`StageInstance`] of a stage channel, ending the stage.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn delete_stage_instance(&self, channel_id: u64) -> Result<()> {
         self.wind(204, Request {
             body: None,
             headers: None,
-            route: RouteInfo::DeleteRole { guild_id, role_id },
+            route: RouteInfo::DeleteStageInstance { channel_id },
+        }).await
+    }
+
+    /// Deletes a [`Sticker`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn delete_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteSticker { guild_id, sticker_id },
         }).await
     }
 
@@ -531,9 +1263,16 @@ impl Http {
     /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
     /// [`delete_webhook_with_token`]: fn.delete_webhook_with_token.html
     pub async fn delete_webhook(&self, webhook_id: u64) -> Result<()> {
+        self.delete_webhook_with_reason(webhook_id, "").await
+    }
+
+    /// Deletes a [`Webhook`] given its Id, with a reason shown in the audit log.
+    ///
+    /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+    pub async fn delete_webhook_with_reason(&self, webhook_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteWebhook { webhook_id },
         }).await
     }
@@ -568,28 +1307,124 @@ impl Http {
         }).await
     }
 
+    /// Deletes a [`Message`] previously sent by a [`Webhook`].
+    ///
+    /// This method does _not_ require authentication.
+    ///
+    /// [`Message`]: ../../model/channel/struct.Message.html
+    /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+    pub async fn delete_webhook_message(&self, webhook_id: u64, token: &str, message_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteWebhookMessage { message_id, token, webhook_id },
+        }).await
+    }
+
+    /// Overwrites the [`ApplicationRoleConnectionMetadata`] records of the
+    /// application, at most 5.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationRoleConnectionMetadata`]: ../../model/application/struct.ApplicationRoleConnectionMetadata.html
+    /// [docs]: https://discord.com/developers/docs/resources/application-role-connection-metadata#update-application-role-connection-metadata-records
+    pub async fn edit_application_role_connection_metadata_records(
+        &self,
+        application_id: u64,
+        map: &Value,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditApplicationRoleConnectionMetadataRecords { application_id },
+        }).await
+    }
+
+    /// Edits an [`AutoModRule`] of the guild.
+    ///
+    /// Refer to Discord's [docs] for information on what fields this accepts.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../../model/guild/struct.AutoModRule.html
+    /// [docs]: https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_automod_rule(&self, guild_id: u64, rule_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
     /// Changes channel information.
     pub async fn edit_channel(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        self.edit_channel_with_reason(channel_id, map, "").await
+    }
+
+    /// Changes channel information, with a reason shown in the audit log.
+    pub async fn edit_channel_with_reason(&self, channel_id: u64, map: &JsonMap, reason: &str) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::EditChannel {channel_id },
         }).await
     }
 
     /// Changes emoji information.
     pub async fn edit_emoji(&self, guild_id: u64, emoji_id: u64, map: &Value) -> Result<Emoji> {
+        self.edit_emoji_with_reason(guild_id, emoji_id, map, "").await
+    }
+
+    /// Changes emoji information, with a reason shown in the audit log.
+    pub async fn edit_emoji_with_reason(&self, guild_id: u64, emoji_id: u64, map: &Value, reason: &str) -> Result<Emoji> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::EditEmoji { guild_id, emoji_id },
         }).await
     }
 
+    /// Edits a followup message of an [`Interaction`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-followup-message
+    pub async fn edit_followup_message(&self, application_id: u64, interaction_token: &str, message_id: u64, map: &Value) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditFollowupMessage { application_id, interaction_token, message_id },
+        }).await
+    }
+
+    /// Edits a global [`ApplicationCommand`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-global-application-command
+    pub async fn edit_global_application_command(&self, application_id: u64, command_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGlobalApplicationCommand { application_id, command_id },
+        }).await
+    }
+
     /// Changes guild information.
     pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
         let body = serde_json::to_vec(map)?;
@@ -601,6 +1436,55 @@ impl Http {
         }).await
     }
 
+    /// Edits an [`ApplicationCommand`] of a guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-guild-application-command
+    pub async fn edit_guild_application_command(&self, application_id: u64, guild_id: u64, command_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildApplicationCommand { application_id, guild_id, command_id },
+        }).await
+    }
+
+    /// Overwrites the [`ApplicationCommandPermission`] of a guild's command.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommandPermission`]: ../../model/interactions/struct.ApplicationCommandPermission.html
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#edit-application-command-permissions
+    pub async fn edit_guild_application_command_permissions(&self, application_id: u64, guild_id: u64, command_id: u64, map: &Value) -> Result<ApplicationCommandPermission> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildApplicationCommandPermissions { application_id, guild_id, command_id },
+        }).await
+    }
+
+    /// Overwrites the [`ApplicationCommandPermission`]s of several of a
+    /// guild's commands.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`ApplicationCommandPermission`]: ../../model/interactions/struct.ApplicationCommandPermission.html
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#batch-edit-application-command-permissions
+    pub async fn edit_guild_application_commands_permissions(&self, application_id: u64, guild_id: u64, map: &Value) -> Result<Vec<ApplicationCommandPermission>> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildApplicationCommandsPermissions { application_id, guild_id },
+        }).await
+    }
+
     /// Edits the positions of a guild's channels.
     pub async fn edit_guild_channel_positions(
         &self,
@@ -629,13 +1513,76 @@ impl Http {
         }).await
     }
 
+    /// Edits a [`Guild`]'s membership screening.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn edit_guild_membership_screening(&self, guild_id: u64, map: &Value) -> Result<MembershipScreening> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildMembershipScreening { guild_id },
+        }).await
+    }
+
+    /// Edits the name and description of a [`GuildTemplate`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_guild_template(&self, guild_id: u64, code: &str, map: &Value) -> Result<GuildTemplate> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildTemplate { code, guild_id },
+        }).await
+    }
+
+    /// Edits a [`Guild`]'s welcome screen.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn edit_guild_welcome_screen(&self, guild_id: u64, map: &Value) -> Result<GuildWelcomeScreen> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildWelcomeScreen { guild_id },
+        }).await
+    }
+
+    /// Edits the widget settings of a [`Guild`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_guild_widget(&self, guild_id: u64, map: &Value) -> Result<GuildWidgetSettings> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildWidget { guild_id },
+        }).await
+    }
+
     /// Does specific actions to a member.
     pub async fn edit_member(&self, guild_id: u64, user_id: u64, map: &JsonMap) -> Result<()> {
+        self.edit_member_with_reason(guild_id, user_id, map, "").await
+    }
+
+    /// Does specific actions to a member, with a reason shown in the audit log.
+    pub async fn edit_member_with_reason(&self, guild_id: u64, user_id: u64, map: &JsonMap, reason: &str) -> Result<()> {
         let body = serde_json::to_vec(map)?;
 
         self.wind(204, Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::EditMember { guild_id, user_id },
         }).await
     }
@@ -653,6 +1600,34 @@ impl Http {
         }).await
     }
 
+    /// Edits a message by Id, uploading new files as attachments.
+    ///
+    /// Existing attachments are kept only if listed in the `attachments`
+    /// array of the `map`, refer to [`EditMessage::attachment`].
+    ///
+    /// **Note**: Only the author of a message can modify it.
+    ///
+    /// [`EditMessage::attachment`]: ../../builder/struct.EditMessage.html#method.attachment
+    pub async fn edit_message_and_attachments<'a, T, It: IntoIterator<Item=T>>(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        map: JsonMap,
+        new_attachments: It,
+    ) -> Result<Message>
+        where T: Into<AttachmentType<'a>> {
+        self.require_token()?;
+
+        let uri = api!("/channels/{}/messages/{}", channel_id, message_id);
+        let form = self.attachment_form(new_attachments, map).await?;
+
+        self.send_multipart(Method::PATCH, uri, form)
+            .await?
+            .json::<Message>()
+            .await
+            .map_err(From::from)
+    }
+
     /// Edits the current user's nickname for the provided [`Guild`] via its Id.
     ///
     /// Pass `None` to reset the nickname.
@@ -669,6 +1644,22 @@ impl Http {
         }).await
     }
 
+    /// Edits the initial response to an [`Interaction`].
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-original-interaction-response
+    pub async fn edit_original_interaction_response(&self, application_id: u64, interaction_token: &str, map: &Value) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditOriginalInteractionResponse { application_id, interaction_token },
+        }).await
+    }
+
     /// Edits the current user's profile settings.
     pub async fn edit_profile(&self, map: &JsonMap) -> Result<CurrentUser> {
         let body = serde_json::to_vec(map)?;
@@ -684,10 +1675,15 @@ impl Http {
 
     /// Changes a role in a guild.
     pub async fn edit_role(&self, guild_id: u64, role_id: u64, map: &JsonMap) -> Result<Role> {
+        self.edit_role_with_reason(guild_id, role_id, map, "").await
+    }
+
+    /// Changes a role in a guild, with a reason shown in the audit log.
+    pub async fn edit_role_with_reason(&self, guild_id: u64, role_id: u64, map: &JsonMap, reason: &str) -> Result<Role> {
         let body = serde_json::to_vec(&map)?;
         let mut value = self.request(Request {
             body: Some(&body),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::EditRole { guild_id, role_id },
         }).await?.json::<Value>().await?;
 
@@ -722,6 +1718,55 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Edits a [`ScheduledEvent`] of the guild.
+    ///
+    /// Refer to Discord's [docs] for information on what fields this accepts.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    /// [docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event
+    pub async fn edit_scheduled_event(&self, guild_id: u64, event_id: u64, map: &Value) -> Result<ScheduledEvent> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditScheduledEvent { guild_id, event_id },
+        }).await
+    }
+
+    /// Edits the [`StageInstance`] of a stage channel.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn edit_stage_instance(&self, channel_id: u64, map: &Value) -> Result<StageInstance> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditStageInstance { channel_id },
+        }).await
+    }
+
+    /// Edits a [`Sticker`] of the guild.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn edit_sticker(&self, guild_id: u64, sticker_id: u64, map: &JsonMap) -> Result<Sticker> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditSticker { guild_id, sticker_id },
+        }).await
+    }
+
     /// Edits a the webhook with the given data.
     ///
     /// The Value is a map with optional values of:
@@ -760,9 +1805,16 @@ impl Http {
     /// [`create_webhook`]: fn.create_webhook.html
     /// [`edit_webhook_with_token`]: fn.edit_webhook_with_token.html
     pub async fn edit_webhook(&self, webhook_id: u64, map: &Value) -> Result<Webhook> {
+        self.edit_webhook_with_reason(webhook_id, map, "").await
+    }
+
+    /// Edits a [`Webhook`], with a reason shown in the audit log.
+    ///
+    /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+    pub async fn edit_webhook_with_reason(&self, webhook_id: u64, map: &Value, reason: &str) -> Result<Webhook> {
         self.fire(Request {
             body: Some(map.to_string().as_bytes()),
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::EditWebhook { webhook_id },
         }).await
     }
@@ -800,7 +1852,34 @@ impl Http {
         self.fire(Request {
             body: Some(&body),
             headers: None,
-            route: RouteInfo::EditWebhookWithToken { token, webhook_id },
+            route: RouteInfo::EditWebhookWithToken { token, webhook_id },
+        }).await
+    }
+
+    /// Edits a [`Message`] previously sent by a [`Webhook`].
+    ///
+    /// This method does _not_ require authentication.
+    ///
+    /// [`Message`]: ../../model/channel/struct.Message.html
+    /// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+    pub async fn edit_webhook_message(&self, webhook_id: u64, token: &str, message_id: u64, map: &Value) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditWebhookMessage { message_id, token, webhook_id },
+        }).await
+    }
+
+    /// Ends the [`Poll`] of a message sent by the current user immediately.
+    ///
+    /// [`Poll`]: ../../model/channel/struct.Poll.html
+    pub async fn end_poll(&self, channel_id: u64, message_id: u64) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::EndPoll { channel_id, message_id },
         }).await
     }
 
@@ -821,6 +1900,12 @@ impl Http {
     /// - `avatar_url`: Override the default avatar of the webhook with a URL.
     /// - `tts`: Whether this is a text-to-speech message (defaults to `false`).
     /// - `username`: Override the default username of the webhook.
+    /// - `components`: The components of the message, only for webhooks
+    ///   owned by an application.
+    /// - `flags`: The flags of the message, only `SUPPRESS_EMBEDS` can be set.
+    ///
+    /// Pass a `thread_id` to send the message to a thread of the webhook's
+    /// channel.
     ///
     /// Additionally, _at least one_ of the following must be given:
     ///
@@ -846,7 +1931,7 @@ impl Http {
     /// let value = json!({"content": "test"});
     /// let map = value.as_object().unwrap();
     ///
-    /// let message = http.execute_webhook(id, token, true, map).await?;
+    /// let message = http.execute_webhook(id, None, token, true, map).await?;
     /// #     Ok(())
     /// # }
     /// ```
@@ -857,6 +1942,7 @@ impl Http {
     pub async fn execute_webhook(
         &self,
         webhook_id: u64,
+        thread_id: Option<u64>,
         token: &str,
         wait: bool,
         map: &JsonMap
@@ -869,7 +1955,7 @@ impl Http {
         let response = self.request(Request {
             body: Some(&body),
             headers: Some(headers),
-            route: RouteInfo::ExecuteWebhook { token, wait, webhook_id },
+            route: RouteInfo::ExecuteWebhook { thread_id, token, wait, webhook_id },
         }).await?;
 
         if response.status() == StatusCode::NO_CONTENT {
@@ -883,6 +1969,35 @@ impl Http {
             .map_err(From::from)
     }
 
+    /// Executes a webhook with file(s), refer to [`execute_webhook`] for more
+    /// information.
+    ///
+    /// [`execute_webhook`]: #method.execute_webhook
+    pub async fn execute_webhook_with_files<'a, T, It: IntoIterator<Item=T>>(
+        &self,
+        webhook_id: u64,
+        thread_id: Option<u64>,
+        token: &str,
+        wait: bool,
+        files: It,
+        map: JsonMap,
+    ) -> Result<Option<Message>>
+        where T: Into<AttachmentType<'a>> {
+        let uri = Route::webhook_with_token_optioned(webhook_id, token, wait, thread_id);
+        let form = self.attachment_form(files, map).await?;
+        let response = self.send_multipart(Method::POST, uri, form).await?;
+
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+
+        response
+            .json::<Message>()
+            .await
+            .map(Some)
+            .map_err(From::from)
+    }
+
     /// Gets the active maintenances from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -904,12 +2019,166 @@ impl Http {
         }
     }
 
-    /// Gets all the users that are banned in specific guild.
-    pub async fn get_bans(&self, guild_id: u64) -> Result<Vec<Ban>> {
+    /// Gets the [`ApplicationRoleConnectionMetadata`] records of the
+    /// application.
+    ///
+    /// [`ApplicationRoleConnectionMetadata`]: ../../model/application/struct.ApplicationRoleConnectionMetadata.html
+    pub async fn get_application_role_connection_metadata_records(
+        &self,
+        application_id: u64,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetApplicationRoleConnectionMetadataRecords { application_id },
+        }).await
+    }
+
+    /// Gets an [`AutoModRule`] of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../../model/guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_automod_rule(&self, guild_id: u64, rule_id: u64) -> Result<AutoModRule> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
+    /// Gets all [`AutoModRule`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../../model/guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_automod_rules(&self, guild_id: u64) -> Result<Vec<AutoModRule>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetAutoModRules { guild_id },
+        }).await
+    }
+
+    /// Gets the users that are banned in a specific guild, ordered by their
+    /// Id.
+    ///
+    /// Up to 1000 bans are returned at once, after the first user if no
+    /// `target` is given. Use [`get_bans_iter`] to get all bans of a guild.
+    ///
+    /// [`get_bans_iter`]: #method.get_bans_iter
+    pub async fn get_bans(&self, guild_id: u64, target: Option<UserPagination>, limit: Option<u64>) -> Result<Vec<Ban>> {
+        let (after, before) = match target {
+            Some(UserPagination::After(id)) => (Some(id.0), None),
+            Some(UserPagination::Before(id)) => (None, Some(id.0)),
+            None => (None, None),
+        };
+
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetBansOptioned {
+                after,
+                before,
+                guild_id,
+                limit,
+            },
+        }).await
+    }
+
+    /// Streams over all the bans of a guild, ordered by the Id of the banned
+    /// user.
+    ///
+    /// The bans are fetched lazily, up to 1,000 at a time, as the stream is
+    /// consumed. The stream ends after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut bans = http.get_bans_iter(81384788765712384).boxed();
+    /// while let Some(ban_result) = bans.next().await {
+    ///     match ban_result {
+    ///         Ok(ban) => println!("{} is banned", ban.user.name),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_bans_iter(&self, guild_id: u64) -> impl Stream<Item = Result<Ban>> + '_ {
+        // Number of bans to fetch.
+        let grab_size: u64 = 1000;
+        let init_state = (Vec::new(), None, false);
+
+        futures::stream::unfold(init_state, move |(mut buffer, mut after, mut done)| async move {
+            if buffer.is_empty() && !done {
+                let target = after.map(UserPagination::After);
+                let bans = match self.get_bans(guild_id, target, Some(grab_size)).await {
+                    Ok(bans) => bans,
+                    Err(why) => return Some((Err(why), (buffer, after, true))),
+                };
+
+                // Bans are ordered by user Id, so the last one is the cursor
+                // of the next page. A short page is the last one.
+                done = bans.len() < grab_size as usize;
+                after = bans.last().map(|ban| ban.user.id);
+
+                // Reverse to optimize pop()
+                buffer = bans;
+                buffer.reverse();
+            }
+
+            buffer.pop().map(|ban| (Ok(ban), (buffer, after, done)))
+        })
+    }
+
+    /// Gets the archived private threads of a channel, most recently archived
+    /// first.
+    ///
+    /// `before` is an ISO8601 timestamp to only return threads archived before.
+    ///
+    /// **Note**: Requires the [Read Message History] and Manage Threads
+    /// permissions.
+    ///
+    /// [Read Message History]: ../../model/permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn get_archived_private_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetArchivedPrivateThreads { before, channel_id, limit },
+        }).await
+    }
+
+    /// Gets the archived public threads of a channel, most recently archived
+    /// first.
+    ///
+    /// `before` is an ISO8601 timestamp to only return threads archived before.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: ../../model/permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn get_archived_public_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
         self.fire(Request {
             body: None,
             headers: None,
-            route: RouteInfo::GetBans { guild_id },
+            route: RouteInfo::GetArchivedPublicThreads { before, channel_id, limit },
         }).await
     }
 
@@ -1011,6 +2280,19 @@ impl Http {
         }).await
     }
 
+    /// Gets information about the authorization of an OAuth2 access token,
+    /// such as the user who granted it and its scopes.
+    pub async fn get_current_authorization_info(&self, access_token: &str) -> Result<CurrentAuthorizationInfo> {
+        let mut headers = Headers::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_str(&format!("Bearer {}", access_token))?);
+
+        self.fire(Request {
+            body: None,
+            headers: Some(headers),
+            route: RouteInfo::GetCurrentAuthorizationInfo,
+        }).await
+    }
+
     /// Gets information about the user we're connected with.
     pub async fn get_current_user(&self) -> Result<CurrentUser> {
         self.fire(Request {
@@ -1047,6 +2329,28 @@ impl Http {
         }).await
     }
 
+    /// Gets a global [`ApplicationCommand`].
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_global_application_command(&self, application_id: u64, command_id: u64) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommand { application_id, command_id },
+        }).await
+    }
+
+    /// Gets all global [`ApplicationCommand`]s.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_global_application_commands(&self, application_id: u64) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommands { application_id },
+        }).await
+    }
+
     /// Gets guild information.
     pub async fn get_guild(&self, guild_id: u64) -> Result<PartialGuild> {
         self.fire(Request {
@@ -1056,6 +2360,60 @@ impl Http {
         }).await
     }
 
+    /// Gets all active threads of a guild, including private threads.
+    pub async fn get_guild_active_threads(&self, guild_id: u64) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildActiveThreads { guild_id },
+        }).await
+    }
+
+    /// Gets an [`ApplicationCommand`] of a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_guild_application_command(&self, application_id: u64, guild_id: u64, command_id: u64) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommand { application_id, guild_id, command_id },
+        }).await
+    }
+
+    /// Gets the [`ApplicationCommandPermission`] of a guild's command.
+    ///
+    /// [`ApplicationCommandPermission`]: ../../model/interactions/struct.ApplicationCommandPermission.html
+    pub async fn get_guild_application_command_permissions(&self, application_id: u64, guild_id: u64, command_id: u64) -> Result<ApplicationCommandPermission> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommandPermissions { application_id, guild_id, command_id },
+        }).await
+    }
+
+    /// Gets all [`ApplicationCommand`]s of a guild.
+    ///
+    /// [`ApplicationCommand`]: ../../model/interactions/struct.ApplicationCommand.html
+    pub async fn get_guild_application_commands(&self, application_id: u64, guild_id: u64) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommands { application_id, guild_id },
+        }).await
+    }
+
+    /// Gets the [`ApplicationCommandPermission`]s of all of a guild's
+    /// commands.
+    ///
+    /// [`ApplicationCommandPermission`]: ../../model/interactions/struct.ApplicationCommandPermission.html
+    pub async fn get_guild_application_commands_permissions(&self, application_id: u64, guild_id: u64) -> Result<Vec<ApplicationCommandPermission>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommandsPermissions { application_id, guild_id },
+        }).await
+    }
+
     /// Gets a guild embed information.
     pub async fn get_guild_embed(&self, guild_id: u64) -> Result<GuildEmbed> {
         self.fire(Request {
@@ -1083,23 +2441,54 @@ impl Http {
         }).await
     }
 
-    /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
+    /// Gets a [`Sticker`] of the guild.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_guild_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildSticker { guild_id, sticker_id },
+        }).await
+    }
+
+    /// Gets all [`Sticker`]s of the guild.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_guild_stickers(&self, guild_id: u64) -> Result<Vec<Sticker>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildStickers { guild_id },
+        }).await
+    }
+
+    /// Gets the [`GuildTemplate`]s of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildTemplates { guild_id },
+        }).await
+    }
 
-        self.request(Request {
+    /// Gets a guild's vanity URL code, if it has one, and how often it was
+    /// used.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<GuildVanityUrl> {
+        self.fire(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetGuildVanityUrl { guild_id },
-        })
-            .await?
-        .json::<GuildVanityUrl>()
-            .await
-            .map(|x| x.code)
-            .map_err(From::from)
+        }).await
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
@@ -1129,6 +2518,31 @@ impl Http {
         serde_json::from_value::<Vec<Member>>(value).map_err(From::from)
     }
 
+    /// Gets the membership screening of a [`Guild`].
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn get_guild_membership_screening(&self, guild_id: u64) -> Result<MembershipScreening> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildMembershipScreening { guild_id },
+        }).await
+    }
+
+    /// Gets the preview of a [`Guild`].
+    ///
+    /// **Note**: The current user need not be a member of the guild if it is
+    /// discoverable.
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn get_guild_preview(&self, guild_id: u64) -> Result<GuildPreview> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildPreview { guild_id },
+        }).await
+    }
+
     /// Gets the amount of users that can be pruned.
     pub async fn get_guild_prune_count(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
         // Note for 0.6.x: turn this into a function parameter.
@@ -1180,32 +2594,87 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
-    /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
-    ///
-    /// This method requires authentication.
+    /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
+    ///
+    /// This method requires authentication.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve all of the webhooks owned by a guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::default();
+    /// let guild_id = 81384788765712384;
+    ///
+    /// let webhooks = http.get_guild_webhooks(guild_id).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn get_guild_webhooks(&self, guild_id: u64) -> Result<Vec<Webhook>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWebhooks { guild_id },
+        }).await
+    }
+
+    /// Gets the welcome screen of a [`Guild`].
+    ///
+    /// [`Guild`]: ../../model/guild/struct.Guild.html
+    pub async fn get_guild_welcome_screen(&self, guild_id: u64) -> Result<GuildWelcomeScreen> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWelcomeScreen { guild_id },
+        }).await
+    }
+
+    /// Gets the public [`GuildWidget`] of a guild.
+    ///
+    /// **Note**: The widget must be enabled in the guild's widget settings.
+    ///
+    /// [`GuildWidget`]: ../../model/guild/struct.GuildWidget.html
+    pub async fn get_guild_widget(&self, guild_id: u64) -> Result<GuildWidget> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWidget { guild_id },
+        }).await
+    }
+
+    /// Gets the PNG image of a guild's widget, in the given style.
     ///
-    /// # Examples
+    /// Refer to [`GuildWidgetStyle`] for the available styles.
     ///
-    /// Retrieve all of the webhooks owned by a guild:
+    /// **Note**: The widget must be enabled in the guild's widget settings.
     ///
-    /// ```rust,no_run
-    /// # use serenity::http::Http;
-    /// #
-    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// #     let http = Http::default();
-    /// let guild_id = 81384788765712384;
+    /// [`GuildWidgetStyle`]: ../../model/guild/enum.GuildWidgetStyle.html
+    pub async fn get_guild_widget_image(&self, guild_id: u64, style: &str) -> Result<Vec<u8>> {
+        let response = self.request(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWidgetImage { guild_id, style },
+        }).await?;
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Gets the widget settings of a [`Guild`].
     ///
-    /// let webhooks = http.get_guild_webhooks(guild_id).await?;
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [`Guild`]: ../../model/guild/struct.Guild.html
-    pub async fn get_guild_webhooks(&self, guild_id: u64) -> Result<Vec<Webhook>> {
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn get_guild_widget_settings(&self, guild_id: u64) -> Result<GuildWidgetSettings> {
         self.fire(Request {
             body: None,
             headers: None,
-            route: RouteInfo::GetGuildWebhooks { guild_id },
+            route: RouteInfo::GetGuildWidgetSettings { guild_id },
         }).await
     }
 
@@ -1261,6 +2730,27 @@ impl Http {
         }).await
     }
 
+    /// Gets the archived private threads of a channel the current user has
+    /// joined, most recently joined first.
+    ///
+    /// `before` is a thread Id to only return threads before.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: ../../model/permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn get_joined_archived_private_threads(
+        &self,
+        channel_id: u64,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetJoinedArchivedPrivateThreads { before, channel_id, limit },
+        }).await
+    }
+
     /// Gets member of a guild.
     pub async fn get_member(&self, guild_id: u64, user_id: u64) -> Result<Member> {
         let mut value = self.request(Request {
@@ -1297,6 +2787,17 @@ impl Http {
         }).await
     }
 
+    /// Gets the initial response to an [`Interaction`].
+    ///
+    /// [`Interaction`]: ../../model/interactions/struct.Interaction.html
+    pub async fn get_original_interaction_response(&self, application_id: u64, interaction_token: &str) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetOriginalInteractionResponse { application_id, interaction_token },
+        }).await
+    }
+
     /// Gets all pins of a channel.
     pub async fn get_pins(&self, channel_id: u64) -> Result<Vec<Message>> {
         self.fire(Request {
@@ -1306,6 +2807,32 @@ impl Http {
         }).await
     }
 
+    /// Gets the users who voted for an answer of a [`Poll`], at most 100 at a
+    /// time, optionally after the given user.
+    ///
+    /// [`Poll`]: ../../model/channel/struct.Poll.html
+    pub async fn get_poll_answer_voters(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        answer_id: u64,
+        after: Option<u64>,
+        limit: Option<u8>,
+    ) -> Result<Vec<User>> {
+        #[derive(Deserialize)]
+        struct VotersResponse {
+            users: Vec<User>,
+        }
+
+        let response: VotersResponse = self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetPollAnswerVoters { after, answer_id, channel_id, limit, message_id },
+        }).await?;
+
+        Ok(response.users)
+    }
+
     /// Gets user Ids based on their reaction to a message. This endpoint is dumb.
     pub async fn get_reaction_users(
         &self,
@@ -1330,6 +2857,110 @@ impl Http {
         }).await
     }
 
+    /// Gets a [`ScheduledEvent`] of the guild, optionally with the number of
+    /// subscribed users.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    pub async fn get_scheduled_event(&self, guild_id: u64, event_id: u64, with_user_count: bool) -> Result<ScheduledEvent> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEvent { guild_id, event_id, with_user_count },
+        }).await
+    }
+
+    /// Gets the users subscribed to a [`ScheduledEvent`].
+    ///
+    /// Optionally pass a `limit` of at most 100 users and whether to include
+    /// their member data.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    pub async fn get_scheduled_event_users(
+        &self,
+        guild_id: u64,
+        event_id: u64,
+        limit: Option<u64>,
+        with_member: Option<bool>,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEventUsers { guild_id, event_id, limit, with_member },
+        }).await
+    }
+
+    /// Gets all [`ScheduledEvent`]s of the guild, optionally with the number
+    /// of subscribed users.
+    ///
+    /// [`ScheduledEvent`]: ../../model/guild/struct.ScheduledEvent.html
+    pub async fn get_scheduled_events(&self, guild_id: u64, with_user_count: bool) -> Result<Vec<ScheduledEvent>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEvents { guild_id, with_user_count },
+        }).await
+    }
+
+    /// Gets the [`StageInstance`] of a stage channel, if it is live.
+    ///
+    /// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+    pub async fn get_stage_instance(&self, channel_id: u64) -> Result<StageInstance> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetStageInstance { channel_id },
+        }).await
+    }
+
+    /// Gets a [`Sticker`] by its Id, either a standard or a guild sticker.
+    ///
+    /// [`Sticker`]: ../../model/sticker/struct.Sticker.html
+    pub async fn get_sticker(&self, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetSticker { sticker_id },
+        }).await
+    }
+
+    /// Gets the [`StickerPack`]s available to Nitro subscribers.
+    ///
+    /// [`StickerPack`]: ../../model/sticker/struct.StickerPack.html
+    pub async fn get_nitro_sticker_packs(&self) -> Result<Vec<StickerPack>> {
+        #[derive(Deserialize)]
+        struct StickerPacks {
+            sticker_packs: Vec<StickerPack>,
+        }
+
+        self.fire::<StickerPacks>(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetStickerPacks,
+        }).await.map(|packs| packs.sticker_packs)
+    }
+
+    /// Gets a [`GuildTemplate`] by its code.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    pub async fn get_template(&self, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetTemplate { code },
+        }).await
+    }
+
+    /// Gets the members of a thread.
+    ///
+    /// **Note**: Requires the `GUILD_MEMBERS` gateway intent.
+    pub async fn get_thread_channel_members(&self, channel_id: u64) -> Result<Vec<ThreadMember>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetThreadMembers { channel_id },
+        }).await
+    }
+
     /// Gets the current unresolved incidents from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -1369,6 +3000,12 @@ impl Http {
     }
 
     /// Gets a user by Id.
+    ///
+    /// Unlike users received over the gateway, this includes their profile
+    /// fields, such as their [`banner`] and [`accent_colour`].
+    ///
+    /// [`banner`]: ../../model/user/struct.User.html#structfield.banner
+    /// [`accent_colour`]: ../../model/user/struct.User.html#structfield.accent_colour
     pub async fn get_user(&self, user_id: u64) -> Result<User> {
         self.fire(Request {
             body: None,
@@ -1386,7 +3023,12 @@ impl Http {
         }).await
     }
 
-    /// Gets all voice regions.
+    /// Gets all voice regions, whose Ids can be set as the region of a voice
+    /// or stage channel.
+    ///
+    /// Refer to [`get_guild_regions`] for the regions available to a guild.
+    ///
+    /// [`get_guild_regions`]: #method.get_guild_regions
     pub async fn get_voice_regions(&self) -> Result<Vec<VoiceRegion>> {
         self.fire(Request {
             body: None,
@@ -1452,6 +3094,17 @@ impl Http {
         }).await
     }
 
+    /// Joins a thread as the current user.
+    ///
+    /// **Note**: The thread must not be archived.
+    pub async fn join_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::JoinThread { channel_id },
+        }).await
+    }
+
     /// Kicks a member from a guild.
     pub async fn kick_member(&self, guild_id: u64, user_id: u64) -> Result<()> {
         self.kick_member_with_reason(guild_id, user_id, "").await
@@ -1461,7 +3114,7 @@ impl Http {
     pub async fn kick_member_with_reason(&self, guild_id: u64, user_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::KickMember {
                 guild_id,
                 user_id,
@@ -1479,6 +3132,17 @@ impl Http {
         }).await
     }
 
+    /// Leaves a thread as the current user.
+    ///
+    /// **Note**: The thread must not be archived.
+    pub async fn leave_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::LeaveThread { channel_id },
+        }).await
+    }
+
     /// Sends file(s) to a channel.
     ///
     /// # Errors
@@ -1490,96 +3154,17 @@ impl Http {
     /// [`HttpError::UnsuccessfulRequest`]: enum.HttpError.html#variant.UnsuccessfulRequest
     pub async fn send_files<'a, T, It: IntoIterator<Item=T>>(&self, channel_id: u64, files: It, map: JsonMap) -> Result<Message>
         where T: Into<AttachmentType<'a>> {
-        let uri = api!("/channels/{}/messages", channel_id);
-        let url = match Url::parse(&uri) {
-            Ok(url) => url,
-            Err(_) => return Err(Error::Url(uri)),
-        };
+        self.require_token()?;
 
-        let mut multipart = reqwest::multipart::Form::new();
-        let mut file_num = "0".to_string();
-
-        for file in files {
-
-            match file.into() {
-                AttachmentType::Bytes{ data, filename } => {
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(data.into_owned())
-                            .file_name(filename));
-                },
-                AttachmentType::File{ file, filename } => {
-                    let mut buf = Vec::new();
-                    file.try_clone().await?.read_to_end(&mut buf).await?;
-
-                    multipart = multipart
-                        .part(file_num.to_string(),
-                            Part::stream(buf)
-                                .file_name(filename));
-                },
-                AttachmentType::Path(path) => {
-                    let filename = path
-                        .file_name()
-                        .map(|filename| filename.to_string_lossy().into_owned());
-                    let mut file = File::open(path).await?;
-                    let mut buf = vec![];
-                    file.read_to_end(&mut buf).await?;
-
-                    let part = match filename {
-                        Some(filename) => Part::bytes(buf).file_name(filename),
-                        None => Part::bytes(buf),
-                    };
-
-                    multipart = multipart.part(file_num.to_string(), part);
-                },
-                AttachmentType::Image(url) => {
-                    let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
-                    let filename = url.path_segments()
-                      .and_then(|segments| segments.last().map(ToString::to_string))
-                      .ok_or_else(|| Error::Url(url.to_string()))?;
-                    let response = self.client.get(url).send().await?;
-                    let mut bytes = response.bytes().await?;
-                    let mut picture: Vec<u8> = vec![0; bytes.len()];
-                    bytes.copy_to_slice(&mut picture[..]);
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(picture)
-                            .file_name(filename.to_string()));
-                },
-            }
-
-            unsafe {
-                let vec = file_num.as_mut_vec();
-                vec[0] += 1;
-            }
-        }
-
-        for (k, v) in map {
-            match v {
-                Value::Bool(false) => multipart = multipart.text(k.clone(), "false"),
-                Value::Bool(true) => multipart = multipart.text(k.clone(), "true"),
-                Value::Number(inner) => multipart = multipart.text(k.clone(), inner.to_string()),
-                Value::String(inner) => multipart = multipart.text(k.clone(), inner),
-                Value::Object(inner) =>multipart =  multipart.text(k.clone(), serde_json::to_string(&inner)?),
-                _ => continue,
-            };
-        }
-
-        let response = self.client
-            .post(url)
-            .header(AUTHORIZATION, HeaderValue::from_str(&self.token)?)
-            .header(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT))
-            .multipart(multipart)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(HttpError::from_response(response).await.into());
-        }
+        let uri = api!("/channels/{}/messages", channel_id);
+        let form = self.attachment_form(files, map).await?;
 
-        response
+        self.send_multipart(Method::POST, uri, form)
+            .await?
             .json::<Message>()
             .await
             .map_err(From::from)
-        }
+    }
 
     /// Sends a message to a channel.
     pub async fn send_message(&self, channel_id: u64, map: &Value) -> Result<Message> {
@@ -1603,9 +3188,14 @@ impl Http {
 
     /// Unbans a user from a guild.
     pub async fn remove_ban(&self, guild_id: u64, user_id: u64) -> Result<()> {
+        self.remove_ban_with_reason(guild_id, user_id, "").await
+    }
+
+    /// Unbans a user from a guild, with a reason shown in the audit log.
+    pub async fn remove_ban_with_reason(&self, guild_id: u64, user_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::RemoveBan { guild_id, user_id },
         }).await
     }
@@ -1627,6 +3217,21 @@ impl Http {
         }).await
     }
 
+    /// Removes a [`User`] from a thread.
+    ///
+    /// **Note**: Requires the Manage Threads permission, or the current user
+    /// must be the creator of the private thread. The thread must not be
+    /// archived.
+    ///
+    /// [`User`]: ../../model/user/struct.User.html
+    pub async fn remove_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::RemoveThreadMember { channel_id, user_id },
+        }).await
+    }
+
     /// Starts removing some members from a guild based on the last time they've been online.
     pub async fn start_guild_prune(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
         // Note for 0.6.x: turn this into a function parameter.
@@ -1698,6 +3303,20 @@ impl Http {
         Typing::start(self.clone(), channel_id)
     }
 
+    /// Updates a [`GuildTemplate`] to the current state of its guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../../model/guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate { code, guild_id },
+        }).await
+    }
+
     /// Unpins a message from a channel.
     pub async fn unpin_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -1797,6 +3416,10 @@ impl Http {
     /// [`fire`]: fn.fire.html
     #[instrument]
     pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        if req.route.requires_token() {
+            self.require_token()?;
+        }
+
         let ratelimiting_req = RatelimitedRequest::from(req);
         let response = self
             .ratelimiter
@@ -1827,6 +3450,164 @@ impl Http {
 
         Err(Error::Http(Box::new(HttpError::from_response(response).await)))
     }
+
+    /// Reads an attachment into a part of a multipart form.
+    /// Builds a multipart form of the files and the JSON payload of a
+    /// message.
+    ///
+    /// If the payload lists `attachments`, every file gets an entry in it,
+    /// keeping descriptions given for its index.
+    async fn attachment_form<'a, T, It>(&self, files: It, mut map: JsonMap) -> Result<Form>
+        where T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T> {
+        let mut multipart = Form::new();
+        let mut attachments = match map.remove("attachments") {
+            Some(Value::Array(attachments)) => Some(attachments),
+            _ => None,
+        };
+
+        for (index, file) in files.into_iter().enumerate() {
+            multipart = multipart.part(format!("files[{}]", index), self.attachment_part(file.into()).await?);
+
+            if let Some(attachments) = attachments.as_mut() {
+                let id = Value::from(index as u64);
+
+                if !attachments.iter().any(|a| a.get("id") == Some(&id)) {
+                    attachments.push(json!({ "id": id }));
+                }
+            }
+        }
+
+        if let Some(attachments) = attachments {
+            map.insert("attachments".to_string(), Value::Array(attachments));
+        }
+
+        Ok(multipart.text("payload_json", serde_json::to_string(&map)?))
+    }
+
+    /// Sends a multipart form, bypassing the ratelimiter.
+    async fn send_multipart(&self, method: Method, uri: String, form: Form) -> Result<ReqwestResponse> {
+        let uri = proxied(uri.into(), self.proxy.as_ref());
+
+        let url = match Url::parse(&uri) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::Url(uri.into_owned())),
+        };
+
+        let mut builder = self.client.request(method, url);
+
+        if !self.token.is_empty() {
+            builder = builder.header(AUTHORIZATION, HeaderValue::from_str(&self.token)?);
+        }
+
+        let response = builder
+            .header(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT))
+            .multipart(form)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::from_response(response).await.into());
+        }
+
+        Ok(response)
+    }
+
+    /// Reads the contents of an attachment, downloading it if it is an image
+    /// URL.
+    pub(crate) async fn attachment_data(&self, file: AttachmentType<'_>) -> Result<Vec<u8>> {
+        Ok(match file {
+            AttachmentType::Bytes{ data, filename: _ } => data.into_owned(),
+            AttachmentType::File{ file, filename: _ } => {
+                let mut buf = Vec::new();
+                file.try_clone().await?.read_to_end(&mut buf).await?;
+
+                buf
+            },
+            AttachmentType::Path(path) => {
+                let mut file = File::open(path).await?;
+                let mut buf = vec![];
+                file.read_to_end(&mut buf).await?;
+
+                buf
+            },
+            AttachmentType::Image(url) => {
+                let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+                let response = self.client.get(url).send().await?;
+
+                response.bytes().await?.to_vec()
+            },
+        })
+    }
+
+    async fn attachment_part(&self, file: AttachmentType<'_>) -> Result<Part> {
+        Ok(match file {
+            AttachmentType::Bytes{ data, filename } => {
+                Part::bytes(data.into_owned()).file_name(filename)
+            },
+            AttachmentType::File{ file, filename } => {
+                let mut buf = Vec::new();
+                file.try_clone().await?.read_to_end(&mut buf).await?;
+
+                Part::stream(buf).file_name(filename)
+            },
+            AttachmentType::Path(path) => {
+                let filename = path
+                    .file_name()
+                    .map(|filename| filename.to_string_lossy().into_owned());
+                let mut file = File::open(path).await?;
+                let mut buf = vec![];
+                file.read_to_end(&mut buf).await?;
+
+                match filename {
+                    Some(filename) => Part::bytes(buf).file_name(filename),
+                    None => Part::bytes(buf),
+                }
+            },
+            AttachmentType::Image(url) => {
+                let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+                let filename = url.path_segments()
+                  .and_then(|segments| segments.last().map(ToString::to_string))
+                  .ok_or_else(|| Error::Url(url.to_string()))?;
+                let response = self.client.get(url).send().await?;
+                let mut bytes = response.bytes().await?;
+                let mut picture: Vec<u8> = vec![0; bytes.len()];
+                bytes.copy_to_slice(&mut picture[..]);
+
+                Part::bytes(picture).file_name(filename)
+            },
+        })
+    }
+}
+
+/// The header giving the reason for an action, shown in the audit log.
+///
+/// No header is sent for an empty reason.
+fn audit_log_reason(reason: &str) -> Result<Option<Headers>> {
+    if reason.is_empty() {
+        return Ok(None);
+    }
+
+    // The reason must be percent-encoded, encoding spaces as `%20`.
+    let encoded = url::form_urlencoded::byte_serialize(reason.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20");
+
+    let mut headers = Headers::new();
+    headers.insert(HeaderName::from_static("x-audit-log-reason"), HeaderValue::from_str(&encoded)?);
+
+    Ok(Some(headers))
+}
+
+/// Parses the Id and the token of a webhook from its URL, such as
+/// `https://discord.com/api/webhooks/{id}/{token}`.
+fn parse_webhook_url(url: &str) -> Option<(u64, String)> {
+    let url = Url::parse(url).ok()?;
+    let mut segments = url.path_segments()?.skip_while(|segment| *segment != "webhooks").skip(1);
+
+    let id = segments.next()?.parse().ok()?;
+    let token = segments.next().filter(|token| !token.is_empty())?;
+
+    Some((id, token.to_string()))
 }
 
 #[cfg(not(feature = "native_tls_backend"))]
@@ -1853,6 +3634,34 @@ impl Default for Http {
             client,
             ratelimiter: Ratelimiter::new(client2, ""),
             token: "".to_string(),
+            proxy: None,
+            webhook: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{audit_log_reason, parse_webhook_url};
+
+    #[test]
+    fn encodes_audit_log_reason() {
+        assert!(audit_log_reason("").unwrap().is_none());
+
+        let headers = audit_log_reason("spam & ads, 100%").unwrap().unwrap();
+        assert_eq!(headers["x-audit-log-reason"], "spam%20%26%20ads%2C%20100%25");
+    }
+
+    #[test]
+    fn parses_webhook_url() {
+        let webhook = parse_webhook_url("https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpC");
+        assert_eq!(webhook, Some((245037420704169985, "ig5AO-wdVWpC".to_string())));
+
+        let webhook = parse_webhook_url("https://discordapp.com/api/v8/webhooks/245037420704169985/ig5AO-wdVWpC");
+        assert_eq!(webhook, Some((245037420704169985, "ig5AO-wdVWpC".to_string())));
+
+        assert!(parse_webhook_url("https://discord.com/api/webhooks/245037420704169985").is_none());
+        assert!(parse_webhook_url("https://discord.com/api/channels/1/messages").is_none());
+        assert!(parse_webhook_url("not a url").is_none());
+    }
+}
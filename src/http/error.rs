@@ -5,6 +5,8 @@ use reqwest::{
     StatusCode,
     Url,
 };
+use serde::de::{Deserialize, Deserializer};
+use serde_json::Value;
 use std::{
     error::Error as StdError,
     fmt::{
@@ -15,14 +17,161 @@ use std::{
 };
 use url::ParseError as UrlError;
 
+/// The body of an unsuccessful response from Discord.
 #[derive(Clone, Serialize, Deserialize, PartialEq)]
 pub struct DiscordJsonError {
+    /// The JSON error code, also available as an [`ErrorCode`] through
+    /// [`error_code`].
+    ///
+    /// [`ErrorCode`]: enum.ErrorCode.html
+    /// [`error_code`]: #method.error_code
     pub code: isize,
     pub message: String,
+    /// The errors of the individual fields of an invalid request body.
+    #[serde(default, deserialize_with = "deserialize_errors", skip_serializing)]
+    pub errors: Vec<DiscordJsonSingleError>,
     #[serde(skip)]
     non_exhaustive: (),
 }
 
+impl DiscordJsonError {
+    /// The JSON error code.
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+}
+
+/// The error of a single field of an invalid request body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiscordJsonSingleError {
+    /// The code of the error, such as `BASE_TYPE_REQUIRED`.
+    pub code: String,
+    pub message: String,
+    /// The path to the field, such as `embeds.0.title`.
+    pub path: String,
+}
+
+/// Flattens the nested errors of the fields of a request body.
+fn deserialize_errors<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<DiscordJsonSingleError>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    let mut errors = Vec::new();
+
+    collect_errors(&value, &mut Vec::new(), &mut errors);
+
+    Ok(errors)
+}
+
+fn collect_errors<'a>(value: &'a Value, path: &mut Vec<&'a str>, errors: &mut Vec<DiscordJsonSingleError>) {
+    let map = match value.as_object() {
+        Some(map) => map,
+        None => return,
+    };
+
+    for (key, value) in map {
+        if key == "_errors" {
+            for error in value.as_array().into_iter().flatten() {
+                errors.push(DiscordJsonSingleError {
+                    code: error.get("code").and_then(Value::as_str).unwrap_or_default().to_string(),
+                    message: error.get("message").and_then(Value::as_str).unwrap_or_default().to_string(),
+                    path: path.join("."),
+                });
+            }
+        } else {
+            path.push(key);
+            collect_errors(value, path, errors);
+            path.pop();
+        }
+    }
+}
+
+macro_rules! error_codes {
+    ($($(#[$attr:meta])* $variant:ident = $code:expr,)*) => {
+        /// A JSON error code of Discord, telling why a request failed.
+        ///
+        /// Refer to Discord's [documentation] for all codes.
+        ///
+        /// [documentation]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum ErrorCode {
+            $($(#[$attr])* $variant,)*
+            /// A code not known to the library.
+            Unknown(isize),
+        }
+
+        impl ErrorCode {
+            /// The number of the code.
+            pub fn num(self) -> isize {
+                match self {
+                    $(ErrorCode::$variant => $code,)*
+                    ErrorCode::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<isize> for ErrorCode {
+            fn from(code: isize) -> Self {
+                match code {
+                    $($code => ErrorCode::$variant,)*
+                    code => ErrorCode::Unknown(code),
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// A general error, such as an invalid body.
+    General = 0,
+    UnknownAccount = 10001,
+    UnknownApplication = 10002,
+    UnknownChannel = 10003,
+    UnknownGuild = 10004,
+    UnknownIntegration = 10005,
+    UnknownInvite = 10006,
+    UnknownMember = 10007,
+    UnknownMessage = 10008,
+    UnknownPermissionOverwrite = 10009,
+    UnknownProvider = 10010,
+    UnknownRole = 10011,
+    UnknownToken = 10012,
+    UnknownUser = 10013,
+    UnknownEmoji = 10014,
+    UnknownWebhook = 10015,
+    UnknownBan = 10026,
+    UnknownSticker = 10060,
+    UnknownInteraction = 10062,
+    UnknownApplicationCommand = 10063,
+    UnknownStageInstance = 10067,
+    UnknownGuildScheduledEvent = 10070,
+    BotsCannotUseEndpoint = 20001,
+    OnlyBotsCanUseEndpoint = 20002,
+    MaximumGuildsReached = 30001,
+    MaximumPinsReached = 30003,
+    MaximumRolesReached = 30005,
+    MaximumWebhooksReached = 30007,
+    MaximumReactionsReached = 30010,
+    MaximumChannelsReached = 30013,
+    Unauthorized = 40001,
+    RequestEntityTooLarge = 40005,
+    InteractionAlreadyAcknowledged = 40060,
+    MissingAccess = 50001,
+    InvalidAccountType = 50002,
+    CannotExecuteOnDmChannel = 50003,
+    CannotEditMessageByOtherUser = 50005,
+    CannotSendEmptyMessage = 50006,
+    CannotSendMessagesToUser = 50007,
+    CannotSendMessagesInVoiceChannel = 50008,
+    MissingPermissions = 50013,
+    InvalidToken = 50014,
+    MessageTooOldToBulkDelete = 50034,
+    InvalidFormBody = 50035,
+    ThreadArchived = 50083,
+    ReactionBlocked = 90001,
+    ApiOverloaded = 130000,
+}
+
 impl std::fmt::Debug for DiscordJsonError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "\"{}\"", self.message)
@@ -46,6 +195,7 @@ impl ErrorResponse {
             error: r.json().await.unwrap_or_else(|_| DiscordJsonError {
                 code: -1,
                 message: "[Serenity] Could not decode json when receiving error response from discord!".to_string(),
+                errors: Vec::new(),
                 non_exhaustive: (),
             }),
         }
@@ -70,6 +220,9 @@ pub enum Error {
     InvalidHeader(InvalidHeaderValue),
     /// Reqwest's Error contain information on why sending a request failed.
     Request(ReqwestError),
+    /// When a request requiring a bot token was made with a client created
+    /// from a webhook URL, which can only use the routes of its webhook.
+    TokenRequired,
 }
 
 impl Error {
@@ -112,6 +265,33 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Returns the JSON error code of Discord if the error is an unsuccessful
+    /// request.
+    ///
+    /// # Examples
+    ///
+    /// Ignore messages that were already deleted:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http::{ErrorCode, Http, HttpError};
+    /// use serenity::Error;
+    ///
+    /// # async fn run() -> Result<(), Error> {
+    /// # let http = Http::default();
+    /// match http.delete_message(381880193700069377, 381880193700069378).await {
+    ///     Err(Error::Http(why)) if why.error_code() == Some(ErrorCode::UnknownMessage) => {},
+    ///     other => other?,
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(res.error.error_code()),
+            _ => None,
+        }
+    }
 }
 
 impl From<ErrorResponse> for Error {
@@ -147,6 +327,7 @@ impl Display for Error {
             Error::Url(_) => f.write_str("Provided URL is incorrect."),
             Error::InvalidHeader(_) => f.write_str("Provided value is an invalid header value."),
             Error::Request(_) => f.write_str("Error while sending HTTP request."),
+            Error::TokenRequired => f.write_str("A bot token is required for this request."),
         }
     }
 }
@@ -172,6 +353,7 @@ mod test {
         let error = DiscordJsonError {
             code: 43121215,
             message: String::from("This is a Ferris error"),
+            errors: Vec::new(),
             non_exhaustive: (),
         };
 
@@ -192,4 +374,25 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn test_error_codes_and_field_errors() {
+        let body = r#"{
+            "code": 50035,
+            "message": "Invalid Form Body",
+            "errors": {
+                "embeds": {"0": {"title": {"_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long."}]}}}
+            }
+        }"#;
+        let error = serde_json::from_str::<DiscordJsonError>(body).unwrap();
+
+        assert_eq!(error.error_code(), ErrorCode::InvalidFormBody);
+        assert_eq!(error.errors, vec![DiscordJsonSingleError {
+            code: "BASE_TYPE_MAX_LENGTH".to_string(),
+            message: "Too long.".to_string(),
+            path: "embeds.0.title".to_string(),
+        }]);
+        assert_eq!(ErrorCode::from(10008), ErrorCode::UnknownMessage);
+        assert_eq!(ErrorCode::from(12345).num(), 12345);
+    }
 }
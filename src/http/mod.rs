@@ -25,14 +25,19 @@
 
 pub mod client;
 pub mod error;
+pub mod oauth2;
 pub mod ratelimiting;
 pub mod request;
+pub mod retry;
 pub mod routing;
 pub mod typing;
+#[cfg(test)]
+pub(crate) mod test_server;
 
 pub use reqwest::StatusCode;
 pub use self::client::*;
-pub use self::error::Error as HttpError;
+pub use self::error::{Error as HttpError, ErrorCode};
+pub use self::retry::RetryPolicy;
 pub use self::typing::*;
 
 use reqwest::Method;
@@ -43,7 +48,10 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
-use tokio::fs::File;
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncReadExt},
+};
 
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
@@ -154,6 +162,15 @@ impl LightMethod {
             LightMethod::Put => Method::PUT,
         }
     }
+
+    /// Whether sending a request with the method again has the same effect
+    /// as sending it once.
+    pub fn is_idempotent(self) -> bool {
+        match self {
+            LightMethod::Delete | LightMethod::Get | LightMethod::Put => true,
+            LightMethod::Patch | LightMethod::Post => false,
+        }
+    }
 }
 
 /// Enum that allows a user to pass a `Path` or a `File` type to `send_files`
@@ -170,10 +187,35 @@ pub enum AttachmentType<'a> {
     Image(&'a str),
 }
 
+impl AttachmentType<'static> {
+    /// Reads an attachment from an asynchronous stream, such as a network
+    /// response or a pipe, naming it with the given filename.
+    ///
+    /// **Note**: The whole stream is buffered in memory before the attachment
+    /// is uploaded, as a request is sent again when it is ratelimited. Avoid
+    /// this for large files on disk, and pass their [`Path`] instead.
+    ///
+    /// [`Path`]: #variant.Path
+    pub async fn from_reader_buffered<R>(mut reader: R, filename: impl ToString) -> crate::Result<Self>
+    where R: AsyncRead + Unpin {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await?;
+
+        Ok(AttachmentType::Bytes {
+            data: Cow::Owned(data),
+            filename: filename.to_string(),
+        })
+    }
+}
+
 impl<'a> From<(&'a [u8], &str)> for AttachmentType<'a> {
     fn from(params: (&'a [u8], &str)) -> AttachmentType<'a> { AttachmentType::Bytes{ data: Cow::Borrowed(params.0), filename: params.1.to_string() } }
 }
 
+impl<'a> From<(Vec<u8>, &str)> for AttachmentType<'a> {
+    fn from(params: (Vec<u8>, &str)) -> AttachmentType<'a> { AttachmentType::Bytes{ data: Cow::Owned(params.0), filename: params.1.to_string() } }
+}
+
 impl<'a> From<&'a str> for AttachmentType<'a> {
     /// Constructs an `AttachmentType` from a string.
     /// This string may refer to the path of a file on disk, or the http url to an image on the internet.
@@ -212,6 +254,19 @@ pub enum GuildPagination {
     Before(GuildId),
 }
 
+/// Representation of the method of a query to send for the [`get_bans`]
+/// function.
+///
+/// [`get_bans`]: struct.Http.html#method.get_bans
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum UserPagination {
+    /// The Id to get the users after.
+    After(UserId),
+    /// The Id to get the users before.
+    Before(UserId),
+}
+
 #[cfg(test)]
 mod test {
     use super::AttachmentType;
@@ -228,4 +283,14 @@ mod test {
             _ => false,
         });
     }
+
+    #[tokio::test]
+    async fn test_attachment_type_from_reader_buffered() {
+        let attachment = AttachmentType::from_reader_buffered(&b"meow"[..], "cat.txt").await.unwrap();
+
+        assert!(match attachment {
+            AttachmentType::Bytes { data, filename } => &*data == b"meow" && filename == "cat.txt",
+            _ => false,
+        });
+    }
 }
@@ -0,0 +1,253 @@
+//! Helpers for authorizing users with OAuth2, such as for a dashboard built
+//! alongside a bot.
+//!
+//! An application sends users to an [`AuthorizationUrl`], and receives an
+//! authorization code at its redirect URI once a user granted the requested
+//! scopes. The code is exchanged for an [`AccessToken`] with the application's
+//! [`OAuth2Credentials`], sharing the [`Http`] client of the bot.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use serenity::http::Http;
+//! use serenity::http::oauth2::{AuthorizationUrl, OAuth2Credentials};
+//! use serenity::model::application::OAuth2Scope;
+//! use serenity::model::id::ApplicationId;
+//!
+//! # async fn run() -> serenity::Result<()> {
+//! # let http = Http::default();
+//! let redirect_uri = "https://example.com/callback";
+//!
+//! let url = AuthorizationUrl::new(ApplicationId(249608697955745802))
+//!     .scopes(vec![OAuth2Scope::Identify, OAuth2Scope::Guilds])
+//!     .redirect_uri(redirect_uri)
+//!     .state("random state")
+//!     .build();
+//! println!("Authorize at {}", url);
+//!
+//! // Once the user was redirected with a code:
+//! let credentials = OAuth2Credentials::new(ApplicationId(249608697955745802), "client secret");
+//! let token = credentials.exchange_code(&http, "code", redirect_uri).await?;
+//!
+//! let info = http.get_current_authorization_info(&token.access_token).await?;
+//! println!("Authorized by {:?}", info.user.map(|user| user.name));
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`AccessToken`]: ../../model/application/struct.AccessToken.html
+//! [`AuthorizationUrl`]: struct.AuthorizationUrl.html
+//! [`Http`]: ../client/struct.Http.html
+//! [`OAuth2Credentials`]: struct.OAuth2Credentials.html
+
+use crate::internal::prelude::*;
+use crate::model::{
+    application::{AccessToken, OAuth2Scope},
+    id::{ApplicationId, GuildId},
+    permissions::Permissions,
+};
+use std::fmt;
+use super::Http;
+
+/// A builder for the URL users are sent to for authorizing an application.
+#[derive(Clone, Debug)]
+pub struct AuthorizationUrl {
+    client_id: ApplicationId,
+    scopes: Vec<OAuth2Scope>,
+    permissions: Option<Permissions>,
+    redirect_uri: Option<String>,
+    state: Option<String>,
+    guild_id: Option<GuildId>,
+    disable_guild_select: bool,
+}
+
+impl AuthorizationUrl {
+    /// Creates a builder for the URL authorizing the given application,
+    /// without any scopes.
+    pub fn new(client_id: impl Into<ApplicationId>) -> Self {
+        AuthorizationUrl {
+            client_id: client_id.into(),
+            scopes: Vec::new(),
+            permissions: None,
+            redirect_uri: None,
+            state: None,
+            guild_id: None,
+            disable_guild_select: false,
+        }
+    }
+
+    /// Sets the scopes requested from the user.
+    pub fn scopes(&mut self, scopes: Vec<OAuth2Scope>) -> &mut Self {
+        self.scopes = scopes;
+
+        self
+    }
+
+    /// Sets the permissions requested for the bot, if the [`Bot`] scope is
+    /// requested.
+    ///
+    /// [`Bot`]: ../../model/application/enum.OAuth2Scope.html#variant.Bot
+    pub fn permissions(&mut self, permissions: Permissions) -> &mut Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Sets the URI the user is redirected to with an authorization code,
+    /// which must be one of the redirect URIs of the application.
+    ///
+    /// If no redirect URI is set, no code is granted, as is the case when only
+    /// adding a bot to a guild.
+    pub fn redirect_uri(&mut self, redirect_uri: impl Into<String>) -> &mut Self {
+        self.redirect_uri = Some(redirect_uri.into());
+
+        self
+    }
+
+    /// Sets a value passed back to the redirect URI, to protect against
+    /// cross-site request forgery.
+    pub fn state(&mut self, state: impl Into<String>) -> &mut Self {
+        self.state = Some(state.into());
+
+        self
+    }
+
+    /// Preselects the guild to add the bot to.
+    pub fn guild_id(&mut self, guild_id: impl Into<GuildId>) -> &mut Self {
+        self.guild_id = Some(guild_id.into());
+
+        self
+    }
+
+    /// Sets whether the user may pick another guild than the one preselected
+    /// with [`guild_id`].
+    ///
+    /// [`guild_id`]: #method.guild_id
+    pub fn disable_guild_select(&mut self, disable: bool) -> &mut Self {
+        self.disable_guild_select = disable;
+
+        self
+    }
+
+    /// Builds the URL.
+    pub fn build(&self) -> String {
+        let scopes = self.scopes
+            .iter()
+            .map(|scope| scope.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        query.append_pair("client_id", &self.client_id.0.to_string());
+        query.append_pair("scope", &scopes);
+
+        if let Some(permissions) = self.permissions {
+            query.append_pair("permissions", &permissions.bits().to_string());
+        }
+
+        if let Some(redirect_uri) = &self.redirect_uri {
+            query.append_pair("response_type", "code");
+            query.append_pair("redirect_uri", redirect_uri);
+        }
+
+        if let Some(state) = &self.state {
+            query.append_pair("state", state);
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            query.append_pair("guild_id", &guild_id.0.to_string());
+        }
+
+        if self.disable_guild_select {
+            query.append_pair("disable_guild_select", "true");
+        }
+
+        format!("https://discord.com/api/oauth2/authorize?{}", query.finish())
+    }
+}
+
+/// The credentials of an application, to exchange authorization codes for
+/// [`AccessToken`]s and to refresh them.
+///
+/// [`AccessToken`]: ../../model/application/struct.AccessToken.html
+#[derive(Clone)]
+pub struct OAuth2Credentials {
+    client_id: ApplicationId,
+    client_secret: String,
+}
+
+impl OAuth2Credentials {
+    /// Creates the credentials of an application from its Id and its client
+    /// secret.
+    ///
+    /// **Note**: Keep the client secret private, as untrusted sources can use
+    /// it to act as the application.
+    pub fn new(client_id: impl Into<ApplicationId>, client_secret: impl Into<String>) -> Self {
+        OAuth2Credentials {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+        }
+    }
+
+    /// Exchanges the code a user was redirected with for an access token.
+    ///
+    /// The redirect URI must be the one the code was granted for.
+    pub async fn exchange_code(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<AccessToken> {
+        let client_id = self.client_id.0.to_string();
+
+        http.as_ref().create_oauth2_token(&[
+            ("client_id", &client_id),
+            ("client_secret", &self.client_secret),
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+        ]).await
+    }
+
+    /// Gets a new access token with the refresh token of an expiring one.
+    pub async fn refresh_token(&self, http: impl AsRef<Http>, refresh_token: &str) -> Result<AccessToken> {
+        let client_id = self.client_id.0.to_string();
+
+        http.as_ref().create_oauth2_token(&[
+            ("client_id", &client_id),
+            ("client_secret", &self.client_secret),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ]).await
+    }
+}
+
+impl fmt::Debug for OAuth2Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Credentials")
+            .field("client_id", &self.client_id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthorizationUrl;
+    use crate::model::{application::OAuth2Scope, permissions::Permissions};
+
+    #[test]
+    fn builds_authorization_url() {
+        let url = AuthorizationUrl::new(249608697955745802)
+            .scopes(vec![OAuth2Scope::Bot, OAuth2Scope::ApplicationsCommands])
+            .permissions(Permissions::SEND_MESSAGES)
+            .redirect_uri("https://example.com/callback")
+            .build();
+
+        assert_eq!(
+            url,
+            "https://discord.com/api/oauth2/authorize?client_id=249608697955745802\
+             &scope=bot+applications.commands&permissions=2048&response_type=code\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback",
+        );
+    }
+}
@@ -42,13 +42,16 @@
 pub use super::routing::Route;
 
 use reqwest::{Client, Response};
-use reqwest::{header::HeaderMap, StatusCode};
+use reqwest::{header::HeaderMap, StatusCode, Url};
 use crate::internal::prelude::*;
 use tokio::sync::{Mutex, RwLock};
 use std::{
     collections::HashMap,
     fmt,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     str::{
         self,
         FromStr,
@@ -58,7 +61,7 @@ use std::{
     f64,
 };
 use tokio::time::{delay_for, Duration};
-use super::{HttpError, Request};
+use super::{HttpError, Request, RetryPolicy};
 use tracing::{debug, instrument};
 
 /// Ratelimiter for requests to the Discord API.
@@ -87,6 +90,118 @@ pub struct Ratelimiter {
     // when the 'reset' passes.
     routes: Arc<RwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>>,
     token: String,
+    /// The proxy requests are sent to instead of Discord.
+    pub(super) proxy: Option<Url>,
+    /// How requests failing transiently are retried.
+    pub(super) retry_policy: RetryPolicy,
+    /// The callback notified of ratelimits being hit.
+    pub(super) callback: Option<Arc<dyn RatelimitCallback>>,
+    counters: Counters,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    requests: AtomicU64,
+    exhausted: AtomicU64,
+    ratelimited: AtomicU64,
+    global_ratelimited: AtomicU64,
+}
+
+/// A callback notified when requests hit a ratelimit, e.g. to record metrics.
+///
+/// The callback is called before the request waits for the ratelimit to
+/// reset, and should return quickly.
+///
+/// # Examples
+///
+/// Log when requests are ratelimited by Discord:
+///
+/// ```rust,no_run
+/// use serenity::http::HttpBuilder;
+/// use serenity::http::ratelimiting::{RatelimitCallback, RatelimitInfo};
+///
+/// struct Logger;
+///
+/// impl RatelimitCallback for Logger {
+///     fn ratelimited(&self, info: &RatelimitInfo) {
+///         println!("Ratelimited on {:?} for {:?}", info.route, info.timeout);
+///     }
+/// }
+///
+/// # fn run() -> serenity::Result<()> {
+/// let http = HttpBuilder::new("token").ratelimit_callback(Logger).build()?;
+/// #     Ok(())
+/// # }
+/// ```
+pub trait RatelimitCallback: Send + Sync {
+    /// Called when no requests remain in the bucket of a route, so a request
+    /// waits for the bucket to reset before being sent.
+    fn bucket_exhausted(&self, _info: &RatelimitInfo) {}
+
+    /// Called when Discord responded with a 429 for a route.
+    fn ratelimited(&self, _info: &RatelimitInfo) {}
+
+    /// Called when Discord responded with a 429 due to the global ratelimit,
+    /// blocking all requests.
+    fn global_ratelimited(&self, _info: &RatelimitInfo) {}
+}
+
+/// Information about a ratelimit hit by a request, given to a
+/// [`RatelimitCallback`].
+///
+/// [`RatelimitCallback`]: trait.RatelimitCallback.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RatelimitInfo {
+    /// The route of the request.
+    pub route: Route,
+    /// The Id of the bucket Discord assigned the route to, if known.
+    pub bucket: Option<String>,
+    /// How long the request waits for the ratelimit to reset.
+    pub timeout: Duration,
+    /// The number of requests allowed in the period of the ratelimit, if
+    /// known.
+    pub limit: Option<i64>,
+    /// Whether the ratelimit applies to all requests.
+    pub global: bool,
+}
+
+/// A snapshot of the state of the [`Ratelimiter`], retrieved with
+/// [`Http::ratelimiter_stats`].
+///
+/// The counts are totals since the ratelimiter was created.
+///
+/// [`Http::ratelimiter_stats`]: ../client/struct.Http.html#method.ratelimiter_stats
+/// [`Ratelimiter`]: struct.Ratelimiter.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RatelimiterStats {
+    /// The number of requests sent.
+    pub requests: u64,
+    /// The number of requests which waited for an exhausted bucket.
+    pub exhausted: u64,
+    /// The number of 429 responses for a route.
+    pub ratelimited: u64,
+    /// The number of 429 responses due to the global ratelimit.
+    pub global_ratelimited: u64,
+    /// The state of the bucket of each route requests were made to.
+    pub routes: HashMap<Route, RouteStats>,
+}
+
+/// The state of the bucket of a route, as part of [`RatelimiterStats`].
+///
+/// [`RatelimiterStats`]: struct.RatelimiterStats.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RouteStats {
+    /// The Id of the bucket Discord assigned the route to, if known.
+    pub bucket: Option<String>,
+    /// The total number of requests that can be made in a period of time.
+    pub limit: i64,
+    /// The number of requests remaining in the period of time.
+    pub remaining: i64,
+    /// The absolute time when the period resets.
+    pub reset: Option<SystemTime>,
 }
 
 impl fmt::Debug for Ratelimiter {
@@ -115,6 +230,10 @@ impl Ratelimiter {
             global: Default::default(),
             routes: Default::default(),
             token,
+            proxy: None,
+            retry_policy: RetryPolicy::default(),
+            callback: None,
+            counters: Counters::default(),
         }
     }
 
@@ -152,9 +271,35 @@ impl Ratelimiter {
         Arc::clone(&self.routes)
     }
 
+    /// Takes a snapshot of the ratelimits of all routes and of the counts of
+    /// requests made and ratelimited.
+    pub async fn stats(&self) -> RatelimiterStats {
+        let mut routes = HashMap::new();
+
+        for (route, ratelimit) in self.routes.read().await.iter() {
+            let ratelimit = ratelimit.lock().await;
+
+            routes.insert(*route, RouteStats {
+                bucket: ratelimit.bucket.clone(),
+                limit: ratelimit.limit,
+                remaining: ratelimit.remaining,
+                reset: ratelimit.reset,
+            });
+        }
+
+        RatelimiterStats {
+            requests: self.counters.requests.load(Ordering::Relaxed),
+            exhausted: self.counters.exhausted.load(Ordering::Relaxed),
+            ratelimited: self.counters.ratelimited.load(Ordering::Relaxed),
+            global_ratelimited: self.counters.global_ratelimited.load(Ordering::Relaxed),
+            routes,
+        }
+    }
+
     #[instrument]
     pub async fn perform(&self, req: RatelimitedRequest<'_>) -> Result<Response> {
         let RatelimitedRequest { req } = req;
+        let mut retry = 0;
 
         loop {
             // This will block if another thread hit the global ratelimit.
@@ -169,7 +314,7 @@ impl Ratelimiter {
             // amount.
             //
             // This isn't normally important, but might be for ratelimiting.
-            let (_, route, _) = req.route.deconstruct();
+            let (method, route, _) = req.route.deconstruct();
 
             // Perform pre-checking here:
             //
@@ -187,10 +332,49 @@ impl Ratelimiter {
                     .or_default()
             );
 
-            bucket.lock().await.pre_hook(&route).await;
+            {
+                let mut bucket = bucket.lock().await;
+
+                if let Some(timeout) = bucket.exhausted_for() {
+                    self.counters.exhausted.fetch_add(1, Ordering::Relaxed);
+
+                    if let Some(callback) = &self.callback {
+                        callback.bucket_exhausted(&RatelimitInfo {
+                            route,
+                            bucket: bucket.bucket.clone(),
+                            timeout,
+                            limit: Some(bucket.limit),
+                            global: false,
+                        });
+                    }
+                }
+
+                bucket.pre_hook(&route).await;
+            }
+
+            let request = req.build_with_proxy(&self.client, &self.token, self.proxy.as_ref())?.build()?;
+            let response = match self.client.execute(request).await {
+                Ok(response) => response,
+                Err(why) => {
+                    retry += 1;
+
+                    if !self.retry_policy.retries_error(method, &why, retry) {
+                        return Err(why.into());
+                    }
 
-            let request = req.build(&self.client, &self.token)?.build()?;
-            let response = self.client.execute(request).await?;
+                    let backoff = self.retry_policy.backoff(retry);
+                    debug!("Retrying request to route {:?} in {:?} after error: {:?}", route, backoff, why);
+                    delay_for(backoff).await;
+
+                    continue;
+                },
+            };
+
+            self.counters.requests.fetch_add(1, Ordering::Relaxed);
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                self.notify_ratelimited(route, &response)?;
+            }
 
             // Check if the request got ratelimited by checking for status 429,
             // and if so, sleep for the value of the header 'retry-after' -
@@ -205,9 +389,7 @@ impl Ratelimiter {
             // It _may_ be possible for the limit to be raised at any time,
             // so check if it did from the value of the 'x-ratelimit-limit'
             // header. If the limit was 5 and is now 7, add 2 to the 'remaining'
-            if route == Route::None {
-                return Ok(response);
-            } else {
+            if route != Route::None {
                 let redo = if response.headers().get("x-ratelimit-global").is_some() {
                     let _ = self.global.lock().await;
 
@@ -225,12 +407,57 @@ impl Ratelimiter {
                     bucket.lock().await.post_hook(&response, &route).await
                 };
 
-                if !redo.unwrap_or(true) {
-                    return Ok(response);
+                if redo.unwrap_or(true) {
+                    continue;
                 }
             }
+
+            // Retry transient failures, including ratelimits without a known
+            // reset time.
+            retry += 1;
+
+            if !self.retry_policy.retries_status(method, response.status(), retry) {
+                return Ok(response);
+            }
+
+            let backoff = self.retry_policy.backoff(retry);
+            debug!("Retrying request to route {:?} in {:?} after status {}", route, backoff, response.status());
+            delay_for(backoff).await;
         }
     }
+
+    fn notify_ratelimited(&self, route: Route, response: &Response) -> Result<()> {
+        let global = response.headers().get("x-ratelimit-global").is_some();
+        let counter = if global {
+            &self.counters.global_ratelimited
+        } else {
+            &self.counters.ratelimited
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        let callback = match &self.callback {
+            Some(callback) => callback,
+            None => return Ok(()),
+        };
+
+        let info = RatelimitInfo {
+            route,
+            bucket: parse_header(&response.headers(), "x-ratelimit-bucket")?,
+            timeout: parse_header::<f64>(&response.headers(), "retry-after")?
+                .map(Duration::from_secs_f64)
+                .unwrap_or_default(),
+            limit: parse_header(&response.headers(), "x-ratelimit-limit")?,
+            global,
+        };
+
+        if global {
+            callback.global_ratelimited(&info);
+        } else {
+            callback.ratelimited(&info);
+        }
+
+        Ok(())
+    }
 }
 
 /// A set of data containing information about the ratelimits for a particular
@@ -254,6 +481,8 @@ pub struct Ratelimit {
     reset: Option<SystemTime>,
     /// The total time when the interval resets.
     reset_after: Option<Duration>,
+    /// The Id of the bucket Discord assigned the route to.
+    bucket: Option<String>,
 }
 
 impl Ratelimit {
@@ -267,6 +496,16 @@ impl Ratelimit {
         self.reset_after
     }
 
+    /// How long a request has to wait for the bucket to reset, if no requests
+    /// remain in it.
+    fn exhausted_for(&self) -> Option<Duration> {
+        if self.limit() == 0 || self.remaining() != 0 {
+            return None;
+        }
+
+        self.get_delay()
+    }
+
     #[instrument]
     pub async fn pre_hook(&mut self, route: &Route) {
         if self.limit() == 0 {
@@ -317,6 +556,10 @@ impl Ratelimit {
             self.reset_after = Some(Duration::from_secs_f64(reset_after));
         }
 
+        if let Some(bucket) = parse_header(&response.headers(), "x-ratelimit-bucket")? {
+            self.bucket = Some(bucket);
+        }
+
         Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
             false
         } else if let Some(retry_after) = parse_header::<f64>(&response.headers(), "retry-after")? {
@@ -352,6 +595,12 @@ impl Ratelimit {
     pub fn reset_after(&self) -> Option<Duration> {
         self.reset_after
     }
+
+    /// The Id of the bucket Discord assigned the route to, if known.
+    #[inline]
+    pub fn bucket(&self) -> Option<&str> {
+        self.bucket.as_deref()
+    }
 }
 
 impl Default for Ratelimit {
@@ -361,6 +610,7 @@ impl Default for Ratelimit {
             remaining: i64::MAX,
             reset:  None,
             reset_after:  None,
+            bucket: None,
         }
     }
 }
@@ -410,7 +660,8 @@ mod tests {
         error::Error as StdError,
         result::Result as StdResult,
     };
-    use super::parse_header;
+    use super::{parse_header, Ratelimit};
+    use std::time::{Duration, SystemTime};
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
 
@@ -486,4 +737,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_exhausted_for() {
+        let mut ratelimit = Ratelimit::default();
+        assert!(ratelimit.exhausted_for().is_none());
+
+        ratelimit.limit = 5;
+        ratelimit.remaining = 0;
+        ratelimit.reset = Some(SystemTime::now() + Duration::from_secs(60));
+        ratelimit.reset_after = Some(Duration::from_secs(60));
+        assert!(ratelimit.exhausted_for().is_some());
+
+        ratelimit.remaining = 1;
+        assert!(ratelimit.exhausted_for().is_none());
+    }
 }
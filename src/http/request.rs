@@ -7,6 +7,7 @@ use reqwest::{
     header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT, HeaderMap as Headers, HeaderValue},
     Url,
 };
+use std::borrow::Cow;
 use tracing::instrument;
 use super::{
     HttpError,
@@ -67,6 +68,15 @@ impl<'a> Request<'a> {
 
     #[instrument(skip(token))]
     pub fn build(&'a self, client: &Client, token: &str) -> Result<ReqwestRequestBuilder, HttpError> {
+        self.build_with_proxy(client, token, None)
+    }
+
+    /// Builds the request with a client like [`build`], sending it to the
+    /// given proxy rather than to Discord if one is set.
+    ///
+    /// [`build`]: #method.build
+    #[instrument(skip(token))]
+    pub fn build_with_proxy(&'a self, client: &Client, token: &str, proxy: Option<&Url>) -> Result<ReqwestRequestBuilder, HttpError> {
         let Request {
             body,
             headers: ref request_headers,
@@ -74,6 +84,7 @@ impl<'a> Request<'a> {
         } = *self;
 
         let (method, _, path) = route_info.deconstruct();
+        let path = proxied(path, proxy);
 
         let mut builder = client.request(
             method.reqwest_method(),
@@ -86,8 +97,13 @@ impl<'a> Request<'a> {
 
         let mut headers = Headers::with_capacity(4);
         headers.insert(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT));
-        headers.insert(AUTHORIZATION,
-            HeaderValue::from_str(&token).map_err(HttpError::InvalidHeader)?);
+
+        // Clients created from a webhook URL have no token, the routes of the
+        // webhook being authorized by the token in their path.
+        if !token.is_empty() {
+            headers.insert(AUTHORIZATION,
+                HeaderValue::from_str(&token).map_err(HttpError::InvalidHeader)?);
+        }
 
         // Discord will return a 400: Bad Request response if we set the content type header,
         // but don't give a body.
@@ -129,3 +145,38 @@ impl<'a> Request<'a> {
         &mut self.route
     }
 }
+
+/// Points a URL of Discord's API to a proxy, keeping its path.
+pub(super) fn proxied<'a>(url: Cow<'a, str>, proxy: Option<&Url>) -> Cow<'a, str> {
+    let proxy = match proxy {
+        Some(proxy) => proxy,
+        None => return url,
+    };
+
+    const API_HOST: &str = "https://discord.com/";
+
+    if url.starts_with(API_HOST) {
+        let path = &url[API_HOST.len()..];
+
+        Cow::Owned(format!("{}/{}", proxy.as_str().trim_end_matches('/'), path))
+    } else {
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::proxied;
+    use reqwest::Url;
+    use std::borrow::Cow;
+
+    #[test]
+    fn proxies_api_urls() {
+        let proxy = Url::parse("http://localhost:3000").unwrap();
+        let url = Cow::from("https://discord.com/api/v8/channels/1");
+
+        assert_eq!(proxied(url.clone(), None), url);
+        assert_eq!(proxied(url, Some(&proxy)), "http://localhost:3000/api/v8/channels/1");
+        assert_eq!(proxied(Cow::from("https://cdn.example/a.png"), Some(&proxy)), "https://cdn.example/a.png");
+    }
+}
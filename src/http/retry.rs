@@ -0,0 +1,264 @@
+use crate::internal::random;
+use reqwest::{Error as ReqwestError, StatusCode};
+use super::LightMethod;
+use std::time::Duration;
+
+/// How requests are retried after failing transiently, such as when Discord's
+/// API is briefly unavailable.
+///
+/// Retries are delayed by an exponential backoff. Requests which are
+/// ratelimited with a known reset time are always retried after it, without
+/// counting as retries.
+///
+/// Only requests with idempotent methods, such as `GET`, `PUT` and `DELETE`,
+/// are retried by default. Other requests, such as sending a message, may
+/// have been applied by Discord despite failing, so retrying them could
+/// repeat their action. They are only retried when they were not sent, or
+/// were ratelimited, unless [`retry_non_idempotent`] is enabled.
+///
+/// **Note**: Requests uploading files are not retried.
+///
+/// # Examples
+///
+/// Retry up to 5 times, but only when Discord is unavailable:
+///
+/// ```rust,no_run
+/// use serenity::http::{HttpBuilder, RetryPolicy};
+///
+/// # fn run() -> serenity::Result<()> {
+/// let mut policy = RetryPolicy::new();
+/// policy
+///     .max_retries(5)
+///     .statuses(vec![502, 503, 504])
+///     .retry_connection_errors(false);
+///
+/// let http = HttpBuilder::new("token").retry_policy(policy).build()?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`retry_non_idempotent`]: #structfield.retry_non_idempotent
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// The number of times a request is retried before its failure is
+    /// returned.
+    ///
+    /// Defaults to `3`.
+    pub max_retries: u32,
+    /// The status codes of responses that are retried.
+    ///
+    /// Defaults to `429`, `500`, `502`, `503` and `504`.
+    pub statuses: Vec<u16>,
+    /// Whether requests failing to connect or to receive a response, for
+    /// example due to a connection reset, are retried.
+    ///
+    /// Defaults to `true`.
+    pub retry_connection_errors: bool,
+    /// Whether requests with non-idempotent methods, such as `POST` and
+    /// `PATCH`, are retried the same as other requests after timing out or
+    /// receiving a retried status.
+    ///
+    /// **Note**: Enabling this may duplicate actions, such as sending a
+    /// message twice, if Discord applied a request that appeared to fail.
+    ///
+    /// Defaults to `false`.
+    pub retry_non_idempotent: bool,
+    /// The delay before the first retry.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub initial_backoff: Duration,
+    /// The maximum delay between retries.
+    ///
+    /// Defaults to 10 seconds.
+    pub max_backoff: Duration,
+    /// Whether delays are randomly shortened by up to half.
+    ///
+    /// Defaults to `true`.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            statuses: vec![429, 500, 502, 503, 504],
+            retry_connection_errors: true,
+            retry_non_idempotent: false,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new policy with the default settings.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy never retrying requests.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the number of times a request is retried.
+    ///
+    /// Refer to [`max_retries`] for more information.
+    ///
+    /// [`max_retries`]: #structfield.max_retries
+    pub fn max_retries(&mut self, retries: u32) -> &mut Self {
+        self.max_retries = retries;
+
+        self
+    }
+
+    /// Sets the status codes of responses that are retried.
+    ///
+    /// Refer to [`statuses`] for more information.
+    ///
+    /// [`statuses`]: #structfield.statuses
+    pub fn statuses(&mut self, statuses: Vec<u16>) -> &mut Self {
+        self.statuses = statuses;
+
+        self
+    }
+
+    /// Sets whether requests failing to connect are retried.
+    ///
+    /// Refer to [`retry_connection_errors`] for more information.
+    ///
+    /// [`retry_connection_errors`]: #structfield.retry_connection_errors
+    pub fn retry_connection_errors(&mut self, retry: bool) -> &mut Self {
+        self.retry_connection_errors = retry;
+
+        self
+    }
+
+    /// Sets whether requests with non-idempotent methods are retried the
+    /// same as other requests.
+    ///
+    /// Refer to [`retry_non_idempotent`] for more information.
+    ///
+    /// [`retry_non_idempotent`]: #structfield.retry_non_idempotent
+    pub fn retry_non_idempotent(&mut self, retry: bool) -> &mut Self {
+        self.retry_non_idempotent = retry;
+
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// Refer to [`initial_backoff`] for more information.
+    ///
+    /// [`initial_backoff`]: #structfield.initial_backoff
+    pub fn initial_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.initial_backoff = backoff;
+
+        self
+    }
+
+    /// Sets the maximum delay between retries.
+    ///
+    /// Refer to [`max_backoff`] for more information.
+    ///
+    /// [`max_backoff`]: #structfield.max_backoff
+    pub fn max_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.max_backoff = backoff;
+
+        self
+    }
+
+    /// Sets whether delays are randomly shortened.
+    ///
+    /// Refer to [`jitter`] for more information.
+    ///
+    /// [`jitter`]: #structfield.jitter
+    pub fn jitter(&mut self, jitter: bool) -> &mut Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Whether a request with the given method receiving a response with the
+    /// given status is retried as the given retry, counted from 1.
+    ///
+    /// Ratelimited requests were not applied, so are retried regardless of
+    /// their method.
+    pub(crate) fn retries_status(&self, method: LightMethod, status: StatusCode, retry: u32) -> bool {
+        let repeatable = status == StatusCode::TOO_MANY_REQUESTS || self.retries_method(method);
+
+        retry <= self.max_retries && repeatable && self.statuses.contains(&status.as_u16())
+    }
+
+    /// Whether a request with the given method failing with the given error
+    /// is retried as the given retry, counted from 1.
+    ///
+    /// Requests failing to connect were never sent, so are retried regardless
+    /// of their method.
+    pub(crate) fn retries_error(&self, method: LightMethod, error: &ReqwestError, retry: u32) -> bool {
+        let transient = error.is_connect()
+            || (self.retries_method(method) && (error.is_timeout() || error.is_request()));
+
+        retry <= self.max_retries && self.retry_connection_errors && transient
+    }
+
+    fn retries_method(&self, method: LightMethod) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    /// The delay before the given retry, counted from 1.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2f64.powi(retry.saturating_sub(1) as i32);
+        let backoff = self.initial_backoff.as_secs_f64() * factor;
+        let mut backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        if self.jitter {
+            backoff *= 1.0 - random() / 2.0;
+        }
+
+        Duration::from_secs_f64(backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::http::LightMethod;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    #[test]
+    fn retries_transient_statuses() {
+        let mut policy = RetryPolicy::new();
+        policy.jitter(false);
+
+        assert!(policy.retries_status(LightMethod::Get, StatusCode::BAD_GATEWAY, 3));
+        assert!(!policy.retries_status(LightMethod::Get, StatusCode::BAD_GATEWAY, 4));
+        assert!(!policy.retries_status(LightMethod::Get, StatusCode::NOT_FOUND, 1));
+        assert!(!RetryPolicy::none().retries_status(LightMethod::Get, StatusCode::BAD_GATEWAY, 1));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(10), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn retries_non_idempotent_methods_only_when_safe() {
+        let mut policy = RetryPolicy::new();
+
+        assert!(policy.retries_status(LightMethod::Put, StatusCode::BAD_GATEWAY, 1));
+        assert!(policy.retries_status(LightMethod::Delete, StatusCode::BAD_GATEWAY, 1));
+        assert!(!policy.retries_status(LightMethod::Post, StatusCode::BAD_GATEWAY, 1));
+        assert!(!policy.retries_status(LightMethod::Patch, StatusCode::SERVICE_UNAVAILABLE, 1));
+        assert!(policy.retries_status(LightMethod::Post, StatusCode::TOO_MANY_REQUESTS, 1));
+
+        policy.retry_non_idempotent(true);
+        assert!(policy.retries_status(LightMethod::Post, StatusCode::BAD_GATEWAY, 1));
+    }
+}
@@ -12,6 +12,56 @@ use super::LightMethod;
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route {
+    /// Route for the `/applications/:application_id/commands` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommands(u64),
+    /// Route for the `/applications/:application_id/commands/:command_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/guilds/:guild_id/commands`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommands(u64),
+    /// Route for the
+    /// `/applications/:application_id/guilds/:guild_id/commands/:command_id`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommandsId(u64),
+    /// Route for the
+    /// `/applications/:application_id/guilds/:guild_id/commands/:command_id/permissions`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommandsIdPermissions(u64),
+    /// Route for the
+    /// `/applications/:application_id/guilds/:guild_id/commands/permissions`
+    /// path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    ApplicationsIdGuildsIdCommandsPermissions(u64),
+    /// Route for the `/applications/:application_id/role-connections/metadata`
+    /// path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    ApplicationsIdRoleConnectionsMetadata(u64),
     /// Route for the `/channels/:channel_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -57,6 +107,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdAck(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/crosspost`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdCrosspost(u64),
     /// Route for the `/channels/:channel_id/messages/:message_id/reactions`
     /// path.
     ///
@@ -72,6 +129,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdMessagesIdThreads(u64),
     /// Route for the `/channels/:channel_id/permissions/:target_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -90,12 +153,61 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdPinsMessageId(u64),
+    /// Route for the
+    /// `/channels/:channel_id/polls/:message_id/answers/:answer_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdPollsIdAnswersId(u64),
+    /// Route for the `/channels/:channel_id/polls/:message_id/expire` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdPollsIdExpire(u64),
+    /// Route for the `/channels/:channel_id/thread-members` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadMembers(u64),
+    /// Route for the `/channels/:channel_id/thread-members/:user_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadMembersId(u64),
+    /// Route for the `/channels/:channel_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreads(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/private` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/public` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdThreadsArchivedPublic(u64),
     /// Route for the `/channels/:channel_id/typing` path.
     ///
     /// The data is the relevant [`ChannelId`].
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsIdTyping(u64),
+    /// Route for the `/channels/:channel_id/users/@me/threads/archived/private` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdUsersMeThreadsArchivedPrivate(u64),
     /// Route for the `/channels/:channel_id/webhooks` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -125,12 +237,30 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdAuditLogs(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdAutoModerationRules(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules/:rule_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdAutoModerationRulesId(u64),
     /// Route for the `/guilds/:guild_id/bans/:user_id` path.
     ///
     /// The data is the relevant [`GuildId`].
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdBansUserId(u64),
+    /// Route for the `/guilds/:guild_id/bulk-ban` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdBulkBan(u64),
     /// Route for the `/guilds/:guild_id/channels/:channel_id` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -204,6 +334,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdMembersMeNick(u64),
+    /// Route for the `/guilds/:guild_id/member-verification` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdMemberVerification(u64),
+    /// Route for the `/guilds/:guild_id/preview` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdPreview(u64),
     /// Route for the `/guilds/:guild_id/prune` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -228,6 +370,54 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdRolesId(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdScheduledEvents(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events/:event_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdScheduledEventsId(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events/:event_id/users` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdScheduledEventsIdUsers(u64),
+    /// Route for the `/guilds/:guild_id/stickers` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickers(u64),
+    /// Route for the `/guilds/:guild_id/stickers/:sticker_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdTemplatesCode(u64),
+    /// Route for the `/guilds/:guild_id/threads/active` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdThreadsActive(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -240,8 +430,52 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/:guild_id/welcome-screen` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWelcomeScreen(u64),
+    /// Route for the `/guilds/:guild_id/widget` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWidget(u64),
+    /// Route for the `/guilds/:guild_id/widget.json` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWidgetJson(u64),
+    /// Route for the `/guilds/:guild_id/widget.png` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWidgetPng(u64),
+    /// Route for the `/guilds/templates/:code` path.
+    GuildsTemplatesCode,
+    /// Route for the `/interactions/:interaction_id/:token/callback` path.
+    ///
+    /// The data is the relevant [`InteractionId`].
+    ///
+    /// [`InteractionId`]: ../../model/id/struct.InteractionId.html
+    InteractionsIdTokenCallback(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/stage-instances` path.
+    StageInstances,
+    /// Route for the `/stage-instances/:channel_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    StageInstancesChannelId(u64),
+    /// Route for the `/sticker-packs` path.
+    StickerPacks,
+    /// Route for the `/stickers/:sticker_id` path.
+    StickersId,
     /// Route for the `/users/:user_id` path.
     UsersId,
     /// Route for the `/users/@me` path.
@@ -256,6 +490,14 @@ pub enum Route {
     VoiceRegions,
     /// Route for the `/webhooks/:webhook_id` path.
     WebhooksId(u64),
+    /// Route for the `/webhooks/:webhook_id/:token/messages/:message_id` path.
+    ///
+    /// The data is the relevant [`WebhookId`], or the [`ApplicationId`] for
+    /// interaction responses.
+    ///
+    /// [`ApplicationId`]: ../../model/id/struct.ApplicationId.html
+    /// [`WebhookId`]: ../../model/id/struct.WebhookId.html
+    WebhooksIdTokenMessagesId(u64),
     /// Route where no ratelimit headers are in place (i.e. user account-only
     /// routes).
     ///
@@ -265,6 +507,52 @@ pub enum Route {
 }
 
 impl Route {
+    pub fn application_command(application_id: u64, command_id: u64) -> String {
+        format!(api!("/applications/{}/commands/{}"), application_id, command_id)
+    }
+
+    pub fn application_commands(application_id: u64) -> String {
+        format!(api!("/applications/{}/commands"), application_id)
+    }
+
+    pub fn application_guild_command(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!(
+            api!("/applications/{}/guilds/{}/commands/{}"),
+            application_id,
+            guild_id,
+            command_id,
+        )
+    }
+
+    pub fn application_guild_command_permissions(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!(
+            api!("/applications/{}/guilds/{}/commands/{}/permissions"),
+            application_id,
+            guild_id,
+            command_id,
+        )
+    }
+
+    pub fn application_guild_commands(application_id: u64, guild_id: u64) -> String {
+        format!(api!("/applications/{}/guilds/{}/commands"), application_id, guild_id)
+    }
+
+    pub fn application_guild_commands_permissions(application_id: u64, guild_id: u64) -> String {
+        format!(api!("/applications/{}/guilds/{}/commands/permissions"), application_id, guild_id)
+    }
+
+    pub fn application_role_connections_metadata(application_id: u64) -> String {
+        format!(api!("/applications/{}/role-connections/metadata"), application_id)
+    }
+
     pub fn channel(channel_id: u64) -> String {
         format!(api!("/channels/{}"), channel_id)
     }
@@ -277,6 +565,10 @@ impl Route {
         format!(api!("/channels/{}/messages/{}"), channel_id, message_id)
     }
 
+    pub fn channel_message_crosspost(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/crosspost"), channel_id, message_id)
+    }
+
     pub fn channel_message_reaction<D, T>(
         channel_id: u64,
         message_id: u64,
@@ -334,6 +626,10 @@ impl Route {
         uri
     }
 
+    pub fn channel_message_threads(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/threads"), channel_id, message_id)
+    }
+
     pub fn channel_messages(channel_id: u64, query: Option<&str>) -> String {
         format!(
             api!("/channels/{}/messages{}"),
@@ -358,10 +654,88 @@ impl Route {
         format!(api!("/channels/{}/pins"), channel_id)
     }
 
+    pub fn channel_poll_answer_voters(
+        channel_id: u64,
+        message_id: u64,
+        answer_id: u64,
+        after: Option<u64>,
+        limit: Option<u8>,
+    ) -> String {
+        let mut uri = format!(
+            api!("/channels/{}/polls/{}/answers/{}?"),
+            channel_id,
+            message_id,
+            answer_id,
+        );
+
+        if let Some(after) = after {
+            let _ = write!(uri, "&after={}", after);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(uri, "&limit={}", limit);
+        }
+
+        uri
+    }
+
+    pub fn channel_poll_expire(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/polls/{}/expire"), channel_id, message_id)
+    }
+
+    pub fn channel_thread_member<D: Display>(channel_id: u64, user_id: D) -> String {
+        format!(api!("/channels/{}/thread-members/{}"), channel_id, user_id)
+    }
+
+    pub fn channel_thread_members(channel_id: u64) -> String {
+        format!(api!("/channels/{}/thread-members"), channel_id)
+    }
+
+    pub fn channel_threads(channel_id: u64) -> String {
+        format!(api!("/channels/{}/threads"), channel_id)
+    }
+
+    pub fn channel_threads_archived<D: Display>(
+        channel_id: u64,
+        kind: &str,
+        before: Option<D>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/channels/{}/threads/archived/{}?"), channel_id, kind);
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn channel_typing(channel_id: u64) -> String {
         format!(api!("/channels/{}/typing"), channel_id)
     }
 
+    pub fn channel_users_me_threads_archived_private(
+        channel_id: u64,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/channels/{}/users/@me/threads/archived/private?"), channel_id);
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn channel_webhooks(channel_id: u64) -> String {
         format!(api!("/channels/{}/webhooks"), channel_id)
     }
@@ -409,23 +783,16 @@ impl Route {
         s
     }
 
-    pub fn guild_ban(guild_id: u64, user_id: u64) -> String {
-        format!(api!("/guilds/{}/bans/{}"), guild_id, user_id)
+    pub fn guild_automod_rule(guild_id: u64, rule_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules/{}"), guild_id, rule_id)
     }
 
-    pub fn guild_ban_optioned(
-        guild_id: u64,
-        user_id: u64,
-        delete_message_days: u8,
-        reason: &str,
-    ) -> String {
-        format!(
-            api!("/guilds/{}/bans/{}?delete_message_days={}&reason={}"),
-            guild_id,
-            user_id,
-            delete_message_days,
-            reason,
-        )
+    pub fn guild_automod_rules(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules"), guild_id)
+    }
+
+    pub fn guild_ban(guild_id: u64, user_id: u64) -> String {
+        format!(api!("/guilds/{}/bans/{}"), guild_id, user_id)
     }
 
     pub fn guild_kick_optioned(
@@ -441,10 +808,37 @@ impl Route {
         )
     }
 
+    pub fn guild_bulk_ban(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/bulk-ban"), guild_id)
+    }
+
     pub fn guild_bans(guild_id: u64) -> String {
         format!(api!("/guilds/{}/bans"), guild_id)
     }
 
+    pub fn guild_bans_optioned(
+        guild_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/guilds/{}/bans?"), guild_id);
+
+        if let Some(after) = after {
+            let _ = write!(s, "&after={}", after);
+        }
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn guild_channels(guild_id: u64) -> String {
         format!(api!("/guilds/{}/channels"), guild_id)
     }
@@ -530,6 +924,14 @@ impl Route {
         format!(api!("/guilds/{}/members/@me/nick"), guild_id)
     }
 
+    pub fn guild_membership_screening(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/member-verification"), guild_id)
+    }
+
+    pub fn guild_preview(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/preview"), guild_id)
+    }
+
     pub fn guild_prune(guild_id: u64, days: u64) -> String {
         format!(api!("/guilds/{}/prune?days={}"), guild_id, days)
     }
@@ -546,6 +948,65 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_scheduled_event(guild_id: u64, event_id: u64, with_user_count: Option<bool>) -> String {
+        let mut s = format!(api!("/guilds/{}/scheduled-events/{}"), guild_id, event_id);
+
+        if let Some(with_user_count) = with_user_count {
+            let _ = write!(s, "?with_user_count={}", with_user_count);
+        }
+
+        s
+    }
+
+    pub fn guild_scheduled_event_users(
+        guild_id: u64,
+        event_id: u64,
+        limit: Option<u64>,
+        with_member: Option<bool>,
+    ) -> String {
+        let mut s = format!(api!("/guilds/{}/scheduled-events/{}/users?"), guild_id, event_id);
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        if let Some(with_member) = with_member {
+            let _ = write!(s, "&with_member={}", with_member);
+        }
+
+        s
+    }
+
+    pub fn guild_scheduled_events(guild_id: u64, with_user_count: Option<bool>) -> String {
+        let mut s = format!(api!("/guilds/{}/scheduled-events"), guild_id);
+
+        if let Some(with_user_count) = with_user_count {
+            let _ = write!(s, "?with_user_count={}", with_user_count);
+        }
+
+        s
+    }
+
+    pub fn guild_sticker(guild_id: u64, sticker_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers/{}"), guild_id, sticker_id)
+    }
+
+    pub fn guild_stickers(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers"), guild_id)
+    }
+
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        format!(api!("/guilds/{}/templates/{}"), guild_id, code)
+    }
+
+    pub fn guild_templates(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/templates"), guild_id)
+    }
+
+    pub fn guild_threads_active(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/threads/active"), guild_id)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -554,10 +1015,35 @@ impl Route {
         format!(api!("/guilds/{}/webhooks"), guild_id)
     }
 
+    pub fn guild_welcome_screen(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/welcome-screen"), guild_id)
+    }
+
+    pub fn guild_widget(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/widget"), guild_id)
+    }
+
+    pub fn guild_widget_json(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/widget.json"), guild_id)
+    }
+
+    pub fn guild_widget_png(guild_id: u64, style: &str) -> String {
+        format!(api!("/guilds/{}/widget.png?style={}"), guild_id, style)
+    }
+
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
 
+    pub fn guilds_template(code: &str) -> String {
+        format!(api!("/guilds/templates/{}"), code)
+    }
+
+    pub fn interaction_response<D>(interaction_id: u64, token: D) -> String
+        where D: Display {
+        format!(api!("/interactions/{}/{}/callback"), interaction_id, token)
+    }
+
     pub fn invite(code: &str) -> String {
         format!(api!("/invites/{}"), code)
     }
@@ -570,10 +1056,34 @@ impl Route {
         api!("/oauth2/applications/@me")
     }
 
+    pub fn oauth2_authorization_current() -> &'static str {
+        api!("/oauth2/@me")
+    }
+
+    pub fn oauth2_token() -> &'static str {
+        api!("/oauth2/token")
+    }
+
     pub fn private_channel() -> &'static str {
         api!("/users/@me/channels")
     }
 
+    pub fn stage_instance(channel_id: u64) -> String {
+        format!(api!("/stage-instances/{}"), channel_id)
+    }
+
+    pub fn stage_instances() -> &'static str {
+        api!("/stage-instances")
+    }
+
+    pub fn sticker(sticker_id: u64) -> String {
+        format!(api!("/stickers/{}"), sticker_id)
+    }
+
+    pub fn sticker_packs() -> &'static str {
+        api!("/sticker-packs")
+    }
+
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
@@ -629,14 +1139,30 @@ impl Route {
         format!(api!("/webhooks/{}"), webhook_id)
     }
 
+    pub fn webhook_message<D>(webhook_id: u64, token: D, message_id: u64) -> String
+        where D: Display {
+        format!(api!("/webhooks/{}/{}/messages/{}"), webhook_id, token, message_id)
+    }
+
+    pub fn webhook_original_message<D>(webhook_id: u64, token: D) -> String
+        where D: Display {
+        format!(api!("/webhooks/{}/{}/messages/@original"), webhook_id, token)
+    }
+
     pub fn webhook_with_token<D>(webhook_id: u64, token: D) -> String
         where D: Display {
         format!(api!("/webhooks/{}/{}"), webhook_id, token)
     }
 
-    pub fn webhook_with_token_optioned<D>(webhook_id: u64, token: D, wait: bool)
+    pub fn webhook_with_token_optioned<D>(webhook_id: u64, token: D, wait: bool, thread_id: Option<u64>)
         -> String where D: Display {
-        format!(api!("/webhooks/{}/{}?wait={}"), webhook_id, token, wait)
+        let mut s = format!(api!("/webhooks/{}/{}?wait={}"), webhook_id, token, wait);
+
+        if let Some(thread_id) = thread_id {
+            let _ = write!(s, "&thread_id={}", thread_id);
+        }
+
+        s
     }
 }
 
@@ -648,32 +1174,69 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    AddThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
-        delete_message_days: Option<u8>,
-        reason: Option<&'a str>,
+    },
+    GuildBulkBan {
+        guild_id: u64,
     },
     BroadcastTyping {
         channel_id: u64,
     },
+    CreateAutoModRule {
+        guild_id: u64,
+    },
     CreateChannel {
         guild_id: u64,
     },
     CreateEmoji {
         guild_id: u64,
     },
+    CreateFollowupMessage {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
+    CreateGlobalApplicationCommand {
+        application_id: u64,
+    },
+    CreateGlobalApplicationCommands {
+        application_id: u64,
+    },
     CreateGuild,
+    CreateGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+    },
+    CreateGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
+    CreateInteractionResponse {
+        interaction_id: u64,
+        interaction_token: &'a str,
+    },
     CreateInvite {
         channel_id: u64,
     },
     CreateMessage {
         channel_id: u64,
     },
+    CreateOAuth2Token,
     CreatePermission {
         channel_id: u64,
         target_id: u64,
@@ -687,9 +1250,28 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    CreateScheduledEvent {
+        guild_id: u64,
+    },
+    CreateStageInstance,
+    CreateThread {
+        channel_id: u64,
+    },
+    CreateThreadFromMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
     CreateWebhook {
         channel_id: u64,
     },
+    CrosspostMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
+    DeleteAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -697,13 +1279,31 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    DeleteFollowupMessage {
+        application_id: u64,
+        interaction_token: &'a str,
+        message_id: u64,
+    },
+    DeleteGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     DeleteGuild {
         guild_id: u64,
     },
+    DeleteGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
     DeleteGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        code: &'a str,
+        guild_id: u64,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -723,6 +1323,10 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: &'a str,
     },
+    DeleteOriginalInteractionResponse {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
     DeletePermission {
         channel_id: u64,
         target_id: u64,
@@ -737,13 +1341,36 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    DeleteScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+    },
+    DeleteStageInstance {
+        channel_id: u64,
+    },
+    DeleteSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
+    DeleteWebhookMessage {
+        message_id: u64,
+        token: &'a str,
+        webhook_id: u64,
+    },
     DeleteWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
     },
+    EditApplicationRoleConnectionMetadataRecords {
+        application_id: u64,
+    },
+    EditAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     EditChannel {
         channel_id: u64,
     },
@@ -751,15 +1378,51 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    EditFollowupMessage {
+        application_id: u64,
+        interaction_token: &'a str,
+        message_id: u64,
+    },
+    EditGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     EditGuild {
         guild_id: u64,
     },
+    EditGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    EditGuildApplicationCommandPermissions {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    EditGuildApplicationCommandsPermissions {
+        application_id: u64,
+        guild_id: u64,
+    },
     EditGuildChannels {
         guild_id: u64,
     },
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildMembershipScreening {
+        guild_id: u64,
+    },
+    EditGuildTemplate {
+        code: &'a str,
+        guild_id: u64,
+    },
+    EditGuildWelcomeScreen {
+        guild_id: u64,
+    },
+    EditGuildWidget {
+        guild_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -771,6 +1434,10 @@ pub enum RouteInfo<'a> {
     EditNickname {
         guild_id: u64,
     },
+    EditOriginalInteractionResponse {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
     EditProfile,
     EditRole {
         guild_id: u64,
@@ -779,19 +1446,53 @@ pub enum RouteInfo<'a> {
     EditRolePosition {
         guild_id: u64,
     },
+    EditScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+    },
+    EditStageInstance {
+        channel_id: u64,
+    },
+    EditSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     EditWebhook {
         webhook_id: u64,
     },
+    EditWebhookMessage {
+        message_id: u64,
+        token: &'a str,
+        webhook_id: u64,
+    },
     EditWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
     },
+    EndPoll {
+        channel_id: u64,
+        message_id: u64,
+    },
     ExecuteWebhook {
+        thread_id: Option<u64>,
         token: &'a str,
         wait: bool,
         webhook_id: u64,
     },
     GetActiveMaintenance,
+    GetApplicationRoleConnectionMetadataRecords {
+        application_id: u64,
+    },
+    GetArchivedPrivateThreads {
+        before: Option<&'a str>,
+        channel_id: u64,
+        limit: Option<u64>,
+    },
+    GetArchivedPublicThreads {
+        before: Option<&'a str>,
+        channel_id: u64,
+        limit: Option<u64>,
+    },
     GetAuditLogs {
         action_type: Option<u8>,
         before: Option<u64>,
@@ -799,9 +1500,22 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
+    GetAutoModRules {
+        guild_id: u64,
+    },
     GetBans {
         guild_id: u64,
     },
+    GetBansOptioned {
+        after: Option<u64>,
+        before: Option<u64>,
+        guild_id: u64,
+        limit: Option<u64>,
+    },
     GetBotGateway,
     GetChannel {
         channel_id: u64,
@@ -816,6 +1530,7 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
     },
     GetCurrentApplicationInfo,
+    GetCurrentAuthorizationInfo,
     GetCurrentUser,
     GetEmojis {
         guild_id: u64,
@@ -825,9 +1540,37 @@ pub enum RouteInfo<'a> {
         emoji_id: u64,
     },
     GetGateway,
+    GetGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
+    GetGlobalApplicationCommands {
+        application_id: u64,
+    },
     GetGuild {
         guild_id: u64,
     },
+    GetGuildActiveThreads {
+        guild_id: u64,
+    },
+    GetGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    GetGuildApplicationCommandPermissions {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    GetGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
+    GetGuildApplicationCommandsPermissions {
+        application_id: u64,
+        guild_id: u64,
+    },
     GetGuildEmbed {
         guild_id: u64,
     },
@@ -842,6 +1585,12 @@ pub enum RouteInfo<'a> {
         limit: Option<u64>,
         guild_id: u64,
     },
+    GetGuildMembershipScreening {
+        guild_id: u64,
+    },
+    GetGuildPreview {
+        guild_id: u64,
+    },
     GetGuildPruneCount {
         days: u64,
         guild_id: u64,
@@ -852,12 +1601,35 @@ pub enum RouteInfo<'a> {
     GetGuildRoles {
         guild_id: u64,
     },
+    GetGuildSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
+    GetGuildStickers {
+        guild_id: u64,
+    },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
     GetGuildWebhooks {
         guild_id: u64,
     },
+    GetGuildWelcomeScreen {
+        guild_id: u64,
+    },
+    GetGuildWidget {
+        guild_id: u64,
+    },
+    GetGuildWidgetImage {
+        guild_id: u64,
+        style: &'a str,
+    },
+    GetGuildWidgetSettings {
+        guild_id: u64,
+    },
     GetGuilds {
         after: Option<u64>,
         before: Option<u64>,
@@ -867,6 +1639,11 @@ pub enum RouteInfo<'a> {
         code: &'a str,
         stats: bool,
     },
+    GetJoinedArchivedPrivateThreads {
+        before: Option<u64>,
+        channel_id: u64,
+        limit: Option<u64>,
+    },
     GetMember {
         guild_id: u64,
         user_id: u64,
@@ -879,9 +1656,20 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         query: String,
     },
+    GetOriginalInteractionResponse {
+        application_id: u64,
+        interaction_token: &'a str,
+    },
     GetPins {
         channel_id: u64,
     },
+    GetPollAnswerVoters {
+        after: Option<u64>,
+        answer_id: u64,
+        channel_id: u64,
+        limit: Option<u8>,
+        message_id: u64,
+    },
     GetReactionUsers {
         after: Option<u64>,
         channel_id: u64,
@@ -889,6 +1677,34 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    GetScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+        with_user_count: bool,
+    },
+    GetScheduledEventUsers {
+        guild_id: u64,
+        event_id: u64,
+        limit: Option<u64>,
+        with_member: Option<bool>,
+    },
+    GetScheduledEvents {
+        guild_id: u64,
+        with_user_count: bool,
+    },
+    GetStageInstance {
+        channel_id: u64,
+    },
+    GetSticker {
+        sticker_id: u64,
+    },
+    GetStickerPacks,
+    GetTemplate {
+        code: &'a str,
+    },
+    GetThreadMembers {
+        channel_id: u64,
+    },
     GetUnresolvedIncidents,
     GetUpcomingMaintenances,
     GetUser {
@@ -903,6 +1719,9 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    JoinThread {
+        channel_id: u64,
+    },
     KickMember {
         guild_id: u64,
         user_id: u64,
@@ -914,6 +1733,9 @@ pub enum RouteInfo<'a> {
     LeaveGuild {
         guild_id: u64,
     },
+    LeaveThread {
+        channel_id: u64,
+    },
     PinMessage {
         channel_id: u64,
         message_id: u64,
@@ -927,6 +1749,10 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    RemoveThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     StartGuildPrune {
         days: u64,
         guild_id: u64,
@@ -938,6 +1764,10 @@ pub enum RouteInfo<'a> {
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
+    SyncGuildTemplate {
+        code: &'a str,
+        guild_id: u64,
+    },
     UnpinMessage {
         channel_id: u64,
         message_id: u64,
@@ -945,6 +1775,27 @@ pub enum RouteInfo<'a> {
 }
 
 impl<'a> RouteInfo<'a> {
+    /// Whether the route requires the authorization of a bot token, rather
+    /// than being authorized by the token of a webhook or interaction in its
+    /// path.
+    pub fn requires_token(&self) -> bool {
+        !matches!(self,
+            RouteInfo::CreateFollowupMessage { .. }
+            | RouteInfo::CreateInteractionResponse { .. }
+            | RouteInfo::DeleteFollowupMessage { .. }
+            | RouteInfo::DeleteOriginalInteractionResponse { .. }
+            | RouteInfo::DeleteWebhookMessage { .. }
+            | RouteInfo::DeleteWebhookWithToken { .. }
+            | RouteInfo::EditFollowupMessage { .. }
+            | RouteInfo::EditOriginalInteractionResponse { .. }
+            | RouteInfo::EditWebhookMessage { .. }
+            | RouteInfo::EditWebhookWithToken { .. }
+            | RouteInfo::ExecuteWebhook { .. }
+            | RouteInfo::GetOriginalInteractionResponse { .. }
+            | RouteInfo::GetWebhookWithToken { .. }
+        )
+    }
+
     pub fn deconstruct(&self) -> (LightMethod, Route, Cow<'_, str>) {
         match *self {
             RouteInfo::AddMemberRole { guild_id, role_id, user_id } => (
@@ -952,27 +1803,31 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
-            RouteInfo::GuildBanUser {
-                guild_id,
-                delete_message_days,
-                reason,
-                user_id,
-            } => (
-                // TODO
+            RouteInfo::AddThreadMember { channel_id, user_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
+            RouteInfo::GuildBanUser { guild_id, user_id } => (
                 LightMethod::Put,
                 Route::GuildsIdBansUserId(guild_id),
-                Cow::from(Route::guild_ban_optioned(
-                    guild_id,
-                    user_id,
-                    delete_message_days.unwrap_or(0),
-                    reason.unwrap_or(""),
-                )),
+                Cow::from(Route::guild_ban(guild_id, user_id)),
+            ),
+            RouteInfo::GuildBulkBan { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdBulkBan(guild_id),
+                Cow::from(Route::guild_bulk_ban(guild_id)),
             ),
             RouteInfo::BroadcastTyping { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::CreateAutoModRule { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_automod_rules(guild_id)),
+            ),
             RouteInfo::CreateChannel { guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdChannels(guild_id),
@@ -983,21 +1838,66 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojis(guild_id),
                 Cow::from(Route::guild_emojis(guild_id)),
             ),
+            RouteInfo::CreateFollowupMessage { application_id, interaction_token } => (
+                LightMethod::Post,
+                Route::WebhooksId(application_id),
+                Cow::from(Route::webhook_with_token(application_id, interaction_token)),
+            ),
+            RouteInfo::CreateGlobalApplicationCommand { application_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::CreateGlobalApplicationCommands { application_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::CreateGuildApplicationCommand { application_id, guild_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::CreateGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::CreateGuild => (
                 LightMethod::Post,
                 Route::Guilds,
                 Cow::from(Route::guilds()),
             ),
+            RouteInfo::CreateGuildFromTemplate { code } => (
+                LightMethod::Post,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
             RouteInfo::CreateGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateGuildTemplate { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
+            RouteInfo::CreateInteractionResponse { interaction_id, interaction_token } => (
+                LightMethod::Post,
+                Route::InteractionsIdTokenCallback(interaction_id),
+                Cow::from(Route::interaction_response(interaction_id, interaction_token)),
+            ),
             RouteInfo::CreateInvite { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
                 Cow::from(Route::channel_invites(channel_id)),
             ),
+            RouteInfo::CreateOAuth2Token => (
+                LightMethod::Post,
+                Route::None,
+                Cow::from(Route::oauth2_token()),
+            ),
             RouteInfo::CreateMessage { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdMessages(channel_id),
@@ -1028,11 +1928,41 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::CreateScheduledEvent { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdScheduledEvents(guild_id),
+                Cow::from(Route::guild_scheduled_events(guild_id, None)),
+            ),
+            RouteInfo::CreateStageInstance => (
+                LightMethod::Post,
+                Route::StageInstances,
+                Cow::from(Route::stage_instances()),
+            ),
+            RouteInfo::CreateThread { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdThreads(channel_id),
+                Cow::from(Route::channel_threads(channel_id)),
+            ),
+            RouteInfo::CreateThreadFromMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdThreads(channel_id),
+                Cow::from(Route::channel_message_threads(channel_id, message_id)),
+            ),
             RouteInfo::CreateWebhook { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::CrosspostMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdCrosspost(channel_id),
+                Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
+            ),
+            RouteInfo::DeleteAutoModRule { guild_id, rule_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
             RouteInfo::DeleteChannel { channel_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
@@ -1043,6 +1973,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::DeleteFollowupMessage { application_id, interaction_token, message_id } => (
+                LightMethod::Delete,
+                Route::WebhooksIdTokenMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, interaction_token, message_id)),
+            ),
+            RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
+            RouteInfo::DeleteGuildApplicationCommand { application_id, guild_id, command_id } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
             RouteInfo::DeleteGuild { guild_id } => (
                 LightMethod::Delete,
                 Route::GuildsId(guild_id),
@@ -1053,6 +1998,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate { code, guild_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::DeleteInvite { code } => (
                 LightMethod::Delete,
                 Route::InvitesCode,
@@ -1085,6 +2035,11 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdMessagesBulkDelete(channel_id),
                 Cow::from(Route::channel_messages_bulk_delete(channel_id)),
             ),
+            RouteInfo::DeleteOriginalInteractionResponse { application_id, interaction_token } => (
+                LightMethod::Delete,
+                Route::WebhooksIdTokenMessagesId(application_id),
+                Cow::from(Route::webhook_original_message(application_id, interaction_token)),
+            ),
             RouteInfo::DeletePermission { channel_id, target_id } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPermissionsOverwriteId(channel_id),
@@ -1110,16 +2065,46 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::DeleteScheduledEvent { guild_id, event_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event(guild_id, event_id, None)),
+            ),
+            RouteInfo::DeleteStageInstance { channel_id } => (
+                LightMethod::Delete,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::DeleteSticker { guild_id, sticker_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::DeleteWebhook { webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::DeleteWebhookMessage { message_id, token, webhook_id } => (
+                LightMethod::Delete,
+                Route::WebhooksIdTokenMessagesId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+            ),
             RouteInfo::DeleteWebhookWithToken { token, webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::EditApplicationRoleConnectionMetadataRecords { application_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connections_metadata(application_id)),
+            ),
+            RouteInfo::EditAutoModRule { guild_id, rule_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
             RouteInfo::EditChannel { channel_id } => (
                 LightMethod::Patch,
                 Route::ChannelsId(channel_id),
@@ -1130,6 +2115,31 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::EditFollowupMessage { application_id, interaction_token, message_id } => (
+                LightMethod::Patch,
+                Route::WebhooksIdTokenMessagesId(application_id),
+                Cow::from(Route::webhook_message(application_id, interaction_token, message_id)),
+            ),
+            RouteInfo::EditGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
+            RouteInfo::EditGuildApplicationCommand { application_id, guild_id, command_id } => (
+                LightMethod::Patch,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::EditGuildApplicationCommandPermissions { application_id, guild_id, command_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommandsIdPermissions(guild_id),
+                Cow::from(Route::application_guild_command_permissions(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::EditGuildApplicationCommandsPermissions { application_id, guild_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommandsPermissions(guild_id),
+                Cow::from(Route::application_guild_commands_permissions(application_id, guild_id)),
+            ),
             RouteInfo::EditGuild { guild_id } => (
                 LightMethod::Patch,
                 Route::GuildsId(guild_id),
@@ -1145,6 +2155,26 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildMembershipScreening { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdMemberVerification(guild_id),
+                Cow::from(Route::guild_membership_screening(guild_id)),
+            ),
+            RouteInfo::EditGuildTemplate { code, guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
+            RouteInfo::EditGuildWelcomeScreen { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
+            RouteInfo::EditGuildWidget { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWidget(guild_id),
+                Cow::from(Route::guild_widget(guild_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1160,6 +2190,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersMeNick(guild_id),
                 Cow::from(Route::guild_nickname(guild_id)),
             ),
+            RouteInfo::EditOriginalInteractionResponse { application_id, interaction_token } => (
+                LightMethod::Patch,
+                Route::WebhooksIdTokenMessagesId(application_id),
+                Cow::from(Route::webhook_original_message(application_id, interaction_token)),
+            ),
             RouteInfo::EditProfile => (
                 LightMethod::Patch,
                 Route::UsersMe,
@@ -1175,23 +2210,49 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::EditScheduledEvent { guild_id, event_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event(guild_id, event_id, None)),
+            ),
+            RouteInfo::EditStageInstance { channel_id } => (
+                LightMethod::Patch,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::EditSticker { guild_id, sticker_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::EditWebhook { webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::EditWebhookMessage { message_id, token, webhook_id } => (
+                LightMethod::Patch,
+                Route::WebhooksIdTokenMessagesId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+            ),
             RouteInfo::EditWebhookWithToken { token, webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
-            RouteInfo::ExecuteWebhook { token, wait, webhook_id } => (
+            RouteInfo::EndPoll { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdPollsIdExpire(channel_id),
+                Cow::from(Route::channel_poll_expire(channel_id, message_id)),
+            ),
+            RouteInfo::ExecuteWebhook { thread_id, token, wait, webhook_id } => (
                 LightMethod::Post,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token_optioned(
                     webhook_id,
                     token,
                     wait,
+                    thread_id,
                 )),
             ),
             RouteInfo::GetActiveMaintenance => (
@@ -1199,6 +2260,21 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::status_maintenances_active()),
             ),
+            RouteInfo::GetApplicationRoleConnectionMetadataRecords { application_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connections_metadata(application_id)),
+            ),
+            RouteInfo::GetArchivedPrivateThreads { before, channel_id, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPrivate(channel_id),
+                Cow::from(Route::channel_threads_archived(channel_id, "private", before, limit)),
+            ),
+            RouteInfo::GetArchivedPublicThreads { before, channel_id, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadsArchivedPublic(channel_id),
+                Cow::from(Route::channel_threads_archived(channel_id, "public", before, limit)),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -1216,11 +2292,26 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
+            RouteInfo::GetAutoModRule { guild_id, rule_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
+            RouteInfo::GetAutoModRules { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_automod_rules(guild_id)),
+            ),
             RouteInfo::GetBans { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
                 Cow::from(Route::guild_bans(guild_id)),
             ),
+            RouteInfo::GetBansOptioned { after, before, guild_id, limit } => (
+                LightMethod::Get,
+                Route::GuildsIdBans(guild_id),
+                Cow::from(Route::guild_bans_optioned(guild_id, after, before, limit)),
+            ),
             RouteInfo::GetBotGateway => (
                 LightMethod::Get,
                 Route::GatewayBot,
@@ -1251,6 +2342,11 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::oauth2_application_current()),
             ),
+            RouteInfo::GetCurrentAuthorizationInfo => (
+                LightMethod::Get,
+                Route::None,
+                Cow::from(Route::oauth2_authorization_current()),
+            ),
             RouteInfo::GetCurrentUser => (
                 LightMethod::Get,
                 Route::UsersMe,
@@ -1271,6 +2367,41 @@ impl<'a> RouteInfo<'a> {
                 Route::Gateway,
                 Cow::from(Route::gateway()),
             ),
+            RouteInfo::GetGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
+            RouteInfo::GetGlobalApplicationCommands { application_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::GetGuildActiveThreads { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdThreadsActive(guild_id),
+                Cow::from(Route::guild_threads_active(guild_id)),
+            ),
+            RouteInfo::GetGuildApplicationCommand { application_id, guild_id, command_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommandsId(guild_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::GetGuildApplicationCommandPermissions { application_id, guild_id, command_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommandsIdPermissions(guild_id),
+                Cow::from(Route::application_guild_command_permissions(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::GetGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommands(guild_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::GetGuildApplicationCommandsPermissions { application_id, guild_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommandsPermissions(guild_id),
+                Cow::from(Route::application_guild_commands_permissions(application_id, guild_id)),
+            ),
             RouteInfo::GetGuild { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsId(guild_id),
@@ -1296,6 +2427,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembers(guild_id),
                 Cow::from(Route::guild_members_optioned(guild_id, after, limit)),
             ),
+            RouteInfo::GetGuildMembershipScreening { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdMemberVerification(guild_id),
+                Cow::from(Route::guild_membership_screening(guild_id)),
+            ),
+            RouteInfo::GetGuildPreview { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdPreview(guild_id),
+                Cow::from(Route::guild_preview(guild_id)),
+            ),
             RouteInfo::GetGuildPruneCount { days, guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdPrune(guild_id),
@@ -1311,6 +2452,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::GetGuildSticker { guild_id, sticker_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
+            RouteInfo::GetGuildStickers { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickers(guild_id),
+                Cow::from(Route::guild_stickers(guild_id)),
+            ),
+            RouteInfo::GetGuildTemplates { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
@@ -1321,6 +2477,26 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWebhooks(guild_id),
                 Cow::from(Route::guild_webhooks(guild_id)),
             ),
+            RouteInfo::GetGuildWelcomeScreen { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
+            RouteInfo::GetGuildWidget { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWidgetJson(guild_id),
+                Cow::from(Route::guild_widget_json(guild_id)),
+            ),
+            RouteInfo::GetGuildWidgetImage { guild_id, style } => (
+                LightMethod::Get,
+                Route::GuildsIdWidgetPng(guild_id),
+                Cow::from(Route::guild_widget_png(guild_id, style)),
+            ),
+            RouteInfo::GetGuildWidgetSettings { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWidget(guild_id),
+                Cow::from(Route::guild_widget(guild_id)),
+            ),
             RouteInfo::GetGuilds { after, before, limit } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
@@ -1336,6 +2512,11 @@ impl<'a> RouteInfo<'a> {
                 Route::InvitesCode,
                 Cow::from(Route::invite_optioned(code, stats)),
             ),
+            RouteInfo::GetJoinedArchivedPrivateThreads { before, channel_id, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdUsersMeThreadsArchivedPrivate(channel_id),
+                Cow::from(Route::channel_users_me_threads_archived_private(channel_id, before, limit)),
+            ),
             RouteInfo::GetMember { guild_id, user_id } => (
                 LightMethod::Get,
                 Route::GuildsIdMembersId(guild_id),
@@ -1354,11 +2535,27 @@ impl<'a> RouteInfo<'a> {
                     Some(query.as_ref()),
                 )),
             ),
+            RouteInfo::GetOriginalInteractionResponse { application_id, interaction_token } => (
+                LightMethod::Get,
+                Route::WebhooksIdTokenMessagesId(application_id),
+                Cow::from(Route::webhook_original_message(application_id, interaction_token)),
+            ),
             RouteInfo::GetPins { channel_id } => (
                 LightMethod::Get,
                 Route::ChannelsIdPins(channel_id),
                 Cow::from(Route::channel_pins(channel_id)),
             ),
+            RouteInfo::GetPollAnswerVoters { after, answer_id, channel_id, limit, message_id } => (
+                LightMethod::Get,
+                Route::ChannelsIdPollsIdAnswersId(channel_id),
+                Cow::from(Route::channel_poll_answer_voters(
+                    channel_id,
+                    message_id,
+                    answer_id,
+                    after,
+                    limit,
+                )),
+            ),
             RouteInfo::GetReactionUsers {
                 after,
                 channel_id,
@@ -1376,6 +2573,46 @@ impl<'a> RouteInfo<'a> {
                     after,
                 )),
             ),
+            RouteInfo::GetScheduledEvent { guild_id, event_id, with_user_count } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event(guild_id, event_id, Some(with_user_count))),
+            ),
+            RouteInfo::GetScheduledEventUsers { guild_id, event_id, limit, with_member } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEventsIdUsers(guild_id),
+                Cow::from(Route::guild_scheduled_event_users(guild_id, event_id, limit, with_member)),
+            ),
+            RouteInfo::GetScheduledEvents { guild_id, with_user_count } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEvents(guild_id),
+                Cow::from(Route::guild_scheduled_events(guild_id, Some(with_user_count))),
+            ),
+            RouteInfo::GetStageInstance { channel_id } => (
+                LightMethod::Get,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::GetSticker { sticker_id } => (
+                LightMethod::Get,
+                Route::StickersId,
+                Cow::from(Route::sticker(sticker_id)),
+            ),
+            RouteInfo::GetStickerPacks => (
+                LightMethod::Get,
+                Route::StickerPacks,
+                Cow::from(Route::sticker_packs()),
+            ),
+            RouteInfo::GetTemplate { code } => (
+                LightMethod::Get,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
+            RouteInfo::GetThreadMembers { channel_id } => (
+                LightMethod::Get,
+                Route::ChannelsIdThreadMembers(channel_id),
+                Cow::from(Route::channel_thread_members(channel_id)),
+            ),
             RouteInfo::GetUnresolvedIncidents => (
                 LightMethod::Get,
                 Route::None,
@@ -1411,6 +2648,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::JoinThread { channel_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::KickMember { guild_id, user_id, reason } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersId(guild_id),
@@ -1430,6 +2672,11 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMeGuildsId,
                 Cow::from(Route::user_guild("@me", guild_id)),
             ),
+            RouteInfo::LeaveThread { channel_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::PinMessage { channel_id, message_id } => (
                 LightMethod::Put,
                 Route::ChannelsIdPins(channel_id),
@@ -1445,6 +2692,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::RemoveThreadMember { channel_id, user_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
             RouteInfo::StartGuildPrune { days, guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
@@ -1473,6 +2725,11 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::status_maintenances_upcoming()),
             ),
+            RouteInfo::SyncGuildTemplate { code, guild_id } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::UnpinMessage { channel_id, message_id } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPinsMessageId(channel_id),
@@ -0,0 +1,42 @@
+//! A local server answering HTTP requests, for tests of code sending requests
+//! through a proxy set with [`HttpBuilder::proxy`].
+//!
+//! [`HttpBuilder::proxy`]: ../client/struct.HttpBuilder.html#method.proxy
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Answers a request with each of the JSON bodies in turn, returning the URL
+/// of the server and a receiver of the request lines, e.g. `GET /api/v8/...`.
+pub(crate) fn serve(bodies: Vec<String>) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for (stream, body) in listener.incoming().zip(bodies) {
+            let mut stream = stream.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let len = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..len]);
+            }
+
+            let request = String::from_utf8_lossy(&request);
+            let _ = tx.send(request.lines().next().unwrap_or_default().to_string());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body,
+            ).unwrap();
+        }
+    });
+
+    (format!("http://{}", addr), rx)
+}
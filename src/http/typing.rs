@@ -1,6 +1,7 @@
 use crate::{error::Result, http::Http};
 use std::sync::Arc;
-use tokio::{sync::oneshot::{self, Sender, error::TryRecvError}, time::{delay_for, Duration}};
+use tokio::{sync::oneshot::{self, Sender}, time::{timeout, Duration}};
+use tracing::warn;
 
 /// A struct to start typing in a [`Channel`] for an indefinite period of time.
 ///
@@ -9,7 +10,12 @@ use tokio::{sync::oneshot::{self, Sender, error::TryRecvError}, time::{delay_for
 /// Typing is started by using the [`Typing::start`] method
 /// and stopped by using the [`Typing::stop`] method.
 /// Note that on some clients, typing may persist for a few seconds after `stop` is called.
-/// Typing is also stopped when the struct is dropped.
+/// Typing is also stopped when the struct is dropped, so it can be held as a
+/// guard for the duration of a command.
+///
+/// While typing, the typing endpoint is triggered again every 7 seconds on a
+/// background task. The task ends as soon as typing is stopped, or if
+/// triggering typing fails, e.g. due to missing permissions.
 ///
 /// If a message is sent while typing is triggered, the user will stop typing for a brief period
 /// of time and then resume again until either `stop` is called or the struct is dropped.
@@ -60,19 +66,21 @@ impl Typing {
 
         tokio::spawn(async move {
             loop {
-                match rx.try_recv() {
-                    Ok(_) | Err(TryRecvError::Closed) => break,
-                    _ => (),
-                }
+                if let Err(why) = http.broadcast_typing(channel_id).await {
+                    warn!("Failed to trigger typing in channel {}: {:?}", channel_id, why);
 
-                http.broadcast_typing(channel_id).await?;
+                    break;
+                }
 
                 // It is unclear for how long typing persists after this method is called.
                 // It is generally assumed to be 7 or 10 seconds, so we use 7 to be safe.
-                delay_for(Duration::from_secs(7)).await;
+                //
+                // The receiver completes once typing is stopped or the
+                // sender is dropped, ending the task right away.
+                if timeout(Duration::from_secs(7), &mut rx).await.is_ok() {
+                    break;
+                }
             }
-
-            Result::Ok(())
         });
 
         Ok(Self(sx))
@@ -0,0 +1,275 @@
+//! Helpers for receiving interactions over HTTP, instead of over the gateway.
+//!
+//! When an application sets an interactions endpoint URL, Discord sends every
+//! interaction to it as a POST request, expecting the response to the
+//! interaction as the response to the request. This allows bots without a
+//! gateway connection, such as serverless functions.
+//!
+//! Every request must be verified with a [`Verifier`] before being handled,
+//! as Discord checks that requests with invalid signatures are rejected.
+//!
+//! # Examples
+//!
+//! Handle the body of a request, returning the status and body of the
+//! response, with the web framework of your choice:
+//!
+//! ```rust,no_run
+//! use serenity::interactions_endpoint::{parse_interaction, response_body, Verifier};
+//! use serenity::model::interactions::{InteractionResponseType, InteractionType};
+//!
+//! fn handle(verifier: &Verifier, signature: &str, timestamp: &str, body: &[u8]) -> (u16, Vec<u8>) {
+//!     if verifier.verify(signature, timestamp, body).is_err() {
+//!         return (401, Vec::new());
+//!     }
+//!
+//!     let interaction = match parse_interaction(body) {
+//!         Ok(interaction) => interaction,
+//!         Err(_) => return (400, Vec::new()),
+//!     };
+//!
+//!     let body = match interaction.kind {
+//!         InteractionType::Ping => response_body(|r| r.kind(InteractionResponseType::Pong)),
+//!         _ => response_body(|r| r
+//!             .kind(InteractionResponseType::ChannelMessageWithSource)
+//!             .interaction_response_data(|d| d.content("Pong!"))),
+//!     };
+//!
+//!     match body {
+//!         Ok(body) => (200, body),
+//!         Err(_) => (500, Vec::new()),
+//!     }
+//! }
+//!
+//! let verifier = Verifier::new("67c6bd767ca099e79efac9fcce4d2022a63bf7dea780e7f3d813f694c1597089")
+//!     .expect("Invalid public key");
+//! ```
+//!
+//! [`Verifier`]: struct.Verifier.html
+
+use crate::internal::prelude::*;
+use crate::model::interactions::Interaction;
+use ed25519_dalek::{PublicKey, Signature, Verifier as _};
+use std::{
+    convert::TryFrom,
+    error::Error as StdError,
+    fmt,
+};
+
+#[cfg(feature = "builder")]
+use crate::builder::CreateInteractionResponse;
+#[cfg(feature = "builder")]
+use crate::utils;
+
+/// An error verifying the signature of a request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// The public key is not a valid hex-encoded Ed25519 public key.
+    InvalidPublicKey,
+    /// The signature is not valid hex, or does not match the request.
+    InvalidSignature,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::InvalidPublicKey => f.write_str("Invalid public key."),
+            VerifyError::InvalidSignature => f.write_str("Invalid request signature."),
+        }
+    }
+}
+
+impl StdError for VerifyError {}
+
+/// Verifies that requests to the interactions endpoint were sent by Discord.
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    public_key: PublicKey,
+}
+
+impl Verifier {
+    /// Creates a verifier from the hex-encoded public key of the application,
+    /// shown in the developer portal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyError::InvalidPublicKey`] if the key is invalid.
+    ///
+    /// [`VerifyError::InvalidPublicKey`]: enum.VerifyError.html#variant.InvalidPublicKey
+    pub fn new(public_key: &str) -> StdResult<Self, VerifyError> {
+        let bytes = decode_hex(public_key).ok_or(VerifyError::InvalidPublicKey)?;
+        let public_key = PublicKey::from_bytes(&bytes).map_err(|_| VerifyError::InvalidPublicKey)?;
+
+        Ok(Verifier {
+            public_key,
+        })
+    }
+
+    /// Verifies a request with the values of its `X-Signature-Ed25519` and
+    /// `X-Signature-Timestamp` headers, and its body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyError::InvalidSignature`] if the request must be
+    /// rejected.
+    ///
+    /// [`VerifyError::InvalidSignature`]: enum.VerifyError.html#variant.InvalidSignature
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> StdResult<(), VerifyError> {
+        let bytes = decode_hex(signature).ok_or(VerifyError::InvalidSignature)?;
+        let signature = Signature::try_from(&bytes[..]).map_err(|_| VerifyError::InvalidSignature)?;
+
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+
+        self.public_key
+            .verify(&message, &signature)
+            .map_err(|_| VerifyError::InvalidSignature)
+    }
+}
+
+/// Parses the body of a verified request into an [`Interaction`], the same
+/// as received over the gateway.
+///
+/// [`Interaction`]: ../model/interactions/struct.Interaction.html
+pub fn parse_interaction(body: &[u8]) -> Result<Interaction> {
+    serde_json::from_slice(body).map_err(From::from)
+}
+
+/// Serializes the response to an interaction, to be sent as the body of the
+/// response to the request with the `Content-Type` `application/json`.
+///
+/// Refer to [`Interaction::create_interaction_response`] for building the
+/// response.
+///
+/// **Note**: Files can not be attached to responses sent this way. Respond
+/// with a deferred response and send the files with the [`Http`] client
+/// instead.
+///
+/// [`Http`]: ../http/client/struct.Http.html
+/// [`Interaction::create_interaction_response`]: ../model/interactions/struct.Interaction.html#method.create_interaction_response
+#[cfg(feature = "builder")]
+pub fn response_body<F>(f: F) -> Result<Vec<u8>>
+where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse {
+    let mut response = CreateInteractionResponse::default();
+    f(&mut response);
+    let map = utils::hashmap_to_json_map(response.0);
+
+    serde_json::to_vec(&map).map_err(From::from)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => Some((hex_digit(*high)? << 4) | hex_digit(*low)?),
+            _ => None,
+        })
+        .collect()
+}
+
+fn hex_digit(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_hex, parse_interaction, Verifier, VerifyError};
+    use crate::model::interactions::{InteractionData, InteractionType};
+
+    // The public key and the signature of an empty message of the first test
+    // vector of RFC 8032.
+    const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    #[test]
+    fn decodes_hex() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0, 255, 16]));
+        assert_eq!(decode_hex("0"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let verifier = Verifier::new(PUBLIC_KEY).unwrap();
+
+        assert_eq!(verifier.verify(SIGNATURE, "", b""), Ok(()));
+        assert_eq!(verifier.verify(SIGNATURE, "1600000000", b""), Err(VerifyError::InvalidSignature));
+    }
+
+    #[test]
+    fn parses_pings() {
+        // Discord validates the endpoint with a ping, carrying neither a user
+        // nor a member.
+        let body = br#"{
+            "id": "1032987236497252412",
+            "application_id": "1023994284637696094",
+            "type": 1,
+            "token": "token",
+            "version": 1
+        }"#;
+        let interaction = parse_interaction(body).unwrap();
+
+        assert_eq!(interaction.kind, InteractionType::Ping);
+        assert!(interaction.user.is_none());
+    }
+
+    #[test]
+    fn parses_application_commands() {
+        let body = br#"{
+            "id": "1032987236497252413",
+            "application_id": "1023994284637696094",
+            "type": 2,
+            "data": {"id": "1023994284637696095", "name": "ping", "type": 1},
+            "channel_id": "381880193700069377",
+            "user": {"id": "80351110224678912", "username": "Nelly", "discriminator": "1337", "avatar": null},
+            "token": "token",
+            "version": 1
+        }"#;
+        let interaction = parse_interaction(body).unwrap();
+
+        assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+        assert_eq!(interaction.user.unwrap().name, "Nelly");
+
+        match interaction.data {
+            Some(InteractionData::ApplicationCommand(data)) => assert_eq!(data.name, "ping"),
+            _ => panic!("expected application command data"),
+        }
+    }
+
+    #[cfg(feature = "builder")]
+    #[test]
+    fn serializes_responses() {
+        use super::response_body;
+        use crate::model::interactions::InteractionResponseType;
+        use serde_json::{json, Value};
+
+        let pong = response_body(|r| r.kind(InteractionResponseType::Pong)).unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&pong).unwrap(), json!({"type": 1}));
+
+        let message = response_body(|r| r
+            .kind(InteractionResponseType::ChannelMessageWithSource)
+            .interaction_response_data(|d| d.content("Pong!"))).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&message).unwrap(),
+            json!({"type": 4, "data": {"content": "Pong!"}}),
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        assert_eq!(Verifier::new("abc").unwrap_err(), VerifyError::InvalidPublicKey);
+
+        let verifier = Verifier::new(PUBLIC_KEY).unwrap();
+        let signature = "0".repeat(128);
+
+        assert_eq!(
+            verifier.verify(&signature, "1600000000", br#"{"type":1}"#),
+            Err(VerifyError::InvalidSignature),
+        );
+        assert_eq!(
+            verifier.verify("not hex", "1600000000", br#"{"type":1}"#),
+            Err(VerifyError::InvalidSignature),
+        );
+    }
+}
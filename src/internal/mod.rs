@@ -5,3 +5,16 @@ pub mod prelude;
 
 #[cfg(feature = "gateway")]
 pub mod ws_impl;
+
+/// A random number between 0 and 1, good enough for jitter.
+#[cfg(any(feature = "gateway", feature = "http"))]
+pub(crate) fn random() -> f64 {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+
+    let random = RandomState::new().build_hasher().finish();
+
+    random as f64 / u64::MAX as f64
+}
@@ -1,4 +1,4 @@
-use flate2::read::ZlibDecoder;
+use flate2::{read::ZlibDecoder, Decompress, FlushDecompress};
 use crate::gateway::{GatewayError, WsStream};
 use crate::internal::prelude::*;
 use async_tungstenite::tungstenite::Message;
@@ -17,9 +17,13 @@ use std::{
     },
     io::Error as IoError,
 };
+use std::io::{Error as StdIoError, ErrorKind};
 use url::Url;
 use futures::stream::SplitSink;
 
+/// The suffix of a `zlib-stream` message marking the end of a payload.
+const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+
 #[async_trait]
 pub trait ReceiverExt {
     async fn recv_json(&mut self) -> Result<Option<Value>>;
@@ -34,15 +38,7 @@ pub trait SenderExt {
 #[async_trait]
 impl ReceiverExt for WsStream {
     async fn recv_json(&mut self) -> Result<Option<Value>> {
-        const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
-
-        let ws_message = match timeout(TIMEOUT, self.next()).await {
-            Ok(Some(Ok(v))) => Some(v),
-            Ok(Some(Err(e))) => return Err(e.into()),
-            Ok(None) | Err(_) => None,
-        };
-
-        convert_ws_message(ws_message)
+        convert_ws_message(recv_message(self).await?)
     }
 
     async fn try_recv_json(&mut self) -> Result<Option<Value>> {
@@ -50,6 +46,90 @@ impl ReceiverExt for WsStream {
     }
 }
 
+/// Receives a payload from a connection using `zlib-stream` transport
+/// compression.
+pub(crate) async fn recv_inflated_json(
+    stream: &mut WsStream,
+    inflater: &mut ZlibStreamInflater,
+) -> Result<Option<Value>> {
+    match recv_message(stream).await? {
+        Some(Message::Binary(bytes)) => inflater.inflate(&bytes),
+        message => convert_ws_message(message),
+    }
+}
+
+async fn recv_message(stream: &mut WsStream) -> Result<Option<Message>> {
+    const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
+
+    match timeout(TIMEOUT, stream.next()).await {
+        Ok(Some(Ok(v))) => Ok(Some(v)),
+        Ok(Some(Err(e))) => Err(e.into()),
+        Ok(None) | Err(_) => Ok(None),
+    }
+}
+
+/// Decompresses the payloads of a connection using `zlib-stream` transport
+/// compression, which share a single zlib context and may be split over
+/// multiple messages.
+///
+/// A new inflater must be used for every connection.
+#[derive(Debug)]
+pub(crate) struct ZlibStreamInflater {
+    decompress: Decompress,
+    compressed: Vec<u8>,
+    buffer: Vec<u8>,
+}
+
+impl ZlibStreamInflater {
+    pub(crate) fn new() -> Self {
+        ZlibStreamInflater {
+            decompress: Decompress::new(true),
+            compressed: Vec::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Adds a received message, returning the payload if the message completed
+    /// it.
+    pub(crate) fn inflate(&mut self, bytes: &[u8]) -> Result<Option<Value>> {
+        self.compressed.extend_from_slice(bytes);
+
+        if !self.compressed.ends_with(&ZLIB_SUFFIX) {
+            return Ok(None);
+        }
+
+        self.buffer.clear();
+        let mut input = &self.compressed[..];
+
+        loop {
+            if self.buffer.len() == self.buffer.capacity() {
+                self.buffer.reserve((input.len() * 4).max(1024));
+            }
+
+            let total_in = self.decompress.total_in();
+
+            self.decompress
+                .decompress_vec(input, &mut self.buffer, FlushDecompress::Sync)
+                .map_err(|why| StdIoError::new(ErrorKind::InvalidData, why))?;
+
+            input = &input[(self.decompress.total_in() - total_in) as usize..];
+
+            // All output was written once the buffer has space left over.
+            if input.is_empty() && self.buffer.len() < self.buffer.capacity() {
+                break;
+            }
+        }
+
+        self.compressed.clear();
+
+        serde_json::from_slice(&self.buffer).map(Some).map_err(|why| {
+            warn!("Err deserializing inflated bytes: {:?}", why);
+
+            why.into()
+        })
+    }
+}
+
 #[async_trait]
 impl SenderExt for SplitSink<WsStream, Message> {
     async fn send_json(&mut self, value: &Value) -> Result<()> {
@@ -174,3 +254,31 @@ pub(crate) async fn create_native_tls_client(url: Url) -> Result<WsStream> {
 
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ZlibStreamInflater;
+    use flate2::{Compress, Compression, FlushCompress};
+    use serde_json::json;
+
+    fn compress(compress: &mut Compress, payload: &str) -> Vec<u8> {
+        let mut output = Vec::with_capacity(payload.len() + 64);
+        compress.compress_vec(payload.as_bytes(), &mut output, FlushCompress::Sync).unwrap();
+
+        output
+    }
+
+    #[test]
+    fn inflates_split_payloads() {
+        let mut compressor = Compress::new(Compression::default(), true);
+        let mut inflater = ZlibStreamInflater::new();
+
+        let first = compress(&mut compressor, r#"{"op":10,"d":{"heartbeat_interval":41250}}"#);
+        let (start, end) = first.split_at(first.len() / 2);
+        assert!(inflater.inflate(start).unwrap().is_none());
+        assert_eq!(inflater.inflate(end).unwrap(), Some(json!({"op": 10, "d": {"heartbeat_interval": 41250}})));
+
+        let second = compress(&mut compressor, r#"{"op":11}"#);
+        assert_eq!(inflater.inflate(&second).unwrap(), Some(json!({"op": 11})));
+    }
+}
@@ -78,10 +78,14 @@ pub mod framework;
 pub mod gateway;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "interactions_endpoint")]
+pub mod interactions_endpoint;
 #[cfg(feature = "utils")]
 pub mod utils;
 #[cfg(feature = "collector")]
 pub mod collector;
+#[cfg(feature = "voice-driver")]
+pub mod voice;
 
 mod error;
 
@@ -109,6 +113,8 @@ pub struct CacheAndHttp {
     #[cfg(feature = "cache")]
     pub update_cache_timeout: Option<Duration>,
     pub http: Arc<Http>,
+    #[cfg(feature = "gateway")]
+    pub(crate) in_flight: crate::client::InFlight,
 }
 
 // For the procedural macros in `command_attr`.
@@ -1,11 +1,21 @@
 //! Models about OAuth2 applications.
 
+use chrono::{DateTime, Utc};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use super::{
-    id::UserId,
+    id::{ApplicationId, UserId},
     user::User,
     utils::*,
 };
-use std::fmt;
+use std::{collections::HashMap, fmt, result::Result as StdResult, str::FromStr};
+
+#[cfg(feature = "model")]
+use crate::builder::CreateApplicationRoleConnectionMetadataRecords;
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
 
 /// Information about a user's application. An application does not necessarily
 /// have an associated bot user.
@@ -170,3 +180,318 @@ enum_number!(
         Accepted,
     }
 );
+
+/// A scope of an OAuth2 authorization, granting an application access to parts
+/// of a user's account, or to the guild it is added to.
+///
+/// Refer to [Discord's documentation] for the access each scope grants.
+///
+/// [Discord's documentation]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OAuth2Scope {
+    /// Adds the application's bot user to a guild.
+    Bot,
+    /// Allows the application to create commands in a guild.
+    ApplicationsCommands,
+    /// Allows access to the user, without their email.
+    Identify,
+    /// Allows access to the email of the user.
+    Email,
+    /// Allows access to the third-party accounts linked by the user.
+    Connections,
+    /// Allows access to the guilds the user is a member of.
+    Guilds,
+    /// Allows the bot to add the user to guilds.
+    GuildsJoin,
+    /// Allows the bot to add the user to group DMs.
+    GdmJoin,
+    /// Allows access to the messages of the user over RPC.
+    MessagesRead,
+    /// Creates a webhook in a channel picked by the user.
+    WebhookIncoming,
+    /// A scope unknown to the library.
+    Unknown,
+}
+
+impl OAuth2Scope {
+    /// The name of the scope as used by Discord, empty for an unknown one.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuth2Scope::Bot => "bot",
+            OAuth2Scope::ApplicationsCommands => "applications.commands",
+            OAuth2Scope::Identify => "identify",
+            OAuth2Scope::Email => "email",
+            OAuth2Scope::Connections => "connections",
+            OAuth2Scope::Guilds => "guilds",
+            OAuth2Scope::GuildsJoin => "guilds.join",
+            OAuth2Scope::GdmJoin => "gdm.join",
+            OAuth2Scope::MessagesRead => "messages.read",
+            OAuth2Scope::WebhookIncoming => "webhook.incoming",
+            OAuth2Scope::Unknown => "",
+        }
+    }
+}
+
+impl FromStr for OAuth2Scope {
+    type Err = ();
+
+    /// Parses the name of a scope, resulting in [`Unknown`] for any scope
+    /// unknown to the library.
+    ///
+    /// [`Unknown`]: #variant.Unknown
+    fn from_str(s: &str) -> StdResult<Self, ()> {
+        Ok(match s {
+            "bot" => OAuth2Scope::Bot,
+            "applications.commands" => OAuth2Scope::ApplicationsCommands,
+            "identify" => OAuth2Scope::Identify,
+            "email" => OAuth2Scope::Email,
+            "connections" => OAuth2Scope::Connections,
+            "guilds" => OAuth2Scope::Guilds,
+            "guilds.join" => OAuth2Scope::GuildsJoin,
+            "gdm.join" => OAuth2Scope::GdmJoin,
+            "messages.read" => OAuth2Scope::MessagesRead,
+            "webhook.incoming" => OAuth2Scope::WebhookIncoming,
+            _ => OAuth2Scope::Unknown,
+        })
+    }
+}
+
+impl fmt::Display for OAuth2Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuth2Scope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(name.parse().unwrap_or(OAuth2Scope::Unknown))
+    }
+}
+
+impl Serialize for OAuth2Scope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// An OAuth2 access token granted to an application on behalf of a user.
+#[derive(Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AccessToken {
+    /// The token to authenticate requests on behalf of the user with.
+    ///
+    /// **Note**: Keep this information private, as untrusted sources can use
+    /// it to access the user's account within the granted scopes.
+    pub access_token: String,
+    /// The type of the token, always `Bearer`.
+    pub token_type: String,
+    /// The number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// The token to get a new access token with once it expires.
+    pub refresh_token: String,
+    /// The granted scopes, separated by spaces.
+    pub scope: String,
+}
+
+impl AccessToken {
+    /// The granted scopes.
+    pub fn scopes(&self) -> Vec<OAuth2Scope> {
+        self.scope
+            .split_whitespace()
+            .map(|scope| scope.parse().unwrap_or(OAuth2Scope::Unknown))
+            .collect()
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+/// Information about the authorization of an [`AccessToken`], retrieved with
+/// [`Http::get_current_authorization_info`].
+///
+/// [`AccessToken`]: struct.AccessToken.html
+/// [`Http::get_current_authorization_info`]: ../../http/client/struct.Http.html#method.get_current_authorization_info
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CurrentAuthorizationInfo {
+    /// The application the authorization was granted to.
+    pub application: PartialApplicationInfo,
+    /// The scopes the user granted.
+    pub scopes: Vec<OAuth2Scope>,
+    /// When the access token expires.
+    pub expires: DateTime<Utc>,
+    /// The user who granted the authorization, if the [`Identify`] scope was
+    /// granted.
+    ///
+    /// [`Identify`]: enum.OAuth2Scope.html#variant.Identify
+    pub user: Option<User>,
+}
+
+/// The public information about an application.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PartialApplicationInfo {
+    /// The Id of the application.
+    pub id: ApplicationId,
+    /// The name assigned to the application by the application owner.
+    pub name: String,
+    /// A hash pointing to the application's icon.
+    pub icon: Option<String>,
+    /// A description of the application, assigned by the application owner.
+    #[serde(default)]
+    pub description: String,
+    /// Indicator of whether anyone may add the application's bot to a guild.
+    #[serde(default)]
+    pub bot_public: bool,
+    /// Indicator of whether the bot requires an OAuth2 code grant.
+    #[serde(default)]
+    pub bot_require_code_grant: bool,
+}
+
+/// A metadata record of an application, which the role connection of a user
+/// is compared against to grant them a linked role in a guild.
+///
+/// Refer to Discord's [documentation] for more information.
+///
+/// [documentation]: https://discord.com/developers/docs/resources/application-role-connection-metadata
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnectionMetadata {
+    /// How the value of the user's role connection is compared.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// The key of the value in the user's role connection metadata.
+    pub key: String,
+    /// The name of the record.
+    pub name: String,
+    /// The localized names of the record, keyed by locale.
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The description of the record.
+    pub description: String,
+    /// The localized descriptions of the record, keyed by locale.
+    pub description_localizations: Option<HashMap<String, String>>,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationRoleConnectionMetadata {
+    /// Gets the metadata records of the application.
+    #[inline]
+    pub async fn get_records(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        http.as_ref().get_application_role_connection_metadata_records(application_id.into().0).await
+    }
+
+    /// Overwrites the metadata records of the application with the given
+    /// records, at most 5.
+    ///
+    /// Records that are not given are deleted.
+    ///
+    /// # Examples
+    ///
+    /// Grant a linked role to users who have played at least 10 games:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::model::application::{
+    ///     ApplicationRoleConnectionMetadata,
+    ///     ApplicationRoleConnectionMetadataType,
+    /// };
+    ///
+    /// ApplicationRoleConnectionMetadata::set_records(&http, 1, |r| {
+    ///     r.create_record(|m| {
+    ///         m.kind(ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual)
+    ///             .key("games_played")
+    ///             .name("Games played")
+    ///             .description("Games played in total")
+    ///     })
+    /// }).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn set_records<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>>
+    where F: FnOnce(&mut CreateApplicationRoleConnectionMetadataRecords) -> &mut CreateApplicationRoleConnectionMetadataRecords {
+        let mut records = CreateApplicationRoleConnectionMetadataRecords::default();
+        f(&mut records);
+
+        http.as_ref()
+            .edit_application_role_connection_metadata_records(application_id.into().0, &Value::Array(records.0))
+            .await
+    }
+}
+
+/// How the value of a user's role connection is compared against an
+/// [`ApplicationRoleConnectionMetadata`] record.
+///
+/// The value of the user is always on the left side of the comparison.
+///
+/// [`ApplicationRoleConnectionMetadata`]: struct.ApplicationRoleConnectionMetadata.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ApplicationRoleConnectionMetadataType {
+    /// The integer value is at most the record's value.
+    IntegerLessThanOrEqual = 1,
+    /// The integer value is at least the record's value.
+    IntegerGreaterThanOrEqual = 2,
+    /// The integer value is equal to the record's value.
+    IntegerEqual = 3,
+    /// The integer value is not equal to the record's value.
+    IntegerNotEqual = 4,
+    /// The ISO8601 timestamp value is at most the record's value in days
+    /// before now.
+    DatetimeLessThanOrEqual = 5,
+    /// The ISO8601 timestamp value is at least the record's value in days
+    /// before now.
+    DatetimeGreaterThanOrEqual = 6,
+    /// The boolean value is equal to the record's value.
+    BooleanEqual = 7,
+    /// The boolean value is not equal to the record's value.
+    BooleanNotEqual = 8,
+}
+
+enum_number!(
+    ApplicationRoleConnectionMetadataType {
+        IntegerLessThanOrEqual,
+        IntegerGreaterThanOrEqual,
+        IntegerEqual,
+        IntegerNotEqual,
+        DatetimeLessThanOrEqual,
+        DatetimeGreaterThanOrEqual,
+        BooleanEqual,
+        BooleanNotEqual,
+    }
+);
+
+impl ApplicationRoleConnectionMetadataType {
+    pub fn num(self) -> u64 {
+        match self {
+            ApplicationRoleConnectionMetadataType::IntegerLessThanOrEqual => 1,
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual => 2,
+            ApplicationRoleConnectionMetadataType::IntegerEqual => 3,
+            ApplicationRoleConnectionMetadataType::IntegerNotEqual => 4,
+            ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual => 5,
+            ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual => 6,
+            ApplicationRoleConnectionMetadataType::BooleanEqual => 7,
+            ApplicationRoleConnectionMetadataType::BooleanNotEqual => 8,
+        }
+    }
+}
@@ -6,8 +6,11 @@ use std::fmt::Write as FmtWrite;
 use crate::builder::{
     CreateInvite,
     CreateMessage,
+    CreateStageInstance,
+    CreateThread,
     EditChannel,
     EditMessage,
+    EditStageInstance,
     GetMessages
 };
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -21,6 +24,8 @@ use crate::http::{Http, CacheHttp, Typing};
 #[cfg(feature = "model")]
 use serde_json::json;
 #[cfg(feature = "model")]
+use chrono::{DateTime, SecondsFormat, Utc};
+#[cfg(feature = "model")]
 use std::sync::Arc;
 use futures::stream::Stream;
 #[cfg(feature = "collector")]
@@ -33,6 +38,56 @@ use crate::collector::{
 
 #[cfg(feature = "model")]
 impl ChannelId {
+    /// Adds a [`User`] to this thread.
+    ///
+    /// **Note**: The current user must be a member of the thread, which must
+    /// not be archived.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    #[inline]
+    pub async fn add_thread_member(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<()> {
+        http.as_ref().add_thread_channel_member(self.0, user_id.into().0).await
+    }
+
+    /// Gets the archived private threads of this channel, most recently
+    /// archived first.
+    ///
+    /// Pass `before` to only get threads archived before the given time.
+    ///
+    /// **Note**: Requires the [Read Message History] and Manage Threads
+    /// permissions.
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn archived_private_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<DateTime<Utc>>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        http.as_ref().get_archived_private_threads(self.0, before.as_deref(), limit).await
+    }
+
+    /// Gets the archived public threads of this channel, most recently
+    /// archived first.
+    ///
+    /// Pass `before` to only get threads archived before the given time.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn archived_public_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<DateTime<Utc>>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        http.as_ref().get_archived_public_threads(self.0, before.as_deref(), limit).await
+    }
+
     /// Broadcasts that the current user is typing to a channel for the next 5
     /// seconds.
     ///
@@ -126,12 +181,106 @@ impl ChannelId {
         http.as_ref().create_reaction(self.0, message_id.into().0, &reaction_type.into()).await
     }
 
+    /// Creates a [`StageInstance`] in this stage channel, starting a stage.
+    ///
+    /// Refer to [`CreateStageInstance`] for the available options.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`CreateStageInstance`]: ../../builder/struct.CreateStageInstance.html
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    #[cfg(feature = "utils")]
+    pub async fn create_stage_instance<F>(self, http: impl AsRef<Http>, f: F) -> Result<StageInstance>
+    where F: FnOnce(&mut CreateStageInstance) -> &mut CreateStageInstance
+    {
+        let mut instance = CreateStageInstance::default();
+        instance.channel_id(self);
+        f(&mut instance);
+
+        let map = utils::hashmap_to_json_map(instance.0);
+
+        http.as_ref().create_stage_instance(&Value::Object(map)).await
+    }
+
+    /// Creates a thread in this channel which is not attached to a message.
+    ///
+    /// Refer to [`CreateThread`] for the available options.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    #[cfg(feature = "utils")]
+    pub async fn create_thread<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        let mut thread = CreateThread::default();
+        f(&mut thread);
+
+        let map = utils::hashmap_to_json_map(thread.0);
+
+        http.as_ref().create_thread(self.0, &map).await
+    }
+
+    /// Creates a thread attached to a [`Message`] of this channel.
+    ///
+    /// Refer to [`CreateThread`] for the available options.
+    ///
+    /// [`CreateThread`]: ../../builder/struct.CreateThread.html
+    /// [`Message`]: ../channel/struct.Message.html
+    #[cfg(feature = "utils")]
+    pub async fn create_thread_from_message<F>(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        f: F,
+    ) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        let mut thread = CreateThread::default();
+        f(&mut thread);
+
+        let map = utils::hashmap_to_json_map(thread.0);
+
+        http.as_ref().create_thread_from_message(self.0, message_id.into().0, &map).await
+    }
+
+    /// Publishes a [`Message`] of this announcement channel to the channels
+    /// following it, returning the crossposted message.
+    ///
+    /// **Note**: Requires the [Send Messages] permission for messages of the
+    /// current user, or the [Manage Messages] permission otherwise.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub async fn crosspost(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<Message> {
+        http.as_ref().crosspost_message(self.0, message_id.into().0).await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[inline]
     pub async fn delete(self, http: impl AsRef<Http>) -> Result<Channel> {
         http.as_ref().delete_channel(self.0).await
     }
 
+    /// Deletes this channel with a reason shown in the audit log. Refer to
+    /// [`delete`] for further documentation.
+    ///
+    /// [`delete`]: #method.delete
+    #[inline]
+    pub async fn delete_with_reason(self, http: impl AsRef<Http>, reason: impl AsRef<str>) -> Result<Channel> {
+        http.as_ref().delete_channel_with_reason(self.0, reason.as_ref()).await
+    }
+
+    /// Deletes the [`StageInstance`] of this stage channel, ending the stage.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    #[inline]
+    pub async fn delete_stage_instance(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_stage_instance(self.0).await
+    }
+
     /// Deletes a [`Message`] given its Id.
     ///
     /// Refer to [`Message::delete`] for more information.
@@ -147,6 +296,21 @@ impl ChannelId {
         http.as_ref().delete_message(self.0, message_id.into().0).await
     }
 
+    /// Deletes a [`Message`] given its Id, with a reason shown in the audit
+    /// log. Refer to [`delete_message`] for further documentation.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`delete_message`]: #method.delete_message
+    #[inline]
+    pub async fn delete_message_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().delete_message_with_reason(self.0, message_id.into().0, reason.as_ref()).await
+    }
+
     /// Deletes all messages by Ids from the given vector in the given channel.
     ///
     /// The minimum amount of messages is 2 and the maximum amount is 100.
@@ -165,6 +329,28 @@ impl ChannelId {
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
     pub async fn delete_messages<T, It>(self, http: impl AsRef<Http>, message_ids: It) -> Result<()>
     where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
+    {
+        self._delete_messages(http.as_ref(), message_ids, "").await
+    }
+
+    /// Deletes all messages by Ids from the given vector in the given channel,
+    /// with a reason shown in the audit log. Refer to [`delete_messages`] for
+    /// further documentation.
+    ///
+    /// [`delete_messages`]: #method.delete_messages
+    pub async fn delete_messages_with_reason<T, It>(
+        self,
+        http: impl AsRef<Http>,
+        message_ids: It,
+        reason: impl AsRef<str>,
+    ) -> Result<()>
+    where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
+    {
+        self._delete_messages(http.as_ref(), message_ids, reason.as_ref()).await
+    }
+
+    async fn _delete_messages<T, It>(self, http: &Http, message_ids: It, reason: &str) -> Result<()>
+    where T: AsRef<MessageId>, It: IntoIterator<Item=T>,
     {
         let ids = message_ids
             .into_iter()
@@ -178,12 +364,104 @@ impl ChannelId {
         }
 
         if ids.len() == 1 {
-            self.delete_message(http, ids[0]).await
+            http.delete_message_with_reason(self.0, ids[0], reason).await
         } else {
             let map = json!({ "messages": ids });
 
-            http.as_ref().delete_messages(self.0, &map).await
+            http.delete_messages_with_reason(self.0, &map, reason).await
+        }
+    }
+
+    /// Deletes the messages matching the `filter` among up to `limit` of the
+    /// most recent messages in the channel, e.g. all recent messages of a user.
+    ///
+    /// Messages younger than 2 weeks are deleted in bulk, in batches of up to
+    /// 100 messages. Older messages can not be deleted in bulk, so they are
+    /// deleted one by one, which is considerably slower due to ratelimits.
+    ///
+    /// Returns how many of the examined messages were deleted and skipped.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// Delete the messages of a user among the last 500 messages:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, UserId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let user_id = UserId(7);
+    /// let purged = ChannelId(7).purge(&http, 500, |m| m.author.id == user_id).await?;
+    ///
+    /// println!("Deleted {} messages.", purged.deleted);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if fetching or deleting messages fails,
+    /// stopping at the first failure.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub async fn purge<F>(self, http: impl AsRef<Http>, limit: u64, mut filter: F) -> Result<PurgeResult>
+    where F: FnMut(&Message) -> bool
+    {
+        let http = http.as_ref();
+        // Leave a margin, as messages may become too old while purging.
+        let cutoff = Utc::now() - chrono::Duration::days(14) + chrono::Duration::minutes(5);
+
+        let mut result = PurgeResult::default();
+        let mut bulk = Vec::new();
+        let mut single = Vec::new();
+        let mut before = None;
+        let mut examined = 0;
+
+        while examined < limit {
+            let grab_size = std::cmp::min(limit - examined, 100);
+            let messages = self.messages(http, |b| {
+                if let Some(before) = before {
+                    b.before(before);
+                }
+
+                b.limit(grab_size)
+            }).await?;
+
+            before = messages.iter().map(|message| message.id).min();
+
+            for message in &messages {
+                examined += 1;
+
+                if !filter(message) {
+                    result.skipped += 1;
+                } else if message.id.created_at() > cutoff {
+                    bulk.push(message.id);
+                } else {
+                    single.push(message.id);
+                }
+            }
+
+            // There are no older messages left.
+            if (messages.len() as u64) < grab_size {
+                break;
+            }
+        }
+
+        for batch in bulk.chunks(100) {
+            self._delete_messages(http, batch, "").await?;
+            result.deleted += batch.len() as u64;
         }
+
+        for message_id in single {
+            http.delete_message(self.0, message_id.0).await?;
+            result.deleted += 1;
+        }
+
+        Ok(result)
     }
 
     /// Deletes all permission overrides in the channel from a member or role.
@@ -286,6 +564,23 @@ impl ChannelId {
         http.as_ref().edit_channel(self.0, &map).await
     }
 
+    /// Edits the settings of a [`Channel`] with a reason shown in the audit
+    /// log. Refer to [`edit`] for further documentation.
+    ///
+    /// [`Channel`]: ../channel/enum.Channel.html
+    /// [`edit`]: #method.edit
+    #[cfg(feature = "utils")]
+    pub async fn edit_with_reason<F>(self, http: impl AsRef<Http>, reason: impl AsRef<str>, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut EditChannel) -> &mut EditChannel
+    {
+        let mut channel = EditChannel::default();
+        f(&mut channel);
+
+        let map = utils::hashmap_to_json_map(channel.0);
+
+        http.as_ref().edit_channel_with_reason(self.0, &map, reason.as_ref()).await
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
     /// Message editing preserves all unchanged message data.
@@ -307,8 +602,8 @@ impl ChannelId {
     /// [`the limit`]: ../../builder/struct.EditMessage.html#method.content
     #[cfg(feature = "utils")]
     #[inline]
-    pub async fn edit_message<F>(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    pub async fn edit_message<'a, F>(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         let mut msg = EditMessage::default();
         f(&mut msg);
@@ -323,7 +618,41 @@ impl ChannelId {
 
         let map = utils::hashmap_to_json_map(msg.0);
 
-        http.as_ref().edit_message(self.0, message_id.into().0, &Value::Object(map)).await
+        if msg.1.is_empty() {
+            http.as_ref().edit_message(self.0, message_id.into().0, &Value::Object(map)).await
+        } else {
+            http.as_ref().edit_message_and_attachments(self.0, message_id.into().0, map, msg.1).await
+        }
+    }
+
+    /// Edits the [`StageInstance`] of this stage channel.
+    ///
+    /// Refer to [`EditStageInstance`] for the available options.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    ///
+    /// [`EditStageInstance`]: ../../builder/struct.EditStageInstance.html
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    #[cfg(feature = "utils")]
+    pub async fn edit_stage_instance<F>(self, http: impl AsRef<Http>, f: F) -> Result<StageInstance>
+    where F: FnOnce(&mut EditStageInstance) -> &mut EditStageInstance
+    {
+        let mut instance = EditStageInstance::default();
+        f(&mut instance);
+
+        let map = utils::hashmap_to_json_map(instance.0);
+
+        http.as_ref().edit_stage_instance(self.0, &Value::Object(map)).await
+    }
+
+    /// Immediately ends the [`Poll`] of a [`Message`] sent by the current
+    /// user, returning the message with the final results.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`Poll`]: ../channel/struct.Poll.html
+    #[inline]
+    pub async fn end_poll(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<Message> {
+        http.as_ref().end_poll(self.0, message_id.into().0).await
     }
 
     /// Attempts to find a [`Channel`] by its Id in the cache.
@@ -366,6 +695,36 @@ impl ChannelId {
         http.as_ref().get_channel_invites(self.0).await
     }
 
+    /// Joins this thread as the current user.
+    #[inline]
+    pub async fn join_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().join_thread_channel(self.0).await
+    }
+
+    /// Gets the archived private threads of this channel the current user has
+    /// joined, most recently joined first.
+    ///
+    /// Pass `before` to only get threads before the given thread.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[inline]
+    pub async fn joined_archived_private_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<ChannelId>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        http.as_ref().get_joined_archived_private_threads(self.0, before.map(|id| id.0), limit).await
+    }
+
+    /// Leaves this thread as the current user.
+    #[inline]
+    pub async fn leave_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().leave_thread_channel(self.0).await
+    }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -384,6 +743,35 @@ impl ChannelId {
             })
     }
 
+    /// First attempts to find a [`Message`] of the channel in the cache, upon
+    /// failure requests it via the REST API.
+    ///
+    /// Requires the [Read Message History] permission if the message is not
+    /// cached.
+    ///
+    /// **Note**: If the cache is not enabled, the REST API will be used only.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub async fn message_cached_or_fetch(
+        self,
+        cache_http: impl CacheHttp,
+        message_id: impl Into<MessageId>,
+    ) -> Result<Message> {
+        let message_id = message_id.into();
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(message) = cache.message(self, message_id).await {
+                    return Ok(message);
+                }
+            }
+        }
+
+        self.message(cache_http.http(), message_id).await
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to [`GetMessages`] for more information on how to use `builder`.
@@ -490,6 +878,37 @@ impl ChannelId {
         http.as_ref().get_pins(self.0).await
     }
 
+    /// Gets the list of [`User`]s who voted for an answer of the [`Poll`] of
+    /// a [`Message`].
+    ///
+    /// The default `limit` is `25`, the maximum that may be retrieved at a
+    /// time is `100`.
+    ///
+    /// The optional `after` attribute is to retrieve the users after a certain
+    /// user. This is useful for pagination.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`Poll`]: ../channel/struct.Poll.html
+    /// [`User`]: ../user/struct.User.html
+    pub async fn poll_answer_voters(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        answer_id: u64,
+        after: impl Into<Option<UserId>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<User>> {
+        let limit = limit.map(|x| if x > 100 { 100 } else { x });
+
+        http.as_ref().get_poll_answer_voters(
+            self.0,
+            message_id.into().0,
+            answer_id,
+            after.into().map(|x| x.0),
+            limit,
+        ).await
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -524,6 +943,62 @@ impl ChannelId {
         ).await
     }
 
+    /// Streams over all the [`User`]s that reacted to a [`Message`] with the
+    /// given emoji.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`reaction_users`]. A buffer of at most 100 users is used to reduce the
+    /// number of calls necessary.
+    ///
+    /// The stream returns the users in the order of their Ids.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let channel_id = ChannelId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut users = channel_id.reaction_users_iter(&ctx, MessageId(1), '👍').boxed();
+    /// while let Some(user_result) = users.next().await {
+    ///     match user_result {
+    ///         Ok(user) => println!("{} agrees.", user.name),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`User`]: ../user/struct.User.html
+    /// [`reaction_users`]: #method.reaction_users
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub fn reaction_users_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+    ) -> impl Stream<Item=Result<User>> {
+        ReactionUsersIter::<H>::stream(http, self, message_id.into(), reaction_type.into())
+    }
+
+    /// Removes a [`User`] from this thread.
+    ///
+    /// **Note**: Requires the Manage Threads permission, or the current user
+    /// must be the creator of the private thread.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    #[inline]
+    pub async fn remove_thread_member(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<()> {
+        http.as_ref().remove_thread_channel_member(self.0, user_id.into().0).await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -629,10 +1104,6 @@ impl ChannelId {
             }
         }
 
-        if let Some(e) = msg.0.remove(&"embed") {
-            msg.0.insert("payload_json", json!({ "embed": e }));
-        }
-
         let map = utils::hashmap_to_json_map(msg.0.clone());
         http.as_ref().send_files(self.0, files, map).await
     }
@@ -662,16 +1133,6 @@ impl ChannelId {
         let mut create_message = CreateMessage::default();
         let msg = f(&mut create_message);
 
-        if !msg.2.is_empty() {
-            if let Some(e) = msg.0.remove(&"embed") {
-                if let Some(c) = msg.0.remove(&"content") {
-                    msg.0.insert("payload_json", json!({ "content": c, "embed": e }));
-                } else {
-                    msg.0.insert("payload_json", json!({ "embed": e }));
-                }
-            }
-        }
-
         let map = utils::hashmap_to_json_map(msg.0.clone());
 
         Message::check_content_length(&map)?;
@@ -692,6 +1153,14 @@ impl ChannelId {
         Ok(message)
     }
 
+    /// Gets the [`StageInstance`] of this stage channel, if it is live.
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    #[inline]
+    pub async fn stage_instance(self, http: impl AsRef<Http>) -> Result<StageInstance> {
+        http.as_ref().get_stage_instance(self.0).await
+    }
+
     /// Starts typing in the channel for an indefinite period of time.
     ///
     /// Returns [`Typing`] that is used to trigger the typing. [`Typing::stop`] must be called
@@ -733,6 +1202,14 @@ impl ChannelId {
         http.start_typing(self.0)
     }
 
+    /// Gets the members of this thread.
+    ///
+    /// **Note**: Requires the `GUILD_MEMBERS` gateway intent.
+    #[inline]
+    pub async fn thread_members(self, http: impl AsRef<Http>) -> Result<Vec<ThreadMember>> {
+        http.as_ref().get_thread_channel_members(self.0).await
+    }
+
     /// Unpins a [`Message`] in the channel given by its Id.
     ///
     /// Requires the [Manage Messages] permission.
@@ -922,3 +1399,110 @@ impl<H: AsRef<Http>> MessagesIter<H> {
         })
     }
 }
+
+/// The number of messages deleted and skipped by [`ChannelId::purge`].
+///
+/// [`ChannelId::purge`]: ../id/struct.ChannelId.html#method.purge
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[cfg(feature = "model")]
+pub struct PurgeResult {
+    /// The number of messages deleted.
+    pub deleted: u64,
+    /// The number of examined messages not matching the filter.
+    pub skipped: u64,
+}
+
+/// A helper class returned by [`ChannelId::reaction_users_iter`]
+///
+/// [`ChannelId::reaction_users_iter`]: ../id/struct.ChannelId.html#method.reaction_users_iter
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ReactionUsersIter<H: AsRef<Http>> {
+    channel_id: ChannelId,
+    message_id: MessageId,
+    reaction_type: ReactionType,
+    http: H,
+    buffer: Vec<User>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> ReactionUsersIter<H> {
+    fn new(http: H, channel_id: ChannelId, message_id: MessageId, reaction_type: ReactionType) -> ReactionUsersIter<H> {
+        ReactionUsersIter {
+            channel_id,
+            message_id,
+            reaction_type,
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of users.
+    ///
+    /// This drops any users that were currently in the buffer, so it should
+    /// only be called when `self.buffer` is empty. Additionally, this updates
+    /// `self.after` so that the next call does not return duplicate items.
+    /// If there are no more users to be fetched, then this marks
+    /// `self.after` as None, indicating that no more calls ought to be made.
+    ///
+    /// The users are sorted such that the user with the highest Id is the
+    /// first element of the buffer, to be popped last.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of users to fetch.
+        let grab_size = 100;
+
+        self.buffer = self.channel_id.reaction_users(
+            &self.http,
+            self.message_id,
+            self.reaction_type.clone(),
+            Some(grab_size),
+            self.after,
+        ).await?;
+
+        // Fewer users than requested are only returned on the last page.
+        self.after = if self.buffer.len() == usize::from(grab_size) {
+            self.buffer.last().map(|user| user.id)
+        } else {
+            None
+        };
+
+        self.buffer.reverse();
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the users that reacted to a message with the given
+    /// emoji.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`reaction_users`]. A buffer of at most 100 users is used to reduce the
+    /// number of calls necessary.
+    ///
+    /// The stream returns the users in the order of their Ids.
+    ///
+    /// [`reaction_users`]: ../id/struct.ChannelId.html#method.reaction_users
+    pub fn stream(
+        http: impl AsRef<Http>,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        reaction_type: ReactionType,
+    ) -> impl Stream<Item=Result<User>> {
+        let init_state = ReactionUsersIter::new(http, channel_id, message_id, reaction_type);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
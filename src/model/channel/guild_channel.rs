@@ -84,13 +84,20 @@ pub struct GuildChannel {
     pub name: String,
     /// Permission overwrites for [`Member`]s and for [`Role`]s.
     ///
+    /// **Note**: Threads have no permission overwrites, they inherit those of
+    /// their parent channel.
+    ///
     /// [`Member`]: ../guild/struct.Member.html
     /// [`Role`]: ../guild/struct.Role.html
+    #[serde(default)]
     pub permission_overwrites: Vec<PermissionOverwrite>,
     /// The position of the channel.
     ///
     /// The default text channel will _almost always_ have a position of `-1` or
     /// `0`.
+    ///
+    /// **Note**: Threads have no position and default to `0`.
+    #[serde(default)]
     pub position: i64,
     /// The topic of the channel.
     ///
@@ -114,6 +121,38 @@ pub struct GuildChannel {
     /// channels.
     #[serde(default, rename = "rate_limit_per_user")]
     pub slow_mode_rate: Option<u64>,
+    /// The thread specific data.
+    ///
+    /// **Note**: This is only available for threads.
+    pub thread_metadata: Option<ThreadMetadata>,
+    /// The current user's member of the thread, if it has joined the thread.
+    ///
+    /// **Note**: This is only available for threads, and only present in
+    /// some responses.
+    pub member: Option<ThreadMember>,
+    /// The Id of the user who created the thread.
+    ///
+    /// **Note**: This is only available for threads.
+    pub owner_id: Option<UserId>,
+    /// An approximate count of messages in the thread, stops counting at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    pub message_count: Option<u64>,
+    /// An approximate count of members in the thread, stops counting at 50.
+    ///
+    /// **Note**: This is only available for threads.
+    pub member_count: Option<u64>,
+    /// The Id of the [`VoiceRegion`] of the channel, `None` if it is chosen
+    /// automatically.
+    ///
+    /// **Note**: This is only available for voice and stage channels.
+    ///
+    /// [`VoiceRegion`]: ../voice/struct.VoiceRegion.html
+    pub rtc_region: Option<String>,
+    /// The video quality of the channel, automatic if `None`.
+    ///
+    /// **Note**: This is only available for voice and stage channels.
+    pub video_quality_mode: Option<VideoQualityMode>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -403,13 +442,13 @@ impl GuildChannel {
     /// [`Message`]: struct.Message.html
     /// [`the limit`]: ../../builder/struct.EditMessage.html#method.content
     #[inline]
-    pub async fn edit_message<F>(
+    pub async fn edit_message<'a, F>(
         &self,
         http: impl AsRef<Http>,
         message_id: impl Into<MessageId>,
         f: F
     ) -> Result<Message>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         self.id.edit_message(&http, message_id, f).await
     }
@@ -578,7 +617,7 @@ impl GuildChannel {
     #[inline]
     pub async fn permissions_for_user(&self, cache: impl AsRef<Cache>, user_id: impl Into<UserId>) -> Result<Permissions> {
         let guild = self.guild(&cache).await.ok_or(Error::Model(ModelError::GuildNotFound))?;
-        Ok(guild.user_permissions_in(self.id, user_id))
+        Ok(guild.user_permissions_in_ids(self.id, user_id.into()))
     }
 
     /// Calculates the permissions of a role.
@@ -796,7 +835,8 @@ impl GuildChannel {
 
     /// Retrieves [`Member`]s from the current channel.
     ///
-    /// [`ChannelType::Voice`] returns [`Member`]s using the channel.
+    /// [`ChannelType::Voice`] and [`ChannelType::Stage`] return [`Member`]s
+    /// using the channel.
     /// [`ChannelType::Text`] and [`ChannelType::News`] return [`Member`]s
     /// that can read the channel.
     ///
@@ -806,6 +846,7 @@ impl GuildChannel {
     /// [`Member`]: ../guild/struct.Member.html
     /// [`ChannelType`]: enum.ChannelType.html
     /// [`ChannelType::Voice`]: enum.ChannelType.html#variant.Voice
+    /// [`ChannelType::Stage`]: enum.ChannelType.html#variant.Stage
     /// [`ChannelType::Text`]: enum.ChannelType.html#variant.Text
     /// [`ChannelType::News`]: enum.ChannelType.html#variant.News
     /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
@@ -819,7 +860,7 @@ impl GuildChannel {
             .ok_or(ModelError::GuildNotFound)?;
 
         match self.kind {
-            ChannelType::Voice => {
+            ChannelType::Voice | ChannelType::Stage => {
                 Ok(guild
                 .voice_states
                 .values()
@@ -1,6 +1,5 @@
 //! Models relating to Discord channels.
 
-use chrono::{DateTime, Utc};
 use crate::model::prelude::*;
 use serde_json::Value;
 use std::fmt::Display;
@@ -37,9 +36,12 @@ use crate::{
 };
 #[cfg(feature = "model")]
 use crate::http::{Http, CacheHttp};
+#[cfg(feature = "model")]
+use futures::stream::Stream;
 #[cfg(feature = "collector")]
 use crate::collector::{
     ReactionCollectorBuilder, CollectReaction,
+    MessageCollectorBuilder, CollectReply,
 };
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -62,7 +64,7 @@ pub struct Message {
     /// The content of the message.
     pub content: String,
     /// The timestamp of the last time the message was updated, if it was.
-    pub edited_timestamp: Option<DateTime<Utc>>,
+    pub edited_timestamp: Option<Timestamp>,
     /// Array of embeds sent with the message.
     pub embeds: Vec<Embed>,
     /// The Id of the [`Guild`] that the message was sent in. This value will
@@ -97,7 +99,7 @@ pub struct Message {
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
     /// Initial message creation timestamp, calculated from its Id.
-    pub timestamp: DateTime<Utc>,
+    pub timestamp: Timestamp,
     /// Indicator of whether the command is to be played back via
     /// text-to-speech.
     ///
@@ -113,6 +115,11 @@ pub struct Message {
     pub message_reference: Option<MessageReference>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
+    /// The poll attached to the message.
+    pub poll: Option<Poll>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -135,6 +142,31 @@ impl Message {
         self.author.id == cache.as_ref().current_user().await.id
     }
 
+    /// Publishes the message to the channels following its announcement
+    /// channel, returning the crossposted message.
+    ///
+    /// **Note**: Requires the [Send Messages] permission for messages of the
+    /// current user, or the [Manage Messages] permission otherwise.
+    ///
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub async fn crosspost(&self, http: impl AsRef<Http>) -> Result<Message> {
+        self.channel_id.crosspost(http, self.id).await
+    }
+
+    /// Immediately ends the message's [`Poll`], updating the message with the
+    /// final results.
+    ///
+    /// **Note**: The message must have been sent by the current user.
+    ///
+    /// [`Poll`]: struct.Poll.html
+    pub async fn end_poll(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = self.channel_id.end_poll(http, self.id).await?;
+
+        Ok(())
+    }
+
     /// Deletes the message.
     ///
     /// **Note**: The logged in user must either be the author of the message or
@@ -150,6 +182,18 @@ impl Message {
     /// [`ModelError::InvalidUser`]: ../error/enum.Error.html#variant.InvalidUser
     /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._delete_with_reason(cache_http, "").await
+    }
+
+    /// Deletes the message with a reason shown in the audit log. Refer to
+    /// [`delete`] for further documentation.
+    ///
+    /// [`delete`]: #method.delete
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: impl AsRef<str>) -> Result<()> {
+        self._delete_with_reason(cache_http, reason.as_ref()).await
+    }
+
+    async fn _delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -163,7 +207,7 @@ impl Message {
             }
         }
 
-        self.channel_id.delete_message(&cache_http.http(), self.id).await
+        self.channel_id.delete_message_with_reason(&cache_http.http(), self.id, reason).await
     }
 
     /// Deletes all of the [`Reaction`]s associated with the message.
@@ -263,8 +307,8 @@ impl Message {
     /// [`EditMessage`]: ../../builder/struct.EditMessage.html
     /// [`the limit`]: ../../builder/struct.EditMessage.html#method.content
     #[cfg(feature = "utils")]
-    pub async fn edit<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    pub async fn edit<'a, F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         #[cfg(feature = "cache")]
         {
@@ -293,7 +337,11 @@ impl Message {
 
         let map = crate::utils::hashmap_to_json_map(builder.0);
 
-        *self = cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::Object(map)).await?;
+        *self = if builder.1.is_empty() {
+            cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::Object(map)).await?
+        } else {
+            cache_http.http().edit_message_and_attachments(self.channel_id.0, self.id.0, map, builder.1).await?
+        };
 
         Ok(())
     }
@@ -360,6 +408,24 @@ impl Message {
             .replace("@here", "@\u{200B}here")
     }
 
+    /// Gets the list of [`User`]s who voted for an answer of the message's
+    /// [`Poll`], refer to [`ChannelId::poll_answer_voters`] for more
+    /// information.
+    ///
+    /// [`ChannelId::poll_answer_voters`]: ../id/struct.ChannelId.html#method.poll_answer_voters
+    /// [`Poll`]: struct.Poll.html
+    /// [`User`]: ../user/struct.User.html
+    #[inline]
+    pub async fn poll_answer_voters(
+        &self,
+        http: impl AsRef<Http>,
+        answer_id: u64,
+        after: impl Into<Option<UserId>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<User>> {
+        self.channel_id.poll_answer_voters(&http, self.id, answer_id, after, limit).await
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -387,6 +453,25 @@ impl Message {
         self.channel_id.reaction_users(&http, self.id, reaction_type, limit, after).await
     }
 
+    /// Streams over all the [`User`]s that reacted to the message with the
+    /// given emoji, requesting them in pages of 100.
+    ///
+    /// Refer to [`ChannelId::reaction_users_iter`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [`ChannelId::reaction_users_iter`]: ../id/struct.ChannelId.html#method.reaction_users_iter
+    /// [`User`]: ../user/struct.User.html
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    #[inline]
+    pub fn reaction_users_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+        reaction_type: impl Into<ReactionType>,
+    ) -> impl Stream<Item=Result<User>> {
+        self.channel_id.reaction_users_iter(http, self.id, reaction_type)
+    }
+
     /// Returns the associated `Guild` for the message if one is in the cache.
     ///
     /// Returns `None` if the guild's Id could not be found via [`guild_id`] or
@@ -523,6 +608,31 @@ impl Message {
         self._react(cache_http, &reaction_type.into()).await
     }
 
+    /// React to the message with several emojis, in the given order.
+    ///
+    /// The reactions are added one after another, waiting for ratelimits in
+    /// between, so this may take a while for many emojis.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first reaction that fails to be added, returning its
+    /// error. Refer to [`react`] for the possible errors.
+    ///
+    /// [Add Reactions]: ../permissions/struct.Permissions.html#associatedconstant.ADD_REACTIONS
+    /// [`react`]: #method.react
+    pub async fn react_multiple<R, It>(&self, cache_http: impl CacheHttp, reactions: It) -> Result<Vec<Reaction>>
+    where R: Into<ReactionType>, It: IntoIterator<Item=R> {
+        let mut added = Vec::new();
+
+        for reaction_type in reactions {
+            added.push(self._react(&cache_http, &reaction_type.into()).await?);
+        }
+
+        Ok(added)
+    }
+
     async fn _react(&self, cache_http: impl CacheHttp, reaction_type: &ReactionType) -> Result<Reaction> {
         #[allow(unused_mut)]
         let mut user_id = None;
@@ -577,8 +687,52 @@ impl Message {
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
     pub async fn reply(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
-        if let Some(length_over) = Message::overflow_length(&content.to_string()) {
+        self._reply(cache_http, content.to_string(), None).await
+    }
+
+    /// Replies to the message as an inline reply, referencing it and
+    /// notifying its author.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`reply`] for the possible errors.
+    ///
+    /// [`reply`]: #method.reply
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub async fn reply_ping(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        self._reply(cache_http, content.to_string(), Some(true)).await
+    }
+
+    /// Replies to the message as an inline reply, referencing it without
+    /// notifying its author.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`reply`] for the possible errors.
+    ///
+    /// [`reply`]: #method.reply
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub async fn reply_mention_less(&self, cache_http: impl CacheHttp, content: impl Display) -> Result<Message> {
+        self._reply(cache_http, content.to_string(), Some(false)).await
+    }
+
+    /// Sends `content` to the message's channel, either prefixed with a
+    /// mention of the author, or as an inline reply pinging the author if
+    /// `inline_ping` is given.
+    async fn _reply(&self, cache_http: impl CacheHttp, content: String, inline_ping: Option<bool>) -> Result<Message> {
+        if let Some(length_over) = Message::overflow_length(&content) {
             return Err(Error::Model(ModelError::MessageTooLong(length_over)));
         }
 
@@ -596,12 +750,21 @@ impl Message {
             }
         }
 
-        let gen = format!("{} {}", self.author.mention(), content);
-
-        let map = json!({
-            "content": gen,
-            "tts": false,
-        });
+        let map = match inline_ping {
+            Some(ping) => json!({
+                "content": content,
+                "tts": false,
+                "message_reference": MessageReference::from(self),
+                "allowed_mentions": {
+                    "parse": ["users", "roles", "everyone"],
+                    "replied_user": ping,
+                },
+            }),
+            None => json!({
+                "content": format!("{} {}", self.author.mention(), content),
+                "tts": false,
+            }),
+        };
 
         cache_http.http().send_message(self.channel_id.0, &map).await
     }
@@ -735,6 +898,22 @@ impl Message {
         ReactionCollectorBuilder::new(shard_messenger).message_id(self.id.0)
     }
 
+    /// Returns a future that will await one reply to this message.
+    ///
+    /// Use [`CollectReply::timeout`] to give up waiting after a while.
+    ///
+    /// [`CollectReply::timeout`]: ../../collector/message_collector/struct.CollectReply.html#method.timeout
+    #[cfg(feature = "collector")]
+    pub fn await_reply<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> CollectReply<'a> {
+        CollectReply::new(shard_messenger).channel_id(self.channel_id.0).reference_id(self.id.0)
+    }
+
+    /// Returns a stream builder which can be awaited to obtain a stream of replies to this message.
+    #[cfg(feature = "collector")]
+    pub fn await_replies<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> MessageCollectorBuilder<'a> {
+        MessageCollectorBuilder::new(shard_messenger).channel_id(self.channel_id.0).reference_id(self.id.0)
+    }
+
     pub(crate) fn check_content_length(map: &JsonMap) -> Result<()> {
         if let Some(content) = map.get("content") {
             if let Value::String(ref content) = *content {
@@ -864,6 +1043,14 @@ pub enum MessageType {
     NitroTier2 = 10,
     /// An indicator that the guild has reached nitro tier 3
     NitroTier3 = 11,
+    /// An indicator that a channel started following an announcement
+    /// channel.
+    ChannelFollowAdd = 12,
+    /// A reply to another message, referenced by the
+    /// [`message_reference`].
+    ///
+    /// [`message_reference`]: struct.Message.html#structfield.message_reference
+    InlineReply = 19,
 }
 
 enum_number!(
@@ -880,6 +1067,8 @@ enum_number!(
         NitroTier1,
         NitroTier2,
         NitroTier3,
+        ChannelFollowAdd,
+        InlineReply,
     }
 );
 
@@ -900,6 +1089,8 @@ impl MessageType {
             NitroTier1 => 9,
             NitroTier2 => 10,
             NitroTier3 => 11,
+            ChannelFollowAdd => 12,
+            InlineReply => 19,
         }
     }
 }
@@ -965,7 +1156,16 @@ pub struct MessageActivity {
     pub(crate) _nonexhaustive: (),
 }
 
-/// Reference data sent with crossposted messages.
+/// Reference data sent with crossposted messages and replies.
+///
+/// Can be created from a [`Message`] or a tuple of a [`ChannelId`] and a
+/// [`MessageId`] to reply to a message, see
+/// [`CreateMessage::reference_message`].
+///
+/// [`ChannelId`]: ../id/struct.ChannelId.html
+/// [`CreateMessage::reference_message`]: ../../builder/struct.CreateMessage.html#method.reference_message
+/// [`Message`]: struct.Message.html
+/// [`MessageId`]: ../id/struct.MessageId.html
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReference {
     /// ID of the originating message.
@@ -974,10 +1174,38 @@ pub struct MessageReference {
     pub channel_id: ChannelId,
     /// ID of the originating message's guild.
     pub guild_id: Option<GuildId>,
+    /// Whether sending a reply fails if the referenced message does not
+    /// exist. Only used when sending a message, defaulting to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
 
+impl From<&Message> for MessageReference {
+    fn from(m: &Message) -> Self {
+        Self {
+            message_id: Some(m.id),
+            channel_id: m.channel_id,
+            guild_id: m.guild_id,
+            fail_if_not_exists: None,
+            _nonexhaustive: (),
+        }
+    }
+}
+
+impl From<(ChannelId, MessageId)> for MessageReference {
+    fn from(pair: (ChannelId, MessageId)) -> Self {
+        Self {
+            message_id: Some(pair.1),
+            channel_id: pair.0,
+            guild_id: None,
+            fail_if_not_exists: None,
+            _nonexhaustive: (),
+        }
+    }
+}
+
 /// Channel Mention Object
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelMention {
@@ -1008,6 +1236,18 @@ __impl_bitflags! {
         IS_CROSSPOST = 0b0000_0000_0000_0000_0000_0000_0000_0010;
         /// Do not include any embeds when serializing this message.
         SUPPRESS_EMBEDS = 0b0000_0000_0000_0000_0000_0000_0000_0100;
+        /// The message this message crossposts was deleted (via Channel Following).
+        SOURCE_MESSAGE_DELETED = 0b0000_0000_0000_0000_0000_0000_0000_1000;
+        /// This message came from the urgent message system.
+        URGENT = 0b0000_0000_0000_0000_0000_0000_0001_0000;
+        /// This message has a thread attached to it.
+        HAS_THREAD = 0b0000_0000_0000_0000_0000_0000_0010_0000;
+        /// This message is only visible to the user who invoked the
+        /// interaction it responds to.
+        EPHEMERAL = 0b0000_0000_0000_0000_0000_0000_0100_0000;
+        /// This message is an interaction response and the bot is
+        /// "thinking".
+        LOADING = 0b0000_0000_0000_0000_0000_0000_1000_0000;
     }
 }
 
@@ -5,18 +5,24 @@ mod channel_id;
 mod embed;
 mod guild_channel;
 mod message;
+mod poll;
 mod private_channel;
 mod reaction;
 mod channel_category;
+mod stage_instance;
+mod thread;
 
 pub use self::attachment::*;
 pub use self::channel_id::*;
 pub use self::embed::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
+pub use self::poll::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 pub use self::channel_category::*;
+pub use self::stage_instance::*;
+pub use self::thread::*;
 
 use crate::model::prelude::*;
 use serde::de::Error as DeError;
@@ -239,7 +245,7 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 | 5 | 6 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+            0 | 2 | 5 | 6 | 10 | 11 | 12 | 13 => serde_json::from_value::<GuildChannel>(Value::Object(v))
                 .map(Channel::Guild)
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
@@ -316,6 +322,29 @@ pub enum ChannelType {
     ///
     /// [`GuildChannel`]: struct.GuildChannel.html
     Store = 6,
+    /// An indicator that the channel is a thread in a news channel.
+    ///
+    /// Note: Threads are serialized into a [`GuildChannel`]
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    NewsThread = 10,
+    /// An indicator that the channel is a public thread.
+    ///
+    /// Note: Threads are serialized into a [`GuildChannel`]
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    PublicThread = 11,
+    /// An indicator that the channel is a private thread, only visible to
+    /// its members and moderators.
+    ///
+    /// Note: Threads are serialized into a [`GuildChannel`]
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    PrivateThread = 12,
+    /// An indicator that the channel is a stage [`GuildChannel`].
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    Stage = 13,
 }
 
 enum_number!(
@@ -326,6 +355,10 @@ enum_number!(
         Category,
         News,
         Store,
+        NewsThread,
+        PublicThread,
+        PrivateThread,
+        Stage,
     }
 );
 
@@ -339,6 +372,10 @@ impl ChannelType {
             ChannelType::Category => "category",
             ChannelType::News => "news",
             ChannelType::Store => "store",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
+            ChannelType::Stage => "stage",
         }
     }
 
@@ -351,10 +388,68 @@ impl ChannelType {
             ChannelType::Category => 4,
             ChannelType::News => 5,
             ChannelType::Store => 6,
+            ChannelType::NewsThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::Stage => 13,
         }
     }
 }
 
+/// The video quality of a voice or stage [`GuildChannel`].
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum VideoQualityMode {
+    /// Discord chooses the quality for optimal performance.
+    Auto = 1,
+    /// The video quality is 720p.
+    Full = 2,
+}
+
+enum_number!(
+    VideoQualityMode {
+        Auto,
+        Full,
+    }
+);
+
+impl VideoQualityMode {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            VideoQualityMode::Auto => 1,
+            VideoQualityMode::Full => 2,
+        }
+    }
+}
+
+impl Default for VideoQualityMode {
+    fn default() -> Self {
+        VideoQualityMode::Auto
+    }
+}
+
+/// A channel with only a subset of its data, as resolved in an
+/// [`Interaction`].
+///
+/// [`Interaction`]: ../interactions/struct.Interaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialChannel {
+    /// The channel Id.
+    pub id: ChannelId,
+    /// The channel name.
+    pub name: String,
+    /// The channel type.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The permissions of the invoking user in the channel.
+    pub permissions: Permissions,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
 #[derive(Deserialize, Serialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
@@ -444,6 +539,13 @@ mod test {
                 user_limit: None,
                 nsfw: false,
                 slow_mode_rate: Some(0),
+                thread_metadata: None,
+                member: None,
+                owner_id: None,
+                message_count: None,
+                member_count: None,
+                rtc_region: None,
+                video_quality_mode: None,
                 _nonexhaustive: (),
             }
         }
@@ -457,6 +559,9 @@ mod test {
                 recipient: User {
                     id: UserId(2),
                     avatar: None,
+                    banner: None,
+                    accent_colour: None,
+                    avatar_decoration: None,
                     bot: false,
                     discriminator: 1,
                     name: "ab".to_string(),
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+/// A poll attached to a [`Message`], letting users vote for one or more of
+/// its answers.
+///
+/// [`Message`]: struct.Message.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Poll {
+    /// The question of the poll.
+    pub question: PollMedia,
+    /// The answers users can vote for.
+    pub answers: Vec<PollAnswer>,
+    /// When the poll ends.
+    pub expiry: Option<DateTime<Utc>>,
+    /// Whether users can vote for more than one answer.
+    pub allow_multiselect: bool,
+    /// The layout of the poll.
+    pub layout_type: PollLayoutType,
+    /// The vote counts of the answers.
+    ///
+    /// **Note**: These may be imprecise while the poll is running.
+    pub results: Option<PollResults>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The text and emoji of a poll's question or answer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollMedia {
+    /// The text, at most 300 characters for a question and 55 for an answer.
+    pub text: Option<String>,
+    /// The emoji of an answer.
+    pub emoji: Option<ReactionType>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// An answer of a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollAnswer {
+    /// The Id of the answer, unique within its poll.
+    pub answer_id: u64,
+    /// The text and emoji of the answer.
+    pub poll_media: PollMedia,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The vote counts of a [`Poll`]'s answers.
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollResults {
+    /// Whether the votes have been precisely counted after the poll ended.
+    pub is_finalized: bool,
+    /// The vote counts of the answers.
+    ///
+    /// **Note**: Answers without votes are left out.
+    pub answer_counts: Vec<PollAnswerCount>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The vote count of an answer of a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PollAnswerCount {
+    /// The Id of the answer.
+    pub id: u64,
+    /// The number of votes for the answer.
+    pub count: u64,
+    /// Whether the current user voted for the answer.
+    pub me_voted: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The layout of a [`Poll`].
+///
+/// [`Poll`]: struct.Poll.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum PollLayoutType {
+    /// The default layout.
+    Default = 1,
+}
+
+enum_number!(
+    PollLayoutType {
+        Default,
+    }
+);
+
+impl PollLayoutType {
+    pub fn num(self) -> u64 {
+        match self {
+            PollLayoutType::Default => 1,
+        }
+    }
+}
@@ -164,13 +164,13 @@ impl PrivateChannel {
     /// [`Message`]: struct.Message.html
     /// [`the limit`]: ../../builder/struct.EditMessage.html#method.content
     #[inline]
-    pub async fn edit_message<F>(
+    pub async fn edit_message<'a, F>(
         &self,
         http: impl AsRef<Http>,
         message_id: impl Into<MessageId>,
         f: F
     ) -> Result<Message>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         self.id.edit_message(&http, message_id, f).await
     }
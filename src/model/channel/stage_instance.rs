@@ -0,0 +1,62 @@
+use crate::model::prelude::*;
+
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// A live stage in a stage [`GuildChannel`].
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StageInstance {
+    /// The Id of the stage instance.
+    pub id: StageInstanceId,
+    /// The guild Id of the associated stage channel.
+    pub guild_id: GuildId,
+    /// The Id of the associated stage channel.
+    pub channel_id: ChannelId,
+    /// The topic of the stage instance.
+    pub topic: String,
+    /// The privacy level of the stage instance.
+    pub privacy_level: StagePrivacyLevel,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl StageInstance {
+    /// Deletes the stage instance, ending the stage.
+    ///
+    /// **Note**: Requires the current user to be a stage moderator.
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.channel_id.delete_stage_instance(http).await
+    }
+}
+
+/// The privacy level of a [`StageInstance`].
+///
+/// [`StageInstance`]: struct.StageInstance.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum StagePrivacyLevel {
+    /// The stage instance is visible publicly.
+    Public = 1,
+    /// The stage instance is only visible to guild members.
+    GuildOnly = 2,
+}
+
+enum_number!(
+    StagePrivacyLevel {
+        Public,
+        GuildOnly,
+    }
+);
+
+impl StagePrivacyLevel {
+    pub fn num(self) -> u64 {
+        match self {
+            StagePrivacyLevel::Public => 1,
+            StagePrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
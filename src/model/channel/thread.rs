@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+/// The thread specific data of a thread [`GuildChannel`].
+///
+/// [`GuildChannel`]: struct.GuildChannel.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// The number of minutes of inactivity after which the thread is
+    /// automatically archived, one of `60`, `1440`, `4320` or `10080`.
+    pub auto_archive_duration: Option<u64>,
+    /// The last time the thread's archive status was changed.
+    pub archive_timestamp: Option<DateTime<Utc>>,
+    /// Whether the thread is locked, i.e. only members with the Manage
+    /// Threads permission can unarchive it.
+    #[serde(default)]
+    pub locked: bool,
+    /// Whether non-moderators can add other non-moderators to a private
+    /// thread.
+    pub invitable: Option<bool>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A member of a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMember {
+    /// The Id of the thread.
+    ///
+    /// **Note**: This is absent for the current user's member in a thread's
+    /// [`member`] field.
+    ///
+    /// [`member`]: struct.GuildChannel.html#structfield.member
+    pub id: Option<ChannelId>,
+    /// The Id of the user.
+    ///
+    /// **Note**: This is absent for the current user's member in a thread's
+    /// [`member`] field.
+    ///
+    /// [`member`]: struct.GuildChannel.html#structfield.member
+    pub user_id: Option<UserId>,
+    /// The time the user last joined the thread.
+    pub join_timestamp: DateTime<Utc>,
+    /// Notification settings of the user, only used by clients.
+    pub flags: u64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A list of threads, along with the current user's memberships in them.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadsData {
+    /// The threads.
+    pub threads: Vec<GuildChannel>,
+    /// The current user's members of the threads it has joined.
+    pub members: Vec<ThreadMember>,
+    /// Whether more threads can be fetched, for paginated lists.
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
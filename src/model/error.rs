@@ -66,15 +66,25 @@ use super::Permissions;
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Error {
+    /// When attempting to ban none or more than the maximum allowed number of
+    /// users at once.
+    BulkBanAmount,
     /// When attempting to delete below or above the minimum and maximum allowed
     /// number of messages.
     BulkDeleteAmount,
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
+    /// When attempting to delete a number of seconds' worth of messages that
+    /// is not allowed.
+    DeleteMessageSecondsAmount(u32),
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(u64),
+    /// Indicates that the image of an emoji is over the 256 KiB limit.
+    ///
+    /// The number of bytes larger than the limit is provided.
+    EmojiTooLarge(u64),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -137,9 +147,12 @@ pub enum Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Error::BulkBanAmount => f.write_str("Too few/many users to bulk ban."),
             Error::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
             Error::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
+            Error::DeleteMessageSecondsAmount(_) => f.write_str("Invalid delete message seconds."),
             Error::EmbedTooLarge(_) => f.write_str("Embed too large."),
+            Error::EmojiTooLarge(_) => f.write_str("Emoji image too large."),
             Error::GuildNotFound => f.write_str("Guild not found in the cache."),
             Error::RoleNotFound => f.write_str("Role not found in the cache."),
             Error::Hierarchy => f.write_str("Role hierarchy prevents this action."),
@@ -10,18 +10,132 @@ use std::{
     collections::HashMap,
     fmt
 };
-use super::utils::deserialize_emojis;
+use super::utils::{deserialize_emojis, deserialize_stickers};
 use super::prelude::*;
 use crate::constants::OpCode;
 use crate::internal::prelude::*;
 
 #[cfg(feature = "cache")]
-use crate::cache::{Cache, CacheUpdate};
+use crate::cache::{Cache, CacheUpdate, CachedInvite, EvictionPolicy};
+#[cfg(feature = "cache")]
+use std::collections::HashSet;
 #[cfg(feature = "cache")]
 use std::mem;
 #[cfg(feature = "cache")]
 use async_trait::async_trait;
 
+/// Event data for the auto moderation action execution event, fired for
+/// each action of a triggered rule.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModerationActionExecutionEvent {
+    /// The Id of the guild the action was executed in.
+    pub guild_id: GuildId,
+    /// The executed action.
+    pub action: AutoModAction,
+    /// The Id of the triggered rule.
+    pub rule_id: AutoModRuleId,
+    /// The trigger type of the triggered rule.
+    pub rule_trigger_type: AutoModTriggerType,
+    /// The Id of the user whose content triggered the rule.
+    pub user_id: UserId,
+    /// The Id of the channel the content was posted in, if any.
+    pub channel_id: Option<ChannelId>,
+    /// The Id of the message triggering the rule.
+    ///
+    /// **Note**: This is `None` if the message was blocked or the content
+    /// was not part of a message.
+    pub message_id: Option<MessageId>,
+    /// The Id of the alert sent by a [`SendAlertMessage`] action.
+    ///
+    /// [`SendAlertMessage`]: ../guild/enum.AutoModActionType.html#variant.SendAlertMessage
+    pub alert_system_message_id: Option<MessageId>,
+    /// The content triggering the rule.
+    ///
+    /// **Note**: This is empty without the [`GUILD_MESSAGES`] intent.
+    ///
+    /// [`GUILD_MESSAGES`]: ../../client/bridge/gateway/struct.GatewayIntents.html#associatedconstant.GUILD_MESSAGES
+    #[serde(default)]
+    pub content: String,
+    /// The keyword or pattern matching the content.
+    pub matched_keyword: Option<String>,
+    /// The part of the content matching the keyword or pattern.
+    pub matched_content: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the auto moderation rule creation event.
+#[derive(Clone, Debug)]
+pub struct AutoModerationRuleCreateEvent {
+    /// The created rule.
+    pub rule: AutoModRule,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for AutoModerationRuleCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for AutoModerationRuleCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
+
+/// Event data for the auto moderation rule update event.
+#[derive(Clone, Debug)]
+pub struct AutoModerationRuleUpdateEvent {
+    /// The updated rule.
+    pub rule: AutoModRule,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for AutoModerationRuleUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for AutoModerationRuleUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
+
+/// Event data for the auto moderation rule deletion event.
+#[derive(Clone, Debug)]
+pub struct AutoModerationRuleDeleteEvent {
+    /// The deleted rule.
+    pub rule: AutoModRule,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for AutoModerationRuleDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            rule: AutoModRule::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for AutoModerationRuleDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        AutoModRule::serialize(&self.rule, serializer)
+    }
+}
+
 /// Event data for the channel creation event.
 ///
 /// This is fired when:
@@ -84,28 +198,22 @@ impl CacheUpdate for ChannelCreateEvent {
                     return Some(Channel::Private(channel.clone()));
                 }
 
-                let id = {
-                    let user_id = {
-                            cache.update_user_entry(&channel.recipient).await;
+                cache.update_user_entry(&channel.recipient).await;
 
-                            channel.recipient.id
-                    };
-
-                    if let Some(u) = cache.users.read().await.get(&user_id) {
-                        channel.recipient = u.clone();
-                    }
-
-                    channel.id
-                };
+                if let Some(u) = cache.users.read().await.get(&channel.recipient.id) {
+                    channel.recipient = u.clone();
+                }
 
                 cache
-                    .private_channels
-                    .write()
+                    .insert_private_channel(channel.clone())
                     .await
-                    .insert(id, channel.clone())
                     .map(Channel::Private)
             },
             Channel::Category(ref category) => {
+                if !cache.settings().await.cache_categories {
+                    return None;
+                }
+
                 cache
                     .categories
                     .write()
@@ -152,11 +260,7 @@ impl CacheUpdate for ChannelDeleteEvent {
                 cache.categories.write().await.remove(&channel_id);
             },
             Channel::Private(ref channel) => {
-                let id = {
-                    channel.id
-                };
-
-                cache.private_channels.write().await.remove(&id);
+                cache.remove_private_channel(channel.id).await;
             },
         };
 
@@ -300,8 +404,20 @@ impl CacheUpdate for GuildCreateEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.write().await.remove(&self.guild.id);
+        cache.restored_guilds.write().await.remove(&self.guild.id);
         let mut guild = self.guild.clone();
 
+        let settings = cache.settings().await;
+
+        if settings.voice_members_only {
+            let voice_states = &guild.voice_states;
+            guild.members.retain(|user_id, _| voice_states.contains_key(user_id));
+        }
+
+        if !settings.cache_presences {
+            guild.presences.clear();
+        }
+
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user).await;
             if let Some(u) = cache.user(user_id).await {
@@ -309,6 +425,14 @@ impl CacheUpdate for GuildCreateEvent {
             }
         }
 
+        cache.forget_guild(guild.id).await;
+
+        let member_ids = guild.members.keys().copied().collect::<Vec<_>>();
+        cache.track_members(&mut guild, &member_ids).await;
+
+        let presence_ids = guild.presences.keys().copied().collect::<Vec<_>>();
+        cache.track_presences(&mut guild, &presence_ids).await;
+
         cache.channels.write().await.extend(guild.channels.clone().into_iter());
         cache
             .guilds
@@ -348,6 +472,8 @@ impl CacheUpdate for GuildDeleteEvent {
     type Output = Guild;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.forget_guild(self.guild.id).await;
+
         match cache.guilds.write().await.remove(&self.guild.id) {
             Some(guild) => {
                 for channel_id in guild.channels.keys() {
@@ -424,14 +550,22 @@ impl CacheUpdate for GuildMemberAddEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         let user_id = self.member.user.id;
-        cache.update_user_entry(&self.member.user).await;
-        if let Some(u) = cache.user(user_id).await {
-            self.member.user = u;
+        let cached = cache.caches_member(self.guild_id, user_id).await;
+
+        if cached {
+            cache.update_user_entry(&self.member.user).await;
+            if let Some(u) = cache.user(user_id).await {
+                self.member.user = u;
+            }
         }
 
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+
+            if cached {
+                guild.members.insert(user_id, self.member.clone());
+                cache.track_members(guild, &[user_id]).await;
+            }
         }
 
         None
@@ -485,6 +619,8 @@ impl CacheUpdate for GuildMemberRemoveEvent {
     type Output = Member;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.forget_member(self.guild_id, self.user.id).await;
+
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
             guild.member_count -= 1;
             return guild.members.remove(&self.user.id);
@@ -500,6 +636,15 @@ pub struct GuildMemberUpdateEvent {
     pub nick: Option<String>,
     pub roles: Vec<RoleId>,
     pub user: User,
+    pub communication_disabled_until: Option<Timestamp>,
+    /// Whether the member has yet to pass the guild's membership screening.
+    ///
+    /// Comparing this with the cached member's [`Member::pending`] tells
+    /// whether the member just passed the screening.
+    ///
+    /// [`Member::pending`]: ../guild/struct.Member.html#structfield.pending
+    #[serde(default)]
+    pub pending: bool,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -510,6 +655,10 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.caches_member(self.guild_id, self.user.id).await {
+            return None;
+        }
+
         cache.update_user_entry(&self.user).await;
 
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
@@ -521,6 +670,8 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 member.nick.clone_from(&self.nick);
                 member.roles.clone_from(&self.roles);
                 member.user.clone_from(&self.user);
+                member.communication_disabled_until = self.communication_disabled_until;
+                member.pending = self.pending;
 
                 found = true;
 
@@ -540,11 +691,15 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                         nick: self.nick.clone(),
                         roles: self.roles.clone(),
                         user: self.user.clone(),
+                        communication_disabled_until: self.communication_disabled_until,
+                        pending: self.pending,
                         _nonexhaustive: (),
                     },
                 );
             }
 
+            cache.track_members(guild, &[self.user.id]).await;
+
             item
         } else {
             None
@@ -559,6 +714,8 @@ pub struct GuildMembersChunkEvent {
     pub chunk_index: u32,
     pub chunk_count: u32,
     pub nonce: Option<String>,
+    /// The presences of the members, if they were requested.
+    pub presences: Vec<Presence>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -569,12 +726,20 @@ impl CacheUpdate for GuildMembersChunkEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
-        for member in self.members.values() {
-            cache.update_user_entry(&member.user).await;
+        let mut members = HashMap::with_capacity(self.members.len());
+
+        for (user_id, member) in &self.members {
+            if cache.caches_member(self.guild_id, *user_id).await {
+                cache.update_user_entry(&member.user).await;
+                members.insert(*user_id, member.clone());
+            }
         }
 
         if let Some(g) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            g.members.extend(self.members.clone());
+            let member_ids = members.keys().copied().collect::<Vec<_>>();
+            g.members.extend(members);
+
+            cache.track_members(g, &member_ids).await;
         }
 
         None
@@ -629,12 +794,19 @@ impl<'de> Deserialize<'de> for GuildMembersChunkEvent {
             .and_then(|nonce| nonce.as_str())
             .map(|nonce| nonce.to_string());
 
+        let presences = match map.remove("presences") {
+            Some(v) => serde_json::from_value::<Vec<Presence>>(v)
+                .map_err(DeError::custom)?,
+            None => Vec::new(),
+        };
+
         Ok(GuildMembersChunkEvent {
             guild_id,
             members,
             chunk_index,
             chunk_count,
             nonce,
+            presences,
             _nonexhaustive: (),
         })
     }
@@ -773,6 +945,101 @@ impl<'de> Deserialize<'de> for GuildRoleUpdateEvent {
     }
 }
 
+/// Event data for the integration creation event.
+///
+/// This is fired when an integration of a guild was created.
+#[derive(Clone, Debug)]
+pub struct IntegrationCreateEvent {
+    /// The created integration.
+    pub integration: Integration,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for IntegrationCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            integration: Integration::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for IntegrationCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Integration::serialize(&self.integration, serializer)
+    }
+}
+
+/// Event data for the integration update event.
+///
+/// This is fired when an integration of a guild was updated.
+#[derive(Clone, Debug)]
+pub struct IntegrationUpdateEvent {
+    /// The updated integration.
+    pub integration: Integration,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for IntegrationUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            integration: Integration::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for IntegrationUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Integration::serialize(&self.integration, serializer)
+    }
+}
+
+/// Event data for the integration deletion event.
+///
+/// This is fired when an integration of a guild was deleted.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IntegrationDeleteEvent {
+    /// The Id of the deleted integration.
+    pub id: IntegrationId,
+    /// The Id of the guild the integration belonged to.
+    pub guild_id: GuildId,
+    /// The Id of the bot application of the integration, if it was a
+    /// `discord` integration.
+    pub application_id: Option<ApplicationId>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the interaction creation event.
+///
+/// This is fired when a user invokes an application command or uses a message
+/// component of the application.
+#[derive(Clone, Debug)]
+pub struct InteractionCreateEvent {
+    /// The interaction that was created.
+    pub interaction: Interaction,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for InteractionCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            interaction: Interaction::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for InteractionCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Interaction::serialize(&self.interaction, serializer)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteCreateEvent {
     pub channel_id: ChannelId,
@@ -786,6 +1053,22 @@ pub struct InviteCreateEvent {
     pub(crate) _nonexhaustive: (),
 }
 
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for InviteCreateEvent {
+    type Output = ();
+
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        let guild_id = self.guild_id?;
+
+        if let Some(invites) = cache.invites.write().await.get_mut(&guild_id) {
+            invites.insert(self.code.clone(), CachedInvite::from(&*self));
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteDeleteEvent {
     pub channel_id: ChannelId,
@@ -795,6 +1078,142 @@ pub struct InviteDeleteEvent {
     pub(crate) _nonexhaustive: (),
 }
 
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for InviteDeleteEvent {
+    type Output = CachedInvite;
+
+    async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let guild_id = self.guild_id?;
+
+        cache.invites.write().await.get_mut(&guild_id)?.remove(&self.code)
+    }
+}
+
+/// Event data for the guild scheduled event creation event.
+#[derive(Clone, Debug)]
+pub struct GuildScheduledEventCreateEvent {
+    /// The created scheduled event.
+    pub event: ScheduledEvent,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for GuildScheduledEventCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            event: ScheduledEvent::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for GuildScheduledEventCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        ScheduledEvent::serialize(&self.event, serializer)
+    }
+}
+
+/// Event data for the guild scheduled event deletion event.
+#[derive(Clone, Debug)]
+pub struct GuildScheduledEventDeleteEvent {
+    /// The deleted scheduled event.
+    pub event: ScheduledEvent,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for GuildScheduledEventDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            event: ScheduledEvent::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for GuildScheduledEventDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        ScheduledEvent::serialize(&self.event, serializer)
+    }
+}
+
+/// Event data for the guild scheduled event update event.
+#[derive(Clone, Debug)]
+pub struct GuildScheduledEventUpdateEvent {
+    /// The updated scheduled event.
+    pub event: ScheduledEvent,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for GuildScheduledEventUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            event: ScheduledEvent::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for GuildScheduledEventUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        ScheduledEvent::serialize(&self.event, serializer)
+    }
+}
+
+/// Event data for the guild scheduled event user add event.
+///
+/// This is fired when a user subscribed to a [`ScheduledEvent`].
+///
+/// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildScheduledEventUserAddEvent {
+    #[serde(rename = "guild_scheduled_event_id")]
+    pub scheduled_event_id: ScheduledEventId,
+    pub user_id: UserId,
+    pub guild_id: GuildId,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the guild scheduled event user remove event.
+///
+/// This is fired when a user unsubscribed from a [`ScheduledEvent`].
+///
+/// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildScheduledEventUserRemoveEvent {
+    #[serde(rename = "guild_scheduled_event_id")]
+    pub scheduled_event_id: ScheduledEventId,
+    pub user_id: UserId,
+    pub guild_id: GuildId,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildStickersUpdateEvent {
+    #[serde(serialize_with = "serialize_stickers", deserialize_with = "deserialize_stickers")] pub stickers: HashMap<StickerId, Sticker>,
+    pub guild_id: GuildId,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "cache")]
+#[async_trait]
+impl CacheUpdate for GuildStickersUpdateEvent {
+    type Output = ();
+
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
+            guild.stickers.clone_from(&self.stickers);
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildUnavailableEvent {
     #[serde(rename = "id")] pub guild_id: GuildId,
@@ -835,6 +1254,9 @@ impl CacheUpdate for GuildUpdateEvent {
             guild.owner_id.clone_from(&self.guild.owner_id);
             guild.region.clone_from(&self.guild.region);
             guild.roles.clone_from(&self.guild.roles);
+            guild.splash.clone_from(&self.guild.splash);
+            guild.discovery_splash.clone_from(&self.guild.discovery_splash);
+            guild.banner.clone_from(&self.guild.banner);
             guild.verification_level = self.guild.verification_level;
         }
 
@@ -871,7 +1293,8 @@ impl CacheUpdate for MessageCreateEvent {
     type Output = Message;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let max = cache.settings().await.max_messages;
+        let settings = cache.settings().await;
+        let max = settings.max_messages;
 
         if max == 0 {
             return None;
@@ -886,6 +1309,22 @@ impl CacheUpdate for MessageCreateEvent {
             .entry(self.message.channel_id)
             .or_insert_with(Default::default);
 
+        if let EvictionPolicy::Ttl(ttl) = settings.eviction {
+            if let Ok(ttl) = chrono::Duration::from_std(ttl) {
+                let now = Utc::now();
+
+                while let Some(id) = queue.front() {
+                    match messages.get(id) {
+                        Some(message) if *message.timestamp + ttl > now => break,
+                        _ => {
+                            messages.remove(id);
+                            queue.pop_front();
+                        },
+                    }
+                }
+            }
+        }
+
         let mut removed_msg = None;
 
         if messages.len() == max {
@@ -895,8 +1334,14 @@ impl CacheUpdate for MessageCreateEvent {
             }
         }
 
+        let mut message = self.message.clone();
+
+        if !settings.cache_message_content {
+            message.content.clear();
+        }
+
         queue.push_back(self.message.id);
-        messages.insert(self.message.id, self.message.clone());
+        messages.insert(self.message.id, message);
 
         removed_msg
     }
@@ -946,8 +1391,8 @@ pub struct MessageUpdateEvent {
     pub nonce: Option<String>,
     pub tts: Option<bool>,
     pub pinned: Option<bool>,
-    pub timestamp: Option<DateTime<Utc>>,
-    pub edited_timestamp: Option<DateTime<Utc>>,
+    pub timestamp: Option<Timestamp>,
+    pub edited_timestamp: Option<Timestamp>,
     pub author: Option<User>,
     pub mention_everyone: Option<bool>,
     pub mentions: Option<Vec<User>>,
@@ -964,6 +1409,8 @@ impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let cache_content = cache.settings().await.cache_message_content;
+
         if let Some(messages) = cache.messages.write().await.get_mut(&self.channel_id) {
 
             if let Some(message) = messages.get_mut(&self.id) {
@@ -974,7 +1421,9 @@ impl CacheUpdate for MessageUpdateEvent {
                 }
 
                 if let Some(content) = self.content.clone() {
-                    message.content = content;
+                    if cache_content {
+                        message.content = content;
+                    }
                 }
 
                 if let Some(edited_timestamp) = self.edited_timestamp {
@@ -1005,6 +1454,40 @@ impl CacheUpdate for MessageUpdateEvent {
     }
 }
 
+/// Event data for the poll vote add event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagePollVoteAddEvent {
+    /// The Id of the user whose vote was added.
+    pub user_id: UserId,
+    /// The Id of the channel of the poll's message.
+    pub channel_id: ChannelId,
+    /// The Id of the poll's message.
+    pub message_id: MessageId,
+    /// The Id of the guild of the poll's message, if any.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the answer.
+    pub answer_id: u64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the poll vote removal event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagePollVoteRemoveEvent {
+    /// The Id of the user whose vote was removed.
+    pub user_id: UserId,
+    /// The Id of the channel of the poll's message.
+    pub channel_id: ChannelId,
+    /// The Id of the poll's message.
+    pub message_id: MessageId,
+    /// The Id of the guild of the poll's message, if any.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the answer.
+    pub answer_id: u64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct PresenceUpdateEvent {
     pub guild_id: Option<GuildId>,
@@ -1019,12 +1502,23 @@ impl CacheUpdate for PresenceUpdateEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().await.cache_presences {
+            return None;
+        }
+
         let user_id = self.presence.user_id;
 
+        let caches_member = match self.guild_id {
+            Some(guild_id) => cache.caches_member(guild_id, user_id).await,
+            None => true,
+        };
+
         if let Some(user) = self.presence.user.as_mut() {
-            cache.update_user_entry(&user).await;
-            if let Some(u) = cache.user(user_id).await {
-                *user = u;
+            if caches_member {
+                cache.update_user_entry(&user).await;
+                if let Some(u) = cache.user(user_id).await {
+                    *user = u;
+                }
             }
         }
 
@@ -1033,15 +1527,17 @@ impl CacheUpdate for PresenceUpdateEvent {
                 // If the member went offline, remove them from the presence list.
                 if self.presence.status == OnlineStatus::Offline {
                     guild.presences.remove(&self.presence.user_id);
+                    cache.forget_presence(guild_id, self.presence.user_id).await;
                 } else {
                     guild
                         .presences
                         .insert(self.presence.user_id, self.presence.clone());
+                    cache.track_presences(guild, &[self.presence.user_id]).await;
                 }
 
                 // Create a partial member instance out of the presence update
                 // data.
-                if !guild.members.contains_key(&self.presence.user_id) {
+                if caches_member && !guild.members.contains_key(&self.presence.user_id) {
                     if let Some(user) = self.presence.user.as_ref() {
                         guild.members.insert(self.presence.user_id, Member {
                             deaf: false,
@@ -1051,8 +1547,11 @@ impl CacheUpdate for PresenceUpdateEvent {
                             nick: None,
                             user: user.clone(),
                             roles: vec![],
+                            communication_disabled_until: None,
+                            pending: false,
                             _nonexhaustive: (),
                         });
+                        cache.track_members(guild, &[self.presence.user_id]).await;
                     }
                 }
             }
@@ -1102,6 +1601,10 @@ impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().await.cache_presences {
+            return None;
+        }
+
         cache.presences.write().await.extend({
             let mut p: HashMap<UserId, Presence> = HashMap::default();
 
@@ -1207,11 +1710,44 @@ impl CacheUpdate for ReadyEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         let mut ready = self.ready.clone();
+        let mut restored = HashSet::new();
+
+        // Guilds of the shard restored from a snapshot are kept until they are
+        // received again, unless the current user is no longer in them.
+        {
+            let [shard_id, shard_count] = ready.shard.unwrap_or([0, 1]);
+            let listed = ready.guilds.iter().map(|status| match *status {
+                GuildStatus::Offline(unavailable) => unavailable.id,
+                GuildStatus::OnlineGuild(ref guild) => guild.id,
+                GuildStatus::OnlinePartialGuild(ref partial_guild) => partial_guild.id,
+            }).collect::<HashSet<_>>();
+            let mut guilds = cache.guilds.write().await;
+            let mut channels = cache.channels.write().await;
+
+            cache.restored_guilds.write().await.retain(|guild_id| {
+                if (guild_id.0 >> 22) % shard_count != shard_id {
+                    return true;
+                }
+
+                if listed.contains(guild_id) {
+                    restored.insert(*guild_id);
+                } else if let Some(guild) = guilds.remove(guild_id) {
+                    for channel_id in guild.channels.keys() {
+                        channels.remove(channel_id);
+                    }
+                }
+
+                false
+            });
+        }
 
         for guild in ready.guilds {
             match guild {
                 GuildStatus::Offline(unavailable) => {
-                    cache.guilds.write().await.remove(&unavailable.id);
+                    if !restored.contains(&unavailable.id) {
+                        cache.guilds.write().await.remove(&unavailable.id);
+                    }
+
                     cache.unavailable_guilds.write().await.insert(unavailable.id);
                 },
                 GuildStatus::OnlineGuild(guild) => {
@@ -1225,18 +1761,21 @@ impl CacheUpdate for ReadyEvent {
         // `ready.private_channels` will always be empty, and possibly be removed in the future.
         // So don't handle it at all.
 
-        for (user_id, presence) in &mut ready.presences {
-            if let Some(ref user) = presence.user {
-                cache.update_user_entry(user).await;
+        if cache.settings().await.cache_presences {
+            for (user_id, presence) in &mut ready.presences {
+                if let Some(ref user) = presence.user {
+                    cache.update_user_entry(user).await;
+                }
+
+                presence.user = match cache.user(user_id).await {
+                    Some(user) => Some(user),
+                    None => None,
+                };
             }
 
-            presence.user = match cache.user(user_id).await {
-                Some(user) => Some(user),
-                None => None,
-            };
+            cache.presences.write().await.extend(ready.presences);
         }
 
-        cache.presences.write().await.extend(ready.presences);
         *cache.shard_count.write().await = ready.shard.map_or(1, |s| s[1]);
         *cache.user.write().await = ready.user;
 
@@ -1267,6 +1806,181 @@ pub struct ResumedEvent {
     pub(crate) _nonexhaustive: (),
 }
 
+/// Event data for the stage instance creation event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceCreateEvent {
+    /// The created stage instance.
+    pub stage_instance: StageInstance,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for StageInstanceCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for StageInstanceCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the stage instance deletion event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceDeleteEvent {
+    /// The deleted stage instance.
+    pub stage_instance: StageInstance,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for StageInstanceDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for StageInstanceDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the stage instance update event.
+#[derive(Clone, Debug)]
+pub struct StageInstanceUpdateEvent {
+    /// The updated stage instance.
+    pub stage_instance: StageInstance,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for StageInstanceUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for StageInstanceUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+/// Event data for the thread creation event.
+///
+/// This is fired when a thread is created, or when the current user is added
+/// to a private thread.
+#[derive(Clone, Debug)]
+pub struct ThreadCreateEvent {
+    /// The thread that was created.
+    pub thread: GuildChannel,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for ThreadCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildChannel::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for ThreadCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        GuildChannel::serialize(&self.thread, serializer)
+    }
+}
+
+/// Event data for the thread deletion event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadDeleteEvent {
+    /// The Id of the deleted thread.
+    pub id: ChannelId,
+    pub guild_id: GuildId,
+    /// The Id of the channel the thread was in.
+    pub parent_id: Option<ChannelId>,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the thread list sync event.
+///
+/// This is fired when the current user gains access to a channel, containing
+/// the active threads in the channel.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadListSyncEvent {
+    pub guild_id: GuildId,
+    /// The Ids of the channels whose threads are synced.
+    ///
+    /// If empty, the threads of the whole guild are synced.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    /// The active threads of the channels.
+    pub threads: Vec<GuildChannel>,
+    /// The current user's members of the synced threads it has joined.
+    pub members: Vec<ThreadMember>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the thread members update event.
+///
+/// This is fired when users are added to or removed from a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMembersUpdateEvent {
+    /// The Id of the thread.
+    pub id: ChannelId,
+    pub guild_id: GuildId,
+    /// An approximate count of members in the thread, stops counting at 50.
+    pub member_count: u64,
+    #[serde(default)]
+    pub added_members: Vec<ThreadMember>,
+    #[serde(default)]
+    pub removed_member_ids: Vec<UserId>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Event data for the thread update event.
+#[derive(Clone, Debug)]
+pub struct ThreadUpdateEvent {
+    /// The updated thread.
+    pub thread: GuildChannel,
+    pub(crate) _nonexhaustive: (),
+}
+
+impl<'de> Deserialize<'de> for ThreadUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            thread: GuildChannel::deserialize(deserializer)?,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+impl Serialize for ThreadUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        GuildChannel::serialize(&self.thread, serializer)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TypingStartEvent {
     pub guild_id: Option<GuildId>,
@@ -1352,8 +2066,13 @@ impl CacheUpdate for VoiceStateUpdateEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
         if let Some(guild_id) = self.guild_id {
+            let voice_members_only = cache.settings().await.voice_members_only;
 
             if let Some(guild) = cache.guilds.write().await.get_mut(&guild_id) {
+                if voice_members_only && self.voice_state.channel_id.is_none() {
+                    guild.members.remove(&self.voice_state.user_id);
+                    cache.forget_member(guild_id, self.voice_state.user_id).await;
+                }
 
                 if self.voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
@@ -1503,6 +2222,34 @@ impl<'de> Deserialize<'de> for GatewayEvent {
 #[non_exhaustive]
 #[serde(untagged)]
 pub enum Event {
+    /// An [`AutoModAction`] was executed.
+    ///
+    /// Fires the [`EventHandler::auto_moderation_action_execution`] event.
+    ///
+    /// [`AutoModAction`]: ../guild/struct.AutoModAction.html
+    /// [`EventHandler::auto_moderation_action_execution`]: ../../client/trait.EventHandler.html#method.auto_moderation_action_execution
+    AutoModerationActionExecution(AutoModerationActionExecutionEvent),
+    /// An [`AutoModRule`] was created.
+    ///
+    /// Fires the [`EventHandler::auto_moderation_rule_create`] event.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EventHandler::auto_moderation_rule_create`]: ../../client/trait.EventHandler.html#method.auto_moderation_rule_create
+    AutoModerationRuleCreate(AutoModerationRuleCreateEvent),
+    /// An [`AutoModRule`] was updated.
+    ///
+    /// Fires the [`EventHandler::auto_moderation_rule_update`] event.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EventHandler::auto_moderation_rule_update`]: ../../client/trait.EventHandler.html#method.auto_moderation_rule_update
+    AutoModerationRuleUpdate(AutoModerationRuleUpdateEvent),
+    /// An [`AutoModRule`] was deleted.
+    ///
+    /// Fires the [`EventHandler::auto_moderation_rule_delete`] event.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EventHandler::auto_moderation_rule_delete`]: ../../client/trait.EventHandler.html#method.auto_moderation_rule_delete
+    AutoModerationRuleDelete(AutoModerationRuleDeleteEvent),
     /// A [`Channel`] was created.
     ///
     /// Fires the [`EventHandler::channel_create`] event.
@@ -1546,9 +2293,53 @@ pub enum Event {
     GuildRoleCreate(GuildRoleCreateEvent),
     GuildRoleDelete(GuildRoleDeleteEvent),
     GuildRoleUpdate(GuildRoleUpdateEvent),
+    /// A scheduled event was created.
+    GuildScheduledEventCreate(GuildScheduledEventCreateEvent),
+    /// A scheduled event was deleted.
+    GuildScheduledEventDelete(GuildScheduledEventDeleteEvent),
+    /// A scheduled event was updated.
+    GuildScheduledEventUpdate(GuildScheduledEventUpdateEvent),
+    /// A user subscribed to a scheduled event.
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAddEvent),
+    /// A user unsubscribed from a scheduled event.
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveEvent),
+    /// A guild's stickers were updated.
+    ///
+    /// Fires the [`EventHandler::guild_stickers_update`] event.
+    ///
+    /// [`EventHandler::guild_stickers_update`]: ../../client/trait.EventHandler.html#method.guild_stickers_update
+    GuildStickersUpdate(GuildStickersUpdateEvent),
     /// When a guild is unavailable, such as due to a Discord server outage.
     GuildUnavailable(GuildUnavailableEvent),
     GuildUpdate(GuildUpdateEvent),
+    /// An [`Integration`] was created.
+    ///
+    /// Fires the [`EventHandler::integration_create`] event handler.
+    ///
+    /// [`Integration`]: guild/struct.Integration.html
+    /// [`EventHandler::integration_create`]: ../../client/trait.EventHandler.html#method.integration_create
+    IntegrationCreate(IntegrationCreateEvent),
+    /// An [`Integration`] was updated.
+    ///
+    /// Fires the [`EventHandler::integration_update`] event handler.
+    ///
+    /// [`Integration`]: guild/struct.Integration.html
+    /// [`EventHandler::integration_update`]: ../../client/trait.EventHandler.html#method.integration_update
+    IntegrationUpdate(IntegrationUpdateEvent),
+    /// An [`Integration`] was deleted.
+    ///
+    /// Fires the [`EventHandler::integration_delete`] event handler.
+    ///
+    /// [`Integration`]: guild/struct.Integration.html
+    /// [`EventHandler::integration_delete`]: ../../client/trait.EventHandler.html#method.integration_delete
+    IntegrationDelete(IntegrationDeleteEvent),
+    /// An [`Interaction`] was created.
+    ///
+    /// Fires the [`EventHandler::interaction_create`] event handler.
+    ///
+    /// [`Interaction`]: interactions/struct.Interaction.html
+    /// [`EventHandler::interaction_create`]: ../../client/trait.EventHandler.html#method.interaction_create
+    InteractionCreate(InteractionCreateEvent),
     /// An [`Invite`] was created.
     ///
     /// Fires the [`EventHandler::invite_create`] event handler.
@@ -1568,6 +2359,20 @@ pub enum Event {
     MessageDeleteBulk(MessageDeleteBulkEvent),
     /// A message has been edited, either by the user or the system
     MessageUpdate(MessageUpdateEvent),
+    /// A user added a vote for an answer of a [`Poll`].
+    ///
+    /// Fires the [`EventHandler::message_poll_vote_add`] event.
+    ///
+    /// [`Poll`]: ../channel/struct.Poll.html
+    /// [`EventHandler::message_poll_vote_add`]: ../../client/trait.EventHandler.html#method.message_poll_vote_add
+    MessagePollVoteAdd(MessagePollVoteAddEvent),
+    /// A user removed a vote from an answer of a [`Poll`].
+    ///
+    /// Fires the [`EventHandler::message_poll_vote_remove`] event.
+    ///
+    /// [`Poll`]: ../channel/struct.Poll.html
+    /// [`EventHandler::message_poll_vote_remove`]: ../../client/trait.EventHandler.html#method.message_poll_vote_remove
+    MessagePollVoteRemove(MessagePollVoteRemoveEvent),
     /// A member's presence state (or username or avatar) has changed
     PresenceUpdate(PresenceUpdateEvent),
     /// The precense list of the user's friends should be replaced entirely
@@ -1599,6 +2404,24 @@ pub enum Event {
     Ready(ReadyEvent),
     /// The connection has successfully resumed after a disconnect.
     Resumed(ResumedEvent),
+    /// A stage instance was created.
+    StageInstanceCreate(StageInstanceCreateEvent),
+    /// A stage instance was deleted.
+    StageInstanceDelete(StageInstanceDeleteEvent),
+    /// A stage instance was updated.
+    StageInstanceUpdate(StageInstanceUpdateEvent),
+    /// A thread was created, or the current user was added to a private
+    /// thread.
+    ThreadCreate(ThreadCreateEvent),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDeleteEvent),
+    /// The current user gained access to a channel, syncing its active
+    /// threads.
+    ThreadListSync(ThreadListSyncEvent),
+    /// Users were added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdateEvent),
+    /// A thread was updated.
+    ThreadUpdate(ThreadUpdateEvent),
     /// A user is typing; considered to last 5 seconds
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
@@ -1620,6 +2443,10 @@ impl Event {
     /// Return the type of this event.
     pub fn event_type(&self) -> EventType {
         match self {
+            Self::AutoModerationActionExecution(_) => EventType::AutoModerationActionExecution,
+            Self::AutoModerationRuleCreate(_) => EventType::AutoModerationRuleCreate,
+            Self::AutoModerationRuleUpdate(_) => EventType::AutoModerationRuleUpdate,
+            Self::AutoModerationRuleDelete(_) => EventType::AutoModerationRuleDelete,
             Self::ChannelCreate(_) => EventType::ChannelCreate,
             Self::ChannelDelete(_) => EventType::ChannelDelete,
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
@@ -1637,14 +2464,26 @@ impl Event {
             Self::GuildRoleCreate(_) => EventType::GuildRoleCreate,
             Self::GuildRoleDelete(_) => EventType::GuildRoleDelete,
             Self::GuildRoleUpdate(_) => EventType::GuildRoleUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
+            Self::GuildStickersUpdate(_) => EventType::GuildStickersUpdate,
             Self::GuildUnavailable(_) => EventType::GuildUnavailable,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
+            Self::IntegrationCreate(_) => EventType::IntegrationCreate,
+            Self::IntegrationUpdate(_) => EventType::IntegrationUpdate,
+            Self::IntegrationDelete(_) => EventType::IntegrationDelete,
+            Self::InteractionCreate(_) => EventType::InteractionCreate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
             Self::MessageCreate(_) => EventType::MessageCreate,
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace(_) => EventType::PresencesReplace,
             Self::ReactionAdd(_) => EventType::ReactionAdd,
@@ -1652,6 +2491,14 @@ impl Event {
             Self::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
             Self::Ready(_) => EventType::Ready,
             Self::Resumed(_) => EventType::Resumed,
+            Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
+            Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
+            Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -1681,6 +2528,18 @@ impl Event {
 /// [`GuildUnavailableEvent`]: struct.GuildUnavailableEvent.html
 pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
     Ok(match kind {
+        EventType::AutoModerationActionExecution => {
+            Event::AutoModerationActionExecution(serde_json::from_value(v)?)
+        },
+        EventType::AutoModerationRuleCreate => {
+            Event::AutoModerationRuleCreate(serde_json::from_value(v)?)
+        },
+        EventType::AutoModerationRuleUpdate => {
+            Event::AutoModerationRuleUpdate(serde_json::from_value(v)?)
+        },
+        EventType::AutoModerationRuleDelete => {
+            Event::AutoModerationRuleDelete(serde_json::from_value(v)?)
+        },
         EventType::ChannelCreate => Event::ChannelCreate(serde_json::from_value(v)?),
         EventType::ChannelDelete => Event::ChannelDelete(serde_json::from_value(v)?),
         EventType::ChannelPinsUpdate => {
@@ -1743,8 +2602,38 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::GuildRoleUpdate => {
             Event::GuildRoleUpdate(serde_json::from_value(v)?)
         },
+        EventType::IntegrationCreate => {
+            Event::IntegrationCreate(serde_json::from_value(v)?)
+        },
+        EventType::IntegrationUpdate => {
+            Event::IntegrationUpdate(serde_json::from_value(v)?)
+        },
+        EventType::IntegrationDelete => {
+            Event::IntegrationDelete(serde_json::from_value(v)?)
+        },
+        EventType::InteractionCreate => {
+            Event::InteractionCreate(serde_json::from_value(v)?)
+        },
         EventType::InviteCreate => Event::InviteCreate(serde_json::from_value(v)?),
         EventType::InviteDelete => Event::InviteDelete(serde_json::from_value(v)?),
+        EventType::GuildScheduledEventCreate => {
+            Event::GuildScheduledEventCreate(serde_json::from_value(v)?)
+        },
+        EventType::GuildScheduledEventDelete => {
+            Event::GuildScheduledEventDelete(serde_json::from_value(v)?)
+        },
+        EventType::GuildScheduledEventUpdate => {
+            Event::GuildScheduledEventUpdate(serde_json::from_value(v)?)
+        },
+        EventType::GuildScheduledEventUserAdd => {
+            Event::GuildScheduledEventUserAdd(serde_json::from_value(v)?)
+        },
+        EventType::GuildScheduledEventUserRemove => {
+            Event::GuildScheduledEventUserRemove(serde_json::from_value(v)?)
+        },
+        EventType::GuildStickersUpdate => {
+            Event::GuildStickersUpdate(serde_json::from_value(v)?)
+        },
         EventType::GuildUpdate => Event::GuildUpdate(serde_json::from_value(v)?),
         EventType::MessageCreate => Event::MessageCreate(serde_json::from_value(v)?),
         EventType::MessageDelete => Event::MessageDelete(serde_json::from_value(v)?),
@@ -1761,12 +2650,28 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
             Event::ReactionRemoveAll(serde_json::from_value(v)?)
         },
         EventType::MessageUpdate => Event::MessageUpdate(serde_json::from_value(v)?),
+        EventType::MessagePollVoteAdd => {
+            Event::MessagePollVoteAdd(serde_json::from_value(v)?)
+        },
+        EventType::MessagePollVoteRemove => {
+            Event::MessagePollVoteRemove(serde_json::from_value(v)?)
+        },
         EventType::PresenceUpdate => Event::PresenceUpdate(serde_json::from_value(v)?),
         EventType::PresencesReplace => {
             Event::PresencesReplace(serde_json::from_value(v)?)
         },
         EventType::Ready => Event::Ready(serde_json::from_value(v)?),
         EventType::Resumed => Event::Resumed(serde_json::from_value(v)?),
+        EventType::StageInstanceCreate => Event::StageInstanceCreate(serde_json::from_value(v)?),
+        EventType::StageInstanceDelete => Event::StageInstanceDelete(serde_json::from_value(v)?),
+        EventType::StageInstanceUpdate => Event::StageInstanceUpdate(serde_json::from_value(v)?),
+        EventType::ThreadCreate => Event::ThreadCreate(serde_json::from_value(v)?),
+        EventType::ThreadDelete => Event::ThreadDelete(serde_json::from_value(v)?),
+        EventType::ThreadListSync => Event::ThreadListSync(serde_json::from_value(v)?),
+        EventType::ThreadMembersUpdate => {
+            Event::ThreadMembersUpdate(serde_json::from_value(v)?)
+        },
+        EventType::ThreadUpdate => Event::ThreadUpdate(serde_json::from_value(v)?),
         EventType::TypingStart => Event::TypingStart(serde_json::from_value(v)?),
         EventType::UserUpdate => Event::UserUpdate(serde_json::from_value(v)?),
         EventType::VoiceServerUpdate => {
@@ -1796,6 +2701,30 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum EventType {
+    /// Indicator that a `AUTO_MODERATION_ACTION_EXECUTION` payload was received.
+    ///
+    /// This maps to [`AutoModerationActionExecutionEvent`].
+    ///
+    /// [`AutoModerationActionExecutionEvent`]: struct.AutoModerationActionExecutionEvent.html
+    AutoModerationActionExecution,
+    /// Indicator that a `AUTO_MODERATION_RULE_CREATE` payload was received.
+    ///
+    /// This maps to [`AutoModerationRuleCreateEvent`].
+    ///
+    /// [`AutoModerationRuleCreateEvent`]: struct.AutoModerationRuleCreateEvent.html
+    AutoModerationRuleCreate,
+    /// Indicator that a `AUTO_MODERATION_RULE_UPDATE` payload was received.
+    ///
+    /// This maps to [`AutoModerationRuleUpdateEvent`].
+    ///
+    /// [`AutoModerationRuleUpdateEvent`]: struct.AutoModerationRuleUpdateEvent.html
+    AutoModerationRuleUpdate,
+    /// Indicator that a `AUTO_MODERATION_RULE_DELETE` payload was received.
+    ///
+    /// This maps to [`AutoModerationRuleDeleteEvent`].
+    ///
+    /// [`AutoModerationRuleDeleteEvent`]: struct.AutoModerationRuleDeleteEvent.html
+    AutoModerationRuleDelete,
     /// Indicator that a channel create payload was received.
     ///
     /// This maps to [`ChannelCreateEvent`].
@@ -1898,6 +2827,42 @@ pub enum EventType {
     ///
     /// [`GuildRoleUpdateEvent`]: struct.GuildRoleUpdateEvent.html
     GuildRoleUpdate,
+    /// Indicator that a guild scheduled event create payload was received.
+    ///
+    /// This maps to [`GuildScheduledEventCreateEvent`].
+    ///
+    /// [`GuildScheduledEventCreateEvent`]: struct.GuildScheduledEventCreateEvent.html
+    GuildScheduledEventCreate,
+    /// Indicator that a guild scheduled event delete payload was received.
+    ///
+    /// This maps to [`GuildScheduledEventDeleteEvent`].
+    ///
+    /// [`GuildScheduledEventDeleteEvent`]: struct.GuildScheduledEventDeleteEvent.html
+    GuildScheduledEventDelete,
+    /// Indicator that a guild scheduled event update payload was received.
+    ///
+    /// This maps to [`GuildScheduledEventUpdateEvent`].
+    ///
+    /// [`GuildScheduledEventUpdateEvent`]: struct.GuildScheduledEventUpdateEvent.html
+    GuildScheduledEventUpdate,
+    /// Indicator that a guild scheduled event user add payload was received.
+    ///
+    /// This maps to [`GuildScheduledEventUserAddEvent`].
+    ///
+    /// [`GuildScheduledEventUserAddEvent`]: struct.GuildScheduledEventUserAddEvent.html
+    GuildScheduledEventUserAdd,
+    /// Indicator that a guild scheduled event user remove payload was received.
+    ///
+    /// This maps to [`GuildScheduledEventUserRemoveEvent`].
+    ///
+    /// [`GuildScheduledEventUserRemoveEvent`]: struct.GuildScheduledEventUserRemoveEvent.html
+    GuildScheduledEventUserRemove,
+    /// Indicator that a `GUILD_STICKERS_UPDATE` payload was received.
+    ///
+    /// This maps to [`GuildStickersUpdateEvent`].
+    ///
+    /// [`GuildStickersUpdateEvent`]: struct.GuildStickersUpdateEvent.html
+    GuildStickersUpdate,
     /// Indicator that a guild unavailable payload was received.
     ///
     /// This maps to [`GuildUnavailableEvent`].
@@ -1910,6 +2875,30 @@ pub enum EventType {
     ///
     /// [`GuildUpdateEvent`]: struct.GuildUpdateEvent.html
     GuildUpdate,
+    /// Indicator that an integration was created.
+    ///
+    /// This maps to [`IntegrationCreateEvent`].
+    ///
+    /// [`IntegrationCreateEvent`]: struct.IntegrationCreateEvent.html
+    IntegrationCreate,
+    /// Indicator that an integration was updated.
+    ///
+    /// This maps to [`IntegrationUpdateEvent`].
+    ///
+    /// [`IntegrationUpdateEvent`]: struct.IntegrationUpdateEvent.html
+    IntegrationUpdate,
+    /// Indicator that an integration was deleted.
+    ///
+    /// This maps to [`IntegrationDeleteEvent`].
+    ///
+    /// [`IntegrationDeleteEvent`]: struct.IntegrationDeleteEvent.html
+    IntegrationDelete,
+    /// Indicator that an interaction was created.
+    ///
+    /// This maps to [`InteractionCreateEvent`].
+    ///
+    /// [`InteractionCreateEvent`]: struct.InteractionCreateEvent.html
+    InteractionCreate,
     /// Indicator that an invite was created.
     ///
     /// This maps to [`InviteCreateEvent`].
@@ -1946,6 +2935,18 @@ pub enum EventType {
     ///
     /// [`MessageUpdateEvent`]: struct.MessageUpdateEvent.html
     MessageUpdate,
+    /// Indicator that a `MESSAGE_POLL_VOTE_ADD` payload was received.
+    ///
+    /// This maps to [`MessagePollVoteAddEvent`].
+    ///
+    /// [`MessagePollVoteAddEvent`]: struct.MessagePollVoteAddEvent.html
+    MessagePollVoteAdd,
+    /// Indicator that a `MESSAGE_POLL_VOTE_REMOVE` payload was received.
+    ///
+    /// This maps to [`MessagePollVoteRemoveEvent`].
+    ///
+    /// [`MessagePollVoteRemoveEvent`]: struct.MessagePollVoteRemoveEvent.html
+    MessagePollVoteRemove,
     /// Indicator that a presence update payload was received.
     ///
     /// This maps to [`PresenceUpdateEvent`].
@@ -1988,6 +2989,54 @@ pub enum EventType {
     ///
     /// [`ResumedEvent`]: struct.ResumedEvent.html
     Resumed,
+    /// Indicator that a stage instance create payload was received.
+    ///
+    /// This maps to [`StageInstanceCreateEvent`].
+    ///
+    /// [`StageInstanceCreateEvent`]: struct.StageInstanceCreateEvent.html
+    StageInstanceCreate,
+    /// Indicator that a stage instance delete payload was received.
+    ///
+    /// This maps to [`StageInstanceDeleteEvent`].
+    ///
+    /// [`StageInstanceDeleteEvent`]: struct.StageInstanceDeleteEvent.html
+    StageInstanceDelete,
+    /// Indicator that a stage instance update payload was received.
+    ///
+    /// This maps to [`StageInstanceUpdateEvent`].
+    ///
+    /// [`StageInstanceUpdateEvent`]: struct.StageInstanceUpdateEvent.html
+    StageInstanceUpdate,
+    /// Indicator that a thread create payload was received.
+    ///
+    /// This maps to [`ThreadCreateEvent`].
+    ///
+    /// [`ThreadCreateEvent`]: struct.ThreadCreateEvent.html
+    ThreadCreate,
+    /// Indicator that a thread delete payload was received.
+    ///
+    /// This maps to [`ThreadDeleteEvent`].
+    ///
+    /// [`ThreadDeleteEvent`]: struct.ThreadDeleteEvent.html
+    ThreadDelete,
+    /// Indicator that a thread list sync payload was received.
+    ///
+    /// This maps to [`ThreadListSyncEvent`].
+    ///
+    /// [`ThreadListSyncEvent`]: struct.ThreadListSyncEvent.html
+    ThreadListSync,
+    /// Indicator that a thread members update payload was received.
+    ///
+    /// This maps to [`ThreadMembersUpdateEvent`].
+    ///
+    /// [`ThreadMembersUpdateEvent`]: struct.ThreadMembersUpdateEvent.html
+    ThreadMembersUpdate,
+    /// Indicator that a thread update payload was received.
+    ///
+    /// This maps to [`ThreadUpdateEvent`].
+    ///
+    /// [`ThreadUpdateEvent`]: struct.ThreadUpdateEvent.html
+    ThreadUpdate,
     /// Indicator that a typing start payload was received.
     ///
     /// This maps to [`TypingStartEvent`].
@@ -2032,6 +3081,10 @@ impl From<&Event> for EventType {
 }
 
 impl EventType {
+    const AUTO_MODERATION_ACTION_EXECUTION: &'static str = "AUTO_MODERATION_ACTION_EXECUTION";
+    const AUTO_MODERATION_RULE_CREATE: &'static str = "AUTO_MODERATION_RULE_CREATE";
+    const AUTO_MODERATION_RULE_UPDATE: &'static str = "AUTO_MODERATION_RULE_UPDATE";
+    const AUTO_MODERATION_RULE_DELETE: &'static str = "AUTO_MODERATION_RULE_DELETE";
     const CHANNEL_CREATE: &'static str = "CHANNEL_CREATE";
     const CHANNEL_DELETE: &'static str = "CHANNEL_DELETE";
     const CHANNEL_PINS_UPDATE: &'static str = "CHANNEL_PINS_UPDATE";
@@ -2049,8 +3102,18 @@ impl EventType {
     const GUILD_ROLE_CREATE: &'static str = "GUILD_ROLE_CREATE";
     const GUILD_ROLE_DELETE: &'static str = "GUILD_ROLE_DELETE";
     const GUILD_ROLE_UPDATE: &'static str = "GUILD_ROLE_UPDATE";
+    const INTEGRATION_CREATE: &'static str = "INTEGRATION_CREATE";
+    const INTEGRATION_UPDATE: &'static str = "INTEGRATION_UPDATE";
+    const INTEGRATION_DELETE: &'static str = "INTEGRATION_DELETE";
+    const INTERACTION_CREATE: &'static str = "INTERACTION_CREATE";
     const INVITE_CREATE: &'static str = "INVITE_CREATE";
     const INVITE_DELETE: &'static str = "INVITE_DELETE";
+    const GUILD_SCHEDULED_EVENT_CREATE: &'static str = "GUILD_SCHEDULED_EVENT_CREATE";
+    const GUILD_SCHEDULED_EVENT_DELETE: &'static str = "GUILD_SCHEDULED_EVENT_DELETE";
+    const GUILD_SCHEDULED_EVENT_UPDATE: &'static str = "GUILD_SCHEDULED_EVENT_UPDATE";
+    const GUILD_SCHEDULED_EVENT_USER_ADD: &'static str = "GUILD_SCHEDULED_EVENT_USER_ADD";
+    const GUILD_SCHEDULED_EVENT_USER_REMOVE: &'static str = "GUILD_SCHEDULED_EVENT_USER_REMOVE";
+    const GUILD_STICKERS_UPDATE: &'static str = "GUILD_STICKERS_UPDATE";
     const GUILD_UPDATE: &'static str = "GUILD_UPDATE";
     const MESSAGE_CREATE: &'static str = "MESSAGE_CREATE";
     const MESSAGE_DELETE: &'static str = "MESSAGE_DELETE";
@@ -2059,10 +3122,20 @@ impl EventType {
     const MESSAGE_REACTION_REMOVE: &'static str = "MESSAGE_REACTION_REMOVE";
     const MESSAGE_REACTION_REMOVE_ALL: &'static str = "MESSAGE_REACTION_REMOVE_ALL";
     const MESSAGE_UPDATE: &'static str = "MESSAGE_UPDATE";
+    const MESSAGE_POLL_VOTE_ADD: &'static str = "MESSAGE_POLL_VOTE_ADD";
+    const MESSAGE_POLL_VOTE_REMOVE: &'static str = "MESSAGE_POLL_VOTE_REMOVE";
     const PRESENCE_UPDATE: &'static str = "PRESENCE_UPDATE";
     const PRESENCES_REPLACE: &'static str = "PRESENCES_REPLACE";
     const READY: &'static str = "READY";
     const RESUMED: &'static str = "RESUMED";
+    const STAGE_INSTANCE_CREATE: &'static str = "STAGE_INSTANCE_CREATE";
+    const STAGE_INSTANCE_DELETE: &'static str = "STAGE_INSTANCE_DELETE";
+    const STAGE_INSTANCE_UPDATE: &'static str = "STAGE_INSTANCE_UPDATE";
+    const THREAD_CREATE: &'static str = "THREAD_CREATE";
+    const THREAD_DELETE: &'static str = "THREAD_DELETE";
+    const THREAD_LIST_SYNC: &'static str = "THREAD_LIST_SYNC";
+    const THREAD_MEMBERS_UPDATE: &'static str = "THREAD_MEMBERS_UPDATE";
+    const THREAD_UPDATE: &'static str = "THREAD_UPDATE";
     const TYPING_START: &'static str = "TYPING_START";
     const USER_UPDATE: &'static str = "USER_UPDATE";
     const VOICE_SERVER_UPDATE: &'static str = "VOICE_SERVER_UPDATE";
@@ -2074,6 +3147,10 @@ impl EventType {
     /// case this method returns `None`.
     pub fn name(&self) -> Option<&str> {
         match self {
+            Self::AutoModerationActionExecution => Some(Self::AUTO_MODERATION_ACTION_EXECUTION),
+            Self::AutoModerationRuleCreate => Some(Self::AUTO_MODERATION_RULE_CREATE),
+            Self::AutoModerationRuleUpdate => Some(Self::AUTO_MODERATION_RULE_UPDATE),
+            Self::AutoModerationRuleDelete => Some(Self::AUTO_MODERATION_RULE_DELETE),
             Self::ChannelCreate => Some(Self::CHANNEL_CREATE),
             Self::ChannelDelete => Some(Self::CHANNEL_DELETE),
             Self::ChannelPinsUpdate => Some(Self::CHANNEL_PINS_UPDATE),
@@ -2091,8 +3168,18 @@ impl EventType {
             Self::GuildRoleCreate => Some(Self::GUILD_ROLE_CREATE),
             Self::GuildRoleDelete => Some(Self::GUILD_ROLE_DELETE),
             Self::GuildRoleUpdate => Some(Self::GUILD_ROLE_UPDATE),
+            Self::IntegrationCreate => Some(Self::INTEGRATION_CREATE),
+            Self::IntegrationUpdate => Some(Self::INTEGRATION_UPDATE),
+            Self::IntegrationDelete => Some(Self::INTEGRATION_DELETE),
+            Self::InteractionCreate => Some(Self::INTERACTION_CREATE),
             Self::InviteCreate => Some(Self::INVITE_CREATE),
             Self::InviteDelete => Some(Self::INVITE_DELETE),
+            Self::GuildScheduledEventCreate => Some(Self::GUILD_SCHEDULED_EVENT_CREATE),
+            Self::GuildScheduledEventDelete => Some(Self::GUILD_SCHEDULED_EVENT_DELETE),
+            Self::GuildScheduledEventUpdate => Some(Self::GUILD_SCHEDULED_EVENT_UPDATE),
+            Self::GuildScheduledEventUserAdd => Some(Self::GUILD_SCHEDULED_EVENT_USER_ADD),
+            Self::GuildScheduledEventUserRemove => Some(Self::GUILD_SCHEDULED_EVENT_USER_REMOVE),
+            Self::GuildStickersUpdate => Some(Self::GUILD_STICKERS_UPDATE),
             Self::GuildUpdate => Some(Self::GUILD_UPDATE),
             Self::MessageCreate => Some(Self::MESSAGE_CREATE),
             Self::MessageDelete => Some(Self::MESSAGE_DELETE),
@@ -2101,10 +3188,20 @@ impl EventType {
             Self::ReactionRemove => Some(Self::MESSAGE_REACTION_REMOVE),
             Self::ReactionRemoveAll => Some(Self::MESSAGE_REACTION_REMOVE_ALL),
             Self::MessageUpdate => Some(Self::MESSAGE_UPDATE),
+            Self::MessagePollVoteAdd => Some(Self::MESSAGE_POLL_VOTE_ADD),
+            Self::MessagePollVoteRemove => Some(Self::MESSAGE_POLL_VOTE_REMOVE),
             Self::PresenceUpdate => Some(Self::PRESENCE_UPDATE),
             Self::PresencesReplace => Some(Self::PRESENCES_REPLACE),
             Self::Ready => Some(Self::READY),
             Self::Resumed => Some(Self::RESUMED),
+            Self::StageInstanceCreate => Some(Self::STAGE_INSTANCE_CREATE),
+            Self::StageInstanceDelete => Some(Self::STAGE_INSTANCE_DELETE),
+            Self::StageInstanceUpdate => Some(Self::STAGE_INSTANCE_UPDATE),
+            Self::ThreadCreate => Some(Self::THREAD_CREATE),
+            Self::ThreadDelete => Some(Self::THREAD_DELETE),
+            Self::ThreadListSync => Some(Self::THREAD_LIST_SYNC),
+            Self::ThreadMembersUpdate => Some(Self::THREAD_MEMBERS_UPDATE),
+            Self::ThreadUpdate => Some(Self::THREAD_UPDATE),
             Self::TypingStart => Some(Self::TYPING_START),
             Self::UserUpdate => Some(Self::USER_UPDATE),
             Self::VoiceServerUpdate => Some(Self::VOICE_SERVER_UPDATE),
@@ -2135,6 +3232,10 @@ impl<'de> Deserialize<'de> for EventType {
             fn visit_str<E>(self, v: &str) -> StdResult<Self::Value, E>
                 where E: DeError {
                 Ok(match v {
+                    EventType::AUTO_MODERATION_ACTION_EXECUTION => EventType::AutoModerationActionExecution,
+                    EventType::AUTO_MODERATION_RULE_CREATE => EventType::AutoModerationRuleCreate,
+                    EventType::AUTO_MODERATION_RULE_UPDATE => EventType::AutoModerationRuleUpdate,
+                    EventType::AUTO_MODERATION_RULE_DELETE => EventType::AutoModerationRuleDelete,
                     EventType::CHANNEL_CREATE => EventType::ChannelCreate,
                     EventType::CHANNEL_DELETE => EventType::ChannelDelete,
                     EventType::CHANNEL_PINS_UPDATE => EventType::ChannelPinsUpdate,
@@ -2152,8 +3253,18 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::GUILD_ROLE_CREATE => EventType::GuildRoleCreate,
                     EventType::GUILD_ROLE_DELETE => EventType::GuildRoleDelete,
                     EventType::GUILD_ROLE_UPDATE => EventType::GuildRoleUpdate,
+                    EventType::INTEGRATION_CREATE => EventType::IntegrationCreate,
+                    EventType::INTEGRATION_UPDATE => EventType::IntegrationUpdate,
+                    EventType::INTEGRATION_DELETE => EventType::IntegrationDelete,
+                    EventType::INTERACTION_CREATE => EventType::InteractionCreate,
                     EventType::INVITE_CREATE => EventType::InviteCreate,
                     EventType::INVITE_DELETE => EventType::InviteDelete,
+                    EventType::GUILD_SCHEDULED_EVENT_CREATE => EventType::GuildScheduledEventCreate,
+                    EventType::GUILD_SCHEDULED_EVENT_DELETE => EventType::GuildScheduledEventDelete,
+                    EventType::GUILD_SCHEDULED_EVENT_UPDATE => EventType::GuildScheduledEventUpdate,
+                    EventType::GUILD_SCHEDULED_EVENT_USER_ADD => EventType::GuildScheduledEventUserAdd,
+                    EventType::GUILD_SCHEDULED_EVENT_USER_REMOVE => EventType::GuildScheduledEventUserRemove,
+                    EventType::GUILD_STICKERS_UPDATE => EventType::GuildStickersUpdate,
                     EventType::GUILD_UPDATE => EventType::GuildUpdate,
                     EventType::MESSAGE_CREATE => EventType::MessageCreate,
                     EventType::MESSAGE_DELETE => EventType::MessageDelete,
@@ -2162,10 +3273,20 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::MESSAGE_REACTION_REMOVE => EventType::ReactionRemove,
                     EventType::MESSAGE_REACTION_REMOVE_ALL => EventType::ReactionRemoveAll,
                     EventType::MESSAGE_UPDATE => EventType::MessageUpdate,
+                    EventType::MESSAGE_POLL_VOTE_ADD => EventType::MessagePollVoteAdd,
+                    EventType::MESSAGE_POLL_VOTE_REMOVE => EventType::MessagePollVoteRemove,
                     EventType::PRESENCE_UPDATE => EventType::PresenceUpdate,
                     EventType::PRESENCES_REPLACE => EventType::PresencesReplace,
                     EventType::READY => EventType::Ready,
                     EventType::RESUMED => EventType::Resumed,
+                    EventType::STAGE_INSTANCE_CREATE => EventType::StageInstanceCreate,
+                    EventType::STAGE_INSTANCE_DELETE => EventType::StageInstanceDelete,
+                    EventType::STAGE_INSTANCE_UPDATE => EventType::StageInstanceUpdate,
+                    EventType::THREAD_CREATE => EventType::ThreadCreate,
+                    EventType::THREAD_DELETE => EventType::ThreadDelete,
+                    EventType::THREAD_LIST_SYNC => EventType::ThreadListSync,
+                    EventType::THREAD_MEMBERS_UPDATE => EventType::ThreadMembersUpdate,
+                    EventType::THREAD_UPDATE => EventType::ThreadUpdate,
                     EventType::TYPING_START => EventType::TypingStart,
                     EventType::USER_UPDATE => EventType::UserUpdate,
                     EventType::VOICE_SERVER_UPDATE => EventType::VoiceServerUpdate,
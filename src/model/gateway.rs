@@ -59,6 +59,13 @@ pub struct Activity {
     /// [`ActivityType::Streaming`]: enum.ActivityType.html#variant.Streaming
     /// [`kind`]: #structfield.kind
     pub url: Option<String>,
+    /// The buttons shown on the activity.
+    ///
+    /// The gateway only sends the labels of the buttons of other users, so
+    /// their [`url`] is empty.
+    ///
+    /// [`url`]: struct.ActivityButton.html#structfield.url
+    pub buttons: Vec<ActivityButton>,
     #[serde(skip_serializing)]
     pub(crate) _nonexhaustive: (),
 }
@@ -105,6 +112,7 @@ impl Activity {
             emoji: None,
             timestamps: None,
             url: None,
+            buttons: Vec::new(),
             _nonexhaustive: (),
         }
     }
@@ -152,6 +160,7 @@ impl Activity {
             emoji: None,
             timestamps: None,
             url: Some(url.to_string()),
+            buttons: Vec::new(),
             _nonexhaustive: (),
         }
     }
@@ -196,6 +205,7 @@ impl Activity {
             emoji: None,
             timestamps: None,
             url: None,
+            buttons: Vec::new(),
             _nonexhaustive: (),
         }
     }
@@ -240,9 +250,93 @@ impl Activity {
             emoji: None,
             timestamps: None,
             url: None,
+            buttons: Vec::new(),
+            _nonexhaustive: (),
+        }
+    }
+
+    /// Creates an `Activity` struct that appears as a custom status with the
+    /// given `state`.
+    ///
+    /// An emoji can be shown in front of the status with [`with_emoji`].
+    ///
+    /// **Note**: Maximum `state` length is 128.
+    ///
+    /// # Examples
+    ///
+    /// Set a custom status with an emoji:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::gateway::{Activity, ActivityEmoji};
+    /// # #[cfg(feature = "client")]
+    /// use serenity::client::Context;
+    ///
+    /// # #[cfg(feature = "client")]
+    /// async fn set_status(ctx: &Context) {
+    ///     let emoji = ActivityEmoji {
+    ///         name: "🦀".to_string(),
+    ///         id: None,
+    ///         animated: None,
+    ///     };
+    ///
+    ///     ctx.set_activity(Activity::custom("Writing Rust").with_emoji(emoji)).await;
+    /// }
+    /// ```
+    ///
+    /// [`with_emoji`]: #method.with_emoji
+    pub fn custom(state: &str) -> Activity {
+        Activity {
+            application_id: None,
+            assets: None,
+            details: None,
+            flags: None,
+            instance: None,
+            kind: ActivityType::Custom,
+            name: "Custom Status".to_string(),
+            party: None,
+            secrets: None,
+            state: Some(state.to_string()),
+            emoji: None,
+            timestamps: None,
+            url: None,
+            buttons: Vec::new(),
             _nonexhaustive: (),
         }
     }
+
+    /// Sets the emoji shown in front of a custom status.
+    pub fn with_emoji(mut self, emoji: ActivityEmoji) -> Self {
+        self.emoji = Some(emoji);
+
+        self
+    }
+
+    /// Sets the Unix timestamps, in milliseconds, of when the activity started
+    /// and/or ends.
+    ///
+    /// **Note**: Discord does not display timestamps of bot users.
+    pub fn with_timestamps(mut self, start: Option<u64>, end: Option<u64>) -> Self {
+        self.timestamps = Some(ActivityTimestamps {
+            end,
+            start,
+            _nonexhaustive: (),
+        });
+
+        self
+    }
+
+    /// Adds a button linking to the `url` to the activity.
+    ///
+    /// **Note**: An activity can have at most 2 buttons, and Discord does not
+    /// display buttons of bot users.
+    pub fn with_button(mut self, label: &str, url: &str) -> Self {
+        self.buttons.push(ActivityButton {
+            label: label.to_string(),
+            url: url.to_string(),
+        });
+
+        self
+    }
 }
 
 impl<'de> Deserialize<'de> for Activity {
@@ -298,6 +392,20 @@ impl<'de> Deserialize<'de> for Activity {
         };
         let url = map.remove("url")
             .and_then(|v| serde_json::from_value::<String>(v).ok());
+        let buttons = match map.remove("buttons") {
+            Some(Value::Array(buttons)) => buttons
+                .into_iter()
+                .map(|button| match button {
+                    Value::String(label) => Ok(ActivityButton {
+                        label,
+                        url: String::new(),
+                    }),
+                    button => ActivityButton::deserialize(button),
+                })
+                .collect::<StdResult<Vec<_>, _>>()
+                .map_err(DeError::custom)?,
+            _ => Vec::new(),
+        };
 
         Ok(Activity {
             application_id,
@@ -313,6 +421,7 @@ impl<'de> Deserialize<'de> for Activity {
             emoji,
             timestamps,
             url,
+            buttons,
             _nonexhaustive: (),
         })
     }
@@ -377,6 +486,16 @@ pub struct ActivitySecrets {
     pub(crate) _nonexhaustive: (),
 }
 
+/// A button shown on an activity, linking to a URL.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ActivityButton {
+    /// The text shown on the button.
+    pub label: String,
+    /// The URL opened when the button is clicked.
+    pub url: String,
+}
+
 /// Representation of an emoji used in a custom status
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ActivityEmoji {
@@ -596,9 +715,17 @@ pub struct SessionStartLimit {
     pub reset_after: u64,
     /// The total number of session starts within the ratelimit period allowed.
     pub total: u64,
+    /// The number of shards that may identify at the same time, once every 5
+    /// seconds.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: u64,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
+
+fn default_max_concurrency() -> u64 {
+    1
+}
 /// Timestamps of when a user started and/or is ending their activity.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ActivityTimestamps {
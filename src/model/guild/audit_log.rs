@@ -1,12 +1,14 @@
 use crate::internal::prelude::*;
+use chrono::{DateTime, Utc};
 use serde::de::{
     self,
     Deserialize,
+    DeserializeOwned,
     Deserializer,
     MapAccess,
     Visitor
 };
-use serde::ser::Serializer;
+use serde::ser::{Serialize, Serializer};
 use crate::model::prelude::*;
 use std::{
     collections::HashMap,
@@ -30,7 +32,7 @@ pub enum Target {
 }
 
 /// Determines the action that was done on a target.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Action {
     GuildUpdate,
@@ -43,6 +45,14 @@ pub enum Action {
     Emoji(ActionEmoji),
     Message(ActionMessage),
     Integration(ActionIntegration),
+    StageInstance(ActionStageInstance),
+    Sticker(ActionSticker),
+    ScheduledEvent(ActionScheduledEvent),
+    Thread(ActionThread),
+    ApplicationCommandPermissionUpdate,
+    AutoMod(ActionAutoMod),
+    /// An action not yet known to the library, with its number.
+    Unknown(u8),
 }
 
 impl Action {
@@ -60,11 +70,18 @@ impl Action {
             Action::Emoji(ref x) => x.num(),
             Action::Message(ref x) => x.num(),
             Action::Integration(ref x) => x.num(),
+            Action::StageInstance(ref x) => x.num(),
+            Action::Sticker(ref x) => x.num(),
+            Action::ScheduledEvent(ref x) => x.num(),
+            Action::Thread(ref x) => x.num(),
+            ApplicationCommandPermissionUpdate => 121,
+            Action::AutoMod(ref x) => x.num(),
+            Unknown(num) => num,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionChannel {
@@ -83,7 +100,7 @@ impl ActionChannel {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionChannelOverwrite {
@@ -102,7 +119,7 @@ impl ActionChannelOverwrite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionMember {
@@ -133,7 +150,7 @@ impl ActionMember {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionRole {
@@ -152,7 +169,7 @@ impl ActionRole {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionInvite {
@@ -171,7 +188,7 @@ impl ActionInvite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionWebhook {
@@ -190,13 +207,13 @@ impl ActionWebhook {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionEmoji {
     Create = 60,
-    Delete = 61,
-    Update = 62,
+    Update = 61,
+    Delete = 62,
 }
 
 impl ActionEmoji {
@@ -209,7 +226,7 @@ impl ActionEmoji {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionMessage {
@@ -231,7 +248,7 @@ impl ActionMessage {
 }
 
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionIntegration {
@@ -250,12 +267,304 @@ impl ActionIntegration {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Change {
-    #[serde(rename = "key")] pub name: String,
-    // TODO: Change these to an actual type.
-    #[serde(rename = "old_value")] pub old: Option<Value>,
-    #[serde(rename = "new_value")] pub new: Option<Value>,
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionStageInstance {
+    Create = 83,
+    Update = 84,
+    Delete = 85,
+}
+
+impl ActionStageInstance {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionStageInstance::Create => 83,
+            ActionStageInstance::Update => 84,
+            ActionStageInstance::Delete => 85,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionSticker {
+    Create = 90,
+    Update = 91,
+    Delete = 92,
+}
+
+impl ActionSticker {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionSticker::Create => 90,
+            ActionSticker::Update => 91,
+            ActionSticker::Delete => 92,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionScheduledEvent {
+    Create = 100,
+    Update = 101,
+    Delete = 102,
+}
+
+impl ActionScheduledEvent {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionScheduledEvent::Create => 100,
+            ActionScheduledEvent::Update => 101,
+            ActionScheduledEvent::Delete => 102,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionThread {
+    Create = 110,
+    Update = 111,
+    Delete = 112,
+}
+
+impl ActionThread {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionThread::Create => 110,
+            ActionThread::Update => 111,
+            ActionThread::Delete => 112,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionAutoMod {
+    RuleCreate = 140,
+    RuleUpdate = 141,
+    RuleDelete = 142,
+    BlockMessage = 143,
+    FlagToChannel = 144,
+    UserCommunicationDisabled = 145,
+}
+
+impl ActionAutoMod {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionAutoMod::RuleCreate => 140,
+            ActionAutoMod::RuleUpdate => 141,
+            ActionAutoMod::RuleDelete => 142,
+            ActionAutoMod::BlockMessage => 143,
+            ActionAutoMod::FlagToChannel => 144,
+            ActionAutoMod::UserCommunicationDisabled => 145,
+        }
+    }
+}
+
+/// A role added to or removed from a member, as part of a [`Change`].
+///
+/// [`Change`]: enum.Change.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AffectedRole {
+    pub id: RoleId,
+    pub name: String,
+}
+
+macro_rules! changes {
+    ($($(#[$attr:meta])* $variant:ident($key:literal): $kind:ty,)*) => {
+        /// A change made to a property of the target of an [`AuditLogEntry`],
+        /// with its values before and after the change.
+        ///
+        /// Changes to properties not known to the library, or whose values do
+        /// not have the expected type, are kept as an [`Other`] change.
+        ///
+        /// [`AuditLogEntry`]: struct.AuditLogEntry.html
+        /// [`Other`]: #variant.Other
+        #[derive(Clone, Debug)]
+        #[non_exhaustive]
+        pub enum Change {
+            $(
+                $(#[$attr])*
+                $variant {
+                    old: Option<$kind>,
+                    new: Option<$kind>,
+                },
+            )*
+            /// A change of any other property.
+            Other {
+                name: String,
+                old: Option<Value>,
+                new: Option<Value>,
+            },
+        }
+
+        impl Change {
+            /// The name of the changed property, as sent by Discord.
+            pub fn name(&self) -> &str {
+                match self {
+                    $($(#[$attr])* Change::$variant { .. } => $key,)*
+                    Change::Other { name, .. } => name,
+                }
+            }
+
+            fn from_raw(raw: RawChange) -> Self {
+                match raw.key.as_str() {
+                    $($(#[$attr])* $key => {
+                        if let (Ok(old), Ok(new)) = (decode(&raw.old_value), decode(&raw.new_value)) {
+                            return Change::$variant { old, new };
+                        }
+                    },)*
+                    _ => {},
+                }
+
+                Change::Other {
+                    name: raw.key,
+                    old: raw.old_value,
+                    new: raw.new_value,
+                }
+            }
+        }
+
+        impl Serialize for Change {
+            fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+                match self {
+                    $($(#[$attr])* Change::$variant { old, new } => RawChangeRef {
+                        key: $key,
+                        old_value: old,
+                        new_value: new,
+                    }.serialize(serializer),)*
+                    Change::Other { name, old, new } => RawChangeRef {
+                        key: name,
+                        old_value: old,
+                        new_value: new,
+                    }.serialize(serializer),
+                }
+            }
+        }
+    };
+}
+
+changes! {
+    AfkChannelId("afk_channel_id"): ChannelId,
+    AfkTimeout("afk_timeout"): u64,
+    Allow("allow"): Permissions,
+    ApplicationId("application_id"): ApplicationId,
+    Archived("archived"): bool,
+    Asset("asset"): String,
+    AutoArchiveDuration("auto_archive_duration"): u64,
+    AutoModActions("actions"): Vec<AutoModAction>,
+    AutoModEventType("event_type"): AutoModEventType,
+    AutoModTriggerMetadata("trigger_metadata"): AutoModTriggerMetadata,
+    AutoModTriggerType("trigger_type"): AutoModTriggerType,
+    Available("available"): bool,
+    AvatarHash("avatar_hash"): String,
+    BannerHash("banner_hash"): String,
+    Bitrate("bitrate"): u64,
+    ChannelId("channel_id"): ChannelId,
+    Code("code"): String,
+    #[cfg(feature = "utils")]
+    Colour("color"): Colour,
+    #[cfg(not(feature = "utils"))]
+    Colour("color"): u32,
+    CommunicationDisabledUntil("communication_disabled_until"): DateTime<Utc>,
+    Deaf("deaf"): bool,
+    DefaultAutoArchiveDuration("default_auto_archive_duration"): u64,
+    DefaultMessageNotifications("default_message_notifications"): DefaultMessageNotificationLevel,
+    Deny("deny"): Permissions,
+    Description("description"): String,
+    DiscoverySplashHash("discovery_splash_hash"): String,
+    EnableEmoticons("enable_emoticons"): bool,
+    Enabled("enabled"): bool,
+    EntityType("entity_type"): ScheduledEventType,
+    ExemptChannels("exempt_channels"): Vec<ChannelId>,
+    ExemptRoles("exempt_roles"): Vec<RoleId>,
+    ExpireBehavior("expire_behavior"): u64,
+    ExpireGracePeriod("expire_grace_period"): u64,
+    ExplicitContentFilter("explicit_content_filter"): ExplicitContentFilter,
+    FormatType("format_type"): StickerFormatType,
+    GuildId("guild_id"): GuildId,
+    Hoist("hoist"): bool,
+    IconHash("icon_hash"): String,
+    ImageHash("image_hash"): String,
+    Invitable("invitable"): bool,
+    InviterId("inviter_id"): UserId,
+    Location("location"): String,
+    Locked("locked"): bool,
+    MaxAge("max_age"): u64,
+    MaxUses("max_uses"): u64,
+    Mentionable("mentionable"): bool,
+    MfaLevel("mfa_level"): MfaLevel,
+    Mute("mute"): bool,
+    Name("name"): String,
+    Nick("nick"): String,
+    Nsfw("nsfw"): bool,
+    OwnerId("owner_id"): UserId,
+    PermissionOverwrites("permission_overwrites"): Vec<PermissionOverwrite>,
+    Permissions("permissions"): Permissions,
+    Position("position"): i64,
+    PreferredLocale("preferred_locale"): String,
+    PremiumProgressBarEnabled("premium_progress_bar_enabled"): bool,
+    PrivacyLevel("privacy_level"): StagePrivacyLevel,
+    PruneDeleteDays("prune_delete_days"): u64,
+    PublicUpdatesChannelId("public_updates_channel_id"): ChannelId,
+    RateLimitPerUser("rate_limit_per_user"): u64,
+    Region("region"): String,
+    RolesAdded("$add"): Vec<AffectedRole>,
+    RolesRemoved("$remove"): Vec<AffectedRole>,
+    RulesChannelId("rules_channel_id"): ChannelId,
+    SplashHash("splash_hash"): String,
+    Status("status"): ScheduledEventStatus,
+    SystemChannelId("system_channel_id"): ChannelId,
+    Tags("tags"): String,
+    Temporary("temporary"): bool,
+    Topic("topic"): String,
+    UnicodeEmoji("unicode_emoji"): String,
+    UserLimit("user_limit"): u64,
+    Uses("uses"): u64,
+    VanityUrlCode("vanity_url_code"): String,
+    VerificationLevel("verification_level"): VerificationLevel,
+    WidgetChannelId("widget_channel_id"): ChannelId,
+    WidgetEnabled("widget_enabled"): bool,
+}
+
+impl<'de> Deserialize<'de> for Change {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        RawChange::deserialize(deserializer).map(Change::from_raw)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawChange {
+    key: String,
+    #[serde(default)]
+    old_value: Option<Value>,
+    #[serde(default)]
+    new_value: Option<Value>,
+}
+
+#[derive(Serialize)]
+struct RawChangeRef<'a, T> {
+    key: &'a str,
+    #[serde(skip_serializing_if = "is_none")]
+    old_value: &'a Option<T>,
+    #[serde(skip_serializing_if = "is_none")]
+    new_value: &'a Option<T>,
+}
+
+fn is_none<T>(value: &&Option<T>) -> bool {
+    value.is_none()
+}
+
+fn decode<T: DeserializeOwned>(value: &Option<Value>) -> serde_json::Result<Option<T>> {
+    value.as_ref().map(|value| T::deserialize(value)).transpose()
 }
 
 #[derive(Debug)]
@@ -372,7 +681,7 @@ mod action_handler {
             type Value = Action;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("an integer between 1 to 72")
+                formatter.write_str("an integer")
             }
 
             // NOTE: Serde internally delegates number types below `u64` to it.
@@ -390,7 +699,13 @@ mod action_handler {
                     60..=62 => Action::Emoji(unsafe { transmute(value) }),
                     72..=75 => Action::Message(unsafe { transmute(value) }),
                     80..=82 => Action::Integration(unsafe { transmute(value) }),
-                    _ => return Err(E::custom(format!("Unexpected action number: {}", value))),
+                    83..=85 => Action::StageInstance(unsafe { transmute(value) }),
+                    90..=92 => Action::Sticker(unsafe { transmute(value) }),
+                    100..=102 => Action::ScheduledEvent(unsafe { transmute(value) }),
+                    110..=112 => Action::Thread(unsafe { transmute(value) }),
+                    121 => Action::ApplicationCommandPermissionUpdate,
+                    140..=145 => Action::AutoMod(unsafe { transmute(value) }),
+                    _ => Action::Unknown(value),
                 })
             }
         }
@@ -0,0 +1,325 @@
+use crate::model::prelude::*;
+
+#[cfg(feature = "model")]
+use crate::builder::EditAutoModRule;
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// A rule of a [`Guild`]'s auto moderation, blocking or flagging messages
+/// matching its trigger.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModRule {
+    /// The Id of the rule.
+    pub id: AutoModRuleId,
+    /// The Id of the guild the rule belongs to.
+    pub guild_id: GuildId,
+    /// The name of the rule.
+    pub name: String,
+    /// The Id of the user who created the rule.
+    pub creator_id: UserId,
+    /// The context in which the rule is checked.
+    pub event_type: AutoModEventType,
+    /// The kind of content triggering the rule.
+    pub trigger_type: AutoModTriggerType,
+    /// Additional data of the rule's trigger.
+    pub trigger_metadata: AutoModTriggerMetadata,
+    /// The actions executed when the rule is triggered.
+    pub actions: Vec<AutoModAction>,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// The roles whose members are not affected by the rule.
+    pub exempt_roles: Vec<RoleId>,
+    /// The channels in which the rule is not checked.
+    pub exempt_channels: Vec<ChannelId>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl AutoModRule {
+    /// Deletes the rule.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.guild_id.delete_automod_rule(http, self.id).await
+    }
+
+    /// Edits the rule, refer to [`GuildId::edit_automod_rule`] for more
+    /// information.
+    ///
+    /// [`GuildId::edit_automod_rule`]: ../id/struct.GuildId.html#method.edit_automod_rule
+    #[inline]
+    pub async fn edit<F>(&self, http: impl AsRef<Http>, f: F) -> Result<AutoModRule>
+    where F: FnOnce(&mut EditAutoModRule) -> &mut EditAutoModRule {
+        self.guild_id.edit_automod_rule(http, self.id, f).await
+    }
+}
+
+/// Additional data of an [`AutoModRule`]'s trigger.
+///
+/// Which fields are relevant depends on the rule's [`AutoModTriggerType`].
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+/// [`AutoModTriggerType`]: enum.AutoModTriggerType.html
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AutoModTriggerMetadata {
+    /// The words or phrases blocked by a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    #[serde(default)]
+    pub keyword_filter: Vec<String>,
+    /// The regular expressions blocked by a [`Keyword`] rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    #[serde(default)]
+    pub regex_patterns: Vec<String>,
+    /// The Discord-defined word lists blocked by a [`KeywordPreset`] rule.
+    ///
+    /// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+    #[serde(default)]
+    pub presets: Vec<AutoModKeywordPresetType>,
+    /// The words or phrases exempt from a [`Keyword`] or [`KeywordPreset`]
+    /// rule.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    /// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+    /// The number of unique role and user mentions allowed per message by a
+    /// [`MentionSpam`] rule.
+    ///
+    /// [`MentionSpam`]: enum.AutoModTriggerType.html#variant.MentionSpam
+    pub mention_total_limit: Option<u64>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// An action executed when an [`AutoModRule`] is triggered.
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutoModAction {
+    /// The type of the action.
+    #[serde(rename = "type")]
+    pub kind: AutoModActionType,
+    /// Additional data of the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutoModActionMetadata>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+impl AutoModAction {
+    /// Creates an action of the given type without additional data.
+    pub fn new(kind: AutoModActionType) -> Self {
+        Self {
+            kind,
+            metadata: None,
+            _nonexhaustive: (),
+        }
+    }
+
+    /// Creates a [`BlockMessage`] action, optionally showing the user a
+    /// custom explanation.
+    ///
+    /// [`BlockMessage`]: enum.AutoModActionType.html#variant.BlockMessage
+    pub fn block_message(custom_message: Option<String>) -> Self {
+        Self {
+            kind: AutoModActionType::BlockMessage,
+            metadata: custom_message.map(|message| AutoModActionMetadata {
+                custom_message: Some(message),
+                ..Default::default()
+            }),
+            _nonexhaustive: (),
+        }
+    }
+
+    /// Creates a [`SendAlertMessage`] action logging to the given channel.
+    ///
+    /// [`SendAlertMessage`]: enum.AutoModActionType.html#variant.SendAlertMessage
+    pub fn send_alert_message<C: Into<ChannelId>>(channel_id: C) -> Self {
+        Self {
+            kind: AutoModActionType::SendAlertMessage,
+            metadata: Some(AutoModActionMetadata {
+                channel_id: Some(channel_id.into()),
+                ..Default::default()
+            }),
+            _nonexhaustive: (),
+        }
+    }
+
+    /// Creates a [`Timeout`] action timing the user out for the given number
+    /// of seconds.
+    ///
+    /// **Note**: The duration can be at most 2419200 seconds (4 weeks).
+    ///
+    /// [`Timeout`]: enum.AutoModActionType.html#variant.Timeout
+    pub fn timeout(duration_seconds: u64) -> Self {
+        Self {
+            kind: AutoModActionType::Timeout,
+            metadata: Some(AutoModActionMetadata {
+                duration_seconds: Some(duration_seconds),
+                ..Default::default()
+            }),
+            _nonexhaustive: (),
+        }
+    }
+}
+
+/// Additional data of an [`AutoModAction`].
+///
+/// [`AutoModAction`]: struct.AutoModAction.html
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AutoModActionMetadata {
+    /// The channel alerts are sent to by a [`SendAlertMessage`] action.
+    ///
+    /// [`SendAlertMessage`]: enum.AutoModActionType.html#variant.SendAlertMessage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// The timeout duration in seconds of a [`Timeout`] action.
+    ///
+    /// [`Timeout`]: enum.AutoModActionType.html#variant.Timeout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u64>,
+    /// The explanation shown to the user by a [`BlockMessage`] action.
+    ///
+    /// [`BlockMessage`]: enum.AutoModActionType.html#variant.BlockMessage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The context in which an [`AutoModRule`] is checked.
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModEventType {
+    /// The rule is checked when a member sends or edits a message.
+    MessageSend = 1,
+}
+
+enum_number!(
+    AutoModEventType {
+        MessageSend,
+    }
+);
+
+impl AutoModEventType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModEventType::MessageSend => 1,
+        }
+    }
+}
+
+/// The kind of content triggering an [`AutoModRule`].
+///
+/// [`AutoModRule`]: struct.AutoModRule.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModTriggerType {
+    /// The content contains words or patterns of the rule's keyword filter.
+    Keyword = 1,
+    /// The content is considered spam.
+    Spam = 3,
+    /// The content contains words of Discord-defined word lists.
+    KeywordPreset = 4,
+    /// The content contains too many unique mentions.
+    MentionSpam = 5,
+}
+
+enum_number!(
+    AutoModTriggerType {
+        Keyword,
+        Spam,
+        KeywordPreset,
+        MentionSpam,
+    }
+);
+
+impl AutoModTriggerType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModTriggerType::Keyword => 1,
+            AutoModTriggerType::Spam => 3,
+            AutoModTriggerType::KeywordPreset => 4,
+            AutoModTriggerType::MentionSpam => 5,
+        }
+    }
+}
+
+/// A Discord-defined word list of a [`KeywordPreset`] rule.
+///
+/// [`KeywordPreset`]: enum.AutoModTriggerType.html#variant.KeywordPreset
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModKeywordPresetType {
+    /// Words considered swearing or cursing.
+    Profanity = 1,
+    /// Words referring to sexually explicit behaviour or activity.
+    SexualContent = 2,
+    /// Personal insults or words considered hate speech.
+    Slurs = 3,
+}
+
+enum_number!(
+    AutoModKeywordPresetType {
+        Profanity,
+        SexualContent,
+        Slurs,
+    }
+);
+
+impl AutoModKeywordPresetType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModKeywordPresetType::Profanity => 1,
+            AutoModKeywordPresetType::SexualContent => 2,
+            AutoModKeywordPresetType::Slurs => 3,
+        }
+    }
+}
+
+/// The type of an [`AutoModAction`].
+///
+/// [`AutoModAction`]: struct.AutoModAction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModActionType {
+    /// Blocks the message.
+    BlockMessage = 1,
+    /// Sends an alert to a channel.
+    SendAlertMessage = 2,
+    /// Times the member out.
+    ///
+    /// **Note**: Only available for [`Keyword`] and [`MentionSpam`] rules.
+    ///
+    /// [`Keyword`]: enum.AutoModTriggerType.html#variant.Keyword
+    /// [`MentionSpam`]: enum.AutoModTriggerType.html#variant.MentionSpam
+    Timeout = 3,
+}
+
+enum_number!(
+    AutoModActionType {
+        BlockMessage,
+        SendAlertMessage,
+        Timeout,
+    }
+);
+
+impl AutoModActionType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModActionType::BlockMessage => 1,
+            AutoModActionType::SendAlertMessage => 2,
+            AutoModActionType::Timeout => 3,
+        }
+    }
+}
@@ -18,6 +18,8 @@ use crate::model::id::GuildId;
 use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::http::CacheHttp;
+#[cfg(feature = "model")]
+use crate::model::misc::{CdnUrl, ImageFormat};
 
 /// Represents a custom guild emoji, which can either be created using the API,
 /// or via an integration. Emojis created using the API only work within the
@@ -199,8 +201,24 @@ impl Emoji {
     /// ```
     #[inline]
     pub fn url(&self) -> String {
-        let extension = if self.animated {"gif"} else {"png"};
-        format!(cdn!("/emojis/{}.{}"), self.id, extension)
+        let url = self.cdn_url();
+
+        if self.animated {
+            url.build()
+        } else {
+            url.format(ImageFormat::Png).build()
+        }
+    }
+
+    /// Returns a builder for the URL of the emoji's image, to request it in
+    /// another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    #[inline]
+    pub fn cdn_url(&self) -> CdnUrl {
+        CdnUrl::new(format!("/emojis/{}", self.id)).animated(self.animated)
     }
 }
 
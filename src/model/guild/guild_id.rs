@@ -1,9 +1,27 @@
 use crate::model::prelude::*;
 
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::cache::Cache;
+use crate::cache::{Cache, CachedInvite};
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{
+    CreateApplicationCommand,
+    CreateApplicationCommandPermissionsData,
+    CreateApplicationCommands,
+    CreateApplicationCommandsPermissions,
+    CreateScheduledEvent,
+    CreateSticker,
+    EditAutoModRule,
+    EditChannelPosition,
+    EditGuild,
+    EditGuildTemplate,
+    EditGuildWelcomeScreen,
+    EditGuildWidget,
+    EditMember,
+    EditMembershipScreening,
+    EditRole,
+    EditScheduledEvent,
+    EditSticker,
+};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -12,7 +30,7 @@ use crate::utils;
 use crate::builder::CreateChannel;
 #[cfg(feature = "model")]
 use serde_json::json;
-#[cfg(feature = "cache")]
+#[cfg(feature = "model")]
 use futures::stream::Stream;
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -22,15 +40,28 @@ use crate::collector::{
     CollectReaction, ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::http::{Http, CacheHttp, UserPagination};
+#[cfg(feature = "model")]
+use crate::constants::EMOJI_MAX_SIZE;
+#[cfg(feature = "model")]
+use std::path::Path;
 
 #[cfg(feature = "model")]
 impl GuildId {
+    /// Gets all active threads of the guild, including private threads.
+    #[inline]
+    pub async fn active_threads(self, http: impl AsRef<Http>) -> Result<ThreadsData> {
+        http.as_ref().get_guild_active_threads(self.0).await
+    }
+
     /// Ban a [`User`] from the guild, deleting a number of
     /// days' worth of messages (`dmd`) between the range 0 and 7.
     ///
     /// Refer to the documentation for [`Guild::ban`] for more information.
     ///
+    /// **Note**: Discord deprecated deleting messages by days, so the days are
+    /// sent as seconds. Use [`ban_with_seconds`] for finer control.
+    ///
     /// **Note**: Requires the [Ban Members] permission.
     ///
     /// # Examples
@@ -59,10 +90,11 @@ impl GuildId {
     /// [`ModelError::DeleteMessageDaysAmount`]: ../error/enum.Error.html#variant.DeleteMessageDaysAmount
     /// [`Guild::ban`]: ../guild/struct.Guild.html#method.ban
     /// [`User`]: ../user/struct.User.html
+    /// [`ban_with_seconds`]: #method.ban_with_seconds
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
     pub async fn ban(self, http: impl AsRef<Http>, user: impl Into<UserId>, dmd: u8) -> Result<()> {
-        self._ban_with_reason(http, user.into(), dmd, "").await
+        self.ban_with_reason(http, user, dmd, "").await
     }
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`ban`] to further documentation.
@@ -77,42 +109,402 @@ impl GuildId {
         dmd: u8,
         reason: impl AsRef<str>
     ) -> Result<()> {
-        self._ban_with_reason(http, user.into(), dmd, reason.as_ref()).await
-    }
-
-    async fn _ban_with_reason(self, http: impl AsRef<Http>, user: UserId, dmd: u8, reason: &str) -> Result<()> {
         if dmd > 7 {
             return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
         }
 
+        self._ban_with_reason(http, user.into(), u32::from(dmd) * 86_400, reason.as_ref()).await
+    }
+
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the
+    /// given number of seconds, up to 7 days.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Ban a member and remove all messages they've sent in the last hour:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, UserId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// # let user = UserId(1);
+    /// GuildId(81384788765712384).ban_with_seconds(&http, user, 3600).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if more than 7
+    /// days' worth of seconds is given.
+    ///
+    /// [`ModelError::DeleteMessageSecondsAmount`]: ../error/enum.Error.html#variant.DeleteMessageSecondsAmount
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[inline]
+    pub async fn ban_with_seconds(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+    ) -> Result<()> {
+        self._ban_with_reason(http, user.into(), delete_message_seconds, "").await
+    }
+
+    /// Ban a [`User`] from the guild with a reason, deleting the messages they
+    /// sent in the given number of seconds. Refer to [`ban_with_seconds`] for
+    /// further documentation.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [`ban_with_seconds`]: #method.ban_with_seconds
+    #[inline]
+    pub async fn ban_with_seconds_and_reason(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self._ban_with_reason(http, user.into(), delete_message_seconds, reason.as_ref()).await
+    }
+
+    async fn _ban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user: UserId,
+        delete_message_seconds: u32,
+        reason: &str,
+    ) -> Result<()> {
+        if delete_message_seconds > 604_800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(delete_message_seconds)));
+        }
+
+        if reason.len() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+
+        http.as_ref().ban_user(self.0, user.0, delete_message_seconds, reason).await
+    }
+
+    /// Bans up to 200 users from the guild at once, deleting their messages
+    /// sent in the given number of seconds, up to 7 days.
+    ///
+    /// This issues a single request, instead of one per [`ban`].
+    ///
+    /// Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Ban two users, deleting their messages of the last hour:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> serenity::Result<()> {
+    /// # let http = Http::default();
+    /// use serenity::model::id::{GuildId, UserId};
+    ///
+    /// let users = vec![UserId(114941315417899012), UserId(82763826541436928)];
+    /// let response = GuildId(81384788765712384).bulk_ban(&http, users, 3600).await?;
+    ///
+    /// println!("Banned {} users", response.banned_users.len());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::BulkBanAmount`] if no or more than 200 users
+    /// are given, or a [`ModelError::DeleteMessageSecondsAmount`] if more
+    /// than 7 days' worth of seconds is given.
+    ///
+    /// [`ban`]: #method.ban
+    /// [`ModelError::BulkBanAmount`]: ../error/enum.Error.html#variant.BulkBanAmount
+    /// [`ModelError::DeleteMessageSecondsAmount`]: ../error/enum.Error.html#variant.DeleteMessageSecondsAmount
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn bulk_ban<It>(
+        self,
+        http: impl AsRef<Http>,
+        users: It,
+        delete_message_seconds: u32,
+    ) -> Result<BulkBanResponse>
+    where
+        It: IntoIterator,
+        It::Item: Into<UserId>,
+    {
+        self._bulk_ban_with_reason(http, users, delete_message_seconds, "").await
+    }
+
+    /// Bans up to 200 users from the guild at once with a reason. Refer to
+    /// [`bulk_ban`] for further documentation.
+    ///
+    /// [`bulk_ban`]: #method.bulk_ban
+    #[inline]
+    pub async fn bulk_ban_with_reason<It>(
+        self,
+        http: impl AsRef<Http>,
+        users: It,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<BulkBanResponse>
+    where
+        It: IntoIterator,
+        It::Item: Into<UserId>,
+    {
+        self._bulk_ban_with_reason(http, users, delete_message_seconds, reason.as_ref()).await
+    }
+
+    async fn _bulk_ban_with_reason<It>(
+        self,
+        http: impl AsRef<Http>,
+        users: It,
+        delete_message_seconds: u32,
+        reason: &str,
+    ) -> Result<BulkBanResponse>
+    where
+        It: IntoIterator,
+        It::Item: Into<UserId>,
+    {
+        let user_ids = users
+            .into_iter()
+            .map(|user| user.into().0)
+            .collect::<Vec<u64>>();
+
+        if user_ids.is_empty() || user_ids.len() > 200 {
+            return Err(Error::Model(ModelError::BulkBanAmount));
+        }
+
+        if delete_message_seconds > 604_800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(delete_message_seconds)));
+        }
+
         if reason.len() > 512 {
             return Err(Error::ExceededLimit(reason.to_string(), 512));
         }
 
-        http.as_ref().ban_user(self.0, user.0, dmd, reason).await
+        let map = json!({
+            "user_ids": user_ids,
+            "delete_message_seconds": delete_message_seconds,
+        });
+
+        http.as_ref().bulk_ban_users(self.0, &map, reason).await
     }
 
-    /// Gets a list of the guild's bans.
+    /// Gets a list of the guild's bans, ordered by the Id of the banned user.
+    ///
+    /// Up to `limit` bans, at most 1000, are returned after or before the
+    /// user given as `target`. Use [`bans_iter`] to get all bans.
     ///
     /// Requires the [Ban Members] permission.
     ///
+    /// [`bans_iter`]: #method.bans_iter
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
-    pub async fn bans(self, http: impl AsRef<Http>) -> Result<Vec<Ban>> {
-        http.as_ref().get_bans(self.0).await
+    pub async fn bans(
+        self,
+        http: impl AsRef<Http>,
+        target: Option<UserPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Ban>> {
+        http.as_ref().get_bans(self.0, target, limit).await
     }
 
-    /// Gets a list of the guild's audit log entries
+    /// Streams over all the bans of the guild, ordered by the Id of the banned
+    /// user.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`bans`]. A
+    /// buffer of at most 1000 bans is used to reduce the number of calls
+    /// necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut bans = guild_id.bans_iter(&ctx).boxed();
+    /// while let Some(ban_result) = bans.next().await {
+    ///     match ban_result {
+    ///         Ok(ban) => println!("{} is banned", ban.user.name),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`bans`]: #method.bans
+    pub fn bans_iter<H: AsRef<Http>>(self, http: H) -> impl Stream<Item=Result<Ban>> {
+        BansIter::<H>::stream(http, self)
+    }
+
+    /// Gets all [`ApplicationCommand`]s of the application only available
+    /// in the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    #[inline]
+    pub async fn application_commands(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        http.as_ref().get_guild_application_commands(application_id.into().0, self.0).await
+    }
+
+    /// Gets an [`ApplicationCommand`] of the application only available in
+    /// the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    #[inline]
+    pub async fn application_command(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<ApplicationCommand> {
+        http.as_ref().get_guild_application_command(application_id.into().0, self.0, command_id.into().0).await
+    }
+
+    /// Gets the permissions of an [`ApplicationCommand`] of the application
+    /// in the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    #[inline]
+    pub async fn application_command_permissions(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<ApplicationCommandPermission> {
+        http.as_ref().get_guild_application_command_permissions(application_id.into().0, self.0, command_id.into().0).await
+    }
+
+    /// Gets the permissions of all [`ApplicationCommand`]s of the
+    /// application in the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    #[inline]
+    pub async fn application_commands_permissions(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationCommandPermission>> {
+        http.as_ref().get_guild_application_commands_permissions(application_id.into().0, self.0).await
+    }
+
+    /// Gets a list of the guild's audit log entries, newest first.
+    ///
+    /// The entries can be filtered by the type of action, the user who did it,
+    /// and to those older than the entry given as `before`. At most 100
+    /// entries are returned, by default 50.
+    ///
+    /// # Examples
+    ///
+    /// Get the latest bans of the guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::GuildId;
+    /// use serenity::model::guild::{Action, ActionMember};
+    ///
+    /// # async fn run() -> serenity::Result<()> {
+    /// # let http = Http::default();
+    /// # let guild_id = GuildId(7);
+    /// let action = Action::Member(ActionMember::BanAdd);
+    /// let logs = guild_id.audit_logs(&http, Some(action), None, None, Some(10)).await?;
+    ///
+    /// for entry in logs.entries.values() {
+    ///     println!("{:?} banned by {}", entry.target_id, entry.user_id);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
     #[inline]
     pub async fn audit_logs(
         self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
     ) -> Result<AuditLogs> {
-        http.as_ref().get_audit_logs(self.0, action_type, user_id.map(|u| u.0), before.map(|a| a.0), limit).await
+        http.as_ref().get_audit_logs(
+            self.0,
+            action_type.map(|action| action.num()),
+            user_id.map(|u| u.0),
+            before.map(|a| a.0),
+            limit,
+        ).await
+    }
+
+    /// Streams over the guild's audit log entries, newest first, optionally
+    /// filtered by the type of action and the user who did it.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`audit_logs`]. A buffer of at most 100 entries is used to reduce the
+    /// number of calls necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut entries = guild_id.audit_logs_iter(&ctx, None, None).boxed();
+    /// while let Some(entry_result) = entries.next().await {
+    ///     match entry_result {
+    ///         Ok(entry) => println!("{:?} by {}", entry.action, entry.user_id),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`audit_logs`]: #method.audit_logs
+    pub fn audit_logs_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> impl Stream<Item=Result<AuditLogEntry>> {
+        AuditLogsIter::<H>::stream(http, self, action_type, user_id)
+    }
+
+    /// Gets an [`AutoModRule`] of the guild by its Id.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn automod_rule(self, http: impl AsRef<Http>, rule_id: impl Into<AutoModRuleId>) -> Result<AutoModRule> {
+        http.as_ref().get_automod_rule(self.0, rule_id.into().0).await
+    }
+
+    /// Gets all [`AutoModRule`]s of the guild.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn automod_rules(self, http: impl AsRef<Http>) -> Result<Vec<AutoModRule>> {
+        http.as_ref().get_automod_rules(self.0).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -133,6 +525,49 @@ impl GuildId {
         Ok(channels)
     }
 
+    /// Creates an [`ApplicationCommand`] only available in the guild.
+    ///
+    /// Unlike global commands, guild commands are available immediately.
+    ///
+    /// Refer to [`CreateApplicationCommand`] for an example.
+    ///
+    /// **Note**: Creating a command with the same name as an existing command
+    /// overwrites the existing command.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    /// [`CreateApplicationCommand`]: ../../builder/struct.CreateApplicationCommand.html
+    pub async fn create_application_command<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let map = utils::hashmap_to_json_map(command.0);
+
+        http.as_ref().create_guild_application_command(application_id.into().0, self.0, &Value::Object(map)).await
+    }
+
+    /// Creates an [`AutoModRule`] in the guild.
+    ///
+    /// Refer to [`EditAutoModRule`] for the required fields.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EditAutoModRule`]: ../../builder/struct.EditAutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn create_automod_rule<F>(self, http: impl AsRef<Http>, f: F) -> Result<AutoModRule>
+    where F: FnOnce(&mut EditAutoModRule) -> &mut EditAutoModRule {
+        let mut rule = EditAutoModRule::default();
+        f(&mut rule);
+        let map = utils::hashmap_to_json_map(rule.0);
+
+        http.as_ref().create_automod_rule(self.0, &map).await
+    }
+
     /// Creates a [`GuildChannel`] in the the guild.
     ///
     /// Refer to [`Http::create_channel`] for more information.
@@ -176,11 +611,9 @@ impl GuildId {
     ///
     /// # Examples
     ///
-    /// See the [`EditProfile::avatar`] example for an in-depth example as to
-    /// how to read an image from the filesystem and encode it as base64. Most
-    /// of the example can be applied similarly for this method.
+    /// See [`utils::read_image`] for how to read an image from the filesystem
+    /// and encode it as base64.
     ///
-    /// [`EditProfile::avatar`]: ../../builder/struct.EditProfile.html#method.avatar
     /// [`Guild::create_emoji`]: ../guild/struct.Guild.html#method.create_emoji
     /// [`utils::read_image`]: ../../utils/fn.read_image.html
     /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
@@ -194,6 +627,88 @@ impl GuildId {
         http.as_ref().create_emoji(self.0, &map).await
     }
 
+    /// Creates an emoji in the guild with a name and an image, given as the
+    /// raw bytes of a PNG, JPEG, GIF or WebP file.
+    ///
+    /// The image is encoded as a base64 data URI for you. If `roles` is not
+    /// empty, only members with one of the roles may use the emoji.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmojiTooLarge`] if the image is larger than
+    /// 256 KiB, without sending a request.
+    ///
+    /// [`ModelError::EmojiTooLarge`]: ../error/enum.Error.html#variant.EmojiTooLarge
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn create_emoji_from_bytes(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        image: &[u8],
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        let size = image.len() as u64;
+
+        if size > EMOJI_MAX_SIZE {
+            return Err(Error::Model(ModelError::EmojiTooLarge(size - EMOJI_MAX_SIZE)));
+        }
+
+        let mut map = json!({
+            "name": name,
+            "image": utils::encode_image(image),
+        });
+
+        if !roles.is_empty() {
+            map["roles"] = json!(roles.iter().map(|role| role.0).collect::<Vec<u64>>());
+        }
+
+        http.as_ref().create_emoji(self.0, &map).await
+    }
+
+    /// Creates an emoji in the guild with a name and the image at the given
+    /// path.
+    ///
+    /// Refer to [`create_emoji_from_bytes`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Create an emoji only members with a role may use:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let guild_id = GuildId(81384788765712384);
+    ///
+    /// let emoji = guild_id
+    ///     .create_emoji_from_path(&http, "ferris", "./ferris.png", &[RoleId(136107769680887808)])
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the file can not be read.
+    ///
+    /// [`Error::Io`]: ../../enum.Error.html#variant.Io
+    /// [`create_emoji_from_bytes`]: #method.create_emoji_from_bytes
+    pub async fn create_emoji_from_path(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        path: impl AsRef<Path>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        let image = tokio::fs::read(path).await?;
+
+        self.create_emoji_from_bytes(http, name, &image, roles).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -234,6 +749,62 @@ impl GuildId {
         Ok(role)
     }
 
+    /// Creates a [`ScheduledEvent`] in the guild.
+    ///
+    /// Refer to [`CreateScheduledEvent`] for the required fields.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`CreateScheduledEvent`]: ../../builder/struct.CreateScheduledEvent.html
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    pub async fn create_scheduled_event<F>(self, http: impl AsRef<Http>, f: F) -> Result<ScheduledEvent>
+    where F: FnOnce(&mut CreateScheduledEvent) -> &mut CreateScheduledEvent {
+        let mut event = CreateScheduledEvent::default();
+        f(&mut event);
+        let map = utils::hashmap_to_json_map(event.0);
+
+        http.as_ref().create_scheduled_event(self.0, &Value::Object(map)).await
+    }
+
+    /// Uploads a [`Sticker`] to the guild.
+    ///
+    /// Refer to [`CreateSticker`] for the required fields.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Other`] if no file was set.
+    ///
+    /// [`CreateSticker`]: ../../builder/struct.CreateSticker.html
+    /// [`Error::Other`]: ../../enum.Error.html#variant.Other
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn create_sticker<'a, F>(self, http: impl AsRef<Http>, f: F) -> Result<Sticker>
+    where for<'b> F: FnOnce(&'b mut CreateSticker<'a>) -> &'b mut CreateSticker<'a> {
+        let mut create_sticker = CreateSticker::default();
+        f(&mut create_sticker);
+        let CreateSticker(map, file) = create_sticker;
+        let file = file.ok_or(Error::Other("A sticker requires a file"))?;
+
+        http.as_ref().create_sticker(self.0, utils::hashmap_to_json_map(map), file).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn create_template(self, http: impl AsRef<Http>, name: &str, description: Option<&str>) -> Result<GuildTemplate> {
+        let map = json!({
+            "name": name,
+            "description": description,
+        });
+
+        http.as_ref().create_guild_template(self.0, &map).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -247,6 +818,19 @@ impl GuildId {
         http.as_ref().delete_guild(self.0).await
     }
 
+    /// Deletes an [`ApplicationCommand`] only available in the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    #[inline]
+    pub async fn delete_application_command(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_application_command(application_id.into().0, self.0, command_id.into().0).await
+    }
+
     /// Deletes an [`Emoji`] from the guild.
     ///
     /// Requires the [Manage Emojis] permission.
@@ -258,6 +842,32 @@ impl GuildId {
         http.as_ref().delete_emoji(self.0, emoji_id.into().0).await
     }
 
+    /// Deletes an [`Emoji`] from the guild, with a reason shown in the audit
+    /// log. Refer to [`delete_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [`delete_emoji`]: #method.delete_emoji
+    #[inline]
+    pub async fn delete_emoji_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().delete_emoji_with_reason(self.0, emoji_id.into().0, reason.as_ref()).await
+    }
+
+    /// Deletes an [`AutoModRule`] by Id from the guild.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn delete_automod_rule(self, http: impl AsRef<Http>, rule_id: impl Into<AutoModRuleId>) -> Result<()> {
+        http.as_ref().delete_automod_rule(self.0, rule_id.into().0).await
+    }
+
     /// Deletes an integration by Id from the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -268,6 +878,21 @@ impl GuildId {
         http.as_ref().delete_guild_integration(self.0, integration_id.into().0).await
     }
 
+    /// Deletes an integration by Id from the guild, with a reason shown in
+    /// the audit log. Refer to [`delete_integration`] for further
+    /// documentation.
+    ///
+    /// [`delete_integration`]: #method.delete_integration
+    #[inline]
+    pub async fn delete_integration_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_integration_with_reason(self.0, integration_id.into().0, reason.as_ref()).await
+    }
+
     /// Deletes a [`Role`] by Id from the guild.
     ///
     /// Also see [`Role::delete`] if you have the `cache` and `methods` features
@@ -283,6 +908,54 @@ impl GuildId {
         http.as_ref().delete_role(self.0, role_id.into().0).await
     }
 
+    /// Deletes a [`Role`] by Id from the guild, with a reason shown in the
+    /// audit log. Refer to [`delete_role`] for further documentation.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`delete_role`]: #method.delete_role
+    #[inline]
+    pub async fn delete_role_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().delete_role_with_reason(self.0, role_id.into().0, reason.as_ref()).await
+    }
+
+    /// Deletes a [`ScheduledEvent`] by Id from the guild.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    #[inline]
+    pub async fn delete_scheduled_event(self, http: impl AsRef<Http>, event_id: impl Into<ScheduledEventId>) -> Result<()> {
+        http.as_ref().delete_scheduled_event(self.0, event_id.into().0).await
+    }
+
+    /// Deletes a [`Sticker`] by Id from the guild.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn delete_sticker(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>) -> Result<()> {
+        http.as_ref().delete_sticker(self.0, sticker_id.into().0).await
+    }
+
+    /// Deletes a [`GuildTemplate`] of the guild by its code, returning the
+    /// deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self.0, code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -303,6 +976,52 @@ impl GuildId {
         http.as_ref().edit_guild(self.0, &map).await
     }
 
+    /// Edits an [`ApplicationCommand`] only available in the guild.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    pub async fn edit_application_command<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let map = utils::hashmap_to_json_map(command.0);
+
+        http.as_ref().edit_guild_application_command(
+            application_id.into().0,
+            self.0,
+            command_id.into().0,
+            &Value::Object(map),
+        ).await
+    }
+
+    /// Edits an [`AutoModRule`] of the guild.
+    ///
+    /// Refer to [`EditAutoModRule`] for the available options.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`AutoModRule`]: ../guild/struct.AutoModRule.html
+    /// [`EditAutoModRule`]: ../../builder/struct.EditAutoModRule.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_automod_rule<F>(
+        self,
+        http: impl AsRef<Http>,
+        rule_id: impl Into<AutoModRuleId>,
+        f: F,
+    ) -> Result<AutoModRule>
+    where F: FnOnce(&mut EditAutoModRule) -> &mut EditAutoModRule {
+        let mut rule = EditAutoModRule::default();
+        f(&mut rule);
+        let map = utils::hashmap_to_json_map(rule.0);
+
+        http.as_ref().edit_automod_rule(self.0, rule_id.into().0, &map).await
+    }
+
     /// Edits an [`Emoji`]'s name in the guild.
     ///
     /// Also see [`Emoji::edit`] if you have the `cache` and `methods` features
@@ -322,6 +1041,47 @@ impl GuildId {
         http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map).await
     }
 
+    /// Edits an [`Emoji`]'s name in the guild, with a reason shown in the
+    /// audit log. Refer to [`edit_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [`edit_emoji`]: #method.edit_emoji
+    #[inline]
+    pub async fn edit_emoji_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+        reason: impl AsRef<str>,
+    ) -> Result<Emoji> {
+        let map = json!({
+            "name": name,
+        });
+
+        http.as_ref().edit_emoji_with_reason(self.0, emoji_id.into().0, &map, reason.as_ref()).await
+    }
+
+    /// Sets the roles allowed to use an [`Emoji`] of the guild, allowing
+    /// everyone to use it if `roles` is empty.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn edit_emoji_roles(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        let map = json!({
+            "roles": roles.iter().map(|role| role.0).collect::<Vec<u64>>(),
+        });
+
+        http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map).await
+    }
+
     /// Edits the properties of member of the guild, such as muting or
     /// nicknaming them.
     ///
@@ -345,6 +1105,26 @@ impl GuildId {
         http.as_ref().edit_member(self.0, user_id.into().0, &map).await
     }
 
+    /// Edits a [`Member`] of the guild with a reason shown in the audit log.
+    /// Refer to [`edit_member`] for further documentation.
+    ///
+    /// [`Member`]: ../guild/struct.Member.html
+    /// [`edit_member`]: #method.edit_member
+    pub async fn edit_member_with_reason<F>(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: impl AsRef<str>,
+        f: F,
+    ) -> Result<()>
+        where F: FnOnce(&mut EditMember) -> &mut EditMember {
+        let mut edit_member = EditMember::default();
+        f(&mut edit_member);
+        let map = utils::hashmap_to_json_map(edit_member.0);
+
+        http.as_ref().edit_member_with_reason(self.0, user_id.into().0, &map, reason.as_ref()).await
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -384,6 +1164,27 @@ impl GuildId {
         http.as_ref().edit_role(self.0, role_id.into().0, &map).await
     }
 
+    /// Edits a [`Role`] with a reason shown in the audit log. Refer to
+    /// [`edit_role`] for further documentation.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`edit_role`]: #method.edit_role
+    pub async fn edit_role_with_reason<F>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: impl AsRef<str>,
+        f: F,
+    ) -> Result<Role>
+    where F: FnOnce(&mut EditRole) -> &mut EditRole
+    {
+        let mut edit_role = EditRole::default();
+        f(&mut edit_role);
+        let map = utils::hashmap_to_json_map(edit_role.0);
+
+        http.as_ref().edit_role_with_reason(self.0, role_id.into().0, &map, reason.as_ref()).await
+    }
+
     /// Edits the order of [`Role`]s
     /// Requires the [Manage Roles] permission.
     ///
@@ -403,6 +1204,115 @@ impl GuildId {
         http.as_ref().edit_role_position(self.0, role_id.into().0, position).await
     }
 
+    /// Edits a [`ScheduledEvent`] of the guild.
+    ///
+    /// Refer to [`EditScheduledEvent`] for the available options.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [`EditScheduledEvent`]: ../../builder/struct.EditScheduledEvent.html
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    pub async fn edit_scheduled_event<F>(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        f: F,
+    ) -> Result<ScheduledEvent>
+    where F: FnOnce(&mut EditScheduledEvent) -> &mut EditScheduledEvent {
+        let mut event = EditScheduledEvent::default();
+        f(&mut event);
+        let map = utils::hashmap_to_json_map(event.0);
+
+        http.as_ref().edit_scheduled_event(self.0, event_id.into().0, &Value::Object(map)).await
+    }
+
+    /// Edits a [`Sticker`] of the guild.
+    ///
+    /// Refer to [`EditSticker`] for the available options.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`EditSticker`]: ../../builder/struct.EditSticker.html
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn edit_sticker<F>(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>, f: F) -> Result<Sticker>
+    where F: FnOnce(&mut EditSticker) -> &mut EditSticker {
+        let mut edit_sticker = EditSticker::default();
+        f(&mut edit_sticker);
+        let map = utils::hashmap_to_json_map(edit_sticker.0);
+
+        http.as_ref().edit_sticker(self.0, sticker_id.into().0, &map).await
+    }
+
+    /// Edits a [`GuildTemplate`] of the guild by its code.
+    ///
+    /// Refer to [`EditGuildTemplate`] for the available options.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildTemplate`]: ../../builder/struct.EditGuildTemplate.html
+    /// [`GuildTemplate`]: ../guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_template<F>(self, http: impl AsRef<Http>, code: &str, f: F) -> Result<GuildTemplate>
+    where F: FnOnce(&mut EditGuildTemplate) -> &mut EditGuildTemplate {
+        let mut edit_template = EditGuildTemplate::default();
+        f(&mut edit_template);
+        let map = utils::hashmap_to_json_map(edit_template.0);
+
+        http.as_ref().edit_guild_template(self.0, code, &Value::Object(map)).await
+    }
+
+    /// Edits the guild's membership screening.
+    ///
+    /// Refer to [`EditMembershipScreening`] for the available options.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`EditMembershipScreening`]: ../../builder/struct.EditMembershipScreening.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_membership_screening<F>(self, http: impl AsRef<Http>, f: F) -> Result<MembershipScreening>
+    where F: FnOnce(&mut EditMembershipScreening) -> &mut EditMembershipScreening {
+        let mut edit_membership_screening = EditMembershipScreening::default();
+        f(&mut edit_membership_screening);
+        let map = utils::hashmap_to_json_map(edit_membership_screening.0);
+
+        http.as_ref().edit_guild_membership_screening(self.0, &Value::Object(map)).await
+    }
+
+    /// Edits the guild's welcome screen.
+    ///
+    /// Refer to [`EditGuildWelcomeScreen`] for the available options.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildWelcomeScreen`]: ../../builder/struct.EditGuildWelcomeScreen.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_welcome_screen<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildWelcomeScreen>
+    where F: FnOnce(&mut EditGuildWelcomeScreen) -> &mut EditGuildWelcomeScreen {
+        let mut edit_welcome_screen = EditGuildWelcomeScreen::default();
+        f(&mut edit_welcome_screen);
+        let map = utils::hashmap_to_json_map(edit_welcome_screen.0);
+
+        http.as_ref().edit_guild_welcome_screen(self.0, &Value::Object(map)).await
+    }
+
+    /// Edits the guild's widget settings.
+    ///
+    /// Refer to [`EditGuildWidget`] for the available options.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildWidget`]: ../../builder/struct.EditGuildWidget.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit_widget<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildWidgetSettings>
+    where F: FnOnce(&mut EditGuildWidget) -> &mut EditGuildWidget {
+        let mut edit_widget = EditGuildWidget::default();
+        f(&mut edit_widget);
+        let map = utils::hashmap_to_json_map(edit_widget.0);
+
+        http.as_ref().edit_guild_widget(self.0, &Value::Object(map)).await
+    }
+
     /// Tries to find the [`Guild`] by its Id in the cache.
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
@@ -424,6 +1334,29 @@ impl GuildId {
         http.as_ref().get_guild(self.0).await
     }
 
+    /// First attempts to find the [`Guild`] by its Id in the cache, upon
+    /// failure requests it as a [`PartialGuild`] via the REST API.
+    ///
+    /// A cached guild is converted into a [`PartialGuild`], so that a single
+    /// type is returned either way.
+    ///
+    /// **Note**: If the cache is not enabled, the REST API will be used only.
+    ///
+    /// [`Guild`]: ../guild/struct.Guild.html
+    /// [`PartialGuild`]: ../guild/struct.PartialGuild.html
+    pub async fn to_guild_cached_or_fetch(self, cache_http: impl CacheHttp) -> Result<PartialGuild> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(guild) = cache.guild_field(self, |guild| PartialGuild::from(guild)).await {
+                    return Ok(guild);
+                }
+            }
+        }
+
+        cache_http.http().get_guild(self.0).await
+    }
+
     /// Gets all [`Emoji`]s of this guild via HTTP.
     ///
     /// [`Emoji`]: struct.Emoji.html
@@ -458,6 +1391,50 @@ impl GuildId {
         http.as_ref().get_guild_invites(self.0).await
     }
 
+    /// Gets all of the guild's invites, returning those whose number of uses
+    /// increased since they were last fetched with this method.
+    ///
+    /// Called when a member joins, this finds out which invite they joined
+    /// with. The invites are tracked by the cache, so this must have been
+    /// called once before, e.g. when the guild became available, and returns
+    /// no invites the first time.
+    ///
+    /// Refer to [`Cache::update_guild_invites`] for more information.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// # use serenity::prelude::*;
+    /// #
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn guild_member_addition(&self, ctx: Context, guild_id: GuildId, member: Member) {
+    ///         if let Ok(invites) = guild_id.used_invites(&ctx).await {
+    ///             if let [invite] = &invites[..] {
+    ///                 println!("{} joined with {}", member.user.name, invite.code);
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Cache::update_guild_invites`]: ../../cache/struct.Cache.html#method.update_guild_invites
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[cfg(feature = "cache")]
+    pub async fn used_invites(self, cache_http: impl CacheHttp) -> Result<Vec<CachedInvite>> {
+        let invites = self.invites(cache_http.http()).await?;
+
+        match cache_http.cache() {
+            Some(cache) => Ok(cache.update_guild_invites(self, &invites).await),
+            None => Ok(Vec::new()),
+        }
+    }
+
     /// Kicks a [`Member`] from the guild.
     ///
     /// Requires the [Kick Members] permission.
@@ -595,6 +1572,21 @@ impl GuildId {
         http.as_ref().edit_member(self.0, user_id.into().0, &map).await
     }
 
+    /// Gets the guild's membership screening.
+    #[inline]
+    pub async fn membership_screening(self, http: impl AsRef<Http>) -> Result<MembershipScreening> {
+        http.as_ref().get_guild_membership_screening(self.0).await
+    }
+
+    /// Gets the guild's preview.
+    ///
+    /// **Note**: The current user need not be a member of the guild if it is
+    /// discoverable.
+    #[inline]
+    pub async fn preview(self, http: impl AsRef<Http>) -> Result<GuildPreview> {
+        http.as_ref().get_guild_preview(self.0).await
+    }
+
     /// Gets the number of [`Member`]s that would be pruned with the given
     /// number of days.
     ///
@@ -611,28 +1603,222 @@ impl GuildId {
         http.as_ref().get_guild_prune_count(self.0, &map).await
     }
 
-    /// Re-orders the channels of the guild.
+    /// Gets the [`VoiceRegion`]s available to the guild, including VIP-only
+    /// regions if the guild has the `VIP_REGIONS` feature.
+    ///
+    /// Refer to [`Http::get_voice_regions`] for the regions available to all
+    /// guilds.
+    ///
+    /// [`Http::get_voice_regions`]: ../../http/client/struct.Http.html#method.get_voice_regions
+    /// [`VoiceRegion`]: ../voice/struct.VoiceRegion.html
+    #[inline]
+    pub async fn regions(self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        http.as_ref().get_guild_regions(self.0).await
+    }
+
+    /// Re-orders the channels of the guild in a single request.
     ///
-    /// Accepts an iterator of a tuple of the channel ID to modify and its new
-    /// position.
+    /// Accepts an iterator of tuples of the channel ID to modify and its new
+    /// position, optionally followed by its new category and whether to sync
+    /// its permissions with the category. Refer to [`EditChannelPosition`]
+    /// for the accepted tuples.
     ///
     /// Although not required, you should specify all channels' positions,
     /// regardless of whether they were updated. Otherwise, positioning can
     /// sometimes get weird.
+    ///
+    /// Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// Swap two channels, moving the second one into a category:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let guild_id = GuildId(81384788765712384);
+    /// let category = ChannelId(381880193251409931);
+    ///
+    /// guild_id.reorder_channels(&http, vec![
+    ///     (ChannelId(381880193700069377), 1, None, false),
+    ///     (ChannelId(381880193700069378), 0, Some(category), true),
+    /// ]).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`EditChannelPosition`]: ../../builder/struct.EditChannelPosition.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
     #[inline]
     pub async fn reorder_channels<It>(self, http: impl AsRef<Http>, channels: It) -> Result<()>
-    where It: IntoIterator<Item = (ChannelId, u64)>
+    where
+        It: IntoIterator,
+        It::Item: Into<EditChannelPosition>,
     {
         let items = channels
             .into_iter()
-            .map(|(id, pos)| json!({
-                "id": id,
-                "position": pos,
-            })).collect();
+            .map(|channel| Value::Object(utils::hashmap_to_json_map(channel.into().0)))
+            .collect();
 
         http.as_ref().edit_guild_channel_positions(self.0, &Value::Array(items)).await
     }
 
+    /// Moves a channel of the guild, such as into a category while syncing
+    /// its permissions with the category.
+    ///
+    /// Refer to [`EditChannelPosition`] for the properties which can be
+    /// changed, and to [`reorder_channels`] to move several channels at once.
+    ///
+    /// Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let guild_id = GuildId(81384788765712384);
+    /// let category = ChannelId(381880193251409931);
+    ///
+    /// guild_id.edit_channel_position(&http, ChannelId(381880193700069377), |p| {
+    ///     p.category(category).lock_permissions(true)
+    /// }).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`EditChannelPosition`]: ../../builder/struct.EditChannelPosition.html
+    /// [`reorder_channels`]: #method.reorder_channels
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[inline]
+    pub async fn edit_channel_position<F>(self, http: impl AsRef<Http>, channel_id: impl Into<ChannelId>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditChannelPosition) -> &mut EditChannelPosition
+    {
+        let mut builder = EditChannelPosition::default();
+        f(&mut builder).channel(channel_id.into());
+
+        self.reorder_channels(http, Some(builder)).await
+    }
+
+    /// Gets a [`ScheduledEvent`] of the guild by Id.
+    ///
+    /// Pass `with_user_count` to fill in the event's [`user_count`].
+    ///
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    /// [`user_count`]: ../guild/struct.ScheduledEvent.html#structfield.user_count
+    #[inline]
+    pub async fn scheduled_event(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        with_user_count: bool,
+    ) -> Result<ScheduledEvent> {
+        http.as_ref().get_scheduled_event(self.0, event_id.into().0, with_user_count).await
+    }
+
+    /// Gets the users subscribed to a [`ScheduledEvent`] of the guild.
+    ///
+    /// Optionally pass a `limit` of at most 100 users, defaulting to 100.
+    /// Pass `with_member` to fill in the users' [`member`] data.
+    ///
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    /// [`member`]: ../guild/struct.ScheduledEventUser.html#structfield.member
+    #[inline]
+    pub async fn scheduled_event_users(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        limit: Option<u64>,
+        with_member: bool,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        http.as_ref().get_scheduled_event_users(self.0, event_id.into().0, limit, Some(with_member)).await
+    }
+
+    /// Gets all [`ScheduledEvent`]s of the guild.
+    ///
+    /// Pass `with_user_count` to fill in the events' [`user_count`].
+    ///
+    /// [`ScheduledEvent`]: ../guild/struct.ScheduledEvent.html
+    /// [`user_count`]: ../guild/struct.ScheduledEvent.html#structfield.user_count
+    #[inline]
+    pub async fn scheduled_events(self, http: impl AsRef<Http>, with_user_count: bool) -> Result<Vec<ScheduledEvent>> {
+        http.as_ref().get_scheduled_events(self.0, with_user_count).await
+    }
+
+    /// Overwrites all [`ApplicationCommand`]s only available in the guild
+    /// with the given commands.
+    ///
+    /// Commands that are not given are deleted.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    pub async fn set_application_commands<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands {
+        let mut commands = CreateApplicationCommands::default();
+        f(&mut commands);
+
+        http.as_ref().create_guild_application_commands(application_id.into().0, self.0, &Value::Array(commands.0)).await
+    }
+
+    /// Overwrites the permissions of an [`ApplicationCommand`] of the
+    /// application in the guild.
+    ///
+    /// Permissions that are not given are removed.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    pub async fn set_application_command_permissions<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+        f: F,
+    ) -> Result<ApplicationCommandPermission>
+    where F: FnOnce(&mut CreateApplicationCommandPermissionsData) -> &mut CreateApplicationCommandPermissionsData {
+        let mut permissions = CreateApplicationCommandPermissionsData::default();
+        f(&mut permissions);
+        let map = utils::hashmap_to_json_map(permissions.0);
+
+        http.as_ref().edit_guild_application_command_permissions(
+            application_id.into().0,
+            self.0,
+            command_id.into().0,
+            &Value::Object(map),
+        ).await
+    }
+
+    /// Overwrites the permissions of several [`ApplicationCommand`]s of the
+    /// application in the guild.
+    ///
+    /// Refer to [`CreateApplicationCommandsPermissions`] for an example.
+    ///
+    /// [`ApplicationCommand`]: ../interactions/struct.ApplicationCommand.html
+    /// [`CreateApplicationCommandsPermissions`]: ../../builder/struct.CreateApplicationCommandsPermissions.html
+    pub async fn set_application_commands_permissions<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommandPermission>>
+    where F: FnOnce(&mut CreateApplicationCommandsPermissions) -> &mut CreateApplicationCommandsPermissions {
+        let mut permissions = CreateApplicationCommandsPermissions::default();
+        f(&mut permissions);
+
+        http.as_ref().edit_guild_application_commands_permissions(
+            application_id.into().0,
+            self.0,
+            &Value::Array(permissions.0),
+        ).await
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -676,6 +1862,22 @@ impl GuildId {
     #[inline]
     pub async fn shard_id(self, shard_count: u64) -> u64 { crate::utils::shard_id(self.0, shard_count) }
 
+    /// Gets a [`Sticker`] of the guild by its Id.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    #[inline]
+    pub async fn sticker(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>) -> Result<Sticker> {
+        http.as_ref().get_guild_sticker(self.0, sticker_id.into().0).await
+    }
+
+    /// Gets all [`Sticker`]s of the guild.
+    ///
+    /// [`Sticker`]: ../sticker/struct.Sticker.html
+    #[inline]
+    pub async fn stickers(self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        http.as_ref().get_guild_stickers(self.0).await
+    }
+
     /// Starts an integration sync for the given integration Id.
     ///
     /// Requires the [Manage Guild] permission.
@@ -704,6 +1906,29 @@ impl GuildId {
         http.as_ref().start_guild_prune(self.0, &map).await
     }
 
+    /// Updates a [`GuildTemplate`] of the guild to the current state of the
+    /// guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self.0, code).await
+    }
+
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: ../guild/struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// Requires the [Ban Members] permission.
@@ -715,13 +1940,29 @@ impl GuildId {
         http.as_ref().remove_ban(self.0, user_id.into().0).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Unbans a [`User`] from the guild with a reason shown in the audit log.
+    /// Refer to [`unban`] for further documentation.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [`unban`]: #method.unban
+    #[inline]
+    pub async fn unban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().remove_ban_with_reason(self.0, user_id.into().0, reason.as_ref()).await
+    }
+
+    /// Retrieve's the guild's vanity URL, with the number of times it was
+    /// used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         http.as_ref().get_guild_vanity_url(self.0).await
     }
 
@@ -735,6 +1976,42 @@ impl GuildId {
         http.as_ref().get_guild_webhooks(self.0).await
     }
 
+    /// Retrieves the guild's welcome screen.
+    #[inline]
+    pub async fn welcome_screen(self, http: impl AsRef<Http>) -> Result<GuildWelcomeScreen> {
+        http.as_ref().get_guild_welcome_screen(self.0).await
+    }
+
+    /// Gets the guild's public widget.
+    ///
+    /// **Note**: The widget must be enabled, refer to [`edit_widget`].
+    ///
+    /// [`edit_widget`]: #method.edit_widget
+    #[inline]
+    pub async fn widget(self, http: impl AsRef<Http>) -> Result<GuildWidget> {
+        http.as_ref().get_guild_widget(self.0).await
+    }
+
+    /// Gets the PNG image of the guild's widget in the given style.
+    ///
+    /// **Note**: The widget must be enabled, refer to [`edit_widget`].
+    ///
+    /// [`edit_widget`]: #method.edit_widget
+    #[inline]
+    pub async fn widget_image(self, http: impl AsRef<Http>, style: GuildWidgetStyle) -> Result<Vec<u8>> {
+        http.as_ref().get_guild_widget_image(self.0, style.name()).await
+    }
+
+    /// Gets the guild's widget settings.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn widget_settings(self, http: impl AsRef<Http>) -> Result<GuildWidgetSettings> {
+        http.as_ref().get_guild_widget_settings(self.0).await
+    }
+
     /// Returns a future that will await one message sent in this guild.
     #[cfg(feature = "collector")]
     pub fn await_reply<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> CollectReply<'a> {
@@ -900,3 +2177,175 @@ impl<H: AsRef<Http>> MembersIter<H> {
         })
     }
 }
+
+/// A helper class returned by [`GuildId::audit_logs_iter`]
+///
+/// [`GuildId::audit_logs_iter`]: ../id/struct.GuildId.html#method.audit_logs_iter
+#[derive(Debug)]
+#[cfg(feature = "model")]
+pub struct AuditLogsIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    http: H,
+    action_type: Option<Action>,
+    user_id: Option<UserId>,
+    buffer: Vec<AuditLogEntry>,
+    before: Option<AuditLogEntryId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> AuditLogsIter<H> {
+    fn new(
+        guild_id: GuildId,
+        http: H,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> AuditLogsIter<H> {
+        AuditLogsIter {
+            guild_id,
+            http,
+            action_type,
+            user_id,
+            buffer: Vec::new(),
+            before: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of entries.
+    ///
+    /// This drops any entries that were currently in the buffer, so it should
+    /// only be called when `self.buffer` is empty. Additionally, this updates
+    /// `self.before` to the oldest entry fetched, so that the next call does
+    /// not return duplicate items. If there are no more entries to be fetched,
+    /// then this marks `self.before` as None, indicating that no more calls
+    /// ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of entries to fetch.
+        let grab_size: u8 = 100;
+
+        let mut logs = self.guild_id.audit_logs(
+            &self.http,
+            self.action_type,
+            self.user_id,
+            self.before,
+            Some(grab_size),
+        ).await?;
+
+        self.buffer = logs.entries.drain().map(|(_, entry)| entry).collect();
+
+        // Sort the oldest entry first to optimize pop().
+        self.buffer.sort_by_key(|entry| entry.id);
+
+        // If shorter than the limit, there are no more results anyway.
+        self.before = if self.buffer.len() < grab_size as usize {
+            None
+        } else {
+            self.buffer.first().map(|entry| entry.id)
+        };
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over the audit log entries of a guild, newest first.
+    ///
+    /// Refer to [`GuildId::audit_logs_iter`] for more information.
+    ///
+    /// [`GuildId::audit_logs_iter`]: ../id/struct.GuildId.html#method.audit_logs_iter
+    pub fn stream(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> impl Stream<Item=Result<AuditLogEntry>> {
+        let init_state = AuditLogsIter::new(guild_id, http, action_type, user_id);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.before.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// A helper class returned by [`GuildId::bans_iter`]
+///
+/// [`GuildId::bans_iter`]: ../id/struct.GuildId.html#method.bans_iter
+#[derive(Debug)]
+#[cfg(feature = "model")]
+pub struct BansIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    http: H,
+    buffer: Vec<Ban>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> BansIter<H> {
+    fn new(guild_id: GuildId, http: H) -> BansIter<H> {
+        BansIter {
+            guild_id,
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of bans.
+    ///
+    /// This drops any bans that were currently in the buffer, so it should
+    /// only be called when `self.buffer` is empty. Additionally, this updates
+    /// `self.after` to the banned user with the highest Id, so that the next
+    /// call does not return duplicate items. If there are no more bans to be
+    /// fetched, then this marks `self.after` as None, indicating that no more
+    /// calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        // Number of bans to fetch.
+        let grab_size: u64 = 1000;
+
+        let target = self.after.map(UserPagination::After);
+        self.buffer = self.guild_id.bans(&self.http, target, Some(grab_size)).await?;
+
+        // If shorter than the limit, there are no more results anyway.
+        self.after = if self.buffer.len() < grab_size as usize {
+            None
+        } else {
+            self.buffer.last().map(|ban| ban.user.id)
+        };
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the bans of a guild, ordered by the Id of the banned
+    /// user.
+    ///
+    /// Refer to [`GuildId::bans_iter`] for more information.
+    ///
+    /// [`GuildId::bans_iter`]: ../id/struct.GuildId.html#method.bans_iter
+    pub fn stream(http: impl AsRef<Http>, guild_id: GuildId) -> impl Stream<Item=Result<Ban>> {
+        let init_state = BansIter::new(guild_id, http);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|ban| (Ok(ban), state))
+        })
+    }
+}
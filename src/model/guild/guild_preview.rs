@@ -0,0 +1,38 @@
+use crate::model::prelude::*;
+
+/// A preview of a discoverable [`Guild`], available without being a member
+/// of it.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildPreview {
+    /// The Id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// The hash of the guild's icon.
+    pub icon: Option<String>,
+    /// The hash of the guild's invite splash image.
+    pub splash: Option<String>,
+    /// The hash of the guild's discovery splash image.
+    pub discovery_splash: Option<String>,
+    /// The guild's custom emojis.
+    pub emojis: Vec<Emoji>,
+    /// The guild's enabled features.
+    ///
+    /// Refer to [`Guild::features`] for a list of known features.
+    ///
+    /// [`Guild::features`]: struct.Guild.html#structfield.features
+    pub features: Vec<String>,
+    /// The approximate number of members in the guild.
+    pub approximate_member_count: u64,
+    /// The approximate number of online members in the guild.
+    pub approximate_presence_count: u64,
+    /// The description of the guild.
+    pub description: Option<String>,
+    /// The guild's custom stickers.
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
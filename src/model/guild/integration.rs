@@ -4,16 +4,41 @@ use super::*;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Integration {
     pub id: IntegrationId,
-    pub account: IntegrationAccount,
-    pub enabled: bool,
-    #[serde(rename = "expire_behaviour")] pub expire_behaviour: u64,
-    pub expire_grace_period: u64,
-    pub kind: String,
+    /// The Id of the guild the integration belongs to.
+    ///
+    /// **Note**: This is only present in gateway events.
+    pub guild_id: Option<GuildId>,
     pub name: String,
-    pub role_id: RoleId,
-    pub synced_at: u64,
-    pub syncing: bool,
-    pub user: User,
+    /// The type of the integration, e.g. `twitch`, `youtube`, `discord` or
+    /// `guild_subscription`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub enabled: bool,
+    pub syncing: Option<bool>,
+    /// The Id of the role subscribers of the integration receive.
+    pub role_id: Option<RoleId>,
+    /// Whether emoticons of the integration are synced.
+    pub enable_emoticons: Option<bool>,
+    /// What happens to subscribers whose subscription expired, `0` meaning
+    /// the role is removed and `1` that they are kicked.
+    #[serde(rename = "expire_behavior")]
+    pub expire_behaviour: Option<u64>,
+    /// The number of days subscribers keep their role after their
+    /// subscription expired.
+    pub expire_grace_period: Option<u64>,
+    /// The user of the integration.
+    pub user: Option<User>,
+    pub account: IntegrationAccount,
+    /// The time the integration was last synced.
+    pub synced_at: Option<Timestamp>,
+    /// The number of subscribers of the integration.
+    pub subscriber_count: Option<u64>,
+    /// Whether the integration was revoked.
+    pub revoked: Option<bool>,
+    /// The bot application of a `discord` integration.
+    pub application: Option<IntegrationApplication>,
+    /// The scopes the application was authorized for.
+    pub scopes: Option<Vec<OAuth2Scope>>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -31,3 +56,22 @@ pub struct IntegrationAccount {
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
+
+/// The bot application of an [`Integration`].
+///
+/// [`Integration`]: struct.Integration.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IntegrationApplication {
+    /// The Id of the application.
+    pub id: ApplicationId,
+    /// The name of the application.
+    pub name: String,
+    /// The icon hash of the application.
+    pub icon: Option<String>,
+    /// The description of the application.
+    pub description: String,
+    /// The bot user of the application.
+    pub bot: Option<User>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
@@ -1,5 +1,4 @@
 use crate::model::prelude::*;
-use chrono::{DateTime, Utc};
 use std::cmp::Reverse;
 use std::fmt::{
     Display,
@@ -30,7 +29,7 @@ pub struct Member {
     /// The unique Id of the guild that the member is a part of.
     pub guild_id: GuildId,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<Utc>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
     pub mute: bool,
     /// The member's nickname, if present.
@@ -39,6 +38,16 @@ pub struct Member {
     pub nick: Option<String>,
     /// Vector of Ids of [`Role`](struct.Role.html)s given to the member.
     pub roles: Vec<RoleId>,
+    /// Timestamp until which the member is timed out, unable to send
+    /// messages, react or join voice channels.
+    pub communication_disabled_until: Option<Timestamp>,
+    /// Whether the member has yet to pass the guild's [`MembershipScreening`].
+    ///
+    /// Pending members can not interact with the guild.
+    ///
+    /// [`MembershipScreening`]: struct.MembershipScreening.html
+    #[serde(default)]
+    pub pending: bool,
     /// Attached User struct.
     pub user: User,
     #[serde(skip)]
@@ -77,25 +86,38 @@ impl Member {
     /// Adds one or multiple [`Role`]s to the member, editing
     /// its roles in-place if the request was successful.
     ///
+    /// All roles are added with a single request. Roles the member already
+    /// has are skipped, and no request is made if there are none left.
+    ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// [`Role`]: struct.Role.html
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     pub async fn add_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<()> {
-        self.roles.extend_from_slice(role_ids);
+        let mut roles = self.roles.clone();
+
+        for role_id in role_ids {
+            if !roles.contains(role_id) {
+                roles.push(*role_id);
+            }
+        }
+
+        if roles.len() == self.roles.len() {
+            return Ok(());
+        }
+
+        self._set_roles(http, roles).await
+    }
 
+    async fn _set_roles(&mut self, http: impl AsRef<Http>, roles: Vec<RoleId>) -> Result<()> {
         let mut builder = EditMember::default();
-        builder.roles(&self.roles);
+        builder.roles(&roles);
         let map = utils::hashmap_to_json_map(builder.0);
 
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
-            Ok(()) => Ok(()),
-            Err(why) => {
-                self.roles.retain(|r| !role_ids.contains(r));
+        http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await?;
+        self.roles = roles;
 
-                Err(why)
-            },
-        }
+        Ok(())
     }
 
     /// Ban a [`User`] from the guild, deleting a number of
@@ -123,6 +145,32 @@ impl Member {
         self.guild_id.ban_with_reason(http, self.user.id, dmd, reason).await
     }
 
+    /// Ban the member from the guild, deleting the messages they sent in the
+    /// given number of seconds, up to 7 days.
+    ///
+    /// Refer to [`GuildId::ban_with_seconds`] for more information.
+    ///
+    /// [`GuildId::ban_with_seconds`]: ../id/struct.GuildId.html#method.ban_with_seconds
+    #[inline]
+    pub async fn ban_with_seconds(&self, http: impl AsRef<Http>, delete_message_seconds: u32) -> Result<()> {
+        self.guild_id.ban_with_seconds(http, self.user.id, delete_message_seconds).await
+    }
+
+    /// Ban the member from the guild with a reason, deleting the messages they
+    /// sent in the given number of seconds. Refer to [`ban_with_seconds`] for
+    /// further documentation.
+    ///
+    /// [`ban_with_seconds`]: #method.ban_with_seconds
+    #[inline]
+    pub async fn ban_with_seconds_and_reason(
+        &self,
+        http: impl AsRef<Http>,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.guild_id.ban_with_seconds_and_reason(http, self.user.id, delete_message_seconds, reason).await
+    }
+
     /// Determines the member's colour.
     #[cfg(feature = "cache")]
     pub async fn colour(&self, cache: impl AsRef<Cache>) -> Option<Colour> {
@@ -150,8 +198,8 @@ impl Member {
     pub async fn default_channel(&self, cache: impl AsRef<Cache>) -> Option<GuildChannel> {
         let guild = self.guild_id.to_guild_cached(cache).await?;
 
-        for (cid, channel) in &guild.channels {
-            if guild.user_permissions_in(*cid, self.user.id).read_messages() {
+        for channel in guild.channels.values() {
+            if guild.user_permissions_in(channel, self).read_messages() {
                 return Some(channel.clone());
             }
         }
@@ -159,6 +207,31 @@ impl Member {
         None
     }
 
+    /// Times the member out until the given time, disabling their ability to
+    /// send messages, react or join voice channels.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission. The time can be
+    /// at most 28 days in the future.
+    ///
+    /// [Moderate Members]: ../permissions/struct.Permissions.html#associatedconstant.MODERATE_MEMBERS
+    pub async fn disable_communication(&mut self, http: impl AsRef<Http>, until: impl Into<Timestamp>) -> Result<()> {
+        let until = until.into();
+
+        self.edit(http, |m| m.disable_communication_until(until)).await?;
+        self.communication_disabled_until = Some(until);
+
+        Ok(())
+    }
+
+    /// Whether the member is currently timed out.
+    ///
+    /// Refer to [`communication_disabled_until`] for more information.
+    ///
+    /// [`communication_disabled_until`]: #structfield.communication_disabled_until
+    pub fn is_communication_disabled(&self) -> bool {
+        self.communication_disabled_until.map_or(false, |until| until > Timestamp::now())
+    }
+
     /// Calculates the member's display name.
     ///
     /// The nickname takes priority over the member's username if it exists.
@@ -197,6 +270,28 @@ impl Member {
         http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await
     }
 
+    /// Edits the member with a reason shown in the audit log. Refer to
+    /// [`edit`] for further documentation.
+    ///
+    /// [`edit`]: #method.edit
+    pub async fn edit_with_reason<F>(&self, http: impl AsRef<Http>, reason: impl AsRef<str>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditMember) -> &mut EditMember
+    {
+        self.guild_id.edit_member_with_reason(http, self.user.id, reason, f).await
+    }
+
+    /// Removes the member's timeout, if any.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: ../permissions/struct.Permissions.html#associatedconstant.MODERATE_MEMBERS
+    pub async fn enable_communication(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        self.edit(http, |m| m.enable_communication()).await?;
+        self.communication_disabled_until = None;
+
+        Ok(())
+    }
+
     /// Retrieves the ID and position of the member's highest role in the
     /// hierarchy, if they have one.
     ///
@@ -394,27 +489,28 @@ impl Member {
         }
     }
 
-    /// Removes one or multiple [`Role`]s from the member.
+    /// Removes one or multiple [`Role`]s from the member, editing its roles
+    /// in-place if the request was successful.
+    ///
+    /// All roles are removed with a single request. No request is made if the
+    /// member has none of the roles.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// [`Role`]: struct.Role.html
     /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
     pub async fn remove_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<()> {
-        self.roles.retain(|r| !role_ids.contains(r));
-
-        let mut builder = EditMember::default();
-        builder.roles(&self.roles);
-        let map = utils::hashmap_to_json_map(builder.0);
-
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
-            Ok(()) => Ok(()),
-            Err(why) => {
-                self.roles.extend_from_slice(role_ids);
+        let roles = self.roles
+            .iter()
+            .filter(|r| !role_ids.contains(r))
+            .copied()
+            .collect::<Vec<RoleId>>();
 
-                Err(why)
-            },
+        if roles.len() == self.roles.len() {
+            return Ok(());
         }
+
+        self._set_roles(http, roles).await
     }
 
     /// Retrieves the full role data for the user's roles.
@@ -494,7 +590,7 @@ pub struct PartialMember {
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
     /// Timestamp representing the date when the member joined.
-    pub joined_at: Option<DateTime<Utc>>,
+    pub joined_at: Option<Timestamp>,
     /// Indicator of whether the member can speak in voice channels.
     pub mute: bool,
     /// The member's nickname, if present.
@@ -0,0 +1,65 @@
+use crate::model::prelude::*;
+
+/// The rules new members of a community [`Guild`] must agree to before
+/// interacting with it.
+///
+/// While they have not agreed, a [`Member`] is [`pending`].
+///
+/// [`Guild`]: struct.Guild.html
+/// [`Member`]: struct.Member.html
+/// [`pending`]: struct.Member.html#structfield.pending
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreening {
+    /// When the screening was last modified.
+    pub version: String,
+    /// The fields new members must fill out.
+    pub form_fields: Vec<MembershipScreeningField>,
+    /// The description of the guild shown on the screening.
+    pub description: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A field of a [`MembershipScreening`].
+///
+/// [`MembershipScreening`]: struct.MembershipScreening.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MembershipScreeningField {
+    /// The type of the field.
+    pub field_type: MembershipScreeningFieldType,
+    /// The title of the field.
+    pub label: String,
+    /// The list of rules of a [`Terms`] field.
+    ///
+    /// [`Terms`]: enum.MembershipScreeningFieldType.html#variant.Terms
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// Whether the member must fill out the field.
+    pub required: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+impl MembershipScreeningField {
+    /// Creates a required field listing the server rules to agree to.
+    pub fn terms<S: ToString, It: IntoIterator<Item=S>>(label: impl ToString, rules: It) -> Self {
+        Self {
+            field_type: MembershipScreeningFieldType::Terms,
+            label: label.to_string(),
+            values: rules.into_iter().map(|rule| rule.to_string()).collect(),
+            required: true,
+            _nonexhaustive: (),
+        }
+    }
+}
+
+/// The type of a [`MembershipScreeningField`].
+///
+/// [`MembershipScreeningField`]: struct.MembershipScreeningField.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum MembershipScreeningFieldType {
+    /// The server rules, which members must agree to.
+    #[serde(rename = "TERMS")]
+    Terms,
+}
@@ -2,40 +2,58 @@
 
 mod emoji;
 mod guild_id;
+mod guild_preview;
 mod integration;
 mod member;
+mod membership_screening;
 mod partial_guild;
 mod role;
 mod audit_log;
+mod automod;
 mod premium_tier;
+mod scheduled_event;
+mod template;
+mod welcome_screen;
+mod widget;
 
 pub use self::emoji::*;
 pub use self::guild_id::*;
+pub use self::guild_preview::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::membership_screening::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
 pub use self::audit_log::*;
+pub use self::automod::*;
 pub use self::premium_tier::*;
+pub use self::scheduled_event::*;
+pub use self::template::*;
+pub use self::welcome_screen::*;
+pub use self::widget::*;
 
 use chrono::{DateTime, Utc};
 use crate::model::prelude::*;
 use serde::de::Error as DeError;
 use super::utils::*;
-use futures::stream::StreamExt;
+use futures::stream::{Stream, StreamExt};
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(all(feature = "http", feature = "model"))]
 use serde_json::json;
 #[cfg(feature = "model")]
-use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
+use crate::builder::{CreateChannel, EditChannelPosition, EditGuild, EditMember, EditRole};
 #[cfg(feature = "model")]
 use crate::constants::LARGE_THRESHOLD;
 #[cfg(feature = "model")]
+use crate::model::misc::image_url;
+#[cfg(feature = "model")]
 use tracing::{error, warn};
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::http::{Http, CacheHttp, UserPagination};
+#[cfg(feature = "model")]
+use std::path::Path;
 
 /// A representation of a banning of a user.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash, Serialize)]
@@ -46,6 +64,19 @@ pub struct Ban {
     pub user: User,
 }
 
+/// The result of banning multiple users at once with [`GuildId::bulk_ban`].
+///
+/// [`GuildId::bulk_ban`]: ../id/struct.GuildId.html#method.bulk_ban
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct BulkBanResponse {
+    /// The users that were banned.
+    pub banned_users: Vec<UserId>,
+    /// The users that could not be banned, for example because they are
+    /// already banned.
+    pub failed_users: Vec<UserId>,
+}
+
 /// Information about a Discord guild, such as channels, emojis, etc.
 #[derive(Clone, Debug, Serialize)]
 pub struct Guild {
@@ -146,6 +177,9 @@ pub struct Guild {
     /// If the [`"InviteSplash"`] feature is enabled, this can be used to generate
     /// a URL to a splash image.
     pub splash: Option<String>,
+    /// An identifying hash of the guild's discovery splash image, shown in
+    /// the server discovery if the guild has the `DISCOVERABLE` feature.
+    pub discovery_splash: Option<String>,
     /// The ID of the channel to which system messages are sent.
     pub system_channel_id: Option<ChannelId>,
     /// Indicator of the current verification level of the guild.
@@ -170,6 +204,9 @@ pub struct Guild {
     /// The preferred locale of this guild only set if guild has the "DISCOVERABLE"
     /// feature, defaults to en-US.
     pub preferred_locale: String,
+    /// All of the guild's custom stickers.
+    #[serde(serialize_with = "serialize_gen_map")]
+    pub stickers: HashMap<StickerId, Sticker>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -194,7 +231,7 @@ impl Guild {
     /// returns `None`)
     pub async fn default_channel(&self, uid: UserId) -> Option<&GuildChannel> {
         for (cid, channel) in &self.channels {
-            if self.user_permissions_in(*cid, uid).read_messages() {
+            if self.user_permissions_in_ids(*cid, uid).read_messages() {
                 return Some(channel);
             }
         }
@@ -208,9 +245,9 @@ impl Guild {
     /// Note however that this is very costy if used in a server with lots of channels,
     /// members, or both.
     pub async fn default_channel_guaranteed(&self) -> Option<&GuildChannel> {
-        for (cid, channel) in &self.channels {
-            for memid in self.members.keys() {
-                if self.user_permissions_in(*cid, *memid).read_messages() {
+        for channel in self.channels.values() {
+            for member in self.members.values() {
+                if self.user_permissions_in(channel, member).read_messages() {
                     return Some(channel);
                 }
             }
@@ -278,7 +315,7 @@ impl Guild {
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
     pub async fn ban(&self, cache_http: impl CacheHttp, user: impl Into<UserId>, dmd: u8) -> Result<()> {
-        self._ban_with_reason(cache_http, user.into(), dmd, "").await
+        self.ban_with_reason(cache_http, user, dmd, "").await
     }
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`ban`] to further documentation.
@@ -292,10 +329,60 @@ impl Guild {
         user: impl Into<UserId>,
         dmd: u8,
         reason: impl AsRef<str>) -> Result<()> {
-        self._ban_with_reason(cache_http, user.into(), dmd, reason.as_ref()).await
+        if dmd > 7 {
+            return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
+        }
+
+        self._ban_with_reason(cache_http, user.into(), u32::from(dmd) * 86_400, reason.as_ref()).await
+    }
+
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the
+    /// given number of seconds, up to 7 days.
+    ///
+    /// Refer to [`GuildId::ban_with_seconds`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidPermissions`] if the current user does
+    /// not have permission to perform bans.
+    ///
+    /// [`GuildId::ban_with_seconds`]: ../id/struct.GuildId.html#method.ban_with_seconds
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`User`]: ../user/struct.User.html
+    #[inline]
+    pub async fn ban_with_seconds(
+        &self,
+        cache_http: impl CacheHttp,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+    ) -> Result<()> {
+        self._ban_with_reason(cache_http, user.into(), delete_message_seconds, "").await
     }
 
-    async fn _ban_with_reason(&self, cache_http: impl CacheHttp, user: UserId, dmd: u8, reason: &str) -> Result<()> {
+    /// Ban a [`User`] from the guild with a reason, deleting the messages they
+    /// sent in the given number of seconds. Refer to [`ban_with_seconds`] for
+    /// further documentation.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [`ban_with_seconds`]: #method.ban_with_seconds
+    #[inline]
+    pub async fn ban_with_seconds_and_reason(
+        &self,
+        cache_http: impl CacheHttp,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self._ban_with_reason(cache_http, user.into(), delete_message_seconds, reason.as_ref()).await
+    }
+
+    async fn _ban_with_reason(
+        &self,
+        cache_http: impl CacheHttp,
+        user: UserId,
+        delete_message_seconds: u32,
+        reason: &str,
+    ) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -309,11 +396,13 @@ impl Guild {
             }
         }
 
-        self.id.ban_with_reason(cache_http.http(), user, dmd, reason).await
+        self.id.ban_with_seconds_and_reason(cache_http.http(), user, delete_message_seconds, reason).await
     }
 
     /// Retrieves a list of [`Ban`]s for the guild.
     ///
+    /// Refer to [`GuildId::bans`] for more information.
+    ///
     /// **Note**: Requires the [Ban Members] permission.
     ///
     /// # Errors
@@ -322,9 +411,15 @@ impl Guild {
     /// if the current user does not have permission to perform bans.
     ///
     /// [`Ban`]: struct.Ban.html
+    /// [`GuildId::bans`]: ../id/struct.GuildId.html#method.bans
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
-    pub async fn bans(&self, cache_http: impl CacheHttp) -> Result<Vec<Ban>> {
+    pub async fn bans(
+        &self,
+        cache_http: impl CacheHttp,
+        target: Option<UserPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Ban>> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -336,7 +431,17 @@ impl Guild {
             }
         }
 
-        self.id.bans(cache_http.http()).await
+        self.id.bans(cache_http.http(), target, limit).await
+    }
+
+    /// Streams over all the bans of the guild.
+    ///
+    /// Refer to [`GuildId::bans_iter`] for more information.
+    ///
+    /// [`GuildId::bans_iter`]: ../id/struct.GuildId.html#method.bans_iter
+    #[inline]
+    pub fn bans_iter<H: AsRef<Http>>(&self, http: H) -> impl Stream<Item=Result<Ban>> {
+        self.id.bans_iter(http)
     }
 
     /// Retrieves a list of [`AuditLogs`] for the guild.
@@ -346,7 +451,7 @@ impl Guild {
     pub async fn audit_logs(
         &self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
@@ -354,6 +459,21 @@ impl Guild {
         self.id.audit_logs(&http, action_type, user_id, before, limit).await
     }
 
+    /// Streams over the guild's audit log entries, newest first.
+    ///
+    /// Refer to [`GuildId::audit_logs_iter`] for more information.
+    ///
+    /// [`GuildId::audit_logs_iter`]: ../id/struct.GuildId.html#method.audit_logs_iter
+    #[inline]
+    pub fn audit_logs_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+        action_type: Option<Action>,
+        user_id: Option<UserId>,
+    ) -> impl Stream<Item=Result<AuditLogEntry>> {
+        self.id.audit_logs_iter(http, action_type, user_id)
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// [`Guild`]: struct.Guild.html
@@ -397,6 +517,29 @@ impl Guild {
         http.as_ref().create_guild(&map).await
     }
 
+    /// Creates a guild from the [`GuildTemplate`] with the given code, with
+    /// the current user as its owner.
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full
+    /// [`Guild`] will be received over a [`Shard`].
+    ///
+    /// **Note**: This endpoint is usually only available for user accounts.
+    /// Refer to [`create`] for more information.
+    ///
+    /// [`Guild`]: struct.Guild.html
+    /// [`GuildTemplate`]: struct.GuildTemplate.html
+    /// [`PartialGuild`]: struct.PartialGuild.html
+    /// [`Shard`]: ../../gateway/struct.Shard.html
+    /// [`create`]: #method.create
+    pub async fn create_from_template(http: impl AsRef<Http>, code: &str, name: &str, icon: Option<&str>) -> Result<PartialGuild> {
+        let map = json!({
+            "icon": icon,
+            "name": name,
+        });
+
+        http.as_ref().create_guild_from_template(code, &map).await
+    }
+
     /// Creates a new [`Channel`] in the guild.
     ///
     /// **Note**: Requires the [Manage Channels] permission.
@@ -448,11 +591,9 @@ impl Guild {
     ///
     /// # Examples
     ///
-    /// See the [`EditProfile::avatar`] example for an in-depth example as to
-    /// how to read an image from the filesystem and encode it as base64. Most
-    /// of the example can be applied similarly for this method.
+    /// See [`utils::read_image`] for how to read an image from the filesystem
+    /// and encode it as base64.
     ///
-    /// [`EditProfile::avatar`]: ../../builder/struct.EditProfile.html#method.avatar
     /// [`utils::read_image`]: ../../utils/fn.read_image.html
     /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
     #[inline]
@@ -460,6 +601,40 @@ impl Guild {
         self.id.create_emoji(&http, name, image).await
     }
 
+    /// Creates an emoji in the guild with a name and an image, given as the
+    /// raw bytes of a PNG, JPEG, GIF or WebP file.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_bytes`] for more information.
+    ///
+    /// [`GuildId::create_emoji_from_bytes`]: ../id/struct.GuildId.html#method.create_emoji_from_bytes
+    #[inline]
+    pub async fn create_emoji_from_bytes(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        image: &[u8],
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_bytes(&http, name, image, roles).await
+    }
+
+    /// Creates an emoji in the guild with a name and the image at the given
+    /// path.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_path`] for more information.
+    ///
+    /// [`GuildId::create_emoji_from_path`]: ../id/struct.GuildId.html#method.create_emoji_from_path
+    #[inline]
+    pub async fn create_emoji_from_path(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        path: impl AsRef<Path>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_path(&http, name, path, roles).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -509,6 +684,17 @@ impl Guild {
         self.id.create_role(cache_http.http(), f).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(&self, http: impl AsRef<Http>, name: &str, description: Option<&str>) -> Result<GuildTemplate> {
+        self.id.create_template(&http, name, description).await
+    }
+
     /// Deletes the current guild if the current user is the owner of the
     /// guild.
     ///
@@ -546,6 +732,21 @@ impl Guild {
         self.id.delete_emoji(&http, emoji_id).await
     }
 
+    /// Deletes an [`Emoji`] from the guild, with a reason shown in the audit
+    /// log. Refer to [`delete_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [`delete_emoji`]: #method.delete_emoji
+    #[inline]
+    pub async fn delete_emoji_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.id.delete_emoji_with_reason(&http, emoji_id, reason).await
+    }
+
     /// Deletes an integration by Id from the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -556,6 +757,21 @@ impl Guild {
         self.id.delete_integration(&http, integration_id).await
     }
 
+    /// Deletes an integration by Id from the guild, with a reason shown in
+    /// the audit log. Refer to [`delete_integration`] for further
+    /// documentation.
+    ///
+    /// [`delete_integration`]: #method.delete_integration
+    #[inline]
+    pub async fn delete_integration_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.id.delete_integration_with_reason(&http, integration_id, reason).await
+    }
+
     /// Deletes a [`Role`] by Id from the guild.
     ///
     /// Also see [`Role::delete`] if you have the `cache` and `methods` features
@@ -627,6 +843,8 @@ impl Guild {
                 self.region = guild.region;
                 self.roles = guild.roles;
                 self.splash = guild.splash;
+                self.discovery_splash = guild.discovery_splash;
+                self.banner = guild.banner;
                 self.verification_level = guild.verification_level;
 
                 Ok(())
@@ -650,6 +868,39 @@ impl Guild {
         self.id.edit_emoji(&http, emoji_id, name).await
     }
 
+    /// Edits an [`Emoji`]'s name in the guild, with a reason shown in the
+    /// audit log. Refer to [`edit_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [`edit_emoji`]: #method.edit_emoji
+    #[inline]
+    pub async fn edit_emoji_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+        reason: impl AsRef<str>,
+    ) -> Result<Emoji> {
+        self.id.edit_emoji_with_reason(&http, emoji_id, name, reason).await
+    }
+
+    /// Sets the roles allowed to use an [`Emoji`] of the guild, allowing
+    /// everyone to use it if `roles` is empty.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn edit_emoji_roles(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.edit_emoji_roles(&http, emoji_id, roles).await
+    }
+
     /// Edits the properties of member of the guild, such as muting or
     /// nicknaming them.
     ///
@@ -832,9 +1083,19 @@ impl Guild {
 
     /// Returns the formatted URL of the guild's icon, if one exists.
     pub fn icon_url(&self) -> Option<String> {
+        self.icon_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the guild's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn icon_cdn_url(&self) -> Option<CdnUrl> {
         self.icon
             .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+            .map(|icon| CdnUrl::hashed(&format!("/icons/{}", self.id), icon))
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -1325,113 +1586,40 @@ impl Guild {
         self.id.move_member(&http, user_id, channel_id).await
     }
 
-    /// Calculate a [`User`]'s permissions in a given channel in the guild.
+    /// Calculates the permissions of a [`Member`] in a channel of the guild.
     ///
-    /// [`User`]: ../user/struct.User.html
+    /// The permissions are calculated with the following precedence:
+    ///
+    /// 1. The owner of the guild has all permissions.
+    /// 2. The permissions of the `@everyone` role and of the member's roles
+    ///    are combined. Members with the [Administrator] permission have all
+    ///    permissions.
+    /// 3. The permission overwrite of the channel for `@everyone` is applied,
+    ///    then the overwrites for the member's roles at once, and finally the
+    ///    overwrite for the member.
+    /// 4. Timed out members only keep the [Read Messages] and
+    ///    [Read Message History] permissions.
+    /// 5. Permissions which can not be used are removed, e.g. the permissions
+    ///    of voice channels in text channels, or any permission to send
+    ///    messages without the [Send Messages] permission.
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [Administrator]: ../permissions/struct.Permissions.html#associatedconstant.ADMINISTRATOR
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    /// [Read Messages]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGES
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
     #[inline]
-    pub fn user_permissions_in(&self, channel_id: impl Into<ChannelId>, user_id: impl Into<UserId>) -> Permissions {
-        self._user_permissions_in(channel_id.into(), user_id.into())
+    pub fn user_permissions_in(&self, channel: &GuildChannel, member: &Member) -> Permissions {
+        calculate_permissions(self.id, self.owner_id, &self.roles, Some(channel), member.user.id, Some(member))
     }
 
-    fn _user_permissions_in(
-        &self,
-        channel_id: ChannelId,
-        user_id: UserId,
-    ) -> Permissions {
-        // The owner has all permissions in all cases.
-        if user_id == self.owner_id {
-            return Permissions::all();
-        }
-
-        // Start by retrieving the @everyone role's permissions.
-        let everyone = match self.roles.get(&RoleId(self.id.0)) {
-            Some(everyone) => everyone,
-            None => {
-                error!(
-                    "(╯°□°）╯︵ ┻━┻ @everyone role ({}) missing in '{}'",
-                    self.id,
-                    self.name
-                );
-
-                return Permissions::empty();
-            },
-        };
-
-        // Create a base set of permissions, starting with `@everyone`s.
-        let mut permissions = everyone.permissions;
-
-        let member = match self.members.get(&user_id) {
-            Some(member) => member,
-            None => return everyone.permissions,
-        };
-
-        for &role in &member.roles {
-            if let Some(role) = self.roles.get(&role) {
-                permissions |= role.permissions;
-            } else {
-                warn!(
-                    "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.id,
-                    self.id,
-                    role
-                );
-            }
-        }
-
-        // Administrators have all permissions in any channel.
-        if permissions.contains(Permissions::ADMINISTRATOR) {
-            return Permissions::all();
-        }
-
-        if let Some(channel) = self.channels.get(&channel_id) {
-            // If this is a text channel, then throw out voice permissions.
-            if channel.kind == ChannelType::Text {
-                permissions &= !(Permissions::CONNECT
-                    | Permissions::SPEAK
-                    | Permissions::MUTE_MEMBERS
-                    | Permissions::DEAFEN_MEMBERS
-                    | Permissions::MOVE_MEMBERS
-                    | Permissions::USE_VAD
-                    | Permissions::STREAM);
-            }
-
-            // Apply the permission overwrites for the channel for each of the
-            // overwrites that - first - applies to the member's roles, and then
-            // the member itself.
-            //
-            // First apply the denied permission overwrites for each, then apply
-            // the allowed.
-
-            let mut data = Vec::with_capacity(member.roles.len());
-
-            // Roles
-            for overwrite in &channel.permission_overwrites {
-                if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                    if role.0 != self.id.0 && !member.roles.contains(&role) {
-                        continue;
-                    }
-
-                    if let Some(role) = self.roles.get(&role) {
-                        data.push((role.position, overwrite.deny, overwrite.allow));
-                    }
-                }
-            }
-
-            data.sort_by(|a, b| a.0.cmp(&b.0));
-
-            for overwrite in data {
-                permissions = (permissions & !overwrite.1) | overwrite.2;
-            }
-
-            // Member
-            for overwrite in &channel.permission_overwrites {
-                if PermissionOverwriteType::Member(user_id) != overwrite.kind {
-                    continue;
-                }
+    /// Calculates a user's permissions in a channel of the guild by their Ids,
+    /// falling back to the permissions of `@everyone` if the user is not a
+    /// cached member.
+    pub(crate) fn user_permissions_in_ids(&self, channel_id: ChannelId, user_id: UserId) -> Permissions {
+        let channel = self.channels.get(&channel_id);
 
-                permissions = (permissions & !overwrite.deny) | overwrite.allow;
-            }
-        } else {
+        if channel.is_none() {
             warn!(
                 "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
                 self.id,
@@ -1439,14 +1627,9 @@ impl Guild {
             );
         }
 
-        // The default channel is always readable.
-        if channel_id.0 == self.id.0 {
-            permissions |= Permissions::READ_MESSAGES;
-        }
-
-        self.remove_unusable_permissions(&mut permissions);
+        let member = self.members.get(&user_id);
 
-        permissions
+        calculate_permissions(self.id, self.owner_id, &self.roles, channel, user_id, member)
     }
 
     /// Calculate a [`Role`]'s permissions in a given channel in the guild.
@@ -1528,41 +1711,53 @@ impl Guild {
         self.id.prune_count(cache_http.http(), days).await
     }
 
+    #[inline]
     pub(crate) fn remove_unusable_permissions(&self, permissions: &mut Permissions) {
-        // No SEND_MESSAGES => no message-sending-related actions
-        // If the member does not have the `SEND_MESSAGES` permission, then
-        // throw out message-able permissions.
-        if !permissions.contains(Permissions::SEND_MESSAGES) {
-            *permissions &= !(Permissions::SEND_TTS_MESSAGES
-                | Permissions::MENTION_EVERYONE
-                | Permissions::EMBED_LINKS
-                | Permissions::ATTACH_FILES);
-        }
+        remove_unusable_permissions(permissions);
+    }
 
-        // If the permission does not have the `READ_MESSAGES` permission, then
-        // throw out actionable permissions.
-        if !permissions.contains(Permissions::READ_MESSAGES) {
-            *permissions &= Permissions::KICK_MEMBERS
-                | Permissions::BAN_MEMBERS
-                | Permissions::ADMINISTRATOR
-                | Permissions::MANAGE_GUILD
-                | Permissions::CHANGE_NICKNAME
-                | Permissions::MANAGE_NICKNAMES;
-        }
+    /// Gets the [`VoiceRegion`]s available to the guild.
+    ///
+    /// Refer to [`GuildId::regions`] for more information.
+    ///
+    /// [`GuildId::regions`]: ../id/struct.GuildId.html#method.regions
+    /// [`VoiceRegion`]: ../voice/struct.VoiceRegion.html
+    #[inline]
+    pub async fn regions(&self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        self.id.regions(&http).await
     }
 
-    /// Re-orders the channels of the guild.
+    /// Re-orders the channels of the guild in a single request.
+    ///
+    /// Refer to [`GuildId::reorder_channels`] for more information.
     ///
     /// Although not required, you should specify all channels' positions,
     /// regardless of whether they were updated. Otherwise, positioning can
     /// sometimes get weird.
+    ///
+    /// [`GuildId::reorder_channels`]: ../id/struct.GuildId.html#method.reorder_channels
     #[inline]
     pub async fn reorder_channels<It>(&self, http: impl AsRef<Http>, channels: It) -> Result<()>
-    where It: IntoIterator<Item = (ChannelId, u64)>
+    where
+        It: IntoIterator,
+        It::Item: Into<EditChannelPosition>,
     {
         self.id.reorder_channels(&http, channels).await
     }
 
+    /// Moves a channel of the guild, such as into a category while syncing
+    /// its permissions with the category.
+    ///
+    /// Refer to [`GuildId::edit_channel_position`] for more information.
+    ///
+    /// [`GuildId::edit_channel_position`]: ../id/struct.GuildId.html#method.edit_channel_position
+    #[inline]
+    pub async fn edit_channel_position<F>(&self, http: impl AsRef<Http>, channel_id: impl Into<ChannelId>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditChannelPosition) -> &mut EditChannelPosition
+    {
+        self.id.edit_channel_position(&http, channel_id, f).await
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -1605,9 +1800,37 @@ impl Guild {
 
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the guild's splash image, if one
+    /// exists, to request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn splash_cdn_url(&self) -> Option<CdnUrl> {
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| CdnUrl::hashed(&format!("/splashes/{}", self.id), splash))
+    }
+
+    /// Returns the formatted URL of the guild's banner, if one exists.
+    ///
+    /// The format defaults to a GIF for animated banners and to WebP
+    /// otherwise. The size must be a power of two between 16 and 4096.
+    pub fn banner_url(&self, format: Option<ImageFormat>, size: Option<u16>) -> Option<String> {
+        self.banner
+            .as_ref()
+            .map(|banner| image_url(&format!("/banners/{}", self.id), banner, format, size))
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image, if
+    /// one exists.
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        self.discovery_splash
+            .as_ref()
+            .map(|splash| CdnUrl::hashed(&format!("/discovery-splashes/{}", self.id), splash).build())
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -1650,6 +1873,17 @@ impl Guild {
         self.id.start_prune(cache_http.http(), days).await
     }
 
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(&http).await
+    }
+
     /// Unbans the given [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1677,13 +1911,14 @@ impl Guild {
         self.id.unban(&cache_http.http(), user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity URL, with the number of times it was
+    /// used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(&http).await
     }
 
@@ -1867,6 +2102,10 @@ impl<'de> Deserialize<'de> for Guild {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
+        let discovery_splash = match map.remove("discovery_splash") {
+            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
         let system_channel_id = match map.remove("system_channel_id") {
             Some(v) => Option::<ChannelId>::deserialize(v).map_err(DeError::custom)?,
             None => None,
@@ -1903,6 +2142,10 @@ impl<'de> Deserialize<'de> for Guild {
             .ok_or_else(|| DeError::custom("expected preferred locale"))
             .and_then(String::deserialize)
             .map_err(DeError::custom)?;
+        let stickers = match map.remove("stickers") {
+            Some(v) => deserialize_stickers(v).map_err(DeError::custom)?,
+            None => HashMap::new(),
+        };
 
         Ok(Self {
             afk_channel_id,
@@ -1926,6 +2169,7 @@ impl<'de> Deserialize<'de> for Guild {
             region,
             roles,
             splash,
+            discovery_splash,
             system_channel_id,
             verification_level,
             voice_states,
@@ -1935,11 +2179,143 @@ impl<'de> Deserialize<'de> for Guild {
             banner,
             vanity_url_code,
             preferred_locale,
+            stickers,
             _nonexhaustive: (),
         })
     }
 }
 
+/// Calculates the permissions of a user in a guild with the given owner and
+/// roles, in one of its channels if given.
+///
+/// Refer to [`Guild::user_permissions_in`] for the precedence of permissions.
+///
+/// [`Guild::user_permissions_in`]: struct.Guild.html#method.user_permissions_in
+#[cfg(feature = "model")]
+pub(crate) fn calculate_permissions(
+    guild_id: GuildId,
+    owner_id: UserId,
+    roles: &HashMap<RoleId, Role>,
+    channel: Option<&GuildChannel>,
+    user_id: UserId,
+    member: Option<&Member>,
+) -> Permissions {
+    // The owner has all permissions in all cases.
+    if user_id == owner_id {
+        return Permissions::all();
+    }
+
+    // Start by retrieving the @everyone role's permissions.
+    let everyone = match roles.get(&RoleId(guild_id.0)) {
+        Some(everyone) => everyone,
+        None => {
+            error!("(╯°□°）╯︵ ┻━┻ @everyone role missing in {}", guild_id);
+
+            return Permissions::empty();
+        },
+    };
+
+    let member_roles = member.map_or(&[][..], |member| &member.roles[..]);
+    let mut permissions = everyone.permissions;
+
+    for role_id in member_roles {
+        if let Some(role) = roles.get(role_id) {
+            permissions |= role.permissions;
+        } else {
+            warn!("(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}", user_id, guild_id, role_id);
+        }
+    }
+
+    // Administrators have all permissions in any channel.
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    if let Some(channel) = channel {
+        // If this is a text channel, then throw out voice permissions.
+        if channel.kind == ChannelType::Text {
+            permissions &= !(Permissions::CONNECT
+                | Permissions::SPEAK
+                | Permissions::MUTE_MEMBERS
+                | Permissions::DEAFEN_MEMBERS
+                | Permissions::MOVE_MEMBERS
+                | Permissions::USE_VAD
+                | Permissions::STREAM);
+        }
+
+        let mut everyone_overwrite = None;
+        let mut member_overwrite = None;
+        let mut roles_deny = Permissions::empty();
+        let mut roles_allow = Permissions::empty();
+
+        for overwrite in &channel.permission_overwrites {
+            match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) if role_id.0 == guild_id.0 => {
+                    everyone_overwrite = Some(overwrite);
+                },
+                PermissionOverwriteType::Role(role_id) if member_roles.contains(&role_id) => {
+                    roles_deny |= overwrite.deny;
+                    roles_allow |= overwrite.allow;
+                },
+                PermissionOverwriteType::Member(id) if id == user_id => {
+                    member_overwrite = Some(overwrite);
+                },
+                _ => {},
+            }
+        }
+
+        if let Some(overwrite) = everyone_overwrite {
+            permissions = (permissions & !overwrite.deny) | overwrite.allow;
+        }
+
+        // The overwrites of the member's roles are applied at once, so that
+        // any of them allowing a permission takes precedence over the others
+        // denying it.
+        permissions = (permissions & !roles_deny) | roles_allow;
+
+        if let Some(overwrite) = member_overwrite {
+            permissions = (permissions & !overwrite.deny) | overwrite.allow;
+        }
+
+        // The default channel is always readable.
+        if channel.id.0 == guild_id.0 {
+            permissions |= Permissions::READ_MESSAGES;
+        }
+    }
+
+    if member.map_or(false, |member| member.is_communication_disabled()) {
+        permissions &= Permissions::READ_MESSAGES | Permissions::READ_MESSAGE_HISTORY;
+    }
+
+    remove_unusable_permissions(&mut permissions);
+
+    permissions
+}
+
+#[cfg(feature = "model")]
+fn remove_unusable_permissions(permissions: &mut Permissions) {
+    // No SEND_MESSAGES => no message-sending-related actions
+    // If the member does not have the `SEND_MESSAGES` permission, then
+    // throw out message-able permissions.
+    if !permissions.contains(Permissions::SEND_MESSAGES) {
+        *permissions &= !(Permissions::SEND_TTS_MESSAGES
+            | Permissions::MENTION_EVERYONE
+            | Permissions::EMBED_LINKS
+            | Permissions::ATTACH_FILES);
+    }
+
+    // If the permission does not have the `READ_MESSAGES` permission, then
+    // throw out actionable permissions.
+    if !permissions.contains(Permissions::READ_MESSAGES) {
+        *permissions &= Permissions::KICK_MEMBERS
+            | Permissions::BAN_MEMBERS
+            | Permissions::ADMINISTRATOR
+            | Permissions::MANAGE_GUILD
+            | Permissions::CHANGE_NICKNAME
+            | Permissions::MANAGE_NICKNAMES;
+    }
+}
+
 /// Checks if a `&str` contains another `&str`.
 #[cfg(feature = "model")]
 fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
@@ -1997,6 +2373,17 @@ pub struct GuildEmbed {
     pub enabled: bool,
 }
 
+/// The vanity URL of a guild, with the number of times it was used.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildVanityUrl {
+    /// The code of the vanity invite, if the guild has set one.
+    pub code: Option<String>,
+    /// The number of times the vanity invite was used.
+    pub uses: u64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
 /// Representation of the number of members that would be pruned by a guild
 /// prune operation.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -2028,9 +2415,19 @@ pub struct GuildInfo {
 impl GuildInfo {
     /// Returns the formatted URL of the guild's icon, if the guild has an icon.
     pub fn icon_url(&self) -> Option<String> {
+        self.icon_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the guild's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn icon_cdn_url(&self) -> Option<CdnUrl> {
         self.icon
             .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+            .map(|icon| CdnUrl::hashed(&format!("/icons/{}", self.id), icon))
     }
 }
 
@@ -2052,7 +2449,7 @@ impl InviteGuild {
     pub fn splash_url(&self) -> Option<String> {
         self.icon
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|icon| CdnUrl::hashed(&format!("/splashes/{}", self.id), icon).build())
     }
 }
 
@@ -2277,6 +2674,9 @@ mod test {
             User {
                 id: UserId(210),
                 avatar: Some("abc".to_string()),
+                banner: None,
+                accent_colour: None,
+                avatar_decoration: None,
                 bot: true,
                 discriminator: 1432,
                 name: "test".to_string(),
@@ -2295,11 +2695,13 @@ mod test {
             Member {
                 deaf: false,
                 guild_id: GuildId(1),
-                joined_at: Some(dt),
+                joined_at: Some(dt.into()),
                 mute: false,
                 nick: Some("aaaa".to_string()),
                 roles: vec1,
                 user: u,
+                communication_disabled_until: None,
+                pending: false,
                 _nonexhaustive: (),
             }
         }
@@ -2344,6 +2746,7 @@ mod test {
                 region: "NA".to_string(),
                 roles: hm5,
                 splash: Some("asdf".to_string()),
+                discovery_splash: None,
                 verification_level: VerificationLevel::None,
                 voice_states: hm6,
                 description: None,
@@ -2355,6 +2758,7 @@ mod test {
                 banner: None,
                 vanity_url_code: Some("bruhmoment".to_string()),
                 preferred_locale: "en-US".to_string(),
+                stickers: HashMap::new(),
                 _nonexhaustive: (),
             }
         }
@@ -2371,6 +2775,19 @@ mod test {
             assert_eq!(lhs, gen_member().display_name());
         }
 
+        #[test]
+        fn banner_url() {
+            let mut guild = gen();
+            assert_eq!(guild.banner_url(None, None), None);
+
+            guild.banner = Some("a_bcd".to_string());
+            assert!(guild.banner_url(None, None).unwrap().ends_with("/banners/1/a_bcd.gif"));
+            assert!(guild
+                .banner_url(Some(ImageFormat::Png), Some(512))
+                .unwrap()
+                .ends_with("/banners/1/a_bcd.png?size=512"));
+        }
+
         #[tokio::test]
         async fn member_named_nickname() {
             let guild = gen();
@@ -2378,5 +2795,72 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        fn gen_role(id: u64, permissions: Permissions) -> Role {
+            serde_json::from_value(serde_json::json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "mentionable": false,
+                "name": "role",
+                "permissions": permissions.bits().to_string(),
+                "position": 0,
+            })).unwrap()
+        }
+
+        fn gen_overwrite(id: u64, kind: u8, allow: Permissions, deny: Permissions) -> serde_json::Value {
+            serde_json::json!({
+                "id": id.to_string(),
+                "type": kind,
+                "allow": allow.bits().to_string(),
+                "deny": deny.bits().to_string(),
+            })
+        }
+
+        #[test]
+        fn user_permissions_in() {
+            let mut guild = gen();
+            guild.owner_id = UserId(1);
+            guild.roles.insert(RoleId(1), gen_role(1, Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES));
+            guild.roles.insert(RoleId(2), gen_role(2, Permissions::EMBED_LINKS));
+            guild.roles.insert(RoleId(3), gen_role(3, Permissions::empty()));
+
+            let mut member = gen_member();
+            member.roles = vec![RoleId(2), RoleId(3)];
+
+            let channel: GuildChannel = serde_json::from_value(serde_json::json!({
+                "id": "5",
+                "guild_id": "1",
+                "type": 0,
+                "name": "general",
+                "permission_overwrites": [
+                    gen_overwrite(1, 0, Permissions::empty(), Permissions::SEND_MESSAGES),
+                    gen_overwrite(2, 0, Permissions::empty(), Permissions::ADD_REACTIONS),
+                    gen_overwrite(3, 0, Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS, Permissions::empty()),
+                    gen_overwrite(210, 1, Permissions::empty(), Permissions::EMBED_LINKS),
+                ],
+            })).unwrap();
+
+            // The roles' overwrites take precedence over `@everyone`'s, with
+            // allowed permissions winning, and the member's over the roles'.
+            assert_eq!(
+                guild.user_permissions_in(&channel, &member),
+                Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+            );
+
+            // Without the roles, the member can not send messages, nor embed
+            // links as a result.
+            member.roles.clear();
+            assert_eq!(guild.user_permissions_in(&channel, &member), Permissions::READ_MESSAGES);
+
+            member.communication_disabled_until = Some((Utc::now() + chrono::Duration::hours(1)).into());
+            member.roles = vec![RoleId(2), RoleId(3)];
+            assert_eq!(guild.user_permissions_in(&channel, &member), Permissions::READ_MESSAGES);
+
+            guild.roles.insert(RoleId(3), gen_role(3, Permissions::ADMINISTRATOR));
+            assert_eq!(guild.user_permissions_in(&channel, &member), Permissions::all());
+        }
     }
 }
@@ -6,7 +6,15 @@ use crate::model::utils::{deserialize_emojis, deserialize_roles};
 #[cfg(feature = "model")]
 use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::model::misc::image_url;
+#[cfg(feature = "model")]
+use super::calculate_permissions;
+#[cfg(feature = "model")]
+use crate::http::{Http, CacheHttp, UserPagination};
+#[cfg(feature = "model")]
+use futures::stream::Stream;
+#[cfg(feature = "model")]
+use std::path::Path;
 #[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
 use crate::cache::Cache;
 #[cfg(feature = "collector")]
@@ -43,6 +51,9 @@ pub struct PartialGuild {
     pub region: String,
     #[serde(serialize_with = "serialize_roles", deserialize_with = "deserialize_roles")] pub roles: HashMap<RoleId, Role>,
     pub splash: Option<String>,
+    /// An identifying hash of the guild's discovery splash image, shown in
+    /// the server discovery if the guild has the `DISCOVERABLE` feature.
+    pub discovery_splash: Option<String>,
     pub verification_level: VerificationLevel,
     pub description: Option<String>,
     pub premium_tier: PremiumTier,
@@ -99,14 +110,66 @@ impl PartialGuild {
         self.id.ban_with_reason(&http, user, dmd, reason).await
     }
 
+    /// Ban a [`User`] from the guild, deleting the messages they sent in the
+    /// given number of seconds, up to 7 days.
+    ///
+    /// Refer to [`GuildId::ban_with_seconds`] for more information.
+    ///
+    /// [`GuildId::ban_with_seconds`]: ../id/struct.GuildId.html#method.ban_with_seconds
+    /// [`User`]: ../user/struct.User.html
+    #[inline]
+    pub async fn ban_with_seconds(
+        &self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+    ) -> Result<()> {
+        self.id.ban_with_seconds(&http, user, delete_message_seconds).await
+    }
+
+    /// Ban a [`User`] from the guild with a reason, deleting the messages they
+    /// sent in the given number of seconds. Refer to [`ban_with_seconds`] for
+    /// further documentation.
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [`ban_with_seconds`]: #method.ban_with_seconds
+    #[inline]
+    pub async fn ban_with_seconds_and_reason(
+        &self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.id.ban_with_seconds_and_reason(&http, user, delete_message_seconds, reason).await
+    }
+
     /// Gets a list of the guild's bans.
     ///
+    /// Refer to [`GuildId::bans`] for more information.
+    ///
     /// Requires the [Ban Members] permission.
     ///
+    /// [`GuildId::bans`]: ../id/struct.GuildId.html#method.bans
     /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
     #[inline]
-    pub async fn bans(&self, http: impl AsRef<Http>) -> Result<Vec<Ban>> {
-        self.id.bans(&http).await
+    pub async fn bans(
+        &self,
+        http: impl AsRef<Http>,
+        target: Option<UserPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Ban>> {
+        self.id.bans(&http, target, limit).await
+    }
+
+    /// Streams over all the bans of the guild.
+    ///
+    /// Refer to [`GuildId::bans_iter`] for more information.
+    ///
+    /// [`GuildId::bans_iter`]: ../id/struct.GuildId.html#method.bans_iter
+    #[inline]
+    pub fn bans_iter<H: AsRef<Http>>(&self, http: H) -> impl Stream<Item=Result<Ban>> {
+        self.id.bans_iter(http)
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -150,11 +213,9 @@ impl PartialGuild {
     ///
     /// # Examples
     ///
-    /// See the [`EditProfile::avatar`] example for an in-depth example as to
-    /// how to read an image from the filesystem and encode it as base64. Most
-    /// of the example can be applied similarly for this method.
+    /// See [`utils::read_image`] for how to read an image from the filesystem
+    /// and encode it as base64.
     ///
-    /// [`EditProfile::avatar`]: ../../builder/struct.EditProfile.html#method.avatar
     /// [`Guild::create_emoji`]: struct.Guild.html#method.create_emoji
     /// [`utils::read_image`]: ../../utils/fn.read_image.html
     /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
@@ -163,6 +224,40 @@ impl PartialGuild {
         self.id.create_emoji(&http, name, image).await
     }
 
+    /// Creates an emoji in the guild with a name and an image, given as the
+    /// raw bytes of a PNG, JPEG, GIF or WebP file.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_bytes`] for more information.
+    ///
+    /// [`GuildId::create_emoji_from_bytes`]: ../id/struct.GuildId.html#method.create_emoji_from_bytes
+    #[inline]
+    pub async fn create_emoji_from_bytes(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        image: &[u8],
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_bytes(&http, name, image, roles).await
+    }
+
+    /// Creates an emoji in the guild with a name and the image at the given
+    /// path.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_path`] for more information.
+    ///
+    /// [`GuildId::create_emoji_from_path`]: ../id/struct.GuildId.html#method.create_emoji_from_path
+    #[inline]
+    pub async fn create_emoji_from_path(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        path: impl AsRef<Path>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_path(&http, name, path, roles).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -199,6 +294,17 @@ impl PartialGuild {
         self.id.create_role(&http, f).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(&self, http: impl AsRef<Http>, name: &str, description: Option<&str>) -> Result<GuildTemplate> {
+        self.id.create_template(&http, name, description).await
+    }
+
     /// Deletes the current guild if the current user is the owner of the
     /// guild.
     ///
@@ -219,6 +325,21 @@ impl PartialGuild {
         self.id.delete_emoji(&http, emoji_id).await
     }
 
+    /// Deletes an [`Emoji`] from the guild, with a reason shown in the audit
+    /// log. Refer to [`delete_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [`delete_emoji`]: #method.delete_emoji
+    #[inline]
+    pub async fn delete_emoji_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.id.delete_emoji_with_reason(&http, emoji_id, reason).await
+    }
+
     /// Deletes an integration by Id from the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -229,6 +350,21 @@ impl PartialGuild {
         self.id.delete_integration(&http, integration_id).await
     }
 
+    /// Deletes an integration by Id from the guild, with a reason shown in
+    /// the audit log. Refer to [`delete_integration`] for further
+    /// documentation.
+    ///
+    /// [`delete_integration`]: #method.delete_integration
+    #[inline]
+    pub async fn delete_integration_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self.id.delete_integration_with_reason(&http, integration_id, reason).await
+    }
+
     /// Deletes a [`Role`] by Id from the guild.
     ///
     /// Also see [`Role::delete`] if you have the `cache` and `methods` features
@@ -291,6 +427,39 @@ impl PartialGuild {
         self.id.edit_emoji(&http, emoji_id, name).await
     }
 
+    /// Edits an [`Emoji`]'s name in the guild, with a reason shown in the
+    /// audit log. Refer to [`edit_emoji`] for further documentation.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [`edit_emoji`]: #method.edit_emoji
+    #[inline]
+    pub async fn edit_emoji_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        name: &str,
+        reason: impl AsRef<str>,
+    ) -> Result<Emoji> {
+        self.id.edit_emoji_with_reason(&http, emoji_id, name, reason).await
+    }
+
+    /// Sets the roles allowed to use an [`Emoji`] of the guild, allowing
+    /// everyone to use it if `roles` is empty.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: struct.Emoji.html
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub async fn edit_emoji_roles(
+        &self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        roles: &[RoleId],
+    ) -> Result<Emoji> {
+        self.id.edit_emoji_roles(&http, emoji_id, roles).await
+    }
+
     /// Edits the properties of member of the guild, such as muting or
     /// nicknaming them.
     ///
@@ -363,9 +532,19 @@ impl PartialGuild {
 
     /// Returns a formatted URL of the guild's icon, if the guild has an icon.
     pub fn icon_url(&self) -> Option<String> {
+        self.icon_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the guild's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn icon_cdn_url(&self) -> Option<CdnUrl> {
         self.icon
             .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+            .map(|icon| CdnUrl::hashed(&format!("/icons/{}", self.id), icon))
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -434,6 +613,27 @@ impl PartialGuild {
         self.id.members(&http, limit, after).await
     }
 
+    /// Calculates the permissions of a [`Member`] in the guild, without the
+    /// overwrites of any channel.
+    ///
+    /// [`Member`]: struct.Member.html
+    #[inline]
+    pub fn member_permissions(&self, member: &Member) -> Permissions {
+        calculate_permissions(self.id, self.owner_id, &self.roles, None, member.user.id, Some(member))
+    }
+
+    /// Calculates the permissions of a [`Member`] in a channel of the guild.
+    ///
+    /// Refer to [`Guild::user_permissions_in`] for the precedence of
+    /// permissions.
+    ///
+    /// [`Guild::user_permissions_in`]: struct.Guild.html#method.user_permissions_in
+    /// [`Member`]: struct.Member.html
+    #[inline]
+    pub fn user_permissions_in(&self, channel: &GuildChannel, member: &Member) -> Permissions {
+        calculate_permissions(self.id, self.owner_id, &self.roles, Some(channel), member.user.id, Some(member))
+    }
+
     /// Moves a member to a specific voice channel.
     ///
     /// Requires the [Move Members] permission.
@@ -461,6 +661,17 @@ impl PartialGuild {
         self.id.prune_count(&http, days).await
     }
 
+    /// Gets the [`VoiceRegion`]s available to the guild.
+    ///
+    /// Refer to [`GuildId::regions`] for more information.
+    ///
+    /// [`GuildId::regions`]: ../id/struct.GuildId.html#method.regions
+    /// [`VoiceRegion`]: ../voice/struct.VoiceRegion.html
+    #[inline]
+    pub async fn regions(&self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        self.id.regions(&http).await
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -506,9 +717,37 @@ impl PartialGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[inline]
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the guild's splash image, if one
+    /// exists, to request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn splash_cdn_url(&self) -> Option<CdnUrl> {
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| CdnUrl::hashed(&format!("/splashes/{}", self.id), splash))
+    }
+
+    /// Returns the formatted URL of the guild's banner, if one exists.
+    ///
+    /// The format defaults to a GIF for animated banners and to WebP
+    /// otherwise. The size must be a power of two between 16 and 4096.
+    pub fn banner_url(&self, format: Option<ImageFormat>, size: Option<u16>) -> Option<String> {
+        self.banner
+            .as_ref()
+            .map(|banner| image_url(&format!("/banners/{}", self.id), banner, format, size))
+    }
+
+    /// Returns the formatted URL of the guild's discovery splash image, if
+    /// one exists.
+    pub fn discovery_splash_url(&self) -> Option<String> {
+        self.discovery_splash
+            .as_ref()
+            .map(|splash| CdnUrl::hashed(&format!("/discovery-splashes/{}", self.id), splash).build())
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -525,6 +764,17 @@ impl PartialGuild {
         self.id.start_integration_sync(&http, integration_id).await
     }
 
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`GuildTemplate`]: struct.GuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(&http).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// Requires the [Ban Members] permission.
@@ -536,13 +786,14 @@ impl PartialGuild {
         self.id.unban(&http, user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity URL, with the number of times it was
+    /// used.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<GuildVanityUrl> {
         self.id.vanity_url(&http).await
     }
 
@@ -624,6 +875,70 @@ impl PartialGuild {
     }
 }
 
+impl From<Guild> for PartialGuild {
+    /// Converts a guild into its partial form, leaving out the widget settings
+    /// as they are not received with guilds.
+    fn from(guild: Guild) -> Self {
+        Self {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            default_message_notifications: guild.default_message_notifications,
+            widget_channel_id: None,
+            widget_enabled: false,
+            emojis: guild.emojis,
+            features: guild.features,
+            icon: guild.icon,
+            mfa_level: guild.mfa_level,
+            name: guild.name,
+            owner_id: guild.owner_id,
+            region: guild.region,
+            roles: guild.roles,
+            splash: guild.splash,
+            discovery_splash: guild.discovery_splash,
+            verification_level: guild.verification_level,
+            description: guild.description,
+            premium_tier: guild.premium_tier,
+            premium_subscription_count: guild.premium_subscription_count,
+            banner: guild.banner,
+            vanity_url_code: guild.vanity_url_code,
+            _nonexhaustive: (),
+        }
+    }
+}
+
+impl<'a> From<&'a Guild> for PartialGuild {
+    /// Copies the fields of a guild into its partial form, without cloning
+    /// the members, channels and other collections left out of it.
+    fn from(guild: &Guild) -> Self {
+        Self {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            default_message_notifications: guild.default_message_notifications,
+            widget_channel_id: None,
+            widget_enabled: false,
+            emojis: guild.emojis.clone(),
+            features: guild.features.clone(),
+            icon: guild.icon.clone(),
+            mfa_level: guild.mfa_level,
+            name: guild.name.clone(),
+            owner_id: guild.owner_id,
+            region: guild.region.clone(),
+            roles: guild.roles.clone(),
+            splash: guild.splash.clone(),
+            discovery_splash: guild.discovery_splash.clone(),
+            verification_level: guild.verification_level,
+            description: guild.description.clone(),
+            premium_tier: guild.premium_tier,
+            premium_subscription_count: guild.premium_subscription_count,
+            banner: guild.banner.clone(),
+            vanity_url_code: guild.vanity_url_code.clone(),
+            _nonexhaustive: (),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for PartialGuild {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         let mut map = JsonMap::deserialize(deserializer)?;
@@ -706,6 +1021,10 @@ impl<'de> Deserialize<'de> for PartialGuild {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
+        let discovery_splash = match map.remove("discovery_splash") {
+            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
         let verification_level = map.remove("verification_level")
             .ok_or_else(|| DeError::custom("expected guild verification_level"))
             .and_then(VerificationLevel::deserialize)
@@ -747,6 +1066,7 @@ impl<'de> Deserialize<'de> for PartialGuild {
             region,
             roles,
             splash,
+            discovery_splash,
             verification_level,
             description,
             premium_tier,
@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 
 #[cfg(feature = "model")]
 use crate::builder::EditRole;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
@@ -15,7 +15,7 @@ use crate::model::misc::RoleParseError;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::utils::parse_role;
 #[cfg(feature = "model")]
-use crate::http::Http;
+use crate::http::{Http, CacheHttp};
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use async_trait::async_trait;
 
@@ -70,6 +70,15 @@ pub struct Role {
     ///
     /// The `@everyone` role is usually either `-1` or `0`.
     pub position: i64,
+    /// The hash of the role's icon, shown next to its members' names.
+    ///
+    /// **Note**: Requires the guild to have the `ROLE_ICONS` feature.
+    pub icon: Option<String>,
+    /// The unicode emoji shown next to the role's members' names instead of
+    /// an [`icon`].
+    ///
+    /// [`icon`]: #structfield.icon
+    pub unicode_emoji: Option<String>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -86,6 +95,15 @@ impl Role {
         http.as_ref().delete_role(self.guild_id.0, self.id.0).await
     }
 
+    /// Deletes the role with a reason shown in the audit log. Refer to
+    /// [`delete`] for further documentation.
+    ///
+    /// [`delete`]: #method.delete
+    #[inline]
+    pub async fn delete_with_reason(&mut self, http: impl AsRef<Http>, reason: impl AsRef<str>) -> Result<()> {
+        self.guild_id.delete_role_with_reason(http, self.id, reason).await
+    }
+
     /// Edits a [`Role`], optionally setting its new fields.
     ///
     /// Requires the [Manage Roles] permission.
@@ -113,6 +131,37 @@ impl Role {
         self.guild_id.edit_role(http, self.id, f).await
     }
 
+    /// Edits the role with a reason shown in the audit log. Refer to [`edit`]
+    /// for further documentation.
+    ///
+    /// [`edit`]: #method.edit
+    #[inline]
+    pub async fn edit_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        reason: impl AsRef<str>,
+        f: impl FnOnce(&mut EditRole) -> &mut EditRole,
+    ) -> Result<Role> {
+        self.guild_id.edit_role_with_reason(http, self.id, reason, f).await
+    }
+
+    /// Returns the formatted URL of the role's icon, if one exists.
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon_cdn_url().map(|url| url.build())
+    }
+
+    /// Returns a builder for the URL of the role's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    pub fn icon_cdn_url(&self) -> Option<CdnUrl> {
+        self.icon
+            .as_ref()
+            .map(|icon| CdnUrl::hashed(&format!("/role-icons/{}", self.id), icon))
+    }
+
     /// Searches the cache for the guild that owns the role.
     ///
     /// # Errors
@@ -201,6 +250,39 @@ impl RoleId {
 
         None
     }
+
+    /// First attempts to find the [`Role`] of the guild in the cache, upon
+    /// failure requests the roles of the guild via the REST API.
+    ///
+    /// **Note**: If the cache is not enabled, the REST API will be used only.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::RoleNotFound`] if the guild does not have the
+    /// role.
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`ModelError::RoleNotFound`]: ../error/enum.Error.html#variant.RoleNotFound
+    pub async fn to_role(self, cache_http: impl CacheHttp, guild_id: impl Into<GuildId>) -> Result<Role> {
+        let guild_id = guild_id.into();
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(role) = cache.role(guild_id, self).await {
+                    return Ok(role);
+                }
+            }
+        }
+
+        cache_http
+            .http()
+            .get_guild_roles(guild_id.0)
+            .await?
+            .into_iter()
+            .find(|role| role.id == self)
+            .ok_or(Error::Model(ModelError::RoleNotFound))
+    }
 }
 
 impl From<Role> for RoleId {
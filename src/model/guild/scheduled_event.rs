@@ -0,0 +1,381 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::model::misc::image_url;
+
+/// An event scheduled in a [`Guild`].
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledEvent {
+    /// The Id of the scheduled event.
+    pub id: ScheduledEventId,
+    /// The Id of the guild the event is scheduled in.
+    pub guild_id: GuildId,
+    /// The Id of the channel the event takes place in.
+    ///
+    /// **Note**: This is `None` for [`External`] events.
+    ///
+    /// [`External`]: enum.ScheduledEventType.html#variant.External
+    pub channel_id: Option<ChannelId>,
+    /// The Id of the user who created the event.
+    ///
+    /// **Note**: This is `None` for events created before October 25th, 2021.
+    pub creator_id: Option<UserId>,
+    /// The name of the event.
+    pub name: String,
+    /// The description of the event.
+    pub description: Option<String>,
+    /// The time the event is scheduled to start.
+    #[serde(rename = "scheduled_start_time")]
+    pub start_time: DateTime<Utc>,
+    /// The time the event is scheduled to end.
+    ///
+    /// **Note**: This is required for [`External`] events.
+    ///
+    /// [`External`]: enum.ScheduledEventType.html#variant.External
+    #[serde(rename = "scheduled_end_time")]
+    pub end_time: Option<DateTime<Utc>>,
+    /// The privacy level of the event, `2` meaning only guild members can
+    /// see it.
+    pub privacy_level: u8,
+    /// The status of the event.
+    pub status: ScheduledEventStatus,
+    /// The type of the event's location.
+    #[serde(rename = "entity_type")]
+    pub kind: ScheduledEventType,
+    /// The Id of the stage instance hosting the event, if any.
+    pub entity_id: Option<u64>,
+    /// Additional data of the event's location.
+    ///
+    /// **Note**: This is only present for [`External`] events.
+    ///
+    /// [`External`]: enum.ScheduledEventType.html#variant.External
+    #[serde(rename = "entity_metadata")]
+    pub metadata: Option<ScheduledEventMetadata>,
+    /// The user who created the event.
+    pub creator: Option<User>,
+    /// The number of users subscribed to the event.
+    ///
+    /// **Note**: This is only present when requested with
+    /// `with_user_count`.
+    pub user_count: Option<u64>,
+    /// The hash of the event's cover image.
+    pub image: Option<String>,
+    /// The rule the event recurs by, if it is a recurring event.
+    pub recurrence_rule: Option<RecurrenceRule>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl ScheduledEvent {
+    /// Deletes the scheduled event.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.guild_id.delete_scheduled_event(http, self.id).await
+    }
+
+    /// Gets the users subscribed to the scheduled event.
+    ///
+    /// Refer to [`GuildId::scheduled_event_users`] for more information.
+    ///
+    /// [`GuildId::scheduled_event_users`]: ../id/struct.GuildId.html#method.scheduled_event_users
+    #[inline]
+    pub async fn users(
+        &self,
+        http: impl AsRef<Http>,
+        limit: Option<u64>,
+        with_member: bool,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        self.guild_id.scheduled_event_users(http, self.id, limit, with_member).await
+    }
+
+    /// Returns the formatted URL of the event's cover image, if one exists.
+    ///
+    /// The format defaults to WebP. The size must be a power of two between
+    /// 16 and 4096.
+    pub fn image_url(&self, format: Option<ImageFormat>, size: Option<u16>) -> Option<String> {
+        self.image
+            .as_ref()
+            .map(|image| image_url(&format!("/guild-events/{}", self.id), image, format, size))
+    }
+}
+
+/// The rule a recurring [`ScheduledEvent`] recurs by.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecurrenceRule {
+    /// The start of the interval the event recurs within.
+    pub start: DateTime<Utc>,
+    /// The end of the interval the event recurs within, if any.
+    pub end: Option<DateTime<Utc>>,
+    /// How often the event recurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// The spacing between recurrences, e.g. `2` with a [`Weekly`]
+    /// frequency recurs every other week.
+    ///
+    /// [`Weekly`]: enum.RecurrenceRuleFrequency.html#variant.Weekly
+    pub interval: u64,
+    /// The days of the week the event recurs on.
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// The days of specific weeks of the month the event recurs on.
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// The months the event recurs in.
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// The days of the month the event recurs on.
+    pub by_month_day: Option<Vec<u8>>,
+    /// The days of the year the event recurs on.
+    pub by_year_day: Option<Vec<u16>>,
+    /// The number of times the event recurs before stopping.
+    pub count: Option<u64>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A day of a specific week of the month a recurring [`ScheduledEvent`]
+/// recurs on, e.g. the second Tuesday.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecurrenceRuleNWeekday {
+    /// The week of the month, from 1 to 5.
+    pub n: u8,
+    /// The day of the week.
+    pub day: RecurrenceRuleWeekday,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// Additional data of an [`External`] [`ScheduledEvent`]'s location.
+///
+/// [`External`]: enum.ScheduledEventType.html#variant.External
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledEventMetadata {
+    /// The location of the event, 1 to 100 characters.
+    pub location: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A user subscribed to a [`ScheduledEvent`].
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledEventUser {
+    /// The Id of the scheduled event.
+    #[serde(rename = "guild_scheduled_event_id")]
+    pub event_id: ScheduledEventId,
+    /// The subscribed user.
+    pub user: User,
+    /// The user's member data in the event's guild.
+    ///
+    /// **Note**: This is only present when requested with `with_member`.
+    pub member: Option<PartialMember>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// How often a recurring [`ScheduledEvent`] recurs.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum RecurrenceRuleFrequency {
+    Yearly = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Daily = 3,
+}
+
+enum_number!(
+    RecurrenceRuleFrequency {
+        Yearly,
+        Monthly,
+        Weekly,
+        Daily,
+    }
+);
+
+impl RecurrenceRuleFrequency {
+    pub fn num(self) -> u64 {
+        match self {
+            RecurrenceRuleFrequency::Yearly => 0,
+            RecurrenceRuleFrequency::Monthly => 1,
+            RecurrenceRuleFrequency::Weekly => 2,
+            RecurrenceRuleFrequency::Daily => 3,
+        }
+    }
+}
+
+/// A day of the week a recurring [`ScheduledEvent`] recurs on.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum RecurrenceRuleWeekday {
+    Monday = 0,
+    Tuesday = 1,
+    Wednesday = 2,
+    Thursday = 3,
+    Friday = 4,
+    Saturday = 5,
+    Sunday = 6,
+}
+
+enum_number!(
+    RecurrenceRuleWeekday {
+        Monday,
+        Tuesday,
+        Wednesday,
+        Thursday,
+        Friday,
+        Saturday,
+        Sunday,
+    }
+);
+
+impl RecurrenceRuleWeekday {
+    pub fn num(self) -> u64 {
+        match self {
+            RecurrenceRuleWeekday::Monday => 0,
+            RecurrenceRuleWeekday::Tuesday => 1,
+            RecurrenceRuleWeekday::Wednesday => 2,
+            RecurrenceRuleWeekday::Thursday => 3,
+            RecurrenceRuleWeekday::Friday => 4,
+            RecurrenceRuleWeekday::Saturday => 5,
+            RecurrenceRuleWeekday::Sunday => 6,
+        }
+    }
+}
+
+/// A month a recurring [`ScheduledEvent`] recurs in.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum RecurrenceRuleMonth {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+enum_number!(
+    RecurrenceRuleMonth {
+        January,
+        February,
+        March,
+        April,
+        May,
+        June,
+        July,
+        August,
+        September,
+        October,
+        November,
+        December,
+    }
+);
+
+impl RecurrenceRuleMonth {
+    pub fn num(self) -> u64 {
+        match self {
+            RecurrenceRuleMonth::January => 1,
+            RecurrenceRuleMonth::February => 2,
+            RecurrenceRuleMonth::March => 3,
+            RecurrenceRuleMonth::April => 4,
+            RecurrenceRuleMonth::May => 5,
+            RecurrenceRuleMonth::June => 6,
+            RecurrenceRuleMonth::July => 7,
+            RecurrenceRuleMonth::August => 8,
+            RecurrenceRuleMonth::September => 9,
+            RecurrenceRuleMonth::October => 10,
+            RecurrenceRuleMonth::November => 11,
+            RecurrenceRuleMonth::December => 12,
+        }
+    }
+}
+
+/// The status of a [`ScheduledEvent`].
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ScheduledEventStatus {
+    /// The event has not started yet.
+    Scheduled = 1,
+    /// The event is ongoing.
+    Active = 2,
+    /// The event has ended.
+    Completed = 3,
+    /// The event was canceled before it started.
+    Canceled = 4,
+}
+
+enum_number!(
+    ScheduledEventStatus {
+        Scheduled,
+        Active,
+        Completed,
+        Canceled,
+    }
+);
+
+impl ScheduledEventStatus {
+    pub fn num(self) -> u64 {
+        match self {
+            ScheduledEventStatus::Scheduled => 1,
+            ScheduledEventStatus::Active => 2,
+            ScheduledEventStatus::Completed => 3,
+            ScheduledEventStatus::Canceled => 4,
+        }
+    }
+}
+
+/// The type of a [`ScheduledEvent`]'s location.
+///
+/// [`ScheduledEvent`]: struct.ScheduledEvent.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ScheduledEventType {
+    /// The event takes place in a stage channel.
+    StageInstance = 1,
+    /// The event takes place in a voice channel.
+    Voice = 2,
+    /// The event takes place outside of Discord.
+    External = 3,
+}
+
+enum_number!(
+    ScheduledEventType {
+        StageInstance,
+        Voice,
+        External,
+    }
+);
+
+impl ScheduledEventType {
+    pub fn num(self) -> u64 {
+        match self {
+            ScheduledEventType::StageInstance => 1,
+            ScheduledEventType::Voice => 2,
+            ScheduledEventType::External => 3,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::model::prelude::*;
+
+#[cfg(feature = "model")]
+use crate::builder::EditGuildTemplate;
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// A snapshot of a [`Guild`]'s channels, roles and settings, which new guilds
+/// can be created from.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildTemplate {
+    /// The code of the template, which it is shared by.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of guilds created from the template.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// The time the template was created.
+    pub created_at: Timestamp,
+    /// The time the template was last synced with its guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the guild the template was created from, as it was
+    /// last synced.
+    pub serialized_source_guild: Value,
+    /// Whether the guild has changed since the template was last synced.
+    pub is_dirty: Option<bool>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl GuildTemplate {
+    /// Creates a guild from the template, with the current user as its
+    /// owner.
+    ///
+    /// Refer to [`Guild::create_from_template`] for more information.
+    ///
+    /// [`Guild::create_from_template`]: struct.Guild.html#method.create_from_template
+    #[inline]
+    pub async fn create_guild(&self, http: impl AsRef<Http>, name: &str, icon: Option<&str>) -> Result<PartialGuild> {
+        Guild::create_from_template(http, &self.code, name, icon).await
+    }
+
+    /// Deletes the template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.delete_template(http, &self.code).await
+    }
+
+    /// Edits the template.
+    ///
+    /// Refer to [`EditGuildTemplate`] for the available options.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [`EditGuildTemplate`]: ../../builder/struct.EditGuildTemplate.html
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditGuildTemplate) -> &mut EditGuildTemplate {
+        *self = self.source_guild_id.edit_template(http, &self.code, f).await?;
+
+        Ok(())
+    }
+
+    /// Updates the template to the current state of its guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub async fn sync(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = self.source_guild_id.sync_template(http, &self.code).await?;
+
+        Ok(())
+    }
+
+    /// Returns the URL new guilds can be created from the template with.
+    pub fn url(&self) -> String {
+        format!("https://discord.new/{}", self.code)
+    }
+}
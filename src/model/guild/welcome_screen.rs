@@ -0,0 +1,32 @@
+use crate::model::prelude::*;
+
+/// The screen shown to new members of a community [`Guild`].
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWelcomeScreen {
+    /// The description of the guild shown on the screen.
+    pub description: Option<String>,
+    /// The channels recommended on the screen, at most 5.
+    pub welcome_channels: Vec<GuildWelcomeChannel>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A channel recommended on a [`GuildWelcomeScreen`].
+///
+/// [`GuildWelcomeScreen`]: struct.GuildWelcomeScreen.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWelcomeChannel {
+    /// The Id of the channel.
+    pub channel_id: ChannelId,
+    /// The description shown for the channel.
+    pub description: String,
+    /// The Id of the channel's custom emoji, if it has one.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the channel's emoji, or the unicode character of a
+    /// standard emoji.
+    pub emoji_name: Option<String>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
@@ -0,0 +1,118 @@
+use crate::model::prelude::*;
+
+/// The settings of a [`Guild`]'s widget.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetSettings {
+    /// Whether the widget is enabled.
+    pub enabled: bool,
+    /// The Id of the channel the widget invites to, if any.
+    pub channel_id: Option<ChannelId>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The public widget of a [`Guild`], showing its voice channels and online
+/// members.
+///
+/// [`Guild`]: struct.Guild.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidget {
+    /// The Id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// The URL of the invite to the channel set in the widget settings, if
+    /// any.
+    pub instant_invite: Option<String>,
+    /// The voice channels everyone can connect to.
+    pub channels: Vec<GuildWidgetChannel>,
+    /// The online members, at most 100.
+    pub members: Vec<GuildWidgetMember>,
+    /// The number of online members.
+    pub presence_count: u64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A voice channel shown on a [`GuildWidget`].
+///
+/// [`GuildWidget`]: struct.GuildWidget.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: String,
+    /// The position of the channel in the channel list.
+    pub position: i64,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// An online member shown on a [`GuildWidget`].
+///
+/// **Note**: The widget does not expose the Ids of members, so the fields
+/// identifying them are anonymized.
+///
+/// [`GuildWidget`]: struct.GuildWidget.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetMember {
+    /// The index of the member on the widget, which is not their user Id.
+    pub id: String,
+    /// The username of the member.
+    pub username: String,
+    /// The discriminator of the member, always `0000`.
+    pub discriminator: String,
+    /// The avatar hash of the member, always `None`.
+    pub avatar: Option<String>,
+    /// The online status of the member.
+    pub status: OnlineStatus,
+    /// The URL of the member's avatar.
+    pub avatar_url: String,
+    /// The Id of the voice channel the member is connected to, if any.
+    pub channel_id: Option<ChannelId>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The style of the image of a [`GuildWidget`].
+///
+/// [`GuildWidget`]: struct.GuildWidget.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildWidgetStyle {
+    /// A small shield with the number of online members.
+    Shield,
+    /// A large banner with the guild's icon and name, and a join button.
+    Banner1,
+    /// A smaller banner with the guild's icon and name, and the number of
+    /// online members.
+    Banner2,
+    /// A banner with the guild's icon and name, the number of online
+    /// members, and a call to join the guild.
+    Banner3,
+    /// A large banner with the guild's icon and name, the number of online
+    /// members, and a join button.
+    Banner4,
+}
+
+impl GuildWidgetStyle {
+    /// The name of the style, as used in the image URL.
+    pub fn name(&self) -> &str {
+        match *self {
+            GuildWidgetStyle::Shield => "shield",
+            GuildWidgetStyle::Banner1 => "banner1",
+            GuildWidgetStyle::Banner2 => "banner2",
+            GuildWidgetStyle::Banner3 => "banner3",
+            GuildWidgetStyle::Banner4 => "banner4",
+        }
+    }
+}
+
+impl Default for GuildWidgetStyle {
+    fn default() -> Self {
+        GuildWidgetStyle::Shield
+    }
+}
@@ -91,6 +91,14 @@ macro_rules! id_u64 {
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct ApplicationId(pub u64);
 
+/// An identifier for an [`ApplicationCommand`](../interactions/struct.ApplicationCommand.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct CommandId(pub u64);
+
+/// An identifier for an [`AutoModRule`](../guild/struct.AutoModRule.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct AutoModRuleId(pub u64);
+
 /// An identifier for a Channel
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct ChannelId(pub u64);
@@ -107,6 +115,10 @@ pub struct GuildId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct IntegrationId(pub u64);
 
+/// An identifier for an [`Interaction`](../interactions/struct.Interaction.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct InteractionId(pub u64);
+
 /// An identifier for a Message
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct MessageId(pub u64);
@@ -115,6 +127,22 @@ pub struct MessageId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct RoleId(pub u64);
 
+/// An identifier for a [`ScheduledEvent`](../guild/struct.ScheduledEvent.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct ScheduledEventId(pub u64);
+
+/// An identifier for a [`StageInstance`](../channel/struct.StageInstance.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StageInstanceId(pub u64);
+
+/// An identifier for a [`Sticker`](../sticker/struct.Sticker.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StickerId(pub u64);
+
+/// An identifier for a [`StickerPack`](../sticker/struct.StickerPack.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StickerPackId(pub u64);
+
 /// An identifier for a User
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct UserId(pub u64);
@@ -134,12 +162,19 @@ pub struct AttachmentId(u64);
 id_u64! {
     AttachmentId;
     ApplicationId;
+    AutoModRuleId;
     ChannelId;
+    CommandId;
     EmojiId;
     GuildId;
     IntegrationId;
+    InteractionId;
     MessageId;
     RoleId;
+    ScheduledEventId;
+    StageInstanceId;
+    StickerId;
+    StickerPackId;
     UserId;
     WebhookId;
     AuditLogEntryId;
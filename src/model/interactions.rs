@@ -0,0 +1,1186 @@
+//! Models about application commands and interactions.
+
+use super::prelude::*;
+use super::utils::{default_true, deserialize_u64, serialize_u64};
+use crate::internal::prelude::*;
+use serde::de::Error as DeError;
+use std::collections::HashMap;
+
+#[cfg(feature = "model")]
+use crate::builder::{
+    CreateApplicationCommand,
+    CreateApplicationCommands,
+    CreateAutocompleteResponse,
+    CreateInteractionResponse,
+    CreateInteractionResponseFollowup,
+    CreateModal,
+    EditInteractionResponse,
+};
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "collector")]
+use crate::client::bridge::gateway::ShardMessenger;
+#[cfg(feature = "collector")]
+use crate::collector::CollectModalInteraction;
+#[cfg(feature = "model")]
+use crate::utils;
+
+/// A command of an application, invoked by users via the chat input as
+/// a slash command.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/slash-commands#applicationcommand).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommand {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the guild the command is registered in, if it is a guild
+    /// command.
+    pub guild_id: Option<GuildId>,
+    /// The name of the command, 1-32 lowercase characters.
+    pub name: String,
+    /// The description of the command, 1-100 characters.
+    pub description: String,
+    /// The parameters of the command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Whether the command is enabled by default when the application is
+    /// added to a guild.
+    #[serde(default = "default_true")]
+    pub default_permission: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl ApplicationCommand {
+    /// Creates a global command, available in all guilds of the application
+    /// and in direct messages.
+    ///
+    /// **Note**: Creating a command with the same name as an existing command
+    /// overwrites the existing command. Global commands may take up to an
+    /// hour to be available to users.
+    ///
+    /// # Examples
+    ///
+    /// Create a `ping` command:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::model::interactions::ApplicationCommand;
+    ///
+    /// let _ = ApplicationCommand::create_global_application_command(&http, 1, |c| {
+    ///     c.name("ping").description("Checks whether the bot is alive")
+    /// }).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn create_global_application_command<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let map = utils::hashmap_to_json_map(command.0);
+
+        http.as_ref().create_global_application_command(application_id.into().0, &Value::Object(map)).await
+    }
+
+    /// Overwrites all global commands of the application with the given
+    /// commands.
+    ///
+    /// Commands that are not given are deleted.
+    pub async fn set_global_application_commands<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands {
+        let mut commands = CreateApplicationCommands::default();
+        f(&mut commands);
+
+        http.as_ref().create_global_application_commands(application_id.into().0, &Value::Array(commands.0)).await
+    }
+
+    /// Edits a global command of the application.
+    pub async fn edit_global_application_command<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let map = utils::hashmap_to_json_map(command.0);
+
+        http.as_ref().edit_global_application_command(
+            application_id.into().0,
+            command_id.into().0,
+            &Value::Object(map),
+        ).await
+    }
+
+    /// Gets all global commands of the application.
+    #[inline]
+    pub async fn get_global_application_commands(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        http.as_ref().get_global_application_commands(application_id.into().0).await
+    }
+
+    /// Gets a single global command of the application.
+    #[inline]
+    pub async fn get_global_application_command(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<ApplicationCommand> {
+        http.as_ref().get_global_application_command(application_id.into().0, command_id.into().0).await
+    }
+
+    /// Deletes a global command of the application.
+    #[inline]
+    pub async fn delete_global_application_command(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<()> {
+        http.as_ref().delete_global_application_command(application_id.into().0, command_id.into().0).await
+    }
+}
+
+/// A parameter of an [`ApplicationCommand`], or one of its sub-commands or
+/// sub-command groups.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandOption {
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The name of the option, 1-32 lowercase characters.
+    pub name: String,
+    /// The description of the option, 1-100 characters.
+    pub description: String,
+    /// Whether the option must be given by the user.
+    #[serde(default)]
+    pub required: bool,
+    /// The choices the user must pick from.
+    ///
+    /// If empty, the user may enter any value.
+    #[serde(default)]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The options of a sub-command, or the sub-commands of a sub-command
+    /// group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// The channel types a [`Channel`] option is restricted to.
+    ///
+    /// If empty, any channel may be picked.
+    ///
+    /// [`Channel`]: enum.ApplicationCommandOptionType.html#variant.Channel
+    #[serde(default)]
+    pub channel_types: Vec<ChannelType>,
+    /// Whether choices are suggested via [`Autocomplete`] interactions while
+    /// the user fills in the option.
+    ///
+    /// [`Autocomplete`]: enum.InteractionType.html#variant.Autocomplete
+    #[serde(default)]
+    pub autocomplete: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A choice of an [`ApplicationCommandOption`].
+///
+/// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandOptionChoice {
+    /// The name of the choice, shown to the user.
+    pub name: String,
+    /// The value of the choice, either a string or a number depending on the
+    /// option's type.
+    pub value: Value,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The type of an [`ApplicationCommandOption`].
+///
+/// [`ApplicationCommandOption`]: struct.ApplicationCommandOption.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ApplicationCommandOptionType {
+    /// A sub-command, having its own options.
+    SubCommand = 1,
+    /// A group of sub-commands.
+    SubCommandGroup = 2,
+    /// A string.
+    String = 3,
+    /// An integer.
+    Integer = 4,
+    /// A boolean.
+    Boolean = 5,
+    /// A [`User`].
+    ///
+    /// [`User`]: ../user/struct.User.html
+    User = 6,
+    /// A [`Channel`].
+    ///
+    /// [`Channel`]: ../channel/enum.Channel.html
+    Channel = 7,
+    /// A [`Role`].
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    Role = 8,
+    /// A [`User`] or a [`Role`].
+    ///
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`User`]: ../user/struct.User.html
+    Mentionable = 9,
+    /// A floating point number.
+    Number = 10,
+}
+
+enum_number!(
+    ApplicationCommandOptionType {
+        SubCommand,
+        SubCommandGroup,
+        String,
+        Integer,
+        Boolean,
+        User,
+        Channel,
+        Role,
+        Mentionable,
+        Number,
+    }
+);
+
+impl ApplicationCommandOptionType {
+    pub fn num(self) -> u64 {
+        use self::ApplicationCommandOptionType::*;
+
+        match self {
+            SubCommand => 1,
+            SubCommandGroup => 2,
+            String => 3,
+            Integer => 4,
+            Boolean => 5,
+            User => 6,
+            Channel => 7,
+            Role => 8,
+            Mentionable => 9,
+            Number => 10,
+        }
+    }
+}
+
+/// The permissions of an [`ApplicationCommand`] in a guild, overriding its
+/// [`default_permission`] for roles, users or channels.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-guild-application-command-permissions-structure).
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+/// [`default_permission`]: struct.ApplicationCommand.html#structfield.default_permission
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandPermission {
+    /// The Id of the command, or of the application if the permissions apply
+    /// to all of its commands.
+    pub id: CommandId,
+    /// The Id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the guild the permissions apply in.
+    pub guild_id: GuildId,
+    /// The permissions of the command in the guild.
+    pub permissions: Vec<ApplicationCommandPermissionData>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// A permission of an [`ApplicationCommand`] for a role, user or channel.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandPermissionData {
+    /// The Id of the role, user or channel, depending on the [`kind`].
+    ///
+    /// [`kind`]: #structfield.kind
+    #[serde(serialize_with = "serialize_u64", deserialize_with = "deserialize_u64")]
+    pub id: u64,
+    /// The type of the target of the permission.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandPermissionType,
+    /// Whether the command is allowed for the target.
+    pub permission: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The type of the target of an [`ApplicationCommandPermissionData`].
+///
+/// [`ApplicationCommandPermissionData`]: struct.ApplicationCommandPermissionData.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ApplicationCommandPermissionType {
+    /// The permission applies to a role.
+    Role = 1,
+    /// The permission applies to a user.
+    User = 2,
+    /// The permission applies to a channel.
+    Channel = 3,
+}
+
+enum_number!(
+    ApplicationCommandPermissionType {
+        Role,
+        User,
+        Channel,
+    }
+);
+
+impl ApplicationCommandPermissionType {
+    pub fn num(self) -> u64 {
+        match self {
+            ApplicationCommandPermissionType::Role => 1,
+            ApplicationCommandPermissionType::User => 2,
+            ApplicationCommandPermissionType::Channel => 3,
+        }
+    }
+}
+
+/// An interaction, received when a user invokes an [`ApplicationCommand`] or
+/// uses a message component.
+///
+/// An interaction must be responded to within 3 seconds via
+/// [`create_interaction_response`], its token stays valid for 15 minutes
+/// for editing the response and sending followup messages.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/slash-commands#interaction).
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+/// [`create_interaction_response`]: #method.create_interaction_response
+#[derive(Clone, Debug, Serialize)]
+pub struct Interaction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction is meant for.
+    pub application_id: ApplicationId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the interaction, depending on its type.
+    pub data: Option<InteractionData>,
+    /// The Id of the guild the interaction was invoked in, if any.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the interaction was invoked in.
+    pub channel_id: Option<ChannelId>,
+    /// The member who invoked the interaction, if it was invoked in a guild.
+    pub member: Option<Member>,
+    /// The user who invoked the interaction.
+    ///
+    /// **Note**: For interactions invoked in a guild, this is the user of
+    /// the [`member`].
+    ///
+    /// [`member`]: #structfield.member
+    pub user: Option<User>,
+    /// A continuation token for responding to the interaction.
+    pub token: String,
+    /// Always `1`.
+    pub version: u8,
+    /// The message a component interaction was invoked on.
+    pub message: Option<Message>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl Interaction {
+    /// Creates the initial response to the interaction.
+    ///
+    /// Refer to Discord's [docs] for the response types and the data they
+    /// accept.
+    ///
+    /// # Examples
+    ///
+    /// Respond with a message only the invoking user can see:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::interactions::Interaction};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run(interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// interaction.create_interaction_response(&http, |r| {
+    ///     r.interaction_response_data(|d| d.content("Pong!").ephemeral(true))
+    /// }).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction has already been
+    /// responded to or its response window elapsed.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-response
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse {
+        let mut response = CreateInteractionResponse::default();
+        f(&mut response);
+        let map = utils::hashmap_to_json_map(response.0);
+
+        http.as_ref().create_interaction_response(self.id.0, &self.token, &Value::Object(map)).await
+    }
+
+    /// Responds to the interaction by opening a modal.
+    ///
+    /// The user's input is received as a [`ModalSubmit`] interaction.
+    ///
+    /// # Examples
+    ///
+    /// Ask the user for feedback:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::interactions::Interaction};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run(interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// use serenity::model::interactions::InputTextStyle;
+    ///
+    /// interaction.create_modal_response(&http, |m| {
+    ///     m.custom_id("feedback").title("Feedback").components(|c| {
+    ///         c.create_action_row(|r| {
+    ///             r.create_input_text(|i| {
+    ///                 i.custom_id("text")
+    ///                     .label("What do you think?")
+    ///                     .style(InputTextStyle::Paragraph)
+    ///             })
+    ///         })
+    ///     })
+    /// }).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ModalSubmit`]: enum.InteractionType.html#variant.ModalSubmit
+    pub async fn create_modal_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateModal) -> &mut CreateModal {
+        let mut modal = CreateModal::default();
+        f(&mut modal);
+        let map = utils::hashmap_to_json_map(modal.0);
+
+        let mut response = CreateInteractionResponse::default();
+        response.kind(InteractionResponseType::Modal);
+        response.0.insert("data", Value::Object(map));
+        let map = utils::hashmap_to_json_map(response.0);
+
+        http.as_ref().create_interaction_response(self.id.0, &self.token, &Value::Object(map)).await
+    }
+
+    /// Returns a future that will await the submission of a modal opened
+    /// via [`create_modal_response`], submitted by the user who invoked this
+    /// interaction.
+    ///
+    /// Use [`CollectModalInteraction::custom_id`] to only accept a specific
+    /// modal, and [`CollectModalInteraction::timeout`] to give up waiting
+    /// after a while.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{client::Context, model::interactions::{Interaction, InteractionData}};
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn run(ctx: Context, interaction: Interaction) {
+    /// let submission = interaction.await_submission(&ctx)
+    ///     .custom_id("feedback")
+    ///     .timeout(Duration::from_secs(300))
+    ///     .await;
+    ///
+    /// if let Some(InteractionData::ModalSubmit(data)) = submission.and_then(|s| s.data.clone()) {
+    ///     println!("Feedback: {:?}", data.value("text"));
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`create_modal_response`]: #method.create_modal_response
+    /// [`CollectModalInteraction::custom_id`]: ../../collector/modal_interaction_collector/struct.CollectModalInteraction.html#method.custom_id
+    /// [`CollectModalInteraction::timeout`]: ../../collector/modal_interaction_collector/struct.CollectModalInteraction.html#method.timeout
+    #[cfg(feature = "collector")]
+    pub fn await_submission<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> CollectModalInteraction<'a> {
+        let mut collect = CollectModalInteraction::new(shard_messenger);
+
+        if let Some(ref user) = self.user {
+            collect = collect.author_id(user.id.0);
+        }
+
+        if let Some(channel_id) = self.channel_id {
+            collect = collect.channel_id(channel_id.0);
+        }
+
+        collect
+    }
+
+    /// Responds to an [`Autocomplete`] interaction with suggested choices for
+    /// the option being filled in.
+    ///
+    /// # Examples
+    ///
+    /// Suggest fruits starting with the entered text:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::interactions::{Interaction, InteractionData}};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run(interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::default());
+    /// if let Some(InteractionData::Autocomplete(data)) = &interaction.data {
+    ///     let partial = data.partial_value().unwrap_or_default();
+    ///
+    ///     interaction.create_autocomplete_response(&http, |r| {
+    ///         for fruit in &["apple", "banana", "cherry"] {
+    ///             if fruit.starts_with(partial) {
+    ///                 r.add_string_choice(fruit, fruit);
+    ///             }
+    ///         }
+    ///
+    ///         r
+    ///     }).await?;
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Autocomplete`]: enum.InteractionType.html#variant.Autocomplete
+    pub async fn create_autocomplete_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateAutocompleteResponse) -> &mut CreateAutocompleteResponse {
+        let mut choices = CreateAutocompleteResponse::default();
+        f(&mut choices);
+        let map = utils::hashmap_to_json_map(choices.0);
+
+        let mut response = CreateInteractionResponse::default();
+        response.kind(InteractionResponseType::Autocomplete);
+        response.0.insert("data", Value::Object(map));
+        let map = utils::hashmap_to_json_map(response.0);
+
+        http.as_ref().create_interaction_response(self.id.0, &self.token, &Value::Object(map)).await
+    }
+
+    /// Gets the initial response to the interaction.
+    #[inline]
+    pub async fn get_interaction_response(&self, http: impl AsRef<Http>) -> Result<Message> {
+        http.as_ref().get_original_interaction_response(self.application_id.0, &self.token).await
+    }
+
+    /// Edits the initial response to the interaction.
+    ///
+    /// **Note**: This also fills in a deferred response.
+    pub async fn edit_original_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse {
+        let mut response = EditInteractionResponse::default();
+        f(&mut response);
+        let map = utils::hashmap_to_json_map(response.0);
+
+        http.as_ref().edit_original_interaction_response(self.application_id.0, &self.token, &Value::Object(map)).await
+    }
+
+    /// Deletes the initial response to the interaction.
+    #[inline]
+    pub async fn delete_original_interaction_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_original_interaction_response(self.application_id.0, &self.token).await
+    }
+
+    /// Sends a followup message to the interaction.
+    ///
+    /// **Note**: The interaction must have been responded to first.
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup {
+        let mut followup = CreateInteractionResponseFollowup::default();
+        f(&mut followup);
+        let map = utils::hashmap_to_json_map(followup.0);
+
+        http.as_ref().create_followup_message(self.application_id.0, &self.token, &Value::Object(map)).await
+    }
+
+    /// Edits a followup message sent to the interaction.
+    pub async fn edit_followup_message<F>(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        f: F,
+    ) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup {
+        let mut followup = CreateInteractionResponseFollowup::default();
+        f(&mut followup);
+        let map = utils::hashmap_to_json_map(followup.0);
+
+        http.as_ref().edit_followup_message(
+            self.application_id.0,
+            &self.token,
+            message_id.into().0,
+            &Value::Object(map),
+        ).await
+    }
+
+    /// Deletes a followup message sent to the interaction.
+    #[inline]
+    pub async fn delete_followup_message(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        http.as_ref().delete_followup_message(self.application_id.0, &self.token, message_id.into().0).await
+    }
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let guild_id = match map.get("guild_id") {
+            Some(v) => Option::<GuildId>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
+
+        if let Some(guild_id) = guild_id {
+            let id = Value::Number(Number::from(guild_id.0));
+
+            if let Some(member) = map.get_mut("member").and_then(|x| x.as_object_mut()) {
+                member.insert("guild_id".to_string(), id.clone());
+            }
+
+            if let Some(roles) = map.get_mut("data")
+                .and_then(|x| x.get_mut("resolved"))
+                .and_then(|x| x.get_mut("roles"))
+                .and_then(|x| x.as_object_mut()) {
+                for role in roles.values_mut() {
+                    if let Some(role) = role.as_object_mut() {
+                        role.insert("guild_id".to_string(), id.clone());
+                    }
+                }
+            }
+        }
+
+        let id = map.remove("id")
+            .ok_or_else(|| DeError::custom("expected interaction id"))
+            .and_then(InteractionId::deserialize)
+            .map_err(DeError::custom)?;
+        let application_id = map.remove("application_id")
+            .ok_or_else(|| DeError::custom("expected interaction application_id"))
+            .and_then(ApplicationId::deserialize)
+            .map_err(DeError::custom)?;
+        let kind = map.remove("type")
+            .ok_or_else(|| DeError::custom("expected interaction type"))
+            .and_then(InteractionType::deserialize)
+            .map_err(DeError::custom)?;
+        let data = match map.remove("data") {
+            Some(Value::Null) | None => None,
+            Some(v) => match kind {
+                InteractionType::ApplicationCommand => serde_json::from_value(v)
+                    .map(InteractionData::ApplicationCommand)
+                    .map(Some),
+                InteractionType::MessageComponent => serde_json::from_value(v)
+                    .map(InteractionData::MessageComponent)
+                    .map(Some),
+                InteractionType::Autocomplete => serde_json::from_value(v)
+                    .map(InteractionData::Autocomplete)
+                    .map(Some),
+                InteractionType::ModalSubmit => serde_json::from_value(v)
+                    .map(InteractionData::ModalSubmit)
+                    .map(Some),
+                InteractionType::Ping => Ok(None),
+            }.map_err(DeError::custom)?,
+        };
+        let channel_id = match map.remove("channel_id") {
+            Some(v) => serde_json::from_value::<Option<ChannelId>>(v)
+                .map_err(DeError::custom)?,
+            None => None,
+        };
+        let member = match map.remove("member") {
+            Some(v) => serde_json::from_value::<Option<Member>>(v)
+                .map_err(DeError::custom)?,
+            None => None,
+        };
+        let user = match map.remove("user") {
+            Some(Value::Null) | None => member.as_ref().map(|m| m.user.clone()),
+            Some(v) => Some(User::deserialize(v).map_err(DeError::custom)?),
+        };
+        let token = map.remove("token")
+            .ok_or_else(|| DeError::custom("expected interaction token"))
+            .and_then(String::deserialize)
+            .map_err(DeError::custom)?;
+        let version = map.remove("version")
+            .ok_or_else(|| DeError::custom("expected interaction version"))
+            .and_then(u8::deserialize)
+            .map_err(DeError::custom)?;
+        let message = match map.remove("message") {
+            Some(v) => serde_json::from_value::<Option<Message>>(v)
+                .map_err(DeError::custom)?,
+            None => None,
+        };
+
+        Ok(Self {
+            id,
+            application_id,
+            kind,
+            data,
+            guild_id,
+            channel_id,
+            member,
+            user,
+            token,
+            version,
+            message,
+            _nonexhaustive: (),
+        })
+    }
+}
+
+/// The type of an [`Interaction`].
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InteractionType {
+    /// A ping, only sent to applications receiving interactions via an
+    /// outgoing webhook.
+    Ping = 1,
+    /// An [`ApplicationCommand`] has been invoked.
+    ///
+    /// [`ApplicationCommand`]: struct.ApplicationCommand.html
+    ApplicationCommand = 2,
+    /// A message component has been used.
+    MessageComponent = 3,
+    /// An option of an [`ApplicationCommand`] is being filled in and
+    /// suggestions are requested.
+    ///
+    /// [`ApplicationCommand`]: struct.ApplicationCommand.html
+    Autocomplete = 4,
+    /// A modal has been submitted.
+    ModalSubmit = 5,
+}
+
+enum_number!(
+    InteractionType {
+        Ping,
+        ApplicationCommand,
+        MessageComponent,
+        Autocomplete,
+        ModalSubmit,
+    }
+);
+
+impl InteractionType {
+    pub fn num(self) -> u64 {
+        use self::InteractionType::*;
+
+        match self {
+            Ping => 1,
+            ApplicationCommand => 2,
+            MessageComponent => 3,
+            Autocomplete => 4,
+            ModalSubmit => 5,
+        }
+    }
+}
+
+/// The data of an [`Interaction`], depending on its [`kind`].
+///
+/// [`Interaction`]: struct.Interaction.html
+/// [`kind`]: struct.Interaction.html#structfield.kind
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum InteractionData {
+    ApplicationCommand(ApplicationCommandInteractionData),
+    MessageComponent(MessageComponentInteractionData),
+    Autocomplete(AutocompleteInteractionData),
+    ModalSubmit(ModalSubmitInteractionData),
+}
+
+/// The data of an [`ApplicationCommand`] invocation.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandInteractionData {
+    /// The Id of the invoked command.
+    pub id: CommandId,
+    /// The name of the invoked command.
+    pub name: String,
+    /// The options given by the user.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    /// The users, members, roles and channels referenced by the options.
+    #[serde(default)]
+    pub resolved: ApplicationCommandInteractionDataResolved,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// An option given to an [`ApplicationCommand`] invocation.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationCommandInteractionDataOption {
+    /// The name of the option.
+    pub name: String,
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The value of the option, absent for sub-commands and sub-command
+    /// groups.
+    ///
+    /// Users, channels, roles and mentionables are given as their Id, look
+    /// them up in the [`resolved`] data.
+    ///
+    /// [`resolved`]: struct.ApplicationCommandInteractionData.html#structfield.resolved
+    pub value: Option<Value>,
+    /// The options of a sub-command, or the sub-command of a sub-command
+    /// group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    /// Whether the option is the one being filled in, for autocomplete
+    /// interactions.
+    #[serde(default)]
+    pub focused: bool,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The entities referenced by the options of an [`ApplicationCommand`]
+/// invocation, or picked in a select menu.
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationCommandInteractionDataResolved {
+    #[serde(default)]
+    pub users: HashMap<UserId, User>,
+    /// The members of the referenced users, if invoked in a guild.
+    #[serde(default)]
+    pub members: HashMap<UserId, PartialMember>,
+    #[serde(default)]
+    pub roles: HashMap<RoleId, Role>,
+    #[serde(default)]
+    pub channels: HashMap<ChannelId, PartialChannel>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The data of an [`Autocomplete`] interaction, i.e. the partially filled in
+/// options of an [`ApplicationCommand`].
+///
+/// [`ApplicationCommand`]: struct.ApplicationCommand.html
+/// [`Autocomplete`]: enum.InteractionType.html#variant.Autocomplete
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AutocompleteInteractionData {
+    /// The Id of the command being invoked.
+    pub id: CommandId,
+    /// The name of the command being invoked.
+    pub name: String,
+    /// The options filled in so far, with the option being filled in marked
+    /// as [`focused`].
+    ///
+    /// [`focused`]: struct.ApplicationCommandInteractionDataOption.html#structfield.focused
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+impl AutocompleteInteractionData {
+    /// Returns the option being filled in, searching sub-commands and
+    /// sub-command groups.
+    pub fn focused_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        fn find(options: &[ApplicationCommandInteractionDataOption]) -> Option<&ApplicationCommandInteractionDataOption> {
+            options.iter().find_map(|option| {
+                if option.focused {
+                    Some(option)
+                } else {
+                    find(&option.options)
+                }
+            })
+        }
+
+        find(&self.options)
+    }
+
+    /// Returns what the user has entered into the option being filled in so
+    /// far.
+    ///
+    /// **Note**: The partial value is a string regardless of the option's
+    /// type, as it may not be a valid number yet.
+    pub fn partial_value(&self) -> Option<&str> {
+        self.focused_option()
+            .and_then(|option| option.value.as_ref())
+            .and_then(Value::as_str)
+    }
+}
+
+/// The data of a message component usage.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageComponentInteractionData {
+    /// The developer defined Id of the component.
+    pub custom_id: String,
+    /// The type of the component.
+    pub component_type: ComponentType,
+    /// The values picked by the user, for select menus.
+    ///
+    /// For user, role, mentionable and channel select menus, these are the
+    /// Ids of the picked entities.
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// The users, members, roles and channels picked in a user, role,
+    /// mentionable or channel select menu.
+    #[serde(default)]
+    pub resolved: ApplicationCommandInteractionDataResolved,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The data of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModalSubmitInteractionData {
+    /// The developer defined Id of the modal.
+    pub custom_id: String,
+    /// The action rows of the modal, containing the submitted values.
+    #[serde(default)]
+    pub components: Vec<ModalSubmitActionRow>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+impl ModalSubmitInteractionData {
+    /// Returns the submitted value of the input text with the given
+    /// developer defined Id, if any.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components.iter()
+            .flat_map(|row| &row.components)
+            .find(|input| input.custom_id == custom_id)
+            .map(|input| input.value.as_str())
+    }
+}
+
+/// An action row of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModalSubmitActionRow {
+    /// Always [`ActionRow`].
+    ///
+    /// [`ActionRow`]: enum.ComponentType.html#variant.ActionRow
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The input texts of the row.
+    pub components: Vec<ModalSubmitInputText>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// An input text of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModalSubmitInputText {
+    /// Always [`InputText`].
+    ///
+    /// [`InputText`]: enum.ComponentType.html#variant.InputText
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The developer defined Id of the input text.
+    pub custom_id: String,
+    /// The value entered by the user.
+    #[serde(default)]
+    pub value: String,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The type of a message component.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ComponentType {
+    /// A container for other components.
+    ActionRow = 1,
+    /// A button.
+    Button = 2,
+    /// A menu to select options from.
+    SelectMenu = 3,
+    /// A text input, only available in modals.
+    InputText = 4,
+    /// A menu to select users from.
+    UserSelect = 5,
+    /// A menu to select roles from.
+    RoleSelect = 6,
+    /// A menu to select users and roles from.
+    MentionableSelect = 7,
+    /// A menu to select channels from.
+    ChannelSelect = 8,
+}
+
+enum_number!(
+    ComponentType {
+        ActionRow,
+        Button,
+        SelectMenu,
+        InputText,
+        UserSelect,
+        RoleSelect,
+        MentionableSelect,
+        ChannelSelect,
+    }
+);
+
+impl ComponentType {
+    pub fn num(self) -> u64 {
+        use self::ComponentType::*;
+
+        match self {
+            ActionRow => 1,
+            Button => 2,
+            SelectMenu => 3,
+            InputText => 4,
+            UserSelect => 5,
+            RoleSelect => 6,
+            MentionableSelect => 7,
+            ChannelSelect => 8,
+        }
+    }
+}
+
+/// The style of a button component.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ButtonStyle {
+    /// A blurple button.
+    Primary = 1,
+    /// A grey button.
+    Secondary = 2,
+    /// A green button.
+    Success = 3,
+    /// A red button.
+    Danger = 4,
+    /// A grey button opening a URL, not sending an interaction.
+    Link = 5,
+}
+
+enum_number!(
+    ButtonStyle {
+        Primary,
+        Secondary,
+        Success,
+        Danger,
+        Link,
+    }
+);
+
+impl ButtonStyle {
+    pub fn num(self) -> u64 {
+        use self::ButtonStyle::*;
+
+        match self {
+            Primary => 1,
+            Secondary => 2,
+            Success => 3,
+            Danger => 4,
+            Link => 5,
+        }
+    }
+}
+
+/// The style of an input text component.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InputTextStyle {
+    /// A single-line input.
+    Short = 1,
+    /// A multi-line input.
+    Paragraph = 2,
+}
+
+enum_number!(
+    InputTextStyle {
+        Short,
+        Paragraph,
+    }
+);
+
+impl InputTextStyle {
+    pub fn num(self) -> u64 {
+        use self::InputTextStyle::*;
+
+        match self {
+            Short => 1,
+            Paragraph => 2,
+        }
+    }
+}
+
+/// The type of a response to an [`Interaction`].
+///
+/// [`Interaction`]: struct.Interaction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InteractionResponseType {
+    /// Acknowledges a [`Ping`].
+    ///
+    /// [`Ping`]: enum.InteractionType.html#variant.Ping
+    Pong = 1,
+    /// Responds with a message.
+    ChannelMessageWithSource = 4,
+    /// Acknowledges the interaction, showing a loading state to the user
+    /// until the response is edited in.
+    DeferredChannelMessageWithSource = 5,
+    /// Acknowledges a component interaction without showing a loading state,
+    /// the message may be edited later.
+    DeferredUpdateMessage = 6,
+    /// Edits the message the component is attached to.
+    UpdateMessage = 7,
+    /// Suggests choices for the option being filled in.
+    ///
+    /// **Note**: Only available for [`Autocomplete`] interactions.
+    ///
+    /// [`Autocomplete`]: enum.InteractionType.html#variant.Autocomplete
+    Autocomplete = 8,
+    /// Opens a modal for the user to fill in.
+    ///
+    /// **Note**: Not available for [`ModalSubmit`] interactions.
+    ///
+    /// [`ModalSubmit`]: enum.InteractionType.html#variant.ModalSubmit
+    Modal = 9,
+}
+
+enum_number!(
+    InteractionResponseType {
+        Pong,
+        ChannelMessageWithSource,
+        DeferredChannelMessageWithSource,
+        DeferredUpdateMessage,
+        UpdateMessage,
+        Autocomplete,
+        Modal,
+    }
+);
+
+impl InteractionResponseType {
+    pub fn num(self) -> u64 {
+        use self::InteractionResponseType::*;
+
+        match self {
+            Pong => 1,
+            ChannelMessageWithSource => 4,
+            DeferredChannelMessageWithSource => 5,
+            DeferredUpdateMessage => 6,
+            UpdateMessage => 7,
+            Autocomplete => 8,
+            Modal => 9,
+        }
+    }
+}
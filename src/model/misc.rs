@@ -84,6 +84,10 @@ impl Mentionable for GuildChannel {
     fn mention(&self) -> String { format!("<#{}>", self.id.0) }
 }
 
+impl Mentionable for Timestamp {
+    fn mention(&self) -> String { format!("<t:{}>", self.unix_timestamp()) }
+}
+
 #[cfg(all(feature = "model", feature = "utils"))]
 #[derive(Debug)]
 #[non_exhaustive]
@@ -194,7 +198,9 @@ pub struct EmojiIdentifier {
 impl EmojiIdentifier {
     /// Generates a URL to the emoji's image.
     #[inline]
-    pub fn url(&self) -> String { format!(cdn!("/emojis/{}.png"), self.id) }
+    pub fn url(&self) -> String {
+        CdnUrl::new(format!("/emojis/{}", self.id)).format(ImageFormat::Png).build()
+    }
 }
 
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -204,6 +210,143 @@ impl FromStr for EmojiIdentifier {
     fn from_str(s: &str) -> StdResult<Self, ()> { utils::parse_emoji(s).ok_or_else(|| ()) }
 }
 
+/// The format of an image requested from Discord's CDN.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    WebP,
+    /// Only available for animated images, whose hash starts with `a_`.
+    Gif,
+}
+
+impl ImageFormat {
+    /// The file extension of the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Gif => "gif",
+        }
+    }
+}
+
+/// A builder for the URL of an image on Discord's CDN.
+///
+/// Animated images default to a GIF and other images to WebP. Requesting a
+/// GIF of an image that is not animated falls back to WebP.
+///
+/// # Examples
+///
+/// Get a 256 pixel PNG of a user's avatar:
+///
+/// ```rust,no_run
+/// # use serenity::model::user::User;
+/// use serenity::model::misc::ImageFormat;
+///
+/// # fn run(user: User) {
+/// let url = user.avatar_cdn_url().map(|url| url.format(ImageFormat::Png).size(256).build());
+/// # }
+/// ```
+#[cfg(any(feature = "model", feature = "utils"))]
+#[derive(Clone, Debug)]
+pub struct CdnUrl {
+    path: String,
+    animated: bool,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+}
+
+#[cfg(any(feature = "model", feature = "utils"))]
+impl CdnUrl {
+    /// Creates a builder for the image at the given path of the CDN, without
+    /// its extension, such as `/emojis/{emoji_id}`.
+    pub fn new(path: impl Into<String>) -> Self {
+        CdnUrl {
+            path: path.into(),
+            animated: false,
+            format: None,
+            size: None,
+        }
+    }
+
+    /// Creates a builder for the image with the given hash, in the directory
+    /// at the given path of the CDN, such as `/avatars/{user_id}`.
+    ///
+    /// The image is animated if its hash starts with `a_`.
+    pub fn hashed(path: &str, hash: &str) -> Self {
+        CdnUrl::new(format!("{}/{}", path, hash)).animated(hash.starts_with("a_"))
+    }
+
+    /// Sets whether the image is animated, and so available as a GIF.
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+
+        self
+    }
+
+    /// Sets the format of the image.
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.format = Some(format);
+
+        self
+    }
+
+    /// Sets the size of the image in pixels.
+    ///
+    /// Discord only serves sizes which are powers of two from 16 to 4096, so
+    /// other sizes are rounded up to the next supported size.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(match size {
+            0..=16 => 16,
+            4096..=u16::MAX => 4096,
+            size => size.next_power_of_two(),
+        });
+
+        self
+    }
+
+    /// The format the image is requested in.
+    pub fn image_format(&self) -> ImageFormat {
+        match self.format {
+            Some(ImageFormat::Gif) if !self.animated => ImageFormat::WebP,
+            Some(format) => format,
+            None if self.animated => ImageFormat::Gif,
+            None => ImageFormat::WebP,
+        }
+    }
+
+    /// Builds the URL.
+    pub fn build(&self) -> String {
+        let mut url = cdn!("{}.{}", self.path, self.image_format().extension());
+
+        if let Some(size) = self.size {
+            url.push_str(&format!("?size={}", size));
+        }
+
+        url
+    }
+}
+
+/// Formats the URL of an image on Discord's CDN, defaulting to a GIF for
+/// animated images and to WebP otherwise.
+#[cfg(feature = "model")]
+pub(crate) fn image_url(path: &str, hash: &str, format: Option<ImageFormat>, size: Option<u16>) -> String {
+    let mut url = CdnUrl::hashed(path, hash);
+
+    if let Some(format) = format {
+        url = url.format(format);
+    }
+
+    if let Some(size) = size {
+        url = url.size(size);
+    }
+
+    url.build()
+}
+
 
 /// A component that was affected during a service incident.
 ///
@@ -291,6 +434,19 @@ mod test {
         assert_eq!(UserId(5).to_string(), "5");
     }
 
+    #[test]
+    fn test_cdn_url() {
+        let url = CdnUrl::hashed("/avatars/1", "a_abc");
+        assert_eq!(url.clone().build(), "https://cdn.discordapp.com/avatars/1/a_abc.gif");
+        assert_eq!(
+            url.format(ImageFormat::Png).size(100).build(),
+            "https://cdn.discordapp.com/avatars/1/a_abc.png?size=128",
+        );
+
+        let url = CdnUrl::hashed("/icons/2", "abc").format(ImageFormat::Gif).size(8192);
+        assert_eq!(url.build(), "https://cdn.discordapp.com/icons/2/abc.webp?size=4096");
+    }
+
     #[cfg(feature = "utils")]
     mod utils {
         use crate::model::prelude::*;
@@ -313,6 +469,13 @@ mod test {
                 user_limit: None,
                 nsfw: false,
                 slow_mode_rate: Some(0),
+                thread_metadata: None,
+                member: None,
+                owner_id: None,
+                message_count: None,
+                member_count: None,
+                rtc_region: None,
+                video_quality_mode: None,
                 _nonexhaustive: (),
             });
             let emoji = Emoji {
@@ -334,11 +497,16 @@ mod test {
                 name: "fake role".to_string(),
                 permissions: Permissions::empty(),
                 position: 1,
+                icon: None,
+                unicode_emoji: None,
                 _nonexhaustive: (),
             };
             let user = User {
                 id: UserId(6),
                 avatar: None,
+                banner: None,
+                accent_colour: None,
+                avatar_decoration: None,
                 bot: false,
                 discriminator: 4132,
                 name: "fake".to_string(),
@@ -352,6 +520,8 @@ mod test {
                 nick: None,
                 roles: vec![],
                 user: user.clone(),
+                communication_disabled_until: None,
+                pending: false,
                 _nonexhaustive: (),
             };
 
@@ -29,10 +29,13 @@ pub mod event;
 pub mod gateway;
 pub mod guild;
 pub mod id;
+pub mod interactions;
 pub mod invite;
 pub mod misc;
 pub mod permissions;
 pub mod prelude;
+pub mod sticker;
+pub mod timestamp;
 pub mod user;
 pub mod voice;
 pub mod webhook;
@@ -42,6 +45,7 @@ pub use serenity_voice_model as voice_gateway;
 
 pub use self::error::Error as ModelError;
 pub use self::permissions::Permissions;
+pub use self::timestamp::Timestamp;
 
 use crate::internal::prelude::*;
 use self::utils::*;
@@ -48,62 +48,9 @@ use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 use super::utils::U64Visitor;
 use bitflags::__impl_bitflags;
+use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-
-/// This macro generates the [`Permissions::get_permission_names`] method.
-///
-/// It is invoked by passing the names of all methods used to check for
-/// permissions along with their names displayed inside Discord.
-///
-/// ## Examples
-///
-/// Using this macro
-///
-/// ```rust,no_run
-/// generate_get_permission_names! {
-///     add_reactions: "Add Reactions",
-///     administrator: "Administrator"
-/// };
-/// ```
-///
-/// Generates this implementation:
-///
-/// ```
-/// impl Permissions {
-///     fn get_permission_names(self) -> Vec<&'static str> {
-///         let mut names = Vec::new();
-///
-///         if self.add_reactions() {
-///             names.push("Add Reactions");
-///         }
-///         if self.administrator() {
-///             names.push("Administrator");
-///         }
-///
-///         names
-///     }
-/// }
-/// ```
-///
-/// [`Permissions::get_permission_names`]: struct.Permissions.html#method.get_permission_names
-macro_rules! generate_get_permission_names {
-    {$ ($perm:ident: $name:expr),*} => {
-        impl Permissions {
-            /// Returns a list of names of all contained permissions.
-            pub fn get_permission_names(self) -> Vec<&'static str> {
-                let mut names = Vec::new();
-
-                $(
-                    if self.$perm() {
-                        names.push($name);
-                    }
-                )*
-
-                names
-            }
-        }
-    }
-}
+use std::str::FromStr;
 
 /// Returns a set of permissions with the original @everyone permissions set
 /// to true.
@@ -323,43 +270,167 @@ __impl_bitflags! {
         ///
         /// [`Integration`]: ../guild/struct.Integration.html
         MANAGE_EMOJIS = 0b0100_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows timing out [member]s, disabling their ability to send
+        /// messages, react or join voice channels.
+        ///
+        /// [member]: ../guild/struct.Member.html
+        MODERATE_MEMBERS = 0b0000_0001_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+    }
+}
+
+/// The names of all permissions as displayed inside Discord, in the order of
+/// their bits.
+const PERMISSION_NAMES: &[(Permissions, &str)] = &[
+    (Permissions::CREATE_INVITE, "Create Invite"),
+    (Permissions::KICK_MEMBERS, "Kick Members"),
+    (Permissions::BAN_MEMBERS, "Ban Members"),
+    (Permissions::ADMINISTRATOR, "Administrator"),
+    (Permissions::MANAGE_CHANNELS, "Manage Channels"),
+    (Permissions::MANAGE_GUILD, "Manage Guild"),
+    (Permissions::ADD_REACTIONS, "Add Reactions"),
+    (Permissions::VIEW_AUDIT_LOG, "View Audit Log"),
+    (Permissions::PRIORITY_SPEAKER, "Priority Speaker"),
+    (Permissions::STREAM, "Stream"),
+    (Permissions::READ_MESSAGES, "Read Messages"),
+    (Permissions::SEND_MESSAGES, "Send Messages"),
+    (Permissions::SEND_TTS_MESSAGES, "Send TTS Messages"),
+    (Permissions::MANAGE_MESSAGES, "Manage Messages"),
+    (Permissions::EMBED_LINKS, "Embed Links"),
+    (Permissions::ATTACH_FILES, "Attach Files"),
+    (Permissions::READ_MESSAGE_HISTORY, "Read Message History"),
+    (Permissions::MENTION_EVERYONE, "Mention Everyone"),
+    (Permissions::USE_EXTERNAL_EMOJIS, "Use External Emojis"),
+    (Permissions::CONNECT, "Connect"),
+    (Permissions::SPEAK, "Speak"),
+    (Permissions::MUTE_MEMBERS, "Mute Members"),
+    (Permissions::DEAFEN_MEMBERS, "Deafen Members"),
+    (Permissions::MOVE_MEMBERS, "Move Members"),
+    (Permissions::USE_VAD, "Use Voice Activity"),
+    (Permissions::CHANGE_NICKNAME, "Change Nickname"),
+    (Permissions::MANAGE_NICKNAMES, "Manage Nicknames"),
+    (Permissions::MANAGE_ROLES, "Manage Roles"),
+    (Permissions::MANAGE_WEBHOOKS, "Manage Webhooks"),
+    (Permissions::MANAGE_EMOJIS, "Manage Emojis"),
+    (Permissions::MODERATE_MEMBERS, "Moderate Members"),
+];
+
+impl Permissions {
+    /// Returns the name of a single permission as displayed inside Discord,
+    /// or `None` if `self` is not exactly one permission.
+    pub fn name(self) -> Option<&'static str> {
+        PERMISSION_NAMES
+            .iter()
+            .find(|&&(permission, _)| permission == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Returns the permission with the given name, ignoring case.
+    ///
+    /// Refer to [`name`] for the names of permissions.
+    ///
+    /// [`name`]: #method.name
+    pub fn from_name(name: &str) -> Option<Self> {
+        PERMISSION_NAMES
+            .iter()
+            .find(|(_, other)| other.eq_ignore_ascii_case(name))
+            .map(|&(permission, _)| permission)
+    }
+
+    /// Returns an iterator over the names of all contained permissions, in
+    /// the order of their bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::permissions::Permissions;
+    ///
+    /// let permissions = Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS;
+    /// let names = permissions.iter_names().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names, vec!["Kick Members", "Send Messages"]);
+    /// ```
+    pub fn iter_names(self) -> impl Iterator<Item = &'static str> {
+        PERMISSION_NAMES
+            .iter()
+            .filter(move |&&(permission, _)| self.contains(permission))
+            .map(|&(_, name)| name)
+    }
+
+    /// Returns a list of names of all contained permissions.
+    ///
+    /// Refer to [`iter_names`] for more information.
+    ///
+    /// [`iter_names`]: #method.iter_names
+    #[inline]
+    pub fn get_permission_names(self) -> Vec<&'static str> {
+        self.iter_names().collect()
+    }
+
+    /// Compares `self` to a newer set of permissions, returning the
+    /// permissions which were added and removed, such as between the old and
+    /// new permissions of a role in an audit log.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::permissions::Permissions;
+    ///
+    /// let old = Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS;
+    /// let new = Permissions::SEND_MESSAGES | Permissions::MANAGE_MESSAGES;
+    /// let diff = old.diff(new);
+    ///
+    /// assert_eq!(diff.added, Permissions::MANAGE_MESSAGES);
+    /// assert_eq!(diff.removed, Permissions::EMBED_LINKS);
+    /// ```
+    pub fn diff(self, new: Permissions) -> PermissionsDiff {
+        PermissionsDiff {
+            added: new - self,
+            removed: self - new,
+        }
+    }
+}
+
+/// The permissions added and removed between two sets of permissions.
+///
+/// Refer to [`Permissions::diff`] for more information.
+///
+/// [`Permissions::diff`]: struct.Permissions.html#method.diff
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct PermissionsDiff {
+    /// The permissions only contained in the newer set.
+    pub added: Permissions,
+    /// The permissions only contained in the older set.
+    pub removed: Permissions,
+}
+
+impl PermissionsDiff {
+    /// Whether no permissions were added or removed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
     }
 }
 
-generate_get_permission_names! {
-    add_reactions: "Add Reactions",
-    administrator: "Administrator",
-    attach_files: "Attach Files",
-    ban_members: "Ban Members",
-    change_nickname: "Change Nickname",
-    connect: "Connect",
-    create_invite: "Create Invite",
-    deafen_members: "Deafen Members",
-    embed_links: "Embed Links",
-    external_emojis: "Use External Emojis",
-    kick_members: "Kick Members",
-    manage_channels: "Manage Channels",
-    manage_emojis: "Manage Emojis",
-    manage_guild: "Manage Guilds",
-    manage_messages: "Manage Messages",
-    manage_nicknames: "Manage Nicknames",
-    manage_roles: "Manage Roles",
-    manage_webhooks: "Manage Webhooks",
-    mention_everyone: "Mention Everyone",
-    move_members: "Move Members",
-    mute_members: "Mute Members",
-    priority_speaker: "Priority Speaker",
-    read_message_history: "Read Message History",
-    read_messages: "Read Messages",
-    send_messages: "Send Messages",
-    send_tts_messages: "Send TTS Messages",
-    speak: "Speak",
-    stream: "Stream",
-    use_external_emojis: "Use External Emojis",
-    use_vad: "Use Voice Activity",
-    view_audit_log: "View Audit Log"
+/// An error returned when parsing [`Permissions`] from their names fails.
+///
+/// [`Permissions`]: struct.Permissions.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PermissionsParseError {
+    /// A name did not match any permission.
+    UnknownPermission(String),
+}
+
+impl Display for PermissionsParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PermissionsParseError::UnknownPermission(name) => write!(f, "unknown permission: {}", name),
+        }
+    }
 }
 
+impl StdError for PermissionsParseError {}
+
 #[cfg(feature = "model")]
 impl Permissions {
     /// Shorthand for checking that the set of permissions contains the
@@ -494,6 +565,12 @@ impl Permissions {
     /// [Mention Everyone]: #associatedconstant.MENTION_EVERYONE
     pub fn mention_everyone(self) -> bool { self.contains(Self::MENTION_EVERYONE) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: #associatedconstant.MODERATE_MEMBERS
+    pub fn moderate_members(self) -> bool { self.contains(Self::MODERATE_MEMBERS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Move Members] permission.
     ///
@@ -555,8 +632,9 @@ impl Default for Permissions {
 
 impl<'de> Deserialize<'de> for Permissions {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Newer API versions send permissions as a string.
         Ok(Permissions::from_bits_truncate(
-            deserializer.deserialize_u64(U64Visitor)?,
+            deserializer.deserialize_any(U64Visitor)?,
         ))
     }
 }
@@ -568,6 +646,8 @@ impl Serialize for Permissions {
     }
 }
 
+/// Displays the names of the permissions, e.g. `Kick Members, Send Messages
+/// and Embed Links`.
 impl Display for Permissions {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let names = self.get_permission_names();
@@ -588,3 +668,53 @@ impl Display for Permissions {
         Ok(())
     }
 }
+
+impl FromStr for Permissions {
+    type Err = PermissionsParseError;
+
+    /// Parses the names of permissions separated by commas or `and`, as
+    /// displayed by the [`Display`] implementation, ignoring case.
+    ///
+    /// [`Display`]: #impl-Display
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut permissions = Permissions::empty();
+
+        for name in s.split(',').flat_map(|part| part.split(" and ")) {
+            let name = name.trim();
+
+            if name.is_empty() {
+                continue;
+            }
+
+            permissions |= Permissions::from_name(name)
+                .ok_or_else(|| PermissionsParseError::UnknownPermission(name.to_string()))?;
+        }
+
+        Ok(permissions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Permissions, PermissionsParseError};
+
+    #[test]
+    fn names_round_trip() {
+        for bit in 0..64 {
+            if let Some(permission) = Permissions::from_bits(1 << bit) {
+                assert_eq!(Permissions::from_name(permission.name().unwrap()), Some(permission));
+            }
+        }
+
+        let permissions = Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES | Permissions::EMBED_LINKS;
+        assert_eq!(permissions.to_string(), "Kick Members, Manage Messages and Embed Links");
+        assert_eq!(permissions.to_string().parse(), Ok(permissions));
+        assert_eq!(Permissions::all().to_string().parse(), Ok(Permissions::all()));
+        assert_eq!("".parse(), Ok(Permissions::empty()));
+        assert_eq!("manage messages".parse(), Ok(Permissions::MANAGE_MESSAGES));
+        assert_eq!(
+            "Speak, Shout".parse::<Permissions>(),
+            Err(PermissionsParseError::UnknownPermission("Shout".to_string())),
+        );
+    }
+}
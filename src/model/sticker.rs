@@ -0,0 +1,259 @@
+//! Models relating to stickers, which can be sent in messages.
+
+use crate::model::prelude::*;
+use super::utils::default_true;
+
+#[cfg(feature = "model")]
+use crate::builder::EditSticker;
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
+
+/// A sticker, either a standard one of a [`StickerPack`] or a custom one
+/// uploaded to a guild.
+///
+/// [`StickerPack`]: struct.StickerPack.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Sticker {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The Id of the pack of a standard sticker.
+    pub pack_id: Option<StickerPackId>,
+    /// The name of the sticker.
+    pub name: String,
+    /// The description of the sticker.
+    pub description: Option<String>,
+    /// The autocomplete suggestion tags of the sticker, separated by commas.
+    ///
+    /// For guild stickers, this is the name of the unicode emoji
+    /// representing the sticker.
+    pub tags: String,
+    /// The type of the sticker.
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    /// The format of the sticker's image.
+    pub format_type: StickerFormatType,
+    /// Whether a guild sticker can be used, may be `false` due to loss of
+    /// boosts.
+    #[serde(default = "default_true")]
+    pub available: bool,
+    /// The Id of the guild a guild sticker was uploaded to.
+    pub guild_id: Option<GuildId>,
+    /// The user who uploaded a guild sticker.
+    ///
+    /// **Note**: This is only present when the current user has the
+    /// [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub user: Option<User>,
+    /// The position of a standard sticker in its pack.
+    pub sort_value: Option<u64>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl Sticker {
+    /// Deletes a guild sticker.
+    ///
+    /// **Note**: Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// [`ModelError::ItemMissing`] is returned if [`guild_id`] is `None`,
+    /// i.e. the sticker is not a guild sticker.
+    ///
+    /// [`ModelError::ItemMissing`]: ../error/enum.Error.html#variant.ItemMissing
+    /// [`guild_id`]: #structfield.guild_id
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        match self.guild_id {
+            Some(guild_id) => guild_id.delete_sticker(http, self.id).await,
+            None => Err(Error::Model(ModelError::ItemMissing)),
+        }
+    }
+
+    /// Edits a guild sticker, refer to [`GuildId::edit_sticker`] for more
+    /// information.
+    ///
+    /// # Errors
+    ///
+    /// [`ModelError::ItemMissing`] is returned if [`guild_id`] is `None`,
+    /// i.e. the sticker is not a guild sticker.
+    ///
+    /// [`GuildId::edit_sticker`]: ../id/struct.GuildId.html#method.edit_sticker
+    /// [`ModelError::ItemMissing`]: ../error/enum.Error.html#variant.ItemMissing
+    /// [`guild_id`]: #structfield.guild_id
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditSticker) -> &mut EditSticker {
+        match self.guild_id {
+            Some(guild_id) => {
+                *self = guild_id.edit_sticker(http, self.id, f).await?;
+
+                Ok(())
+            },
+            None => Err(Error::Model(ModelError::ItemMissing)),
+        }
+    }
+
+    /// Retrieves the URL to the sticker's image.
+    ///
+    /// **Note**: [`Lottie`] stickers are JSON animations rather than images.
+    ///
+    /// [`Lottie`]: enum.StickerFormatType.html#variant.Lottie
+    #[inline]
+    pub fn image_url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+/// The smallest amount of data required to render a [`Sticker`], as sent in
+/// [`Message`]s.
+///
+/// [`Message`]: ../channel/struct.Message.html
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StickerItem {
+    /// The Id of the sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
+    /// The format of the sticker's image.
+    pub format_type: StickerFormatType,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+#[cfg(feature = "model")]
+impl StickerItem {
+    /// Requests the full [`Sticker`] over the REST API.
+    ///
+    /// [`Sticker`]: struct.Sticker.html
+    #[inline]
+    pub async fn to_sticker(&self, http: impl AsRef<Http>) -> Result<Sticker> {
+        self.id.to_sticker(http).await
+    }
+
+    /// Retrieves the URL to the sticker's image.
+    ///
+    /// **Note**: [`Lottie`] stickers are JSON animations rather than images.
+    ///
+    /// [`Lottie`]: enum.StickerFormatType.html#variant.Lottie
+    #[inline]
+    pub fn image_url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+#[cfg(feature = "model")]
+impl StickerId {
+    /// Requests the [`Sticker`] over the REST API.
+    ///
+    /// [`Sticker`]: struct.Sticker.html
+    #[inline]
+    pub async fn to_sticker(self, http: impl AsRef<Http>) -> Result<Sticker> {
+        http.as_ref().get_sticker(self.0).await
+    }
+}
+
+#[cfg(feature = "model")]
+fn sticker_url(id: StickerId, format_type: StickerFormatType) -> String {
+    let url = CdnUrl::new(format!("/stickers/{}", id));
+
+    match format_type {
+        // Lottie stickers are not images, so are always served as JSON.
+        StickerFormatType::Lottie => cdn!("/stickers/{}.json", id),
+        StickerFormatType::Gif => url.animated(true).build(),
+        _ => url.format(ImageFormat::Png).build(),
+    }
+}
+
+/// A pack of standard [`Sticker`]s available to Nitro subscribers.
+///
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StickerPack {
+    /// The Id of the pack.
+    pub id: StickerPackId,
+    /// The stickers in the pack.
+    pub stickers: Vec<Sticker>,
+    /// The name of the pack.
+    pub name: String,
+    /// The Id of the pack's SKU.
+    pub sku_id: u64,
+    /// The Id of the sticker shown as the pack's icon.
+    pub cover_sticker_id: Option<StickerId>,
+    /// The description of the pack.
+    pub description: String,
+    /// The Id of the pack's banner image.
+    pub banner_asset_id: Option<u64>,
+    #[serde(skip)]
+    pub(crate) _nonexhaustive: (),
+}
+
+/// The type of a [`Sticker`].
+///
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum StickerType {
+    /// An official sticker of a [`StickerPack`].
+    ///
+    /// [`StickerPack`]: struct.StickerPack.html
+    Standard = 1,
+    /// A sticker uploaded to a guild.
+    Guild = 2,
+}
+
+enum_number!(
+    StickerType {
+        Standard,
+        Guild,
+    }
+);
+
+impl StickerType {
+    pub fn num(self) -> u64 {
+        match self {
+            StickerType::Standard => 1,
+            StickerType::Guild => 2,
+        }
+    }
+}
+
+/// The format of a [`Sticker`]'s image.
+///
+/// [`Sticker`]: struct.Sticker.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum StickerFormatType {
+    /// A PNG image.
+    Png = 1,
+    /// An animated PNG image.
+    Apng = 2,
+    /// A Lottie JSON animation.
+    Lottie = 3,
+    /// An animated GIF image.
+    Gif = 4,
+}
+
+enum_number!(
+    StickerFormatType {
+        Png,
+        Apng,
+        Lottie,
+        Gif,
+    }
+);
+
+impl StickerFormatType {
+    pub fn num(self) -> u64 {
+        match self {
+            StickerFormatType::Png => 1,
+            StickerFormatType::Apng => 2,
+            StickerFormatType::Lottie => 3,
+            StickerFormatType::Gif => 4,
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Types for points in time sent by Discord as ISO8601 timestamps, and for
+//! displaying them in messages.
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display},
+    ops::Deref,
+    time::SystemTime,
+};
+
+/// A point in time, such as when a [`Message`] was sent or a [`Member`] joined
+/// a guild.
+///
+/// This dereferences to a [`DateTime<Utc>`], and is (de)serialized as an
+/// ISO8601 timestamp.
+///
+/// # Examples
+///
+/// Display the time a message was sent in the local time of every reader:
+///
+/// ```rust
+/// use serenity::model::timestamp::{Timestamp, TimestampStyle};
+///
+/// let timestamp = Timestamp::from_unix_timestamp(1618953630).unwrap();
+///
+/// assert_eq!(timestamp.to_string(), "2021-04-20T21:20:30+00:00");
+/// assert_eq!(timestamp.format_markdown(TimestampStyle::RelativeTime), "<t:1618953630:R>");
+/// ```
+///
+/// [`DateTime<Utc>`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
+/// [`Member`]: ../guild/struct.Member.html
+/// [`Message`]: ../channel/struct.Message.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct Timestamp(DateTime<Utc>);
+
+impl Timestamp {
+    /// Creates a timestamp of the current point in time.
+    #[inline]
+    pub fn now() -> Self {
+        Timestamp(Utc::now())
+    }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch,
+    /// returning `None` if it is out of range.
+    pub fn from_unix_timestamp(seconds: i64) -> Option<Self> {
+        Utc.timestamp_opt(seconds, 0).single().map(Timestamp)
+    }
+
+    /// Returns the number of seconds since the Unix epoch.
+    #[inline]
+    pub fn unix_timestamp(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    /// Formats the timestamp as markdown, which Discord displays in the
+    /// local time and the language of every reader, e.g. `<t:1618953630:R>`.
+    pub fn format_markdown(&self, style: TimestampStyle) -> String {
+        format!("<t:{}:{}>", self.unix_timestamp(), style)
+    }
+}
+
+impl Deref for Timestamp {
+    type Target = DateTime<Utc>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_rfc3339())
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Timestamp(datetime)
+    }
+}
+
+impl<'a> From<&'a DateTime<Utc>> for Timestamp {
+    fn from(datetime: &'a DateTime<Utc>) -> Self {
+        Timestamp(*datetime)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        Timestamp(time.into())
+    }
+}
+
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0.into()
+    }
+}
+
+impl PartialEq<DateTime<Utc>> for Timestamp {
+    fn eq(&self, other: &DateTime<Utc>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<DateTime<Utc>> for Timestamp {
+    fn partial_cmp(&self, other: &DateTime<Utc>) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/// How a [`Timestamp`] is displayed in a message.
+///
+/// Refer to [`Timestamp::format_markdown`] for more information.
+///
+/// [`Timestamp`]: struct.Timestamp.html
+/// [`Timestamp::format_markdown`]: struct.Timestamp.html#method.format_markdown
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TimestampStyle {
+    /// A short time, e.g. `16:20`.
+    ShortTime,
+    /// A long time, e.g. `16:20:30`.
+    LongTime,
+    /// A short date, e.g. `20/04/2021`.
+    ShortDate,
+    /// A long date, e.g. `20 April 2021`.
+    LongDate,
+    /// A short date and time, e.g. `20 April 2021 16:20`.
+    ///
+    /// This is the style used when none is given.
+    ShortDateTime,
+    /// A long date and time, e.g. `Tuesday, 20 April 2021 16:20`.
+    LongDateTime,
+    /// A time relative to now, e.g. `2 months ago`.
+    RelativeTime,
+}
+
+impl TimestampStyle {
+    /// The character identifying the style in markdown.
+    pub fn as_char(self) -> char {
+        match self {
+            TimestampStyle::ShortTime => 't',
+            TimestampStyle::LongTime => 'T',
+            TimestampStyle::ShortDate => 'd',
+            TimestampStyle::LongDate => 'D',
+            TimestampStyle::ShortDateTime => 'f',
+            TimestampStyle::LongDateTime => 'F',
+            TimestampStyle::RelativeTime => 'R',
+        }
+    }
+
+    /// Returns the style identified by the character, if any.
+    pub fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            't' => TimestampStyle::ShortTime,
+            'T' => TimestampStyle::LongTime,
+            'd' => TimestampStyle::ShortDate,
+            'D' => TimestampStyle::LongDate,
+            'f' => TimestampStyle::ShortDateTime,
+            'F' => TimestampStyle::LongDateTime,
+            'R' => TimestampStyle::RelativeTime,
+            _ => return None,
+        })
+    }
+}
+
+impl Default for TimestampStyle {
+    fn default() -> Self {
+        TimestampStyle::ShortDateTime
+    }
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Timestamp, TimestampStyle};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn converts_and_formats() {
+        let time = UNIX_EPOCH + Duration::from_secs(1618953630);
+        let timestamp = Timestamp::from(time);
+
+        assert_eq!(timestamp.unix_timestamp(), 1618953630);
+        assert_eq!(SystemTime::from(timestamp), time);
+        assert_eq!(timestamp.format_markdown(TimestampStyle::LongDate), "<t:1618953630:D>");
+
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(json, r#""2021-04-20T21:20:30Z""#);
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+        assert_eq!(serde_json::from_str::<Timestamp>(r#""2021-04-20T23:20:30+02:00""#).unwrap(), timestamp);
+    }
+}
@@ -40,6 +40,18 @@ pub struct CurrentUser {
     pub mfa_enabled: bool,
     #[serde(rename = "username")] pub name: String,
     pub verified: Option<bool>,
+    /// Optional banner hash.
+    pub banner: Option<String>,
+    /// The colour of the banner, used if no banner is set.
+    #[cfg(feature = "utils")]
+    #[serde(rename = "accent_color")]
+    pub accent_colour: Option<Colour>,
+    /// The colour of the banner, used if no banner is set.
+    #[cfg(not(feature = "utils"))]
+    #[serde(rename = "accent_color")]
+    pub accent_colour: Option<u32>,
+    /// Optional avatar decoration hash.
+    pub avatar_decoration: Option<String>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -74,6 +86,30 @@ impl CurrentUser {
     #[inline]
     pub fn avatar_url(&self) -> Option<String> { avatar_url(self.id, self.avatar.as_ref()) }
 
+    /// Returns a builder for the URL of the user's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    #[inline]
+    pub fn avatar_cdn_url(&self) -> Option<CdnUrl> { avatar_cdn_url(self.id, self.avatar.as_ref()) }
+
+    /// Returns the formatted URL of the user's banner, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the user has a GIF banner.
+    #[inline]
+    pub fn banner_url(&self) -> Option<String> { banner_url(self.id, self.banner.as_ref()) }
+
+    /// Returns a builder for the URL of the user's banner, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    #[inline]
+    pub fn banner_cdn_url(&self) -> Option<CdnUrl> { banner_cdn_url(self.id, self.banner.as_ref()) }
+
     /// Returns the formatted URL to the user's default avatar URL.
     ///
     /// This will produce a PNG URL.
@@ -88,7 +124,7 @@ impl CurrentUser {
     ///
     /// # Examples
     ///
-    /// Change the avatar:
+    /// Change the avatar and the banner:
     ///
     /// ```rust,no_run
     /// # use serenity::http::Http;
@@ -97,17 +133,26 @@ impl CurrentUser {
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// #     let http = Http::default();
     /// #     let mut user = CurrentUser::default();
-    /// let avatar = serenity::utils::read_image("./avatar.png")?;
-    ///
-    /// user.edit(&http, |p| p.avatar(Some(&avatar))).await;
+    /// user.edit(&http, |p| {
+    ///     p.avatar("./avatar.png")
+    ///         .banner("https://example.com/banner.gif")
+    /// }).await?;
     /// #     Ok(())
     /// # }
     /// ```
     ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if an image could not be read from a file,
+    /// or an [`Error::Http`] if it could not be downloaded.
+    ///
     /// [`EditProfile`]: ../../builder/struct.EditProfile.html
-    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
-    where F: FnOnce(&mut EditProfile) -> &mut EditProfile
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    /// [`Error::Io`]: ../../enum.Error.html#variant.Io
+    pub async fn edit<'a, F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where for<'b> F: FnOnce(&'b mut EditProfile<'a>) -> &'b mut EditProfile<'a>
     {
+        let http = http.as_ref();
         let mut map = HashMap::new();
         map.insert("username", Value::String(self.name.clone()));
 
@@ -115,11 +160,18 @@ impl CurrentUser {
             map.insert("email", Value::String(email.clone()));
         }
 
-        let mut edit_profile = EditProfile(map);
+        let mut edit_profile = EditProfile(map, HashMap::new());
         f(&mut edit_profile);
-        let map = utils::hashmap_to_json_map(edit_profile.0);
+        let EditProfile(mut map, images) = edit_profile;
+
+        for (key, image) in images {
+            let data = http.attachment_data(image).await?;
+            map.insert(key, Value::String(utils::encode_image(&data)));
+        }
 
-        *self = http.as_ref().edit_profile(&map).await?;
+        let map = utils::hashmap_to_json_map(map);
+
+        *self = http.edit_profile(&map).await?;
 
         Ok(())
     }
@@ -398,6 +450,33 @@ pub struct User {
     /// change if the username+discriminator pair becomes non-unique.
     #[serde(rename = "username")]
     pub name: String,
+    /// Optional banner hash.
+    ///
+    /// **Note**: This is only present when the user was retrieved via
+    /// [`Http::get_user`].
+    ///
+    /// [`Http::get_user`]: ../../http/client/struct.Http.html#method.get_user
+    pub banner: Option<String>,
+    /// The colour of the user's banner, used if no banner is set.
+    ///
+    /// **Note**: This is only present when the user was retrieved via
+    /// [`Http::get_user`].
+    ///
+    /// [`Http::get_user`]: ../../http/client/struct.Http.html#method.get_user
+    #[cfg(feature = "utils")]
+    #[serde(rename = "accent_color")]
+    pub accent_colour: Option<Colour>,
+    /// The colour of the user's banner, used if no banner is set.
+    ///
+    /// **Note**: This is only present when the user was retrieved via
+    /// [`Http::get_user`].
+    ///
+    /// [`Http::get_user`]: ../../http/client/struct.Http.html#method.get_user
+    #[cfg(not(feature = "utils"))]
+    #[serde(rename = "accent_color")]
+    pub accent_colour: Option<u32>,
+    /// Optional avatar decoration hash.
+    pub avatar_decoration: Option<String>,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -409,6 +488,7 @@ impl Default for User {
     /// - **bot** to `true`.
     /// - **discriminator** to `1432`.
     /// - **name** to `"test"`.
+    /// - **banner**, **accent_colour** and **avatar_decoration** to `None`.
     fn default() -> Self {
         User {
             id: UserId(210),
@@ -416,6 +496,9 @@ impl Default for User {
             bot: true,
             discriminator: 1432,
             name: "test".to_string(),
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
             _nonexhaustive: (),
         }
     }
@@ -447,6 +530,37 @@ impl User {
     #[inline]
     pub fn avatar_url(&self) -> Option<String> { avatar_url(self.id, self.avatar.as_ref()) }
 
+    /// Returns a builder for the URL of the user's icon, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    #[inline]
+    pub fn avatar_cdn_url(&self) -> Option<CdnUrl> { avatar_cdn_url(self.id, self.avatar.as_ref()) }
+
+    /// Returns the formatted URL of the user's banner, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the user has a GIF banner.
+    ///
+    /// **Note**: The banner is only present when the user was retrieved via
+    /// [`Http::get_user`], so a cached user may need to be [`refresh`]ed
+    /// first.
+    ///
+    /// [`Http::get_user`]: ../../http/client/struct.Http.html#method.get_user
+    /// [`refresh`]: #method.refresh
+    #[inline]
+    pub fn banner_url(&self) -> Option<String> { banner_url(self.id, self.banner.as_ref()) }
+
+    /// Returns a builder for the URL of the user's banner, if one exists, to
+    /// request it in another size or format.
+    ///
+    /// Refer to [`CdnUrl`] for more information.
+    ///
+    /// [`CdnUrl`]: ../misc/struct.CdnUrl.html
+    #[inline]
+    pub fn banner_cdn_url(&self) -> Option<CdnUrl> { banner_cdn_url(self.id, self.banner.as_ref()) }
+
     /// Creates a direct message channel between the [current user] and the
     /// user. This can also retrieve the channel if one already exists.
     ///
@@ -473,6 +587,10 @@ impl User {
     /// Sends a message to a user through a direct message channel. This is a
     /// channel that can only be accessed by you and the recipient.
     ///
+    /// If the cache is available and already holds the channel, it is reused
+    /// instead of requesting it again. Refer to [`UserId::direct_message`]
+    /// for more information.
+    ///
     /// # Examples
     ///
     /// When a user sends a message with a content of `"~help"`, DM the author a
@@ -540,12 +658,14 @@ impl User {
     /// [`ModelError::MessagingBot`]: ../error/enum.Error.html#variant.MessagingBot
     /// [`PrivateChannel`]: struct.PrivateChannel.html
     /// [`User::dm`]: struct.User.html#method.dm
+    /// [`UserId::direct_message`]: ../id/struct.UserId.html#method.direct_message
     pub async fn direct_message<F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Message>
         where for <'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a> {
-        self.create_dm_channel(&cache_http)
-            .await?
-        .send_message(&cache_http.http(), f)
-            .await
+        if self.bot {
+            return Err(Error::Model(ModelError::MessagingBot));
+        }
+
+        self.id.direct_message(cache_http, f).await
     }
 
     /// This is an alias of [direct_message].
@@ -774,15 +894,17 @@ impl UserId {
     /// Creates a direct message channel between the [current user] and the
     /// user. This can also retrieve the channel if one already exists.
     ///
+    /// If the cache is available, the channel is looked up by its recipient
+    /// first, and a newly created channel is cached so that later calls do
+    /// not need to request it again.
+    ///
     /// [current user]: ../user/struct.CurrentUser.html
     pub async fn create_dm_channel(self, cache_http: impl CacheHttp) -> Result<PrivateChannel> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                for channel in cache.private_channels().await.values() {
-                    if channel.recipient.id == self {
-                        return Ok(channel.clone());
-                    }
+                if let Some(channel) = cache.private_channel_by_recipient(self).await {
+                    return Ok(channel);
                 }
             }
         }
@@ -791,7 +913,47 @@ impl UserId {
             "recipient_id": self.0,
         });
 
-        cache_http.http().create_private_channel(&map).await
+        let channel = cache_http.http().create_private_channel(&map).await?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cache.insert_private_channel(channel.clone()).await;
+            }
+        }
+
+        Ok(channel)
+    }
+
+    /// Sends a message to the user through a direct message channel,
+    /// returning the sent [`Message`].
+    ///
+    /// The channel is retrieved via [`create_dm_channel`], which reuses a
+    /// cached channel with the user if there is one, sparing a request to
+    /// create it for every message.
+    ///
+    /// Refer to [`User::direct_message`] for an example.
+    ///
+    /// **Note**: Unlike [`User::direct_message`], this does not check whether
+    /// the user is a bot, as the Id alone does not tell.
+    ///
+    /// [`Message`]: ../channel/struct.Message.html
+    /// [`User::direct_message`]: ../user/struct.User.html#method.direct_message
+    /// [`create_dm_channel`]: #method.create_dm_channel
+    pub async fn direct_message<F>(self, cache_http: impl CacheHttp, f: F) -> Result<Message>
+        where for <'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a> {
+        let channel = self.create_dm_channel(&cache_http).await?;
+
+        channel.id.send_message(cache_http.http(), f).await
+    }
+
+    /// This is an alias of [`direct_message`].
+    ///
+    /// [`direct_message`]: #method.direct_message
+    #[inline]
+    pub async fn dm<F>(self, cache_http: impl CacheHttp, f: F) -> Result<Message>
+        where for <'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a> {
+        self.direct_message(cache_http, f).await
     }
 
     /// Attempts to find a [`User`] by its Id in the cache.
@@ -833,6 +995,9 @@ impl From<CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name,
+            banner: user.banner,
+            accent_colour: user.accent_colour,
+            avatar_decoration: user.avatar_decoration,
             _nonexhaustive: (),
         }
     }
@@ -846,6 +1011,9 @@ impl<'a> From<&'a CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name.clone(),
+            banner: user.banner.clone(),
+            accent_colour: user.accent_colour,
+            avatar_decoration: user.avatar_decoration.clone(),
             _nonexhaustive: (),
         }
     }
@@ -881,27 +1049,36 @@ impl<'a> From<&'a User> for UserId {
     fn from(user: &User) -> UserId { user.id }
 }
 
+#[cfg(feature = "model")]
+fn avatar_cdn_url(user_id: UserId, hash: Option<&String>) -> Option<CdnUrl> {
+    hash.map(|hash| CdnUrl::hashed(&format!("/avatars/{}", user_id.0), hash))
+}
+
 #[cfg(feature = "model")]
 fn avatar_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
-    hash.map(|hash| {
-        let ext = if hash.starts_with("a_") {
-            "gif"
-        } else {
-            "webp"
-        };
-
-        cdn!("/avatars/{}/{}.{}?size=1024", user_id.0, hash, ext)
-    })
+    avatar_cdn_url(user_id, hash).map(|url| url.size(1024).build())
+}
+
+#[cfg(feature = "model")]
+fn banner_cdn_url(user_id: UserId, hash: Option<&String>) -> Option<CdnUrl> {
+    hash.map(|hash| CdnUrl::hashed(&format!("/banners/{}", user_id.0), hash))
+}
+
+#[cfg(feature = "model")]
+fn banner_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
+    banner_cdn_url(user_id, hash).map(|url| url.size(1024).build())
 }
 
 #[cfg(feature = "model")]
 fn default_avatar_url(discriminator: u16) -> String {
-    cdn!("/embed/avatars/{}.png", discriminator % 5u16)
+    CdnUrl::new(format!("/embed/avatars/{}", discriminator % 5u16))
+        .format(ImageFormat::Png)
+        .build()
 }
 
 #[cfg(feature = "model")]
 fn static_avatar_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
-    hash.map(|hash| cdn!("/avatars/{}/{}.webp?size=1024", user_id, hash))
+    avatar_cdn_url(user_id, hash).map(|url| url.format(ImageFormat::WebP).size(1024).build())
 }
 
 #[cfg(feature = "model")]
@@ -956,6 +1133,26 @@ mod test {
             assert_eq!(user.tag(), "test#1432");
         }
 
+        #[test]
+        fn banner_url() {
+            let mut user = User::default();
+            assert!(user.banner_url().is_none());
+
+            user.banner = Some("abc".to_string());
+            assert!(
+                user.banner_url()
+                    .unwrap()
+                    .ends_with("/banners/210/abc.webp?size=1024")
+            );
+
+            user.banner = Some("a_aaa".to_string());
+            assert!(
+                user.banner_url()
+                    .unwrap()
+                    .ends_with("/banners/210/a_aaa.gif?size=1024")
+            );
+        }
+
         #[test]
         fn default_avatars() {
             let mut user = User::default();
@@ -44,6 +44,31 @@ pub fn serialize_emojis<S: Serializer>(
     seq.end()
 }
 
+pub fn deserialize_stickers<'de, D: Deserializer<'de>>(
+    deserializer: D)
+    -> StdResult<HashMap<StickerId, Sticker>, D::Error> {
+    let vec: Vec<Sticker> = Deserialize::deserialize(deserializer)?;
+    let mut stickers = HashMap::new();
+
+    for sticker in vec {
+        stickers.insert(sticker.id, sticker);
+    }
+
+    Ok(stickers)
+}
+
+pub fn serialize_stickers<S: Serializer>(
+    stickers: &HashMap<StickerId, Sticker>,
+    serializer: S) -> StdResult<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(stickers.len()))?;
+
+    for sticker in stickers.values() {
+        seq.serialize_element(sticker)?;
+    }
+
+    seq.end()
+}
+
 pub fn deserialize_guild_channels<'de, D: Deserializer<'de>>(
     deserializer: D)
     -> StdResult<HashMap<ChannelId, GuildChannel>, D::Error> {
@@ -260,7 +285,7 @@ pub async fn user_has_perms(
         None => return Err(Error::Model(ModelError::ItemMissing)),
     };
 
-    let perms = guild.user_permissions_in(channel_id, cache.current_user().await.id);
+    let perms = guild.user_permissions_in_ids(channel_id, cache.current_user().await.id);
 
     permissions.remove(perms);
 
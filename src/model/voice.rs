@@ -14,14 +14,8 @@ pub struct VoiceRegion {
     pub id: String,
     /// A recognizable name of the location of the voice region.
     pub name: String,
-    /// Whether the voice region is optimal for use by the current user.
-    pub optional: bool,
-    /// an example hostname.
-    pub sample_hostname: String,
-    /// An example port.
-    pub sample_port: u64,
-    /// Indicator of whether the voice region is only for VIP guilds.
-    pub vip: bool,
+    /// Whether the voice region is the closest to the current user.
+    pub optimal: bool,
     #[serde(skip)]
     pub(crate) _nonexhaustive: (),
 }
@@ -4,6 +4,7 @@ use super::{
     id::{
         ChannelId,
         GuildId,
+        MessageId,
         WebhookId
     },
     user::User
@@ -12,7 +13,7 @@ use super::{
 use std::fmt;
 
 #[cfg(feature = "model")]
-use crate::builder::ExecuteWebhook;
+use crate::builder::{EditWebhookMessage, ExecuteWebhook};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -86,6 +87,18 @@ impl Webhook {
     }
 
     ///
+    /// Deletes a [`Message`] previously sent by the webhook.
+    ///
+    /// As this calls the [`Http::delete_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// [`Http::delete_webhook_message`]: ../../http/client/struct.Http.html#method.delete_webhook_message
+    /// [`Message`]: ../channel/struct.Message.html
+    #[inline]
+    pub async fn delete_message(&self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
+        http.as_ref().delete_webhook_message(self.id.0, &self.token, message_id.into().0).await
+    }
+
     /// Edits the webhook in-place. All fields are optional.
     ///
     /// To nullify the avatar, pass `Some("")`. Otherwise, passing `None` will
@@ -230,14 +243,37 @@ impl Webhook {
     /// # }
     /// ```
     #[inline]
-    pub async fn execute<F>(&self, http: impl AsRef<Http>, wait: bool, f: F) -> Result<Option<Message>>
-    where F: FnOnce(&mut ExecuteWebhook) -> &mut ExecuteWebhook
+    pub async fn execute<'a, F>(&self, http: impl AsRef<Http>, wait: bool, f: F) -> Result<Option<Message>>
+    where for <'b> F: FnOnce(&'b mut ExecuteWebhook<'a>) -> &'b mut ExecuteWebhook<'a>
     {
         let mut execute_webhook = ExecuteWebhook::default();
         f(&mut execute_webhook);
+
+        let thread_id = execute_webhook.0.remove("thread_id").and_then(|id| id.as_u64());
         let map = utils::hashmap_to_json_map(execute_webhook.0);
 
-        http.as_ref().execute_webhook(self.id.0, &self.token, wait, &map).await
+        if execute_webhook.1.is_empty() {
+            http.as_ref().execute_webhook(self.id.0, thread_id, &self.token, wait, &map).await
+        } else {
+            http.as_ref().execute_webhook_with_files(self.id.0, thread_id, &self.token, wait, execute_webhook.1, map).await
+        }
+    }
+
+    /// Edits a [`Message`] previously sent by the webhook.
+    ///
+    /// As this calls the [`Http::edit_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// [`Http::edit_webhook_message`]: ../../http/client/struct.Http.html#method.edit_webhook_message
+    /// [`Message`]: ../channel/struct.Message.html
+    pub async fn edit_message<F>(&self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditWebhookMessage) -> &mut EditWebhookMessage
+    {
+        let mut edit_webhook_message = EditWebhookMessage::default();
+        f(&mut edit_webhook_message);
+        let map = utils::hashmap_to_json_map(edit_webhook_message.0);
+
+        http.as_ref().edit_webhook_message(self.id.0, &self.token, message_id.into().0, &Value::Object(map)).await
     }
 
     /// Retrieves the latest information about the webhook, editing the
@@ -0,0 +1,809 @@
+use crate::cache::Cache;
+use crate::model::{
+    channel::Channel,
+    id::{ChannelId, GuildId, RoleId, UserId},
+};
+use std::{collections::HashMap, ops::Range};
+use super::message_builder::escape_markdown;
+
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, Http};
+
+/// Struct that allows to alter [`content_safe`]'s behaviour.
+///
+/// [`content_safe`]: fn.content_safe.html
+#[derive(Clone, Debug)]
+pub struct ContentSafeOptions {
+    clean_role: bool,
+    clean_user: bool,
+    clean_channel: bool,
+    clean_here: bool,
+    clean_everyone: bool,
+    escape_markdown: bool,
+    show_discriminator: bool,
+    guild_reference: Option<GuildId>,
+}
+
+impl ContentSafeOptions {
+    pub fn new() -> Self {
+        ContentSafeOptions::default()
+    }
+
+    /// [`content_safe`] will replace role mentions (`<@&{id}>`) with its name
+    /// prefixed with `@` (`@rolename`) or with `@deleted-role` if the
+    /// identifier is invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_role(mut self, b: bool) -> Self {
+        self.clean_role = b;
+
+        self
+    }
+
+    /// If set to true, [`content_safe`] will replace user mentions
+    /// (`<@!{id}>` or `<@{id}>`) with the user's name prefixed with `@`
+    /// (`@username`) or with `@invalid-user` if the identifier is invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_user(mut self, b: bool) -> Self {
+        self.clean_user = b;
+
+        self
+    }
+
+    /// If set to true, [`content_safe`] will replace channel mentions
+    /// (`<#{id}>`) with the channel's name prefixed with `#`
+    /// (`#channelname`) or with `#deleted-channel` if the identifier is
+    /// invalid.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_channel(mut self, b: bool) -> Self {
+        self.clean_channel = b;
+
+        self
+    }
+
+    /// If set to true, if [`content_safe`] replaces a user mention it will
+    /// add their four digit discriminator with a preceeding `#`,
+    /// turning `@username` to `@username#discriminator`.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn show_discriminator(mut self, b: bool) -> Self {
+        self.show_discriminator = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace a user mention with the user's
+    /// display name in passed `guild`.
+    ///
+    /// This guild is also used to look up the names of roles over HTTP with
+    /// [`content_safe_with_http`].
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    /// [`content_safe_with_http`]: fn.content_safe_with_http.html
+    pub fn display_as_member_from<G: Into<GuildId>>(mut self, guild: G) -> Self {
+        self.guild_reference = Some(guild.into());
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace `@here` with a non-pinging
+    /// alternative.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_here(mut self, b: bool) -> Self {
+        self.clean_here = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will replace `@everyone` with a non-pinging
+    /// alternative.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn clean_everyone(mut self, b: bool) -> Self {
+        self.clean_everyone = b;
+
+        self
+    }
+
+    /// If set, [`content_safe`] will escape markdown, so the content and the
+    /// names replacing mentions are displayed as written.
+    ///
+    /// Unlike the other options, this is set to `false` by default.
+    ///
+    /// [`content_safe`]: fn.content_safe.html
+    pub fn escape_markdown(mut self, b: bool) -> Self {
+        self.escape_markdown = b;
+
+        self
+    }
+}
+
+impl Default for ContentSafeOptions {
+    /// Instantiates with all options set to `true`, except
+    /// [`escape_markdown`].
+    ///
+    /// [`escape_markdown`]: #method.escape_markdown
+    fn default() -> Self {
+        ContentSafeOptions {
+            clean_role: true,
+            clean_user: true,
+            clean_channel: true,
+            clean_here: true,
+            clean_everyone: true,
+            escape_markdown: false,
+            show_discriminator: true,
+            guild_reference: None,
+        }
+    }
+}
+
+/// The kind of text replaced by [`content_safe_with_http`].
+///
+/// [`content_safe_with_http`]: fn.content_safe_with_http.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SubstitutionKind {
+    /// A user mention, replaced with the name of the user.
+    User,
+    /// A role mention, replaced with the name of the role.
+    Role,
+    /// A channel mention, replaced with the name of the channel.
+    Channel,
+    /// An `@here` mention.
+    Here,
+    /// An `@everyone` mention.
+    Everyone,
+    /// Text containing markdown, replaced with the escaped text.
+    Markdown,
+}
+
+/// A replacement of text made by [`content_safe_with_http`].
+///
+/// [`content_safe_with_http`]: fn.content_safe_with_http.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Substitution {
+    /// The kind of text replaced.
+    pub kind: SubstitutionKind,
+    /// The range of bytes replaced in the original content.
+    pub range: Range<usize>,
+    /// The text the range was replaced with.
+    pub replacement: String,
+    /// Whether the mentioned user, role or channel was found. If not, the
+    /// mention is replaced with e.g. `@invalid-user`.
+    ///
+    /// This is always `true` for other kinds of substitutions.
+    pub resolved: bool,
+}
+
+/// Content sanitised by [`content_safe_with_http`], along with the
+/// substitutions made, in the order they appear in.
+///
+/// [`content_safe_with_http`]: fn.content_safe_with_http.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct SafeContent {
+    /// The sanitised content.
+    pub content: String,
+    /// The substitutions made to the original content.
+    pub substitutions: Vec<Substitution>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum MentionKind {
+    User,
+    Role,
+    Channel,
+}
+
+/// Finds the next mention starting at or after `position`, returning its kind,
+/// the range it occupies, and its Id.
+///
+/// Mentions consisting of digits are found even if the Id is out of range, in
+/// which case the Id is `None`.
+fn next_mention(s: &str, mut position: usize) -> Option<(MentionKind, Range<usize>, Option<u64>)> {
+    while let Some(offset) = s[position..].find('<') {
+        let start = position + offset;
+        position = start + 1;
+
+        let rest = &s[start..];
+        let (kind, prefix_len) = if rest.starts_with("<@&") {
+            (MentionKind::Role, 3)
+        } else if rest.starts_with("<@!") {
+            (MentionKind::User, 3)
+        } else if rest.starts_with("<@") {
+            (MentionKind::User, 2)
+        } else if rest.starts_with("<#") {
+            (MentionKind::Channel, 2)
+        } else {
+            continue;
+        };
+
+        let digits = &rest[prefix_len..];
+        let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+
+        if len > 0 && digits[len..].starts_with('>') {
+            let end = start + prefix_len + len + 1;
+
+            return Some((kind, start..end, digits[..len].parse().ok()));
+        }
+    }
+
+    None
+}
+
+/// Looks up the names of mentioned users, roles and channels in the cache,
+/// falling back to HTTP if given.
+struct Cleaner<'a> {
+    cache: Option<&'a Cache>,
+    #[cfg(feature = "model")]
+    http: Option<&'a Http>,
+    options: &'a ContentSafeOptions,
+    names: HashMap<(MentionKind, u64), Option<String>>,
+    safe: SafeContent,
+}
+
+impl<'a> Cleaner<'a> {
+    async fn clean(mut self, s: &str) -> SafeContent {
+        let mut position = 0;
+
+        while let Some((kind, range, id)) = next_mention(s, position) {
+            let enabled = match kind {
+                MentionKind::User => self.options.clean_user,
+                MentionKind::Role => self.options.clean_role,
+                MentionKind::Channel => self.options.clean_channel,
+            };
+
+            if !enabled {
+                self.push_text(&s[position..range.end], position);
+                position = range.end;
+
+                continue;
+            }
+
+            self.push_text(&s[position..range.start], position);
+            position = range.end;
+
+            let name = match id {
+                Some(id) => self.name(kind, id).await,
+                None => None,
+            };
+            let resolved = name.is_some();
+            let replacement = match (kind, name) {
+                (MentionKind::User, Some(name)) | (MentionKind::Role, Some(name)) => format!("@{}", name),
+                (MentionKind::Channel, Some(name)) => format!("#{}", name),
+                (MentionKind::User, None) => "@invalid-user".to_string(),
+                (MentionKind::Role, None) => "@deleted-role".to_string(),
+                (MentionKind::Channel, None) => "#deleted-channel".to_string(),
+            };
+            let kind = match kind {
+                MentionKind::User => SubstitutionKind::User,
+                MentionKind::Role => SubstitutionKind::Role,
+                MentionKind::Channel => SubstitutionKind::Channel,
+            };
+
+            self.substitute(kind, range, replacement, resolved);
+        }
+
+        self.push_text(&s[position..], position);
+
+        self.safe
+    }
+
+    /// Pushes text without mentions of users, roles or channels, which starts
+    /// at `offset` in the original content.
+    fn push_text(&mut self, mut text: &str, mut offset: usize) {
+        let patterns = [
+            ("@here", SubstitutionKind::Here, self.options.clean_here),
+            ("@everyone", SubstitutionKind::Everyone, self.options.clean_everyone),
+        ];
+
+        loop {
+            let found = patterns.iter()
+                .filter(|(_, _, enabled)| *enabled)
+                .filter_map(|(pattern, kind, _)| text.find(pattern).map(|index| (index, *pattern, *kind)))
+                .min_by_key(|(index, _, _)| *index);
+
+            let (index, pattern, kind) = match found {
+                Some(found) => found,
+                None => break,
+            };
+
+            self.push_plain(&text[..index], offset);
+
+            let start = offset + index;
+            let replacement = format!("@\u{200B}{}", &pattern[1..]);
+            self.substitute(kind, start..start + pattern.len(), replacement, true);
+
+            text = &text[index + pattern.len()..];
+            offset = start + pattern.len();
+        }
+
+        self.push_plain(text, offset);
+    }
+
+    /// Pushes text without any mentions, escaping markdown if enabled.
+    fn push_plain(&mut self, text: &str, offset: usize) {
+        if self.options.escape_markdown {
+            let escaped = escape_markdown(text);
+
+            if escaped != text {
+                self.substitute(SubstitutionKind::Markdown, offset..offset + text.len(), escaped, true);
+
+                return;
+            }
+        }
+
+        self.safe.content.push_str(text);
+    }
+
+    fn substitute(&mut self, kind: SubstitutionKind, range: Range<usize>, replacement: String, resolved: bool) {
+        self.safe.content.push_str(&replacement);
+        self.safe.substitutions.push(Substitution {
+            kind,
+            range,
+            replacement,
+            resolved,
+        });
+    }
+
+    /// Returns the name replacing a mention, sanitised so it can not mention
+    /// anyone itself.
+    async fn name(&mut self, kind: MentionKind, id: u64) -> Option<String> {
+        if let Some(name) = self.names.get(&(kind, id)) {
+            return name.clone();
+        }
+
+        let name = match kind {
+            MentionKind::User => self.user_name(UserId(id)).await,
+            MentionKind::Role => self.role_name(RoleId(id)).await,
+            MentionKind::Channel => self.channel_name(ChannelId(id)).await,
+        };
+
+        let name = name.map(|mut name| {
+            if self.options.clean_here {
+                name = name.replace("@here", "@\u{200B}here");
+            }
+
+            if self.options.clean_everyone {
+                name = name.replace("@everyone", "@\u{200B}everyone");
+            }
+
+            if self.options.escape_markdown {
+                name = escape_markdown(&name);
+            }
+
+            name
+        });
+
+        self.names.insert((kind, id), name.clone());
+
+        name
+    }
+
+    async fn user_name(&self, id: UserId) -> Option<String> {
+        let show_discriminator = self.options.show_discriminator;
+
+        if let Some(guild_id) = self.options.guild_reference {
+            let member = match self.cache {
+                Some(cache) => cache.member(guild_id, id).await,
+                None => None,
+            };
+
+            #[cfg(feature = "model")]
+            let member = match (member, self.http) {
+                (None, Some(http)) => http.get_member(guild_id.0, id.0).await.ok(),
+                (member, _) => member,
+            };
+
+            return member.map(|member| if show_discriminator {
+                member.distinct()
+            } else {
+                member.display_name().into_owned()
+            });
+        }
+
+        let user = match self.cache {
+            Some(cache) => cache.user(id).await,
+            None => None,
+        };
+
+        #[cfg(feature = "model")]
+        let user = match (user, self.http) {
+            (None, Some(http)) => http.get_user(id.0).await.ok(),
+            (user, _) => user,
+        };
+
+        user.map(|user| if show_discriminator {
+            format!("{}#{:04}", user.name, user.discriminator)
+        } else {
+            user.name
+        })
+    }
+
+    async fn role_name(&self, id: RoleId) -> Option<String> {
+        let role = match self.cache {
+            Some(cache) => id.to_role_cached(cache).await,
+            None => None,
+        };
+
+        #[cfg(feature = "model")]
+        let role = match (role, self.http, self.options.guild_reference) {
+            (None, Some(http), Some(guild_id)) => http.get_guild_roles(guild_id.0)
+                .await
+                .ok()
+                .and_then(|roles| roles.into_iter().find(|role| role.id == id)),
+            (role, _, _) => role,
+        };
+
+        role.map(|role| role.name)
+    }
+
+    async fn channel_name(&self, id: ChannelId) -> Option<String> {
+        let channel = match self.cache {
+            Some(cache) => id.to_channel_cached(cache).await,
+            None => None,
+        };
+
+        #[cfg(feature = "model")]
+        let channel = match (channel, self.http) {
+            (None, Some(http)) => http.get_channel(id.0).await.ok(),
+            (channel, _) => channel,
+        };
+
+        match channel {
+            Some(Channel::Guild(channel)) => Some(channel.name),
+            _ => None,
+        }
+    }
+}
+
+/// Transforms role, channel, user, `@everyone` and `@here` mentions
+/// into raw text by using the [`Cache`] only.
+///
+/// [`ContentSafeOptions`] decides what kind of mentions should be filtered
+/// and how the raw-text will be displayed.
+///
+/// Refer to [`content_safe_with_http`] for looking up names that are not
+/// cached, and finding out which substitutions were made.
+///
+/// # Examples
+///
+/// Sanitise an `@everyone` mention.
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use serenity::client::Cache;
+/// # use tokio::sync::RwLock;
+/// #
+/// # async fn run() {
+/// # let cache = Cache::default();
+/// use serenity::utils::{
+///     content_safe,
+///     ContentSafeOptions,
+/// };
+///
+/// let with_mention = "@everyone";
+/// let without_mention = content_safe(&cache, &with_mention, &ContentSafeOptions::default()).await;
+///
+/// assert_eq!("@\u{200B}everyone".to_string(), without_mention);
+/// # }
+/// ```
+///
+/// [`ContentSafeOptions`]: struct.ContentSafeOptions.html
+/// [`Cache`]: ../cache/struct.Cache.html
+/// [`content_safe_with_http`]: fn.content_safe_with_http.html
+pub async fn content_safe(cache: impl AsRef<Cache>, s: impl AsRef<str>, options: &ContentSafeOptions) -> String {
+    let cleaner = Cleaner {
+        cache: Some(cache.as_ref()),
+        #[cfg(feature = "model")]
+        http: None,
+        options,
+        names: HashMap::new(),
+        safe: SafeContent::default(),
+    };
+
+    cleaner.clean(s.as_ref()).await.content
+}
+
+/// Transforms role, channel, user, `@everyone` and `@here` mentions into raw
+/// text like [`content_safe`], but requests the names of users, roles and
+/// channels that are not cached over HTTP.
+///
+/// Returns the sanitised content along with the substitutions made.
+///
+/// **Note**: Roles can only be requested if a guild is set with
+/// [`ContentSafeOptions::display_as_member_from`].
+///
+/// # Examples
+///
+/// Find out whether a message mentioned a user that could not be found:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// # use serenity::prelude::*;
+/// #
+/// # async fn run(ctx: Context, msg: Message) {
+/// use serenity::utils::{content_safe_with_http, ContentSafeOptions, SubstitutionKind};
+///
+/// let options = ContentSafeOptions::default().escape_markdown(true);
+/// let safe = content_safe_with_http(&ctx, &msg.content, &options).await;
+///
+/// let unknown_users = safe.substitutions
+///     .iter()
+///     .filter(|s| s.kind == SubstitutionKind::User && !s.resolved)
+///     .count();
+///
+/// println!("{} ({} unknown users)", safe.content, unknown_users);
+/// # }
+/// ```
+///
+/// [`ContentSafeOptions::display_as_member_from`]: struct.ContentSafeOptions.html#method.display_as_member_from
+/// [`content_safe`]: fn.content_safe.html
+#[cfg(feature = "model")]
+pub async fn content_safe_with_http(
+    cache_http: impl CacheHttp,
+    s: impl AsRef<str>,
+    options: &ContentSafeOptions,
+) -> SafeContent {
+    let cleaner = Cleaner {
+        cache: cache_http.cache().map(|cache| &**cache),
+        http: Some(cache_http.http()),
+        options,
+        names: HashMap::new(),
+        safe: SafeContent::default(),
+    };
+
+    cleaner.clean(s.as_ref()).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::Colour;
+
+    #[tokio::test]
+    async fn records_substitutions() {
+        let options = ContentSafeOptions::default().escape_markdown(true).clean_channel(false);
+        let cleaner = Cleaner {
+            cache: None,
+            #[cfg(feature = "model")]
+            http: None,
+            options: &options,
+            names: HashMap::new(),
+            safe: SafeContent::default(),
+        };
+
+        let safe = cleaner.clean("*hi* <@1> @here <#2>").await;
+        let kinds = safe.substitutions.iter().map(|s| s.kind).collect::<Vec<_>>();
+
+        assert_eq!(safe.content, "\\*hi\\* @invalid-user @\u{200B}here <#2>");
+        assert_eq!(kinds, vec![SubstitutionKind::Markdown, SubstitutionKind::User, SubstitutionKind::Here]);
+        assert_eq!(safe.substitutions[1].range, 5..9);
+        assert!(!safe.substitutions[1].resolved);
+    }
+
+    #[tokio::test]
+    async fn test_content_safe() {
+        use crate::model::{
+            user::User,
+            Permissions,
+            prelude::*,
+        };
+        use chrono::{DateTime, Utc};
+        use std::{
+            collections::HashMap,
+            sync::Arc,
+        };
+
+        let user = User {
+            id: UserId(100000000000000000),
+            avatar: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
+            bot: false,
+            discriminator: 0000,
+            name: "Crab".to_string(),
+            _nonexhaustive: (),
+        };
+
+        let mut guild = Guild {
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            channels: HashMap::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: Vec::new(),
+            icon: None,
+            id: GuildId(381880193251409931),
+            joined_at: DateTime::parse_from_str(
+                "1983 Apr 13 12:09:14.274 +0000",
+                "%Y %b %d %H:%M:%S%.3f %z").unwrap()
+                .with_timezone(&Utc),
+            large: false,
+            member_count: 1,
+            members: HashMap::new(),
+            mfa_level: MfaLevel::None,
+            name: "serenity".to_string(),
+            owner_id: UserId(114941315417899012),
+            presences: HashMap::new(),
+            region: "Ferris Island".to_string(),
+            roles: HashMap::new(),
+            splash: None,
+            discovery_splash: None,
+            system_channel_id: None,
+            verification_level: VerificationLevel::None,
+            voice_states: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: Some("bruhmoment1".to_string()),
+            preferred_locale: "en-US".to_string(),
+            stickers: HashMap::new(),
+            _nonexhaustive: (),
+        };
+
+        let member = Member {
+            deaf: false,
+            guild_id: guild.id,
+            joined_at: None,
+            mute: false,
+            nick: Some("Ferris".to_string()),
+            roles: Vec::new(),
+            user: user.clone(),
+            communication_disabled_until: None,
+            pending: false,
+            _nonexhaustive: (),
+        };
+
+        let role = Role {
+            id: RoleId(333333333333333333),
+            colour: Colour::ORANGE,
+            guild_id: guild.id,
+            hoist: true,
+            managed: false,
+            mentionable: true,
+            name: "ferris-club-member".to_string(),
+            permissions: Permissions::all(),
+            position: 0,
+            icon: None,
+            unicode_emoji: None,
+            _nonexhaustive: (),
+        };
+
+        let channel = GuildChannel {
+            id: ChannelId(111880193700067777),
+            bitrate: None,
+            category_id: None,
+            guild_id: guild.id,
+            kind: ChannelType::Text,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: "general".to_string(),
+            permission_overwrites: Vec::new(),
+            position: 0,
+            topic: None,
+            user_limit: None,
+            nsfw: false,
+            slow_mode_rate: Some(0),
+            thread_metadata: None,
+            member: None,
+            owner_id: None,
+            message_count: None,
+            member_count: None,
+            rtc_region: None,
+            video_quality_mode: None,
+            _nonexhaustive: (),
+        };
+
+        let cache = Arc::new(Cache::default());
+
+        guild.members.insert(user.id, member.clone());
+        guild.roles.insert(role.id, role.clone());
+        cache.users.write().await.insert(user.id, user.clone());
+        cache.guilds.write().await.insert(guild.id, guild.clone());
+        cache.channels.write().await.insert(channel.id, channel.clone());
+
+
+        let with_user_metions = "<@!100000000000000000> <@!000000000000000000> <@123> <@!123> \
+        <@!123123123123123123123> <@123> <@123123123123123123> <@!invalid> \
+        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
+        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
+        <@123invalid> <@> <@ ";
+
+        let without_user_mentions = "@Crab#0000 @invalid-user @invalid-user @invalid-user \
+        @invalid-user @invalid-user @invalid-user <@!invalid> \
+        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
+        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
+        <@123invalid> <@> <@ ";
+
+        // User mentions
+        let options = ContentSafeOptions::default();
+        assert_eq!(without_user_mentions, content_safe(&cache, with_user_metions, &options).await);
+
+        let options = ContentSafeOptions::default();
+        assert_eq!(format!("@{}#{:04}", user.name, user.discriminator),
+            content_safe(&cache, "<@!100000000000000000>", &options).await);
+
+        let options = ContentSafeOptions::default();
+        assert_eq!(format!("@{}#{:04}", user.name, user.discriminator),
+            content_safe(&cache, "<@100000000000000000>", &options).await);
+
+        let options = options.show_discriminator(false);
+        assert_eq!(format!("@{}", user.name),
+            content_safe(&cache, "<@!100000000000000000>", &options).await);
+
+        let options = options.show_discriminator(false);
+        assert_eq!(format!("@{}", user.name),
+            content_safe(&cache, "<@100000000000000000>", &options).await);
+
+        let options = options.display_as_member_from(guild.id);
+        assert_eq!(format!("@{}", member.nick.unwrap()),
+            content_safe(&cache, "<@!100000000000000000>", &options).await);
+
+        let options = options.clean_user(false);
+        assert_eq!(with_user_metions,
+            content_safe(&cache, with_user_metions, &options).await);
+
+        // Channel mentions
+        let with_channel_mentions = "<#> <#deleted-channel> #deleted-channel <#0> \
+        #unsafe-club <#111880193700067777> <#ferrisferrisferris> \
+        <#000000000000000000>";
+
+        let without_channel_mentions = "<#> <#deleted-channel> #deleted-channel \
+        #deleted-channel #unsafe-club #general <#ferrisferrisferris> \
+        #deleted-channel";
+
+        assert_eq!(without_channel_mentions,
+            content_safe(&cache, with_channel_mentions, &options).await);
+
+        let options = options.clean_channel(false);
+        assert_eq!(with_channel_mentions,
+            content_safe(&cache, with_channel_mentions, &options).await);
+
+        // Role mentions
+        let with_role_mentions = "<@&> @deleted-role <@&9829> \
+        <@&333333333333333333> <@&000000000000000000>";
+
+        let without_role_mentions = "<@&> @deleted-role @deleted-role \
+        @ferris-club-member @deleted-role";
+
+        assert_eq!(without_role_mentions,
+            content_safe(&cache, with_role_mentions, &options).await);
+
+        let options = options.clean_role(false);
+        assert_eq!(with_role_mentions,
+            content_safe(&cache, with_role_mentions, &options).await);
+
+        // Everyone mentions
+        let with_everyone_mention = "@everyone";
+
+        let without_everyone_mention = "@\u{200B}everyone";
+
+        assert_eq!(without_everyone_mention,
+            content_safe(&cache, with_everyone_mention, &options).await);
+
+        let options = options.clean_everyone(false);
+        assert_eq!(with_everyone_mention,
+            content_safe(&cache, with_everyone_mention, &options).await);
+
+        // Here mentions
+        let with_here_mention = "@here";
+
+        let without_here_mention = "@\u{200B}here";
+
+        assert_eq!(without_here_mention,
+            content_safe(&cache, with_here_mention, &options).await);
+
+        let options = options.clean_here(false);
+        assert_eq!(with_here_mention,
+            content_safe(&cache, with_here_mention, &options).await);
+    }
+}
@@ -1,5 +1,4 @@
 use crate::model::prelude::*;
-use chrono::{DateTime, Utc};
 use serde_json::Value;
 
 /// A builder for constructing a personal [`Message`] instance.
@@ -81,8 +80,8 @@ impl CustomMessage {
     ///
     /// If not used, the default value is `None` (not all messages are edited).
     #[inline]
-    pub fn edited_timestamp(&mut self, timestamp: DateTime<Utc>) -> &mut Self {
-        self.msg.edited_timestamp = Some(timestamp);
+    pub fn edited_timestamp(&mut self, timestamp: impl Into<Timestamp>) -> &mut Self {
+        self.msg.edited_timestamp = Some(timestamp.into());
 
         self
     }
@@ -197,8 +196,8 @@ impl CustomMessage {
     ///
     /// If not used, the default value is the current local time.
     #[inline]
-    pub fn timestamp(&mut self, timestamp: DateTime<Utc>) -> &mut Self {
-        self.msg.timestamp = timestamp;
+    pub fn timestamp(&mut self, timestamp: impl Into<Timestamp>) -> &mut Self {
+        self.msg.timestamp = timestamp.into();
 
         self
     }
@@ -247,6 +246,9 @@ fn dummy_message() -> Message {
         author: User {
             id: UserId::default(),
             avatar: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
             bot: false,
             discriminator: 0x0000,
             name: String::new(),
@@ -268,11 +270,13 @@ fn dummy_message() -> Message {
         reactions: Vec::new(),
         tts: false,
         webhook_id: None,
-        timestamp: Utc::now(),
+        timestamp: Timestamp::now(),
         activity: None,
         application: None,
         message_reference: None,
         flags: None,
+        sticker_items: Vec::new(),
+        poll: None,
         _nonexhaustive: (),
     }
 }
@@ -1,7 +1,11 @@
+use crate::constants;
+use crate::internal::prelude::*;
 use crate::model::{
+    error::Error as ModelError,
     guild::Emoji,
     id::{ChannelId, RoleId, UserId},
-    misc::Mentionable
+    misc::Mentionable,
+    timestamp::{Timestamp, TimestampStyle},
 };
 use std::{
     default::Default,
@@ -103,6 +107,116 @@ impl MessageBuilder {
     /// ```
     pub fn build(&mut self) -> String { self.clone().0 }
 
+    /// Pulls the inner value out of the builder, if it is within the maximum
+    /// length of a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] with the number of unicode
+    /// code points over the limit if the content is too long. Use
+    /// [`build_split`] to split it into multiple messages instead.
+    ///
+    /// [`ModelError::MessageTooLong`]: ../model/error/enum.Error.html#variant.MessageTooLong
+    /// [`build_split`]: #method.build_split
+    pub fn build_checked(&mut self) -> Result<String> {
+        let limit = usize::from(constants::MESSAGE_CODE_LIMIT);
+        let len = self.len();
+
+        if len > limit {
+            return Err(Error::Model(ModelError::MessageTooLong((len - limit) as u64)));
+        }
+
+        Ok(self.build())
+    }
+
+    /// Splits the content into as many messages as needed to stay within the
+    /// maximum length of a message.
+    ///
+    /// Messages are split between lines where possible. A code block split
+    /// across messages is closed at the end of one message and reopened, with
+    /// its language, at the start of the next.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let mut builder = MessageBuilder::new();
+    ///
+    /// for i in 0..500 {
+    ///     builder.push_line(format!("Line {}", i));
+    /// }
+    ///
+    /// let messages = builder.build_split();
+    ///
+    /// assert_eq!(messages.len(), 3);
+    /// assert!(messages.iter().all(|message| message.chars().count() <= 2000));
+    /// ```
+    pub fn build_split(&self) -> Vec<String> {
+        let limit = usize::from(constants::MESSAGE_CODE_LIMIT);
+        let mut splitter = Splitter {
+            limit,
+            messages: Vec::new(),
+            current: String::new(),
+            current_len: 0,
+            line_start: 0,
+            opened_by_line: false,
+            code_block: None,
+        };
+
+        for (i, line) in self.0.split('\n').enumerate() {
+            let line_len = line.chars().count();
+            let separator = if i > 0 && splitter.current_len > 0 { 1 } else { 0 };
+
+            if splitter.fits(separator + line_len) {
+                splitter.push_line(line, separator == 1);
+            } else {
+                splitter.flush();
+
+                if splitter.fits(usize::from(splitter.current_len > 0) + line_len) {
+                    let separator = splitter.current_len > 0;
+                    splitter.push_line(line, separator);
+                } else {
+                    // The line is too long for a message of its own.
+                    if splitter.current_len > 0 {
+                        splitter.push('\n');
+                    }
+
+                    for c in line.chars() {
+                        if !splitter.fits(1) {
+                            splitter.flush();
+
+                            if splitter.current_len > 0 {
+                                splitter.push('\n');
+                            }
+                        }
+
+                        splitter.push(c);
+                    }
+                }
+            }
+
+            splitter.track_code_block(line);
+        }
+
+        if splitter.current_len > 0 {
+            splitter.messages.push(splitter.current);
+        }
+
+        splitter.messages
+    }
+
+    /// Returns the length of the content in unicode code points, as counted
+    /// towards the maximum length of a message.
+    pub fn len(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    /// Whether no content was pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Mentions the [`GuildChannel`] in the built message.
     ///
     /// This accepts anything that converts _into_ a [`ChannelId`]. Refer to
@@ -547,13 +661,23 @@ impl MessageBuilder {
 
     /// Pushes text to your message, but normalizing content - that means
     /// ensuring that there's no unwanted formatting, mention spam etc.
+    ///
+    /// All markdown is escaped, as well as quotes at the start of lines, and
+    /// mentions of users, roles, `@everyone` and `@here` are broken up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_safe("> ~~hey~~ <@1>").build();
+    ///
+    /// assert_eq!(content, "\\> \\~\\~hey\\~\\~ <@\u{200B}1>");
+    /// ```
     pub fn push_safe<C: I>(&mut self, content: C) -> &mut Self {
         {
             let mut c = content.into();
-            c.inner = normalize(&c.inner)
-                .replace('*', "\\*")
-                .replace('`', "\\`")
-                .replace('_', "\\_");
+            c.inner = escape_markdown(&normalize(&c.inner));
 
             self.0.push_str(&c.to_string());
         }
@@ -873,6 +997,32 @@ impl MessageBuilder {
         self
     }
 
+    /// Displays a [`Timestamp`] in the built message, in the local time of
+    /// every reader.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::timestamp::{Timestamp, TimestampStyle};
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let timestamp = Timestamp::from_unix_timestamp(1618953630).unwrap();
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .push("Sent ")
+    ///     .timestamp(timestamp, TimestampStyle::RelativeTime)
+    ///     .build();
+    ///
+    /// assert_eq!(content, "Sent <t:1618953630:R>");
+    /// ```
+    ///
+    /// [`Timestamp`]: ../model/timestamp/struct.Timestamp.html
+    pub fn timestamp<T: Into<Timestamp>>(&mut self, timestamp: T, style: TimestampStyle) -> &mut Self {
+        self.0.push_str(&timestamp.into().format_markdown(style));
+
+        self
+    }
+
     /// Mentions the [`User`] in the built message.
     ///
     /// This accepts anything that converts _into_ a [`UserId`]. Refer to
@@ -1255,6 +1405,118 @@ impl I for Content {
     fn into(self) -> Content { self }
 }
 
+/// Splits content into messages for [`MessageBuilder::build_split`].
+///
+/// [`MessageBuilder::build_split`]: struct.MessageBuilder.html#method.build_split
+struct Splitter {
+    limit: usize,
+    messages: Vec<String>,
+    current: String,
+    current_len: usize,
+    /// The byte offset at which the last line starts in the current message.
+    line_start: usize,
+    /// Whether the last line opened the current code block.
+    opened_by_line: bool,
+    /// The line opening the code block the content is in, if any.
+    code_block: Option<String>,
+}
+
+impl Splitter {
+    /// Whether the given number of code points fit into the current message,
+    /// leaving room for closing its code block.
+    fn fits(&self, len: usize) -> bool {
+        let closing = if self.code_block.is_some() { 4 } else { 0 };
+
+        self.current_len + len + closing <= self.limit
+    }
+
+    fn push(&mut self, c: char) {
+        self.current.push(c);
+        self.current_len += 1;
+    }
+
+    fn push_line(&mut self, line: &str, separator: bool) {
+        if separator {
+            self.push('\n');
+        }
+
+        self.line_start = self.current.len();
+        self.current.push_str(line);
+        self.current_len += line.chars().count();
+    }
+
+    fn flush(&mut self) {
+        if self.current_len == 0 {
+            return;
+        }
+
+        // Move a line opening a code block to the next message, instead of
+        // leaving an empty code block at the end of this one.
+        if self.opened_by_line && self.line_start > 0 {
+            let line = self.current.split_off(self.line_start);
+            self.current.pop();
+
+            self.messages.push(std::mem::replace(&mut self.current, line));
+            self.current_len = self.current.chars().count();
+            self.line_start = 0;
+
+            return;
+        }
+
+        if self.code_block.is_some() {
+            self.current.push_str("\n```");
+        }
+
+        self.messages.push(std::mem::take(&mut self.current));
+        self.current_len = 0;
+        self.line_start = 0;
+
+        if let Some(opening) = &self.code_block {
+            self.current.push_str(opening);
+            self.current_len = opening.chars().count();
+        }
+    }
+
+    fn track_code_block(&mut self, line: &str) {
+        self.opened_by_line = false;
+
+        if line.matches("```").count() % 2 == 0 {
+            return;
+        }
+
+        if self.code_block.take().is_none() {
+            let language = line.rsplit("```").next().unwrap_or_default();
+
+            self.code_block = Some(if language.chars().all(char::is_alphanumeric) {
+                format!("```{}", language)
+            } else {
+                "```".to_string()
+            });
+            self.opened_by_line = true;
+        }
+    }
+}
+
+/// Escapes markdown in text, so that it is displayed as is.
+pub(crate) fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut line_start = true;
+
+    for c in text.chars() {
+        match c {
+            '\\' | '*' | '_' | '`' | '~' | '|' => escaped.push('\\'),
+            // Quotes are only started at the start of a line.
+            '>' if line_start => escaped.push('\\'),
+            _ => {},
+        }
+
+        escaped.push(c);
+        line_start = c == '\n';
+    }
+
+    escaped
+}
+
 fn normalize(text: &str) -> String {
     // Remove invite links and popular scam websites, mostly to prevent the
     // current user from triggering various ad detectors and prevent embeds.
@@ -1275,10 +1537,13 @@ fn normalize(text: &str) -> String {
         // because it utilises it itself.
         .replace("@everyone", "@\u{200B}everyone")
         .replace("@here", "@\u{200B}here")
+        // Break user and role mentions.
+        .replace("<@", "<@\u{200B}")
 }
 
 #[cfg(test)]
 mod test {
+    use crate::internal::prelude::*;
     use crate::model::prelude::*;
     use super::{
         ContentModifier::{Spoiler, Bold, Code, Italic},
@@ -1582,5 +1847,48 @@ mod test {
         assert_eq!(super::normalize("\u{200B}"), " ");
         assert_eq!(super::normalize("\u{200D}"), " ");
         assert_eq!(super::normalize("\u{200C}"), " ");
+        assert_eq!(super::normalize("<@1> <@&2>"), "<@\u{200B}1> <@\u{200B}&2>");
+    }
+
+    #[test]
+    fn escape_markdown() {
+        assert_eq!(super::escape_markdown(r"||a|| \*b*"), r"\|\|a\|\| \\\*b\*");
+        assert_eq!(super::escape_markdown("> a > b\n> c"), "\\> a > b\n\\> c");
+    }
+
+    #[test]
+    fn build_checked() {
+        let mut builder = MessageBuilder::new();
+        builder.push("a".repeat(2000));
+        assert_eq!(builder.build_checked().unwrap().len(), 2000);
+
+        builder.push("é");
+        match builder.build_checked() {
+            Err(Error::Model(ModelError::MessageTooLong(1))) => {},
+            other => panic!("expected a too long message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn build_split() {
+        assert!(MessageBuilder::new().build_split().is_empty());
+        assert_eq!(MessageBuilder::new().push("foo").build_split(), vec!["foo"]);
+
+        let line = "a".repeat(999);
+        let messages = MessageBuilder::new()
+            .push_line(&line)
+            .push_codeblock(format!("{}\n{}", line, line), Some("rs"))
+            .build_split();
+
+        assert_eq!(messages, vec![
+            line.clone(),
+            format!("```rs\n{}\n```", line),
+            format!("```rs\n{}\n```", line),
+        ]);
+
+        let messages = MessageBuilder::new().push("b".repeat(4500)).build_split();
+        let lens = messages.iter().map(|message| message.len()).collect::<Vec<_>>();
+
+        assert_eq!(lens, vec![2000, 2000, 500]);
     }
 }
@@ -2,8 +2,13 @@
 //! fully use the library.
 
 mod colour;
+#[cfg(feature = "cache")]
+mod content_safe;
 mod message_builder;
 mod custom_message;
+#[cfg(feature = "collector")]
+mod paginator;
+mod parse;
 
 pub use self::{
     colour::Colour,
@@ -13,8 +18,23 @@ pub use self::{
         EmbedMessageBuilding,
         MessageBuilder,
     },
-	custom_message::CustomMessage
+	custom_message::CustomMessage,
+    parse::{parse_mentions, Mention, Mentions},
+};
+#[cfg(feature = "cache")]
+pub use self::content_safe::{
+    content_safe,
+    ContentSafeOptions,
+    SafeContent,
+    Substitution,
+    SubstitutionKind,
 };
+#[cfg(all(feature = "cache", feature = "model"))]
+pub use self::content_safe::content_safe_with_http;
+#[cfg(all(feature = "cache", feature = "model"))]
+pub use self::parse::{ArgumentConvert, ArgumentConvertError};
+#[cfg(feature = "collector")]
+pub use self::paginator::{Page, Paginator, PaginatorControls};
 pub type Color = Colour;
 
 use crate::internal::prelude::*;
@@ -22,13 +42,6 @@ use crate::model::{
     misc::EmojiIdentifier,
     id::EmojiId,
 };
-#[cfg(feature = "cache")]
-use crate::model::id::{
-    ChannelId,
-    GuildId,
-    RoleId,
-    UserId,
-};
 use std::{
     collections::HashMap,
     ffi::OsStr,
@@ -38,13 +51,6 @@ use std::{
     path::Path,
 };
 
-#[cfg(feature = "cache")]
-use crate::model::channel::Channel;
-#[cfg(feature = "cache")]
-use std::str::FromStr;
-#[cfg(feature = "cache")]
-use crate::cache::Cache;
-
 /// Converts a HashMap into a final `serde_json::Map` representation.
 pub fn hashmap_to_json_map<H, T>(map: HashMap<T, Value, H>)
     -> Map<String, Value> where H: BuildHasher, T: Eq + Hash + ToString {
@@ -328,7 +334,7 @@ pub fn parse_emoji(mention: impl AsRef<str>) -> Option<EmojiIdentifier> {
 
 /// Reads an image from a path and encodes it into base64.
 ///
-/// This can be used for methods like [`EditProfile::avatar`].
+/// This can be used for methods like [`EditGuild::icon`].
 ///
 /// # Examples
 ///
@@ -340,7 +346,7 @@ pub fn parse_emoji(mention: impl AsRef<str>) -> Option<EmojiIdentifier> {
 /// let image = utils::read_image("./cat.png").expect("Failed to read image");
 /// ```
 ///
-/// [`EditProfile::avatar`]: ../builder/struct.EditProfile.html#method.avatar
+/// [`EditGuild::icon`]: ../builder/struct.EditGuild.html#method.icon
 #[inline]
 pub fn read_image<P: AsRef<Path>>(path: P) -> Result<String> {
     _read_image(path.as_ref())
@@ -361,6 +367,39 @@ fn _read_image(path: &Path) -> Result<String> {
     Ok(format!("data:image/{};base64,{}", ext, b64))
 }
 
+/// Encodes an image into a base64 data URI, as expected by methods like
+/// [`EditRole::icon`].
+///
+/// The image format is detected from the data, defaulting to PNG if it is
+/// neither a GIF, JPEG nor WebP image.
+///
+/// # Examples
+///
+/// Encode an image downloaded from the web:
+///
+/// ```rust
+/// use serenity::utils;
+///
+/// let bytes = [0x47, 0x49, 0x46, 0x38, 0x39, 0x61];
+///
+/// assert_eq!(utils::encode_image(&bytes), "data:image/gif;base64,R0lGODlh");
+/// ```
+///
+/// [`EditRole::icon`]: ../builder/struct.EditRole.html#method.icon
+pub fn encode_image(data: &[u8]) -> String {
+    let format = if data.starts_with(b"GIF8") {
+        "gif"
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpeg"
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        "webp"
+    } else {
+        "png"
+    };
+
+    format!("data:image/{};base64,{}", format, base64::encode(data))
+}
+
 /// Turns a string into a vector of string arguments, splitting by spaces, but
 /// parsing content within quotes as one individual argument.
 ///
@@ -453,330 +492,8 @@ pub fn parse_quotes(s: impl AsRef<str>) -> Vec<String> {
 #[inline]
 pub fn shard_id(guild_id: impl Into<u64>, shard_count: u64) -> u64 { (guild_id.into() >> 22) % shard_count }
 
-/// Struct that allows to alter [`content_safe`]'s behaviour.
-///
-/// [`content_safe`]: fn.content_safe.html
-#[cfg(feature = "cache")]
-#[derive(Clone, Debug)]
-pub struct ContentSafeOptions {
-    clean_role: bool,
-    clean_user: bool,
-    clean_channel: bool,
-    clean_here: bool,
-    clean_everyone: bool,
-    show_discriminator: bool,
-    guild_reference: Option<GuildId>,
-}
-
-#[cfg(feature = "cache")]
-impl ContentSafeOptions {
-    pub fn new() -> Self {
-        ContentSafeOptions::default()
-    }
-
-    /// [`content_safe`] will replace role mentions (`<@&{id}>`) with its name
-    /// prefixed with `@` (`@rolename`) or with `@deleted-role` if the
-    /// identifier is invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_role(mut self, b: bool) -> Self {
-        self.clean_role = b;
-
-        self
-    }
-
-    /// If set to true, [`content_safe`] will replace user mentions
-    /// (`<@!{id}>` or `<@{id}>`) with the user's name prefixed with `@`
-    /// (`@username`) or with `@invalid-user` if the identifier is invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_user(mut self, b: bool) -> Self {
-        self.clean_user = b;
-
-        self
-    }
-
-    /// If set to true, [`content_safe`] will replace channel mentions
-    /// (`<#{id}>`) with the channel's name prefixed with `#`
-    /// (`#channelname`) or with `#deleted-channel` if the identifier is
-    /// invalid.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_channel(mut self, b: bool) -> Self {
-        self.clean_channel = b;
-
-        self
-    }
-
-    /// If set to true, if [`content_safe`] replaces a user mention it will
-    /// add their four digit discriminator with a preceeding `#`,
-    /// turning `@username` to `@username#discriminator`.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn show_discriminator(mut self, b: bool) -> Self {
-        self.show_discriminator = b;
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace a user mention with the user's
-    /// display name in passed `guild`.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn display_as_member_from<G: Into<GuildId>>(mut self, guild: G) -> Self {
-        self.guild_reference = Some(guild.into());
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace `@here` with a non-pinging
-    /// alternative.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_here(mut self, b: bool) -> Self {
-        self.clean_here = b;
-
-        self
-    }
-
-    /// If set, [`content_safe`] will replace `@everyone` with a non-pinging
-    /// alternative.
-    ///
-    /// [`content_safe`]: fn.content_safe.html
-    pub fn clean_everyone(mut self, b: bool) -> Self {
-        self.clean_everyone = b;
-
-        self
-    }
-}
-
-#[cfg(feature = "cache")]
-impl Default for ContentSafeOptions {
-    /// Instantiates with all options set to `true`.
-    fn default() -> Self {
-        ContentSafeOptions {
-            clean_role: true,
-            clean_user: true,
-            clean_channel: true,
-            clean_here: true,
-            clean_everyone: true,
-            show_discriminator: true,
-            guild_reference: None,
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_roles(cache: impl AsRef<Cache>, s: &mut String) {
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<@&") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<@&".len();
-
-            if let Ok(id) = RoleId::from_str(&s[mention_start..mention_end]) {
-                let to_replace = format!("<@&{}>", &s[mention_start..mention_end]);
-
-                *s = if let Some(role) = id.to_role_cached(&cache).await {
-                    s.replace(&to_replace, &format!("@{}", &role.name))
-                } else {
-                    s.replace(&to_replace, &"@deleted-role")
-                };
-            } else  {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit){
-                    let to_replace = format!("<@&{}>", id);
-
-                    *s = s.replace(&to_replace, &"@deleted-role");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_channels(cache: &impl AsRef<Cache>, s: &mut String) {
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<#") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<#".len();
-
-            if let Ok(id) = ChannelId::from_str(&s[mention_start..mention_end]) {
-                let to_replace = format!("<#{}>", &s[mention_start..mention_end]);
-
-                *s = if let Some(Channel::Guild(channel)) = id.to_channel_cached(&cache).await {
-                    let replacement = format!("#{}", &channel.name);
-                    s.replace(&to_replace, &replacement)
-                } else {
-                    s.replace(&to_replace, &"#deleted-channel")
-                };
-            } else {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit) {
-                    let to_replace = format!("<#{}>", id);
-
-                    *s = s.replace(&to_replace, &"#deleted-channel");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-#[cfg(feature = "cache")]
-#[inline]
-async fn clean_users(
-    cache: &impl AsRef<Cache>,
-    s: &mut String,
-    show_discriminator: bool,
-    guild: Option<GuildId>
-) {
-    let cache = cache.as_ref();
-    let mut progress = 0;
-
-    while let Some(mut mention_start) = s[progress..].find("<@") {
-        mention_start += progress;
-
-        if let Some(mut mention_end) = s[mention_start..].find('>') {
-            mention_end += mention_start;
-            mention_start += "<@".len();
-
-            let has_exclamation = if s[mention_start..].as_bytes().get(0).map_or(false, |c| *c == b'!') {
-                mention_start += "!".len();
-
-                true
-            } else {
-                false
-            };
-
-            if let Ok(id) = UserId::from_str(&s[mention_start..mention_end]) {
-                let replacement = if let Some(guild_id) = guild {
-                    if let Some(guild) = cache.guild(&guild_id).await {
-                        if let Some(member) = guild.members.get(&id) {
-                            if show_discriminator {
-                                format!("@{}", member.distinct())
-                            } else {
-                                format!("@{}", member.display_name())
-                            }
-                        } else {
-                            "@invalid-user".to_string()
-                        }
-                    } else {
-                        "@invalid-user".to_string()
-                    }
-                } else if let Some(user) = cache.user(id).await {
-                    if show_discriminator {
-                        format!("@{}#{:04}", user.name, user.discriminator)
-                    } else {
-                        format!("@{}", user.name)
-                    }
-                } else {
-                    "@invalid-user".to_string()
-                };
-
-                let code_start = if has_exclamation { "<@!" } else { "<@" };
-                let to_replace = format!("{}{}>", code_start, &s[mention_start..mention_end]);
-
-                *s = s.replace(&to_replace, &replacement)
-            } else {
-                let id = &s[mention_start..mention_end].to_string();
-
-                if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit) {
-                    let code_start = if has_exclamation { "<@!" } else { "<@" };
-                    let to_replace = format!("{}{}>", code_start, id);
-
-                    *s = s.replace(&to_replace, &"@invalid-user");
-                } else {
-                    progress = mention_end;
-                }
-            }
-        } else {
-            break;
-        }
-    }
-}
-
-/// Transforms role, channel, user, `@everyone` and `@here` mentions
-/// into raw text by using the [`Cache`] only.
-///
-/// [`ContentSafeOptions`] decides what kind of mentions should be filtered
-/// and how the raw-text will be displayed.
-///
-/// # Examples
-///
-/// Sanitise an `@everyone` mention.
-///
-/// ```rust
-/// # use std::sync::Arc;
-/// # use serenity::client::Cache;
-/// # use tokio::sync::RwLock;
-/// #
-/// # async fn run() {
-/// # let cache = Cache::default();
-/// use serenity::utils::{
-///     content_safe,
-///     ContentSafeOptions,
-/// };
-///
-/// let with_mention = "@everyone";
-/// let without_mention = content_safe(&cache, &with_mention, &ContentSafeOptions::default()).await;
-///
-/// assert_eq!("@\u{200B}everyone".to_string(), without_mention);
-/// # }
-/// ```
-///
-/// [`ContentSafeOptions`]: struct.ContentSafeOptions.html
-/// [`Cache`]: ../cache/struct.Cache.html
-#[cfg(feature = "cache")]
-pub async fn content_safe(cache: impl AsRef<Cache>, s: impl AsRef<str>, options: &ContentSafeOptions) -> String {
-    let mut content = s.as_ref().to_string();
-
-    if options.clean_role {
-        clean_roles(&cache, &mut content).await;
-    }
-
-    if options.clean_channel {
-        clean_channels(&cache, &mut content).await;
-    }
-
-    if options.clean_user {
-        clean_users(&cache, &mut content, options.show_discriminator, options.guild_reference).await;
-    }
-
-    if options.clean_here {
-        content = content.replace("@here", "@\u{200B}here");
-    }
-
-    if options.clean_everyone {
-        content = content.replace("@everyone", "@\u{200B}everyone");
-    }
-
-    content
-}
-
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "cache")]
-    use crate::cache::Cache;
-
     use super::*;
 
     #[test]
@@ -818,210 +535,4 @@ mod test {
         let parsed = parse_quotes("a \"b c\" d\"e f\"  g");
         assert_eq!(parsed, ["a", "b c", "d", "e f", "g"]);
     }
-
-    #[cfg(feature = "cache")]
-    #[tokio::test]
-    async fn test_content_safe() {
-        use crate::model::{
-            user::User,
-            Permissions,
-            prelude::*,
-        };
-        use chrono::{DateTime, Utc};
-        use std::{
-            collections::HashMap,
-            sync::Arc,
-        };
-
-        let user = User {
-            id: UserId(100000000000000000),
-            avatar: None,
-            bot: false,
-            discriminator: 0000,
-            name: "Crab".to_string(),
-            _nonexhaustive: (),
-        };
-
-        let mut guild = Guild {
-            afk_channel_id: None,
-            afk_timeout: 0,
-            application_id: None,
-            channels: HashMap::new(),
-            default_message_notifications: DefaultMessageNotificationLevel::All,
-            emojis: HashMap::new(),
-            explicit_content_filter: ExplicitContentFilter::None,
-            features: Vec::new(),
-            icon: None,
-            id: GuildId(381880193251409931),
-            joined_at: DateTime::parse_from_str(
-                "1983 Apr 13 12:09:14.274 +0000",
-                "%Y %b %d %H:%M:%S%.3f %z").unwrap()
-                .with_timezone(&Utc),
-            large: false,
-            member_count: 1,
-            members: HashMap::new(),
-            mfa_level: MfaLevel::None,
-            name: "serenity".to_string(),
-            owner_id: UserId(114941315417899012),
-            presences: HashMap::new(),
-            region: "Ferris Island".to_string(),
-            roles: HashMap::new(),
-            splash: None,
-            system_channel_id: None,
-            verification_level: VerificationLevel::None,
-            voice_states: HashMap::new(),
-            description: None,
-            premium_tier: PremiumTier::Tier0,
-            premium_subscription_count: 0,
-            banner: None,
-            vanity_url_code: Some("bruhmoment1".to_string()),
-            preferred_locale: "en-US".to_string(),
-            _nonexhaustive: (),
-        };
-
-        let member = Member {
-            deaf: false,
-            guild_id: guild.id,
-            joined_at: None,
-            mute: false,
-            nick: Some("Ferris".to_string()),
-            roles: Vec::new(),
-            user: user.clone(),
-            _nonexhaustive: (),
-        };
-
-        let role = Role {
-            id: RoleId(333333333333333333),
-            colour: Colour::ORANGE,
-            guild_id: guild.id,
-            hoist: true,
-            managed: false,
-            mentionable: true,
-            name: "ferris-club-member".to_string(),
-            permissions: Permissions::all(),
-            position: 0,
-            _nonexhaustive: (),
-        };
-
-        let channel = GuildChannel {
-            id: ChannelId(111880193700067777),
-            bitrate: None,
-            category_id: None,
-            guild_id: guild.id,
-            kind: ChannelType::Text,
-            last_message_id: None,
-            last_pin_timestamp: None,
-            name: "general".to_string(),
-            permission_overwrites: Vec::new(),
-            position: 0,
-            topic: None,
-            user_limit: None,
-            nsfw: false,
-            slow_mode_rate: Some(0),
-            _nonexhaustive: (),
-        };
-
-        let cache = Arc::new(Cache::default());
-
-        guild.members.insert(user.id, member.clone());
-        guild.roles.insert(role.id, role.clone());
-        cache.users.write().await.insert(user.id, user.clone());
-        cache.guilds.write().await.insert(guild.id, guild.clone());
-        cache.channels.write().await.insert(channel.id, channel.clone());
-
-
-        let with_user_metions = "<@!100000000000000000> <@!000000000000000000> <@123> <@!123> \
-        <@!123123123123123123123> <@123> <@123123123123123123> <@!invalid> \
-        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
-        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
-        <@123invalid> <@> <@ ";
-
-        let without_user_mentions = "@Crab#0000 @invalid-user @invalid-user @invalid-user \
-        @invalid-user @invalid-user @invalid-user <@!invalid> \
-        <@invalid> <@日本語 한국어$§)[/__#\\(/&2032$§#> \
-        <@!i)/==(<<>z/9080)> <@!1231invalid> <@invalid123> \
-        <@123invalid> <@> <@ ";
-
-        // User mentions
-        let options = ContentSafeOptions::default();
-        assert_eq!(without_user_mentions, content_safe(&cache, with_user_metions, &options).await);
-
-        let options = ContentSafeOptions::default();
-        assert_eq!(format!("@{}#{:04}", user.name, user.discriminator),
-            content_safe(&cache, "<@!100000000000000000>", &options).await);
-
-        let options = ContentSafeOptions::default();
-        assert_eq!(format!("@{}#{:04}", user.name, user.discriminator),
-            content_safe(&cache, "<@100000000000000000>", &options).await);
-
-        let options = options.show_discriminator(false);
-        assert_eq!(format!("@{}", user.name),
-            content_safe(&cache, "<@!100000000000000000>", &options).await);
-
-        let options = options.show_discriminator(false);
-        assert_eq!(format!("@{}", user.name),
-            content_safe(&cache, "<@100000000000000000>", &options).await);
-
-        let options = options.display_as_member_from(guild.id);
-        assert_eq!(format!("@{}", member.nick.unwrap()),
-            content_safe(&cache, "<@!100000000000000000>", &options).await);
-
-        let options = options.clean_user(false);
-        assert_eq!(with_user_metions,
-            content_safe(&cache, with_user_metions, &options).await);
-
-        // Channel mentions
-        let with_channel_mentions = "<#> <#deleted-channel> #deleted-channel <#0> \
-        #unsafe-club <#111880193700067777> <#ferrisferrisferris> \
-        <#000000000000000000>";
-
-        let without_channel_mentions = "<#> <#deleted-channel> #deleted-channel \
-        #deleted-channel #unsafe-club #general <#ferrisferrisferris> \
-        #deleted-channel";
-
-        assert_eq!(without_channel_mentions,
-            content_safe(&cache, with_channel_mentions, &options).await);
-
-        let options = options.clean_channel(false);
-        assert_eq!(with_channel_mentions,
-            content_safe(&cache, with_channel_mentions, &options).await);
-
-        // Role mentions
-        let with_role_mentions = "<@&> @deleted-role <@&9829> \
-        <@&333333333333333333> <@&000000000000000000>";
-
-        let without_role_mentions = "<@&> @deleted-role @deleted-role \
-        @ferris-club-member @deleted-role";
-
-        assert_eq!(without_role_mentions,
-            content_safe(&cache, with_role_mentions, &options).await);
-
-        let options = options.clean_role(false);
-        assert_eq!(with_role_mentions,
-            content_safe(&cache, with_role_mentions, &options).await);
-
-        // Everyone mentions
-        let with_everyone_mention = "@everyone";
-
-        let without_everyone_mention = "@\u{200B}everyone";
-
-        assert_eq!(without_everyone_mention,
-            content_safe(&cache, with_everyone_mention, &options).await);
-
-        let options = options.clean_everyone(false);
-        assert_eq!(with_everyone_mention,
-            content_safe(&cache, with_everyone_mention, &options).await);
-
-        // Here mentions
-        let with_here_mention = "@here";
-
-        let without_here_mention = "@\u{200B}here";
-
-        assert_eq!(without_here_mention,
-            content_safe(&cache, with_here_mention, &options).await);
-
-        let options = options.clean_here(false);
-        assert_eq!(with_here_mention,
-            content_safe(&cache, with_here_mention, &options).await);
-    }
 }
@@ -0,0 +1,577 @@
+use crate::builder::{
+    CreateComponents,
+    CreateEmbed,
+    CreateInteractionResponseData,
+    EditMessage,
+};
+use crate::client::bridge::gateway::ShardMessenger;
+use crate::collector::{EventCollectorBuilder, ReactionCollectorBuilder};
+use crate::http::Http;
+use crate::internal::prelude::*;
+use crate::model::{
+    channel::{Message, ReactionType},
+    event::{Event, EventType},
+    id::{ChannelId, UserId},
+    interactions::{ButtonStyle, InteractionData, InteractionResponseType},
+};
+use futures::stream::StreamExt;
+use std::{fmt, sync::Arc, time::Duration};
+
+const CUSTOM_ID_PREFIX: &str = "serenity_paginator:";
+
+/// A page shown by a [`Paginator`], consisting of a message content, an
+/// embed, or both.
+///
+/// [`Paginator`]: struct.Paginator.html
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct Page {
+    /// The content of the message showing the page.
+    pub content: Option<String>,
+    /// The embed of the message showing the page.
+    pub embed: Option<CreateEmbed>,
+}
+
+impl From<CreateEmbed> for Page {
+    fn from(embed: CreateEmbed) -> Self {
+        Page {
+            content: None,
+            embed: Some(embed),
+        }
+    }
+}
+
+impl From<String> for Page {
+    fn from(content: String) -> Self {
+        Page {
+            content: Some(content),
+            embed: None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Page {
+    fn from(content: &'a str) -> Self {
+        Page::from(content.to_string())
+    }
+}
+
+/// How users navigate between the pages of a [`Paginator`].
+///
+/// [`Paginator`]: struct.Paginator.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PaginatorControls {
+    /// Buttons below the message.
+    Buttons,
+    /// Reactions to the message, e.g. where the bot can not use buttons.
+    ///
+    /// The bot removes the reactions of users navigating if it has the
+    /// [Manage Messages] permission, otherwise users have to remove their
+    /// reaction before reacting with the same emoji again.
+    ///
+    /// [Manage Messages]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    Reactions,
+}
+
+impl Default for PaginatorControls {
+    fn default() -> Self {
+        PaginatorControls::Buttons
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Action {
+    First,
+    Previous,
+    Next,
+    Last,
+    Stop,
+}
+
+impl Action {
+    const ALL: [Action; 5] = [Action::First, Action::Previous, Action::Next, Action::Last, Action::Stop];
+
+    fn emoji(self) -> char {
+        match self {
+            Action::First => '⏮',
+            Action::Previous => '◀',
+            Action::Next => '▶',
+            Action::Last => '⏭',
+            Action::Stop => '⏹',
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Action::First => "first",
+            Action::Previous => "previous",
+            Action::Next => "next",
+            Action::Last => "last",
+            Action::Stop => "stop",
+        }
+    }
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        if !custom_id.starts_with(CUSTOM_ID_PREFIX) {
+            return None;
+        }
+
+        let name = &custom_id[CUSTOM_ID_PREFIX.len()..];
+
+        Action::ALL.iter().copied().find(|action| action.name() == name)
+    }
+
+    fn from_emoji(emoji: &ReactionType) -> Option<Self> {
+        match emoji {
+            ReactionType::Unicode(unicode) => {
+                // Clients may append a variation selector to the emoji.
+                let unicode = unicode.trim_end_matches('\u{FE0F}');
+
+                Action::ALL.iter().copied().find(|action| unicode.chars().eq(Some(action.emoji())))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the page navigated to from the page at `index`.
+    fn navigate(self, index: usize, count: usize) -> usize {
+        match self {
+            Action::First => 0,
+            Action::Previous => index.saturating_sub(1),
+            Action::Next => (index + 1).min(count - 1),
+            Action::Last => count - 1,
+            Action::Stop => index,
+        }
+    }
+}
+
+type Render = Arc<dyn Fn(&mut Page, usize, usize) + Send + Sync>;
+
+/// Sends a message showing one of several pages, letting users navigate
+/// between the pages with buttons or reactions.
+///
+/// The paginator handles navigating until no user navigated for the
+/// [`timeout`], or the stop control was used. Afterwards, the controls are
+/// removed from the message, or the message is deleted if
+/// [`delete_on_timeout`] is set.
+///
+/// # Examples
+///
+/// Show the pages of a list, numbering them in the footer of their embed:
+///
+/// ```rust,no_run
+/// # use serenity::model::channel::Message;
+/// # use serenity::prelude::*;
+/// #
+/// # async fn run(ctx: Context, msg: Message) -> Result<(), Box<dyn std::error::Error>> {
+/// use serenity::builder::CreateEmbed;
+/// use serenity::utils::Paginator;
+/// use std::time::Duration;
+///
+/// let pages = ["Apples", "Bananas", "Cherries"].iter().map(|fruit| {
+///     let mut embed = CreateEmbed::default();
+///     embed.title(fruit);
+///     embed
+/// });
+///
+/// Paginator::new(pages)
+///     .author_id(msg.author.id)
+///     .timeout(Duration::from_secs(60))
+///     .render(|page, index, count| {
+///         if let Some(embed) = &mut page.embed {
+///             embed.footer(|f| f.text(format!("Page {}/{}", index + 1, count)));
+///         }
+///     })
+///     .send(&ctx, msg.channel_id)
+///     .await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`delete_on_timeout`]: #method.delete_on_timeout
+/// [`timeout`]: #method.timeout
+#[derive(Clone)]
+pub struct Paginator {
+    pages: Vec<Page>,
+    controls: PaginatorControls,
+    author_id: Option<UserId>,
+    start: usize,
+    timeout: Duration,
+    delete_on_timeout: bool,
+    render: Option<Render>,
+}
+
+impl Paginator {
+    /// Creates a paginator showing the given pages, such as embeds or
+    /// strings.
+    pub fn new<P: Into<Page>, It: IntoIterator<Item=P>>(pages: It) -> Self {
+        Paginator {
+            pages: pages.into_iter().map(Into::into).collect(),
+            controls: PaginatorControls::default(),
+            author_id: None,
+            start: 0,
+            timeout: Duration::from_secs(120),
+            delete_on_timeout: false,
+            render: None,
+        }
+    }
+
+    /// Sets how users navigate between the pages.
+    ///
+    /// Defaults to [`PaginatorControls::Buttons`].
+    ///
+    /// [`PaginatorControls::Buttons`]: enum.PaginatorControls.html#variant.Buttons
+    pub fn controls(mut self, controls: PaginatorControls) -> Self {
+        self.controls = controls;
+
+        self
+    }
+
+    /// Sets the only user allowed to navigate, e.g. the author of the command
+    /// showing the pages.
+    ///
+    /// By default, any user may navigate.
+    pub fn author_id(mut self, author_id: impl Into<UserId>) -> Self {
+        self.author_id = Some(author_id.into());
+
+        self
+    }
+
+    /// Sets the index of the page shown first.
+    ///
+    /// Defaults to the first page.
+    pub fn start_page(mut self, index: usize) -> Self {
+        self.start = index;
+
+        self
+    }
+
+    /// Sets how long the paginator waits for users to navigate, restarting
+    /// whenever they do.
+    ///
+    /// Defaults to 2 minutes.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = duration;
+
+        self
+    }
+
+    /// Sets whether the message is deleted once the [`timeout`] elapsed,
+    /// instead of only removing its controls.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`timeout`]: #method.timeout
+    pub fn delete_on_timeout(mut self, delete: bool) -> Self {
+        self.delete_on_timeout = delete;
+
+        self
+    }
+
+    /// Sets a function modifying every page right before it is shown, given
+    /// the page, its index, and the number of pages.
+    ///
+    /// This allows e.g. adding page numbers.
+    pub fn render<F>(mut self, render: F) -> Self
+    where F: Fn(&mut Page, usize, usize) + Send + Sync + 'static {
+        self.render = Some(Arc::new(render));
+
+        self
+    }
+
+    /// Sends the message showing the pages to the channel, and handles
+    /// navigating until the paginator stops.
+    ///
+    /// Returns the sent message, which may have been deleted if
+    /// [`delete_on_timeout`] is set.
+    ///
+    /// **Note**: A single page is sent without any controls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Other`] if there are no pages, or an
+    /// [`Error::Http`] if sending, editing, or reacting to the message fails.
+    ///
+    /// [`Error::Http`]: ../enum.Error.html#variant.Http
+    /// [`Error::Other`]: ../enum.Error.html#variant.Other
+    /// [`delete_on_timeout`]: #method.delete_on_timeout
+    pub async fn send<C>(self, ctx: C, channel_id: impl Into<ChannelId>) -> Result<Message>
+    where C: AsRef<Http> + AsRef<ShardMessenger> {
+        if self.pages.is_empty() {
+            return Err(Error::Other("A paginator requires at least one page"));
+        }
+
+        let http: &Http = ctx.as_ref();
+        let shard_messenger: &ShardMessenger = ctx.as_ref();
+        let channel_id = channel_id.into();
+        let index = self.start.min(self.pages.len() - 1);
+        let page = self.page(index);
+        let controls = if self.pages.len() > 1 { Some(self.controls) } else { None };
+
+        let message = channel_id.send_message(http, |m| {
+            if let Some(content) = page.content {
+                m.content(content);
+            }
+
+            if let Some(embed) = page.embed {
+                m.set_embed(embed);
+            }
+
+            if controls == Some(PaginatorControls::Buttons) {
+                m.set_components(buttons(index, self.pages.len()));
+            }
+
+            m
+        }).await?;
+
+        match controls {
+            Some(PaginatorControls::Buttons) => self.run_buttons(http, shard_messenger, &message, index).await?,
+            Some(PaginatorControls::Reactions) => self.run_reactions(http, shard_messenger, &message, index).await?,
+            None => return Ok(message),
+        }
+
+        if self.delete_on_timeout {
+            message.channel_id.delete_message(http, message.id).await?;
+        }
+
+        Ok(message)
+    }
+
+    async fn run_buttons(
+        &self,
+        http: &Http,
+        shard_messenger: &ShardMessenger,
+        message: &Message,
+        mut index: usize,
+    ) -> Result<()> {
+        let message_id = message.id;
+        let mut collector = EventCollectorBuilder::new(shard_messenger)
+            .add_event_type(EventType::InteractionCreate)
+            .channel_id(message.channel_id)
+            .idle_timeout(self.timeout)
+            .map(move |event| match &*event {
+                Event::InteractionCreate(event) => {
+                    let interaction = &event.interaction;
+
+                    if interaction.message.as_ref().map(|m| m.id) != Some(message_id) {
+                        return None;
+                    }
+
+                    let action = match &interaction.data {
+                        Some(InteractionData::MessageComponent(data)) => Action::from_custom_id(&data.custom_id)?,
+                        _ => return None,
+                    };
+
+                    Some((interaction.clone(), action))
+                },
+                _ => None,
+            })
+            .await;
+
+        while let Some((interaction, action)) = collector.next().await {
+            let is_author = match interaction.user {
+                Some(ref user) => self.is_author(user.id),
+                None => self.author_id.is_none(),
+            };
+
+            if !is_author {
+                interaction.create_interaction_response(http, |r| {
+                    r.interaction_response_data(|d| d.content("These controls are not for you.").ephemeral(true))
+                }).await?;
+
+                continue;
+            }
+
+            if action == Action::Stop {
+                return interaction.create_interaction_response(http, |r| {
+                    r.kind(InteractionResponseType::UpdateMessage)
+                        .interaction_response_data(|d| d.set_components(CreateComponents::default()))
+                }).await;
+            }
+
+            index = action.navigate(index, self.pages.len());
+            let page = self.page(index);
+
+            interaction.create_interaction_response(http, |r| {
+                r.kind(InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|d| {
+                        set_response_page(d, page);
+                        d.set_components(buttons(index, self.pages.len()))
+                    })
+            }).await?;
+        }
+
+        if !self.delete_on_timeout {
+            message.channel_id.edit_message(http, message.id, |m| {
+                m.set_components(CreateComponents::default())
+            }).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn run_reactions(
+        &self,
+        http: &Http,
+        shard_messenger: &ShardMessenger,
+        message: &Message,
+        mut index: usize,
+    ) -> Result<()> {
+        for action in Action::ALL.iter() {
+            message.channel_id.create_reaction(http, message.id, action.emoji()).await?;
+        }
+
+        let bot_id = message.author.id;
+        let mut collector = ReactionCollectorBuilder::new(shard_messenger)
+            .message_id(message.id)
+            .idle_timeout(self.timeout)
+            .filter(move |reaction| reaction.user_id != Some(bot_id));
+
+        if let Some(author_id) = self.author_id {
+            collector = collector.author_id(author_id);
+        }
+
+        let mut collector = collector.await;
+
+        while let Some(action) = collector.next().await {
+            let reaction = action.as_inner_ref();
+            let action = match Action::from_emoji(&reaction.emoji) {
+                Some(action) => action,
+                None => continue,
+            };
+
+            if action == Action::Stop {
+                break;
+            }
+
+            // Missing permissions only keep users from reacting with the
+            // same emoji again, so ignore errors.
+            let _ = message.channel_id
+                .delete_reaction(http, message.id, reaction.user_id, reaction.emoji.clone())
+                .await;
+
+            index = action.navigate(index, self.pages.len());
+            let page = self.page(index);
+
+            message.channel_id.edit_message(http, message.id, |m| set_edit_page(m, page)).await?;
+        }
+
+        if !self.delete_on_timeout {
+            // Reactions can not be removed in private channels.
+            let _ = http.delete_message_reactions(message.channel_id.0, message.id.0).await;
+        }
+
+        Ok(())
+    }
+
+    fn is_author(&self, user_id: UserId) -> bool {
+        self.author_id.map_or(true, |author_id| author_id == user_id)
+    }
+
+    /// Returns the page at `index`, as modified by the [`render`] function.
+    ///
+    /// [`render`]: #method.render
+    fn page(&self, index: usize) -> Page {
+        let mut page = self.pages[index].clone();
+
+        if let Some(render) = &self.render {
+            render(&mut page, index, self.pages.len());
+        }
+
+        page
+    }
+}
+
+impl fmt::Debug for Paginator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("pages", &self.pages)
+            .field("controls", &self.controls)
+            .field("author_id", &self.author_id)
+            .field("start", &self.start)
+            .field("timeout", &self.timeout)
+            .field("delete_on_timeout", &self.delete_on_timeout)
+            .field("render", &self.render.as_ref().map(|_| "Arc<dyn Fn(&mut Page, usize, usize) + Send + Sync>"))
+            .finish()
+    }
+}
+
+/// Creates the navigation buttons for the page at `index`.
+fn buttons(index: usize, count: usize) -> CreateComponents {
+    let mut components = CreateComponents::default();
+
+    components.create_action_row(|row| {
+        for action in Action::ALL.iter() {
+            let disabled = match action {
+                Action::First | Action::Previous => index == 0,
+                Action::Next | Action::Last => index + 1 >= count,
+                Action::Stop => false,
+            };
+            let style = if *action == Action::Stop { ButtonStyle::Danger } else { ButtonStyle::Secondary };
+
+            row.create_button(|b| {
+                b.style(style)
+                    .emoji(action.emoji())
+                    .custom_id(format!("{}{}", CUSTOM_ID_PREFIX, action.name()))
+                    .disabled(disabled)
+            });
+        }
+
+        row
+    });
+
+    components
+}
+
+/// Replaces the content and embed of a message with the page.
+fn set_response_page(data: &mut CreateInteractionResponseData, page: Page) {
+    data.content(page.content.unwrap_or_default());
+    data.set_embeds(page.embed.into_iter().collect());
+}
+
+/// Replaces the content and embed of a message with the page.
+fn set_edit_page<'a, 'b>(m: &'b mut EditMessage<'a>, page: Page) -> &'b mut EditMessage<'a> {
+    m.content(page.content.unwrap_or_default());
+
+    match page.embed {
+        Some(embed) => m.embed(|e| {
+            *e = embed;
+            e
+        }),
+        None => {
+            m.0.insert("embeds", Value::Array(Vec::new()));
+            m
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigates() {
+        assert_eq!(Action::Next.navigate(0, 3), 1);
+        assert_eq!(Action::Next.navigate(2, 3), 2);
+        assert_eq!(Action::Previous.navigate(0, 3), 0);
+        assert_eq!(Action::Last.navigate(0, 3), 2);
+        assert_eq!(Action::First.navigate(2, 3), 0);
+        assert_eq!(Action::Stop.navigate(1, 3), 1);
+    }
+
+    #[test]
+    fn parses_controls() {
+        for action in Action::ALL.iter() {
+            let custom_id = format!("{}{}", CUSTOM_ID_PREFIX, action.name());
+
+            assert_eq!(Action::from_custom_id(&custom_id), Some(*action));
+            assert_eq!(Action::from_emoji(&ReactionType::from(action.emoji())), Some(*action));
+        }
+
+        let with_variation_selector = ReactionType::Unicode("▶\u{FE0F}".to_string());
+
+        assert_eq!(Action::from_emoji(&with_variation_selector), Some(Action::Next));
+        assert_eq!(Action::from_custom_id("next"), None);
+    }
+}
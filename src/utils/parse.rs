@@ -0,0 +1,302 @@
+use crate::model::{
+    id::{ChannelId, RoleId, UserId},
+    misc::EmojiIdentifier,
+    timestamp::{Timestamp, TimestampStyle},
+};
+use std::ops::Range;
+use super::{parse_channel, parse_emoji, parse_role, parse_username};
+
+#[cfg(all(feature = "cache", feature = "model"))]
+use async_trait::async_trait;
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::cache::Cache;
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::model::id::GuildId;
+#[cfg(all(feature = "cache", feature = "model"))]
+use std::{error::Error as StdError, fmt};
+
+/// A mention found in the content of a message.
+///
+/// Refer to [`parse_mentions`] for finding the mentions in a message.
+///
+/// [`parse_mentions`]: fn.parse_mentions.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Mention {
+    /// A mention of a user, e.g. `<@114941315417899012>`.
+    User(UserId),
+    /// A mention of a role, e.g. `<@&136107769680887808>`.
+    Role(RoleId),
+    /// A mention of a channel, e.g. `<#81384788765712384>`.
+    Channel(ChannelId),
+    /// A custom emoji, e.g. `<:smugAnimeFace:302516740095606785>`.
+    Emoji(EmojiIdentifier),
+    /// A timestamp displayed in the local time of the reader, e.g.
+    /// `<t:1618953630:R>`.
+    Timestamp {
+        /// The point in time.
+        time: Timestamp,
+        /// The style the timestamp is displayed with, if given.
+        style: Option<TimestampStyle>,
+    },
+}
+
+/// An iterator over the mentions in the content of a message, along with the
+/// range of bytes they occupy in the content.
+///
+/// Created by [`parse_mentions`].
+///
+/// [`parse_mentions`]: fn.parse_mentions.html
+#[derive(Clone, Debug)]
+pub struct Mentions<'a> {
+    content: &'a str,
+    position: usize,
+}
+
+impl<'a> Iterator for Mentions<'a> {
+    type Item = (Range<usize>, Mention);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.content[self.position..].find('<') {
+            let start = self.position + offset;
+            // Mentions are enclosed in `<>` and never contain a `<`, so
+            // continue with the next one if this one is invalid.
+            self.position = start + 1;
+
+            let end = match self.content[start..].find('>') {
+                Some(len) => start + len + 1,
+                None => return None,
+            };
+
+            if let Some(mention) = parse_single_mention(&self.content[start..end]) {
+                self.position = end;
+
+                return Some((start..end, mention));
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns an iterator over the user, role, channel, emoji and timestamp
+/// mentions in the content of a message, in the order they appear in.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::model::id::{RoleId, UserId};
+/// use serenity::utils::{parse_mentions, Mention};
+///
+/// let content = "Hey <@114941315417899012>, you are now a <@&136107769680887808>!";
+/// let mentions = parse_mentions(content).collect::<Vec<_>>();
+///
+/// assert_eq!(mentions, vec![
+///     (4..25, Mention::User(UserId(114941315417899012))),
+///     (41..63, Mention::Role(RoleId(136107769680887808))),
+/// ]);
+/// ```
+pub fn parse_mentions(content: &str) -> Mentions<'_> {
+    Mentions {
+        content,
+        position: 0,
+    }
+}
+
+fn parse_single_mention(mention: &str) -> Option<Mention> {
+    if mention.starts_with("<@&") {
+        parse_role(mention).map(|id| Mention::Role(RoleId(id)))
+    } else if mention.starts_with("<@") {
+        parse_username(mention).map(|id| Mention::User(UserId(id)))
+    } else if mention.starts_with("<#") {
+        parse_channel(mention).map(|id| Mention::Channel(ChannelId(id)))
+    } else if mention.starts_with("<t:") {
+        parse_timestamp(mention)
+    } else {
+        parse_emoji(mention).map(Mention::Emoji)
+    }
+}
+
+fn parse_timestamp(mention: &str) -> Option<Mention> {
+    let inner = mention.get(3..mention.len() - 1)?;
+    let mut parts = inner.splitn(2, ':');
+
+    let seconds = parts.next()?.parse::<i64>().ok()?;
+    let style = match parts.next() {
+        Some(style) => {
+            let mut chars = style.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(TimestampStyle::from_char(c)?),
+                _ => return None,
+            }
+        },
+        None => None,
+    };
+
+    Some(Mention::Timestamp {
+        time: Timestamp::from_unix_timestamp(seconds)?,
+        style,
+    })
+}
+
+/// Converts an argument of a command into a value, looking up names in the
+/// cache.
+///
+/// Unlike [`FromStr`], which only accepts Ids and mentions, this also accepts
+/// the names of users, channels and roles in the guild the argument was given
+/// in.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::model::id::{GuildId, RoleId};
+/// use serenity::prelude::*;
+/// use serenity::utils::ArgumentConvert;
+///
+/// # async fn run(ctx: Context, guild_id: GuildId) {
+/// let role = RoleId::convert(&ctx.cache, Some(guild_id), "Moderators").await;
+/// # }
+/// ```
+///
+/// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+#[cfg(all(feature = "cache", feature = "model"))]
+#[async_trait]
+pub trait ArgumentConvert: Sized {
+    /// The error returned if the argument can not be converted.
+    type Err;
+
+    /// Converts the argument, looking up names in the guild with the given Id,
+    /// if any.
+    async fn convert<C>(cache: C, guild_id: Option<GuildId>, s: &str) -> Result<Self, Self::Err>
+        where C: AsRef<Cache> + Send + Sync;
+}
+
+/// An error returned when an argument can not be converted with
+/// [`ArgumentConvert`].
+///
+/// [`ArgumentConvert`]: trait.ArgumentConvert.html
+#[cfg(all(feature = "cache", feature = "model"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ArgumentConvertError {
+    /// The argument is neither an Id, a mention, nor a name found in the
+    /// cached guild.
+    NotFound,
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+impl fmt::Display for ArgumentConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentConvertError::NotFound => f.write_str("not found"),
+        }
+    }
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+impl StdError for ArgumentConvertError {}
+
+/// Accepts an Id, a mention, or a member's `name#discriminator`, username or
+/// nickname.
+#[cfg(all(feature = "cache", feature = "model"))]
+#[async_trait]
+impl ArgumentConvert for UserId {
+    type Err = ArgumentConvertError;
+
+    async fn convert<C>(cache: C, guild_id: Option<GuildId>, s: &str) -> Result<Self, Self::Err>
+        where C: AsRef<Cache> + Send + Sync
+    {
+        if let Ok(id) = s.parse() {
+            return Ok(id);
+        }
+
+        let guild_id = guild_id.ok_or(ArgumentConvertError::NotFound)?;
+
+        cache.as_ref()
+            .guild_field(guild_id, |guild| guild.member_named(s).map(|member| member.user.id))
+            .await
+            .flatten()
+            .ok_or(ArgumentConvertError::NotFound)
+    }
+}
+
+/// Accepts an Id, a mention, or the name of a channel, with or without a
+/// leading `#`.
+#[cfg(all(feature = "cache", feature = "model"))]
+#[async_trait]
+impl ArgumentConvert for ChannelId {
+    type Err = ArgumentConvertError;
+
+    async fn convert<C>(cache: C, guild_id: Option<GuildId>, s: &str) -> Result<Self, Self::Err>
+        where C: AsRef<Cache> + Send + Sync
+    {
+        if let Ok(id) = s.parse() {
+            return Ok(id);
+        }
+
+        let guild_id = guild_id.ok_or(ArgumentConvertError::NotFound)?;
+        let name = s.trim_start_matches('#');
+
+        cache.as_ref()
+            .guild_field(guild_id, |guild| {
+                guild.channels
+                    .values()
+                    .find(|channel| channel.name.eq_ignore_ascii_case(name))
+                    .map(|channel| channel.id)
+            })
+            .await
+            .flatten()
+            .ok_or(ArgumentConvertError::NotFound)
+    }
+}
+
+/// Accepts an Id, a mention, or the name of a role.
+#[cfg(all(feature = "cache", feature = "model"))]
+#[async_trait]
+impl ArgumentConvert for RoleId {
+    type Err = ArgumentConvertError;
+
+    async fn convert<C>(cache: C, guild_id: Option<GuildId>, s: &str) -> Result<Self, Self::Err>
+        where C: AsRef<Cache> + Send + Sync
+    {
+        if let Ok(id) = s.parse() {
+            return Ok(id);
+        }
+
+        let guild_id = guild_id.ok_or(ArgumentConvertError::NotFound)?;
+
+        cache.as_ref()
+            .guild_field(guild_id, |guild| {
+                guild.roles
+                    .values()
+                    .find(|role| role.name.eq_ignore_ascii_case(s))
+                    .map(|role| role.id)
+            })
+            .await
+            .flatten()
+            .ok_or(ArgumentConvertError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::id::{ChannelId, EmojiId, UserId};
+    use crate::model::timestamp::{Timestamp, TimestampStyle};
+    use super::{parse_mentions, Mention};
+
+    #[test]
+    fn finds_mentions() {
+        let content = "<<@!1> in <#2>, <:rust:3> <t:1618953630:R> <t:1:x> <@a> <#4";
+        let mentions = parse_mentions(content).collect::<Vec<_>>();
+
+        assert_eq!(mentions.len(), 4);
+        assert_eq!(mentions[0], (1..6, Mention::User(UserId(1))));
+        assert_eq!(mentions[1], (10..14, Mention::Channel(ChannelId(2))));
+        assert!(matches!(&mentions[2].1, Mention::Emoji(emoji) if emoji.id == EmojiId(3)));
+        assert_eq!(mentions[3].1, Mention::Timestamp {
+            time: Timestamp::from_unix_timestamp(1618953630).unwrap(),
+            style: Some(TimestampStyle::RelativeTime),
+        });
+    }
+}
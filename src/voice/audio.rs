@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+/// A source of audio to send over a voice [`Connection`].
+///
+/// Audio is sent as Opus frames, each holding 20ms of 48kHz stereo audio, so
+/// no encoder is needed to play pre-encoded audio. Iterators over frames are
+/// sources themselves.
+///
+/// # Examples
+///
+/// Play frames read ahead of time:
+///
+/// ```rust,no_run
+/// # use serenity::voice::Connection;
+/// #
+/// # async fn run(connection: &mut Connection, frames: Vec<Vec<u8>>) -> serenity::Result<()> {
+/// connection.play(&mut frames.into_iter()).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Connection`]: struct.Connection.html
+#[async_trait]
+pub trait AudioSource: Send {
+    /// Returns the next Opus frame, or `None` once the source ended.
+    async fn next_frame(&mut self) -> Option<Vec<u8>>;
+}
+
+#[async_trait]
+impl<I> AudioSource for I
+where I: Iterator<Item = Vec<u8>> + Send {
+    async fn next_frame(&mut self) -> Option<Vec<u8>> {
+        self.next()
+    }
+}
@@ -0,0 +1,441 @@
+use async_tungstenite::tungstenite::{protocol::CloseFrame, Message};
+use crate::gateway::WsStream;
+use crate::internal::prelude::*;
+use crate::internal::ws_impl::{ReceiverExt, SenderExt};
+#[cfg(all(feature = "rustls_backend", not(feature = "native_tls_backend")))]
+use crate::internal::ws_impl::create_rustls_client;
+#[cfg(feature = "native_tls_backend")]
+use crate::internal::ws_impl::create_native_tls_client;
+use crate::model::id::{GuildId, UserId};
+use crypto_secretbox::{aead::{Aead, KeyInit}, Nonce, XSalsa20Poly1305};
+use futures::{
+    channel::{mpsc::{self, UnboundedReceiver, UnboundedSender}, oneshot},
+    StreamExt,
+};
+use serenity_voice_model::{
+    constants::GATEWAY_VERSION,
+    id::{GuildId as VoiceGuildId, UserId as VoiceUserId},
+    payload::{Heartbeat, Identify, SelectProtocol, Speaking},
+    Event as VoiceEvent,
+    ProtocolData,
+    SpeakingState,
+};
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr},
+    str,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use super::{AudioSource, VoiceError};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, interval_at, timeout, Duration, Instant};
+use tracing::{debug, instrument};
+use url::Url;
+
+/// The only encryption mode supported by the driver.
+const ENCRYPTION_MODE: &str = "xsalsa20_poly1305";
+
+/// How long the voice server may take for each step of the handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The duration of audio in each Opus frame.
+const FRAME_DURATION: Duration = Duration::from_millis(20);
+
+/// The number of samples per channel in each Opus frame, at 48kHz.
+const SAMPLES_PER_FRAME: u32 = 960;
+
+/// An Opus frame of silence, sent after the audio to keep receivers from
+/// interpolating its end.
+const SILENCE_FRAME: [u8; 3] = [0xf8, 0xff, 0xfe];
+
+const RTP_HEADER_LEN: usize = 12;
+const IP_DISCOVERY_LEN: usize = 74;
+
+/// The information needed to connect to the voice server of a guild,
+/// received from the gateway after joining a voice channel.
+///
+/// The [`VoiceManager`] gathers this for you.
+///
+/// [`VoiceManager`]: struct.VoiceManager.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ConnectionInfo {
+    /// The endpoint of the voice server, from the voice server update.
+    pub endpoint: String,
+    /// The ID of the guild of the voice channel.
+    pub guild_id: GuildId,
+    /// The session ID of the current user, from their voice state update.
+    pub session_id: String,
+    /// The token of the voice server, from the voice server update.
+    pub token: String,
+    /// The ID of the current user.
+    pub user_id: UserId,
+}
+
+impl ConnectionInfo {
+    pub fn new(endpoint: String, guild_id: GuildId, session_id: String, token: String, user_id: UserId) -> Self {
+        ConnectionInfo {
+            endpoint,
+            guild_id,
+            session_id,
+            token,
+            user_id,
+        }
+    }
+}
+
+/// A command for the task owning the voice WebSocket.
+enum WsCommand {
+    Send(VoiceEvent),
+    Close,
+}
+
+/// A connection to the voice server of a guild, sending audio over UDP.
+///
+/// The voice WebSocket is owned by a spawned task, which heartbeats and reads
+/// its messages for as long as the connection is alive, whether audio is
+/// played or not. Dropping the connection closes the WebSocket.
+pub struct Connection {
+    ws_tx: UnboundedSender<WsCommand>,
+    ws_task: JoinHandle<()>,
+    closed: oneshot::Receiver<Error>,
+    udp: UdpSocket,
+    cipher: XSalsa20Poly1305,
+    ssrc: u32,
+    sequence: u16,
+    timestamp: u32,
+}
+
+impl Connection {
+    /// Connects to the voice server, performing the WebSocket handshake, the
+    /// IP discovery over UDP and the negotiation of the encryption key.
+    #[instrument(skip(info))]
+    pub async fn connect(info: &ConnectionInfo) -> Result<Connection> {
+        let mut ws = connect(&info.endpoint).await?;
+
+        send_event(&mut ws, Identify {
+            server_id: VoiceGuildId(info.guild_id.0),
+            session_id: info.session_id.clone(),
+            token: info.token.clone(),
+            user_id: VoiceUserId(info.user_id.0),
+        }.into()).await?;
+
+        let (hello, ready) = timeout(HANDSHAKE_TIMEOUT, async {
+            let mut hello = None;
+            let mut ready = None;
+
+            loop {
+                match recv_event(&mut ws).await? {
+                    Some(VoiceEvent::Hello(event)) => hello = Some(event),
+                    Some(VoiceEvent::Ready(event)) => ready = Some(event),
+                    _ => {},
+                }
+
+                if let (Some(hello), Some(ready)) = (hello, &ready) {
+                    return Ok::<_, Error>((hello, ready.clone()));
+                }
+            }
+        }).await.map_err(|_| VoiceError::ExpectedHandshake)??;
+
+        if !ready.modes.iter().any(|mode| mode == ENCRYPTION_MODE) {
+            return Err(VoiceError::UnsupportedEncryption.into());
+        }
+
+        let mut udp = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+        udp.connect((ready.ip, ready.port)).await?;
+
+        let (address, port) = discover_ip(&mut udp, ready.ssrc).await?;
+        debug!("Discovered external address {}:{}", address, port);
+
+        send_event(&mut ws, SelectProtocol {
+            data: ProtocolData {
+                address,
+                mode: ENCRYPTION_MODE.to_string(),
+                port,
+            },
+            protocol: "udp".to_string(),
+        }.into()).await?;
+
+        let secret_key = timeout(HANDSHAKE_TIMEOUT, async {
+            loop {
+                if let Some(VoiceEvent::SessionDescription(event)) = recv_event(&mut ws).await? {
+                    return Ok::<_, Error>(event.secret_key);
+                }
+            }
+        }).await.map_err(|_| VoiceError::ExpectedHandshake)??;
+
+        let cipher = XSalsa20Poly1305::new_from_slice(&secret_key)
+            .map_err(|_| VoiceError::Encryption)?;
+
+        // Heartbeat early, so a late heartbeat does not time out.
+        let heartbeat_interval = Duration::from_millis((hello.heartbeat_interval * 0.75) as u64);
+        let (ws_tx, ws_rx) = mpsc::unbounded();
+        let (closed_tx, closed) = oneshot::channel();
+
+        let ws_task = tokio::spawn(async move {
+            if let Err(why) = run_ws(ws, ws_rx, heartbeat_interval).await {
+                debug!("Voice connection closed: {:?}", why);
+
+                let _ = closed_tx.send(why);
+            }
+        });
+
+        Ok(Connection {
+            ws_tx,
+            ws_task,
+            closed,
+            udp,
+            cipher,
+            ssrc: ready.ssrc,
+            sequence: 0,
+            timestamp: 0,
+        })
+    }
+
+    /// Plays the audio of the source until it ends, or until the voice
+    /// connection closes.
+    pub async fn play<S: AudioSource + ?Sized>(&mut self, source: &mut S) -> Result<()> {
+        self.set_speaking(true).await?;
+
+        let mut frames = interval(FRAME_DURATION);
+
+        while let Some(frame) = source.next_frame().await {
+            frames.tick().await;
+            self.check_closed()?;
+            self.send_frame(&frame).await?;
+        }
+
+        for _ in 0..5 {
+            frames.tick().await;
+            self.send_frame(&SILENCE_FRAME).await?;
+        }
+
+        self.set_speaking(false).await
+    }
+
+    /// Sends a single Opus frame of 20ms of audio.
+    ///
+    /// Prefer [`play`], which paces the frames.
+    ///
+    /// [`play`]: #method.play
+    pub async fn send_frame(&mut self, frame: &[u8]) -> Result<()> {
+        let header = rtp_header(self.sequence, self.timestamp, self.ssrc);
+        let packet = encrypt_packet(&self.cipher, header, frame)?;
+
+        self.udp.send(&packet).await?;
+
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(SAMPLES_PER_FRAME);
+
+        Ok(())
+    }
+
+    /// Sets whether the current user is shown as speaking.
+    pub async fn set_speaking(&mut self, speaking: bool) -> Result<()> {
+        let state = if speaking { SpeakingState::MICROPHONE } else { SpeakingState::empty() };
+
+        self.check_closed()?;
+
+        let event = Speaking {
+            delay: Some(0),
+            speaking: state,
+            ssrc: self.ssrc,
+            user_id: None,
+        }.into();
+
+        self.ws_tx.unbounded_send(WsCommand::Send(event))
+            .map_err(|_| VoiceError::Closed(None).into())
+    }
+
+    /// Returns the error the voice WebSocket closed with, if it closed.
+    fn check_closed(&mut self) -> Result<()> {
+        match self.closed.try_recv() {
+            Ok(None) => Ok(()),
+            Ok(Some(why)) => Err(why),
+            Err(_) => Err(VoiceError::Closed(None).into()),
+        }
+    }
+
+    /// Closes the voice WebSocket.
+    ///
+    /// This does not leave the voice channel, which is done through the
+    /// gateway, e.g. with [`VoiceManager::leave`].
+    ///
+    /// [`VoiceManager::leave`]: struct.VoiceManager.html#method.leave
+    pub async fn disconnect(mut self) -> Result<()> {
+        let _ = self.ws_tx.unbounded_send(WsCommand::Close);
+        let _ = (&mut self.ws_task).await;
+
+        match self.closed.try_recv() {
+            Ok(Some(why)) => Err(why),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("ssrc", &self.ssrc)
+            .field("sequence", &self.sequence)
+            .field("timestamp", &self.timestamp)
+            .finish()
+    }
+}
+
+#[cfg(all(feature = "rustls_backend", not(feature = "native_tls_backend")))]
+async fn connect(endpoint: &str) -> Result<WsStream> {
+    create_rustls_client(build_url(endpoint)?).await
+}
+
+#[cfg(feature = "native_tls_backend")]
+async fn connect(endpoint: &str) -> Result<WsStream> {
+    create_native_tls_client(build_url(endpoint)?).await
+}
+
+fn build_url(endpoint: &str) -> Result<Url> {
+    // The endpoint may carry a port that is not used by the WebSocket.
+    let host = endpoint.trim_end_matches(":80");
+
+    Url::parse(&format!("wss://{}/?v={}", host, GATEWAY_VERSION))
+        .map_err(|_| Error::Url(endpoint.to_string()))
+}
+
+async fn send_event(ws: &mut WsStream, event: VoiceEvent) -> Result<()> {
+    ws.send_json(&serde_json::to_value(event)?).await
+}
+
+/// Heartbeats over the voice WebSocket and sends the events of the
+/// connection, until it is disconnected, dropped or closed by the server.
+///
+/// The received messages are not needed to send audio, but must be read for
+/// tungstenite to answer pings and notice the connection closing.
+async fn run_ws(mut ws: WsStream, mut commands: UnboundedReceiver<WsCommand>, heartbeat_interval: Duration) -> Result<()> {
+    let mut heartbeats = interval_at(Instant::now() + heartbeat_interval, heartbeat_interval);
+
+    loop {
+        tokio::select! {
+            _ = heartbeats.tick() => {
+                let nonce = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |since| since.as_millis() as u64);
+
+                send_event(&mut ws, Heartbeat { nonce }.into()).await?;
+            },
+            command = commands.next() => match command {
+                Some(WsCommand::Send(event)) => send_event(&mut ws, event).await?,
+                Some(WsCommand::Close) | None => {
+                    ws.close(None).await?;
+
+                    return Ok(());
+                },
+            },
+            message = ws.next() => match message {
+                Some(Ok(Message::Close(frame))) => {
+                    return Err(VoiceError::Closed(frame.map(CloseFrame::into_owned)).into());
+                },
+                Some(Ok(_)) => {},
+                Some(Err(why)) => return Err(why.into()),
+                None => return Err(VoiceError::Closed(None).into()),
+            },
+        }
+    }
+}
+
+/// Receives an event, skipping those the voice model does not know.
+async fn recv_event(ws: &mut WsStream) -> Result<Option<VoiceEvent>> {
+    Ok(ws.recv_json().await?.and_then(|value| serde_json::from_value(value).ok()))
+}
+
+/// Discovers the external address and port of the socket, as seen by the
+/// voice server.
+async fn discover_ip(udp: &mut UdpSocket, ssrc: u32) -> Result<(IpAddr, u16)> {
+    let mut request = [0; IP_DISCOVERY_LEN];
+    request[0..2].copy_from_slice(&1u16.to_be_bytes());
+    request[2..4].copy_from_slice(&70u16.to_be_bytes());
+    request[4..8].copy_from_slice(&ssrc.to_be_bytes());
+
+    udp.send(&request).await?;
+
+    let mut response = [0; IP_DISCOVERY_LEN];
+    let len = timeout(HANDSHAKE_TIMEOUT, udp.recv(&mut response))
+        .await
+        .map_err(|_| VoiceError::ExpectedHandshake)??;
+
+    Ok(parse_ip_discovery(&response[..len]).ok_or(VoiceError::IpDiscovery)?)
+}
+
+fn parse_ip_discovery(response: &[u8]) -> Option<(IpAddr, u16)> {
+    if response.len() != IP_DISCOVERY_LEN || response[0..2] != 2u16.to_be_bytes() {
+        return None;
+    }
+
+    let address = &response[8..72];
+    let end = address.iter().position(|&byte| byte == 0).unwrap_or(address.len());
+    let address = str::from_utf8(&address[..end]).ok()?.parse().ok()?;
+    let port = u16::from_be_bytes([response[72], response[73]]);
+
+    Some((address, port))
+}
+
+fn rtp_header(sequence: u16, timestamp: u32, ssrc: u32) -> [u8; RTP_HEADER_LEN] {
+    let mut header = [0; RTP_HEADER_LEN];
+    // Version 2, without padding, extensions or contributing sources, and
+    // the dynamic payload type Discord uses for Opus.
+    header[0] = 0x80;
+    header[1] = 0x78;
+    header[2..4].copy_from_slice(&sequence.to_be_bytes());
+    header[4..8].copy_from_slice(&timestamp.to_be_bytes());
+    header[8..12].copy_from_slice(&ssrc.to_be_bytes());
+
+    header
+}
+
+/// Encrypts a frame for the `xsalsa20_poly1305` mode, which uses the RTP
+/// header padded with zeroes as the nonce.
+fn encrypt_packet(cipher: &XSalsa20Poly1305, header: [u8; RTP_HEADER_LEN], frame: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce = [0; 24];
+    nonce[..RTP_HEADER_LEN].copy_from_slice(&header);
+
+    let encrypted = cipher.encrypt(&Nonce::from(nonce), frame)
+        .map_err(|_| VoiceError::Encryption)?;
+
+    let mut packet = Vec::with_capacity(RTP_HEADER_LEN + encrypted.len());
+    packet.extend_from_slice(&header);
+    packet.extend_from_slice(&encrypted);
+
+    Ok(packet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ip_discovery() {
+        let mut response = [0; IP_DISCOVERY_LEN];
+        response[0..2].copy_from_slice(&2u16.to_be_bytes());
+        response[8..19].copy_from_slice(b"203.0.113.7");
+        response[72..74].copy_from_slice(&50_000u16.to_be_bytes());
+
+        assert_eq!(parse_ip_discovery(&response), Some(([203, 0, 113, 7].into(), 50_000)));
+        assert_eq!(parse_ip_discovery(&response[..10]), None);
+    }
+
+    #[test]
+    fn encrypts_with_header_nonce() {
+        let cipher = XSalsa20Poly1305::new_from_slice(&[7; 32]).unwrap();
+        let header = rtp_header(1, SAMPLES_PER_FRAME, 42);
+        let packet = encrypt_packet(&cipher, header, &SILENCE_FRAME).unwrap();
+
+        assert_eq!(&packet[..4], &[0x80, 0x78, 0, 1]);
+        assert_eq!(&packet[8..12], &42u32.to_be_bytes());
+
+        let mut nonce = [0; 24];
+        nonce[..RTP_HEADER_LEN].copy_from_slice(&header);
+        let decrypted = cipher.decrypt(&Nonce::from(nonce), &packet[RTP_HEADER_LEN..]);
+
+        assert_eq!(decrypted.as_deref().ok(), Some(&SILENCE_FRAME[..]));
+    }
+}
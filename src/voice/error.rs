@@ -0,0 +1,49 @@
+use async_tungstenite::tungstenite::protocol::CloseFrame;
+use std::{
+    error::Error as StdError,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+/// An error that occurred while connecting to or sending audio over a voice
+/// connection.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The voice connection closed, potentially uncleanly.
+    Closed(Option<CloseFrame<'static>>),
+    /// The audio could not be encrypted.
+    Encryption,
+    /// The voice server did not complete the handshake in time.
+    ExpectedHandshake,
+    /// The response to the IP discovery over UDP was malformed.
+    IpDiscovery,
+    /// Discord did not answer a join request with the voice server and
+    /// session in time.
+    JoinTimeout,
+    /// No shard is registered for the guild, e.g. because the shard is not
+    /// ready yet.
+    NoShard,
+    /// The voice server does not offer the `xsalsa20_poly1305` encryption
+    /// mode.
+    UnsupportedEncryption,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::Closed(_) => f.write_str("Voice connection closed"),
+            Error::Encryption => f.write_str("Failed encrypting audio"),
+            Error::ExpectedHandshake => f.write_str("Expected a voice handshake"),
+            Error::IpDiscovery => f.write_str("Invalid IP discovery response"),
+            Error::JoinTimeout => f.write_str("Timed out joining the voice channel"),
+            Error::NoShard => f.write_str("No shard registered for the guild"),
+            Error::UnsupportedEncryption => f.write_str("No supported encryption mode offered"),
+        }
+    }
+}
+
+impl StdError for Error {}
@@ -0,0 +1,243 @@
+use async_trait::async_trait;
+use crate::client::bridge::gateway::ShardMessenger;
+use crate::client::bridge::voice::VoiceGatewayManager;
+use crate::gateway::InterMessage;
+use crate::internal::prelude::*;
+use crate::model::{
+    id::{ChannelId, GuildId, UserId},
+    voice::VoiceState,
+};
+use futures::channel::{
+    mpsc::UnboundedSender as Sender,
+    oneshot,
+};
+use std::collections::HashMap;
+use super::{Connection, ConnectionInfo, VoiceError};
+use tokio::sync::Mutex;
+use tokio::time::{timeout, Duration};
+
+/// How long Discord may take to answer a join request.
+const JOIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The voice session of a guild being joined, until both the voice state and
+/// voice server of the current user were received.
+#[derive(Debug, Default)]
+struct PendingJoin {
+    session_id: Option<String>,
+    endpoint: Option<String>,
+    token: Option<String>,
+    tx: Option<oneshot::Sender<ConnectionInfo>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    user_id: Option<UserId>,
+    shard_count: u64,
+    shards: HashMap<u64, ShardMessenger>,
+    pending: HashMap<GuildId, PendingJoin>,
+}
+
+/// The built-in [`VoiceGatewayManager`], joining voice channels and
+/// connecting to their voice servers.
+///
+/// # Examples
+///
+/// Register the manager with the client, and play audio in a voice channel:
+///
+/// ```rust,no_run
+/// use serenity::prelude::*;
+/// use serenity::model::id::{ChannelId, GuildId};
+/// use serenity::voice::VoiceManager;
+/// use std::sync::Arc;
+///
+/// # struct Handler;
+/// # impl EventHandler for Handler {}
+/// #
+/// # async fn run(frames: Vec<Vec<u8>>) -> Result<(), Box<dyn std::error::Error>> {
+/// let voice = Arc::new(VoiceManager::new());
+/// let client = Client::builder("token")
+///     .event_handler(Handler)
+///     .voice_manager_arc(voice.clone())
+///     .await?;
+///
+/// // Once the shard of the guild is ready:
+/// let mut connection = voice.join(GuildId(81384788765712384), ChannelId(381880193700069377)).await?;
+/// connection.play(&mut frames.into_iter()).await?;
+/// connection.disconnect().await?;
+/// voice.leave(GuildId(81384788765712384)).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`VoiceGatewayManager`]: ../client/bridge/voice/trait.VoiceGatewayManager.html
+#[derive(Debug, Default)]
+pub struct VoiceManager {
+    state: Mutex<State>,
+}
+
+impl VoiceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Joins a voice channel, or moves to it, and connects to the voice server
+    /// of its guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VoiceError::NoShard`] if the shard of the guild is not ready,
+    /// and [`VoiceError::JoinTimeout`] if Discord did not answer in time,
+    /// e.g. due to missing permissions.
+    ///
+    /// [`VoiceError::JoinTimeout`]: enum.VoiceError.html#variant.JoinTimeout
+    /// [`VoiceError::NoShard`]: enum.VoiceError.html#variant.NoShard
+    pub async fn join(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<Connection> {
+        let (tx, rx) = oneshot::channel();
+
+        {
+            let mut state = self.state.lock().await;
+            let shard = state.shard(guild_id).cloned().ok_or(VoiceError::NoShard)?;
+
+            state.pending.insert(guild_id, PendingJoin {
+                tx: Some(tx),
+                ..PendingJoin::default()
+            });
+
+            shard.join_voice_channel(guild_id, channel_id, false, false);
+        }
+
+        let info = match timeout(JOIN_TIMEOUT, rx).await {
+            Ok(Ok(info)) => info,
+            _ => {
+                self.state.lock().await.pending.remove(&guild_id);
+
+                return Err(VoiceError::JoinTimeout.into());
+            },
+        };
+
+        Connection::connect(&info).await
+    }
+
+    /// Leaves the voice channel of a guild.
+    ///
+    /// The [`Connection`] to the guild's voice server should be disconnected
+    /// as well.
+    ///
+    /// [`Connection`]: struct.Connection.html
+    pub async fn leave(&self, guild_id: GuildId) -> Result<()> {
+        let mut state = self.state.lock().await;
+        state.pending.remove(&guild_id);
+
+        let shard = state.shard(guild_id).ok_or(VoiceError::NoShard)?;
+        shard.leave_voice_channel(guild_id);
+
+        Ok(())
+    }
+}
+
+impl State {
+    fn shard(&self, guild_id: GuildId) -> Option<&ShardMessenger> {
+        if self.shard_count == 0 {
+            return None;
+        }
+
+        self.shards.get(&((guild_id.0 >> 22) % self.shard_count))
+    }
+
+    /// Completes the join of a guild once all of its information arrived.
+    fn complete(&mut self, guild_id: GuildId) {
+        let user_id = match self.user_id {
+            Some(user_id) => user_id,
+            None => return,
+        };
+
+        let pending = match self.pending.get_mut(&guild_id) {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        if let (Some(session_id), Some(endpoint), Some(token)) = (&pending.session_id, &pending.endpoint, &pending.token) {
+            let info = ConnectionInfo::new(endpoint.clone(), guild_id, session_id.clone(), token.clone(), user_id);
+
+            if let Some(tx) = pending.tx.take() {
+                let _ = tx.send(info);
+            }
+
+            self.pending.remove(&guild_id);
+        }
+    }
+}
+
+#[async_trait]
+impl VoiceGatewayManager for VoiceManager {
+    async fn initialise(&self, shard_count: u64, user_id: UserId) {
+        let mut state = self.state.lock().await;
+        state.shard_count = shard_count;
+        state.user_id = Some(user_id);
+    }
+
+    async fn register_shard(&self, shard_id: u64, sender: Sender<InterMessage>) {
+        self.state.lock().await.shards.insert(shard_id, ShardMessenger::new(sender));
+    }
+
+    async fn deregister_shard(&self, shard_id: u64) {
+        self.state.lock().await.shards.remove(&shard_id);
+    }
+
+    async fn server_update(&self, guild_id: GuildId, endpoint: &Option<String>, token: &str) {
+        let mut state = self.state.lock().await;
+
+        if let (Some(pending), Some(endpoint)) = (state.pending.get_mut(&guild_id), endpoint) {
+            pending.endpoint = Some(endpoint.clone());
+            pending.token = Some(token.to_string());
+        }
+
+        state.complete(guild_id);
+    }
+
+    async fn state_update(&self, guild_id: GuildId, voice_state: &VoiceState) {
+        let mut state = self.state.lock().await;
+
+        if state.user_id != Some(voice_state.user_id) {
+            return;
+        }
+
+        if let Some(pending) = state.pending.get_mut(&guild_id) {
+            pending.session_id = Some(voice_state.session_id.clone());
+        }
+
+        state.complete(guild_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn completes_join_with_state_and_server() {
+        let (tx, mut rx) = oneshot::channel();
+        let mut state = State {
+            user_id: Some(UserId(1)),
+            ..State::default()
+        };
+        state.pending.insert(GuildId(2), PendingJoin {
+            session_id: Some("session".to_string()),
+            tx: Some(tx),
+            ..PendingJoin::default()
+        });
+
+        state.complete(GuildId(2));
+        assert!(rx.try_recv().unwrap().is_none());
+
+        let pending = state.pending.get_mut(&GuildId(2)).unwrap();
+        pending.endpoint = Some("voice.example:80".to_string());
+        pending.token = Some("token".to_string());
+        state.complete(GuildId(2));
+
+        let info = rx.await.unwrap();
+        assert_eq!((info.guild_id, info.user_id), (GuildId(2), UserId(1)));
+        assert_eq!(info.session_id, "session");
+        assert!(state.pending.is_empty());
+    }
+}
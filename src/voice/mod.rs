@@ -0,0 +1,27 @@
+//! A built-in voice driver, sending audio to voice channels.
+//!
+//! The [`VoiceManager`] joins voice channels through the gateway and connects
+//! to their voice servers, returning a [`Connection`] to play audio over.
+//! Audio is sent as Opus frames from an [`AudioSource`], and encrypted with
+//! the `xsalsa20_poly1305` mode.
+//!
+//! Receiving audio is not supported. For more involved uses, such as mixing
+//! or transcoding audio, an external voice crate can implement the
+//! [`VoiceGatewayManager`] instead.
+//!
+//! [`AudioSource`]: trait.AudioSource.html
+//! [`Connection`]: struct.Connection.html
+//! [`VoiceGatewayManager`]: ../client/bridge/voice/trait.VoiceGatewayManager.html
+//! [`VoiceManager`]: struct.VoiceManager.html
+
+mod audio;
+mod connection;
+mod error;
+mod manager;
+
+pub use self::{
+    audio::AudioSource,
+    connection::{Connection, ConnectionInfo},
+    error::Error as VoiceError,
+    manager::VoiceManager,
+};
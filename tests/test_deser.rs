@@ -27,6 +27,31 @@ fn activity() {
     p!(Activity, "activity_1");
     p!(Activity, "activity_2");
     p!(Activity, "activity_3");
+
+    let activity = p!(Activity, "activity_4");
+    assert_eq!(activity.buttons.len(), 2);
+    assert_eq!(activity.buttons[0].label, "Join");
+}
+
+#[test]
+fn application_command() {
+    p!(ApplicationCommand, "application_command_1");
+}
+
+#[test]
+fn application_command_permissions() {
+    let permissions = p!(ApplicationCommandPermission, "application_command_permissions_1");
+
+    assert_eq!(permissions.permissions[1].kind, ApplicationCommandPermissionType::User);
+    assert_eq!(permissions.permissions[1].id, 142029498476756992);
+}
+
+#[test]
+fn auto_moderation_rule_create() {
+    let event = p!(AutoModerationRuleCreateEvent, "auto_moderation_rule_create_1");
+
+    assert_eq!(event.rule.trigger_type, AutoModTriggerType::Keyword);
+    assert_eq!(event.rule.actions[1].kind, AutoModActionType::SendAlertMessage);
 }
 
 #[test]
@@ -70,6 +95,80 @@ fn guild_channel_1_rest() {
     p!(GuildChannel, "guild_channel_rest_1");
 }
 
+// A voice channel with a set region and video quality.
+#[test]
+fn guild_channel_voice() {
+    let channel = p!(GuildChannel, "guild_channel_voice_1");
+
+    assert_eq!(channel.rtc_region.as_deref(), Some("rotterdam"));
+    assert_eq!(channel.video_quality_mode, Some(VideoQualityMode::Full));
+
+    let channel = p!(GuildChannel, "guild_channel_rest_1");
+
+    assert_eq!(channel.rtc_region, None);
+    assert_eq!(channel.video_quality_mode, None);
+}
+
+#[test]
+fn voice_region() {
+    let region = p!(VoiceRegion, "voice_region_1");
+
+    assert_eq!(region.id, "rotterdam");
+    assert!(region.optimal);
+}
+
+#[test]
+fn guild_template() {
+    let template = p!(GuildTemplate, "guild_template_1");
+
+    assert_eq!(template.code, "hgM48av5Q69A");
+    assert_eq!(template.creator_id, UserId(132693723173240832));
+    assert_eq!(template.source_guild_id, GuildId(678070694164299796));
+    assert_eq!(template.created_at.unix_timestamp(), 1585861838);
+    assert_eq!(template.is_dirty, None);
+}
+
+#[test]
+fn integration() {
+    let integration = p!(Integration, "integration_1");
+
+    assert_eq!(integration.kind, "discord");
+    assert_eq!(integration.guild_id, Some(GuildId(381880193251409931)));
+    assert_eq!(integration.application.unwrap().id, ApplicationId(1023994284637696094));
+    assert_eq!(integration.scopes, Some(vec![OAuth2Scope::Bot, OAuth2Scope::ApplicationsCommands]));
+    assert!(integration.synced_at.is_none());
+}
+
+#[test]
+fn application_role_connection_metadata() {
+    let metadata = p!(ApplicationRoleConnectionMetadata, "application_role_connection_metadata_1");
+
+    assert_eq!(metadata.kind, ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual);
+    assert_eq!(metadata.name_localizations.unwrap()["de"], "Gespielte Spiele");
+    assert!(metadata.description_localizations.is_none());
+}
+
+#[test]
+fn user() {
+    let user = p!(User, "user_1");
+
+    assert_eq!(user.banner.as_deref(), Some("a_06c16474723fe537c283b8efa61a30c8"));
+    assert_eq!(user.accent_colour.map(|c| c.0), Some(0xFF0000));
+    assert!(user.avatar_decoration.is_none());
+}
+
+#[test]
+fn guild_widget() {
+    let widget = p!(GuildWidget, "guild_widget_1");
+
+    assert_eq!(widget.id, GuildId(290926798626357999));
+    assert_eq!(widget.channels[0].id, ChannelId(705216630279993882));
+    assert_eq!(widget.members[0].status, OnlineStatus::Online);
+    assert_eq!(widget.members[0].channel_id, Some(ChannelId(705216630279993882)));
+    assert_eq!(widget.members[1].channel_id, None);
+    assert_eq!(widget.presence_count, 2);
+}
+
 // A guild that has some application ID.
 #[test]
 fn guild_some_application_id() {
@@ -121,6 +220,37 @@ fn guild_role_delete() {
 #[test]
 fn guild_role_update() {
     p!(GuildRoleUpdateEvent, "guild_role_update_1");
+
+    let event = p!(GuildRoleUpdateEvent, "guild_role_update_2");
+
+    assert_eq!(event.role.icon_url().unwrap(), "https://cdn.discordapp.com/role-icons/302929192750940160/2a3a8ab8e9a43c8f1bd3fcaa0b6a7d2c.webp");
+}
+
+#[test]
+fn guild_scheduled_event_create() {
+    let event = p!(GuildScheduledEventCreateEvent, "guild_scheduled_event_create_1");
+
+    assert_eq!(event.event.kind, ScheduledEventType::External);
+    assert_eq!(event.event.status, ScheduledEventStatus::Scheduled);
+
+    // recurring event with a cover image
+    let event = p!(GuildScheduledEventCreateEvent, "guild_scheduled_event_create_2");
+    let rule = event.event.recurrence_rule.as_ref().expect("expected a recurrence rule");
+
+    assert_eq!(rule.frequency, RecurrenceRuleFrequency::Monthly);
+    assert_eq!(rule.by_n_weekday.as_ref().unwrap()[0].day, RecurrenceRuleWeekday::Friday);
+    assert_eq!(
+        event.event.image_url(None, None).unwrap(),
+        "https://cdn.discordapp.com/guild-events/902322137341853727/9d1c2ee9ef0b5b8f0e8f3a7d3ad1e4bb.webp",
+    );
+}
+
+#[test]
+fn guild_stickers_update() {
+    let event = p!(GuildStickersUpdateEvent, "guild_stickers_update_1");
+
+    assert_eq!(event.stickers.len(), 2);
+    assert_eq!(event.stickers[&StickerId(749054660769218632)].format_type, StickerFormatType::Lottie);
 }
 
 #[test]
@@ -128,6 +258,48 @@ fn guild_update() {
     p!(GuildUpdateEvent, "guild_update_1");
 }
 
+#[test]
+fn interaction_create() {
+    let event = p!(InteractionCreateEvent, "interaction_create_1");
+
+    assert_eq!(event.interaction.user.unwrap().id, UserId(53908232506183680));
+    assert_eq!(event.interaction.member.unwrap().guild_id, GuildId(244567637332328449));
+}
+
+// A ping, carrying neither a user nor a member.
+#[test]
+fn interaction_ping() {
+    let interaction = p!(Interaction, "interaction_ping_1");
+
+    assert_eq!(interaction.kind, InteractionType::Ping);
+    assert!(interaction.data.is_none());
+    assert!(interaction.user.is_none());
+    assert!(interaction.member.is_none());
+}
+
+#[test]
+fn interaction_modal_submit() {
+    let interaction = p!(Interaction, "interaction_modal_submit_1");
+
+    match interaction.data {
+        Some(InteractionData::ModalSubmit(data)) => assert_eq!(data.value("text"), Some("Looks good")),
+        _ => panic!("expected modal submit data"),
+    }
+}
+
+#[test]
+fn interaction_user_select() {
+    let interaction = p!(Interaction, "interaction_user_select_1");
+
+    match interaction.data {
+        Some(InteractionData::MessageComponent(data)) => {
+            assert_eq!(data.component_type, ComponentType::UserSelect);
+            assert!(data.resolved.users.contains_key(&UserId(53908232506183680)));
+        },
+        _ => panic!("expected message component data"),
+    }
+}
+
 #[test]
 fn message_create() {
     // standard
@@ -138,6 +310,19 @@ fn message_create() {
 
     // message from guild with partial member data
     p!(MessageCreateEvent, "message_create_3");
+
+    // message with a poll
+    let event = p!(MessageCreateEvent, "message_create_4");
+    let poll = event.message.poll.expect("expected a poll");
+    assert_eq!(poll.answers.len(), 2);
+    assert_eq!(poll.layout_type, PollLayoutType::Default);
+    assert_eq!(poll.results.unwrap().answer_counts[0].count, 3);
+}
+
+#[test]
+fn message_poll_vote_add() {
+    let event = p!(MessagePollVoteAddEvent, "message_poll_vote_add_1");
+    assert_eq!(event.answer_id, 1);
 }
 
 #[test]
@@ -169,6 +354,21 @@ fn ready() {
     p!(ReadyEvent, "ready_1");
 }
 
+#[test]
+fn stage_instance_create() {
+    let event = p!(StageInstanceCreateEvent, "stage_instance_create_1");
+
+    assert_eq!(event.stage_instance.privacy_level, StagePrivacyLevel::GuildOnly);
+}
+
+#[test]
+fn thread_create() {
+    let event = p!(ThreadCreateEvent, "thread_create_1");
+
+    assert_eq!(event.thread.kind, ChannelType::PublicThread);
+    assert!(event.thread.thread_metadata.is_some());
+}
+
 #[test]
 fn typing_start() {
     p!(TypingStartEvent, "typing_start_1");
@@ -226,3 +426,62 @@ fn decode_footer_deser() {
 
     p!(Message, "message_footer_2");
 }
+
+#[test]
+fn guild_welcome_screen() {
+    let screen = p!(GuildWelcomeScreen, "guild_welcome_screen");
+
+    assert_eq!(screen.welcome_channels[1].emoji_id, Some(EmojiId(697236247739105341)));
+}
+
+#[test]
+fn membership_screening() {
+    let screening = p!(MembershipScreening, "membership_screening");
+
+    assert_eq!(screening.form_fields[0].field_type, MembershipScreeningFieldType::Terms);
+    assert_eq!(screening.form_fields[0].values.len(), 2);
+}
+
+#[test]
+fn message_inline_reply() {
+    let message = p!(Message, "message_inline_reply");
+
+    assert_eq!(message.kind, MessageType::InlineReply);
+    assert_eq!(message.message_reference.unwrap().message_id, Some(MessageId(300000000000000001)));
+}
+
+#[test]
+fn bulk_ban_response() {
+    let response = p!(BulkBanResponse, "bulk_ban_response");
+
+    assert_eq!(response.banned_users, vec![UserId(114941315417899012), UserId(82763826541436928)]);
+    assert_eq!(response.failed_users, vec![UserId(80351110224678912)]);
+}
+
+#[test]
+fn current_authorization_info() {
+    let info = p!(CurrentAuthorizationInfo, "current_authorization_info");
+
+    assert_eq!(info.application.id, ApplicationId(159799960412356608));
+    assert_eq!(info.scopes, vec![OAuth2Scope::GuildsJoin, OAuth2Scope::Identify, OAuth2Scope::Unknown]);
+    assert_eq!(info.user.unwrap().name, "Discord");
+}
+
+#[test]
+fn audit_log_entry() {
+    let entry = p!(AuditLogEntry, "audit_log_entry_1");
+
+    assert_eq!(entry.action, Action::Member(ActionMember::RoleUpdate));
+    match &entry.changes.as_ref().unwrap()[0] {
+        Change::RolesAdded { old: None, new: Some(roles) } => assert_eq!(roles[0].id, 887286548430045204),
+        change => panic!("unexpected change: {:?}", change),
+    }
+
+    let entry = p!(AuditLogEntry, "audit_log_entry_2");
+    let changes = entry.changes.unwrap();
+
+    assert_eq!(entry.action, Action::Channel(ActionChannel::Update));
+    assert!(matches!(&changes[1], Change::RateLimitPerUser { old: Some(0), new: Some(5) }));
+    assert_eq!(changes[2].name(), "default_reaction_emoji");
+    assert!(matches!(&changes[2], Change::Other { old: None, new: Some(_), .. }));
+}